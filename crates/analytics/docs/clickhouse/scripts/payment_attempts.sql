@@ -38,6 +38,7 @@ CREATE TABLE payment_attempt_queue (
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
     `mandate_data` Nullable(String),
+    `connector_latency` Nullable(Int64),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -85,6 +86,7 @@ CREATE TABLE payment_attempts (
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
     `mandate_data` Nullable(String),
+    `connector_latency` Nullable(Int64),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
@@ -136,6 +138,7 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
     `mandate_data` Nullable(String),
+    `connector_latency` Nullable(Int64),
     `inserted_at` DateTime64(3),
     `sign_flag` Int8
 ) AS
@@ -179,6 +182,7 @@ SELECT
     unified_code,
     unified_message,
     mandate_data,
+    connector_latency,
     now() AS inserted_at,
     sign_flag
 FROM
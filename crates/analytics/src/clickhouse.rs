@@ -13,7 +13,11 @@ use super::{
         distribution::PaymentDistributionRow, filters::FilterRow, metrics::PaymentMetricRow,
     },
     query::{Aggregate, ToSql, Window},
-    refunds::{filters::RefundFilterRow, metrics::RefundMetricRow},
+    refunds::{
+        filters::RefundFilterRow,
+        metrics::RefundMetricRow,
+        refund_rate::{AmountCapturedRow, CapturedPaymentRow},
+    },
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
     types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
 };
@@ -23,7 +27,7 @@ use crate::{
         filters::ApiEventFilter,
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
-    connector_events::events::ConnectorEventsResult,
+    connector_events::{events::ConnectorEventsResult, latency::ConnectorLatencyBucketRow},
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
     sdk_events::events::SdkEventsResult,
@@ -157,6 +161,7 @@ impl super::payments::metrics::PaymentMetricAnalytics for ClickhouseClient {}
 impl super::payments::distribution::PaymentDistributionAnalytics for ClickhouseClient {}
 impl super::refunds::metrics::RefundMetricAnalytics for ClickhouseClient {}
 impl super::refunds::filters::RefundFilterAnalytics for ClickhouseClient {}
+impl super::refunds::refund_rate::RefundRateAnalytics for ClickhouseClient {}
 impl super::sdk_events::filters::SdkEventFilterAnalytics for ClickhouseClient {}
 impl super::sdk_events::metrics::SdkEventMetricAnalytics for ClickhouseClient {}
 impl super::sdk_events::events::SdkEventsFilterAnalytics for ClickhouseClient {}
@@ -165,6 +170,7 @@ impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
 impl super::connector_events::events::ConnectorEventLogAnalytics for ClickhouseClient {}
+impl super::connector_events::latency::ConnectorLatencyAnalytics for ClickhouseClient {}
 impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
@@ -214,6 +220,16 @@ impl TryInto<ConnectorEventsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ConnectorLatencyBucketRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorLatencyBucketRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorLatencyBucketRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<PaymentMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -254,6 +270,26 @@ impl TryInto<RefundMetricRow> for serde_json::Value {
     }
 }
 
+impl TryInto<CapturedPaymentRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<CapturedPaymentRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse CapturedPaymentRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<AmountCapturedRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<AmountCapturedRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse AmountCapturedRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<RefundFilterRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -1,5 +1,9 @@
 mod core;
 pub mod events;
-pub trait ConnectorEventAnalytics: events::ConnectorEventLogAnalytics {}
+pub mod latency;
+pub trait ConnectorEventAnalytics:
+    events::ConnectorEventLogAnalytics + latency::ConnectorLatencyAnalytics
+{
+}
 
-pub use self::core::connector_events_core;
+pub use self::core::{connector_events_core, connector_latency_percentiles_core};
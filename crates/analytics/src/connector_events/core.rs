@@ -1,8 +1,13 @@
-use api_models::analytics::connector_events::ConnectorEventsRequest;
+use api_models::analytics::connector_events::{
+    ConnectorEventsRequest, ConnectorLatencyPercentiles, ConnectorLatencyPercentilesRequest,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
 
-use super::events::{get_connector_events, ConnectorEventsResult};
+use super::{
+    events::{get_connector_events, ConnectorEventsResult},
+    latency::get_connector_latency_percentiles,
+};
 use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
 
 pub async fn connector_events_core(
@@ -24,3 +29,23 @@ pub async fn connector_events_core(
     .switch()?;
     Ok(data)
 }
+
+pub async fn connector_latency_percentiles_core(
+    pool: &AnalyticsProvider,
+    req: ConnectorLatencyPercentilesRequest,
+    merchant_id: String,
+) -> AnalyticsResult<Vec<ConnectorLatencyPercentiles>> {
+    let data = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Connector Latency Percentiles not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Connector Latency Percentiles"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_connector_latency_percentiles(&merchant_id, req, ckh_pool).await
+        }
+    }
+    .switch()?;
+    Ok(data)
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+
+use api_models::analytics::{
+    connector_events::{ConnectorLatencyPercentiles, ConnectorLatencyPercentilesRequest},
+    Granularity,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, FilterTypes, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+pub trait ConnectorLatencyAnalytics: LoadRow<ConnectorLatencyBucketRow> {}
+
+/// Latencies are counted in logarithmic buckets rather than sorted, so that percentiles can be
+/// computed over any number of connector calls with a single aggregation. Bucket `i` holds the
+/// latencies in `[base^i, base^(i + 1))` milliseconds.
+const LATENCY_BUCKET_BASE: f64 = 1.02;
+
+/// Upper bound of the relative error of the computed percentiles (about 1%). A percentile is
+/// reported as the value of its bucket which is at most this far from any latency in the bucket,
+/// e.g. a 200ms p95 is reported as a value between 198ms and 202ms.
+pub const LATENCY_PERCENTILE_RELATIVE_ERROR: f64 =
+    (LATENCY_BUCKET_BASE - 1.0) / (LATENCY_BUCKET_BASE + 1.0);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ConnectorLatencyBucketRow {
+    pub connector_name: Option<String>,
+    pub flow: String,
+    pub latency_bucket: i32,
+    pub count: u64,
+}
+
+/// The value which represents all the latencies in the bucket, chosen so that its relative
+/// error is the same for the smallest and the largest latency in the bucket
+fn get_latency_bucket_value(latency_bucket: i32) -> f64 {
+    2.0 * LATENCY_BUCKET_BASE.powi(latency_bucket.saturating_add(1)) / (LATENCY_BUCKET_BASE + 1.0)
+}
+
+/// Computes the nearest-rank percentile from the counts of latencies in each bucket
+fn get_latency_percentile(latency_buckets: &BTreeMap<i32, u64>, percentile: u64) -> Option<f64> {
+    let total_count: u64 = latency_buckets.values().sum();
+    let rank = total_count.saturating_mul(percentile).div_ceil(100).max(1);
+
+    let mut cumulative_count = 0_u64;
+    latency_buckets
+        .iter()
+        .find(|(_, count)| {
+            cumulative_count = cumulative_count.saturating_add(**count);
+            cumulative_count >= rank
+        })
+        .map(|(latency_bucket, _)| get_latency_bucket_value(*latency_bucket))
+}
+
+pub fn compute_latency_percentiles(
+    rows: Vec<ConnectorLatencyBucketRow>,
+) -> Vec<ConnectorLatencyPercentiles> {
+    let mut latency_buckets: BTreeMap<(Option<String>, String), BTreeMap<i32, u64>> =
+        BTreeMap::new();
+    for row in rows {
+        *latency_buckets
+            .entry((row.connector_name, row.flow))
+            .or_default()
+            .entry(row.latency_bucket)
+            .or_default() += row.count;
+    }
+
+    latency_buckets
+        .into_iter()
+        .filter_map(|((connector_name, flow), buckets)| {
+            Some(ConnectorLatencyPercentiles {
+                request_count: buckets.values().sum(),
+                p50_latency: get_latency_percentile(&buckets, 50)?,
+                p95_latency: get_latency_percentile(&buckets, 95)?,
+                p99_latency: get_latency_percentile(&buckets, 99)?,
+                connector_name,
+                flow,
+            })
+        })
+        .collect()
+}
+
+pub async fn get_connector_latency_percentiles<T>(
+    merchant_id: &str,
+    req: ConnectorLatencyPercentilesRequest,
+    pool: &T,
+) -> FiltersResult<Vec<ConnectorLatencyPercentiles>>
+where
+    T: AnalyticsDataSource + ConnectorLatencyAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::ConnectorEventsAnalytics);
+
+    query_builder.add_select_column("connector_name").switch()?;
+    query_builder.add_select_column("flow").switch()?;
+    query_builder
+        .add_select_column(format!(
+            "toInt32(floor(log(greatest(latency, 1)) / log({LATENCY_BUCKET_BASE}))) as latency_bucket"
+        ))
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    query_builder
+        .add_custom_filter_clause("latency", "NULL", FilterTypes::IsNotNull)
+        .attach_printable("Error filtering out events without latency")
+        .switch()?;
+
+    if !req.connectors.is_empty() {
+        query_builder
+            .add_filter_in_range_clause("connector_name", &req.connectors)
+            .switch()?;
+    }
+
+    for column in ["connector_name", "flow", "latency_bucket"] {
+        query_builder.add_group_by_clause(column).switch()?;
+    }
+
+    let rows = query_builder
+        .execute_query::<ConnectorLatencyBucketRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)?;
+
+    Ok(compute_latency_percentiles(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    /// Buckets the latencies the same way as the clickhouse query
+    fn get_rows(
+        flow: &str,
+        latencies: impl IntoIterator<Item = u32>,
+    ) -> Vec<ConnectorLatencyBucketRow> {
+        latencies
+            .into_iter()
+            .map(|latency| ConnectorLatencyBucketRow {
+                connector_name: Some("stripe".to_string()),
+                flow: flow.to_string(),
+                latency_bucket: (0..)
+                    .find(|bucket| LATENCY_BUCKET_BASE.powi(bucket + 1) > f64::from(latency.max(1)))
+                    .unwrap(),
+                count: 1,
+            })
+            .collect()
+    }
+
+    fn assert_within_error_bound(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() <= expected * LATENCY_PERCENTILE_RELATIVE_ERROR,
+            "{value} is not within the error bound of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_latency_percentiles_are_within_error_bound() {
+        let rows = get_rows("Authorize", 1..=1000);
+        let percentiles = compute_latency_percentiles(rows);
+        let percentiles = percentiles.first().unwrap();
+
+        assert_eq!(percentiles.request_count, 1000);
+        assert_within_error_bound(percentiles.p50_latency, 500.0);
+        assert_within_error_bound(percentiles.p95_latency, 950.0);
+        assert_within_error_bound(percentiles.p99_latency, 990.0);
+    }
+
+    #[test]
+    fn test_latency_percentiles_are_grouped_by_flow() {
+        // A slow tail of a tenth of the calls only shows up in the p95 of the flow
+        let rows = get_rows("Authorize", [120; 90].into_iter().chain([3000; 10]))
+            .into_iter()
+            .chain(get_rows("PSync", [40; 100]))
+            .collect();
+        let percentiles = compute_latency_percentiles(rows);
+
+        assert_eq!(percentiles.len(), 2);
+        let authorize = percentiles.first().unwrap();
+        let psync = percentiles.last().unwrap();
+        assert_eq!(authorize.flow, "Authorize");
+        assert_within_error_bound(authorize.p50_latency, 120.0);
+        assert_within_error_bound(authorize.p95_latency, 3000.0);
+        assert_eq!(psync.flow, "PSync");
+        assert_eq!(psync.request_count, 100);
+        assert_within_error_bound(psync.p50_latency, 40.0);
+        assert_within_error_bound(psync.p95_latency, 40.0);
+    }
+}
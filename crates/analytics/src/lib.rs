@@ -713,6 +713,7 @@ pub enum AnalyticsFlow {
     GetInfo,
     GetPaymentMetrics,
     GetRefundsMetrics,
+    GetRefundRate,
     GetSdkMetrics,
     GetAuthMetrics,
     GetPaymentFilters,
@@ -726,6 +727,7 @@ pub enum AnalyticsFlow {
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
+    GetConnectorLatencyPercentiles,
     GetOutgoingWebhookEvents,
     GetGlobalSearchResults,
     GetSearchResults,
@@ -16,6 +16,7 @@ pub struct PaymentMetricsAccumulator {
     pub retries_count: CountAccumulator,
     pub retries_amount_processed: SumAccumulator,
     pub connector_success_rate: SuccessRateAccumulator,
+    pub payment_method_success_rate: AuthorizationRateAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -36,6 +37,12 @@ pub struct SuccessRateAccumulator {
     pub total: i64,
 }
 
+#[derive(Debug, Default)]
+pub struct AuthorizationRateAccumulator {
+    pub authorized: i64,
+    pub total: i64,
+}
+
 #[derive(Debug, Default)]
 #[repr(transparent)]
 pub struct CountAccumulator {
@@ -131,6 +138,38 @@ impl PaymentMetricAccumulator for SuccessRateAccumulator {
     }
 }
 
+impl PaymentMetricAccumulator for AuthorizationRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentMetricRow) {
+        // Attempts which were authorized, whether or not they have been captured since
+        if let Some(ref status) = metrics.status {
+            if matches!(
+                status.as_ref(),
+                storage_enums::AttemptStatus::Authorized
+                    | storage_enums::AttemptStatus::Charged
+                    | storage_enums::AttemptStatus::PartialCharged
+                    | storage_enums::AttemptStatus::PartialChargedAndChargeable
+                    | storage_enums::AttemptStatus::CaptureInitiated
+            ) {
+                self.authorized += metrics.count.unwrap_or_default();
+            }
+        };
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.authorized).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.total).ok()?),
+            )
+        }
+    }
+}
+
 impl PaymentMetricAccumulator for CountAccumulator {
     type MetricOutput = Option<u64>;
     #[inline]
@@ -205,6 +244,98 @@ impl PaymentMetricsAccumulator {
             retries_count: self.retries_count.collect(),
             retries_amount_processed: self.retries_amount_processed.collect(),
             connector_success_rate: self.connector_success_rate.collect(),
+            payment_method_success_rate: self.payment_method_success_rate.collect(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::types::DBEnumWrapper;
+
+    fn get_metric_row(
+        payment_method: &str,
+        payment_method_type: &str,
+        status: storage_enums::AttemptStatus,
+        count: Option<i64>,
+    ) -> PaymentMetricRow {
+        PaymentMetricRow {
+            currency: None,
+            status: Some(DBEnumWrapper(status)),
+            connector: Some("stripe".to_string()),
+            authentication_type: None,
+            payment_method: Some(payment_method.to_string()),
+            payment_method_type: Some(payment_method_type.to_string()),
+            total: None,
+            count,
+            start_bucket: None,
+            end_bucket: None,
+        }
+    }
+
+    #[test]
+    fn test_payment_method_success_rate() {
+        let rows = [
+            get_metric_row(
+                "card",
+                "credit",
+                storage_enums::AttemptStatus::Charged,
+                Some(3),
+            ),
+            get_metric_row(
+                "card",
+                "credit",
+                storage_enums::AttemptStatus::Authorized,
+                Some(1),
+            ),
+            get_metric_row(
+                "card",
+                "credit",
+                storage_enums::AttemptStatus::Failure,
+                Some(1),
+            ),
+            get_metric_row(
+                "card",
+                "debit",
+                storage_enums::AttemptStatus::Failure,
+                Some(2),
+            ),
+            get_metric_row(
+                "wallet",
+                "apple_pay",
+                storage_enums::AttemptStatus::Charged,
+                Some(1),
+            ),
+            get_metric_row(
+                "upi",
+                "upi_collect",
+                storage_enums::AttemptStatus::Charged,
+                None,
+            ),
+        ];
+
+        let mut accumulators: HashMap<_, AuthorizationRateAccumulator> = HashMap::new();
+        for row in rows.iter() {
+            accumulators
+                .entry((row.payment_method.clone(), row.payment_method_type.clone()))
+                .or_default()
+                .add_metrics_bucket(row);
+        }
+        let success_rates: HashMap<_, _> = accumulators
+            .into_iter()
+            .map(|((_, payment_method_type), accumulator)| {
+                (payment_method_type, accumulator.collect())
+            })
+            .collect();
+
+        assert_eq!(success_rates[&Some("credit".to_string())], Some(80.0));
+        assert_eq!(success_rates[&Some("debit".to_string())], Some(0.0));
+        assert_eq!(success_rates[&Some("apple_pay".to_string())], Some(100.0));
+        // Buckets without any attempts have no success rate rather than dividing by zero
+        assert_eq!(success_rates[&Some("upi_collect".to_string())], None);
+        assert_eq!(AuthorizationRateAccumulator::default().collect(), None);
+    }
+}
@@ -161,6 +161,11 @@ pub async fn get_metrics(
                                 .connector_success_rate
                                 .add_metrics_bucket(&value);
                         }
+                        PaymentMetrics::PaymentMethodSuccessRate => {
+                            metrics_builder
+                                .payment_method_success_rate
+                                .add_metrics_bucket(&value);
+                        }
                     }
                 }
 
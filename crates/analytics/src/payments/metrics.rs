@@ -13,6 +13,7 @@ use crate::{
 mod avg_ticket_size;
 mod connector_success_rate;
 mod payment_count;
+mod payment_method_success_rate;
 mod payment_processed_amount;
 mod payment_success_count;
 mod retries_count;
@@ -21,6 +22,7 @@ mod success_rate;
 use avg_ticket_size::AvgTicketSize;
 use connector_success_rate::ConnectorSuccessRate;
 use payment_count::PaymentCount;
+use payment_method_success_rate::PaymentMethodSuccessRate;
 use payment_processed_amount::PaymentProcessedAmount;
 use payment_success_count::PaymentSuccessCount;
 use success_rate::PaymentSuccessRate;
@@ -165,6 +167,18 @@ where
                     )
                     .await
             }
+            Self::PaymentMethodSuccessRate => {
+                PaymentMethodSuccessRate
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
         }
     }
 }
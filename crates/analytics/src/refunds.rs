@@ -3,7 +3,8 @@ mod core;
 
 pub mod filters;
 pub mod metrics;
+pub mod refund_rate;
 pub mod types;
 pub use accumulator::{RefundMetricAccumulator, RefundMetricsAccumulator};
 
-pub use self::core::{get_filters, get_metrics};
+pub use self::core::{get_filters, get_metrics, get_refund_rate};
@@ -3,10 +3,11 @@ use std::collections::HashMap;
 
 use api_models::analytics::{
     refunds::{
-        RefundDimensions, RefundMetrics, RefundMetricsBucketIdentifier, RefundMetricsBucketResponse,
+        RefundDimensions, RefundMetrics, RefundMetricsBucketIdentifier,
+        RefundMetricsBucketResponse, RefundRateMetric,
     },
-    AnalyticsMetadata, GetRefundFilterRequest, GetRefundMetricRequest, MetricsResponse,
-    RefundFilterValue, RefundFiltersResponse,
+    AnalyticsMetadata, GetRefundFilterRequest, GetRefundMetricRequest, GetRefundRateRequest,
+    MetricsResponse, RefundFilterValue, RefundFiltersResponse,
 };
 use error_stack::ResultExt;
 use router_env::{
@@ -16,6 +17,7 @@ use router_env::{
 
 use super::{
     filters::{get_refund_filter_for_dimension, RefundFilterRow},
+    refund_rate::get_refund_rates,
     RefundMetricsAccumulator,
 };
 use crate::{
@@ -200,3 +202,19 @@ pub async fn get_filters(
     }
     Ok(res)
 }
+
+pub async fn get_refund_rate(
+    pool: &AnalyticsProvider,
+    merchant_id: &str,
+    req: GetRefundRateRequest,
+) -> AnalyticsResult<Vec<RefundRateMetric>> {
+    match pool {
+        AnalyticsProvider::Sqlx(sqlx_pool) | AnalyticsProvider::CombinedSqlx(sqlx_pool, _) => {
+            get_refund_rates(merchant_id, &req, sqlx_pool).await
+        }
+        AnalyticsProvider::Clickhouse(ckh_pool) | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_refund_rates(merchant_id, &req, ckh_pool).await
+        }
+    }
+    .change_context(AnalyticsError::UnknownError)
+}
@@ -0,0 +1,462 @@
+use std::collections::{BTreeMap, HashMap};
+
+use api_models::analytics::{
+    payments::PaymentDimensions,
+    refunds::{RefundDimensions, RefundRateMetric},
+    GetRefundRateRequest, Granularity,
+};
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::metrics::{RefundMetricAnalytics, RefundMetricRow};
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsError,
+        MetricsResult,
+    },
+};
+
+/// Number of payment ids filtered on by a single query for the amounts captured
+const AMOUNT_CAPTURED_QUERY_CHUNK_SIZE: usize = 1000;
+
+/// A payment captured by a connector, along with the time at which its attempt was created
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct CapturedPaymentRow {
+    pub payment_id: String,
+    pub connector: Option<String>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+}
+
+/// The amount captured for a payment, which adds up all of its captures
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct AmountCapturedRow {
+    pub payment_id: String,
+    pub total: Option<BigDecimal>,
+}
+
+pub trait RefundRateAnalytics: LoadRow<CapturedPaymentRow> + LoadRow<AmountCapturedRow> {}
+
+type RefundRateKey = (
+    Option<String>,
+    Option<storage_enums::Currency>,
+    PrimitiveDateTime,
+);
+
+#[derive(Debug, Default)]
+struct RefundRateTotals {
+    refunded_amount: BigDecimal,
+    captured_amount: BigDecimal,
+}
+
+fn get_refund_rate_key(
+    connector: Option<String>,
+    currency: Option<storage_enums::Currency>,
+    start_bucket: Option<PrimitiveDateTime>,
+) -> Option<RefundRateKey> {
+    let day = start_bucket?.replace_time(time::Time::MIDNIGHT);
+    Some((connector, currency, day))
+}
+
+/// Matches the successful refunds of each connector and day with the amount captured by the
+/// connector on the same day. The amount captured for a payment adds up all of its captures, so
+/// payments captured in several parts count every part. Refunds of payments captured on an earlier
+/// day are still counted, and a day on which nothing was captured has no refund rate.
+pub fn compute_refund_rates(
+    refund_rows: Vec<RefundMetricRow>,
+    captured_payment_rows: Vec<CapturedPaymentRow>,
+    amounts_captured: &HashMap<String, BigDecimal>,
+) -> Vec<RefundRateMetric> {
+    let mut totals: BTreeMap<RefundRateKey, RefundRateTotals> = BTreeMap::new();
+
+    for row in refund_rows {
+        if let Some(key) = get_refund_rate_key(
+            row.connector,
+            row.currency.map(|currency| currency.0),
+            row.start_bucket,
+        ) {
+            totals.entry(key).or_default().refunded_amount += row.total.unwrap_or_default();
+        }
+    }
+    for row in captured_payment_rows {
+        let amount_captured = amounts_captured.get(&row.payment_id);
+        if let Some((key, amount_captured)) = get_refund_rate_key(
+            row.connector,
+            row.currency.map(|currency| currency.0),
+            row.start_bucket,
+        )
+        .zip(amount_captured)
+        {
+            totals.entry(key).or_default().captured_amount += amount_captured;
+        }
+    }
+
+    totals
+        .into_iter()
+        .map(|((connector, currency, day), totals)| RefundRateMetric {
+            connector,
+            currency,
+            day,
+            refunded_amount: totals.refunded_amount.to_u64().unwrap_or_default(),
+            captured_amount: totals.captured_amount.to_u64().unwrap_or_default(),
+            refund_rate: (!totals.captured_amount.is_zero())
+                .then(|| (&totals.refunded_amount / &totals.captured_amount).to_f64())
+                .flatten(),
+        })
+        .collect()
+}
+
+async fn get_refunded_amounts<T>(
+    merchant_id: &str,
+    req: &GetRefundRateRequest,
+    pool: &T,
+) -> MetricsResult<Vec<RefundMetricRow>>
+where
+    T: AnalyticsDataSource + RefundMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Refund);
+
+    for dim in [RefundDimensions::Connector, RefundDimensions::Currency] {
+        query_builder.add_select_column(dim).switch()?;
+    }
+    query_builder
+        .add_select_column(Aggregate::Sum {
+            field: "refund_amount",
+            alias: Some("total"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder
+        .add_filter_clause(
+            RefundDimensions::RefundStatus,
+            storage_enums::RefundStatus::Success,
+        )
+        .switch()?;
+    if !req.connectors.is_empty() {
+        query_builder
+            .add_filter_in_range_clause(RefundDimensions::Connector, &req.connectors)
+            .switch()?;
+    }
+
+    for dim in [RefundDimensions::Connector, RefundDimensions::Currency] {
+        query_builder
+            .add_group_by_clause(dim)
+            .attach_printable("Error grouping by dimensions")
+            .switch()?;
+    }
+    Granularity::OneDay
+        .set_group_by_clause(&mut query_builder)
+        .attach_printable("Error adding granularity")
+        .switch()?;
+
+    query_builder
+        .execute_query::<RefundMetricRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
+
+/// Payments captured by each connector and day. The amounts are fetched from the payment intents,
+/// since an attempt only holds the amount of its latest capture.
+async fn get_captured_payments<T>(
+    merchant_id: &str,
+    req: &GetRefundRateRequest,
+    pool: &T,
+) -> MetricsResult<Vec<CapturedPaymentRow>>
+where
+    T: AnalyticsDataSource + RefundRateAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+    for dim in [PaymentDimensions::Connector, PaymentDimensions::Currency] {
+        query_builder.add_select_column(dim).switch()?;
+    }
+    query_builder.add_select_column("payment_id").switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder
+        .add_filter_in_range_clause(
+            PaymentDimensions::PaymentStatus,
+            &[
+                storage_enums::AttemptStatus::Charged,
+                storage_enums::AttemptStatus::PartialCharged,
+            ],
+        )
+        .switch()?;
+    if !req.connectors.is_empty() {
+        query_builder
+            .add_filter_in_range_clause(PaymentDimensions::Connector, &req.connectors)
+            .switch()?;
+    }
+
+    for dim in [PaymentDimensions::Connector, PaymentDimensions::Currency] {
+        query_builder
+            .add_group_by_clause(dim)
+            .attach_printable("Error grouping by dimensions")
+            .switch()?;
+    }
+    query_builder
+        .add_group_by_clause("payment_id")
+        .attach_printable("Error grouping by payment id")
+        .switch()?;
+    Granularity::OneDay
+        .set_group_by_clause(&mut query_builder)
+        .attach_printable("Error adding granularity")
+        .switch()?;
+
+    query_builder
+        .execute_query::<CapturedPaymentRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
+
+/// Total amount captured for each of the payments, across all of their captures
+async fn get_amounts_captured<T>(
+    merchant_id: &str,
+    payment_ids: &[String],
+    pool: &T,
+) -> MetricsResult<HashMap<String, BigDecimal>>
+where
+    T: AnalyticsDataSource + RefundRateAnalytics,
+    AnalyticsCollection: ToSql<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut amounts_captured = HashMap::new();
+
+    for payment_ids in payment_ids.chunks(AMOUNT_CAPTURED_QUERY_CHUNK_SIZE) {
+        let mut query_builder: QueryBuilder<T> =
+            QueryBuilder::new(AnalyticsCollection::PaymentIntent);
+
+        query_builder.add_select_column("payment_id").switch()?;
+        query_builder
+            .add_select_column(Aggregate::Sum {
+                field: "amount_captured",
+                alias: Some("total"),
+            })
+            .switch()?;
+
+        query_builder
+            .add_filter_clause("merchant_id", merchant_id)
+            .switch()?;
+        query_builder
+            .add_filter_in_range_clause("payment_id", payment_ids)
+            .switch()?;
+        query_builder
+            .add_group_by_clause("payment_id")
+            .attach_printable("Error grouping by payment id")
+            .switch()?;
+
+        let rows = query_builder
+            .execute_query::<AmountCapturedRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?;
+        amounts_captured.extend(
+            rows.into_iter()
+                .map(|row| (row.payment_id, row.total.unwrap_or_default())),
+        );
+    }
+
+    Ok(amounts_captured)
+}
+
+pub async fn get_refund_rates<T>(
+    merchant_id: &str,
+    req: &GetRefundRateRequest,
+    pool: &T,
+) -> MetricsResult<Vec<RefundRateMetric>>
+where
+    T: AnalyticsDataSource + RefundMetricAnalytics + RefundRateAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let (refund_rows, captured_payment_rows) = tokio::try_join!(
+        get_refunded_amounts(merchant_id, req, pool),
+        get_captured_payments(merchant_id, req, pool),
+    )?;
+    let payment_ids: Vec<String> = captured_payment_rows
+        .iter()
+        .map(|row| row.payment_id.clone())
+        .collect();
+    let amounts_captured = get_amounts_captured(merchant_id, &payment_ids, pool).await?;
+
+    Ok(compute_refund_rates(
+        refund_rows,
+        captured_payment_rows,
+        &amounts_captured,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_time(day: u8, hour: u8) -> PrimitiveDateTime {
+        time::Date::from_calendar_date(2024, time::Month::January, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn get_refund_row(
+        connector: &str,
+        amount: i64,
+        created_at: PrimitiveDateTime,
+    ) -> RefundMetricRow {
+        RefundMetricRow {
+            currency: Some(DBEnumWrapper(storage_enums::Currency::USD)),
+            refund_status: None,
+            connector: Some(connector.to_string()),
+            refund_type: None,
+            total: Some(BigDecimal::from(amount)),
+            count: None,
+            start_bucket: Some(created_at),
+            end_bucket: Some(created_at),
+        }
+    }
+
+    fn get_captured_payment_row(
+        payment_id: &str,
+        connector: &str,
+        created_at: PrimitiveDateTime,
+    ) -> CapturedPaymentRow {
+        CapturedPaymentRow {
+            payment_id: payment_id.to_string(),
+            connector: Some(connector.to_string()),
+            currency: Some(DBEnumWrapper(storage_enums::Currency::USD)),
+            start_bucket: Some(created_at),
+        }
+    }
+
+    fn get_amounts_captured(amounts: &[(&str, i64)]) -> HashMap<String, BigDecimal> {
+        amounts
+            .iter()
+            .map(|(payment_id, amount)| (payment_id.to_string(), BigDecimal::from(*amount)))
+            .collect()
+    }
+
+    #[test]
+    fn test_refund_rate_per_connector_and_day() {
+        let refund_rates = compute_refund_rates(
+            vec![
+                get_refund_row("stripe", 500, get_time(1, 10)),
+                get_refund_row("adyen", 3000, get_time(1, 12)),
+            ],
+            vec![
+                get_captured_payment_row("pay_1", "stripe", get_time(1, 9)),
+                get_captured_payment_row("pay_2", "adyen", get_time(1, 8)),
+                get_captured_payment_row("pay_3", "stripe", get_time(2, 11)),
+            ],
+            &get_amounts_captured(&[("pay_1", 10000), ("pay_2", 12000), ("pay_3", 8000)]),
+        );
+
+        let rates: Vec<_> = refund_rates
+            .iter()
+            .map(|rate| {
+                (
+                    rate.connector.as_deref().unwrap(),
+                    rate.day,
+                    rate.refunded_amount,
+                    rate.captured_amount,
+                    rate.refund_rate,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rates,
+            vec![
+                ("adyen", get_time(1, 0), 3000, 12000, Some(0.25)),
+                ("stripe", get_time(1, 0), 500, 10000, Some(0.05)),
+                // Days without refunds have a refund rate of zero
+                ("stripe", get_time(2, 0), 0, 8000, Some(0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_refunds_on_a_day_without_captures_have_no_refund_rate() {
+        let refund_rates = compute_refund_rates(
+            vec![get_refund_row("stripe", 700, get_time(3, 10))],
+            vec![get_captured_payment_row("pay_1", "stripe", get_time(1, 9))],
+            &get_amounts_captured(&[("pay_1", 10000)]),
+        );
+
+        let refund_rate = refund_rates.last().unwrap();
+        assert_eq!(refund_rates.len(), 2);
+        assert_eq!(refund_rate.day, get_time(3, 0));
+        assert_eq!(refund_rate.refunded_amount, 700);
+        assert_eq!(refund_rate.captured_amount, 0);
+        assert_eq!(refund_rate.refund_rate, None);
+    }
+
+    #[test]
+    fn test_captured_amount_adds_up_all_captures_of_a_payment() {
+        // The attempt of pay_1 only holds its latest capture of 2000, while the payment was
+        // captured in parts of 3000 and 2000
+        let refund_rates = compute_refund_rates(
+            vec![get_refund_row("stripe", 1000, get_time(1, 12))],
+            vec![
+                get_captured_payment_row("pay_1", "stripe", get_time(1, 9)),
+                get_captured_payment_row("pay_2", "stripe", get_time(1, 10)),
+            ],
+            &get_amounts_captured(&[("pay_1", 5000), ("pay_2", 5000)]),
+        );
+
+        assert_eq!(refund_rates.len(), 1);
+        let refund_rate = refund_rates.first().unwrap();
+        assert_eq!(refund_rate.captured_amount, 10000);
+        assert_eq!(refund_rate.refund_rate, Some(0.1));
+    }
+}
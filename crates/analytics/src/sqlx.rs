@@ -147,6 +147,7 @@ impl super::payments::metrics::PaymentMetricAnalytics for SqlxClient {}
 impl super::payments::distribution::PaymentDistributionAnalytics for SqlxClient {}
 impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
+impl super::refunds::refund_rate::RefundRateAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
 
@@ -290,6 +291,42 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::refunds::refund_rate::CapturedPaymentRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let payment_id: String = row.try_get("payment_id")?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            payment_id,
+            connector,
+            currency,
+            start_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::refunds::refund_rate::AmountCapturedRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let payment_id: String = row.try_get("payment_id")?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self { payment_id, total })
+    }
+}
+
 impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributionRow {
     fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
         let currency: Option<DBEnumWrapper<Currency>> =
@@ -359,6 +359,40 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The schedule used to retry failed webhook deliveries. If not specified, the default retry
+    /// schedule is used
+    pub retry_schedule: Option<WebhookRetrySchedule>,
+
+    /// The algorithm used to sign outgoing webhooks. Defaults to `hmac_sha512` if not specified
+    pub outgoing_webhook_signature_algorithm: Option<api_enums::OutgoingWebhookSignatureAlgorithm>,
+}
+
+/// The schedule used to retry failed webhook deliveries
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebhookRetrySchedule {
+    /// Retry after each of the specified delays, in order
+    Fixed {
+        /// The delays (in seconds) before each retry attempt
+        #[schema(example = json!([60, 300, 900]))]
+        delays_in_seconds: Vec<u32>,
+    },
+    /// Retry with exponentially increasing delays, capped at `max_delay_in_seconds`
+    Exponential {
+        /// The delay (in seconds) before the first retry attempt
+        #[schema(example = 60)]
+        initial_delay_in_seconds: u32,
+        /// The factor by which the delay is multiplied after each retry attempt
+        #[schema(example = 2)]
+        multiplier: u32,
+        /// The maximum delay (in seconds) between two retry attempts
+        #[schema(example = 3600)]
+        max_delay_in_seconds: u32,
+        /// The maximum number of retry attempts
+        #[schema(example = 10)]
+        max_retries: u32,
+    },
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -478,6 +512,27 @@ pub struct MerchantConnectorCreate {
 
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
+
+    /// Details of the wallets of the merchant that are used by Hyperswitch, like the Apple Pay payment processing certificate
+    pub connector_wallets_details: Option<ConnectorWalletDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorWalletDetails {
+    /// Payment processing certificate with which the Apple Pay payment tokens are decrypted before they are passed to the connector
+    pub apple_pay: Option<ApplePayPaymentProcessingDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApplePayPaymentProcessingDetails {
+    /// Base64 encoded payment processing certificate of the merchant
+    #[schema(value_type = String)]
+    pub payment_processing_certificate: Secret<String>,
+    /// PEM encoded private key of the payment processing certificate
+    #[schema(value_type = String)]
+    pub payment_processing_certificate_key: Secret<String>,
 }
 
 // Different patterns of authentication.
@@ -631,6 +686,10 @@ pub struct MerchantConnectorResponse {
 
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
+
+    /// Details of the wallets of the merchant, with the certificates and keys masked
+    #[schema(value_type = Option<Object>)]
+    pub connector_wallets_details: Option<pii::SecretSerdeValue>,
 }
 
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -709,6 +768,9 @@ pub struct MerchantConnectorUpdate {
 
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
+
+    /// Details of the wallets of the merchant that are used by Hyperswitch, like the Apple Pay payment processing certificate
+    pub connector_wallets_details: Option<ConnectorWalletDetails>,
 }
 
 ///Details of FrmConfigs are mentioned here... it should be passed in payment connector create api call, and stored in merchant_connector_table
@@ -919,6 +981,12 @@ pub struct BusinessProfileCreate {
 
     /// A boolean value to indicate if cusomter shipping details needs to be sent for wallets payments
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+
+    /// Disputes of an amount at or below the threshold of their currency are accepted automatically
+    /// when they are received from the connector. The thresholds are in the lowest denomination of
+    /// each currency, disputes in currencies without a threshold are never accepted automatically.
+    #[schema(value_type = Option<Object>, example = json!({ "USD": 2000, "JPY": 300 }))]
+    pub dispute_auto_accept_threshold: Option<HashMap<api_enums::Currency, u32>>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -991,6 +1059,12 @@ pub struct BusinessProfileResponse {
 
     // Whether to use the billing details passed when creating the intent as payment method billing
     pub use_billing_as_payment_method_billing: Option<bool>,
+
+    /// Disputes of an amount at or below the threshold of their currency are accepted automatically
+    /// when they are received from the connector. The thresholds are in the lowest denomination of
+    /// each currency, disputes in currencies without a threshold are never accepted automatically.
+    #[schema(value_type = Option<Object>, example = json!({ "USD": 2000, "JPY": 300 }))]
+    pub dispute_auto_accept_threshold: Option<HashMap<api_enums::Currency, u32>>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1061,6 +1135,12 @@ pub struct BusinessProfileUpdate {
 
     /// A boolean value to indicate if cusomter shipping details needs to be sent for wallets payments
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+
+    /// Disputes of an amount at or below the threshold of their currency are accepted automatically
+    /// when they are received from the connector. The thresholds are in the lowest denomination of
+    /// each currency, disputes in currencies without a threshold are never accepted automatically.
+    #[schema(value_type = Option<Object>, example = json!({ "USD": 2000, "JPY": 300 }))]
+    pub dispute_auto_accept_threshold: Option<HashMap<api_enums::Currency, u32>>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
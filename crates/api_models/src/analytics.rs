@@ -126,6 +126,15 @@ pub struct GetRefundMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRefundRateRequest {
+    pub time_range: TimeRange,
+    /// Restricts the refund rates to these connectors, all connectors are included if empty
+    #[serde(default)]
+    pub connectors: Vec<String>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetSdkEventMetricRequest {
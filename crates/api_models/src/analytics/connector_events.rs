@@ -4,3 +4,23 @@ pub struct ConnectorEventsRequest {
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorLatencyPercentilesRequest {
+    pub time_range: super::TimeRange,
+    /// Restricts the percentiles to these connectors, all connectors are included if empty
+    #[serde(default)]
+    pub connectors: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct ConnectorLatencyPercentiles {
+    pub connector_name: Option<String>,
+    pub flow: String,
+    pub request_count: u64,
+    /// Latencies of the connector calls, in milliseconds
+    pub p50_latency: f64,
+    pub p95_latency: f64,
+    pub p99_latency: f64,
+}
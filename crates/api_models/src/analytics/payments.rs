@@ -77,6 +77,7 @@ pub enum PaymentMetrics {
     AvgTicketSize,
     RetriesCount,
     ConnectorSuccessRate,
+    PaymentMethodSuccessRate,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -205,6 +206,8 @@ pub struct PaymentMetricsBucketValue {
     pub retries_count: Option<u64>,
     pub retries_amount_processed: Option<u64>,
     pub connector_success_rate: Option<f64>,
+    /// Percentage of attempts that were authorized, out of all attempts of the payment method type
+    pub payment_method_success_rate: Option<f64>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -174,3 +174,16 @@ pub struct RefundMetricsBucketResponse {
     #[serde(flatten)]
     pub dimensions: RefundMetricsBucketIdentifier,
 }
+
+/// Ratio of the refunded amount to the captured amount of a connector on a day
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct RefundRateMetric {
+    pub connector: Option<String>,
+    pub currency: Option<Currency>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub day: time::PrimitiveDateTime,
+    pub refunded_amount: u64,
+    pub captured_amount: u64,
+    /// Not present when nothing was captured on the day
+    pub refund_rate: Option<f64>,
+}
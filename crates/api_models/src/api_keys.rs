@@ -23,6 +23,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The operations that the API Key is allowed to perform. The API Key has full access to the
+    /// merchant account if this is not provided.
+    #[schema(value_type = Option<Vec<ApiKeyPermission>>, example = json!(["payments_read"]))]
+    pub permissions: Option<Vec<common_enums::ApiKeyPermission>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +65,11 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The operations that the API Key is allowed to perform. The API Key has full access to the
+    /// merchant account if this is not set.
+    #[schema(value_type = Option<Vec<ApiKeyPermission>>, example = json!(["payments_read"]))]
+    pub permissions: Option<Vec<common_enums::ApiKeyPermission>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +112,11 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The operations that the API Key is allowed to perform. The API Key has full access to the
+    /// merchant account if this is not set.
+    #[schema(value_type = Option<Vec<ApiKeyPermission>>, example = json!(["payments_read"]))]
+    pub permissions: Option<Vec<common_enums::ApiKeyPermission>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -137,6 +152,38 @@ pub struct UpdateApiKeyRequest {
     pub merchant_id: String,
 }
 
+/// The request body for rotating an API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// The number of seconds for which the rotated API Key continues to be accepted after the
+    /// rotation, to allow switching over to the new API Key. Defaults to 24 hours, and can be at
+    /// most 30 days.
+    #[schema(example = 86400)]
+    pub grace_period_in_secs: Option<u32>,
+
+    /// An expiration date for the newly issued API Key. The new API Key never expires if this is
+    /// not provided.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: Option<ApiKeyExpiration>,
+
+    #[serde(skip_deserializing)]
+    pub key_id: String,
+
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+}
+
+/// The response body for rotating an API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RotateApiKeyResponse {
+    /// The newly issued API Key, which replaces the rotated API Key.
+    pub new_api_key: CreateApiKeyResponse,
+
+    /// The rotated API Key, which expires at the end of the grace period.
+    pub old_api_key: RetrieveApiKeyResponse,
+}
+
 /// The response body for revoking an API Key.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct RevokeApiKeyResponse {
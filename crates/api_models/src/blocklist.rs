@@ -3,7 +3,7 @@ use common_utils::events::ApiEventMetric;
 use masking::StrongSecret;
 use utoipa::ToSchema;
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "data")]
 pub enum BlocklistRequest {
     CardBin(String),
@@ -20,7 +20,17 @@ pub struct GenerateFingerprintRequest {
 pub struct Card {
     pub card_number: StrongSecret<String>,
 }
-pub type AddToBlocklistRequest = BlocklistRequest;
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    #[serde(flatten)]
+    pub entry: BlocklistRequest,
+    /// Time after which the entry no longer blocks payments and is removed from the blocklist. The
+    /// entry is permanent if not provided.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -30,6 +40,10 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    /// Time after which the entry no longer blocks payments, if the entry is temporary
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -45,6 +59,41 @@ pub struct ToggleBlocklistResponse {
 pub type AddToBlocklistResponse = BlocklistResponse;
 pub type DeleteFromBlocklistResponse = BlocklistResponse;
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkBlocklistRequest {
+    /// The entries to be added to or removed from the blocklist
+    pub entries: Vec<BlocklistRequest>,
+}
+
+pub type BulkAddToBlocklistRequest = BulkBlocklistRequest;
+pub type BulkDeleteFromBlocklistRequest = BulkBlocklistRequest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkBlocklistEntryStatus {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkBlocklistEntryResult {
+    /// The entry of the request this result corresponds to
+    pub entry: BlocklistRequest,
+    pub status: BulkBlocklistEntryStatus,
+    /// The blocklist record that was added or removed, if the entry succeeded
+    pub blocklist_entry: Option<BlocklistResponse>,
+    /// The error code, if the entry failed
+    pub error_code: Option<String>,
+    /// The error message, if the entry failed
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkBlocklistResponse {
+    /// The outcome of each entry of the request, in the order they were provided
+    pub entries: Vec<BulkBlocklistEntryResult>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ListBlocklistQuery {
     #[schema(value_type = BlocklistDataKind)]
@@ -66,7 +115,10 @@ pub struct ToggleBlocklistQuery {
 }
 
 impl ApiEventMetric for BlocklistRequest {}
+impl ApiEventMetric for AddToBlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
+impl ApiEventMetric for BulkBlocklistRequest {}
+impl ApiEventMetric for BulkBlocklistResponse {}
 impl ApiEventMetric for ToggleBlocklistResponse {}
 impl ApiEventMetric for ListBlocklistQuery {}
 impl ApiEventMetric for GenerateFingerprintRequest {}
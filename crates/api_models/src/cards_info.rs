@@ -14,6 +14,44 @@ pub struct CardsInfoRequest {
     pub card_iin: String,
 }
 
+#[derive(serde::Deserialize, Debug, serde::Serialize)]
+pub struct CardBinLookupRequest {
+    pub card_bin: String,
+}
+
+#[derive(serde::Deserialize, Debug, serde::Serialize)]
+pub struct CardInfoCreateRequest {
+    pub card_iin: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bank_code_id: Option<String>,
+    pub bank_code: Option<String>,
+    pub country_code: Option<String>,
+    pub last_updated_provider: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug, serde::Serialize)]
+pub struct CardInfoImportRequest {
+    /// Rows of card information, each of which is expected to be a `CardInfoCreateRequest`.
+    /// Rows are parsed individually, so that a malformed row does not fail the whole import.
+    pub card_info: Vec<serde_json::Value>,
+}
+
+#[derive(serde::Serialize, Debug, Default, ToSchema)]
+pub struct CardInfoImportResponse {
+    /// Number of bins for which card information was inserted
+    pub inserted: usize,
+    /// Number of bins for which card information was updated
+    pub updated: usize,
+    /// Number of rows that were malformed or failed to be stored
+    pub failed: usize,
+    /// Number of rows skipped as a later row in the request had the same bin
+    pub duplicates: usize,
+}
+
 #[derive(serde::Serialize, Debug, ToSchema)]
 pub struct CardInfoResponse {
     #[schema(example = "374431")]
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common_utils::events;
 use euclid::{
     dssa::types::EuclidAnalysable,
@@ -44,7 +46,7 @@ impl EuclidAnalysable for AuthenticationType {
 
         vec![(
             self.to_dir_value(),
-            std::collections::HashMap::from_iter([(
+            HashMap::from_iter([(
                 "AUTHENTICATION_TYPE".to_string(),
                 serde_json::json!({
                     "rule_name":rule_name,
@@ -73,10 +75,66 @@ impl EuclidDirFilter for ConditionalConfigs {
     ];
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<DayOfWeek> for time::Weekday {
+    fn from(day: DayOfWeek) -> Self {
+        match day {
+            DayOfWeek::Monday => Self::Monday,
+            DayOfWeek::Tuesday => Self::Tuesday,
+            DayOfWeek::Wednesday => Self::Wednesday,
+            DayOfWeek::Thursday => Self::Thursday,
+            DayOfWeek::Friday => Self::Friday,
+            DayOfWeek::Saturday => Self::Saturday,
+            DayOfWeek::Sunday => Self::Sunday,
+        }
+    }
+}
+
+/// Prefix of the `metadata` keys under which time windows are exposed to the rules, keeping them
+/// apart from the metadata set by the merchant
+pub const TIME_WINDOW_METADATA_KEY_PREFIX: &str = "time_window.";
+
+/// A recurring window of time, such as off-peak hours, during which rules of the config may apply.
+/// Rules match on a window through the `metadata` key, with `time_window.<name>` as the key and
+/// `true` as the value when the request is made within the window, `false` otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimeWindow {
+    /// Time of day at which the window starts, as `HH:MM`
+    pub start_time: String,
+    /// Time of day at which the window ends (exclusive), as `HH:MM`. A window ending at or before
+    /// its start time crosses midnight.
+    pub end_time: String,
+    /// Days of the week on which the window starts, every day if not set
+    pub days: Option<Vec<DayOfWeek>>,
+    /// Offset from UTC of the timezone in which the window is defined, as `+05:30`. Defaults to UTC.
+    pub utc_offset: Option<String>,
+}
+
+impl TimeWindow {
+    /// Key of the window named `name` in the `metadata` the rules are evaluated against
+    pub fn metadata_key(name: &str) -> String {
+        format!("{TIME_WINDOW_METADATA_KEY_PREFIX}{name}")
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DecisionManagerRecord {
     pub name: String,
     pub program: Program<ConditionalConfigs>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub time_windows: HashMap<String, TimeWindow>,
     pub created_at: i64,
     pub modified_at: i64,
 }
@@ -90,12 +148,14 @@ impl events::ApiEventMetric for DecisionManagerRecord {
 pub struct ConditionalConfigReq {
     pub name: Option<String>,
     pub algorithm: Option<Program<ConditionalConfigs>>,
+    pub time_windows: Option<HashMap<String, TimeWindow>>,
 }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 
 pub struct DecisionManagerRequest {
     pub name: Option<String>,
     pub program: Option<Program<ConditionalConfigs>>,
+    pub time_windows: Option<HashMap<String, TimeWindow>>,
 }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
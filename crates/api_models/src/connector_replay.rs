@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// A connector request and response pair recorded for a payment attempt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorReplayRecord {
+    /// The connector the request was sent to
+    pub connector_name: String,
+    /// The flow in which the request was sent, e.g. `Authorize`
+    pub flow: String,
+    /// The url the request was sent to
+    pub url: String,
+    /// The http method of the request
+    pub method: String,
+    /// The request body, with sensitive fields masked
+    pub masked_request: String,
+    /// The response body, with sensitive fields masked
+    pub masked_response: Option<String>,
+    /// The error received from the connector or raised while handling the response
+    pub error: Option<String>,
+    /// The http status code of the response
+    pub status_code: u16,
+    /// The identifier of the API request in which the connector request was sent
+    pub request_id: String,
+    /// Time at which the request was sent, in milliseconds since the unix epoch
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorReplayRetrieveRequest {
+    /// The merchant the payment attempt belongs to
+    pub merchant_id: String,
+    /// The payment attempt for which the connector requests were recorded
+    pub attempt_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorReplayResponse {
+    /// The merchant the payment attempt belongs to
+    pub merchant_id: String,
+    /// The payment attempt for which the connector requests were recorded
+    pub attempt_id: String,
+    /// The recorded connector requests and responses, oldest first
+    pub records: Vec<ConnectorReplayRecord>,
+}
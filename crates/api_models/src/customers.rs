@@ -1,5 +1,5 @@
-use common_utils::{consts, crypto, custom_serde, pii};
-use masking::Secret;
+use common_utils::{consts, crypto, custom_serde, pii, validation};
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -41,6 +41,136 @@ pub struct CustomerRequest {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+/// Maximum number of keys in the metadata of a customer
+const CUSTOMER_METADATA_MAX_KEYS: usize = 50;
+/// Maximum length of a key in the metadata of a customer
+const CUSTOMER_METADATA_MAX_KEY_LENGTH: usize = 40;
+/// Maximum length of a value in the metadata of a customer
+const CUSTOMER_METADATA_MAX_VALUE_LENGTH: usize = 500;
+
+/// A field of a [`CustomerRequest`] which failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomerRequestValidationError {
+    InvalidEmail,
+    InvalidPhoneNumber,
+    InvalidPhoneCountryCode,
+    MetadataNotAnObject,
+    MetadataTooManyKeys,
+    MetadataKeyTooLong,
+    MetadataValueTooLong,
+}
+
+impl CustomerRequestValidationError {
+    pub fn field_name(&self) -> &'static str {
+        match self {
+            Self::InvalidEmail => "email",
+            Self::InvalidPhoneNumber => "phone",
+            Self::InvalidPhoneCountryCode => "phone_country_code",
+            Self::MetadataNotAnObject
+            | Self::MetadataTooManyKeys
+            | Self::MetadataKeyTooLong
+            | Self::MetadataValueTooLong => "metadata",
+        }
+    }
+
+    pub fn expected_format(&self) -> String {
+        match self {
+            Self::InvalidEmail => "a valid email address, such as `JonTest@test.com`".to_string(),
+            Self::InvalidPhoneNumber => {
+                "4 to 15 digits, optionally prefixed with `+` and separated by spaces, hyphens, dots or parentheses".to_string()
+            }
+            Self::InvalidPhoneCountryCode => {
+                "`+` followed by 1 to 3 digits, such as `+65`".to_string()
+            }
+            Self::MetadataNotAnObject => "an object".to_string(),
+            Self::MetadataTooManyKeys => {
+                format!("an object with at most {CUSTOMER_METADATA_MAX_KEYS} keys")
+            }
+            Self::MetadataKeyTooLong => {
+                format!("keys of at most {CUSTOMER_METADATA_MAX_KEY_LENGTH} characters")
+            }
+            Self::MetadataValueTooLong => {
+                format!("values of at most {CUSTOMER_METADATA_MAX_VALUE_LENGTH} characters")
+            }
+        }
+    }
+}
+
+/// Removes the formatting commonly used in phone numbers, such as in `+1 (555) 123-4567`
+fn normalize_phone_number(phone: &str) -> String {
+    let phone = phone.trim();
+    phone
+        .strip_prefix('+')
+        .unwrap_or(phone)
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-' | '.' | '(' | ')'))
+        .collect()
+}
+
+impl CustomerRequest {
+    /// Validates the format of the contact details and the size of the metadata of the customer.
+    /// Only the fields present in the request are validated, so that a partial update is not
+    /// rejected for the fields it does not change.
+    pub fn validate(&self) -> Result<(), CustomerRequestValidationError> {
+        if let Some(email) = &self.email {
+            validation::validate_email(email.peek())
+                .map_err(|_| CustomerRequestValidationError::InvalidEmail)?;
+        }
+
+        if let Some(phone) = self
+            .phone
+            .as_ref()
+            .map(|phone| phone.peek().trim())
+            .filter(|phone| !phone.is_empty())
+        {
+            let digits = normalize_phone_number(phone);
+            if !(4..=15).contains(&digits.len())
+                || !digits.chars().all(|character| character.is_ascii_digit())
+            {
+                return Err(CustomerRequestValidationError::InvalidPhoneNumber);
+            }
+        }
+
+        if let Some(phone_country_code) = self
+            .phone_country_code
+            .as_deref()
+            .map(str::trim)
+            .filter(|phone_country_code| !phone_country_code.is_empty())
+        {
+            match phone_country_code.strip_prefix('+') {
+                Some(code)
+                    if (1..=3).contains(&code.len())
+                        && code.chars().all(|character| character.is_ascii_digit()) => {}
+                _ => return Err(CustomerRequestValidationError::InvalidPhoneCountryCode),
+            }
+        }
+
+        if let Some(metadata) = &self.metadata {
+            let metadata = metadata
+                .peek()
+                .as_object()
+                .ok_or(CustomerRequestValidationError::MetadataNotAnObject)?;
+            if metadata.len() > CUSTOMER_METADATA_MAX_KEYS {
+                return Err(CustomerRequestValidationError::MetadataTooManyKeys);
+            }
+            for (key, value) in metadata {
+                if key.chars().count() > CUSTOMER_METADATA_MAX_KEY_LENGTH {
+                    return Err(CustomerRequestValidationError::MetadataKeyTooLong);
+                }
+                let value_length = match value {
+                    serde_json::Value::String(value) => value.chars().count(),
+                    value => value.to_string().chars().count(),
+                };
+                if value_length > CUSTOMER_METADATA_MAX_VALUE_LENGTH {
+                    return Err(CustomerRequestValidationError::MetadataValueTooLong);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct CustomerResponse {
     /// The identifier for the customer object. If not provided the customer ID will be autogenerated.
@@ -99,6 +229,73 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// An address to be saved for the customer
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerAddressRequest {
+    /// The identifier for the customer object
+    #[serde(skip)]
+    pub customer_id: String,
+    /// The address details
+    pub address: Option<payments::AddressDetails>,
+    /// The phone number associated with the address
+    pub phone: Option<payments::PhoneDetails>,
+    /// The email associated with the address
+    #[schema(value_type = Option<String>, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+}
+
+/// An address saved for the customer, which can be referenced by its `address_id` in payments
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressResponse {
+    /// The identifier for the address, used to reference it in payments
+    #[schema(max_length = 64, example = "add_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub address_id: String,
+    /// The identifier for the customer object
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The address details
+    pub address: Option<payments::AddressDetails>,
+    /// The phone number associated with the address
+    pub phone: Option<payments::PhoneDetails>,
+    /// The email associated with the address
+    #[schema(value_type = Option<String>, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+    /// A timestamp (ISO 8601 code) that determines when the address was saved
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerAddressId {
+    pub customer_id: String,
+    pub address_id: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerAddressDeleteResponse {
+    /// The identifier for the customer object
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifier for the address
+    #[schema(max_length = 64, example = "add_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub address_id: String,
+    /// Whether the address was deleted or not
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+impl From<CustomerAddressRequest> for payments::Address {
+    fn from(request: CustomerAddressRequest) -> Self {
+        Self {
+            address: request.address,
+            phone: request.phone,
+            email: request.email,
+        }
+    }
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
@@ -106,3 +303,144 @@ pub fn generate_customer_id() -> String {
 fn unknown_merchant() -> String {
     String::from("merchant_unknown")
 }
+
+#[cfg(test)]
+mod customer_request_validation_tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn get_customer_request() -> CustomerRequest {
+        CustomerRequest {
+            email: Some(pii::Email::from_str("JonTest@test.com").unwrap()),
+            phone: Some(Secret::new("9999999999".to_string())),
+            phone_country_code: Some("+65".to_string()),
+            metadata: Some(Secret::new(
+                serde_json::json!({ "city": "NY", "unit": "245" }),
+            )),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_valid_customer_request() {
+        assert_eq!(get_customer_request().validate(), Ok(()));
+        assert_eq!(CustomerRequest::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_email() {
+        // Emails are validated when deserialized, but may be constructed from unvalidated values
+        let mut email = pii::Email::from_str("JonTest@test.com").unwrap();
+        *email = Secret::new("JonTest".to_string());
+        let request = CustomerRequest {
+            email: Some(email),
+            ..get_customer_request()
+        };
+
+        let error = request.validate().unwrap_err();
+        assert_eq!(error, CustomerRequestValidationError::InvalidEmail);
+        assert_eq!(error.field_name(), "email");
+    }
+
+    #[test]
+    fn test_invalid_phone_number() {
+        for phone in ["99999x9999", "999", "9999999999999999", "+1 (555) 123-45x7"] {
+            let request = CustomerRequest {
+                phone: Some(Secret::new(phone.to_string())),
+                ..get_customer_request()
+            };
+
+            let error = request.validate().unwrap_err();
+            assert_eq!(error, CustomerRequestValidationError::InvalidPhoneNumber);
+            assert_eq!(error.field_name(), "phone");
+        }
+    }
+
+    #[test]
+    fn test_formatted_phone_number() {
+        for phone in [
+            "+1 555 123 4567",
+            "(555) 123-4567",
+            "555.123.4567",
+            " 99999 99999 ",
+        ] {
+            let request = CustomerRequest {
+                phone: Some(Secret::new(phone.to_string())),
+                ..get_customer_request()
+            };
+
+            assert_eq!(request.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_partial_update_request() {
+        // An update request only carries the fields being changed
+        let request = CustomerRequest {
+            name: Some(Secret::new("Jon Test".to_string())),
+            phone: Some(Secret::new(String::new())),
+            ..Default::default()
+        };
+        assert_eq!(request.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_phone_country_code() {
+        for phone_country_code in ["65", "+", "+6500", "+6a"] {
+            let request = CustomerRequest {
+                phone_country_code: Some(phone_country_code.to_string()),
+                ..get_customer_request()
+            };
+
+            let error = request.validate().unwrap_err();
+            assert_eq!(
+                error,
+                CustomerRequestValidationError::InvalidPhoneCountryCode
+            );
+            assert_eq!(error.field_name(), "phone_country_code");
+        }
+    }
+
+    #[test]
+    fn test_invalid_metadata() {
+        let too_many_keys: serde_json::Map<_, _> = (0..51)
+            .map(|index| (format!("key_{index}"), serde_json::json!(index)))
+            .collect();
+
+        for (metadata, expected_error) in [
+            (
+                serde_json::json!(["NY"]),
+                CustomerRequestValidationError::MetadataNotAnObject,
+            ),
+            (
+                serde_json::Value::Object(too_many_keys),
+                CustomerRequestValidationError::MetadataTooManyKeys,
+            ),
+            (
+                serde_json::Value::Object(serde_json::Map::from_iter([(
+                    "k".repeat(41),
+                    serde_json::json!("NY"),
+                )])),
+                CustomerRequestValidationError::MetadataKeyTooLong,
+            ),
+            (
+                serde_json::Value::Object(serde_json::Map::from_iter([(
+                    "city".to_string(),
+                    serde_json::json!("N".repeat(501)),
+                )])),
+                CustomerRequestValidationError::MetadataValueTooLong,
+            ),
+        ] {
+            let request = CustomerRequest {
+                metadata: Some(Secret::new(metadata)),
+                ..get_customer_request()
+            };
+
+            let error = request.validate().unwrap_err();
+            assert_eq!(error, expected_error);
+            assert_eq!(error.field_name(), "metadata");
+        }
+    }
+}
@@ -197,6 +197,134 @@ pub struct SubmitEvidenceRequest {
     pub uncategorized_text: Option<String>,
 }
 
+/// Evidence for a dispute grouped by what it proves. Files are referenced by the ids returned when
+/// they are uploaded through the files API.
+#[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitEvidenceBundleRequest {
+    /// Dispute Id
+    #[serde(skip_deserializing)]
+    pub dispute_id: String,
+    /// Evidence about the customer and the communication with them
+    pub customer: Option<CustomerEvidence>,
+    /// File Id of receipt
+    pub receipt: Option<String>,
+    /// Product Description
+    pub product_description: Option<String>,
+    /// Evidence of the shipment of the product
+    pub shipping: Option<ShippingEvidence>,
+    /// Evidence of the refund and cancellation policies shown to the customer
+    pub policies: Option<PolicyEvidence>,
+    /// Any other evidence for the dispute
+    pub additional: Option<AdditionalEvidence>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerEvidence {
+    /// Customer name
+    pub name: Option<String>,
+    /// Customer email address
+    pub email_address: Option<String>,
+    /// Billing address of the customer
+    pub billing_address: Option<String>,
+    /// IP address of the customer
+    pub purchase_ip: Option<String>,
+    /// File Id of customer communication
+    pub communication: Option<String>,
+    /// File Id of customer signature
+    pub signature: Option<String>,
+    /// Logs showing the usage of service by customer
+    pub access_activity_log: Option<String>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ShippingEvidence {
+    /// Shipping address of the customer
+    pub address: Option<String>,
+    /// Delivery service that shipped the product
+    pub carrier: Option<String>,
+    /// Shipping date
+    pub date: Option<String>,
+    /// Tracking number of shipped product
+    pub tracking_number: Option<String>,
+    /// File Id of shipping documentation
+    pub documentation: Option<String>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyEvidence {
+    /// File Id of cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// Details of showing cancellation policy to customer before purchase
+    pub cancellation_policy_disclosure: Option<String>,
+    /// Details telling why customer's subscription was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+    /// File Id of refund policy
+    pub refund_policy: Option<String>,
+    /// Details of showing refund policy to customer before purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// Details why customer is not entitled to refund
+    pub refund_refusal_explanation: Option<String>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AdditionalEvidence {
+    /// Customer service date
+    pub service_date: Option<String>,
+    /// File Id of service documentation
+    pub service_documentation: Option<String>,
+    /// File Id showing two distinct transactions when customer claims a payment was charged twice
+    pub invoice_showing_distinct_transactions: Option<String>,
+    /// File Id of recurring transaction agreement
+    pub recurring_transaction_agreement: Option<String>,
+    /// Any additional supporting file
+    pub uncategorized_file: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
+
+impl From<SubmitEvidenceBundleRequest> for SubmitEvidenceRequest {
+    fn from(bundle: SubmitEvidenceBundleRequest) -> Self {
+        let customer = bundle.customer.unwrap_or_default();
+        let shipping = bundle.shipping.unwrap_or_default();
+        let policies = bundle.policies.unwrap_or_default();
+        let additional = bundle.additional.unwrap_or_default();
+        Self {
+            dispute_id: bundle.dispute_id,
+            access_activity_log: customer.access_activity_log,
+            billing_address: customer.billing_address,
+            cancellation_policy: policies.cancellation_policy,
+            cancellation_policy_disclosure: policies.cancellation_policy_disclosure,
+            cancellation_rebuttal: policies.cancellation_rebuttal,
+            customer_communication: customer.communication,
+            customer_email_address: customer.email_address,
+            customer_name: customer.name,
+            customer_purchase_ip: customer.purchase_ip,
+            customer_signature: customer.signature,
+            product_description: bundle.product_description,
+            receipt: bundle.receipt,
+            refund_policy: policies.refund_policy,
+            refund_policy_disclosure: policies.refund_policy_disclosure,
+            refund_refusal_explanation: policies.refund_refusal_explanation,
+            service_date: additional.service_date,
+            service_documentation: additional.service_documentation,
+            shipping_address: shipping.address,
+            shipping_carrier: shipping.carrier,
+            shipping_date: shipping.date,
+            shipping_documentation: shipping.documentation,
+            shipping_tracking_number: shipping.tracking_number,
+            invoice_showing_distinct_transactions: additional.invoice_showing_distinct_transactions,
+            recurring_transaction_agreement: additional.recurring_transaction_agreement,
+            uncategorized_file: additional.uncategorized_file,
+            uncategorized_text: additional.uncategorized_text,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteEvidenceRequest {
     /// Id of the dispute
@@ -22,14 +22,21 @@ use common_utils::{
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
+        api_event::*,
+        auth_events::*,
+        connector_events::{ConnectorEventsRequest, ConnectorLatencyPercentilesRequest},
+        outgoing_webhook_event::OutgoingWebhookLogsRequest,
+        sdk_events::*,
+        search::*,
+        *,
     },
     api_keys::*,
     cards_info::*,
+    connector_replay::*,
     disputes::*,
     files::*,
     mandates::*,
+    organization::*,
     payment_methods::*,
     payments::*,
     verifications::*,
@@ -44,7 +51,10 @@ impl_misc_api_event_type!(
     PaymentLinkInitiateRequest,
     RetrievePaymentLinkResponse,
     MandateListConstraints,
+    ConnectorReplayRetrieveRequest,
+    ConnectorReplayResponse,
     CreateFileResponse,
+    FileDownloadResponse,
     MerchantConnectorResponse,
     MerchantConnectorId,
     MandateResponse,
@@ -70,6 +80,9 @@ impl_misc_api_event_type!(
     MerchantConnectorCreate,
     MerchantId,
     CardsInfoRequest,
+    CardBinLookupRequest,
+    CardInfoImportRequest,
+    CardInfoImportResponse,
     MerchantAccountResponse,
     MerchantAccountListRequest,
     MerchantAccountCreate,
@@ -77,12 +90,19 @@ impl_misc_api_event_type!(
     ApplepayMerchantVerificationRequest,
     ApplepayMerchantResponse,
     ApplepayVerifiedDomainsResponse,
+    ApplepayReverificationRequest,
     UpdateApiKeyRequest,
+    OrganizationId,
+    OrganizationParentUpdateRequest,
+    OrganizationResponse,
+    RotateApiKeyRequest,
+    RotateApiKeyResponse,
     GetApiEventFiltersRequest,
     ApiEventFiltersResponse,
     GetInfoResponse,
     GetPaymentMetricRequest,
     GetRefundMetricRequest,
+    GetRefundRateRequest,
     GetSdkEventMetricRequest,
     GetAuthEventMetricRequest,
     GetPaymentFiltersRequest,
@@ -96,6 +116,7 @@ impl_misc_api_event_type!(
     SdkEventsRequest,
     ReportRequest,
     ConnectorEventsRequest,
+    ConnectorLatencyPercentilesRequest,
     OutgoingWebhookLogsRequest,
     GetGlobalSearchRequest,
     GetSearchRequest,
@@ -1,6 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest, CustomerResponse};
+use crate::customers::{
+    CustomerAddressDeleteResponse, CustomerAddressId, CustomerAddressRequest,
+    CustomerAddressResponse, CustomerDeleteResponse, CustomerId, CustomerRequest, CustomerResponse,
+};
 
 impl ApiEventMetric for CustomerDeleteResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -33,3 +36,35 @@ impl ApiEventMetric for CustomerId {
         })
     }
 }
+
+impl ApiEventMetric for CustomerAddressRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerAddressResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerAddressId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerAddressDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
+    SubmitEvidenceBundleRequest, SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -11,6 +12,13 @@ impl ApiEventMetric for SubmitEvidenceRequest {
         })
     }
 }
+impl ApiEventMetric for SubmitEvidenceBundleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
 impl ApiEventMetric for DisputeResponsePaymentsRetrieve {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Dispute {
@@ -20,6 +20,12 @@ impl ApiEventMetric for gsm::GsmRetrieveRequest {
     }
 }
 
+impl ApiEventMetric for gsm::GsmLookupRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
 impl ApiEventMetric for gsm::GsmDeleteRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Gsm)
@@ -3,14 +3,17 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use crate::{
     payment_methods::{
         CustomerDefaultPaymentMethodResponse, CustomerPaymentMethodsListResponse,
-        DefaultPaymentMethod, ListCountriesCurrenciesRequest, ListCountriesCurrenciesResponse,
-        PaymentMethodDeleteResponse, PaymentMethodListRequest, PaymentMethodListResponse,
-        PaymentMethodResponse, PaymentMethodUpdate,
+        CustomerPaymentMethodsProbeResponse, CustomerPaymentMethodsReorderRequest,
+        CustomerPaymentMethodsReorderResponse, DefaultPaymentMethod,
+        ListCountriesCurrenciesRequest, ListCountriesCurrenciesResponse,
+        PaymentMethodDeleteResponse, PaymentMethodImportRequest, PaymentMethodListRequest,
+        PaymentMethodListResponse, PaymentMethodResponse, PaymentMethodUpdate,
     },
     payments::{
         ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
         PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
-        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelRequest,
+        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest,
+        PaymentsBulkRejectRequest, PaymentsBulkRejectResponse, PaymentsCancelRequest,
         PaymentsCaptureRequest, PaymentsExternalAuthenticationRequest,
         PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
         PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsRetrieveRequest,
@@ -60,6 +63,18 @@ impl ApiEventMetric for PaymentsApproveRequest {
     }
 }
 
+impl ApiEventMetric for PaymentsBulkRejectRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PaymentsBulkRejectResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for PaymentsRejectRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payment {
@@ -121,6 +136,20 @@ impl ApiEventMetric for PaymentMethodDeleteResponse {
 
 impl ApiEventMetric for CustomerPaymentMethodsListResponse {}
 
+impl ApiEventMetric for CustomerPaymentMethodsReorderRequest {}
+
+impl ApiEventMetric for CustomerPaymentMethodsReorderResponse {}
+
+impl ApiEventMetric for CustomerPaymentMethodsProbeResponse {}
+
+impl ApiEventMetric for PaymentMethodImportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentMethodListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethodList {
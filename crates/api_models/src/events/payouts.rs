@@ -1,8 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutListConstraints,
-    PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse, PayoutRetrieveRequest,
+    PayoutActionRequest, PayoutBatchCreateRequest, PayoutBatchCreateResponse, PayoutCreateRequest,
+    PayoutCreateResponse, PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters,
+    PayoutListResponse, PayoutRetrieveRequest,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -52,3 +53,15 @@ impl ApiEventMetric for PayoutListFilters {
         Some(ApiEventsType::Payout)
     }
 }
+
+impl ApiEventMetric for PayoutBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchCreateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout)
+    }
+}
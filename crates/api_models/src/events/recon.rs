@@ -1,6 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::recon::{ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest};
+use crate::recon::{
+    ReconDifferenceReportRequest, ReconDifferenceReportResponse, ReconEntryResolveRequest,
+    ReconEntryResponse, ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest,
+};
 
 impl ApiEventMetric for ReconUpdateMerchantRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -19,3 +22,27 @@ impl ApiEventMetric for ReconStatusResponse {
         Some(ApiEventsType::Recon)
     }
 }
+
+impl ApiEventMetric for ReconDifferenceReportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for ReconDifferenceReportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for ReconEntryResolveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for ReconEntryResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
@@ -1,8 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundListFilters, RefundListMetaData, RefundListRequest, RefundListResponse, RefundRequest,
-    RefundResponse, RefundUpdateRequest, RefundsRetrieveRequest,
+    RefundApprovalRequest, RefundEligibilityRequest, RefundEligibilityResponse, RefundListFilters,
+    RefundListMetaData, RefundListRequest, RefundListResponse, RefundRequest, RefundResponse,
+    RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 impl ApiEventMetric for RefundRequest {
@@ -44,6 +45,25 @@ impl ApiEventMetric for RefundUpdateRequest {
     }
 }
 
+impl ApiEventMetric for RefundApprovalRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.refund_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RefundEligibilityRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RefundEligibilityResponse {}
+
 impl ApiEventMetric for RefundListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
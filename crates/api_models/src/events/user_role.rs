@@ -6,7 +6,7 @@ use crate::user_role::{
         RoleInfoResponse, RoleInfoWithGroupsResponse, RoleInfoWithPermissionsResponse,
         UpdateRoleRequest,
     },
-    AcceptInvitationRequest, AuthorizationInfoResponse, DeleteUserRoleRequest,
+    AcceptInvitationRequest, AuthorizationInfoResponse, DeleteUserRoleRequest, ForceSignoutRequest,
     MerchantSelectRequest, TransferOrgOwnershipRequest, UpdateUserRoleRequest,
 };
 
@@ -18,6 +18,7 @@ common_utils::impl_misc_api_event_type!(
     MerchantSelectRequest,
     AcceptInvitationRequest,
     DeleteUserRoleRequest,
+    ForceSignoutRequest,
     TransferOrgOwnershipRequest,
     CreateRoleRequest,
     UpdateRoleRequest,
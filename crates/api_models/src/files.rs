@@ -6,6 +6,22 @@ pub struct CreateFileResponse {
     pub file_id: String,
 }
 
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct FileDownloadParams {
+    /// Duration in seconds for which the download URL is valid
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct FileDownloadResponse {
+    /// ID of the file
+    pub file_id: String,
+    /// Presigned URL to download the file directly from the file storage
+    pub url: String,
+    /// Duration in seconds for which the URL is valid
+    pub expires_in: u32,
+}
+
 #[derive(Debug, serde::Serialize, ToSchema, Clone)]
 pub struct FileMetadataResponse {
     /// ID of the file created
@@ -26,6 +26,10 @@ pub struct GsmCreateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// priority of the rule when more than one rule matches a connector response, the rule with
+    /// the highest priority is picked
+    #[serde(default)]
+    pub priority: i32,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -42,6 +46,18 @@ pub struct GsmRetrieveRequest {
     pub message: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmLookupRequest {
+    /// The connector through which payment has gone through
+    pub connector: Connector,
+    /// The flow in which the code and message occurred for a connector
+    pub flow: String,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: String,
+}
+
 #[derive(
     Default,
     Clone,
@@ -64,6 +80,59 @@ pub enum GsmDecision {
     DoDefault,
 }
 
+/// The reason behind the retry decision taken for a connector response
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmRetryReason {
+    /// The matched GSM rule marks the error as retryable
+    RetryableError,
+    /// The matched GSM rule asks for the payment to be requeued
+    RequeueRequested,
+    /// The matched GSM rule marks the error as not retryable
+    NonRetryableError,
+    /// No GSM rule matches the connector response
+    GsmRuleNotFound,
+    /// The decision configured on the matched GSM rule is not recognized
+    InvalidGsmDecision,
+}
+
+/// The action suggested to the retry orchestration for a connector response
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmSuggestedAction {
+    RetrySameConnector,
+    RetryAlternateConnector,
+    DoNotRetry,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GsmRetryMetadata {
+    pub retry_reason: GsmRetryReason,
+    pub suggested_action: GsmSuggestedAction,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GsmUpdateRequest {
     /// The connector through which payment has gone through
@@ -88,6 +157,9 @@ pub struct GsmUpdateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// priority of the rule when more than one rule matches a connector response, the rule with
+    /// the highest priority is picked
+    pub priority: Option<i32>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -141,4 +213,11 @@ pub struct GsmResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// priority of the rule when more than one rule matches a connector response, the rule with
+    /// the highest priority is picked
+    pub priority: i32,
+    /// The reason behind the retry decision for this rule
+    pub retry_reason: GsmRetryReason,
+    /// The action suggested to the retry orchestration for this rule
+    pub suggested_action: GsmSuggestedAction,
 }
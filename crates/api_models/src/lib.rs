@@ -6,6 +6,7 @@ pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
 pub mod connector_onboarding;
+pub mod connector_replay;
 pub mod currency;
 pub mod customers;
 pub mod disputes;
@@ -6,3 +6,27 @@ pub struct MigrateCardResponse {
     pub customers_moved: usize,
     pub cards_moved: usize,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockerMigrationStatus {
+    InProgress,
+    Completed,
+}
+
+/// Progress of the card migration of a merchant
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LockerMigrationStatusResponse {
+    pub merchant_id: String,
+    pub status: LockerMigrationStatus,
+    /// Number of customers of the merchant whose cards are being migrated
+    pub total: usize,
+    /// Number of customers whose cards have been processed so far
+    pub customers_processed: usize,
+    /// Number of cards migrated so far
+    pub migrated: usize,
+    /// Number of cards which could not be migrated
+    pub failed: usize,
+    /// Last customer whose cards were processed, the migration resumes after this customer
+    pub cursor: Option<String>,
+}
@@ -43,6 +43,18 @@ pub struct MandateResponse {
     /// Details about the customer’s acceptance
     #[schema(value_type = Option<CustomerAcceptance>)]
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
+    /// The maximum total amount that can be debited with the mandate
+    #[schema(example = 100000)]
+    pub max_total_amount: Option<i64>,
+    /// The maximum number of debits that can be made with the mandate
+    #[schema(example = 12)]
+    pub max_debit_count: Option<i32>,
+    /// The number of debits made with the mandate so far
+    #[schema(example = 1)]
+    pub debit_count: i32,
+    /// The total amount debited with the mandate so far
+    #[schema(example = 6540)]
+    pub debited_amount: i64,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone)]
@@ -82,6 +94,18 @@ pub struct MandateCardDetails {
     pub nick_name: Option<Secret<String>>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMandateListConstraints {
+    /// status of the mandate, mandates in all statuses are returned if not provided
+    #[schema(value_type = Option<MandateStatus>)]
+    pub status: Option<api_enums::MandateStatus>,
+    /// limit on the number of objects to return
+    pub limit: Option<u32>,
+    /// offset on the number of objects to return
+    pub offset: Option<u32>,
+}
+
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct MandateListConstraints {
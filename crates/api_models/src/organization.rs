@@ -1,3 +1,5 @@
+use utoipa::ToSchema;
+
 pub struct OrganizationNew {
     pub org_id: String,
     pub org_name: Option<String>,
@@ -11,3 +13,36 @@ impl OrganizationNew {
         }
     }
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct OrganizationId {
+    pub org_id: String,
+}
+
+/// The request body for setting the parent of an organization.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrganizationParentUpdateRequest {
+    #[serde(skip_deserializing)]
+    pub org_id: String,
+
+    /// The identifier of the organization to set as the parent of this organization. Set to
+    /// `null` to detach the organization from its parent.
+    #[schema(max_length = 32, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub parent_organization_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    /// The identifier for the organization.
+    #[schema(max_length = 32, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub org_id: String,
+
+    /// The name of the organization.
+    #[schema(example = "Acme Corporation")]
+    pub org_name: Option<String>,
+
+    /// The identifier of the parent organization, if this organization is a sub-organization.
+    #[schema(max_length = 32, example = "org_abs9uSGAYbjEwqs0mJwnz")]
+    pub parent_organization_id: Option<String>,
+}
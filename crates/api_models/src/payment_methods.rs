@@ -495,6 +495,13 @@ pub enum SurchargeResponse {
     Fixed(i64),
     /// Surcharge percentage
     Rate(SurchargePercentage),
+    /// Surcharge percentage along with a fixed surcharge value
+    RatePlusFixed {
+        /// Surcharge percentage
+        percentage: SurchargePercentage,
+        /// Fixed Surcharge value
+        amount: i64,
+    },
 }
 
 impl From<Surcharge> for SurchargeResponse {
@@ -502,6 +509,10 @@ impl From<Surcharge> for SurchargeResponse {
         match value {
             Surcharge::Fixed(amount) => Self::Fixed(amount),
             Surcharge::Rate(percentage) => Self::Rate(percentage.into()),
+            Surcharge::RatePlusFixed { percentage, amount } => Self::RatePlusFixed {
+                percentage: percentage.into(),
+                amount,
+            },
         }
     }
 }
@@ -937,6 +948,10 @@ pub struct CustomerPaymentMethod {
     /// The billing details of the payment method
     #[schema(value_type = Option<Address>)]
     pub billing: Option<payments::Address>,
+
+    /// The position of the payment method in the customer's saved payment methods list, if set by the merchant
+    #[schema(example = 0)]
+    pub display_order: Option<i32>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -954,6 +969,100 @@ pub struct DefaultPaymentMethod {
     pub customer_id: String,
     pub payment_method_id: String,
 }
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct CustomerPaymentMethodsReorderRequest {
+    /// The unique identifier of the customer.
+    #[serde(skip)]
+    pub customer_id: String,
+
+    /// The payment method ids of the customer, in the order they should be displayed. The payment
+    /// methods which are not listed are displayed after them, in their current order
+    #[schema(example = json!(["pm_iouuy468iyuowqs", "pm_kqw7sd9jk23ndfs"]))]
+    pub payment_method_ids: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CustomerPaymentMethodsReorderResponse {
+    /// The unique identifier of the customer.
+    #[schema(example = "cus_meowerunwiuwiwqw")]
+    pub customer_id: String,
+
+    /// The payment method ids of the customer, in the order they will be displayed
+    #[schema(example = json!(["pm_iouuy468iyuowqs", "pm_kqw7sd9jk23ndfs"]))]
+    pub payment_method_ids: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CustomerPaymentMethodsProbeResponse {
+    /// The unique identifier of the customer.
+    #[schema(example = "cus_meowerunwiuwiwqw")]
+    pub customer_id: String,
+
+    /// The saved payment methods of the customer, along with whether they can currently be used
+    pub payment_methods: Vec<PaymentMethodUsability>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodUsability {
+    /// The unique identifier of the payment method
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+
+    /// The type of payment method use for the payment.
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    /// This is a sub-category of payment method.
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// Whether the payment method can currently be used for payments
+    #[schema(example = true)]
+    pub is_usable: bool,
+
+    /// The reason why the payment method cannot be used, if it is not usable
+    pub unusable_reason: Option<PaymentMethodUnusableReason>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodImportRequest {
+    /// The unique identifier of the customer.
+    #[serde(skip)]
+    pub customer_id: String,
+
+    /// The token referencing the payment method at the connector it is imported from
+    #[schema(example = "pm_1OqQeL2eZvKYlo2C3dZg4Y4t")]
+    pub external_token_ref: String,
+
+    /// The merchant connector account of the connector which issued the token
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+
+    /// The type of payment method use for the payment.
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// This is a sub-category of payment method.
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = json!({ "city": "NY", "unit": "245" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodUnusableReason {
+    /// The card has expired
+    CardExpired,
+    /// The payment method token is no longer active
+    TokenInactive,
+    /// None of the connectors the payment method was saved with are available
+    ConnectorUnavailable,
+}
 //------------------------------------------------TokenizeService------------------------------------------------
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TokenizePayloadEncrypted {
@@ -27,7 +27,7 @@ use crate::{
     disputes, enums as api_enums,
     ephemeral_key::EphemeralKeyCreateResponse,
     mandates::RecurringDetails,
-    refunds,
+    refunds, routing,
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -263,6 +263,10 @@ pub struct PaymentsRequest {
     /// The billing details of the payment. This address will be used for invoicing.
     pub billing: Option<Address>,
 
+    /// The identifier of an address saved for the customer, to be used as the billing address of the payment. Can only be passed when creating the payment, and not along with `billing`.
+    #[schema(max_length = 64, example = "add_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub billing_address_id: Option<String>,
+
     /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
     /// Providing this field will automatically set `capture` to true
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -334,6 +338,10 @@ pub struct PaymentsRequest {
     /// The shipping address for the payment
     pub shipping: Option<Address>,
 
+    /// The identifier of an address saved for the customer, to be used as the shipping address of the payment. Can only be passed when creating the payment, and not along with `shipping`.
+    #[schema(max_length = 64, example = "add_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub shipping_address_id: Option<String>,
+
     /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements. Must contain at least one letter, maximum 22 characters.
     #[schema(max_length = 255, example = "Hyperswitch Router")]
     pub statement_descriptor_name: Option<String>,
@@ -534,6 +542,52 @@ impl RequestSurchargeDetails {
     }
 }
 
+/// Breakdown of the amount charged on a payment attempt on which surcharge was applied
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct AttemptSurchargeBreakdown {
+    /// Amount of the payment attempt before applying surcharge
+    #[schema(example = 6540)]
+    pub base_amount: i64,
+    /// Surcharge amount applied on the payment attempt
+    #[schema(example = 50)]
+    pub surcharge_amount: i64,
+    /// Tax amount applied on the surcharge
+    #[schema(example = 5)]
+    pub tax_on_surcharge_amount: i64,
+    /// Total amount charged for the payment attempt, sum of base amount, surcharge amount and tax on surcharge amount
+    #[schema(example = 6595)]
+    pub net_amount: i64,
+}
+
+/// Summary of the refunds initiated against a payment, failed refunds are not taken into account
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct PaymentRefundSummary {
+    /// Total amount refunded for the payment
+    #[schema(example = 6540)]
+    pub total_refunded: i64,
+    /// Number of refunds initiated against the payment
+    #[schema(example = 2)]
+    pub refund_count: usize,
+    /// Whether the captured amount of the payment is partially or fully refunded
+    pub refund_state: PaymentRefundState,
+}
+
+/// Whether the captured amount of a payment is partially or fully refunded
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentRefundState {
+    /// No amount of the payment has been refunded
+    #[default]
+    None,
+    /// A part of the captured amount has been refunded
+    Partial,
+    /// The captured amount has been refunded entirely
+    Full,
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct HeaderPayload {
     pub payment_confirm_source: Option<api_enums::PaymentSource>,
@@ -601,6 +655,11 @@ pub struct PaymentAttemptResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
+    /// Breakdown of the base amount, surcharge and tax on surcharge for this attempt, present only if surcharge was applied
+    pub surcharge_breakdown: Option<AttemptSurchargeBreakdown>,
+    /// Time taken by the connector to respond to the latest request of this attempt, in milliseconds
+    #[schema(example = 350)]
+    pub connector_latency: Option<i64>,
 }
 
 #[derive(
@@ -823,6 +882,12 @@ pub struct MandateAmountData {
         "frequency": "DAILY"
     }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The maximum total amount that can be debited over the lifetime of the mandate
+    #[schema(example = 100000)]
+    pub max_total_amount: Option<i64>,
+    /// The maximum number of debits that can be made over the lifetime of the mandate
+    #[schema(example = 12)]
+    pub max_debit_count: Option<i32>,
 }
 
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -2880,6 +2945,9 @@ pub struct PaymentsCaptureRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+    /// Merchant supplied reference for this capture, sent to connectors that support it. Defaults to the attempt id when not provided.
+    #[schema(example = "capture_ledger_7a1b2c")]
+    pub capture_reference: Option<String>,
 }
 
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
@@ -3171,6 +3239,9 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
 
+    /// Summary of the refunds that happened on this intent
+    pub refund_summary: Option<PaymentRefundSummary>,
+
     /// List of dispute that happened on this intent
     #[schema(value_type = Option<Vec<DisputeResponsePaymentsRetrieve>>)]
     pub disputes: Option<Vec<disputes::DisputeResponsePaymentsRetrieve>>,
@@ -3288,6 +3359,10 @@ pub struct PaymentsResponse {
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
 
+    /// A customer-friendly message for the decline of the payment, localized to the language of the customer's browser if messages are configured for it
+    #[schema(example = "Your card has insufficient funds.")]
+    pub customer_error_message: Option<String>,
+
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<api_enums::PaymentExperience>,
@@ -3397,6 +3472,10 @@ pub struct PaymentsResponse {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub updated: Option<PrimitiveDateTime>,
+
+    /// Explanation of how routing chose the connector for this payment, returned only when it is enabled for the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_explanation: Option<routing::RoutingExplanation>,
 }
 
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -4330,6 +4409,18 @@ pub struct PaymentsCancelRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+    /// Whether a payment in `processing` status can be rejected, only applicable to reject requests
+    #[serde(skip)]
+    pub force_reject: bool,
+    /// The reason for rejecting the payment, only applicable to reject requests
+    #[serde(skip)]
+    pub rejection_reason: Option<String>,
+    /// The decision to be recorded against the payment, only applicable to reject requests
+    #[serde(skip)]
+    pub reject_decision: Option<RejectDecision>,
+    /// Whether the customer should be notified about the rejection, only applicable to reject requests
+    #[serde(skip)]
+    pub notify_customer: Option<bool>,
 }
 
 #[derive(Default, Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
@@ -4429,6 +4520,73 @@ pub struct PaymentsRejectRequest {
     /// The identifier for the payment
     #[serde(skip)]
     pub payment_id: String,
+    /// Reject the payment even if it is in `processing` status, for payments stuck with an asynchronous connector
+    pub force: Option<bool>,
+    /// The reason for rejecting the payment, shown as the error message of the payment
+    pub rejection_reason: Option<String>,
+    /// The decision to be recorded against the payment, defaults to `rejected`
+    pub decision: Option<RejectDecision>,
+    /// Whether the customer should be notified about the rejection. Defaults to notifying the
+    /// customer, unless the fraud check flagged the payment as fraudulent
+    pub notify_customer: Option<bool>,
+}
+
+/// The merchant decision recorded against a rejected payment
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectDecision {
+    /// The payment is rejected, usually based on the fraud check
+    #[default]
+    Rejected,
+    /// The payment is declined during a manual review by the merchant
+    ManualReviewDeclined,
+}
+
+impl From<RejectDecision> for enums::MerchantDecision {
+    fn from(decision: RejectDecision) -> Self {
+        match decision {
+            RejectDecision::Rejected => Self::Rejected,
+            RejectDecision::ManualReviewDeclined => Self::ManualReviewDeclined,
+        }
+    }
+}
+
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsBulkRejectRequest {
+    /// The identifiers of the payments to be rejected, at most 100 per request
+    pub payment_ids: Vec<String>,
+    /// The reason for rejecting the payments, shown as the error message of each payment
+    pub rejection_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkRejectPaymentStatus {
+    /// The payment was rejected
+    Rejected,
+    /// The payment was not rejected since its status does not allow it to be rejected
+    NotRejectable,
+    /// The payment could not be rejected due to any other error
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkRejectPaymentResult {
+    /// The identifier of the payment this result corresponds to
+    pub payment_id: String,
+    pub status: BulkRejectPaymentStatus,
+    /// The error code, if the payment was not rejected
+    pub error_code: Option<String>,
+    /// The error message, if the payment was not rejected
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentsBulkRejectResponse {
+    /// The outcome for each payment of the request, in the order they were provided
+    pub payments: Vec<BulkRejectPaymentResult>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, ToSchema, Clone)]
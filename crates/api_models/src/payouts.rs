@@ -433,6 +433,14 @@ pub struct PayoutCreateResponse {
     #[schema(value_type = String, example = "E0001")]
     pub error_code: Option<String>,
 
+    /// Details of the card eligibility check, returned when the connector verified the eligibility of the card for payouts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eligibility_details: Option<PayoutEligibilityDetails>,
+
+    /// If the payout was cancelled the reason provided here
+    #[schema(example = "requested_by_customer")]
+    pub cancellation_reason: Option<String>,
+
     /// The business profile that is associated with this payment
     pub profile_id: String,
 
@@ -447,6 +455,58 @@ pub struct PayoutCreateResponse {
     pub attempts: Option<Vec<PayoutAttemptResponse>>,
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct PayoutEligibilityDetails {
+    /// Whether the card supports push payments, which credit the funds to the card immediately
+    #[schema(example = true)]
+    pub push_funds_supported: Option<bool>,
+
+    /// The card network reported by the connector
+    #[schema(example = "visa")]
+    pub network: Option<String>,
+
+    /// The reason for the eligibility of the card, as reported by the connector
+    #[schema(example = "Card is eligible for domestic payouts only")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The payouts to be created, each payout is processed independently of the others
+    pub payouts: Vec<PayoutCreateRequest>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchItemResult {
+    /// The position of the payout in the batch request, starting from 0
+    pub index: usize,
+    /// The payout_id provided for the payout in the batch request
+    #[schema(value_type = Option<String>, example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+    /// Whether the payout was created, the outcome of a created payout is present in its status
+    pub status: PayoutBatchItemStatus,
+    /// The created payout, if the item succeeded
+    pub payout: Option<PayoutCreateResponse>,
+    /// The error code, if the item failed
+    pub error_code: Option<String>,
+    /// The error message, if the item failed
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchCreateResponse {
+    /// The outcome of each payout of the batch, in the order they were provided
+    pub results: Vec<PayoutBatchItemResult>,
+}
+
 #[derive(
     Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema,
 )]
@@ -521,6 +581,10 @@ pub struct PayoutActionRequest {
         example = "payout_mbabizu24mvu3mela5njyhpit4"
     )]
     pub payout_id: String,
+
+    /// The reason for cancelling the payout, only applicable when cancelling a payout and rejected when fulfilling one
+    #[schema(max_length = 255, example = "requested_by_customer")]
+    pub cancellation_reason: Option<String>,
 }
 
 #[derive(Default, Debug, ToSchema, Clone, Deserialize)]
@@ -36,6 +36,35 @@ pub struct ExchangeTokenCreateResponse {
     pub access_token: String,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectionStatusRetrieveRequest {
+    pub payment_method_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectionStatusRetrieveResponse {
+    pub payment_method_id: String,
+    pub connector: String, // pm_auth connector through which the payment method is linked
+    pub status: PaymentMethodAuthConnectionStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_refreshed_at: Option<time::PrimitiveDateTime>, // last successful refresh of the data at the connector
+}
+
+/// Status of the link between a payment method and the account at the pm_auth connector
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodAuthConnectionStatus {
+    /// The link is healthy and the account can be used for payments
+    Active,
+    /// The customer has to re-authenticate with their bank for the link to be usable again
+    Expired,
+    /// The customer or the bank has revoked access to the account
+    Revoked,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PaymentMethodAuthConfig {
     pub enabled_payment_methods: Vec<PaymentMethodAuthConnectorChoice>,
@@ -53,5 +82,7 @@ impl_misc_api_event_type!(
     LinkTokenCreateRequest,
     LinkTokenCreateResponse,
     ExchangeTokenCreateRequest,
-    ExchangeTokenCreateResponse
+    ExchangeTokenCreateResponse,
+    ConnectionStatusRetrieveRequest,
+    ConnectionStatusRetrieveResponse
 );
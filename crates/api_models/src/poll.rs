@@ -1,5 +1,6 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 use serde::Serialize;
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 #[derive(Debug, ToSchema, Clone, Serialize)]
@@ -10,6 +11,22 @@ pub struct PollResponse {
     pub status: PollStatus,
 }
 
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct PollProgressResponse {
+    /// The identifier for the payment being polled
+    pub payment_id: String,
+    /// Status of the active poll of the payment, `not_found` if there is no active poll
+    pub status: PollStatus,
+    /// Number of polls made so far
+    pub attempts_made: u16,
+    /// Number of polls allowed before the poll is exhausted
+    pub max_attempts: u16,
+    /// Time at which the next poll is expected, absent if no further polls are expected
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_poll_at: Option<PrimitiveDateTime>,
+}
+
 #[derive(Debug, strum::Display, strum::EnumString, Clone, serde::Serialize, ToSchema)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -17,6 +34,8 @@ pub enum PollStatus {
     Pending,
     Completed,
     NotFound,
+    /// The poll ran out of attempts before the payment reached a final state
+    Exhausted,
 }
 
 impl ApiEventMetric for PollResponse {
@@ -26,3 +45,11 @@ impl ApiEventMetric for PollResponse {
         })
     }
 }
+
+impl ApiEventMetric for PollProgressResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
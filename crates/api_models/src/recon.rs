@@ -1,7 +1,7 @@
 use common_utils::pii;
 use masking::Secret;
 
-use crate::enums;
+use crate::{enums, payments::TimeRange};
 
 #[derive(serde::Deserialize, Debug, serde::Serialize)]
 pub struct ReconUpdateMerchantRequest {
@@ -19,3 +19,104 @@ pub struct ReconTokenResponse {
 pub struct ReconStatusResponse {
     pub recon_status: enums::ReconStatus,
 }
+
+#[derive(serde::Deserialize, Debug, serde::Serialize)]
+pub struct ReconDifferenceReportRequest {
+    /// Time range of the payments to reconcile, based on when the payments were created. The end
+    /// time is required, and the time range can span at most 31 days.
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+    /// Connector whose settlement records are being reconciled
+    pub connector: enums::Connector,
+    /// Settlement records as reported by the connector
+    pub settlement_records: Vec<SettlementRecord>,
+    /// Whether mismatches that were already marked as resolved are listed as well
+    #[serde(default)]
+    pub include_resolved: bool,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, serde::Serialize)]
+pub struct SettlementRecord {
+    /// The identifier of the transaction at the connector
+    pub connector_transaction_id: String,
+    /// Amount settled by the connector, in the lowest denomination of the currency
+    pub settled_amount: i64,
+    /// Currency in which the amount was settled
+    pub currency: enums::Currency,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconDifferenceReportResponse {
+    /// Number of transactions whose settlement does not match the recorded payment
+    pub count: usize,
+    pub mismatches: Vec<ReconMismatch>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ReconMismatch {
+    /// The identifier for the recon entry, used to resolve the mismatch
+    pub entry_id: String,
+    /// Whether the mismatch has been resolved
+    pub status: enums::ReconEntryStatus,
+    /// The connector whose settlement records are being reconciled
+    pub connector: String,
+    /// The identifier for the payment, absent for settlements without a recorded payment
+    pub payment_id: Option<String>,
+    /// The identifier of the transaction at the connector
+    pub connector_transaction_id: Option<String>,
+    /// Amount recorded for the payment
+    pub recorded_amount: Option<i64>,
+    /// Currency recorded for the payment
+    pub recorded_currency: Option<enums::Currency>,
+    /// Amount settled by the connector
+    pub settled_amount: Option<i64>,
+    /// Currency in which the connector settled the amount
+    pub settled_currency: Option<enums::Currency>,
+    /// Settled amount minus the recorded amount, absent when the two are in different currencies
+    pub delta: Option<i64>,
+    pub reason: ReconMismatchReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconMismatchReason {
+    /// The settled amount differs from the recorded amount in the same currency
+    Fee,
+    /// The amount was settled in a different currency than the one recorded
+    Fx,
+    /// A recorded payment has no settlement
+    Missing,
+    /// A settlement has no recorded payment
+    Extra,
+}
+
+#[derive(serde::Deserialize, Debug, serde::Serialize)]
+pub struct ReconEntryResolveRequest {
+    /// The identifier for the recon entry
+    #[serde(skip)]
+    pub entry_id: String,
+    /// The connector of the mismatch being resolved
+    pub connector: String,
+    /// Recorded amount of the mismatch being resolved, as listed in the difference report
+    pub recorded_amount: Option<i64>,
+    /// Settled amount of the mismatch being resolved, as listed in the difference report
+    pub settled_amount: Option<i64>,
+    /// Notes on how the mismatch was investigated
+    pub note: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconEntryResponse {
+    pub entry_id: String,
+    pub connector: String,
+    pub status: enums::ReconEntryStatus,
+    /// Recorded amount of the mismatch when it was resolved
+    pub recorded_amount: Option<i64>,
+    /// Settled amount of the mismatch when it was resolved
+    pub settled_amount: Option<i64>,
+    pub note: Option<String>,
+    /// The identifier of the user who resolved the entry
+    pub resolved_by: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -30,6 +30,10 @@ pub struct RefundRequest {
     )]
     pub refund_id: Option<String>,
 
+    /// Merchant supplied reference for the refund, used to reconcile the refund against the merchant's ledger. It has to be unique across all the refunds of the merchant and is sent to connectors that accept a refund reference
+    #[schema(max_length = 64, example = "ledger_ref_7a1b2c")]
+    pub merchant_refund_reference_id: Option<String>,
+
     /// The identifier for the Merchant Account
     #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
     pub merchant_id: Option<String>,
@@ -78,6 +82,76 @@ pub struct RefundsRetrieveRequest {
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundApprovalRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+    /// The decision taken on a refund which is pending approval
+    pub decision: RefundApprovalDecision,
+}
+
+/// The decision taken on a refund which is pending approval
+#[derive(Debug, Clone, Copy, ToSchema, Deserialize, Serialize, Eq, PartialEq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundApprovalDecision {
+    /// The refund is approved and will be sent to the connector
+    Approve,
+    /// The refund is rejected and will not be sent to the connector
+    Reject,
+}
+
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundEligibilityRequest {
+    /// The payment id for which the refund eligibility is to be checked
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "pay_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub payment_id: String,
+
+    /// The amount for which the refund would be initiated. If not provided, only the refundable amount of the payment is checked
+    #[schema(minimum = 100, example = 6540)]
+    pub amount: Option<i64>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RefundEligibilityResponse {
+    /// Whether a refund can be initiated for the payment and amount
+    pub eligible: bool,
+
+    /// The maximum amount that can still be refunded for the payment, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub max_refundable_amount: i64,
+
+    /// The reasons for which the refund cannot be initiated, empty if the refund is eligible
+    pub reasons: Vec<RefundIneligibilityReason>,
+}
+
+/// The reason for which a refund cannot be initiated
+#[derive(Debug, Clone, Copy, ToSchema, Deserialize, Serialize, Eq, PartialEq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundIneligibilityReason {
+    /// The payment is not in a succeeded or partially captured state
+    PaymentNotSucceeded,
+    /// The captured amount of the payment has already been refunded
+    FullyRefunded,
+    /// The refund amount is not a positive amount
+    InvalidRefundAmount,
+    /// The refund amount exceeds the amount that can still be refunded
+    AmountExceedsRefundableAmount,
+    /// The maximum number of refunds for the payment has been reached
+    MaximumRefundCountReached,
+    /// The payment is older than the maximum age allowed for refunds
+    PaymentOrderExpired,
+    /// The connector does not support refunds for the payment method used
+    RefundNotSupportedByConnector,
+}
+
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundUpdateRequest {
@@ -107,6 +181,9 @@ pub enum RefundType {
 pub struct RefundResponse {
     /// Unique Identifier for the refund
     pub refund_id: String,
+    /// Merchant supplied reference for the refund
+    #[schema(example = "ledger_ref_7a1b2c")]
+    pub merchant_refund_reference_id: Option<String>,
     /// The payment id against which refund is initiated
     pub payment_id: String,
     /// The refund amount, which should be less than or equal to the total payment amount. Amount for the payment in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc
@@ -223,6 +300,7 @@ pub enum RefundStatus {
     #[default]
     Pending,
     Review,
+    PendingApproval,
 }
 
 impl From<enums::RefundStatus> for RefundStatus {
@@ -231,6 +309,7 @@ impl From<enums::RefundStatus> for RefundStatus {
             enums::RefundStatus::Failure | enums::RefundStatus::TransactionFailure => Self::Failed,
             enums::RefundStatus::ManualReview => Self::Review,
             enums::RefundStatus::Pending => Self::Pending,
+            enums::RefundStatus::PendingApproval => Self::PendingApproval,
             enums::RefundStatus::Success => Self::Succeeded,
         }
     }
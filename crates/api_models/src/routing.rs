@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use common_utils::errors::ParsingError;
 pub use euclid::{
@@ -11,7 +11,19 @@ pub use euclid::{
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::enums::{RoutableConnectors, TransactionType};
+use crate::enums::{self as api_enums, RoutableConnectors, TransactionType};
+
+/// Ordered connectors to fall back to, keyed by the connector chosen by routing, when the chosen
+/// connector returns an error for which the payment should be retried with an alternate connector
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ConnectorErrorFallback(pub HashMap<RoutableConnectors, Vec<RoutableConnectors>>);
+
+impl ConnectorErrorFallback {
+    pub fn get_fallback_connectors(&self, connector: &RoutableConnectors) -> &[RoutableConnectors] {
+        self.0.get(connector).map(Vec::as_slice).unwrap_or_default()
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -156,7 +168,7 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
     pub split: u8,
@@ -299,7 +311,17 @@ impl From<RoutableConnectorChoice> for ast::ConnectorChoice {
     }
 }
 
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, strum::Display, ToSchema)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum RoutingAlgorithmKind {
@@ -309,6 +331,82 @@ pub enum RoutingAlgorithmKind {
     Advanced,
 }
 
+/// Explanation of how routing chose the connectors for a payment
+#[derive(Debug, Clone, PartialEq, serde::Serialize, ToSchema)]
+pub struct RoutingExplanation {
+    /// The routing configuration which decided the connectors
+    pub routing_source: RoutingDecisionSource,
+    /// The kind of routing algorithm which decided the connectors, absent when the default
+    /// fallback list was used
+    pub algorithm_kind: Option<RoutingAlgorithmKind>,
+    /// The name of the advanced routing rule matched by the payment, absent when no rule matched
+    /// and the default selection of the algorithm was used
+    pub matched_rule: Option<String>,
+    /// The connectors and weights the connector was picked from, when it was picked by a volume split
+    pub volume_split: Option<Vec<ConnectorVolumeSplit>>,
+    /// Connectors chosen by the routing algorithm which were excluded as not eligible for the payment
+    pub excluded_connectors: Vec<RoutableConnectorChoice>,
+    /// Connectors appended from the default fallback list after the routed connectors
+    pub fallback_connectors: Vec<RoutableConnectorChoice>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, strum::Display, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingDecisionSource {
+    /// The routing algorithm passed in the payment request
+    StraightThrough,
+    /// The active routing algorithm of the merchant
+    MerchantAlgorithm,
+    /// The default fallback list, used when no routing algorithm is active
+    DefaultFallback,
+}
+
+/// A sample payment context, evaluated against the active routing configuration without creating
+/// a payment
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingEvaluateRequest {
+    /// The business profile whose active routing configuration is evaluated
+    pub profile_id: Option<String>,
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// The first six digits of the card number
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub billing_country: Option<api_enums::CountryAlpha2>,
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub business_country: Option<api_enums::CountryAlpha2>,
+    pub business_label: Option<String>,
+    #[schema(value_type = Option<AuthenticationType>, example = "no_three_ds")]
+    pub authentication_type: Option<api_enums::AuthenticationType>,
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<api_enums::CaptureMethod>,
+    #[schema(value_type = Option<FutureUsage>, example = "off_session")]
+    pub setup_future_usage: Option<api_enums::FutureUsage>,
+    /// The routing parameters that would be passed in the metadata of the payment
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// The connectors that the active routing configuration selects for a sample payment context
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingEvaluateResponse {
+    /// The connectors selected by routing, in the order they would be tried
+    pub connectors: Vec<RoutableConnectorChoice>,
+    /// How routing chose the connectors, including the rule matched by the payment context
+    pub explanation: RoutingExplanation,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 
 pub struct RoutingPayloadWrapper {
@@ -341,6 +439,39 @@ pub enum RoutingAlgorithmSerde {
     Advanced(ast::Program<ConnectorSelection>),
 }
 
+/// Validates that the splits of a volume split algorithm add up to 100. This is checked when an
+/// algorithm is created or passed in a request rather than when it is deserialized, so that
+/// algorithms that are already stored can always be read.
+pub fn validate_volume_splits(
+    splits: &[ConnectorVolumeSplit],
+) -> Result<(), error_stack::Report<ParsingError>> {
+    let total_split: u32 = splits.iter().map(|split| u32::from(split.split)).sum();
+    if total_split != 100 {
+        Err(ParsingError::StructParseFailure(
+            "Splits must add up to 100 for Volume split Algorithm",
+        ))?
+    }
+    Ok(())
+}
+
+impl RoutingAlgorithm {
+    pub fn validate_volume_splits(&self) -> Result<(), error_stack::Report<ParsingError>> {
+        match self {
+            Self::VolumeSplit(splits) => validate_volume_splits(splits),
+            Self::Advanced(program) => program
+                .rules
+                .iter()
+                .map(|rule| &rule.connector_selection)
+                .chain(std::iter::once(&program.default_selection))
+                .try_for_each(|selection| match selection {
+                    ConnectorSelection::VolumeSplit(splits) => validate_volume_splits(splits),
+                    ConnectorSelection::Priority(_) => Ok(()),
+                }),
+            Self::Single(_) | Self::Priority(_) => Ok(()),
+        }
+    }
+}
+
 impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
     type Error = error_stack::Report<ParsingError>;
 
@@ -401,6 +532,15 @@ pub enum StraightThroughAlgorithmSerde {
     },
 }
 
+impl StraightThroughAlgorithm {
+    pub fn validate_volume_splits(&self) -> Result<(), error_stack::Report<ParsingError>> {
+        match self {
+            Self::VolumeSplit(splits) => validate_volume_splits(splits),
+            Self::Single(_) | Self::Priority(_) => Ok(()),
+        }
+    }
+}
+
 impl TryFrom<StraightThroughAlgorithmSerde> for StraightThroughAlgorithm {
     type Error = error_stack::Report<ParsingError>;
 
@@ -475,6 +615,8 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// Connectors to fall back to when the connector chosen by routing returns an error
+    pub connector_error_fallback: Option<ConnectorErrorFallback>,
 }
 
 impl RoutingAlgorithmRef {
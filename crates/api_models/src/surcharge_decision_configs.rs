@@ -15,8 +15,14 @@ pub struct SurchargeDetailsOutput {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
 pub enum SurchargeOutput {
-    Fixed { amount: i64 },
+    Fixed {
+        amount: i64,
+    },
     Rate(Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>),
+    RatePlusFixed {
+        percentage: Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>,
+        amount: i64,
+    },
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -31,6 +37,7 @@ impl EuclidDirFilter for SurchargeDecisionConfigs {
         DirKeyKind::PaymentCurrency,
         DirKeyKind::BillingCountry,
         DirKeyKind::CardNetwork,
+        DirKeyKind::CardType,
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::BankTransferType,
@@ -71,6 +78,9 @@ impl events::ApiEventMetric for SurchargeDecisionConfigReq {
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MerchantSurchargeConfigs {
     pub show_surcharge_breakup_screen: Option<bool>,
+    /// Evaluate the rules matching on both the card network and the card type first, followed by
+    /// the rules matching on either of them, instead of in the order in which they are listed
+    pub prioritize_card_specific_rules: Option<bool>,
 }
 
 pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
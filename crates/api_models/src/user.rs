@@ -80,6 +80,11 @@ pub struct ChangePasswordRequest {
     pub old_password: Secret<String>,
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct ChangePasswordResponse {
+    pub token: Secret<String>,
+}
+
 #[derive(serde::Deserialize, Debug, serde::Serialize)]
 pub struct ForgotPasswordRequest {
     pub email: pii::Email,
@@ -112,6 +112,11 @@ pub struct DeleteUserRoleRequest {
     pub email: pii::Email,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ForceSignoutRequest {
+    pub email: pii::Email,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct TransferOrgOwnershipRequest {
     pub email: pii::Email,
@@ -1,3 +1,5 @@
+use time::PrimitiveDateTime;
+
 /// The request body for verification of merchant (everything except domain_names are prefilled)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,4 +37,17 @@ pub struct ApplepayGetVerifiedDomainsParam {
 #[serde(rename_all = "snake_case")]
 pub struct ApplepayVerifiedDomainsResponse {
     pub verified_domains: Vec<String>,
+    /// Time at which the domains were last verified with Apple Pay
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub verified_at: Option<PrimitiveDateTime>,
+    /// Time after which the domains have to be verified again
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+/// Request to re-verify the domains already verified for a merchant connector account
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ApplepayReverificationRequest {
+    pub merchant_connector_account_id: String,
 }
@@ -1,4 +1,5 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
 
 use crate::{admin, enums};
 
@@ -6,6 +7,90 @@ use crate::{admin, enums};
 pub struct VerifyConnectorRequest {
     pub connector_name: enums::Connector,
     pub connector_account_details: admin::ConnectorAuthType,
+    /// What should be verified. Only the credentials are verified if this is not provided, in
+    /// which case no verification results are returned
+    pub verification_scope: Option<VerificationScope>,
+    /// Webhook details of the connector, required to verify the webhook configuration
+    pub connector_webhook_details: Option<admin::MerchantConnectorWebhookDetails>,
+    /// The URL to which the customer is redirected after 3DS authentication, required to verify
+    /// the webhook configuration
+    pub return_url: Option<String>,
 }
 
-common_utils::impl_misc_api_event_type!(VerifyConnectorRequest);
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationScope {
+    /// Verify the connector credentials
+    Auth,
+    /// Verify that the webhook secrets have the format expected by the connector and that the
+    /// return URL is configured
+    Webhook,
+    /// Verify both the connector credentials and the webhook configuration
+    Full,
+}
+
+impl VerificationScope {
+    pub fn includes_auth(self) -> bool {
+        matches!(self, Self::Auth | Self::Full)
+    }
+
+    pub fn includes_webhook(self) -> bool {
+        matches!(self, Self::Webhook | Self::Full)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct VerificationResult {
+    /// Whether the verification of the scope succeeded
+    pub verified: bool,
+    /// The reasons for which the verification of the scope failed
+    pub errors: Vec<String>,
+}
+
+impl VerificationResult {
+    pub fn from_errors(errors: Vec<String>) -> Self {
+        Self {
+            verified: errors.is_empty(),
+            errors,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct VerifyConnectorResponse {
+    pub verification_scope: VerificationScope,
+    /// Result of verifying the connector credentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<VerificationResult>,
+    /// Result of verifying the webhook configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<VerificationResult>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct MerchantConnectorHealthRequest {
+    pub merchant_id: String,
+    pub merchant_connector_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct MerchantConnectorHealthResponse {
+    /// The identifier of the merchant connector account that was checked
+    pub merchant_connector_id: String,
+    /// Name of the connector of the merchant connector account
+    pub connector_name: enums::Connector,
+    /// Whether the connector accepted the credentials of the merchant connector account
+    pub healthy: bool,
+    /// Time at which the credentials were last verified with the connector
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub checked_at: PrimitiveDateTime,
+    /// The error returned by the connector, if the credentials were rejected
+    pub error: Option<String>,
+}
+
+common_utils::impl_misc_api_event_type!(
+    VerifyConnectorRequest,
+    VerifyConnectorResponse,
+    MerchantConnectorHealthRequest,
+    MerchantConnectorHealthResponse
+);
@@ -1,4 +1,4 @@
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
+use common_enums::{EventClass, EventType, WebhookDeliveryAttempt, WebhookDeliveryStatus};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -24,6 +24,9 @@ pub struct EventListConstraints {
     /// Filter all events associated with the specified object identifier (Payment Intent ID,
     /// Refund ID, etc.)
     pub object_id: Option<String>,
+
+    /// Filter events by their delivery status.
+    pub delivery_status: Option<WebhookDeliveryStatus>,
 }
 
 #[derive(Debug)]
@@ -33,6 +36,7 @@ pub enum EventListConstraintsInternal {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<WebhookDeliveryStatus>,
     },
     ObjectIdFilter {
         object_id: String,
@@ -183,3 +187,17 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventReplayRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub event_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
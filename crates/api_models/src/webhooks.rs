@@ -73,6 +73,10 @@ pub enum WebhookResponseTracker {
         mandate_id: String,
         status: common_enums::MandateStatus,
     },
+    /// Effects of the individual events of a batched webhook
+    Batch {
+        effects: Vec<Self>,
+    },
     NoEffect,
 }
 
@@ -82,7 +86,7 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_string()),
-            Self::NoEffect | Self::Mandate { .. } => None,
+            Self::NoEffect | Self::Mandate { .. } | Self::Batch { .. } => None,
         }
     }
 }
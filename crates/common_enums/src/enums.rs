@@ -1130,6 +1130,54 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The delivery status of an outgoing webhook event.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// The webhook was delivered to the merchant server successfully
+    Delivered,
+    /// The webhook was sent to the merchant server, but delivery was unsuccessful
+    Failed,
+    /// The webhook has not been sent to the merchant server yet
+    Pending,
+}
+
+/// The algorithm used to sign outgoing webhooks.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookSignatureAlgorithm {
+    /// HMAC using SHA-256, sent in the `X-Webhook-Signature-256` header
+    HmacSha256,
+    /// HMAC using SHA-512, sent in the `X-Webhook-Signature-512` header
+    #[default]
+    HmacSha512,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -1525,6 +1573,7 @@ pub enum RefundStatus {
     ManualReview,
     #[default]
     Pending,
+    PendingApproval,
     Success,
     TransactionFailure,
 }
@@ -1552,6 +1601,7 @@ pub enum MandateStatus {
     Inactive,
     Pending,
     Revoked,
+    Paused,
 }
 
 /// Indicates the card network.
@@ -2232,6 +2282,7 @@ pub enum MerchantDecision {
     Approved,
     Rejected,
     AutoRefunded,
+    ManualReviewDeclined,
 }
 
 #[derive(
@@ -2281,6 +2332,31 @@ pub enum ReconStatus {
     Active,
     Disabled,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconEntryStatus {
+    /// The mismatch has not been looked into yet
+    #[default]
+    Unresolved,
+    /// The mismatch has been investigated and needs no further action
+    Resolved,
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ApplePayFlow {
     Simplified,
@@ -2508,6 +2584,30 @@ pub enum PermissionGroup {
     OrganizationManage,
 }
 
+/// An operation that an API key can be allowed to perform
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyPermission {
+    PaymentsRead,
+    PaymentsWrite,
+    RefundsRead,
+    RefundsWrite,
+}
+
 /// Name of banks supported by Hyperswitch
 #[derive(
     Clone,
@@ -65,9 +65,23 @@ impl<const PRECISION: u8> Percentage<PRECISION> {
                 max_amount
             ))
         } else {
-            let percentage_f64 = f64::from(self.percentage);
-            let result = (amount as f64 * (percentage_f64 / 100.0)).ceil() as i64;
-            Ok(result)
+            // the percentage is applied as an integer scaled by its precision, so that the float
+            // representation of the percentage (1.1 is stored as 1.10000002) does not ceil the
+            // result to the next minor unit
+            let precision_factor = 10_i64.pow(u32::from(PRECISION));
+            let scaled_percentage =
+                (f64::from(self.percentage) * precision_factor as f64).round() as i64;
+            let divisor = precision_factor.saturating_mul(100);
+            amount
+                .checked_mul(scaled_percentage)
+                .and_then(|scaled_amount| scaled_amount.checked_add(divisor - 1))
+                .map(|scaled_amount| scaled_amount.div_euclid(divisor))
+                .ok_or_else(|| {
+                    report!(PercentageError::UnableToApplyPercentage {
+                        percentage: self.percentage,
+                        amount,
+                    })
+                })
         }
     }
     fn is_valid_string_value(value: &str) -> CustomResult<bool, PercentageError> {
@@ -154,6 +168,13 @@ pub enum Surcharge {
     Fixed(i64),
     /// Surcharge percentage
     Rate(Percentage<{ consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>),
+    /// Surcharge percentage along with a fixed surcharge value, e.g. 2.5% + 30
+    RatePlusFixed {
+        /// Surcharge percentage
+        percentage: Percentage<{ consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>,
+        /// Fixed Surcharge value
+        amount: i64,
+    },
 }
 
 /// This struct lets us represent a semantic version type
@@ -198,3 +198,23 @@ fn deserialization_test_err() -> Result<(), Box<dyn std::error::Error + Send + S
     }
     Ok(())
 }
+
+fn apply_percentage(percentage: &str, amount: i64) -> Option<i64> {
+    Percentage::<PRECISION_2>::from_string(percentage.to_string())
+        .ok()?
+        .apply_and_ceil_result(amount)
+        .ok()
+}
+
+#[test]
+fn apply_percentage_and_ceil_result() {
+    assert_eq!(apply_percentage("2.5", 1000), Some(25));
+    assert_eq!(apply_percentage("2.5", 1001), Some(26));
+    assert_eq!(apply_percentage("2.5", 0), Some(0));
+    assert_eq!(apply_percentage("100", i64::MAX), None);
+
+    // float representation of the percentage does not ceil the result to the next minor unit
+    assert_eq!(apply_percentage("1.1", 1000), Some(11));
+    assert_eq!(apply_percentage("2.9", 1000), Some(29));
+    assert_eq!(apply_percentage("0.07", 10000), Some(7));
+}
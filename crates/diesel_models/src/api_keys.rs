@@ -2,7 +2,7 @@ use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::schema::api_keys;
+use crate::{enums as storage_enums, schema::api_keys};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Identifiable, Queryable)]
 #[diesel(table_name = api_keys, primary_key(key_id))]
@@ -16,6 +16,7 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permissions: Option<Vec<storage_enums::ApiKeyPermission>>,
 }
 
 #[derive(Debug, Insertable)]
@@ -30,6 +31,7 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permissions: Option<Vec<storage_enums::ApiKeyPermission>>,
 }
 
 #[derive(Debug)]
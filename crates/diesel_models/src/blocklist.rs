@@ -11,6 +11,7 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
@@ -23,4 +24,5 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
@@ -40,6 +40,7 @@ pub struct BusinessProfile {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub dispute_auto_accept_threshold: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -71,6 +72,7 @@ pub struct BusinessProfileNew {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub dispute_auto_accept_threshold: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -99,6 +101,7 @@ pub struct BusinessProfileUpdateInternal {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub dispute_auto_accept_threshold: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -124,6 +127,7 @@ pub enum BusinessProfileUpdate {
         extended_card_info_config: Option<pii::SecretSerdeValue>,
         use_billing_as_payment_method_billing: Option<bool>,
         collect_shipping_details_from_wallet_connector: Option<bool>,
+        dispute_auto_accept_threshold: Option<serde_json::Value>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
@@ -157,6 +161,7 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 extended_card_info_config,
                 use_billing_as_payment_method_billing,
                 collect_shipping_details_from_wallet_connector,
+                dispute_auto_accept_threshold,
             } => Self {
                 profile_name,
                 modified_at,
@@ -178,6 +183,7 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 extended_card_info_config,
                 use_billing_as_payment_method_billing,
                 collect_shipping_details_from_wallet_connector,
+                dispute_auto_accept_threshold,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -225,6 +231,7 @@ impl From<BusinessProfileNew> for BusinessProfile {
             use_billing_as_payment_method_billing: new.use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector: new
                 .collect_shipping_details_from_wallet_connector,
+            dispute_auto_accept_threshold: new.dispute_auto_accept_threshold,
         }
     }
 }
@@ -254,6 +261,7 @@ impl BusinessProfileUpdate {
             is_connector_agnostic_mit_enabled,
             use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector,
+            dispute_auto_accept_threshold,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -280,6 +288,7 @@ impl BusinessProfileUpdate {
             extended_card_info_config,
             use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector,
+            dispute_auto_accept_threshold,
             ..source
         }
     }
@@ -1,9 +1,11 @@
-use diesel::{Identifiable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::cards_info};
 
-#[derive(Clone, Debug, Queryable, Identifiable, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Debug, Queryable, Identifiable, Insertable, serde::Deserialize, serde::Serialize,
+)]
 #[diesel(table_name = cards_info, primary_key(card_iin))]
 pub struct CardInfo {
     pub card_iin: String,
@@ -19,3 +21,65 @@ pub struct CardInfo {
     pub last_updated: Option<PrimitiveDateTime>,
     pub last_updated_provider: Option<String>,
 }
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = cards_info)]
+pub struct CardInfoUpdateInternal {
+    pub card_issuer: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bank_code_id: Option<String>,
+    pub bank_code: Option<String>,
+    pub country_code: Option<String>,
+    pub last_updated: Option<PrimitiveDateTime>,
+    pub last_updated_provider: Option<String>,
+}
+
+impl From<CardInfo> for CardInfoUpdateInternal {
+    fn from(card_info: CardInfo) -> Self {
+        Self {
+            card_issuer: card_info.card_issuer,
+            card_network: card_info.card_network,
+            card_type: card_info.card_type,
+            card_subtype: card_info.card_subtype,
+            card_issuing_country: card_info.card_issuing_country,
+            bank_code_id: card_info.bank_code_id,
+            bank_code: card_info.bank_code,
+            country_code: card_info.country_code,
+            last_updated: card_info.last_updated,
+            last_updated_provider: card_info.last_updated_provider,
+        }
+    }
+}
+
+impl CardInfoUpdateInternal {
+    pub fn apply_changeset(self, source: CardInfo) -> CardInfo {
+        let Self {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            bank_code_id,
+            bank_code,
+            country_code,
+            last_updated,
+            last_updated_provider,
+        } = self;
+        CardInfo {
+            card_issuer: card_issuer.or(source.card_issuer),
+            card_network: card_network.or(source.card_network),
+            card_type: card_type.or(source.card_type),
+            card_subtype: card_subtype.or(source.card_subtype),
+            card_issuing_country: card_issuing_country.or(source.card_issuing_country),
+            bank_code_id: bank_code_id.or(source.bank_code_id),
+            bank_code: bank_code.or(source.bank_code),
+            country_code: country_code.or(source.country_code),
+            last_updated: last_updated.or(source.last_updated),
+            last_updated_provider: last_updated_provider.or(source.last_updated_provider),
+            ..source
+        }
+    }
+}
@@ -61,6 +61,10 @@ pub struct Dispute {
     pub profile_id: Option<String>,
     pub merchant_connector_id: Option<String>,
     pub dispute_amount: i64,
+    /// Whether the dispute was accepted automatically for being within the auto accept threshold
+    /// of the business profile, `false` if the connector failed to accept it. Not set for disputes
+    /// which were not eligible to be accepted automatically.
+    pub auto_accepted: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -81,6 +85,9 @@ pub enum DisputeUpdate {
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    AutoAcceptUpdate {
+        auto_accepted: bool,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -95,6 +102,7 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: Option<PrimitiveDateTime>,
     evidence: Option<Secret<serde_json::Value>>,
+    auto_accepted: Option<bool>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -132,6 +140,11 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 evidence: Some(evidence),
                 ..Default::default()
             },
+            DisputeUpdate::AutoAcceptUpdate { auto_accepted } => Self {
+                auto_accepted: Some(auto_accepted),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
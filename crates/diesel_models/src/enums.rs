@@ -220,6 +220,8 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub max_total_amount: Option<i64>,
+    pub max_debit_count: Option<i32>,
 }
 
 #[derive(
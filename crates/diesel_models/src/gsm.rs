@@ -38,6 +38,7 @@ pub struct GatewayStatusMap {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub priority: i32,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -54,6 +55,7 @@ pub struct GatewayStatusMappingNew {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub priority: i32,
 }
 
 #[derive(
@@ -79,6 +81,7 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub priority: Option<i32>,
 }
 
 #[derive(Debug)]
@@ -89,6 +92,7 @@ pub struct GatewayStatusMappingUpdate {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub priority: Option<i32>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -100,6 +104,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            priority,
         } = value;
         Self {
             status,
@@ -108,6 +113,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            priority,
             ..Default::default()
         }
     }
@@ -32,6 +32,10 @@ pub struct Mandate {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub max_total_amount: Option<i64>,
+    pub max_debit_count: Option<i32>,
+    pub debit_count: i32,
+    pub debited_amount: i64,
 }
 
 #[derive(
@@ -69,6 +73,10 @@ pub struct MandateNew {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub max_total_amount: Option<i64>,
+    pub max_debit_count: Option<i32>,
+    pub debit_count: Option<i32>,
+    pub debited_amount: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -88,6 +96,10 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<String>,
     },
+    DebitUsageUpdate {
+        debit_count: i32,
+        debited_amount: i64,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -113,6 +125,8 @@ pub struct MandateUpdateInternal {
     connector_mandate_id: Option<String>,
     payment_method_id: Option<String>,
     original_payment_id: Option<String>,
+    debit_count: Option<i32>,
+    debited_amount: Option<i64>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -125,6 +139,8 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id: None,
                 payment_method_id: None,
                 original_payment_id: None,
+                debit_count: None,
+                debited_amount: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
@@ -133,6 +149,8 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id: None,
                 payment_method_id: None,
                 original_payment_id: None,
+                debit_count: None,
+                debited_amount: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
@@ -152,6 +170,14 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 original_payment_id,
                 ..Default::default()
             },
+            MandateUpdate::DebitUsageUpdate {
+                debit_count,
+                debited_amount,
+            } => Self {
+                debit_count: Some(debit_count),
+                debited_amount: Some(debited_amount),
+                ..Default::default()
+            },
         }
     }
 }
@@ -165,6 +191,8 @@ impl MandateUpdateInternal {
             connector_mandate_id,
             payment_method_id,
             original_payment_id,
+            debit_count,
+            debited_amount,
         } = self;
 
         Mandate {
@@ -174,6 +202,8 @@ impl MandateUpdateInternal {
             connector_mandate_id: connector_mandate_id.map_or(source.connector_mandate_id, Some),
             payment_method_id: payment_method_id.unwrap_or(source.payment_method_id),
             original_payment_id: original_payment_id.map_or(source.original_payment_id, Some),
+            debit_count: debit_count.unwrap_or(source.debit_count),
+            debited_amount: debited_amount.unwrap_or(source.debited_amount),
             ..source
         }
     }
@@ -208,6 +238,10 @@ impl From<&MandateNew> for Mandate {
             connector_mandate_ids: mandate_new.connector_mandate_ids.clone(),
             original_payment_id: mandate_new.original_payment_id.clone(),
             merchant_connector_id: mandate_new.merchant_connector_id.clone(),
+            max_total_amount: mandate_new.max_total_amount,
+            max_debit_count: mandate_new.max_debit_count,
+            debit_count: mandate_new.debit_count.unwrap_or(0),
+            debited_amount: mandate_new.debited_amount.unwrap_or(0),
         }
     }
 }
@@ -43,6 +43,9 @@ pub struct MerchantConnectorAccount {
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: storage_enums::ConnectorStatus,
+    pub connector_wallets_details: Option<Encryption>,
+    pub applepay_verified_at: Option<time::PrimitiveDateTime>,
+    pub applepay_verification_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -72,6 +75,9 @@ pub struct MerchantConnectorAccountNew {
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: storage_enums::ConnectorStatus,
+    pub connector_wallets_details: Option<Encryption>,
+    pub applepay_verified_at: Option<time::PrimitiveDateTime>,
+    pub applepay_verification_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -96,6 +102,9 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: Option<storage_enums::ConnectorStatus>,
+    pub connector_wallets_details: Option<Encryption>,
+    pub applepay_verified_at: Option<time::PrimitiveDateTime>,
+    pub applepay_verification_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 impl MerchantConnectorAccountUpdateInternal {
@@ -119,6 +128,13 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            connector_wallets_details: self
+                .connector_wallets_details
+                .or(source.connector_wallets_details),
+            applepay_verified_at: self.applepay_verified_at.or(source.applepay_verified_at),
+            applepay_verification_expires_at: self
+                .applepay_verification_expires_at
+                .or(source.applepay_verification_expires_at),
 
             ..source
         }
@@ -7,6 +7,7 @@ use crate::schema::organization;
 pub struct Organization {
     pub org_id: String,
     pub org_name: Option<String>,
+    pub parent_organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Insertable)]
@@ -14,22 +15,38 @@ pub struct Organization {
 pub struct OrganizationNew {
     pub org_id: String,
     pub org_name: Option<String>,
+    pub parent_organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, AsChangeset)]
 #[diesel(table_name = organization)]
 pub struct OrganizationUpdateInternal {
     org_name: Option<String>,
+    parent_organization_id: Option<Option<String>>,
 }
 
 pub enum OrganizationUpdate {
-    Update { org_name: Option<String> },
+    Update {
+        org_name: Option<String>,
+    },
+    ParentUpdate {
+        parent_organization_id: Option<String>,
+    },
 }
 
 impl From<OrganizationUpdate> for OrganizationUpdateInternal {
     fn from(value: OrganizationUpdate) -> Self {
         match value {
-            OrganizationUpdate::Update { org_name } => Self { org_name },
+            OrganizationUpdate::Update { org_name } => Self {
+                org_name,
+                parent_organization_id: None,
+            },
+            OrganizationUpdate::ParentUpdate {
+                parent_organization_id,
+            } => Self {
+                org_name: None,
+                parent_organization_id: Some(parent_organization_id),
+            },
         }
     }
 }
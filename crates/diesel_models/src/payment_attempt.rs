@@ -70,6 +70,11 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    /// Time taken by the connector to respond to the latest request of the attempt, in milliseconds
+    pub connector_latency: Option<i64>,
+    /// Time at which the attempt was authorized with the connector
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttempt {
@@ -150,6 +155,8 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    /// Time taken by the connector to respond to the latest request of the attempt, in milliseconds
+    pub connector_latency: Option<i64>,
 }
 
 impl PaymentAttemptNew {
@@ -275,6 +282,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        connector_latency: Option<i64>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -286,6 +294,7 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         updated_by: String,
+        connector_latency: Option<i64>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -303,6 +312,7 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
+        connector_latency: Option<i64>,
     },
     CaptureUpdate {
         amount_to_capture: Option<i64>,
@@ -388,6 +398,8 @@ pub struct PaymentAttemptUpdateInternal {
     authentication_id: Option<String>,
     fingerprint_id: Option<String>,
     payment_method_billing_address_id: Option<String>,
+    connector_latency: Option<i64>,
+    authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -404,6 +416,12 @@ impl PaymentAttemptUpdateInternal {
                     .or(source.tax_amount)
                     .unwrap_or(0),
         );
+        // The authorization time is recorded when the attempt moves to authorized
+        if update_internal.status == Some(storage_enums::AttemptStatus::Authorized)
+            && source.status != storage_enums::AttemptStatus::Authorized
+        {
+            update_internal.authorized_at = Some(common_utils::date_time::now());
+        }
         update_internal
     }
 }
@@ -453,6 +471,8 @@ impl PaymentAttemptUpdate {
             authentication_id,
             payment_method_billing_address_id,
             fingerprint_id,
+            connector_latency,
+            authorized_at,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -501,6 +521,8 @@ impl PaymentAttemptUpdate {
             payment_method_billing_address_id: payment_method_billing_address_id
                 .or(source.payment_method_billing_address_id),
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
+            connector_latency: connector_latency.or(source.connector_latency),
+            authorized_at: authorized_at.or(source.authorized_at),
             ..source
         }
     }
@@ -682,6 +704,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_latency,
             } => Self {
                 status: Some(status),
                 connector: connector.map(Some),
@@ -703,6 +726,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_latency,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -717,6 +741,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_latency,
             } => Self {
                 connector: connector.map(Some),
                 status: Some(status),
@@ -730,6 +755,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_latency,
                 ..Default::default()
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -767,6 +793,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             } => Self {
                 status: Some(status),
                 connector: connector.map(Some),
@@ -778,6 +805,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
                 ..Default::default()
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
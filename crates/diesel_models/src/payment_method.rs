@@ -41,6 +41,7 @@ pub struct PaymentMethod {
     pub network_transaction_id: Option<String>,
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
+    pub display_order: Option<i32>,
 }
 
 #[derive(
@@ -77,6 +78,7 @@ pub struct PaymentMethodNew {
     pub network_transaction_id: Option<String>,
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
+    pub display_order: Option<i32>,
 }
 
 #[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -114,6 +116,9 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    DisplayOrderUpdate {
+        display_order: Option<i32>,
+    },
 }
 
 #[derive(
@@ -131,6 +136,7 @@ pub struct PaymentMethodUpdateInternal {
     connector_mandate_details: Option<serde_json::Value>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
+    display_order: Option<i32>,
 }
 
 impl PaymentMethodUpdateInternal {
@@ -148,6 +154,7 @@ impl PaymentMethodUpdateInternal {
             network_transaction_id,
             status,
             connector_mandate_details,
+            display_order,
             ..
         } = self;
 
@@ -160,6 +167,7 @@ impl PaymentMethodUpdateInternal {
             status: status.unwrap_or(source.status),
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
+            display_order: display_order.map_or(source.display_order, Some),
             ..source
         }
     }
@@ -179,6 +187,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                display_order: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -193,6 +202,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                display_order: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -205,6 +215,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                display_order: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -220,6 +231,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                display_order: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -232,6 +244,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                display_order: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -251,6 +264,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer,
                 payment_method_type,
+                display_order: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -265,6 +279,20 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_transaction_id: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                display_order: None,
+            },
+            PaymentMethodUpdate::DisplayOrderUpdate { display_order } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                display_order,
             },
         }
     }
@@ -305,6 +333,7 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             payment_method_billing_address: payment_method_new
                 .payment_method_billing_address
                 .clone(),
+            display_order: payment_method_new.display_order,
         }
     }
 }
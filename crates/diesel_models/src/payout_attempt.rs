@@ -29,6 +29,7 @@ pub struct PayoutAttempt {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub cancellation_reason: Option<String>,
 }
 
 #[derive(
@@ -66,6 +67,7 @@ pub struct PayoutAttemptNew {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub cancellation_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +90,9 @@ pub enum PayoutAttemptUpdate {
         connector: String,
         routing_info: Option<serde_json::Value>,
     },
+    CancellationReasonUpdate {
+        cancellation_reason: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -103,6 +108,7 @@ pub struct PayoutAttemptUpdateInternal {
     pub business_label: Option<String>,
     pub connector: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub cancellation_reason: Option<String>,
     pub last_modified_at: PrimitiveDateTime,
 }
 
@@ -119,6 +125,7 @@ impl Default for PayoutAttemptUpdateInternal {
             business_label: None,
             connector: None,
             routing_info: None,
+            cancellation_reason: None,
             last_modified_at: common_utils::date_time::now(),
         }
     }
@@ -161,6 +168,12 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 routing_info,
                 ..Default::default()
             },
+            PayoutAttemptUpdate::CancellationReasonUpdate {
+                cancellation_reason,
+            } => Self {
+                cancellation_reason,
+                ..Default::default()
+            },
         }
     }
 }
@@ -178,6 +191,7 @@ impl PayoutAttemptUpdate {
             business_label,
             connector,
             routing_info,
+            cancellation_reason,
             last_modified_at,
         } = self.into();
         PayoutAttempt {
@@ -191,6 +205,7 @@ impl PayoutAttemptUpdate {
             business_label: business_label.or(source.business_label),
             connector: connector.or(source.connector),
             routing_info: routing_info.or(source.routing_info),
+            cancellation_reason: cancellation_reason.or(source.cancellation_reason),
             last_modified_at,
             ..source
         }
@@ -210,6 +210,12 @@ pub enum ProcessTrackerRunner {
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
+    PaymentRejectNotificationWorkflow,
+    PaymentAutoVoidWorkflow,
+    ConnectorHealthProbeWorkflow,
+    BlocklistEntryExpiryWorkflow,
+    PaymentDeferredCaptureWorkflow,
+    ApplepayDomainReverificationWorkflow,
 }
 
 #[cfg(test)]
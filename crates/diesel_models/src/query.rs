@@ -31,6 +31,7 @@ pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
+pub mod recon_entry;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
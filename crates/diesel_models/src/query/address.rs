@@ -104,6 +104,42 @@ impl Address {
         .await
     }
 
+    /// Addresses saved for the customer, excluding the addresses of the customer's payments
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::payment_id.is_null()),
+            None,
+            None,
+            Some(dsl::created_at),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_customer_id_address_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        address_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::address_id.eq(address_id.to_owned()))
+                .and(dsl::payment_id.is_null()),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id_payment_id_address_id<'a>(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -1,8 +1,32 @@
-use diesel::associations::HasTable;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable,
+    debug_query,
+    dsl::sql,
+    pg::Pg,
+    sql_function,
+    sql_types::{Bool, Nullable, SingleValue},
+    upsert::excluded,
+    ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
 
-use crate::{cards_info::CardInfo, query::generics, PgPooledConn, StorageResult};
+use crate::{
+    cards_info::{CardInfo, CardInfoUpdateInternal},
+    errors,
+    query::generics,
+    schema::cards_info::dsl,
+    PgPooledConn, StorageResult,
+};
+
+sql_function!(fn coalesce<ST: SingleValue>(x: Nullable<ST>, y: Nullable<ST>) -> Nullable<ST>);
 
 impl CardInfo {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Self> {
+        generics::generic_insert(conn, self).await
+    }
+
     pub async fn find_by_iin(conn: &PgPooledConn, card_iin: &str) -> StorageResult<Option<Self>> {
         generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
             conn,
@@ -10,4 +34,58 @@ impl CardInfo {
         )
         .await
     }
+
+    pub async fn update_by_iin(
+        conn: &PgPooledConn,
+        card_iin: &str,
+        card_info_update: CardInfoUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            card_iin.to_owned(),
+            card_info_update,
+        )
+        .await
+    }
+
+    /// Inserts the card information of all the bins in a single statement. The stored card
+    /// information of a bin that already exists is updated with the fields that are set, the same
+    /// way as `update_by_iin`. Returns whether each row was inserted, rather than updated.
+    pub async fn bulk_upsert(
+        conn: &PgPooledConn,
+        card_info_list: Vec<Self>,
+    ) -> StorageResult<Vec<bool>> {
+        let query = diesel::insert_into(<Self>::table())
+            .values(card_info_list)
+            .on_conflict(dsl::card_iin)
+            .do_update()
+            .set((
+                dsl::card_issuer.eq(coalesce(excluded(dsl::card_issuer), dsl::card_issuer)),
+                dsl::card_network.eq(coalesce(excluded(dsl::card_network), dsl::card_network)),
+                dsl::card_type.eq(coalesce(excluded(dsl::card_type), dsl::card_type)),
+                dsl::card_subtype.eq(coalesce(excluded(dsl::card_subtype), dsl::card_subtype)),
+                dsl::card_issuing_country.eq(coalesce(
+                    excluded(dsl::card_issuing_country),
+                    dsl::card_issuing_country,
+                )),
+                dsl::bank_code_id.eq(coalesce(excluded(dsl::bank_code_id), dsl::bank_code_id)),
+                dsl::bank_code.eq(coalesce(excluded(dsl::bank_code), dsl::bank_code)),
+                dsl::country_code.eq(coalesce(excluded(dsl::country_code), dsl::country_code)),
+                dsl::last_updated.eq(coalesce(excluded(dsl::last_updated), dsl::last_updated)),
+                dsl::last_updated_provider.eq(coalesce(
+                    excluded(dsl::last_updated_provider),
+                    dsl::last_updated_provider,
+                )),
+            ))
+            // `xmax` of a row is only set when an existing row was updated by the statement
+            .returning(sql::<Bool>("xmax = 0"));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while upserting card info")
+    }
 }
@@ -4,6 +4,7 @@ use diesel::{
 
 use super::generics;
 use crate::{
+    enums as storage_enums,
     events::{Event, EventNew, EventUpdateInternal},
     schema::events::dsl,
     PgPooledConn, StorageResult,
@@ -56,6 +57,7 @@ impl Event {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -83,6 +85,10 @@ impl Event {
             query = query.filter(dsl::created_at.le(created_before));
         }
 
+        if let Some(delivery_status) = delivery_status {
+            query = filter_by_delivery_status(query, delivery_status);
+        }
+
         if let Some(limit) = limit {
             query = query.limit(limit);
         }
@@ -142,6 +148,7 @@ impl Event {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -169,6 +176,10 @@ impl Event {
             query = query.filter(dsl::created_at.le(created_before));
         }
 
+        if let Some(delivery_status) = delivery_status {
+            query = filter_by_delivery_status(query, delivery_status);
+        }
+
         if let Some(limit) = limit {
             query = query.limit(limit);
         }
@@ -223,3 +234,28 @@ impl Event {
         .await
     }
 }
+
+/// Filters the initial attempts of events by the delivery status of their webhook. An event whose
+/// webhook was not notified is pending until a response is recorded for it, and failed after.
+fn filter_by_delivery_status(
+    query: crate::schema::events::BoxedQuery<'_, diesel::pg::Pg>,
+    delivery_status: storage_enums::WebhookDeliveryStatus,
+) -> crate::schema::events::BoxedQuery<'_, diesel::pg::Pg> {
+    use diesel::QueryDsl;
+
+    match delivery_status {
+        storage_enums::WebhookDeliveryStatus::Delivered => {
+            query.filter(dsl::is_webhook_notified.eq(true))
+        }
+        storage_enums::WebhookDeliveryStatus::Failed => query.filter(
+            dsl::is_webhook_notified
+                .eq(false)
+                .and(dsl::response.is_not_null()),
+        ),
+        storage_enums::WebhookDeliveryStatus::Pending => query.filter(
+            dsl::is_webhook_notified
+                .eq(false)
+                .and(dsl::response.is_null()),
+        ),
+    }
+}
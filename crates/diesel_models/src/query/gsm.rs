@@ -32,6 +32,29 @@ impl GatewayStatusMap {
         .await
     }
 
+    pub async fn find_all_by_messages(
+        conn: &PgPooledConn,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        messages: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::connector
+                .eq(connector)
+                .and(dsl::flow.eq(flow))
+                .and(dsl::sub_flow.eq(sub_flow))
+                .and(dsl::code.eq(code))
+                .and(dsl::message.eq_any(messages)),
+            None,
+            None,
+            Some(dsl::priority.desc()),
+        )
+        .await
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
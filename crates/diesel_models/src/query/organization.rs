@@ -1,9 +1,15 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, sql_types, ExpressionMethods, Table};
+use error_stack::ResultExt;
 
 use crate::{
-    organization::*, query::generics, schema::organization::dsl, PgPooledConn, StorageResult,
+    errors, organization::*, query::generics, schema::organization::dsl, PgPooledConn,
+    StorageResult,
 };
 
+/// Key of the advisory lock serializing changes to the organization hierarchy
+const ORGANIZATION_HIERARCHY_LOCK_KEY: i64 = 0x6f72_675f_7472_6565;
+
 impl OrganizationNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Organization> {
         generics::generic_insert(conn, self).await
@@ -16,6 +22,39 @@ impl Organization {
             .await
     }
 
+    pub async fn find_by_parent_organization_id(
+        conn: &PgPooledConn,
+        parent_organization_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::parent_organization_id.eq(parent_organization_id.to_owned()),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Takes the advisory lock serializing changes to the organization hierarchy, held until the
+    /// end of the transaction on `conn`. Under read committed isolation, checking the ancestors of
+    /// an organization with plain reads lets two concurrent changes each pass the cycle check
+    /// (e.g. one setting `A.parent = B` and the other `B.parent = A`), which the lock prevents.
+    pub async fn lock_hierarchy(conn: &PgPooledConn) -> StorageResult<()> {
+        diesel::sql_query("SELECT pg_advisory_xact_lock($1)")
+            .bind::<sql_types::BigInt, _>(ORGANIZATION_HIERARCHY_LOCK_KEY)
+            .execute_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while locking the organization hierarchy")?;
+        Ok(())
+    }
+
     pub async fn update_by_org_id(
         conn: &PgPooledConn,
         org_id: String,
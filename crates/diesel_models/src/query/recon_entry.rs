@@ -0,0 +1,59 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, Table,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics;
+use crate::{
+    errors,
+    recon_entry::{ReconEntry, ReconEntryNew, ReconEntryUpdateInternal},
+    schema::recon_entry::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReconEntryNew {
+    /// Inserts the recon entry, or updates the existing entry of the same merchant, connector and
+    /// entry id in the same statement
+    pub async fn upsert(self, conn: &PgPooledConn) -> StorageResult<ReconEntry> {
+        let recon_entry_update = ReconEntryUpdateInternal::from(&self);
+        let query = diesel::insert_into(<ReconEntry>::table())
+            .values(self)
+            .on_conflict((dsl::merchant_id, dsl::connector, dsl::entry_id))
+            .do_update()
+            .set(recon_entry_update);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        query
+            .get_result_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while upserting recon entry")
+    }
+}
+
+impl ReconEntry {
+    pub async fn filter_by_merchant_id_entry_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        entry_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::entry_id.eq_any(entry_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+}
@@ -53,6 +53,20 @@ impl Refund {
         .await
     }
 
+    pub async fn find_by_merchant_id_merchant_refund_reference_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        merchant_refund_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_refund_reference_id.eq(merchant_refund_reference_id.to_owned())),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id_connector_refund_id_connector(
         conn: &PgPooledConn,
         merchant_id: &str,
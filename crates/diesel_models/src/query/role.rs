@@ -1,7 +1,15 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{
+    associations::HasTable,
+    dsl::{exists, not},
+    BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
 
 use crate::{
-    enums::RoleScope, query::generics, role::*, schema::roles::dsl, PgPooledConn, StorageResult,
+    enums::RoleScope,
+    query::generics,
+    role::*,
+    schema::{roles::dsl, user_roles},
+    PgPooledConn, StorageResult,
 };
 
 impl RoleNew {
@@ -62,6 +70,21 @@ impl Role {
         .await
     }
 
+    /// Deletes the role only when it is not assigned to any user. The check is part of the delete
+    /// statement, so the role cannot be assigned between the check and the delete.
+    pub async fn delete_unassigned_by_role_id(
+        conn: &PgPooledConn,
+        role_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::role_id.eq(role_id.to_owned()).and(not(exists(
+                user_roles::table.filter(user_roles::role_id.eq(role_id.to_owned())),
+            ))),
+        )
+        .await
+    }
+
     pub async fn list_roles(
         conn: &PgPooledConn,
         merchant_id: &str,
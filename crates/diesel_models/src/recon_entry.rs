@@ -0,0 +1,76 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::recon_entry};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = recon_entry)]
+pub struct ReconEntry {
+    pub id: i32,
+    pub merchant_id: String,
+    pub connector: String,
+    pub entry_id: String,
+    pub status: enums::ReconEntryStatus,
+    pub note: Option<String>,
+    pub resolved_by: Option<String>,
+    /// Recorded amount of the mismatch at the time it was resolved
+    pub recorded_amount: Option<i64>,
+    /// Settled amount of the mismatch at the time it was resolved
+    pub settled_amount: Option<i64>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_entry)]
+pub struct ReconEntryNew {
+    pub merchant_id: String,
+    pub connector: String,
+    pub entry_id: String,
+    pub status: enums::ReconEntryStatus,
+    pub note: Option<String>,
+    pub resolved_by: Option<String>,
+    pub recorded_amount: Option<i64>,
+    pub settled_amount: Option<i64>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Changes applied to an existing recon entry when it is resolved again
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_entry, treat_none_as_null = true)]
+pub struct ReconEntryUpdateInternal {
+    pub status: enums::ReconEntryStatus,
+    pub note: Option<String>,
+    pub resolved_by: Option<String>,
+    pub recorded_amount: Option<i64>,
+    pub settled_amount: Option<i64>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<&ReconEntryNew> for ReconEntryUpdateInternal {
+    fn from(recon_entry: &ReconEntryNew) -> Self {
+        Self {
+            status: recon_entry.status,
+            note: recon_entry.note.clone(),
+            resolved_by: recon_entry.resolved_by.clone(),
+            recorded_amount: recon_entry.recorded_amount,
+            settled_amount: recon_entry.settled_amount,
+            modified_at: recon_entry.modified_at,
+        }
+    }
+}
+
+impl ReconEntryUpdateInternal {
+    pub fn apply_changeset(self, source: ReconEntry) -> ReconEntry {
+        ReconEntry {
+            status: self.status,
+            note: self.note,
+            resolved_by: self.resolved_by,
+            recorded_amount: self.recorded_amount,
+            settled_amount: self.settled_amount,
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
@@ -55,6 +55,7 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permissions -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -131,6 +132,7 @@ diesel::table! {
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -196,6 +198,7 @@ diesel::table! {
         is_connector_agnostic_mit_enabled -> Nullable<Bool>,
         use_billing_as_payment_method_billing -> Nullable<Bool>,
         collect_shipping_details_from_wallet_connector -> Nullable<Bool>,
+        dispute_auto_accept_threshold -> Nullable<Jsonb>,
     }
 }
 
@@ -362,6 +365,7 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         dispute_amount -> Int8,
+        auto_accepted -> Nullable<Bool>,
     }
 }
 
@@ -484,6 +488,7 @@ diesel::table! {
         unified_code -> Nullable<Varchar>,
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
+        priority -> Int4,
     }
 }
 
@@ -590,6 +595,10 @@ diesel::table! {
         original_payment_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        max_total_amount -> Nullable<Int8>,
+        max_debit_count -> Nullable<Int4>,
+        debit_count -> Int4,
+        debited_amount -> Int8,
     }
 }
 
@@ -671,6 +680,9 @@ diesel::table! {
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
         pm_auth_config -> Nullable<Jsonb>,
         status -> ConnectorStatus,
+        connector_wallets_details -> Nullable<Bytea>,
+        applepay_verified_at -> Nullable<Timestamp>,
+        applepay_verification_expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -694,6 +706,8 @@ diesel::table! {
         #[max_length = 32]
         org_id -> Varchar,
         org_name -> Nullable<Text>,
+        #[max_length = 32]
+        parent_organization_id -> Nullable<Varchar>,
     }
 }
 
@@ -778,6 +792,8 @@ diesel::table! {
         fingerprint_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_method_billing_address_id -> Nullable<Varchar>,
+        connector_latency -> Nullable<Int8>,
+        authorized_at -> Nullable<Timestamp>,
     }
 }
 
@@ -928,6 +944,7 @@ diesel::table! {
         #[max_length = 128]
         client_secret -> Nullable<Varchar>,
         payment_method_billing_address -> Nullable<Bytea>,
+        display_order -> Nullable<Int4>,
     }
 }
 
@@ -967,6 +984,8 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         routing_info -> Nullable<Jsonb>,
+        #[max_length = 255]
+        cancellation_reason -> Nullable<Varchar>,
     }
 }
 
@@ -1034,6 +1053,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_entry (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 255]
+        entry_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        note -> Nullable<Text>,
+        #[max_length = 64]
+        resolved_by -> Nullable<Varchar>,
+        recorded_amount -> Nullable<Int8>,
+        settled_amount -> Nullable<Int8>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1081,6 +1124,8 @@ diesel::table! {
         updated_by -> Varchar,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_refund_reference_id -> Nullable<Varchar>,
     }
 }
 
@@ -1244,6 +1289,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     payout_attempt,
     payouts,
     process_tracker,
+    recon_entry,
     refund,
     reverse_lookup,
     roles,
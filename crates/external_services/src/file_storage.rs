@@ -2,7 +2,10 @@
 //! Module for managing file storage operations with support for multiple storage schemes.
 //!
 
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
 
 use common_utils::errors::CustomResult;
 
@@ -63,6 +66,14 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
 
     /// Retrieves a file from the selected storage scheme.
     async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    /// Generates a URL granting temporary read access to a single file, valid for `expires_in`.
+    /// Returns `None` if the selected storage scheme does not support presigned URLs.
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<Option<String>, FileStorageError>;
 }
 
 dyn_clone::clone_trait_object!(FileStorageInterface);
@@ -93,4 +104,8 @@ pub enum FileStorageError {
     /// Indicates that the file deletion operation failed.
     #[error("Failed to delete file")]
     DeleteFailed,
+
+    /// Indicates that the presigned URL generation failed.
+    #[error("Failed to generate presigned URL")]
+    PresignedUrlGenerationFailed,
 }
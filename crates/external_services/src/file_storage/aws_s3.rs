@@ -1,8 +1,11 @@
+use std::time::Duration;
+
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
     operation::{
         delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
     },
+    presigning::{PresigningConfig, PresigningConfigError},
     Client,
 };
 use aws_sdk_sts::config::Region;
@@ -104,6 +107,26 @@ impl AwsFileStorageClient {
             .map_err(AwsS3StorageError::UnknownError)?
             .to_vec())
     }
+
+    /// Generates a presigned URL to download a single file from AWS S3.
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, AwsS3StorageError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(AwsS3StorageError::InvalidPresigningConfig)?;
+        Ok(self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .presigned(presigning_config)
+            .await
+            .map_err(AwsS3StorageError::PresignFailure)?
+            .uri()
+            .to_string())
+    }
 }
 
 #[async_trait::async_trait]
@@ -135,6 +158,19 @@ impl FileStorageInterface for AwsFileStorageClient {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Generates a presigned URL to download a single file from AWS S3.
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<Option<String>, FileStorageError> {
+        Ok(Some(
+            self.get_presigned_url(file_key, expires_in)
+                .await
+                .change_context(FileStorageError::PresignedUrlGenerationFailed)?,
+        ))
+    }
 }
 
 /// Enum representing errors that can occur during AWS S3 file storage operations.
@@ -152,7 +188,67 @@ enum AwsS3StorageError {
     #[error("File delete from S3 failed: {0:?}")]
     DeleteFailure(aws_smithy_client::SdkError<DeleteObjectError>),
 
+    /// Error indicating that the expiry provided for the presigned URL is invalid.
+    #[error("Invalid presigning config: {0:?}")]
+    InvalidPresigningConfig(PresigningConfigError),
+
+    /// Error indicating that presigning the file retrieval request failed.
+    #[error("Presigning file retrieve request failed: {0:?}")]
+    PresignFailure(aws_smithy_client::SdkError<GetObjectError>),
+
     /// Unknown error occurred.
     #[error("Unknown error occurred: {0:?}")]
     UnknownError(aws_sdk_s3::primitives::ByteStreamError),
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    async fn get_aws_file_storage_client() -> AwsFileStorageClient {
+        std::env::set_var("AWS_SECRET_ACCESS_KEY", "YOUR SECRET ACCESS KEY");
+        std::env::set_var("AWS_ACCESS_KEY_ID", "YOUR AWS ACCESS KEY ID");
+        let config = AwsFileStorageConfig {
+            region: "us-east-1".to_string(),
+            bucket_name: "hyperswitch-files".to_string(),
+        };
+        AwsFileStorageClient::new(&config).await
+    }
+
+    #[tokio::test]
+    async fn check_presigned_url_is_scoped_to_the_file() {
+        let presigned_url = get_aws_file_storage_client()
+            .await
+            .get_presigned_url("merchant_1/file_1", Duration::from_secs(300))
+            .await
+            .expect("presigned url generation failed");
+
+        assert!(presigned_url.contains("hyperswitch-files"));
+        assert!(presigned_url.contains("/merchant_1/file_1?"));
+        assert!(presigned_url.contains("X-Amz-Signature="));
+    }
+
+    #[tokio::test]
+    async fn check_presigned_url_expiry() {
+        let client = get_aws_file_storage_client().await;
+        for expires_in in [60, 900, 3600] {
+            let presigned_url = client
+                .get_presigned_url("merchant_1/file_1", Duration::from_secs(expires_in))
+                .await
+                .expect("presigned url generation failed");
+
+            assert!(presigned_url.contains(&format!("X-Amz-Expires={expires_in}&")));
+        }
+    }
+
+    #[tokio::test]
+    async fn check_presigned_url_expiry_beyond_a_week_is_rejected() {
+        let result = get_aws_file_storage_client()
+            .await
+            .get_presigned_url("merchant_1/file_1", Duration::from_secs(8 * 24 * 60 * 60))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -6,6 +6,7 @@ use std::{
     fs::{remove_file, File},
     io::{Read, Write},
     path::PathBuf,
+    time::Duration,
 };
 
 use common_utils::errors::CustomResult;
@@ -105,6 +106,16 @@ impl FileStorageInterface for FileSystem {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Files stored on the local file system cannot be accessed through a presigned URL,
+    /// they have to be served by the application instead.
+    async fn get_presigned_url(
+        &self,
+        _file_key: &str,
+        _expires_in: Duration,
+    ) -> CustomResult<Option<String>, FileStorageError> {
+        Ok(None)
+    }
 }
 
 /// Represents an error that can occur during local file system storage operations.
@@ -29,6 +29,8 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub max_total_amount: Option<i64>,
+    pub max_debit_count: Option<i32>,
 }
 
 // The fields on this struct are optional, as we want to allow the merchant to provide partial
@@ -89,6 +91,8 @@ impl From<ApiMandateAmountData> for MandateAmountData {
             start_date: value.start_date,
             end_date: value.end_date,
             metadata: value.metadata,
+            max_total_amount: value.max_total_amount,
+            max_debit_count: value.max_debit_count,
         }
     }
 }
@@ -166,6 +166,11 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    /// Time taken by the connector to respond to the latest request of the attempt, in milliseconds
+    pub connector_latency: Option<i64>,
+    /// Time at which the attempt was authorized with the connector
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttempt {
@@ -249,6 +254,8 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    /// Time taken by the connector to respond to the latest request of the attempt, in milliseconds
+    pub connector_latency: Option<i64>,
 }
 
 impl PaymentAttemptNew {
@@ -369,6 +376,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        connector_latency: Option<i64>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -380,6 +388,7 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         updated_by: String,
+        connector_latency: Option<i64>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -397,6 +406,7 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
+        connector_latency: Option<i64>,
     },
     CaptureUpdate {
         amount_to_capture: Option<i64>,
@@ -70,6 +70,7 @@ pub struct PayoutAttempt {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub cancellation_reason: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -93,6 +94,7 @@ pub struct PayoutAttemptNew {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub cancellation_reason: Option<String>,
 }
 
 impl Default for PayoutAttemptNew {
@@ -119,6 +121,7 @@ impl Default for PayoutAttemptNew {
             profile_id: String::default(),
             merchant_connector_id: None,
             routing_info: None,
+            cancellation_reason: None,
         }
     }
 }
@@ -143,6 +146,9 @@ pub enum PayoutAttemptUpdate {
         connector: String,
         routing_info: Option<serde_json::Value>,
     },
+    CancellationReasonUpdate {
+        cancellation_reason: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -157,6 +163,7 @@ pub struct PayoutAttemptUpdateInternal {
     pub business_label: Option<String>,
     pub connector: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub cancellation_reason: Option<String>,
 }
 
 impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
@@ -196,6 +203,12 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 routing_info,
                 ..Default::default()
             },
+            PayoutAttemptUpdate::CancellationReasonUpdate {
+                cancellation_reason,
+            } => Self {
+                cancellation_reason,
+                ..Default::default()
+            },
         }
     }
 }
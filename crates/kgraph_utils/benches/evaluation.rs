@@ -70,6 +70,7 @@ fn build_test_data<'a>(
         applepay_verified_domains: None,
         pm_auth_config: None,
         status: api_enums::ConnectorStatus::Inactive,
+        connector_wallets_details: None,
     };
 
     kgraph_utils::mca::make_mca_graph(vec![stripe_account]).expect("Failed graph construction")
@@ -53,6 +53,7 @@ Never share your secret api keys. Keep them guarded and secure.
     ),
     tags(
         (name = "Merchant Account", description = "Create and manage merchant accounts"),
+        (name = "Organization", description = "Manage organizations and their sub-organizations"),
         (name = "Business Profile", description = "Create and manage business profiles"),
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
@@ -86,6 +87,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
+        routes::refunds::refunds_eligibility,
         routes::refunds::refunds_list,
 
         // Routes for merchant account
@@ -95,6 +97,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
 
+        // Routes for organization
+        routes::organization::organization_parent_update,
+        routes::organization::organization_descendants_list,
+
         // Routes for merchant connector account
         routes::merchant_connector_account::payment_connector_create,
         routes::merchant_connector_account::payment_connector_retrieve,
@@ -105,12 +111,15 @@ Never share your secret api keys. Keep them guarded and secure.
         //Routes for gsm
         routes::gsm::create_gsm_rule,
         routes::gsm::get_gsm_rule,
+        routes::gsm::lookup_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
 
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
+        routes::mandates::pause_mandate,
+        routes::mandates::resume_mandate,
         routes::mandates::customers_mandates_list,
 
         //Routes for customers
@@ -152,15 +161,19 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_linked_config,
         routes::routing::routing_retrieve_default_config_for_profiles,
         routes::routing::routing_update_default_config_for_profile,
+        routes::routing::routing_evaluate_config,
 
         // Routes for blocklist
         routes::blocklist::remove_entry_from_blocklist,
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
+        routes::blocklist::bulk_add_entries_to_blocklist,
+        routes::blocklist::bulk_remove_entries_from_blocklist,
 
         // Routes for payouts
         routes::payouts::payouts_create,
+        routes::payouts::payouts_batch_create,
         routes::payouts::payouts_retrieve,
         routes::payouts::payouts_update,
         routes::payouts::payouts_cancel,
@@ -173,14 +186,17 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::api_key_rotate,
 
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::replay_webhook_event,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
+        routes::poll::retrieve_poll_progress,
     ),
     components(schemas(
         api_models::refunds::RefundRequest,
@@ -188,9 +204,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::RefundEligibilityRequest,
+        api_models::refunds::RefundEligibilityResponse,
+        api_models::refunds::RefundIneligibilityReason,
         api_models::admin::MerchantAccountCreate,
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
+        api_models::organization::OrganizationParentUpdateRequest,
+        api_models::organization::OrganizationResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::AuthenticationConnectorDetails,
@@ -212,6 +233,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::CardDetailUpdate,
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::poll::PollResponse,
+        api_models::poll::PollProgressResponse,
         api_models::poll::PollStatus,
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
@@ -222,6 +244,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
+        api_models::enums::ApiKeyPermission,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
@@ -249,6 +272,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethodStatus,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
+        api_models::admin::ConnectorWalletDetails,
+        api_models::admin::ApplePayPaymentProcessingDetails,
         api_models::admin::PrimaryBusinessDetails,
         api_models::admin::FrmConfigs,
         api_models::admin::FrmPaymentMethod,
@@ -266,11 +291,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
+        api_models::gsm::GsmLookupRequest,
         api_models::gsm::GsmUpdateRequest,
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmRetryReason,
+        api_models::gsm::GsmSuggestedAction,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -399,6 +427,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentAttemptResponse,
+        api_models::payments::AttemptSurchargeBreakdown,
+        api_models::payments::PaymentRefundSummary,
+        api_models::payments::PaymentRefundState,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::IncrementalAuthorizationResponse,
@@ -447,6 +478,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutActionRequest,
         api_models::payouts::PayoutCreateRequest,
         api_models::payouts::PayoutCreateResponse,
+        api_models::payouts::PayoutEligibilityDetails,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchCreateResponse,
+        api_models::payouts::PayoutBatchItemResult,
+        api_models::payouts::PayoutBatchItemStatus,
         api_models::payouts::PayoutListConstraints,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
@@ -471,11 +507,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookRetrySchedule,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
@@ -491,6 +530,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ProfileDefaultRoutingConfig,
         api_models::routing::MerchantRoutingAlgorithm,
         api_models::routing::RoutingAlgorithmKind,
+        api_models::routing::RoutingExplanation,
+        api_models::routing::RoutingDecisionSource,
+        api_models::routing::RoutingEvaluateRequest,
+        api_models::routing::RoutingEvaluateResponse,
         api_models::routing::RoutingDictionary,
         api_models::routing::RoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
@@ -509,15 +552,22 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
+        api_models::blocklist::BulkBlocklistRequest,
+        api_models::blocklist::BulkBlocklistResponse,
+        api_models::blocklist::BulkBlocklistEntryResult,
+        api_models::blocklist::BulkBlocklistEntryStatus,
         api_models::enums::BlocklistDataKind,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::WebhookDeliveryStatus,
+        api_models::enums::OutgoingWebhookSignatureAlgorithm,
     )),
     modifiers(&SecurityAddon)
 )]
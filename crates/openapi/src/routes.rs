@@ -9,6 +9,7 @@ pub mod gsm;
 pub mod mandates;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payments;
@@ -17,7 +17,7 @@ pub async fn toggle_blocklist_guard() {}
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -42,6 +42,34 @@ pub async fn add_entry_to_blocklist() {}
 )]
 pub async fn remove_entry_from_blocklist() {}
 
+#[utoipa::path(
+    post,
+    path = "/blocklist/bulk",
+    request_body = BulkBlocklistRequest,
+    responses(
+        (status = 200, description = "Outcome of blocking each entry", body = BulkBlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Block Fingerprints in bulk",
+    security(("api_key" = []))
+)]
+pub async fn bulk_add_entries_to_blocklist() {}
+
+#[utoipa::path(
+    delete,
+    path = "/blocklist/bulk",
+    request_body = BulkBlocklistRequest,
+    responses(
+        (status = 200, description = "Outcome of unblocking each entry", body = BulkBlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Unblock Fingerprints in bulk",
+    security(("api_key" = []))
+)]
+pub async fn bulk_remove_entries_from_blocklist() {}
+
 #[utoipa::path(
     get,
     path = "/blocklist",
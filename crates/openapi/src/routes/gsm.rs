@@ -36,6 +36,26 @@ pub async fn create_gsm_rule() {}
 )]
 pub async fn get_gsm_rule() {}
 
+/// Gsm - Lookup
+///
+/// Finds the Gsm Rule that a connector error code and message map to during a payments flow. The rule configured with the `*` message for the error code is used when no rule matches the message exactly
+#[utoipa::path(
+    post,
+    path = "/gsm/lookup",
+    request_body(
+        content = GsmLookupRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm retrieved", body = GsmResponse),
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 404, description = "No Gsm Rule matches the error code and message")
+    ),
+    tag = "Gsm",
+    operation_id = "Lookup Gsm Rule",
+    security(("admin_api_key" = [])),
+)]
+pub async fn lookup_gsm_rule() {}
+
 /// Gsm - Update
 ///
 /// Updates a Gsm Rule
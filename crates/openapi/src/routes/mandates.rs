@@ -36,6 +36,46 @@ pub async fn get_mandate() {}
 )]
 pub async fn revoke_mandate() {}
 
+/// Mandates - Pause Mandate
+///
+/// Pauses an active mandate, payments with a paused mandate are rejected until it is resumed
+#[utoipa::path(
+    post,
+    path = "/mandates/pause/{mandate_id}",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    responses(
+        (status = 200, description = "The mandate was paused successfully", body = MandateResponse),
+        (status = 400, description = "Mandate cannot be paused"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Pause a Mandate",
+    security(("api_key" = []))
+)]
+pub async fn pause_mandate() {}
+
+/// Mandates - Resume Mandate
+///
+/// Resumes a paused mandate, making it active again
+#[utoipa::path(
+    post,
+    path = "/mandates/resume/{mandate_id}",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    responses(
+        (status = 200, description = "The mandate was resumed successfully", body = MandateResponse),
+        (status = 400, description = "Mandate cannot be resumed"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Resume a Mandate",
+    security(("api_key" = []))
+)]
+pub async fn resume_mandate() {}
+
 /// Mandates - List Mandates
 #[utoipa::path(
     get,
@@ -62,10 +102,16 @@ pub async fn retrieve_mandates_list() {}
 
 /// Mandates - Customer Mandates List
 ///
-/// Lists all the mandates for a particular customer id.
+/// Lists the mandates for a particular customer id, optionally filtered by the mandate status.
 #[utoipa::path(
     post,
     path = "/customers/{customer_id}/mandates",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the customer"),
+        ("status" = Option<MandateStatus>, Query, description = "The status of the mandates to list, all statuses are listed if not provided"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of Mandate Objects to include in the response"),
+        ("offset" = Option<u32>, Query, description = "The number of Mandate Objects to skip"),
+    ),
     responses(
         (status = 200, description = "List of retrieved mandates for a customer", body = Vec<MandateResponse>),
         (status = 400, description = "Invalid Data"),
@@ -0,0 +1,36 @@
+/// Organization - Update Parent
+///
+/// Make the organization a sub-organization of another organization. An organization cannot be
+/// made a sub-organization of one of its own sub-organizations.
+#[utoipa::path(
+    post,
+    path = "/organization/{org_id}/parent",
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    request_body = OrganizationParentUpdateRequest,
+    responses(
+        (status = 200, description = "Organization Updated", body = OrganizationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Update the parent of an Organization",
+    security(("admin_api_key" = []))
+)]
+pub async fn organization_parent_update() {}
+
+/// Organization - List Descendants
+///
+/// List all the sub-organizations under the organization, including the nested ones.
+#[utoipa::path(
+    get,
+    path = "/organization/{org_id}/descendants",
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Sub-organizations retrieved", body = Vec<OrganizationResponse>),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "List the descendants of an Organization",
+    security(("admin_api_key" = []))
+)]
+pub async fn organization_descendants_list() {}
@@ -13,6 +13,24 @@
 )]
 pub async fn payouts_create() {}
 
+/// Payouts - Batch Create
+///
+/// Creates the payouts of a batch, each payout is processed independently and the outcome of
+/// every payout is returned in the order they were provided.
+#[utoipa::path(
+    post,
+    path = "/payouts/batch/create",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payouts of the batch processed", body = PayoutBatchCreateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a batch of Payouts",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_create() {}
+
 /// Payouts - Retrieve
 #[utoipa::path(
     get,
@@ -14,3 +14,19 @@
     security(("publishable_key" = []))
 )]
 pub async fn retrieve_poll_status() {}
+
+/// Poll - Retrieve Poll Progress
+#[utoipa::path(
+    get,
+    path = "/poll/progress/{payment_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "The poll progress was retrieved successfully", body = PollProgressResponse)
+    ),
+    tag = "Poll",
+    operation_id = "Retrieve Poll Progress",
+    security(("publishable_key" = []))
+)]
+pub async fn retrieve_poll_progress() {}
@@ -112,6 +112,23 @@ pub async fn refunds_retrieve_with_body() {}
 )]
 pub async fn refunds_update() {}
 
+/// Refunds - Eligibility
+///
+/// To check whether a refund can be initiated for a payment and the amount that can still be refunded, without creating a refund
+#[utoipa::path(
+    post,
+    path = "/refunds/eligibility",
+    request_body=RefundEligibilityRequest,
+    responses(
+        (status = 200, description = "Refund eligibility of the payment", body = RefundEligibilityResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Check Refund Eligibility",
+    security(("api_key" = []))
+)]
+pub async fn refunds_eligibility() {}
+
 /// Refunds - List
 ///
 /// Lists all the refunds associated with the merchant or a payment_id if payment_id is not provided
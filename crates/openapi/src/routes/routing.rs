@@ -200,3 +200,23 @@ pub async fn routing_retrieve_default_config_for_profiles() {}
    security(("api_key" = []), ("jwt_key" = []))
 )]
 pub async fn routing_update_default_config_for_profile() {}
+
+/// Routing - Evaluate
+///
+/// Evaluate the active routing config against a sample payment, without creating a payment
+#[utoipa::path(
+    post,
+    path = "/routing/evaluate",
+    request_body = RoutingEvaluateRequest,
+    responses(
+        (status = 200, description = "Routing config evaluated", body = RoutingEvaluateResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Evaluate the routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_evaluate_config() {}
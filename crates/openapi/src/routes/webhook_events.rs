@@ -14,31 +14,37 @@
             "created_after" = Option<PrimitiveDateTime>,
             Query,
             description = "Only include Events created after the specified time. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
         ),
         (
             "created_before" = Option<PrimitiveDateTime>,
             Query,
             description = "Only include Events created before the specified time. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
         ),
         (
             "limit" = Option<i64>,
             Query,
             description = "The maximum number of Events to include in the response. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
         ),
         (
             "offset" = Option<i64>,
             Query,
             description = "The number of Events to skip when retrieving the list of Events.
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
         ),
         (
             "object_id" = Option<String>,
             Query,
             description = "Only include Events associated with the specified object (Payment Intent ID, Refund ID, etc.). \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
+        ),
+        (
+            "delivery_status" = Option<WebhookDeliveryStatus>,
+            Query,
+            description = "Only include Events with the specified delivery status. \
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
         ),
     ),
     responses(
@@ -92,3 +98,29 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Replay
+///
+/// Replay the specified Event to the merchant's webhook endpoint, signed with the current signing key.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id_or_profile_id}/{event_id}/replay",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The Event was replayed. \
+                           Check the `response` field in the response payload to identify the status of the delivery attempt.",
+            body = EventRetrieveResponse
+        ),
+        (status = 404, description = "The Event does not exist"),
+        (status = 400, description = "The webhook endpoint is not configured"),
+    ),
+    tag = "Event",
+    operation_id = "Replay an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_webhook_event() {}
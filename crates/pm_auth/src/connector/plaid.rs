@@ -15,7 +15,9 @@ use crate::{
     types::{
         self as auth_types,
         api::{
-            auth_service::{self, BankAccountCredentials, ExchangeToken, LinkToken},
+            auth_service::{
+                self, BankAccountCredentials, ConnectionStatus, ExchangeToken, LinkToken,
+            },
             ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
         },
     },
@@ -71,6 +73,10 @@ impl ConnectorCommon for Plaid {
         ])
     }
 
+    fn is_reauthentication_required(&self, error: &auth_types::ErrorResponse) -> bool {
+        plaid::ITEM_EXPIRED_ERROR_CODES.contains(&error.code.as_str())
+    }
+
     fn build_error_response(
         &self,
         res: auth_types::Response,
@@ -81,7 +87,9 @@ impl ConnectorCommon for Plaid {
                 .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(auth_types::ErrorResponse {
             status_code: res.status_code,
-            code: crate::consts::NO_ERROR_CODE.to_string(),
+            code: response
+                .error_code
+                .unwrap_or(crate::consts::NO_ERROR_CODE.to_string()),
             message: response.error_message,
             reason: response.display_message,
         })
@@ -338,3 +346,127 @@ impl
         self.build_error_response(res)
     }
 }
+
+impl auth_service::AuthServiceConnectionStatus for Plaid {}
+
+impl
+    ConnectorIntegration<
+        ConnectionStatus,
+        auth_types::ConnectionStatusRequest,
+        auth_types::ConnectionStatusResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::ConnectionStatusRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::ConnectionStatusRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "/item/get"))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::ConnectionStatusRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidItemGetRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::ConnectionStatusRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthConnectionStatusType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthConnectionStatusType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(auth_types::PaymentAuthConnectionStatusType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::ConnectionStatusRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ConnectionStatusRouterData, errors::ConnectorError> {
+        let response: plaid::PlaidItemGetResponse = res
+            .response
+            .parse_struct("PlaidItemGetResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::ConnectionStatusRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_error_response(body: serde_json::Value) -> auth_types::ErrorResponse {
+        Plaid
+            .build_error_response(auth_types::Response {
+                headers: None,
+                response: bytes::Bytes::from(body.to_string()),
+                status_code: 400,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_expired_item_requires_reauthentication() {
+        let error = get_error_response(serde_json::json!({
+            "error_type": "ITEM_ERROR",
+            "error_code": "ITEM_LOGIN_REQUIRED",
+            "error_message": "the login details of this item have changed (credentials, MFA, or required user action) and a user login is required to update this information",
+            "display_message": null,
+            "request_id": "HNTDNrA8F1shFEW"
+        }));
+
+        assert_eq!(error.code, "ITEM_LOGIN_REQUIRED");
+        assert!(Plaid.is_reauthentication_required(&error));
+    }
+
+    #[test]
+    fn test_other_errors_do_not_require_reauthentication() {
+        let error = get_error_response(serde_json::json!({
+            "error_type": "INSTITUTION_ERROR",
+            "error_code": "INSTITUTION_DOWN",
+            "error_message": "this institution is not currently responding to this request",
+            "display_message": null,
+            "request_id": "HNTDNrA8F1shFEW"
+        }));
+
+        assert_eq!(error.code, "INSTITUTION_DOWN");
+        assert!(!Plaid.is_reauthentication_required(&error));
+    }
+}
@@ -1,19 +1,28 @@
 use std::collections::HashMap;
 
+use api_models::pm_auth::PaymentMethodAuthConnectionStatus;
 use common_enums::{PaymentMethod, PaymentMethodType};
 use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::{core::errors, types};
 
+/// Item errors that are resolved by the customer going through Link again in update mode
+pub const ITEM_EXPIRED_ERROR_CODES: [&str; 2] = ["ITEM_LOGIN_REQUIRED", "ITEM_LOCKED"];
+
 #[derive(Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct PlaidLinkTokenRequest {
     client_name: String,
     country_codes: Vec<String>,
     language: String,
+    // Products must not be passed when the link token is created in update mode
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     products: Vec<String>,
     user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_token: Option<String>,
 }
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
@@ -33,7 +42,10 @@ impl TryFrom<&types::LinkTokenRouterData> for PlaidLinkTokenRequest {
                 },
             )?,
             language: item.request.language.clone().unwrap_or("en".to_string()),
-            products: vec!["auth".to_string()],
+            products: match item.request.access_token {
+                Some(_) => Vec::new(),
+                None => vec!["auth".to_string()],
+            },
             user: User {
                 client_user_id: item.request.user_info.clone().ok_or(
                     errors::ConnectorError::MissingRequiredField {
@@ -41,6 +53,11 @@ impl TryFrom<&types::LinkTokenRouterData> for PlaidLinkTokenRequest {
                     },
                 )?,
             },
+            access_token: item
+                .request
+                .access_token
+                .as_ref()
+                .map(|access_token| access_token.peek().to_string()),
         })
     }
 }
@@ -330,6 +347,113 @@ impl<F, T>
         })
     }
 }
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PlaidItemGetRequest {
+    access_token: String,
+}
+
+impl TryFrom<&types::ConnectionStatusRouterData> for PlaidItemGetRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectionStatusRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            access_token: item.request.access_token.peek().to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidItemGetResponse {
+    pub item: PlaidItem,
+    pub status: Option<PlaidItemStatus>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidItem {
+    pub item_id: String,
+    pub error: Option<PlaidErrorResponse>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub consent_expiration_time: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidItemStatus {
+    pub transactions: Option<PlaidProductStatus>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidProductStatus {
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_successful_update: Option<PrimitiveDateTime>,
+}
+
+impl PlaidItem {
+    /// Item errors after which the item can no longer be used and has to be linked afresh
+    const REVOKED_ERROR_CODES: [&'static str; 2] =
+        ["USER_PERMISSION_REVOKED", "ACCESS_NOT_GRANTED"];
+
+    /// Maps the item to the unified connection status. Any other item error, such as the
+    /// institution being down, is transient and leaves the connection active.
+    pub fn get_connection_status(
+        &self,
+        now: PrimitiveDateTime,
+    ) -> PaymentMethodAuthConnectionStatus {
+        let error_code = self
+            .error
+            .as_ref()
+            .and_then(|error| error.error_code.as_deref());
+        match error_code {
+            Some(code) if Self::REVOKED_ERROR_CODES.contains(&code) => {
+                PaymentMethodAuthConnectionStatus::Revoked
+            }
+            Some(code) if ITEM_EXPIRED_ERROR_CODES.contains(&code) => {
+                PaymentMethodAuthConnectionStatus::Expired
+            }
+            _ if self
+                .consent_expiration_time
+                .is_some_and(|consent_expiration_time| consent_expiration_time <= now) =>
+            {
+                PaymentMethodAuthConnectionStatus::Expired
+            }
+            _ => PaymentMethodAuthConnectionStatus::Active,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PlaidItemGetResponse, T, types::ConnectionStatusResponse>>
+    for types::PaymentAuthRouterData<F, T, types::ConnectionStatusResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidItemGetResponse,
+            T,
+            types::ConnectionStatusResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = item
+            .response
+            .item
+            .get_connection_status(common_utils::date_time::now());
+        let last_refreshed_at = item
+            .response
+            .status
+            .and_then(|status| status.transactions)
+            .and_then(|transactions| transactions.last_successful_update);
+
+        Ok(Self {
+            response: Ok(types::ConnectionStatusResponse {
+                status,
+                last_refreshed_at,
+            }),
+            ..item.data
+        })
+    }
+}
+
 pub struct PlaidAuthType {
     pub client_id: Secret<String>,
     pub secret: Secret<String>,
@@ -356,3 +480,121 @@ pub struct PlaidErrorResponse {
     pub error_message: String,
     pub error_type: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_item_get_response(item: serde_json::Value) -> PlaidItemGetResponse {
+        serde_json::from_value(serde_json::json!({
+            "item": item,
+            "status": {
+                "transactions": {
+                    "last_successful_update": "2024-05-20T15:52:39Z",
+                    "last_failed_update": null
+                },
+                "last_webhook": null
+            },
+            "request_id": "m8MDnv9okwxFNBV"
+        }))
+        .unwrap()
+    }
+
+    fn get_now() -> PrimitiveDateTime {
+        let now = time::OffsetDateTime::from_unix_timestamp(1_716_336_000).unwrap();
+        PrimitiveDateTime::new(now.date(), now.time())
+    }
+
+    #[test]
+    fn test_healthy_item_is_active() {
+        let response = get_item_get_response(serde_json::json!({
+            "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+            "error": null,
+            "consent_expiration_time": null
+        }));
+
+        assert_eq!(
+            response.item.get_connection_status(get_now()),
+            PaymentMethodAuthConnectionStatus::Active
+        );
+        let last_successful_update = response
+            .status
+            .and_then(|status| status.transactions)
+            .and_then(|transactions| transactions.last_successful_update)
+            .unwrap();
+        assert_eq!(
+            last_successful_update.assume_utc().unix_timestamp(),
+            1_716_220_359
+        );
+    }
+
+    #[test]
+    fn test_item_requiring_login_is_expired() {
+        let response = get_item_get_response(serde_json::json!({
+            "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+            "error": {
+                "error_type": "ITEM_ERROR",
+                "error_code": "ITEM_LOGIN_REQUIRED",
+                "error_message": "the login details of this item have changed",
+                "display_message": null
+            }
+        }));
+
+        assert_eq!(
+            response.item.get_connection_status(get_now()),
+            PaymentMethodAuthConnectionStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_item_with_lapsed_consent_is_expired() {
+        let response = get_item_get_response(serde_json::json!({
+            "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+            "error": null,
+            "consent_expiration_time": "2024-05-01T00:00:00Z"
+        }));
+
+        assert_eq!(
+            response.item.get_connection_status(get_now()),
+            PaymentMethodAuthConnectionStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_item_with_revoked_permission_is_revoked() {
+        let response = get_item_get_response(serde_json::json!({
+            "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+            "error": {
+                "error_type": "ITEM_ERROR",
+                "error_code": "USER_PERMISSION_REVOKED",
+                "error_message": "the holder of this account has revoked their permission for your application to access it",
+                "display_message": null
+            },
+            "consent_expiration_time": "2025-05-01T00:00:00Z"
+        }));
+
+        assert_eq!(
+            response.item.get_connection_status(get_now()),
+            PaymentMethodAuthConnectionStatus::Revoked
+        );
+    }
+
+    #[test]
+    fn test_item_with_transient_error_is_active() {
+        let response = get_item_get_response(serde_json::json!({
+            "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+            "error": {
+                "error_type": "INSTITUTION_ERROR",
+                "error_code": "INSTITUTION_DOWN",
+                "error_message": "this institution is not currently responding to this request",
+                "display_message": null
+            }
+        }));
+
+        assert_eq!(
+            response.item.get_connection_status(get_now()),
+            PaymentMethodAuthConnectionStatus::Active
+        );
+    }
+}
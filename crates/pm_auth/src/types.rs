@@ -2,9 +2,11 @@ pub mod api;
 
 use std::marker::PhantomData;
 
-use api::auth_service::{BankAccountCredentials, ExchangeToken, LinkToken};
+use api::auth_service::{BankAccountCredentials, ConnectionStatus, ExchangeToken, LinkToken};
+use api_models::pm_auth::PaymentMethodAuthConnectionStatus;
 use common_enums::{PaymentMethod, PaymentMethodType};
 use masking::Secret;
+use time::PrimitiveDateTime;
 #[derive(Debug, Clone)]
 pub struct PaymentAuthRouterData<F, Request, Response> {
     pub flow: PhantomData<F>,
@@ -22,6 +24,8 @@ pub struct LinkTokenRequest {
     pub country_codes: Option<Vec<String>>,
     pub language: Option<String>,
     pub user_info: Option<String>,
+    /// Access token of an existing link, to create a link session that re-authenticates it
+    pub access_token: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -109,6 +113,20 @@ pub type BankDetailsRouterData = PaymentAuthRouterData<
     BankAccountCredentialsResponse,
 >;
 
+#[derive(Debug, Clone)]
+pub struct ConnectionStatusRequest {
+    pub access_token: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectionStatusResponse {
+    pub status: PaymentMethodAuthConnectionStatus,
+    pub last_refreshed_at: Option<PrimitiveDateTime>,
+}
+
+pub type ConnectionStatusRouterData =
+    PaymentAuthRouterData<ConnectionStatus, ConnectionStatusRequest, ConnectionStatusResponse>;
+
 pub type PaymentAuthLinkTokenType =
     dyn api::ConnectorIntegration<LinkToken, LinkTokenRequest, LinkTokenResponse>;
 
@@ -121,6 +139,12 @@ pub type PaymentAuthBankAccountDetailsType = dyn api::ConnectorIntegration<
     BankAccountCredentialsResponse,
 >;
 
+pub type PaymentAuthConnectionStatusType = dyn api::ConnectorIntegration<
+    ConnectionStatus,
+    ConnectionStatusRequest,
+    ConnectionStatusResponse,
+>;
+
 #[derive(Clone, Debug, strum::EnumString, strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum PaymentMethodAuthConnectors {
@@ -153,6 +153,12 @@ pub trait ConnectorCommon {
 
     fn base_url<'a>(&self, connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str;
 
+    /// Whether the error indicates that the access token can no longer be used until the
+    /// customer re-authenticates with their bank
+    fn is_reauthentication_required(&self, _error: &auth_types::ErrorResponse) -> bool {
+        false
+    }
+
     fn build_error_response(
         &self,
         res: auth_types::Response,
@@ -1,6 +1,7 @@
 use crate::types::{
-    BankAccountCredentialsRequest, BankAccountCredentialsResponse, ExchangeTokenRequest,
-    ExchangeTokenResponse, LinkTokenRequest, LinkTokenResponse,
+    BankAccountCredentialsRequest, BankAccountCredentialsResponse, ConnectionStatusRequest,
+    ConnectionStatusResponse, ExchangeTokenRequest, ExchangeTokenResponse, LinkTokenRequest,
+    LinkTokenResponse,
 };
 
 pub trait AuthService:
@@ -8,6 +9,7 @@ pub trait AuthService:
     + AuthServiceLinkToken
     + AuthServiceExchangeToken
     + AuthServiceBankAccountCredentials
+    + AuthServiceConnectionStatus
 {
 }
 
@@ -38,3 +40,11 @@ pub trait AuthServiceBankAccountCredentials:
 >
 {
 }
+
+#[derive(Debug, Clone)]
+pub struct ConnectionStatus;
+
+pub trait AuthServiceConnectionStatus:
+    super::ConnectorIntegration<ConnectionStatus, ConnectionStatusRequest, ConnectionStatusResponse>
+{
+}
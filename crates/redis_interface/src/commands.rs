@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -244,6 +244,14 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -461,6 +469,49 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn append_elements_to_list<V>(
+        &self,
+        key: &str,
+        elements: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<MultipleValues> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.pool
+            .rpush(key, elements)
+            .await
+            .change_context(errors::RedisError::AppendElementsToListFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_list_elements(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .lrange(key, start, stop)
+            .await
+            .change_context(errors::RedisError::GetListElementsFailed)
+    }
+
+    /// Retains only the elements of the list between `start` and `stop`, both inclusive
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn trim_list(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .ltrim(key, start, stop)
+            .await
+            .change_context(errors::RedisError::TrimListFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -14,6 +14,8 @@ pub enum RedisError {
     SetExFailed,
     #[error("Failed to set expiry for key value in Redis")]
     SetExpiryFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
     #[error("Failed to delete key value in Redis")]
@@ -54,6 +56,12 @@ pub enum RedisError {
     SetAddMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to append elements to list in Redis")]
+    AppendElementsToListFailed,
+    #[error("Failed to get list elements in Redis")]
+    GetListElementsFailed,
+    #[error("Failed to trim list in Redis")]
+    TrimListFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -3,9 +3,12 @@ pub use analytics::*;
 pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
-        api_event::api_events_core, connector_events::connector_events_core,
-        errors::AnalyticsError, lambda_utils::invoke_lambda,
-        outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
+        api_event::api_events_core,
+        connector_events::{connector_events_core, connector_latency_percentiles_core},
+        errors::AnalyticsError,
+        lambda_utils::invoke_lambda,
+        outgoing_webhook_event::outgoing_webhook_events_core,
+        sdk_events::sdk_events_core,
         AnalyticsFlow,
     };
     use api_models::analytics::{
@@ -15,7 +18,7 @@ pub mod routes {
         GenerateReportRequest, GetApiEventFiltersRequest, GetApiEventMetricRequest,
         GetAuthEventMetricRequest, GetDisputeMetricRequest, GetPaymentFiltersRequest,
         GetPaymentMetricRequest, GetRefundFilterRequest, GetRefundMetricRequest,
-        GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetRefundRateRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
     };
     use error_stack::ResultExt;
 
@@ -46,6 +49,9 @@ pub mod routes {
                     .service(
                         web::resource("metrics/refunds").route(web::post().to(get_refunds_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/refund_rate").route(web::post().to(get_refund_rate)),
+                    )
                     .service(
                         web::resource("filters/payments")
                             .route(web::post().to(get_payment_filters)),
@@ -78,6 +84,10 @@ pub mod routes {
                         web::resource("metrics/auth_events")
                             .route(web::post().to(get_auth_event_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/connector_latency")
+                            .route(web::post().to(get_connector_latency_percentiles)),
+                    )
                     .service(web::resource("api_event_logs").route(web::get().to(get_api_events)))
                     .service(web::resource("sdk_event_logs").route(web::post().to(get_sdk_events)))
                     .service(
@@ -209,6 +219,32 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_refund_rate(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRefundRateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRefundRate;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::refunds::get_refund_rate(
+                    &state.pool,
+                    &auth.merchant_account.merchant_id,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetSdkEventMetricRequest` element.
@@ -642,6 +678,34 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_connector_latency_percentiles(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<
+            api_models::analytics::connector_events::ConnectorLatencyPercentilesRequest,
+        >,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetConnectorLatencyPercentiles;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                connector_latency_percentiles_core(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.merchant_id,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_global_search_results(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
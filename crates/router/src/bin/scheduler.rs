@@ -280,6 +280,50 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
                         )
                     }
                 }
+                storage::ProcessTrackerRunner::PaymentRejectNotificationWorkflow => {
+                    #[cfg(feature = "email")]
+                    {
+                        Ok(Box::new(
+                            workflows::payment_reject_notification::PaymentRejectNotificationWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "email"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payment reject notification workflow when email feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PaymentAutoVoidWorkflow => Ok(Box::new(
+                    workflows::payment_auto_void::PaymentAutoVoidWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ConnectorHealthProbeWorkflow => Ok(Box::new(
+                    workflows::connector_health_probe::ConnectorHealthProbeWorkflow,
+                )),
+                storage::ProcessTrackerRunner::BlocklistEntryExpiryWorkflow => Ok(Box::new(
+                    workflows::blocklist_entry_expiry::BlocklistEntryExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentDeferredCaptureWorkflow => Ok(Box::new(
+                    workflows::payment_deferred_capture::PaymentDeferredCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ApplepayDomainReverificationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::applepay_domain_reverification::ApplepayDomainReverificationWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run apple pay domain reverification workflow when olap feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -531,7 +531,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
             errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
             errors::ApiErrorResponse::EventNotFound => Self::EventNotFound,
-            errors::ApiErrorResponse::MandateValidationFailed { reason } => {
+            errors::ApiErrorResponse::MandateValidationFailed { reason }
+            | errors::ApiErrorResponse::MandateLimitExceeded { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
             errors::ApiErrorResponse::ReturnUrlUnavailable => Self::ReturnUrlUnavailable,
@@ -646,6 +647,27 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::InvalidWalletToken { wallet_name }
             }
             errors::ApiErrorResponse::ExtendedCardInfoNotFound => Self::ExtendedCardInfoNotFound,
+            errors::ApiErrorResponse::InvalidStatementDescriptor {
+                connector,
+                invalid_characters,
+            } => Self::InvalidRequestData {
+                message: format!(
+                    "Statement descriptor contains characters not supported by {connector}: {invalid_characters}"
+                ),
+            },
+            errors::ApiErrorResponse::CustomerAcceptanceRequired => Self::ParameterMissing {
+                field_name: "customer_acceptance".to_string(),
+                param: "customer_acceptance".to_string(),
+            },
+            errors::ApiErrorResponse::FileSizeExceeded { .. }
+            | errors::ApiErrorResponse::UnsupportedFileType { .. } => Self::FileValidationFailed,
+            errors::ApiErrorResponse::RequiresReauthentication { connector, .. } => {
+                Self::InvalidRequestData {
+                    message: format!(
+                        "The bank account linked through {connector} requires re-authentication"
+                    ),
+                }
+            }
         }
     }
 }
@@ -800,6 +822,17 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::AddressNotFound => SC::AddressNotFound,
+            Self::PreconditionFailed { message } => SC::PreconditionFailed {
+                message: message.clone(),
+            },
+            Self::InvalidDataFormat {
+                field_name,
+                expected_format,
+            } => SC::ParameterUnknown {
+                field_name: field_name.clone(),
+                expected_format: expected_format.clone(),
+            },
         }
     }
 }
@@ -387,16 +387,12 @@ pub async fn payment_intents_capture(
         &req,
         payload,
         |state, auth, payload, req_state| {
-            payments::payments_core::<api_types::Capture, api_types::PaymentsResponse, _, _, _>(
+            payments::capture_delay::payments_capture_core(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.key_store,
-                payments::PaymentCapture,
                 payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
                 api_types::HeaderPayload::default(),
             )
         },
@@ -734,6 +734,8 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            max_total_amount: None,
+                            max_debit_count: None,
                         },
                     )),
                     StripeMandateType::MultiUse => Some(payments::MandateType::MultiUse(Some(
@@ -743,6 +745,8 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            max_total_amount: None,
+                            max_debit_count: None,
                         },
                     ))),
                 },
@@ -753,6 +757,8 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                         start_date: mandate.start_date,
                         end_date: mandate.end_date,
                         metadata: None,
+                        max_total_amount: None,
+                        max_debit_count: None,
                     },
                 ))),
             },
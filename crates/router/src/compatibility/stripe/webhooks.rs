@@ -1,5 +1,5 @@
 use api_models::{
-    enums::{DisputeStatus, MandateStatus},
+    enums::{DisputeStatus, MandateStatus, OutgoingWebhookSignatureAlgorithm},
     webhooks::{self as api},
 };
 use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
@@ -19,6 +19,30 @@ use crate::{
     services::request::Maskable,
 };
 
+fn get_stripe_compatible_signature(
+    timestamp: u64,
+    payload: &str,
+    payment_response_hash_key: Option<impl AsRef<[u8]>>,
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let payment_response_hash_key = payment_response_hash_key
+        .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
+        .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
+
+    let new_signature_payload = format!("{timestamp}.{payload}");
+    let v1 = hex::encode(
+        common_utils::crypto::HmacSha256::sign_message(
+            &common_utils::crypto::HmacSha256,
+            payment_response_hash_key.as_ref(),
+            new_signature_payload.as_bytes(),
+        )
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?,
+    );
+
+    let t = timestamp;
+    Ok(format!("t={t},v1={v1}"))
+}
+
 #[derive(Serialize, Debug)]
 pub struct StripeOutgoingWebhook {
     id: String,
@@ -34,39 +58,48 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        _signature_algorithm: OutgoingWebhookSignatureAlgorithm,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let timestamp = self.created;
-
-        let payment_response_hash_key = payment_response_hash_key
-            .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
-            .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
-
         let webhook_signature_payload = self
             .encode_to_string_of_json()
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
-            common_utils::crypto::HmacSha256::sign_message(
-                &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_ref(),
-                new_signature_payload.as_bytes(),
-            )
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
-
-        let t = timestamp;
-        let signature = Some(format!("t={t},v1={v1}"));
+        let signature = get_stripe_compatible_signature(
+            self.created,
+            &webhook_signature_payload,
+            payment_response_hash_key,
+        )?;
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
-            signature,
+            signature: Some(signature),
         })
     }
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+    fn get_signature_for_payload(
+        payload: &str,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        _signature_algorithm: OutgoingWebhookSignatureAlgorithm,
+    ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+        #[derive(serde::Deserialize)]
+        struct StripeOutgoingWebhookTimestamp {
+            created: u64,
+        }
+
+        let timestamp = serde_json::from_str::<StripeOutgoingWebhookTimestamp>(payload)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+            .attach_printable("Failed to read timestamp from outgoing webhook payload")?
+            .created;
+
+        get_stripe_compatible_signature(timestamp, payload, payment_response_hash_key).map(Some)
+    }
+
+    fn add_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+        _signature_algorithm: OutgoingWebhookSignatureAlgorithm,
+    ) {
         header.push((
             headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
             signature.into(),
@@ -150,7 +183,9 @@ impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
             MandateStatus::Active => Self::Active,
-            MandateStatus::Inactive | MandateStatus::Revoked => Self::Inactive,
+            MandateStatus::Inactive | MandateStatus::Revoked | MandateStatus::Paused => {
+                Self::Inactive
+            }
             MandateStatus::Pending => Self::Pending,
         }
     }
@@ -58,6 +58,25 @@ impl Default for super::settings::Proxy {
     }
 }
 
+impl Default for super::settings::ForexApi {
+    fn default() -> Self {
+        Self {
+            local_fetch_retry_count: Default::default(),
+            api_key: Default::default(),
+            fallback_api_key: Default::default(),
+            call_delay: Default::default(),
+            local_fetch_retry_delay: Default::default(),
+            api_timeout: Default::default(),
+            redis_lock_timeout: Default::default(),
+            rate_sources: vec![
+                super::settings::ForexRateSource::OpenExchangeRates,
+                super::settings::ForexRateSource::ApiLayer,
+            ],
+            max_rate_staleness_in_secs: 86400, // 1 day
+        }
+    }
+}
+
 impl Default for super::settings::Locker {
     fn default() -> Self {
         Self {
@@ -68,6 +87,7 @@ impl Default for super::settings::Locker {
             locker_signing_key_id: "1".into(),
             //true or false
             locker_enabled: true,
+            max_payload_size_in_bytes: 65536,
         }
     }
 }
@@ -101,6 +121,80 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::ConnectorHealthProbeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_in_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::AdaptiveVolumeSplitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_adjustment: 20,
+            min_split: 10,
+            window_in_secs: 60 * 60,
+            min_attempts: 20,
+        }
+    }
+}
+
+impl Default for super::settings::FileUploadConfig {
+    fn default() -> Self {
+        Self {
+            // 10 MiB
+            max_file_size_bytes: 10 * 1024 * 1024,
+            allowed_content_types: HashSet::from([
+                "application/pdf".to_string(),
+                "image/jpeg".to_string(),
+                "image/png".to_string(),
+            ]),
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRetryAfterConfig {
+    fn default() -> Self {
+        Self {
+            max_delay_in_secs: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorReplayRecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_in_secs: 86400,
+            max_records_per_attempt: 20,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl Default for super::settings::Payouts {
+    fn default() -> Self {
+        Self {
+            payout_eligibility: false,
+            max_batch_size: 100,
+            batch_concurrency: 5,
+        }
+    }
+}
+
+impl Default for super::settings::CustomerDeclineMessageConfig {
+    fn default() -> Self {
+        Self {
+            default_locale: "en".to_string(),
+            locales: HashMap::new(),
+            connector_decline_codes: HashMap::new(),
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -338,6 +338,7 @@ pub(crate) async fn fetch_raw_secrets(
         mandates: conf.mandates,
         network_transaction_id_supported_connectors: conf
             .network_transaction_id_supported_connectors,
+        apple_pay_predecrypt_supported_connectors: conf.apple_pay_predecrypt_supported_connectors,
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
@@ -367,5 +368,19 @@ pub(crate) async fn fetch_raw_secrets(
         cors: conf.cors,
         unmasked_headers: conf.unmasked_headers,
         saved_payment_methods: conf.saved_payment_methods,
+        statement_descriptor_max_length: conf.statement_descriptor_max_length,
+        statement_descriptor_allowed_characters: conf.statement_descriptor_allowed_characters,
+        connector_request_compression: conf.connector_request_compression,
+        minimum_three_ds_amount: conf.minimum_three_ds_amount,
+        authorization_expiry: conf.authorization_expiry,
+        connector_health_probe: conf.connector_health_probe,
+        adaptive_volume_split: conf.adaptive_volume_split,
+        over_capture: conf.over_capture,
+        connector_replay_recorder: conf.connector_replay_recorder,
+        customer_decline_messages: conf.customer_decline_messages,
+        connector_retry_after: conf.connector_retry_after,
+        connector_attempt_id_header: conf.connector_attempt_id_header,
+        connector_capture_delay: conf.connector_capture_delay,
+        file_upload: conf.file_upload,
     }
 }
@@ -93,6 +93,7 @@ pub struct Settings<S: SecretState> {
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
+    pub apple_pay_predecrypt_supported_connectors: ApplePayPredecryptSupportedConnectors,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
@@ -121,6 +122,20 @@ pub struct Settings<S: SecretState> {
     pub connector_onboarding: SecretStateContainer<ConnectorOnboarding, S>,
     pub unmasked_headers: UnmaskedHeaders,
     pub saved_payment_methods: EligiblePaymentMethods,
+    pub statement_descriptor_max_length: StatementDescriptorMaxLength,
+    pub statement_descriptor_allowed_characters: StatementDescriptorAllowedCharacters,
+    pub connector_request_compression: ConnectorRequestCompressionConfig,
+    pub minimum_three_ds_amount: MinimumThreeDsAmountConfig,
+    pub authorization_expiry: AuthorizationExpiryConfig,
+    pub connector_health_probe: ConnectorHealthProbeConfig,
+    pub adaptive_volume_split: AdaptiveVolumeSplitConfig,
+    pub over_capture: OverCaptureConfig,
+    pub connector_replay_recorder: ConnectorReplayRecorderConfig,
+    pub customer_decline_messages: CustomerDeclineMessageConfig,
+    pub connector_retry_after: ConnectorRetryAfterConfig,
+    pub connector_attempt_id_header: ConnectorAttemptIdHeaderConfig,
+    pub connector_capture_delay: ConnectorCaptureDelayConfig,
+    pub file_upload: FileUploadConfig,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -145,7 +160,7 @@ pub struct PaymentLink {
     pub sdk_url: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ForexApi {
     pub local_fetch_retry_count: u64,
@@ -159,6 +174,20 @@ pub struct ForexApi {
     pub api_timeout: u64,
     /// in ms
     pub redis_lock_timeout: u64,
+    /// Sources from which the exchange rates are fetched, in order of preference. The next source
+    /// is used when a source fails or responds with stale rates.
+    pub rate_sources: Vec<ForexRateSource>,
+    /// Maximum age (in seconds) of exchange rates which may be used for conversions
+    pub max_rate_staleness_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForexRateSource {
+    /// Open Exchange Rates, authenticated using the `api_key`
+    OpenExchangeRates,
+    /// apilayer, authenticated using the `fallback_api_key`
+    ApiLayer,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -208,6 +237,17 @@ pub struct MultipleApiVersionSupportedConnectors {
 #[serde(transparent)]
 pub struct TokenizationConfig(pub HashMap<String, PaymentMethodTokenFilter>);
 
+/// Maximum length of the statement descriptor supported by a connector, keyed by connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct StatementDescriptorMaxLength(pub HashMap<String, usize>);
+
+/// Characters allowed in the statement descriptor by a connector besides ASCII letters and digits,
+/// keyed by connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct StatementDescriptorAllowedCharacters(pub HashMap<String, String>);
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(transparent)]
 pub struct TempLockerEnableConfig(pub HashMap<String, TempLockerEnablePaymentMethodFilter>);
@@ -267,6 +307,14 @@ pub struct NetworkTransactionIdSupportedConnectors {
     pub connector_list: HashSet<enums::Connector>,
 }
 
+/// Connectors that accept decrypted Apple Pay payment tokens, to which the tokens are decrypted with
+/// the payment processing certificate of the merchant in the manual flow
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ApplePayPredecryptSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct SupportedPaymentMethodsForMandate(
     pub HashMap<enums::PaymentMethod, SupportedPaymentMethodTypesForMandate>,
@@ -300,6 +348,169 @@ pub enum ApplePayPreDecryptFlow {
     NetworkTokenization,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorRequestCompressionConfig {
+    /// Connectors which accept gzip encoded request bodies
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub gzip_enabled_connectors: HashSet<String>,
+    /// Request bodies smaller than this size (in bytes) are sent uncompressed
+    pub min_body_size_in_bytes: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AuthorizationExpiryConfig {
+    /// Authorizations are voided this many seconds before they expire at the connector
+    pub auto_void_buffer_in_seconds: i64,
+    /// Duration (in seconds) for which an authorization remains valid, for each connector
+    pub connectors: HashMap<String, i64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct OverCaptureConfig {
+    /// Percentage of the authorized amount which can be captured over the authorized amount
+    pub grace_percentage: u8,
+    /// Connectors which allow capturing more than the authorized amount
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorHealthProbeConfig {
+    /// Whether connectors are actively probed, and connectors found unhealthy excluded from routing
+    pub enabled: bool,
+    /// Interval (in seconds) between two consecutive health probes of a connector
+    pub interval_in_secs: i64,
+}
+
+/// Adjustment of volume split routing based on the recent success rates of the connectors
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AdaptiveVolumeSplitConfig {
+    /// Whether the configured volume splits are adjusted by the success rates of the connectors
+    pub enabled: bool,
+    /// Maximum change (in percentage points) applied to the configured split of a connector
+    pub max_adjustment: u8,
+    /// Minimum split retained by every connector, so that no connector is fully starved
+    pub min_split: u8,
+    /// Duration (in seconds) of the window over which the success rates are measured
+    pub window_in_secs: i64,
+    /// Minimum number of payments made with a connector in the window to use its success rate
+    pub min_attempts: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorAttemptIdHeaderConfig {
+    /// Header in which the attempt id is sent as the merchant reference, for each connector which
+    /// accepts one. The attempt id is not sent to the other connectors.
+    pub connectors: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorCaptureDelayConfig {
+    /// Minimum duration (in seconds) between the authorization and the capture of a payment, for
+    /// each connector which rejects captures issued too soon after authorization
+    pub connectors: HashMap<String, i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileUploadConfig {
+    /// Maximum size (in bytes) of an uploaded file
+    pub max_file_size_bytes: usize,
+    /// Content types of the files which can be uploaded. The content type of a file is detected
+    /// from its content, the content type and name declared in the request are not trusted.
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub allowed_content_types: HashSet<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorRetryAfterConfig {
+    /// Maximum delay (in seconds) of a sync requested through the `Retry-After` header of a connector
+    pub max_delay_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorReplayRecorderConfig {
+    /// Whether the masked connector requests and responses of payment attempts are recorded
+    pub enabled: bool,
+    /// Time (in seconds) for which the recorded requests and responses of an attempt are retained
+    pub ttl_in_secs: i64,
+    /// Maximum number of requests recorded for an attempt, older requests are discarded first
+    pub max_records_per_attempt: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CustomerDeclineMessageConfig {
+    /// Locale whose messages are used when none are configured for the customer's locale
+    pub default_locale: String,
+    /// Customer-friendly decline messages, keyed by locale
+    pub locales: HashMap<String, LocalizedDeclineMessages>,
+    /// Decline codes that the error codes of a connector are normalized to, keyed by connector
+    /// name and then by the error code returned by the connector
+    pub connector_decline_codes: HashMap<String, HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct LocalizedDeclineMessages {
+    /// Message shown for decline codes which have no message configured
+    pub generic_message: String,
+    /// Messages keyed by the normalized decline code
+    #[serde(default)]
+    pub codes: HashMap<String, String>,
+}
+
+/// Minimum payment amounts below which 3DS is not requested, keyed by connector name and then by
+/// region name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct MinimumThreeDsAmountConfig(
+    pub HashMap<String, HashMap<String, MinimumThreeDsAmountThreshold>>,
+);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MinimumThreeDsAmountThreshold {
+    /// Billing countries the threshold applies to
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub countries: HashSet<enums::CountryAlpha2>,
+    /// Currency in which the threshold amount is specified
+    pub currency: enums::Currency,
+    /// Threshold amount in the lowest denomination of the currency
+    pub amount: i64,
+}
+
+impl MinimumThreeDsAmountConfig {
+    /// Checks whether the payment amount is below the minimum 3DS amount configured for the
+    /// connector in the billing country of the payment
+    pub fn is_below_threshold(
+        &self,
+        connector: &str,
+        country: Option<enums::CountryAlpha2>,
+        currency: enums::Currency,
+        amount: i64,
+    ) -> bool {
+        let Some(country) = country else {
+            return false;
+        };
+
+        self.0.get(connector).is_some_and(|regions| {
+            regions.values().any(|threshold| {
+                threshold.countries.contains(&country)
+                    && threshold.currency == currency
+                    && amount < threshold.amount
+            })
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct TempLockerEnablePaymentMethodFilter {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -405,6 +616,7 @@ pub struct Locker {
     pub basilisk_host: String,
     pub locker_signing_key_id: String,
     pub locker_enabled: bool,
+    pub max_payload_size_in_bytes: usize,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -611,6 +823,9 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    /// Maximum age (in seconds) of an incoming webhook, as per the timestamp sent by the
+    /// connector, beyond which the webhook is treated as a replay and is not processed
+    pub replay_protection_window_in_secs: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -690,6 +905,7 @@ impl Settings<SecuredSecret> {
                     .with_list_parse_key("redis.cluster_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("forex_api.rate_sources")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
             )
@@ -744,6 +960,7 @@ impl Settings<SecuredSecret> {
 
         self.lock_settings.validate()?;
         self.events.validate()?;
+        self.over_capture.validate()?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
@@ -760,9 +977,14 @@ impl Settings<SecuredSecret> {
 }
 
 #[cfg(feature = "payouts")]
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
 pub struct Payouts {
     pub payout_eligibility: bool,
+    /// Maximum number of payouts accepted in a single batch payout request
+    pub max_batch_size: usize,
+    /// Maximum number of payouts of a batch which are processed concurrently
+    pub batch_concurrency: usize,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -929,3 +1151,71 @@ mod hashset_deserialization_test {
         assert!(payment_methods.is_err());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimum_three_ds_amount_config() -> MinimumThreeDsAmountConfig {
+        MinimumThreeDsAmountConfig(HashMap::from([(
+            "stripe".to_string(),
+            HashMap::from([(
+                "uk".to_string(),
+                MinimumThreeDsAmountThreshold {
+                    countries: HashSet::from([enums::CountryAlpha2::GB]),
+                    currency: enums::Currency::GBP,
+                    amount: 3000,
+                },
+            )]),
+        )]))
+    }
+
+    #[test]
+    fn test_three_ds_skipped_for_payment_below_minimum_amount() {
+        let config = minimum_three_ds_amount_config();
+
+        assert!(config.is_below_threshold(
+            "stripe",
+            Some(enums::CountryAlpha2::GB),
+            enums::Currency::GBP,
+            2999,
+        ));
+    }
+
+    #[test]
+    fn test_three_ds_applied_for_payment_above_minimum_amount() {
+        let config = minimum_three_ds_amount_config();
+
+        assert!(!config.is_below_threshold(
+            "stripe",
+            Some(enums::CountryAlpha2::GB),
+            enums::Currency::GBP,
+            3000,
+        ));
+    }
+
+    #[test]
+    fn test_three_ds_applied_outside_configured_region() {
+        let config = minimum_three_ds_amount_config();
+
+        assert!(!config.is_below_threshold(
+            "stripe",
+            Some(enums::CountryAlpha2::FR),
+            enums::Currency::GBP,
+            100,
+        ));
+        assert!(!config.is_below_threshold(
+            "stripe",
+            Some(enums::CountryAlpha2::GB),
+            enums::Currency::EUR,
+            100,
+        ));
+        assert!(!config.is_below_threshold("stripe", None, enums::Currency::GBP, 100));
+        assert!(!config.is_below_threshold(
+            "adyen",
+            Some(enums::CountryAlpha2::GB),
+            enums::Currency::GBP,
+            100,
+        ));
+    }
+}
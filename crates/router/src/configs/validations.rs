@@ -43,7 +43,13 @@ impl super::settings::Locker {
                     "basilisk host must not be empty when mock locker is disabled".into(),
                 ))
             },
-        )
+        )?;
+
+        when(self.max_payload_size_in_bytes == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "locker max payload size must be greater than zero".into(),
+            ))
+        })
     }
 }
 
@@ -180,3 +186,13 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::OverCaptureConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.grace_percentage > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "over capture grace percentage must not be greater than 100".into(),
+            ))
+        })
+    }
+}
@@ -4,7 +4,7 @@ use std::fmt::Debug;
 
 use api_models::{enums::PaymentMethodType, webhooks::IncomingWebhookEvent};
 use base64::Engine;
-use common_utils::request::RequestContent;
+use common_utils::{ext_traits::Encode, request::RequestContent};
 use diesel_models::{enums as storage_enums, enums};
 use error_stack::{report, ResultExt};
 use masking::ExposeInterface;
@@ -1688,6 +1688,25 @@ fn get_webhook_object_from_body(
     Ok(item_object.notification_request_item)
 }
 
+fn get_webhook_event_bodies_from_body(
+    body: &[u8],
+) -> CustomResult<Vec<Vec<u8>>, errors::ParsingError> {
+    let webhook: adyen::AdyenIncomingWebhookBatch =
+        body.parse_struct("AdyenIncomingWebhookBatch")?;
+
+    webhook
+        .notification_items
+        .into_iter()
+        .map(|notification_item| {
+            adyen::AdyenIncomingWebhookBatch {
+                live: webhook.live.clone(),
+                notification_items: vec![notification_item],
+            }
+            .encode_to_vec()
+        })
+        .collect()
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Adyen {
     fn get_webhook_source_verification_algorithm(
@@ -1770,6 +1789,14 @@ impl api::IncomingWebhook for Adyen {
         Ok(payload_sign.as_bytes().eq(&signature))
     }
 
+    fn get_webhook_event_bodies(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<Vec<u8>>, errors::ConnectorError> {
+        get_webhook_event_bodies_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -1833,6 +1860,18 @@ impl api::IncomingWebhook for Adyen {
         Ok(Box::new(response))
     }
 
+    fn get_webhook_mandate_reference(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<types::MandateReference>, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        Ok(adyen::get_webhook_mandate_reference(
+            notif.additional_data.recurring_detail_reference,
+        ))
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -1863,3 +1902,59 @@ impl api::IncomingWebhook for Adyen {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_notification_item(psp_reference: &str, event_code: &str) -> serde_json::Value {
+        serde_json::json!({
+            "NotificationRequestItem": {
+                "additionalData": {
+                    "hmacSignature": "signature"
+                },
+                "amount": {
+                    "value": 1000,
+                    "currency": "EUR"
+                },
+                "pspReference": psp_reference,
+                "eventCode": event_code,
+                "merchantAccountCode": "merchant_account",
+                "merchantReference": "pay_1",
+                "success": "true"
+            }
+        })
+    }
+
+    #[test]
+    fn test_batched_webhook_is_split_into_individual_events() {
+        let body = serde_json::json!({
+            "live": "false",
+            "notificationItems": [
+                get_notification_item("psp_1", "AUTHORISATION"),
+                get_notification_item("psp_2", "CAPTURE"),
+                get_notification_item("psp_3", "REFUND"),
+            ]
+        });
+
+        let event_bodies =
+            get_webhook_event_bodies_from_body(&serde_json::to_vec(&body).unwrap()).unwrap();
+        assert_eq!(event_bodies.len(), 3);
+
+        // Every event is a webhook of its own, carrying only its notification item
+        let notifications = event_bodies
+            .iter()
+            .map(|event_body| get_webhook_object_from_body(event_body).unwrap())
+            .map(|notification| (notification.psp_reference, notification.event_code))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            notifications,
+            vec![
+                ("psp_1".to_string(), adyen::WebhookEventCode::Authorisation),
+                ("psp_2".to_string(), adyen::WebhookEventCode::Capture),
+                ("psp_3".to_string(), adyen::WebhookEventCode::Refund),
+            ]
+        );
+    }
+}
@@ -119,6 +119,8 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    #[cfg(feature = "payouts")]
+    payment_method: Option<String>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -388,6 +390,7 @@ pub struct AdyenWebhookResponse {
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
     event_code: WebhookEventCode,
+    recurring_detail_reference: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -3280,6 +3283,15 @@ pub fn get_adyen_response(
     Ok((status, error, payments_response_data))
 }
 
+pub fn get_webhook_mandate_reference(
+    recurring_detail_reference: Option<Secret<String>>,
+) -> Option<types::MandateReference> {
+    recurring_detail_reference.map(|mandate_id| types::MandateReference {
+        connector_mandate_id: Some(mandate_id.expose()),
+        payment_method_id: None,
+    })
+}
+
 pub fn get_webhook_response(
     response: AdyenWebhookResponse,
     is_capture_manual: bool,
@@ -3333,7 +3345,7 @@ pub fn get_webhook_response(
                     .unwrap_or(response.transaction_id),
             ),
             redirection_data: None,
-            mandate_reference: None,
+            mandate_reference: get_webhook_mandate_reference(response.recurring_detail_reference),
             connector_metadata: None,
             network_txn_id: None,
             connector_response_reference_id: Some(response.merchant_reference_id),
@@ -3871,12 +3883,10 @@ impl TryFrom<&AdyenRouterData<&types::PaymentsCaptureRouterData>> for AdyenCaptu
         item: &AdyenRouterData<&types::PaymentsCaptureRouterData>,
     ) -> Result<Self, Self::Error> {
         let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
-        let reference = match item.router_data.request.multiple_capture_data.clone() {
-            // if multiple capture request, send capture_id as our reference for the capture
-            Some(multiple_capture_request_data) => multiple_capture_request_data.capture_reference,
-            // if single capture request, send connector_request_reference_id(attempt_id)
-            None => item.router_data.connector_request_reference_id.clone(),
-        };
+        let reference = get_capture_reference(
+            &item.router_data.request,
+            &item.router_data.connector_request_reference_id,
+        );
         Ok(Self {
             merchant_account: auth_type.merchant_account,
             reference,
@@ -3888,6 +3898,22 @@ impl TryFrom<&AdyenRouterData<&types::PaymentsCaptureRouterData>> for AdyenCaptu
     }
 }
 
+fn get_capture_reference(
+    request: &types::PaymentsCaptureData,
+    connector_request_reference_id: &str,
+) -> String {
+    match (&request.multiple_capture_data, &request.capture_reference) {
+        // if multiple capture request, send capture_id as our reference for the capture
+        (Some(multiple_capture_request_data), _) => {
+            multiple_capture_request_data.capture_reference.clone()
+        }
+        // if merchant supplied a capture reference, send it as is
+        (None, Some(capture_reference)) => capture_reference.clone(),
+        // otherwise send connector_request_reference_id(attempt_id)
+        (None, None) => connector_request_reference_id.to_string(),
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenCaptureResponse {
@@ -4046,6 +4072,9 @@ pub enum DisputeStatus {
 #[serde(rename_all = "camelCase")]
 pub struct AdyenAdditionalDataWH {
     pub hmac_signature: Secret<String>,
+    /// Sent along with the authorisation of payments that store the payment method
+    #[serde(rename = "recurring.recurringDetailReference")]
+    pub recurring_detail_reference: Option<Secret<String>>,
     pub dispute_status: Option<DisputeStatus>,
     pub chargeback_reason_code: Option<String>,
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
@@ -4219,6 +4248,16 @@ pub struct AdyenIncomingWebhook {
     pub notification_items: Vec<AdyenItemObjectWH>,
 }
 
+/// Adyen delivers notifications in batches, every item of the batch is an event of its own.
+/// The items are kept as is, since the hmac signature of every item is verified separately.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncomingWebhookBatch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live: Option<String>,
+    pub notification_items: Vec<serde_json::Value>,
+}
+
 impl From<AdyenNotificationRequestItemWH> for AdyenWebhookResponse {
     fn from(notif: AdyenNotificationRequestItemWH) -> Self {
         Self {
@@ -4268,6 +4307,7 @@ impl From<AdyenNotificationRequestItemWH> for AdyenWebhookResponse {
             refusal_reason: None,
             refusal_reason_code: None,
             event_code: notif.event_code,
+            recurring_detail_reference: notif.additional_data.recurring_detail_reference,
         }
     }
 }
@@ -4448,6 +4488,45 @@ pub enum PayoutEligibility {
     Unknown,
 }
 
+#[cfg(feature = "payouts")]
+impl PayoutEligibility {
+    fn get_reason(&self) -> &'static str {
+        match self {
+            Self::Yes => "Card is eligible for payouts",
+            Self::No => "Card is not eligible for payouts",
+            Self::Domestic => "Card is eligible for domestic payouts only",
+            Self::Unknown => "Eligibility of the card for payouts is unknown",
+        }
+    }
+}
+
+/// Builds the eligibility details from the additional data of an eligibility check. Adyen reports
+/// whether the funds are credited to the card immediately (`I`) or not (`N`), and the card network
+/// as the payment method.
+#[cfg(feature = "payouts")]
+fn get_payout_eligibility_details(
+    response: &AdyenPayoutResponse,
+) -> Option<api_models::payouts::PayoutEligibilityDetails> {
+    let additional_data = response.additional_data.as_ref()?;
+    let payout_eligible = additional_data.payout_eligible.as_ref()?;
+    let push_funds_supported = match additional_data.funds_availability.as_deref() {
+        Some("I") => Some(true),
+        Some("N") => Some(false),
+        _ => None,
+    };
+
+    Some(api_models::payouts::PayoutEligibilityDetails {
+        push_funds_supported,
+        network: additional_data.payment_method.clone(),
+        reason: Some(
+            response
+                .refusal_reason
+                .clone()
+                .unwrap_or_else(|| payout_eligible.get_reason().to_string()),
+        ),
+    })
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -4733,6 +4812,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
         item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
     ) -> Result<Self, Self::Error> {
         let response: AdyenPayoutResponse = item.response;
+        let eligibility_details = get_payout_eligibility_details(&response);
         let payout_eligible = response
             .additional_data
             .and_then(|pa| pa.payout_eligible)
@@ -4759,6 +4839,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
                 status,
                 connector_payout_id: response.psp_reference,
                 payout_eligible,
+                eligibility_details,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -4779,3 +4860,149 @@ impl From<AdyenStatus> for storage_enums::PayoutStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_capture_request(capture_data: types::PaymentsCaptureData) -> serde_json::Value {
+        let router_data: types::PaymentsCaptureRouterData = types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_1".to_string(),
+            customer_id: None,
+            connector_customer: None,
+            connector: "adyen".to_string(),
+            payment_id: "pay_1".to_string(),
+            attempt_id: "pay_attempt_1".to_string(),
+            status: storage_enums::AttemptStatus::Authorized,
+            payment_method: storage_enums::PaymentMethod::Card,
+            connector_auth_type: types::ConnectorAuthType::BodyKey {
+                api_key: Secret::new("api_key".to_string()),
+                key1: Secret::new("merchant_account".to_string()),
+            },
+            description: None,
+            return_url: None,
+            address: types::PaymentAddress::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            payment_method_token: None,
+            recurring_mandate_payment_data: None,
+            preprocessing_id: None,
+            payment_method_balance: None,
+            connector_api_version: None,
+            request: capture_data,
+            response: Err(types::ErrorResponse::get_not_implemented()),
+            connector_request_reference_id: "pay_attempt_1".to_string(),
+            #[cfg(feature = "payouts")]
+            payout_method_data: None,
+            #[cfg(feature = "payouts")]
+            quote_id: None,
+            test_mode: None,
+            connector_http_status_code: None,
+            external_latency: None,
+            apple_pay_flow: None,
+            frm_metadata: None,
+            dispute_id: None,
+            refund_id: None,
+            connector_response: None,
+            payment_method_status: None,
+        };
+        let adyen_router_data = AdyenRouterData::try_from((
+            &types::api::CurrencyUnit::Minor,
+            router_data.request.currency,
+            router_data.request.amount_to_capture,
+            &router_data,
+        ))
+        .unwrap();
+        serde_json::to_value(AdyenCaptureRequest::try_from(&adyen_router_data).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_capture_request_uses_merchant_capture_reference() {
+        let capture_data = types::PaymentsCaptureData {
+            amount_to_capture: 50,
+            currency: storage_enums::Currency::USD,
+            capture_reference: Some("ledger_ref_123".to_string()),
+            ..Default::default()
+        };
+        let request = get_capture_request(capture_data);
+        assert_eq!(request["reference"], "ledger_ref_123");
+        assert_eq!(request["merchantAccount"], "merchant_account");
+        assert_eq!(request["amount"]["value"], 50);
+    }
+
+    #[test]
+    fn test_capture_request_falls_back_to_attempt_id() {
+        let capture_data = types::PaymentsCaptureData {
+            amount_to_capture: 50,
+            currency: storage_enums::Currency::USD,
+            ..Default::default()
+        };
+        let request = get_capture_request(capture_data);
+        assert_eq!(request["reference"], "pay_attempt_1");
+    }
+
+    #[test]
+    fn test_capture_request_prefers_multiple_capture_reference() {
+        let capture_data = types::PaymentsCaptureData {
+            amount_to_capture: 50,
+            currency: storage_enums::Currency::USD,
+            capture_reference: Some("ledger_ref_123".to_string()),
+            multiple_capture_data: Some(types::MultipleCaptureRequestData {
+                capture_sequence: 2,
+                capture_reference: "capture_2".to_string(),
+            }),
+            ..Default::default()
+        };
+        let request = get_capture_request(capture_data);
+        assert_eq!(request["reference"], "capture_2");
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_eligibility_response_is_mapped_to_eligibility_details() {
+        let response: AdyenPayoutResponse = serde_json::from_value(serde_json::json!({
+            "pspReference": "V4HZ4RBFJGXXGN82",
+            "resultCode": "Received",
+            "additionalData": {
+                "payoutEligible": "D",
+                "fundsAvailability": "I",
+                "paymentMethod": "visa"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            get_payout_eligibility_details(&response),
+            Some(api_models::payouts::PayoutEligibilityDetails {
+                push_funds_supported: Some(true),
+                network: Some("visa".to_string()),
+                reason: Some("Card is eligible for domestic payouts only".to_string()),
+            })
+        );
+
+        let response: AdyenPayoutResponse = serde_json::from_value(serde_json::json!({
+            "pspReference": "V4HZ4RBFJGXXGN82",
+            "refusalReason": "Not allowed",
+            "additionalData": {
+                "payoutEligible": "N",
+                "fundsAvailability": "N"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            get_payout_eligibility_details(&response),
+            Some(api_models::payouts::PayoutEligibilityDetails {
+                push_funds_supported: Some(false),
+                network: None,
+                reason: Some("Not allowed".to_string()),
+            })
+        );
+    }
+}
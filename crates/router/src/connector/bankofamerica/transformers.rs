@@ -128,12 +128,14 @@ pub struct BankOfAmericaPaymentInitiator {
 #[serde(rename_all = "camelCase")]
 pub enum BankOfAmericaPaymentInitiatorTypes {
     Customer,
+    Merchant,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MerchantInitiatedTransaction {
     reason: Option<String>,
+    previous_transaction_id: Option<Secret<String>>,
     //Required for recurring mandates payment
     original_authorized_amount: Option<String>,
 }
@@ -406,7 +408,7 @@ impl<F, T>
                             redirection_data: None,
                             mandate_reference,
                             connector_metadata: None,
-                            network_txn_id: None,
+                            network_txn_id: get_network_transaction_id(&info_response),
                             connector_response_reference_id: Some(
                                 info_response
                                     .client_reference_information
@@ -556,6 +558,7 @@ impl
             Option<String>,
         ),
     ) -> Result<Self, Self::Error> {
+        let mut commerce_indicator = get_commerce_indicator(network);
         let (action_list, action_token_types, authorization_options) = if item
             .router_data
             .request
@@ -594,15 +597,43 @@ impl
                             original_amount,
                             original_currency,
                         )?),
+                        previous_transaction_id: None,
                     }),
                 }),
             )
+        } else if let Some(payments::MandateReferenceId::NetworkMandateId(network_transaction_id)) =
+            item.router_data
+                .request
+                .mandate_id
+                .clone()
+                .and_then(|mandate_id| mandate_id.mandate_reference_id)
+        {
+            let original_authorized_amount = item
+                .router_data
+                .recurring_mandate_payment_data
+                .as_ref()
+                .and_then(|recurring_mandate_payment_data| {
+                    recurring_mandate_payment_data
+                        .original_payment_authorized_amount
+                        .zip(recurring_mandate_payment_data.original_payment_authorized_currency)
+                })
+                .map(|(original_amount, original_currency)| {
+                    utils::to_currency_base_unit(original_amount, original_currency)
+                })
+                .transpose()?;
+            commerce_indicator = "recurring".to_string();
+            (
+                None,
+                None,
+                Some(get_network_mandate_authorization_options(
+                    network_transaction_id,
+                    original_authorized_amount,
+                )),
+            )
         } else {
             (None, None, None)
         };
 
-        let commerce_indicator = get_commerce_indicator(network);
-
         Ok(Self {
             capture: Some(matches!(
                 item.router_data.request.capture_method,
@@ -1633,7 +1664,7 @@ fn get_payment_response(
                 redirection_data: None,
                 mandate_reference,
                 connector_metadata: None,
-                network_txn_id: None,
+                network_txn_id: get_network_transaction_id(info_response),
                 connector_response_reference_id: Some(
                     info_response
                         .client_reference_information
@@ -3099,6 +3130,37 @@ fn get_boa_mandate_action_details() -> (
     )
 }
 
+/// Authorization options of a merchant initiated transaction referencing the network transaction
+/// id of the customer initiated transaction which stored the card
+fn get_network_mandate_authorization_options(
+    network_transaction_id: String,
+    original_authorized_amount: Option<String>,
+) -> BankOfAmericaAuthorizationOptions {
+    BankOfAmericaAuthorizationOptions {
+        initiator: Some(BankOfAmericaPaymentInitiator {
+            initiator_type: Some(BankOfAmericaPaymentInitiatorTypes::Merchant),
+            credential_stored_on_file: None,
+            stored_credential_used: Some(true),
+        }),
+        merchant_intitiated_transaction: Some(MerchantInitiatedTransaction {
+            // Unscheduled credential on file transaction
+            reason: Some("7".to_string()),
+            original_authorized_amount,
+            previous_transaction_id: Some(Secret::new(network_transaction_id)),
+        }),
+    }
+}
+
+fn get_network_transaction_id(
+    info_response: &BankOfAmericaClientReferenceResponse,
+) -> Option<String> {
+    info_response
+        .processor_information
+        .as_ref()
+        .and_then(|processor_information| processor_information.network_transaction_id.clone())
+        .map(ExposeInterface::expose)
+}
+
 fn get_commerce_indicator(network: Option<String>) -> String {
     match network {
         Some(card_network) => match card_network.to_lowercase().as_str() {
@@ -3123,3 +3185,63 @@ fn is_customer_initiated_mandate_payment(item: &types::CompleteAuthorizeData) ->
     })
     // add check for customer_acceptance
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_network_transaction_id_is_captured_on_initial_charge() {
+        let info_response: BankOfAmericaClientReferenceResponse =
+            serde_json::from_value(serde_json::json!({
+                "id": "7106891212706003304951",
+                "status": "AUTHORIZED",
+                "clientReferenceInformation": { "code": "pay_123" },
+                "processorInformation": {
+                    "networkTransactionId": "016153570198200",
+                    "approvalCode": "831000",
+                    "responseCode": "00"
+                },
+                "tokenInformation": {
+                    "paymentInstrument": { "id": "1B7D1C2C1F5B2A7BE063AF598E0A4C12" }
+                }
+            }))
+            .unwrap();
+
+        let response =
+            get_payment_response((&info_response, enums::AttemptStatus::Charged, 201)).unwrap();
+
+        assert!(matches!(
+            response,
+            types::PaymentsResponseData::TransactionResponse {
+                network_txn_id: Some(ref network_txn_id),
+                ..
+            } if network_txn_id == "016153570198200"
+        ));
+    }
+
+    #[test]
+    fn test_network_transaction_id_is_supplied_on_mit() {
+        let authorization_options = get_network_mandate_authorization_options(
+            "016153570198200".to_string(),
+            Some("10.00".to_string()),
+        );
+
+        assert_eq!(
+            serde_json::to_value(authorization_options).unwrap(),
+            serde_json::json!({
+                "initiator": {
+                    "type": "merchant",
+                    "credentialStoredOnFile": null,
+                    "storedCredentialUsed": true
+                },
+                "merchantIntitiatedTransaction": {
+                    "reason": "7",
+                    "previousTransactionId": "016153570198200",
+                    "originalAuthorizedAmount": "10.00"
+                }
+            })
+        );
+    }
+}
@@ -3037,6 +3037,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, CybersourceFulfillResponse>>
                 status: Some(enums::PayoutStatus::foreign_from(item.response.status)),
                 connector_payout_id: item.response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -220,6 +220,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, EbanxPayoutResponse>>
                 )),
                 connector_payout_id: item.response.payout.uid,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -303,6 +304,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, EbanxFulfillResponse>>
                 status: Some(storage_enums::PayoutStatus::from(item.response.status)),
                 connector_payout_id: item.data.request.get_transfer_id()?,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -398,6 +400,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, EbanxCancelResponse>>
                     .clone()
                     .ok_or(errors::ConnectorError::MissingConnectorTransactionID)?,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
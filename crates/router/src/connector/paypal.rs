@@ -11,7 +11,9 @@ use router_env::{instrument, tracing};
 use transformers as paypal;
 
 use self::transformers::{auth_headers, PaypalAuthResponse, PaypalMeta, PaypalWebhookEventType};
-use super::utils::{ConnectorErrorType, PaymentsCompleteAuthorizeRequestData};
+use super::utils::{
+    ConnectorErrorType, PaymentsAuthorizeRequestData, PaymentsCompleteAuthorizeRequestData,
+};
 use crate::{
     configs::settings,
     connector::{
@@ -183,6 +185,13 @@ where
     }
 }
 
+/// PayPal localizes the error messages of a response to the language requested in `Accept-Language`
+fn get_accept_language_header(
+    locale: Option<String>,
+) -> Option<(String, request::Maskable<String>)> {
+    locale.map(|locale| (headers::ACCEPT_LANGUAGE.to_string(), locale.into()))
+}
+
 fn construct_auth_assertion_header(
     payer_id: &Secret<String>,
     client_id: &Secret<String>,
@@ -568,7 +577,11 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        let mut headers = self.build_headers(req, connectors)?;
+        headers.extend(get_accept_language_header(
+            req.request.get_locale_as_optional(),
+        ));
+        Ok(headers)
     }
 
     fn get_content_type(&self) -> &'static str {
@@ -1751,3 +1764,46 @@ impl ConnectorErrorTypeMapping for Paypal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_accept_language_header_is_sent_for_locale() {
+        let (header_name, header_value) =
+            get_accept_language_header(Some("de-DE".to_string())).unwrap();
+        assert_eq!(header_name, headers::ACCEPT_LANGUAGE);
+        assert_eq!(header_value.into_inner(), "de-DE");
+
+        assert!(get_accept_language_header(None).is_none());
+    }
+
+    #[test]
+    fn test_localized_error_message_is_captured() {
+        let response = Response {
+            headers: None,
+            response: bytes::Bytes::from(
+                serde_json::json!({
+                    "name": "UNPROCESSABLE_ENTITY",
+                    "message": "Die angeforderte Aktion konnte nicht ausgeführt werden.",
+                    "debug_id": "f1b2c3d4e5",
+                    "details": [{
+                        "issue": "INSTRUMENT_DECLINED",
+                        "description": "Das Zahlungsmittel wurde abgelehnt."
+                    }]
+                })
+                .to_string(),
+            ),
+            status_code: 422,
+        };
+
+        let error_response = Paypal.build_error_response(response, None).unwrap();
+        assert_eq!(error_response.code, "INSTRUMENT_DECLINED");
+        assert_eq!(
+            error_response.reason,
+            Some("description - Das Zahlungsmittel wurde abgelehnt. ;".to_string())
+        );
+    }
+}
@@ -150,6 +150,27 @@ impl ConnectorValidation for Stripe {
         ]);
         connector_utils::is_mandate_supported(pm_data, pm_type, mandate_supported_pmd, self.id())
     }
+
+    fn validate_connector_payment_method_token(
+        &self,
+        token: &str,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // Saved payment methods are referenced by a PaymentMethod, Card or Source object id
+        let is_valid_token = ["pm_", "card_", "src_"].iter().any(|prefix| {
+            token.strip_prefix(prefix).is_some_and(|object_id| {
+                !object_id.is_empty() && object_id.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+        });
+
+        if is_valid_token {
+            Ok(())
+        } else {
+            Err(errors::ConnectorError::InvalidDataFormat {
+                field_name: "external_token_ref",
+            }
+            .into())
+        }
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -1997,6 +2018,116 @@ fn get_signature_elements_from_header(
     Ok(security_header_kvs)
 }
 
+impl api::ConnectorPaymentMethodRetrieve for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::PaymentMethodRetrieve,
+        types::PaymentMethodRetrieveRequestData,
+        types::PaymentMethodRetrieveResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentMethodRetrieveRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.get_auth_header(&req.connector_auth_type)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentMethodRetrieveRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/payment_methods/{}",
+            self.base_url(connectors),
+            req.request.connector_payment_method_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentMethodRetrieveRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::PaymentMethodRetrieveType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::PaymentMethodRetrieveType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PaymentMethodRetrieveRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentMethodRetrieveRouterData, errors::ConnectorError> {
+        let response: stripe::StripePaymentMethodRetrieveResponse = res
+            .response
+            .parse_struct("StripePaymentMethodRetrieveResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::PaymentMethodRetrieveRouterData {
+            response: Ok(types::PaymentMethodRetrieveResponseData {
+                connector_payment_method_id: response.id,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_error_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.error.message.map(|message| {
+                response
+                    .error
+                    .decline_code
+                    .map(|decline_code| {
+                        format!("message - {}, decline_code - {}", message, decline_code)
+                    })
+                    .unwrap_or(message)
+            }),
+            attempt_status: None,
+            connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Stripe {
     fn get_webhook_source_verification_algorithm(
@@ -2243,9 +2374,32 @@ impl api::IncomingWebhook for Stripe {
             .body
             .parse_struct("WebhookEvent")
             .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let mut event_object = details.event_data.event_object;
+        event_object.amount = event_object.get_router_amount()?;
 
-        Ok(Box::new(details.event_data.event_object))
+        Ok(Box::new(event_object))
     }
+
+    fn get_webhook_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        let details: stripe::WebhookEventTimestamp = request
+            .body
+            .parse_struct("WebhookEventTimestamp")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        details
+            .created
+            .map(|created| {
+                time::OffsetDateTime::from_unix_timestamp(created)
+                    .map(common_utils::date_time::convert_to_pdt)
+                    .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+                    .attach_printable("Invalid event creation timestamp in stripe webhook")
+            })
+            .transpose()
+    }
+
     fn get_dispute_details(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -2258,7 +2412,7 @@ impl api::IncomingWebhook for Stripe {
             amount: details
                 .event_data
                 .event_object
-                .amount
+                .get_router_amount()?
                 .get_required_value("amount")
                 .change_context(errors::ConnectorError::MissingRequiredField {
                     field_name: "amount",
@@ -173,6 +173,9 @@ pub struct StripeMetadata {
     // it is set as string instead of bool because stripe pass it as string even if we set it as bool
     #[serde(rename(serialize = "metadata[is_refund_id_as_reference]"))]
     pub is_refund_id_as_reference: Option<String>,
+    // merchant supplied reference for the refund, only sent as additional information since order_id is used for webhooks
+    #[serde(rename(serialize = "metadata[merchant_refund_reference_id]"))]
+    pub merchant_refund_reference_id: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -1914,7 +1917,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
         };
 
         Ok(Self {
-            amount: item.request.amount, //hopefully we don't loose some cents here
+            amount: connector_util::to_connector_minor_unit(
+                types::Connector::Stripe,
+                item.request.amount,
+                item.request.currency,
+            )?,
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
             statement_descriptor_suffix: item.request.statement_descriptor_suffix.clone(),
             statement_descriptor: item.request.statement_descriptor.clone(),
@@ -2162,6 +2169,30 @@ pub struct SepaAndBacsReceiver {
     pub amount_remaining: i64,
 }
 
+/// Convert an amount sent by stripe to the minor unit used by the router. Amounts in currencies
+/// unknown to the router are returned as they are.
+pub fn get_router_minor_unit_amount(
+    amount: i64,
+    currency: &str,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    match currency.to_uppercase().parse::<enums::Currency>() {
+        Ok(currency) => {
+            connector_util::to_router_minor_unit(types::Connector::Stripe, amount, currency)
+        }
+        Err(_) => Ok(amount),
+    }
+}
+
+impl PaymentIntentResponse {
+    fn get_router_amount_received(
+        &self,
+    ) -> Result<Option<i64>, error_stack::Report<errors::ConnectorError>> {
+        self.amount_received
+            .map(|amount_received| get_router_minor_unit_amount(amount_received, &self.currency))
+            .transpose()
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Deserialize)]
 pub struct PaymentSyncResponse {
     #[serde(flatten)]
@@ -2425,8 +2456,11 @@ impl<F, T>
         // Or we identify the mandate txns before hand and always call SetupIntent in case of mandate payment call
         let network_txn_id = Option::foreign_from(item.response.latest_attempt);
 
-        let connector_metadata =
-            get_connector_metadata(item.response.next_action.as_ref(), item.response.amount)?;
+        let connector_metadata = get_connector_metadata(
+            item.response.next_action.as_ref(),
+            item.response.amount,
+            &item.response.currency,
+        )?;
 
         let status = enums::AttemptStatus::from(item.response.status);
 
@@ -2461,7 +2495,7 @@ impl<F, T>
             // statement_descriptor_suffix: item.response.statement_descriptor_suffix.map(|x| x.as_str()),
             // three_ds_form,
             response,
-            amount_captured: item.response.amount_received,
+            amount_captured: item.response.get_router_amount_received()?,
             connector_response: connector_response_data,
             ..item.data
         })
@@ -2471,9 +2505,10 @@ impl<F, T>
 pub fn get_connector_metadata(
     next_action: Option<&StripeNextActionResponse>,
     amount: i64,
+    currency: &str,
 ) -> CustomResult<Option<Value>, errors::ConnectorError> {
     let next_action_response = next_action
-        .and_then(|next_action_response| match next_action_response {
+        .map(|next_action_response| match next_action_response {
             StripeNextActionResponse::DisplayBankTransferInstructions(response) => {
                 let bank_instructions = response.financial_addresses.first();
                 let (sepa_bank_instructions, bacs_bank_instructions) =
@@ -2484,16 +2519,22 @@ pub fn get_connector_metadata(
                         )
                     });
 
+                let amount = get_router_minor_unit_amount(amount, currency)?;
+                let amount_remaining =
+                    get_router_minor_unit_amount(response.amount_remaining, currency)?;
                 let bank_transfer_instructions = SepaAndBacsBankTransferInstructions {
                     sepa_bank_instructions,
                     bacs_bank_instructions,
                     receiver: SepaAndBacsReceiver {
-                        amount_received: amount - response.amount_remaining,
-                        amount_remaining: response.amount_remaining,
+                        amount_received: amount - amount_remaining,
+                        amount_remaining,
                     },
                 };
 
-                Some(bank_transfer_instructions.encode_to_value())
+                bank_transfer_instructions
+                    .encode_to_value()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)
+                    .map(Some)
             }
             StripeNextActionResponse::WechatPayDisplayQrCode(response) => {
                 let wechat_pay_instructions = QrCodeNextInstructions {
@@ -2501,19 +2542,25 @@ pub fn get_connector_metadata(
                     display_to_timestamp: None,
                 };
 
-                Some(wechat_pay_instructions.encode_to_value())
+                wechat_pay_instructions
+                    .encode_to_value()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)
+                    .map(Some)
             }
             StripeNextActionResponse::CashappHandleRedirectOrDisplayQrCode(response) => {
                 let cashapp_qr_instructions: QrCodeNextInstructions = QrCodeNextInstructions {
                     image_data_url: response.qr_code.image_url_png.to_owned(),
                     display_to_timestamp: response.qr_code.expires_at.to_owned(),
                 };
-                Some(cashapp_qr_instructions.encode_to_value())
+                cashapp_qr_instructions
+                    .encode_to_value()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)
+                    .map(Some)
             }
-            _ => None,
+            _ => Ok(None),
         })
-        .transpose()
-        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        .transpose()?
+        .flatten();
     Ok(next_action_response)
 }
 
@@ -2591,8 +2638,11 @@ impl<F, T>
                 }
             });
 
-        let connector_metadata =
-            get_connector_metadata(item.response.next_action.as_ref(), item.response.amount)?;
+        let connector_metadata = get_connector_metadata(
+            item.response.next_action.as_ref(),
+            item.response.amount,
+            &item.response.currency,
+        )?;
 
         let status = enums::AttemptStatus::from(item.response.status.to_owned());
 
@@ -2634,7 +2684,7 @@ impl<F, T>
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status.to_owned()),
             response,
-            amount_captured: item.response.amount_received,
+            amount_captured: item.response.get_router_amount_received()?,
             connector_response: connector_response_data,
             ..item.data
         })
@@ -2891,19 +2941,28 @@ pub struct RefundRequest {
 impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
-        let amount = item.request.refund_amount;
+        let amount = connector_util::to_connector_minor_unit(
+            types::Connector::Stripe,
+            item.request.refund_amount,
+            item.request.currency,
+        )?;
         let payment_intent = item.request.connector_transaction_id.clone();
         Ok(Self {
             amount: Some(amount),
             payment_intent,
-            meta_data: StripeMetadata {
-                order_id: Some(item.request.refund_id.clone()),
-                is_refund_id_as_reference: Some("true".to_string()),
-            },
+            meta_data: get_refund_metadata(&item.request),
         })
     }
 }
 
+fn get_refund_metadata(request: &types::RefundsData) -> StripeMetadata {
+    StripeMetadata {
+        order_id: Some(request.refund_id.clone()),
+        is_refund_id_as_reference: Some("true".to_string()),
+        merchant_refund_reference_id: request.merchant_refund_reference_id.clone(),
+    }
+}
+
 // Type definition for Stripe Refund Response
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone)]
@@ -3168,7 +3227,11 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
-            amount_to_capture: Some(item.request.amount_to_capture),
+            amount_to_capture: Some(connector_util::to_connector_minor_unit(
+                types::Connector::Stripe,
+                item.request.amount_to_capture,
+                item.request.currency,
+            )?),
         })
     }
 }
@@ -3188,7 +3251,11 @@ impl TryFrom<&types::PaymentsPreProcessingRouterData> for StripeCreditTransferSo
                             payment_method_data: MultibancoTransferData {
                                 email: item.request.get_email()?,
                             },
-                            amount: Some(item.request.get_amount()?),
+                            amount: Some(connector_util::to_connector_minor_unit(
+                                types::Connector::Stripe,
+                                item.request.get_amount()?,
+                                currency,
+                            )?),
                             return_url: Some(item.get_return_url()?),
                         }),
                     ),
@@ -3289,7 +3356,12 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for ChargesRequest {
                 order_id,
             ));
             Ok(Self {
-                amount: value.request.amount.to_string(),
+                amount: connector_util::to_connector_minor_unit(
+                    types::Connector::Stripe,
+                    value.request.amount,
+                    value.request.currency,
+                )?
+                .to_string(),
                 currency: value.request.currency.to_string(),
                 customer: Secret::new(value.get_connector_customer_id()?),
                 source: Secret::new(value.get_preprocessing_id()?),
@@ -3445,6 +3517,12 @@ pub struct WebhookEventTypeBody {
     pub event_data: WebhookStatusData,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WebhookEventTimestamp {
+    /// Time at which the event was created, in seconds since the Unix epoch
+    pub created: Option<i64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WebhookEventData {
     #[serde(rename = "object")]
@@ -3482,7 +3560,7 @@ pub struct WebhookPaymentMethodDetails {
 pub struct WebhookEventObjectData {
     pub id: String,
     pub object: WebhookEventObjectType,
-    pub amount: Option<i32>,
+    pub amount: Option<i64>,
     pub currency: String,
     pub payment_intent: Option<String>,
     pub client_secret: Option<Secret<String>>,
@@ -3494,6 +3572,16 @@ pub struct WebhookEventObjectData {
     pub metadata: Option<StripeMetadata>,
 }
 
+impl WebhookEventObjectData {
+    pub fn get_router_amount(
+        &self,
+    ) -> Result<Option<i64>, error_stack::Report<errors::ConnectorError>> {
+        self.amount
+            .map(|amount| get_router_minor_unit_amount(amount, &self.currency))
+            .transpose()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, strum::Display)]
 #[serde(rename_all = "snake_case")]
 pub enum WebhookEventObjectType {
@@ -3873,6 +3961,11 @@ impl TryFrom<&types::SubmitEvidenceRouterData> for Evidence {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripePaymentMethodRetrieveResponse {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DisputeObj {
     #[serde(rename = "id")]
@@ -4106,3 +4199,93 @@ mod test_validate_shipping_address_against_payment_method {
         }
     }
 }
+
+#[cfg(test)]
+mod test_refund_metadata {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::Currency;
+
+    use crate::{connector::stripe::transformers::get_refund_metadata, types};
+
+    fn create_refunds_data(merchant_refund_reference_id: Option<String>) -> types::RefundsData {
+        types::RefundsData {
+            refund_id: "ref_123".to_string(),
+            connector_transaction_id: "pi_123".to_string(),
+            connector_refund_id: None,
+            currency: Currency::USD,
+            payment_amount: 1000,
+            reason: Some("Customer returned product".to_string()),
+            webhook_url: None,
+            refund_amount: 100,
+            connector_metadata: None,
+            browser_info: None,
+            merchant_refund_reference_id,
+        }
+    }
+
+    #[test]
+    fn should_send_merchant_refund_reference_id() {
+        let refunds_data = create_refunds_data(Some("ledger_ref_123".to_string()));
+
+        let request = serde_json::to_value(get_refund_metadata(&refunds_data)).unwrap();
+
+        assert_eq!(
+            request["metadata[merchant_refund_reference_id]"],
+            "ledger_ref_123"
+        );
+        assert_eq!(request["metadata[order_id]"], "ref_123");
+    }
+
+    #[test]
+    fn should_keep_refund_id_as_order_id_without_merchant_reference() {
+        let refunds_data = create_refunds_data(None);
+
+        let metadata = get_refund_metadata(&refunds_data);
+
+        assert_eq!(metadata.order_id, Some("ref_123".to_string()));
+        assert_eq!(metadata.merchant_refund_reference_id, None);
+    }
+}
+
+#[cfg(test)]
+mod test_currency_exponent {
+    #![allow(clippy::unwrap_used)]
+    use crate::connector::stripe::transformers::{
+        get_connector_metadata, get_router_minor_unit_amount, StripeBankTransferDetails,
+        StripeNextActionResponse,
+    };
+
+    #[test]
+    fn should_convert_stripe_amounts_to_router_minor_unit() {
+        assert_eq!(get_router_minor_unit_amount(500000, "ugx").unwrap(), 5000);
+        assert_eq!(get_router_minor_unit_amount(5000, "usd").unwrap(), 5000);
+        assert_eq!(get_router_minor_unit_amount(5000, "xyz").unwrap(), 5000);
+    }
+
+    #[test]
+    fn should_convert_bank_transfer_instruction_amounts_to_router_minor_unit() {
+        let next_action =
+            StripeNextActionResponse::DisplayBankTransferInstructions(StripeBankTransferDetails {
+                amount_remaining: 200000,
+                currency: "ugx".to_string(),
+                reference: None,
+                financial_addresses: vec![],
+                hosted_instructions_url: None,
+                bank_transfer_type: None,
+            });
+
+        let connector_metadata = get_connector_metadata(Some(&next_action), 500000, "ugx")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            connector_metadata,
+            serde_json::json!({
+                "receiver": {
+                    "amount_received": 3000,
+                    "amount_remaining": 2000
+                }
+            })
+        );
+    }
+}
@@ -76,6 +76,8 @@ pub struct StripeConnectPayoutFulfillResponse {
 #[derive(Clone, Debug, Serialize)]
 pub struct StripeConnectReversalRequest {
     amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -236,6 +238,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectPayoutCreateRes
                 status: Some(enums::PayoutStatus::RequiresFulfillment),
                 connector_payout_id: response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -270,6 +273,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectPayoutFulfillRe
                 status: Some(enums::PayoutStatus::from(response.status)),
                 connector_payout_id: response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -283,6 +287,7 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectReversalRequest {
     fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
             amount: item.request.amount,
+            description: item.request.cancellation_reason.clone(),
         })
     }
 }
@@ -302,6 +307,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectReversalRespons
                 status: Some(enums::PayoutStatus::Cancelled),
                 connector_payout_id: response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -376,6 +382,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectRecipientCreate
                 status: Some(enums::PayoutStatus::RequiresVendorAccountCreation),
                 connector_payout_id: response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: true,
             }),
             ..item.data
@@ -459,6 +466,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectRecipientAccoun
                 status: Some(enums::PayoutStatus::RequiresCreation),
                 connector_payout_id: response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -564,6 +564,7 @@ pub trait PaymentsAuthorizeRequestData {
     fn get_connector_mandate_id(&self) -> Result<String, Error>;
     fn get_complete_authorize_url(&self) -> Result<String, Error>;
     fn get_ip_address_as_optional(&self) -> Option<Secret<String, IpAddress>>;
+    fn get_locale_as_optional(&self) -> Option<String>;
     fn get_original_amount(&self) -> i64;
     fn get_surcharge_amount(&self) -> Option<i64>;
     fn get_tax_on_surcharge_amount(&self) -> Option<i64>;
@@ -682,6 +683,11 @@ impl PaymentsAuthorizeRequestData for types::PaymentsAuthorizeData {
                 .map(|ip| Secret::new(ip.to_string()))
         })
     }
+    fn get_locale_as_optional(&self) -> Option<String> {
+        self.browser_info
+            .as_ref()
+            .and_then(|browser_info| browser_info.language.clone())
+    }
     fn get_original_amount(&self) -> i64 {
         self.surcharge_details
             .as_ref()
@@ -1739,6 +1745,70 @@ pub fn to_currency_base_unit_asf64(
         .change_context(errors::ConnectorError::ParsingFailed)
 }
 
+/// Number of digits after the decimal point a connector expects for a currency, for the
+/// currencies where it differs from the exponent used by the router.
+/// Stripe Ref - https://stripe.com/docs/currencies#special-cases
+fn get_connector_currency_exponent_override(
+    connector: types::Connector,
+    currency: enums::Currency,
+) -> Option<u8> {
+    match (connector, currency) {
+        (types::Connector::Stripe, enums::Currency::UGX) => Some(2),
+        _ => None,
+    }
+}
+
+pub fn get_connector_currency_exponent(
+    connector: types::Connector,
+    currency: enums::Currency,
+) -> u8 {
+    get_connector_currency_exponent_override(connector, currency)
+        .unwrap_or_else(|| currency.number_of_digits_after_decimal_point())
+}
+
+/// Convert the amount from the minor unit used by the router to the minor unit expected by the connector
+pub fn to_connector_minor_unit(
+    connector: types::Connector,
+    amount: i64,
+    currency: enums::Currency,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    rescale_minor_unit_amount(
+        amount,
+        currency.number_of_digits_after_decimal_point(),
+        get_connector_currency_exponent(connector, currency),
+    )
+    .ok_or(errors::ConnectorError::RequestEncodingFailed)
+    .attach_printable("Failed to convert amount to the connector currency exponent")
+}
+
+/// Convert the amount from the minor unit used by the connector to the minor unit used by the router
+pub fn to_router_minor_unit(
+    connector: types::Connector,
+    amount: i64,
+    currency: enums::Currency,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    rescale_minor_unit_amount(
+        amount,
+        get_connector_currency_exponent(connector, currency),
+        currency.number_of_digits_after_decimal_point(),
+    )
+    .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+    .attach_printable("Failed to convert amount from the connector currency exponent")
+}
+
+fn rescale_minor_unit_amount(amount: i64, from_exponent: u8, to_exponent: u8) -> Option<i64> {
+    if to_exponent >= from_exponent {
+        10_i64
+            .checked_pow(u32::from(to_exponent - from_exponent))
+            .and_then(|factor| amount.checked_mul(factor))
+    } else {
+        let factor = 10_i64.checked_pow(u32::from(from_exponent - to_exponent))?;
+        (amount.checked_rem(factor)? == 0)
+            .then(|| amount.checked_div(factor))
+            .flatten()
+    }
+}
+
 pub fn str_to_f32<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -2176,6 +2246,7 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
         }
         common_enums::RefundStatus::ManualReview
         | common_enums::RefundStatus::Pending
+        | common_enums::RefundStatus::PendingApproval
         | common_enums::RefundStatus::Success => false,
     }
 }
@@ -2631,3 +2702,48 @@ impl From<types::domain::payments::PaymentMethodData> for PaymentMethodDataType
         }
     }
 }
+
+#[cfg(test)]
+mod connector_currency_exponent_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_connector_minor_unit_with_exponent_override() {
+        assert_eq!(
+            get_connector_currency_exponent(types::Connector::Stripe, enums::Currency::UGX),
+            2
+        );
+        assert_eq!(
+            to_connector_minor_unit(types::Connector::Stripe, 5000, enums::Currency::UGX).unwrap(),
+            500000
+        );
+        assert_eq!(
+            to_router_minor_unit(types::Connector::Stripe, 500000, enums::Currency::UGX).unwrap(),
+            5000
+        );
+        assert!(
+            to_router_minor_unit(types::Connector::Stripe, 500050, enums::Currency::UGX).is_err()
+        );
+    }
+
+    #[test]
+    fn test_connector_minor_unit_with_standard_exponent() {
+        assert_eq!(
+            get_connector_currency_exponent(types::Connector::Stripe, enums::Currency::USD),
+            2
+        );
+        assert_eq!(
+            to_connector_minor_unit(types::Connector::Stripe, 5000, enums::Currency::USD).unwrap(),
+            5000
+        );
+        assert_eq!(
+            to_connector_minor_unit(types::Connector::Adyen, 5000, enums::Currency::UGX).unwrap(),
+            5000
+        );
+        assert_eq!(
+            to_router_minor_unit(types::Connector::Adyen, 5000, enums::Currency::UGX).unwrap(),
+            5000
+        );
+    }
+}
@@ -408,6 +408,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseRecipientCreateResponse>
                 status: Some(storage_enums::PayoutStatus::RequiresCreation),
                 connector_payout_id: response.id.to_string(),
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -454,6 +455,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutQuoteResponse>>
                 status: Some(storage_enums::PayoutStatus::RequiresCreation),
                 connector_payout_id: response.id,
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -517,6 +519,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutResponse>>
                 status: Some(status),
                 connector_payout_id: response.id.to_string(),
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -559,6 +562,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseFulfillResponse>>
                 status: Some(storage_enums::PayoutStatus::from(response.status)),
                 connector_payout_id: "".to_string(),
                 payout_eligible: None,
+                eligibility_details: None,
                 should_add_next_step_to_process_tracker: false,
             }),
             ..item.data
@@ -42,6 +42,9 @@ pub(crate) const CANNOT_CONTINUE_AUTH: &str =
     "Cannot continue with Authorization due to failed Liability Shift.";
 #[cfg(feature = "payouts")]
 pub(crate) const DEFAULT_NOTIFICATION_SCRIPT_LANGUAGE: &str = "en-US";
+/// Length of the `cancellation_reason` column of `payout_attempt`
+#[cfg(feature = "payouts")]
+pub(crate) const PAYOUT_CANCELLATION_REASON_MAX_LENGTH: usize = 255;
 
 // General purpose base64 engines
 pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
@@ -50,6 +53,10 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+/// Default time for which a rotated API key continues to be accepted, in seconds
+pub(crate) const DEFAULT_API_KEY_ROTATION_GRACE_PERIOD_IN_SECS: u32 = 24 * 60 * 60;
+/// Maximum time for which a rotated API key can continue to be accepted, in seconds
+pub(crate) const MAX_API_KEY_ROTATION_GRACE_PERIOD_IN_SECS: u32 = 30 * 24 * 60 * 60;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
 
 // Apple Pay validation url
@@ -65,6 +72,7 @@ pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32
 
 pub(crate) const METRICS_HOST_TAG_NAME: &str = "host";
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
+pub const MAX_PAYMENTS_PER_BULK_REJECT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
 
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
@@ -80,6 +88,8 @@ pub const JWT_TOKEN_COOKIE_NAME: &str = "login_token";
 
 pub const USER_BLACKLIST_PREFIX: &str = "BU_";
 
+pub const USER_MERCHANT_BLACKLIST_PREFIX: &str = "BUM_";
+
 pub const ROLE_BLACKLIST_PREFIX: &str = "BR_";
 
 #[cfg(feature = "email")]
@@ -114,8 +124,42 @@ pub const OUTGOING_CALL_URL: &str = "https://api.stripe.com/healthcheck";
 // 15 minutes = 900 seconds
 pub const POLL_ID_TTL: i64 = 900;
 
+// 100 days, longer than the maximum session expiry of a payment
+pub const MANDATE_DEBIT_RESERVATION_TTL: i64 = 60 * 60 * 24 * 100;
+
 // Default Poll Config
 pub const DEFAULT_POLL_DELAY_IN_SECS: i8 = 2;
 pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
+pub const DEFAULT_MAX_POLL_COUNT: u16 = 10;
+pub const DEFAULT_POLL_INTERVAL_IN_SECS: u16 = 2;
+
+// Error reason set on a payment attempt whose status poll ran out of attempts
+pub const POLL_EXHAUSTED_ERROR_REASON: &str = "poll_exhausted";
+pub const POLL_EXHAUSTED_ERROR_MESSAGE: &str =
+    "Maximum number of status polls exhausted before the payment reached a final state";
 
 pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
+
+/// Sub flow used when looking up GSM rules for connector errors
+pub const DEFAULT_GSM_SUB_FLOW: &str = "sub_flow";
+
+/// GSM rules configured with this message match any error message for the error code
+pub const GSM_WILDCARD_MESSAGE: &str = "*";
+
+/// Default validity of a presigned file download URL
+pub const DEFAULT_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS: u32 = 60 * 15; // 15 minutes
+
+/// Max validity of a presigned file download URL, as allowed by object stores
+pub const MAX_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS: u32 = 60 * 60 * 24 * 7; // 7 days
+
+/// Validity of an Apple Pay merchant domain verification
+pub const APPLEPAY_VERIFICATION_VALIDITY_IN_DAYS: i64 = 365;
+
+/// Apple Pay domains are verified again when their verification is this close to expiring
+pub const APPLEPAY_REVERIFICATION_WINDOW_IN_DAYS: i64 = 30;
+
+/// Delay before the first retry of a failed Apple Pay domain re-verification, doubled on every retry
+pub const APPLEPAY_REVERIFICATION_RETRY_BASE_DELAY_IN_SECS: i64 = 15 * 60; // 15 minutes
+
+/// Max delay between retries of a failed Apple Pay domain re-verification
+pub const APPLEPAY_REVERIFICATION_RETRY_MAX_DELAY_IN_SECS: i64 = 60 * 60 * 24; // 1 day
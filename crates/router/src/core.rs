@@ -9,6 +9,7 @@ pub mod conditional_config;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod connector_replay;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
@@ -22,6 +23,7 @@ pub mod health_check;
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
+pub mod organization;
 pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
@@ -29,6 +31,8 @@ pub mod payments;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod recon;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_config;
@@ -5,7 +5,7 @@ use api_models::{
     enums as api_enums, routing as routing_types,
 };
 use common_utils::{
-    crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
+    crypto::{generate_cryptographically_secure_random_string, Encryptable, OptionalSecretValue},
     date_time,
     ext_traits::{AsyncExt, ConfigExt, Encode, ValueExt},
     pii,
@@ -21,7 +21,7 @@ use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payments::{connector_health, helpers},
         routing::helpers as routing_helpers,
         utils as core_utils,
     },
@@ -84,6 +84,8 @@ pub async fn create_merchant_account(
         .transpose()?
         .map(Into::into);
 
+    validate_webhook_retry_schedule(req.webhook_details.as_ref())?;
+
     let webhook_details = req
         .webhook_details
         .as_ref()
@@ -97,13 +99,18 @@ pub async fn create_merchant_account(
         .transpose()?;
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api_models::routing::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api_models::routing::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing_algorithm.validate_volume_splits().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing_algorithm",
+            },
+        )?;
     }
 
     let key_store = domain::MerchantKeyStore {
@@ -442,6 +449,7 @@ pub async fn update_business_profile_cascade(
             extended_card_info_config: None,
             use_billing_as_payment_method_billing: None,
             collect_shipping_details_from_wallet_connector: None,
+            dispute_auto_accept_threshold: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -489,15 +497,22 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api_models::routing::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api_models::routing::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing_algorithm.validate_volume_splits().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing_algorithm",
+            },
+        )?;
     }
 
+    validate_webhook_retry_schedule(req.webhook_details.as_ref())?;
+
     let primary_business_details = req
         .primary_business_details
         .as_ref()
@@ -701,6 +716,7 @@ fn validate_certificate_in_mca_metadata(
     connector_metadata: Secret<serde_json::Value>,
 ) -> RouterResult<()> {
     let parsed_connector_metadata = connector_metadata
+        .clone()
         .parse_value::<api_models::payments::ConnectorMetadata>("ConnectorMetadata")
         .change_context(errors::ParsingError::StructParseFailure("Metadata"))
         .change_context(errors::ApiErrorResponse::InvalidDataFormat {
@@ -708,23 +724,58 @@ fn validate_certificate_in_mca_metadata(
             expected_format: "connector metadata".to_string(),
         })?;
 
-    parsed_connector_metadata
+    // The session token data is present either in the `apple_pay` metadata or in the manual flow
+    // of the `apple_pay_combined` metadata
+    let session_token_data = parsed_connector_metadata
+        .apple_pay
+        .and_then(|applepay_metadata| applepay_metadata.session_token_data)
+        .or_else(|| {
+            connector_metadata
+                .parse_value::<api_models::payments::ApplepayCombinedSessionTokenData>(
+                    "ApplepayCombinedSessionTokenData",
+                )
+                .ok()
+                .and_then(
+                    |combined_metadata| match combined_metadata.apple_pay_combined {
+                        api_models::payments::ApplePayCombinedMetadata::Manual {
+                            session_token_data,
+                            ..
+                        } => Some(session_token_data),
+                        api_models::payments::ApplePayCombinedMetadata::Simplified { .. } => None,
+                    },
+                )
+        });
+
+    session_token_data
+        .map(|session_token_data| {
+            let api_models::payments::SessionTokenInfo {
+                certificate,
+                certificate_keys,
+                ..
+            } = session_token_data;
+
+            helpers::create_identity_from_certificate_and_key(certificate, certificate_keys)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "certificate/certificate key",
+                })
+                .map(|_identity_result| ())
+        })
+        .transpose()?;
+
+    Ok(())
+}
+
+fn validate_connector_wallets_details(
+    connector_wallets_details: &api_models::admin::ConnectorWalletDetails,
+) -> RouterResult<()> {
+    connector_wallets_details
         .apple_pay
-        .and_then(|applepay_metadata| {
-            applepay_metadata
-                .session_token_data
-                .map(|session_token_data| {
-                    let api_models::payments::SessionTokenInfo {
-                        certificate,
-                        certificate_keys,
-                        ..
-                    } = session_token_data;
-
-                    helpers::create_identity_from_certificate_and_key(certificate, certificate_keys)
-                        .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                            field_name: "certificate/certificate key",
-                        })
-                        .map(|_identity_result| ())
+        .clone()
+        .map(|apple_pay_details| {
+            helpers::ApplePayPaymentProcessingCertificate::from(apple_pay_details)
+                .validate()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "connector_wallets_details.apple_pay",
                 })
         })
         .transpose()?;
@@ -732,6 +783,23 @@ fn validate_certificate_in_mca_metadata(
     Ok(())
 }
 
+async fn get_encrypted_connector_wallets_details(
+    connector_wallets_details: Option<&api_models::admin::ConnectorWalletDetails>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<Encryptable<Secret<serde_json::Value>>>> {
+    let connector_wallets_details = connector_wallets_details
+        .map(|connector_wallets_details| connector_wallets_details.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize connector wallets details")?
+        .map(Secret::new);
+
+    domain_types::encrypt_optional(connector_wallets_details, key_store.key.get_inner().peek())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt connector wallets details")
+}
+
 pub async fn create_payment_connector(
     state: AppState,
     req: api::MerchantConnectorCreate,
@@ -753,6 +821,11 @@ pub async fn create_payment_connector(
         .map(validate_certificate_in_mca_metadata)
         .transpose()?;
 
+    req.connector_wallets_details
+        .as_ref()
+        .map(validate_connector_wallets_details)
+        .transpose()?;
+
     let merchant_account = state
         .store
         .find_merchant_account_by_merchant_id(merchant_id, &key_store)
@@ -961,6 +1034,13 @@ pub async fn create_payment_connector(
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config.clone(),
         status: connector_status,
+        connector_wallets_details: get_encrypted_connector_wallets_details(
+            req.connector_wallets_details.as_ref(),
+            &key_store,
+        )
+        .await?,
+        applepay_verified_at: None,
+        applepay_verification_expires_at: None,
     };
 
     let transaction_type = match req.connector_type {
@@ -1022,6 +1102,14 @@ pub async fn create_payment_connector(
             )
             .await?;
         }
+
+        connector_health::add_connector_health_probe_task_if_enabled(
+            &state,
+            &routable_connector_val.to_string(),
+        )
+        .await
+        .map_err(|error| crate::logger::error!(connector_health_probe_task_error=?error))
+        .ok();
     }
 
     metrics::MCA_CREATE.add(
@@ -1181,6 +1269,16 @@ pub async fn update_payment_connector(
             id: merchant_connector_id.to_string(),
         })?;
 
+    req.metadata
+        .clone()
+        .map(validate_certificate_in_mca_metadata)
+        .transpose()?;
+
+    req.connector_wallets_details
+        .as_ref()
+        .map(validate_connector_wallets_details)
+        .transpose()?;
+
     let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
         pm_enabled
             .iter()
@@ -1286,6 +1384,13 @@ pub async fn update_payment_connector(
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config,
         status: Some(connector_status),
+        connector_wallets_details: get_encrypted_connector_wallets_details(
+            req.connector_wallets_details.as_ref(),
+            &key_store,
+        )
+        .await?,
+        applepay_verified_at: None,
+        applepay_verification_expires_at: None,
     };
 
     // Profile id should always be present
@@ -1500,6 +1605,8 @@ pub async fn create_business_profile(
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+
+    validate_webhook_retry_schedule(request.webhook_details.as_ref())?;
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1617,6 +1724,8 @@ pub async fn update_business_profile(
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
 
+    validate_webhook_retry_schedule(request.webhook_details.as_ref())?;
+
     let webhook_details = request
         .webhook_details
         .as_ref()
@@ -1651,6 +1760,18 @@ pub async fn update_business_profile(
         })
         .transpose()?;
 
+    let dispute_auto_accept_threshold = request
+        .dispute_auto_accept_threshold
+        .as_ref()
+        .map(|dispute_auto_accept_threshold| {
+            dispute_auto_accept_threshold
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dispute_auto_accept_threshold",
+                })
+        })
+        .transpose()?;
+
     let extended_card_info_config = request
         .extended_card_info_config
         .as_ref()
@@ -1696,6 +1817,7 @@ pub async fn update_business_profile(
         use_billing_as_payment_method_billing: request.use_billing_as_payment_method_billing,
         collect_shipping_details_from_wallet_connector: request
             .collect_shipping_details_from_wallet_connector,
+        dispute_auto_accept_threshold,
     };
 
     let updated_business_profile = db
@@ -2109,3 +2231,48 @@ pub fn validate_status_and_disabled(
 
     Ok((connector_status, disabled))
 }
+
+/// The maximum delay (in seconds) allowed between two webhook delivery retry attempts
+const MAX_WEBHOOK_RETRY_DELAY_IN_SECONDS: u32 = 60 * 60 * 24;
+
+fn validate_webhook_retry_schedule(
+    webhook_details: Option<&api_models::admin::WebhookDetails>,
+) -> RouterResult<()> {
+    let Some(retry_schedule) =
+        webhook_details.and_then(|webhook_details| webhook_details.retry_schedule.as_ref())
+    else {
+        return Ok(());
+    };
+
+    let is_valid_delay = |delay: u32| delay > 0 && delay <= MAX_WEBHOOK_RETRY_DELAY_IN_SECONDS;
+
+    let is_valid = match retry_schedule {
+        api_models::admin::WebhookRetrySchedule::Fixed { delays_in_seconds } => {
+            !delays_in_seconds.is_empty() && delays_in_seconds.iter().copied().all(is_valid_delay)
+        }
+        api_models::admin::WebhookRetrySchedule::Exponential {
+            initial_delay_in_seconds,
+            multiplier,
+            max_delay_in_seconds,
+            max_retries,
+        } => {
+            is_valid_delay(*initial_delay_in_seconds)
+                && is_valid_delay(*max_delay_in_seconds)
+                && initial_delay_in_seconds <= max_delay_in_seconds
+                && *multiplier >= 1
+                && *max_retries > 0
+        }
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Invalid `retry_schedule` in `webhook_details`, retry delays must be between 1 and \
+                {MAX_WEBHOOK_RETRY_DELAY_IN_SECONDS} seconds, with at least one retry attempt"
+            ),
+        }
+        .into())
+    }
+}
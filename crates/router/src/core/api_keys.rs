@@ -1,16 +1,19 @@
-use common_utils::date_time;
+use common_utils::{date_time, fp_utils::when};
 #[cfg(feature = "email")]
-use diesel_models::{api_keys::ApiKey, enums as storage_enums};
+use diesel_models::api_keys::ApiKey;
+use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
     configs::settings,
     consts,
     core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
     routes::{metrics, AppState},
-    services::ApplicationResponse,
+    services::{authorization::permissions::Permission, ApplicationResponse},
     types::{api, storage, transformers::ForeignInto},
     utils,
 };
@@ -128,18 +131,71 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    when(
+        api_key
+            .permissions
+            .as_ref()
+            .is_some_and(|permissions| permissions.is_empty()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "permissions must not be empty, omit it to create a full access key"
+                    .to_string()
+            }))
+        },
+    )?;
+
     let hash_key = api_key_config.get_hash_key()?;
+    let (api_key, plaintext_api_key) = insert_new_api_key(
+        store,
+        hash_key.peek(),
+        merchant_id,
+        api_key.name,
+        api_key.description,
+        api_key.expiration.into(),
+        api_key.permissions,
+    )
+    .await?;
+
+    // Add process to process_tracker for email reminder, only if expiry is set to future date
+    // If the `api_key` is set to expire in less than 7 days, the merchant is not notified about it's expiry
+    #[cfg(feature = "email")]
+    {
+        if api_key.expires_at.is_some() {
+            let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
+
+            add_api_key_expiry_task(store, &api_key, expiry_reminder_days)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        (api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
+async fn insert_new_api_key(
+    store: &dyn StorageInterface,
+    hash_key: &[u8; PlaintextApiKey::HASH_KEY_LEN],
+    merchant_id: String,
+    name: String,
+    description: Option<String>,
+    expires_at: Option<PrimitiveDateTime>,
+    permissions: Option<Vec<storage_enums::ApiKeyPermission>>,
+) -> errors::RouterResult<(storage::ApiKey, PlaintextApiKey)> {
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
         key_id: PlaintextApiKey::new_key_id(),
-        merchant_id: merchant_id.to_owned(),
-        name: api_key.name,
-        description: api_key.description,
-        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        merchant_id: merchant_id.clone(),
+        name,
+        description,
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key).into(),
         prefix: plaintext_api_key.prefix(),
         created_at: date_time::now(),
-        expires_at: api_key.expiration.into(),
+        expires_at,
         last_used: None,
+        permissions,
     };
 
     let api_key = store
@@ -154,23 +210,7 @@ pub async fn create_api_key(
         &[metrics::request::add_attributes("merchant", merchant_id)],
     );
 
-    // Add process to process_tracker for email reminder, only if expiry is set to future date
-    // If the `api_key` is set to expire in less than 7 days, the merchant is not notified about it's expiry
-    #[cfg(feature = "email")]
-    {
-        if api_key.expires_at.is_some() {
-            let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
-
-            add_api_key_expiry_task(store, &api_key, expiry_reminder_days)
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
-        }
-    }
-
-    Ok(ApplicationResponse::Json(
-        (api_key, plaintext_api_key).foreign_into(),
-    ))
+    Ok((api_key, plaintext_api_key))
 }
 
 // Add api_key_expiry task to the process_tracker table.
@@ -461,6 +501,182 @@ pub async fn revoke_api_key_expiry_task(
     Ok(())
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: AppState,
+    api_key: api::RotateApiKeyRequest,
+) -> RouterResponse<api::RotateApiKeyResponse> {
+    let store = state.store.as_ref();
+    let hash_key = state.conf.api_keys.get_inner().get_hash_key()?;
+    let grace_period = get_api_key_rotation_grace_period(api_key.grace_period_in_secs)?;
+
+    let old_api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&api_key.merchant_id, &api_key.key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
+    let (new_api_key, plaintext_api_key, old_api_key) = rotate_api_key_in_store(
+        store,
+        hash_key.peek(),
+        old_api_key,
+        api_key.expiration.and_then(Into::into),
+        grace_period,
+        date_time::now(),
+    )
+    .await?;
+
+    // The merchant is already switching over to the new API key, so the expiry reminders of the
+    // rotated API key are no longer needed
+    #[cfg(feature = "email")]
+    {
+        let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
+
+        let task_id = generate_task_id_for_api_key_expiry_workflow(&old_api_key.key_id);
+        let existing_process_tracker_task = store
+            .find_process_by_id(task_id.as_str())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+            .attach_printable(
+                "Failed to retrieve API key expiry reminder task from process tracker",
+            )?;
+
+        if existing_process_tracker_task.is_some() {
+            revoke_api_key_expiry_task(store, &old_api_key.key_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to revoke API key expiry reminder task in process tracker",
+                )?;
+        }
+
+        if new_api_key.expires_at.is_some() {
+            add_api_key_expiry_task(store, &new_api_key, expiry_reminder_days)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(api::RotateApiKeyResponse {
+        new_api_key: (new_api_key, plaintext_api_key).foreign_into(),
+        old_api_key: old_api_key.foreign_into(),
+    }))
+}
+
+async fn rotate_api_key_in_store(
+    store: &dyn StorageInterface,
+    hash_key: &[u8; PlaintextApiKey::HASH_KEY_LEN],
+    old_api_key: storage::ApiKey,
+    new_api_key_expires_at: Option<PrimitiveDateTime>,
+    grace_period: time::Duration,
+    rotated_at: PrimitiveDateTime,
+) -> errors::RouterResult<(storage::ApiKey, PlaintextApiKey, storage::ApiKey)> {
+    if is_api_key_expired(old_api_key.expires_at, rotated_at) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "API key has already expired and cannot be rotated".to_string()
+        }));
+    }
+
+    let (new_api_key, plaintext_api_key) = insert_new_api_key(
+        store,
+        hash_key,
+        old_api_key.merchant_id.clone(),
+        old_api_key.name.clone(),
+        old_api_key.description.clone(),
+        new_api_key_expires_at,
+        old_api_key.permissions.clone(),
+    )
+    .await?;
+
+    let old_api_key = store
+        .update_api_key(
+            old_api_key.merchant_id,
+            old_api_key.key_id,
+            storage::ApiKeyUpdate::Update {
+                name: None,
+                description: None,
+                expires_at: Some(Some(get_rotated_api_key_expiry(
+                    old_api_key.expires_at,
+                    rotated_at,
+                    grace_period,
+                ))),
+                last_used: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)
+        .attach_printable("Failed to set the expiry of the rotated API key")?;
+
+    Ok((new_api_key, plaintext_api_key, old_api_key))
+}
+
+fn get_api_key_rotation_grace_period(
+    grace_period_in_secs: Option<u32>,
+) -> errors::RouterResult<time::Duration> {
+    let grace_period_in_secs =
+        grace_period_in_secs.unwrap_or(consts::DEFAULT_API_KEY_ROTATION_GRACE_PERIOD_IN_SECS);
+
+    when(
+        grace_period_in_secs > consts::MAX_API_KEY_ROTATION_GRACE_PERIOD_IN_SECS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "grace_period_in_secs must not exceed {}",
+                    consts::MAX_API_KEY_ROTATION_GRACE_PERIOD_IN_SECS
+                )
+            }))
+        },
+    )?;
+
+    Ok(time::Duration::seconds(i64::from(grace_period_in_secs)))
+}
+
+/// A rotated API key expires at the end of the grace period, unless it was already set to expire
+/// before that.
+fn get_rotated_api_key_expiry(
+    expires_at: Option<PrimitiveDateTime>,
+    rotated_at: PrimitiveDateTime,
+    grace_period: time::Duration,
+) -> PrimitiveDateTime {
+    let grace_period_end = rotated_at.saturating_add(grace_period);
+    expires_at.map_or(grace_period_end, |expires_at| {
+        expires_at.min(grace_period_end)
+    })
+}
+
+/// API keys without any permissions have full access to the merchant account, while the other API
+/// keys are only allowed to perform the operations covered by their permissions. Operations which
+/// don't require a specific permission are only allowed for full access API keys.
+pub fn is_api_key_permitted(
+    permissions: Option<&[storage_enums::ApiKeyPermission]>,
+    required_permission: Option<Permission>,
+) -> bool {
+    permissions.map_or(true, |permissions| {
+        required_permission
+            .and_then(get_api_key_permission)
+            .is_some_and(|required_permission| permissions.contains(&required_permission))
+    })
+}
+
+fn get_api_key_permission(permission: Permission) -> Option<storage_enums::ApiKeyPermission> {
+    match permission {
+        Permission::PaymentRead => Some(storage_enums::ApiKeyPermission::PaymentsRead),
+        Permission::PaymentWrite => Some(storage_enums::ApiKeyPermission::PaymentsWrite),
+        Permission::RefundRead => Some(storage_enums::ApiKeyPermission::RefundsRead),
+        Permission::RefundWrite => Some(storage_enums::ApiKeyPermission::RefundsWrite),
+        _ => None,
+    }
+}
+
+pub fn is_api_key_expired(
+    expires_at: Option<PrimitiveDateTime>,
+    current_time: PrimitiveDateTime,
+) -> bool {
+    expires_at.is_some_and(|expires_at| expires_at < current_time)
+}
+
 #[instrument(skip_all)]
 pub async fn list_api_keys(
     state: AppState,
@@ -532,4 +748,104 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[tokio::test]
+    async fn test_rotation_issues_new_api_key() {
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let hash_key = [7; PlaintextApiKey::HASH_KEY_LEN];
+        let rotated_at = date_time::now();
+        let grace_period = get_api_key_rotation_grace_period(Some(3600)).unwrap();
+
+        let (old_api_key, old_plaintext_api_key) = insert_new_api_key(
+            &db,
+            &hash_key,
+            "merchant_1".to_string(),
+            "Sandbox integration key".to_string(),
+            None,
+            None,
+            Some(vec![storage_enums::ApiKeyPermission::PaymentsRead]),
+        )
+        .await
+        .unwrap();
+
+        let (new_api_key, new_plaintext_api_key, old_api_key) =
+            rotate_api_key_in_store(&db, &hash_key, old_api_key, None, grace_period, rotated_at)
+                .await
+                .unwrap();
+
+        assert_ne!(new_api_key.key_id, old_api_key.key_id);
+        assert_ne!(new_plaintext_api_key.peek(), old_plaintext_api_key.peek());
+        assert_eq!(new_api_key.name, old_api_key.name);
+        assert_eq!(
+            HashedApiKey::from(new_api_key.hashed_api_key),
+            new_plaintext_api_key.keyed_hash(&hash_key)
+        );
+        assert_eq!(new_api_key.expires_at, None);
+        assert_eq!(new_api_key.permissions, old_api_key.permissions);
+        assert_eq!(
+            old_api_key.expires_at,
+            Some(rotated_at.saturating_add(time::Duration::hours(1)))
+        );
+    }
+
+    #[test]
+    fn test_payments_read_api_key_can_only_retrieve_payments() {
+        let permissions = [storage_enums::ApiKeyPermission::PaymentsRead];
+
+        assert!(is_api_key_permitted(
+            Some(&permissions),
+            Some(Permission::PaymentRead)
+        ));
+        assert!(!is_api_key_permitted(
+            Some(&permissions),
+            Some(Permission::PaymentWrite)
+        ));
+        assert!(!is_api_key_permitted(
+            Some(&permissions),
+            Some(Permission::RefundRead)
+        ));
+        assert!(!is_api_key_permitted(Some(&permissions), None));
+    }
+
+    #[test]
+    fn test_api_key_without_permissions_has_full_access() {
+        assert!(is_api_key_permitted(None, Some(Permission::PaymentRead)));
+        assert!(is_api_key_permitted(None, Some(Permission::PaymentWrite)));
+        assert!(is_api_key_permitted(None, None));
+    }
+
+    #[test]
+    fn test_rotated_api_key_is_rejected_after_grace_period() {
+        let rotated_at = date_time::now();
+        let grace_period = get_api_key_rotation_grace_period(None).unwrap();
+        let expires_at = Some(get_rotated_api_key_expiry(None, rotated_at, grace_period));
+
+        assert!(!is_api_key_expired(expires_at, rotated_at));
+        assert!(!is_api_key_expired(
+            expires_at,
+            rotated_at.saturating_add(time::Duration::hours(23))
+        ));
+        assert!(is_api_key_expired(
+            expires_at,
+            rotated_at.saturating_add(time::Duration::hours(24) + time::Duration::seconds(1))
+        ));
+    }
+
+    #[test]
+    fn test_rotation_does_not_extend_earlier_expiry() {
+        let rotated_at = date_time::now();
+        let grace_period = get_api_key_rotation_grace_period(None).unwrap();
+        let expires_at = rotated_at.saturating_add(time::Duration::hours(2));
+
+        assert_eq!(
+            get_rotated_api_key_expiry(Some(expires_at), rotated_at, grace_period),
+            expires_at
+        );
+        assert!(get_api_key_rotation_grace_period(Some(
+            consts::MAX_API_KEY_ROTATION_GRACE_PERIOD_IN_SECS + 1
+        ))
+        .is_err());
+    }
 }
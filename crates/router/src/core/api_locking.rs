@@ -108,6 +108,47 @@ impl LockAction {
         }
     }
 
+    /// Extends the expiry of the lock held by the current request, for requests which run for
+    /// longer than the lock expiry. Fails if the lock has meanwhile expired and been acquired by
+    /// another request.
+    #[instrument(skip_all)]
+    pub async fn renew_lock_action<A>(self, state: &A, merchant_id: String) -> RouterResult<()>
+    where
+        A: AppStateInfo,
+    {
+        match self {
+            Self::Hold { input } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_locking_key = input.get_redis_locking_key(merchant_id);
+
+                let lock_holder = redis_conn
+                    .get_key::<Option<String>>(&redis_locking_key)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                if lock_holder != state.get_request_id() {
+                    return Err(report!(errors::ApiErrorResponse::ResourceBusy)).attach_printable(
+                        "The lock is no longer held by the request_id requesting to renew it",
+                    );
+                }
+
+                redis_conn
+                    .set_expiry(
+                        &redis_locking_key,
+                        i64::from(state.conf().lock_settings.redis_lock_expiry_seconds),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                logger::info!("Lock renewed for locking input {:?}", input);
+                Ok(())
+            }
+            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+        }
+    }
+
     #[instrument(skip_all)]
     pub async fn free_lock_action<A>(self, state: &A, merchant_id: String) -> RouterResult<()>
     where
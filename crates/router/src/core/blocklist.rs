@@ -30,6 +30,41 @@ pub async fn remove_entry_from_blocklist(
         .map(services::ApplicationResponse::Json)
 }
 
+pub async fn bulk_add_entries_to_blocklist(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    body: api_blocklist::BulkAddToBlocklistRequest,
+) -> RouterResponse<api_blocklist::BulkBlocklistResponse> {
+    let merchant_id = merchant_account.merchant_id;
+    let response = utils::process_bulk_blocklist_request(body, |entry| {
+        utils::insert_entry_into_blocklist(
+            &state,
+            merchant_id.clone(),
+            api_blocklist::AddToBlocklistRequest {
+                entry,
+                expires_at: None,
+            },
+        )
+    })
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+pub async fn bulk_remove_entries_from_blocklist(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    body: api_blocklist::BulkDeleteFromBlocklistRequest,
+) -> RouterResponse<api_blocklist::BulkBlocklistResponse> {
+    let merchant_id = merchant_account.merchant_id;
+    let response = utils::process_bulk_blocklist_request(body, |entry| {
+        utils::delete_entry_from_blocklist(&state, merchant_id.clone(), entry)
+    })
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
 pub async fn list_blocklist_entries(
     state: AppState,
     merchant_account: domain::MerchantAccount,
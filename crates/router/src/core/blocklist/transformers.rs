@@ -28,6 +28,7 @@ impl ForeignFrom<storage::Blocklist> for blocklist::AddToBlocklistResponse {
             fingerprint_id: from.fingerprint_id,
             data_kind: from.data_kind,
             created_at: from.created_at,
+            expires_at: from.expires_at,
         }
     }
 }
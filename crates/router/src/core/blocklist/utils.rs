@@ -1,9 +1,13 @@
+use std::collections::HashSet;
+
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
 use common_utils::errors::CustomResult;
 use diesel_models::configs;
 use error_stack::ResultExt;
 use masking::StrongSecret;
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::{errors, transformers::generate_fingerprint, AppState};
 use crate::{
@@ -13,10 +17,36 @@ use crate::{
         payments::PaymentData,
     },
     logger,
+    routes::metrics,
     types::{domain, storage, transformers::ForeignInto},
     utils,
 };
 
+const BLOCKLIST_ENTRY_EXPIRY_TASK_TAG: &str = "BLOCKLIST";
+
+const BLOCKLIST_ENTRY_EXPIRY_TASK_NAME: &str = "BLOCKLIST_ENTRY_EXPIRY";
+
+const BLOCKLIST_ENTRY_EXPIRY_TASK_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::BlocklistEntryExpiryWorkflow;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BlocklistEntryExpiryTrackingData {
+    pub merchant_id: String,
+    pub fingerprint_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlocklistEntryExpiryDecision {
+    /// The entry has expired and should be removed from the blocklist
+    Purge,
+    /// The entry has not expired yet, it should be checked again at the given time
+    RetryAt(PrimitiveDateTime),
+    /// The entry was removed, made permanent or replaced by an entry with a different expiry
+    Skip,
+}
+
 pub async fn delete_entry_from_blocklist(
     state: &AppState,
     merchant_id: String,
@@ -137,41 +167,133 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+fn validate_blocklist_request(request: &api_blocklist::BlocklistRequest) -> RouterResult<()> {
+    match request {
+        api_blocklist::BlocklistRequest::CardBin(bin) => validate_card_bin(bin),
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => validate_extended_card_bin(bin),
+        api_blocklist::BlocklistRequest::Fingerprint(_) => Ok(()),
+    }
+}
+
+/// Processes each entry of a bulk blocklist request with `process_entry`, continuing past entries
+/// that fail. Entries repeated within the request are only processed once, the repetitions are
+/// reported as failed.
+pub async fn process_bulk_blocklist_request<F, Fut>(
+    request: api_blocklist::BulkBlocklistRequest,
+    mut process_entry: F,
+) -> api_blocklist::BulkBlocklistResponse
+where
+    F: FnMut(api_blocklist::BlocklistRequest) -> Fut,
+    Fut: futures::Future<Output = RouterResult<api_blocklist::BlocklistResponse>>,
+{
+    let mut seen_entries = HashSet::new();
+    let mut results = Vec::with_capacity(request.entries.len());
+
+    for entry in request.entries {
+        let result = if !seen_entries.insert(entry.clone()) {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "entry is repeated within the request".to_string(),
+            }
+            .into())
+        } else {
+            process_entry(entry.clone()).await
+        };
+
+        results.push(match result {
+            Ok(blocklist_entry) => api_blocklist::BulkBlocklistEntryResult {
+                entry,
+                status: api_blocklist::BulkBlocklistEntryStatus::Succeeded,
+                blocklist_entry: Some(blocklist_entry),
+                error_code: None,
+                error_message: None,
+            },
+            Err(error) => {
+                logger::error!(bulk_blocklist_entry_error=?error);
+                api_blocklist::BulkBlocklistEntryResult {
+                    entry,
+                    status: api_blocklist::BulkBlocklistEntryStatus::Failed,
+                    blocklist_entry: None,
+                    error_code: Some(error.current_context().error_code()),
+                    error_message: Some(error.current_context().error_message()),
+                }
+            }
+        });
+    }
+
+    api_blocklist::BulkBlocklistResponse { entries: results }
+}
+
+fn validate_blocklist_entry_expiry(
+    expires_at: Option<PrimitiveDateTime>,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    match expires_at {
+        Some(expires_at) if expires_at <= current_time => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "expires_at must be a time in the future".to_string(),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether the blocklist entry still blocks payments, entries without an expiry are permanent
+fn is_blocklist_entry_active(
+    blocklist_entry: &storage::Blocklist,
+    current_time: PrimitiveDateTime,
+) -> bool {
+    blocklist_entry
+        .expires_at
+        .map_or(true, |expires_at| current_time < expires_at)
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &AppState,
     merchant_id: String,
     to_block: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
-            validate_card_bin(bin)?;
+    validate_blocklist_request(&to_block.entry)?;
+    validate_blocklist_entry_expiry(to_block.expires_at, common_utils::date_time::now())?;
+    let blocklist_entry = match &to_block.entry {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             duplicate_check_insert_bin(
                 bin,
                 state,
                 &merchant_id,
                 common_enums::BlocklistDataKind::CardBin,
+                to_block.expires_at,
             )
             .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
-            validate_extended_card_bin(bin)?;
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             duplicate_check_insert_bin(
                 bin,
                 state,
                 &merchant_id,
                 common_enums::BlocklistDataKind::ExtendedCardBin,
+                to_block.expires_at,
             )
             .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => {
             let blocklist_entry_result = state
                 .store
                 .find_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, fingerprint_id)
                 .await;
 
             match blocklist_entry_result {
+                Ok(blocklist_entry)
+                    if !is_blocklist_entry_active(
+                        &blocklist_entry,
+                        common_utils::date_time::now(),
+                    ) =>
+                {
+                    delete_expired_blocklist_entry(state, &blocklist_entry).await?;
+                }
+
                 Ok(_) => {
                     return Err(errors::ApiErrorResponse::PreconditionFailed {
                         message: "data associated with the given fingerprint is already blocked"
@@ -197,15 +319,111 @@ pub async fn insert_entry_into_blocklist(
                     data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
                     metadata: None,
                     created_at: common_utils::date_time::now(),
+                    expires_at: to_block.expires_at,
                 })
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("failed to add fingerprint to blocklist")?
         }
     };
+
+    if let Some(expires_at) = blocklist_entry.expires_at {
+        // The entry stops blocking payments once it expires even if it could not be purged
+        add_blocklist_entry_expiry_task(state, &blocklist_entry, expires_at)
+            .await
+            .map_err(|error| logger::error!(blocklist_entry_expiry_task_error=?error))
+            .ok();
+    }
+
     Ok(blocklist_entry.foreign_into())
 }
 
+async fn delete_expired_blocklist_entry(
+    state: &AppState,
+    blocklist_entry: &storage::Blocklist,
+) -> RouterResult<()> {
+    state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+            &blocklist_entry.merchant_id,
+            &blocklist_entry.fingerprint_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to delete expired blocklist entry")
+        .map(|_| ())
+}
+
+pub fn get_blocklist_entry_expiry_decision(
+    blocklist_entry: Option<&storage::Blocklist>,
+    tracking_data: &BlocklistEntryExpiryTrackingData,
+    current_time: PrimitiveDateTime,
+) -> BlocklistEntryExpiryDecision {
+    match blocklist_entry.and_then(|blocklist_entry| blocklist_entry.expires_at) {
+        // The entry was blocked again with a different expiry, which has a task of its own
+        Some(expires_at) if expires_at != tracking_data.expires_at => {
+            BlocklistEntryExpiryDecision::Skip
+        }
+        Some(expires_at) if current_time >= expires_at => BlocklistEntryExpiryDecision::Purge,
+        Some(expires_at) => BlocklistEntryExpiryDecision::RetryAt(expires_at),
+        None => BlocklistEntryExpiryDecision::Skip,
+    }
+}
+
+/// Schedules the removal of a temporary blocklist entry once it expires
+#[instrument(skip_all)]
+async fn add_blocklist_entry_expiry_task(
+    state: &AppState,
+    blocklist_entry: &storage::Blocklist,
+    expires_at: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        BLOCKLIST_ENTRY_EXPIRY_TASK_RUNNER,
+        BLOCKLIST_ENTRY_EXPIRY_TASK_NAME,
+        &format!(
+            "{}_{}",
+            blocklist_entry.fingerprint_id,
+            expires_at.assume_utc().unix_timestamp()
+        ),
+        &blocklist_entry.merchant_id,
+    );
+    let tracking_data = BlocklistEntryExpiryTrackingData {
+        merchant_id: blocklist_entry.merchant_id.clone(),
+        fingerprint_id: blocklist_entry.fingerprint_id.clone(),
+        expires_at,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        BLOCKLIST_ENTRY_EXPIRY_TASK_NAME,
+        BLOCKLIST_ENTRY_EXPIRY_TASK_RUNNER,
+        [BLOCKLIST_ENTRY_EXPIRY_TASK_TAG],
+        tracking_data,
+        expires_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct blocklist entry expiry process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while inserting blocklist entry expiry task to process_tracker",
+        )?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "BlocklistEntryExpiry",
+        )],
+    );
+
+    Ok(())
+}
+
 pub async fn get_merchant_fingerprint_secret(
     state: &AppState,
     merchant_id: &str,
@@ -249,6 +467,7 @@ async fn duplicate_check_insert_bin(
     state: &AppState,
     merchant_id: &str,
     data_kind: common_enums::BlocklistDataKind,
+    expires_at: Option<PrimitiveDateTime>,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
@@ -256,6 +475,12 @@ async fn duplicate_check_insert_bin(
         .await;
 
     match blocklist_entry_result {
+        Ok(blocklist_entry)
+            if !is_blocklist_entry_active(&blocklist_entry, common_utils::date_time::now()) =>
+        {
+            delete_expired_blocklist_entry(state, &blocklist_entry).await?;
+        }
+
         Ok(_) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
                 message: "provided bin is already blocked".to_string(),
@@ -280,6 +505,7 @@ async fn duplicate_check_insert_bin(
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
+            expires_at,
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -300,6 +526,75 @@ async fn delete_card_bin_blocklist_entry(
         })
 }
 
+/// Card BIN prefixes of the card that are looked up in the blocklist, along with the kind of
+/// blocklist entry each of them is allowed to match. The ISIN (first 6 digits) is only checked
+/// against `CardBin` entries and the first 8 digits only against `ExtendedCardBin` entries.
+fn get_card_bin_lookup_keys(
+    card_number: &cards::CardNumber,
+) -> Vec<(String, common_enums::BlocklistDataKind)> {
+    vec![
+        (
+            card_number.clone().get_card_isin(),
+            common_enums::BlocklistDataKind::CardBin,
+        ),
+        (
+            card_number.clone().get_extended_card_bin(),
+            common_enums::BlocklistDataKind::ExtendedCardBin,
+        ),
+    ]
+}
+
+/// A blocklist entry only blocks the payment when it was found for the exact key that was looked
+/// up and is of the kind expected for that key, so that a blocked BIN range can't block cards of
+/// unrelated ranges or networks through a fingerprint or a BIN of a different length.
+fn is_blocklist_entry_match(
+    blocklist_entry: &storage::Blocklist,
+    fingerprint_id: &str,
+    data_kind: common_enums::BlocklistDataKind,
+) -> bool {
+    blocklist_entry.fingerprint_id == fingerprint_id && blocklist_entry.data_kind == data_kind
+}
+
+/// Whether any of the blocklist entries looked up for the payment blocks it. Entries that have
+/// expired, or that are of a different kind than expected for the key they were found for, are
+/// ignored.
+fn is_payment_blocked<'a>(
+    blocklist_lookups: impl IntoIterator<
+        Item = (
+            &'a (String, common_enums::BlocklistDataKind),
+            CustomResult<storage::Blocklist, errors::StorageError>,
+        ),
+    >,
+    current_time: PrimitiveDateTime,
+) -> bool {
+    let mut should_payment_be_blocked = false;
+    for ((fingerprint_id, data_kind), lookup) in blocklist_lookups {
+        match lookup {
+            Ok(blocklist_entry) if !is_blocklist_entry_active(&blocklist_entry, current_time) => {
+                logger::info!(
+                    data_kind = ?blocklist_entry.data_kind,
+                    "ignoring expired blocklist entry"
+                );
+            }
+            Ok(blocklist_entry) => {
+                if is_blocklist_entry_match(&blocklist_entry, fingerprint_id, *data_kind) {
+                    should_payment_be_blocked = true;
+                } else {
+                    logger::warn!(
+                        expected_data_kind = ?data_kind,
+                        found_data_kind = ?blocklist_entry.data_kind,
+                        "ignoring blocklist entry of a different data kind"
+                    );
+                }
+            }
+            Err(e) => {
+                logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
+            }
+        }
+    }
+    should_payment_be_blocked
+}
+
 pub async fn validate_data_for_blocklist<F>(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -337,67 +632,35 @@ where
         None
     };
 
-    // Hashed Cardbin to check whether or not this payment should be blocked.
-    let card_bin_fingerprint = payment_data
+    // Card BINs (first 6 and 8 digits) to check whether or not this payment should be blocked.
+    let card_bin_lookup_keys = payment_data
         .payment_method_data
         .as_ref()
-        .and_then(|pm_data| match pm_data {
+        .map(|pm_data| match pm_data {
             api_models::payments::PaymentMethodData::Card(card) => {
-                Some(card.card_number.clone().get_card_isin())
+                get_card_bin_lookup_keys(&card.card_number)
             }
-            _ => None,
-        });
-
-    // Hashed Extended Cardbin to check whether or not this payment should be blocked.
-    let extended_card_bin_fingerprint =
-        payment_data
-            .payment_method_data
-            .as_ref()
-            .and_then(|pm_data| match pm_data {
-                api_models::payments::PaymentMethodData::Card(card) => {
-                    Some(card.card_number.clone().get_extended_card_bin())
-                }
-                _ => None,
-            });
+            _ => Vec::new(),
+        })
+        .unwrap_or_default();
 
     //validating the payment method.
-    let mut blocklist_futures = Vec::new();
-    if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            card_number_fingerprint,
-        ));
-    }
+    let blocklist_lookup_keys = card_number_fingerprint
+        .map(|fingerprint| (fingerprint, common_enums::BlocklistDataKind::PaymentMethod))
+        .into_iter()
+        .chain(card_bin_lookup_keys)
+        .collect::<Vec<_>>();
 
-    if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(
-            db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-                merchant_id,
-                card_bin_fingerprint,
-            ),
-        );
-    }
-
-    if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            extended_card_bin_fingerprint,
-        ));
-    }
-
-    let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
+    let blocklist_lookups =
+        futures::future::join_all(blocklist_lookup_keys.iter().map(|(fingerprint_id, _)| {
+            db.find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+        }))
+        .await;
 
-    let mut should_payment_be_blocked = false;
-    for lookup in blocklist_lookups {
-        match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
-            }
-            Err(e) => {
-                logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
-            }
-        }
-    }
+    let should_payment_be_blocked = is_payment_blocked(
+        blocklist_lookup_keys.iter().zip(blocklist_lookups),
+        common_utils::date_time::now(),
+    );
     if should_payment_be_blocked {
         // Update db for attempt and intent status.
         db.update_payment_intent(
@@ -484,3 +747,292 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn get_blocklist_entry(
+        fingerprint_id: &str,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> storage::Blocklist {
+        storage::Blocklist {
+            id: 1,
+            merchant_id: "merchant_1".to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
+            data_kind,
+            metadata: None,
+            created_at: common_utils::date_time::now(),
+            expires_at: None,
+        }
+    }
+
+    /// Looks up the BINs of the card in the given blocklist entries, by fingerprint id as the
+    /// blocklist table is, and decides on the payment with `is_payment_blocked`
+    fn is_card_blocked_by_bin(card_number: &str, blocklist: &[storage::Blocklist]) -> bool {
+        let card_number = cards::CardNumber::from_str(card_number).unwrap();
+        let blocklist_lookup_keys = get_card_bin_lookup_keys(&card_number);
+        let blocklist_lookups = blocklist_lookup_keys.iter().map(|(fingerprint_id, _)| {
+            blocklist
+                .iter()
+                .find(|entry| entry.fingerprint_id == *fingerprint_id)
+                .cloned()
+                .ok_or_else(|| {
+                    error_stack::report!(errors::StorageError::ValueNotFound(
+                        "blocklist entry".to_string()
+                    ))
+                })
+        });
+
+        is_payment_blocked(
+            blocklist_lookup_keys.iter().zip(blocklist_lookups),
+            common_utils::date_time::now(),
+        )
+    }
+
+    #[test]
+    fn test_blocked_card_bin_matches_multiple_cards() {
+        let blocklist = vec![get_blocklist_entry(
+            "424242",
+            common_enums::BlocklistDataKind::CardBin,
+        )];
+
+        assert!(is_card_blocked_by_bin("4242424242424242", &blocklist));
+        assert!(is_card_blocked_by_bin("4242421234567897", &blocklist));
+    }
+
+    #[test]
+    fn test_blocked_extended_card_bin_matches_only_its_range() {
+        let blocklist = vec![get_blocklist_entry(
+            "42424242",
+            common_enums::BlocklistDataKind::ExtendedCardBin,
+        )];
+
+        assert!(is_card_blocked_by_bin("4242424242424242", &blocklist));
+        assert!(!is_card_blocked_by_bin("4242421234567897", &blocklist));
+    }
+
+    #[test]
+    fn test_non_blocked_card_bin_passes() {
+        let blocklist = vec![get_blocklist_entry(
+            "424242",
+            common_enums::BlocklistDataKind::CardBin,
+        )];
+
+        assert!(!is_card_blocked_by_bin("4111111111111111", &blocklist));
+        assert!(!is_card_blocked_by_bin("5555555555554444", &blocklist));
+    }
+
+    #[test]
+    fn test_card_bin_entry_of_different_kind_does_not_match() {
+        let blocklist = vec![get_blocklist_entry(
+            "424242",
+            common_enums::BlocklistDataKind::PaymentMethod,
+        )];
+
+        assert!(!is_card_blocked_by_bin("4242424242424242", &blocklist));
+    }
+
+    fn get_blocklist_response(
+        fingerprint_id: &str,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> api_blocklist::BlocklistResponse {
+        get_blocklist_entry(fingerprint_id, data_kind).foreign_into()
+    }
+
+    async fn mock_insert_entry_into_blocklist(
+        entry: api_blocklist::BlocklistRequest,
+    ) -> RouterResult<api_blocklist::BlocklistResponse> {
+        validate_blocklist_request(&entry)?;
+        match entry {
+            api_blocklist::BlocklistRequest::CardBin(bin) => Ok(get_blocklist_response(
+                &bin,
+                common_enums::BlocklistDataKind::CardBin,
+            )),
+            api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => Ok(get_blocklist_response(
+                &bin,
+                common_enums::BlocklistDataKind::ExtendedCardBin,
+            )),
+            api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id)
+                if fingerprint_id == "already_blocked" =>
+            {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "data associated with the given fingerprint is already blocked"
+                        .to_string(),
+                }
+                .into())
+            }
+            api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => {
+                Ok(get_blocklist_response(
+                    &fingerprint_id,
+                    common_enums::BlocklistDataKind::PaymentMethod,
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_blocklist_request_with_mixed_validity_entries() {
+        let request = api_blocklist::BulkBlocklistRequest {
+            entries: vec![
+                api_blocklist::BlocklistRequest::CardBin("424242".to_string()),
+                api_blocklist::BlocklistRequest::CardBin("4242".to_string()),
+                api_blocklist::BlocklistRequest::ExtendedCardBin("55555555".to_string()),
+                api_blocklist::BlocklistRequest::Fingerprint("already_blocked".to_string()),
+                api_blocklist::BlocklistRequest::CardBin("424242".to_string()),
+                api_blocklist::BlocklistRequest::Fingerprint("fingerprint_1".to_string()),
+            ],
+        };
+
+        let mut processed_entries = Vec::new();
+        let response = process_bulk_blocklist_request(request, |entry| {
+            processed_entries.push(entry.clone());
+            mock_insert_entry_into_blocklist(entry)
+        })
+        .await;
+
+        // The repeated card bin is not processed a second time
+        assert_eq!(processed_entries.len(), 5);
+
+        let statuses = response
+            .entries
+            .iter()
+            .map(|result| result.status)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                api_blocklist::BulkBlocklistEntryStatus::Succeeded,
+                api_blocklist::BulkBlocklistEntryStatus::Failed,
+                api_blocklist::BulkBlocklistEntryStatus::Succeeded,
+                api_blocklist::BulkBlocklistEntryStatus::Failed,
+                api_blocklist::BulkBlocklistEntryStatus::Failed,
+                api_blocklist::BulkBlocklistEntryStatus::Succeeded,
+            ]
+        );
+
+        let error_codes = response
+            .entries
+            .iter()
+            .map(|result| result.error_code.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            error_codes,
+            vec![
+                None,
+                Some("IR_05"),
+                None,
+                Some("IR_16"),
+                Some("IR_16"),
+                None
+            ]
+        );
+
+        let blocked_fingerprint = response
+            .entries
+            .last()
+            .and_then(|result| result.blocklist_entry.as_ref())
+            .map(|blocklist_entry| blocklist_entry.fingerprint_id.as_str());
+        assert_eq!(blocked_fingerprint, Some("fingerprint_1"));
+    }
+
+    #[test]
+    fn test_expired_blocklist_entry_does_not_block() {
+        let current_time = common_utils::date_time::now();
+        let mut expired_entry =
+            get_blocklist_entry("424242", common_enums::BlocklistDataKind::CardBin);
+        expired_entry.expires_at = Some(current_time.saturating_sub(time::Duration::hours(1)));
+
+        assert!(!is_blocklist_entry_active(&expired_entry, current_time));
+        assert!(!is_card_blocked_by_bin(
+            "4242424242424242",
+            &[expired_entry]
+        ));
+    }
+
+    #[test]
+    fn test_permanent_and_unexpired_blocklist_entries_still_block() {
+        let current_time = common_utils::date_time::now();
+        let permanent_entry =
+            get_blocklist_entry("424242", common_enums::BlocklistDataKind::CardBin);
+        let mut temporary_entry =
+            get_blocklist_entry("42424242", common_enums::BlocklistDataKind::ExtendedCardBin);
+        temporary_entry.expires_at = Some(current_time.saturating_add(time::Duration::hours(24)));
+
+        assert!(is_blocklist_entry_active(&permanent_entry, current_time));
+        assert!(is_blocklist_entry_active(&temporary_entry, current_time));
+        assert!(is_card_blocked_by_bin(
+            "4242421234567897",
+            &[permanent_entry]
+        ));
+        assert!(is_card_blocked_by_bin(
+            "4242424242424242",
+            &[temporary_entry]
+        ));
+    }
+
+    #[test]
+    fn test_blocklist_entry_expiry_must_be_in_the_future() {
+        let current_time = common_utils::date_time::now();
+
+        assert!(validate_blocklist_entry_expiry(None, current_time).is_ok());
+        assert!(validate_blocklist_entry_expiry(
+            Some(current_time.saturating_add(time::Duration::hours(24))),
+            current_time
+        )
+        .is_ok());
+        assert!(validate_blocklist_entry_expiry(Some(current_time), current_time).is_err());
+    }
+
+    #[test]
+    fn test_blocklist_entry_expiry_decision() {
+        let expires_at = time::macros::datetime!(2024-01-02 00:00);
+        let tracking_data = BlocklistEntryExpiryTrackingData {
+            merchant_id: "merchant_1".to_string(),
+            fingerprint_id: "424242".to_string(),
+            expires_at,
+        };
+        let mut blocklist_entry =
+            get_blocklist_entry("424242", common_enums::BlocklistDataKind::CardBin);
+        blocklist_entry.expires_at = Some(expires_at);
+
+        assert_eq!(
+            get_blocklist_entry_expiry_decision(
+                Some(&blocklist_entry),
+                &tracking_data,
+                time::macros::datetime!(2024-01-02 00:00)
+            ),
+            BlocklistEntryExpiryDecision::Purge
+        );
+        assert_eq!(
+            get_blocklist_entry_expiry_decision(
+                Some(&blocklist_entry),
+                &tracking_data,
+                time::macros::datetime!(2024-01-01 00:00)
+            ),
+            BlocklistEntryExpiryDecision::RetryAt(expires_at)
+        );
+        assert_eq!(
+            get_blocklist_entry_expiry_decision(
+                None,
+                &tracking_data,
+                time::macros::datetime!(2024-01-02 00:00)
+            ),
+            BlocklistEntryExpiryDecision::Skip
+        );
+
+        // The entry was blocked again with a later expiry
+        blocklist_entry.expires_at = Some(time::macros::datetime!(2024-01-03 00:00));
+        assert_eq!(
+            get_blocklist_entry_expiry_decision(
+                Some(&blocklist_entry),
+                &tracking_data,
+                time::macros::datetime!(2024-01-02 00:00)
+            ),
+            BlocklistEntryExpiryDecision::Skip
+        );
+    }
+}
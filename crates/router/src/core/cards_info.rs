@@ -1,17 +1,30 @@
+use std::collections::BTreeMap;
+
 use common_utils::fp_utils::when;
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{CacheKind, CARD_INFO_CACHE};
 
 use crate::{
     core::{
-        errors::{self, RouterResponse},
+        errors::{self, RouterResponse, RouterResult},
         payments::helpers,
     },
+    db::{cache::publish_into_redact_channel, StorageInterface},
     routes,
     services::ApplicationResponse,
-    types::{domain, transformers::ForeignFrom},
+    types::{domain, storage::cards_info::CardInfo, transformers::ForeignFrom},
 };
 
+/// Length of the shortest bin for which card information is stored
+const MIN_CARD_BIN_LENGTH: usize = 6;
+
+/// Length of the longest bin for which card information is stored
+const MAX_CARD_BIN_LENGTH: usize = 8;
+
+/// Number of card information rows stored in a single transaction during an import
+const CARD_INFO_IMPORT_BATCH_SIZE: usize = 1000;
+
 fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
     let is_bin_length_in_range = card_iin.len() == 6 || card_iin.len() == 8;
     when(!is_bin_length_in_range, || {
@@ -46,3 +59,282 @@ pub async fn retrieve_card_info(
         api_models::cards_info::CardInfoResponse::foreign_from(card_info),
     ))
 }
+
+fn verify_card_bin(card_bin: &str) -> Result<(), errors::ApiErrorResponse> {
+    let is_valid_card_bin = (MIN_CARD_BIN_LENGTH..=MAX_CARD_BIN_LENGTH).contains(&card_bin.len())
+        && card_bin.chars().all(|c| c.is_ascii_digit());
+    when(!is_valid_card_bin, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "card_bin should contain {MIN_CARD_BIN_LENGTH} to {MAX_CARD_BIN_LENGTH} digits"
+            ),
+        })
+    })
+}
+
+/// Fetches the card information stored for the bin, looking it up in the in-memory cache first.
+/// Only card information that is found is cached, so that newly added bins are picked up.
+async fn find_card_info_by_bin(
+    db: &dyn StorageInterface,
+    card_bin: &str,
+) -> RouterResult<Option<CardInfo>> {
+    if let Some(card_info) = CARD_INFO_CACHE.get_val::<CardInfo>(card_bin).await {
+        return Ok(Some(card_info));
+    }
+
+    let card_info = db
+        .get_card_info(card_bin)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve card information")?;
+
+    if let Some(card_info) = card_info.as_ref() {
+        CARD_INFO_CACHE
+            .push(card_bin.to_string(), card_info.clone())
+            .await;
+    }
+
+    Ok(card_info)
+}
+
+/// Resolves the card bin to the stored card information. When no card information is stored for
+/// a bin longer than 6 digits, the card information of its 6 digit prefix is returned.
+pub async fn get_card_info_by_bin(
+    db: &dyn StorageInterface,
+    card_bin: &str,
+) -> RouterResult<CardInfo> {
+    verify_card_bin(card_bin)?;
+
+    let card_info = match find_card_info_by_bin(db, card_bin).await? {
+        Some(card_info) => Some(card_info),
+        None => match card_bin.get(..MIN_CARD_BIN_LENGTH) {
+            Some(card_iin) if card_iin != card_bin => find_card_info_by_bin(db, card_iin).await?,
+            _ => None,
+        },
+    };
+
+    card_info.ok_or(report!(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Card information not found for the given card bin".to_string(),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn get_card_info(
+    state: routes::AppState,
+    request: api_models::cards_info::CardBinLookupRequest,
+) -> RouterResponse<api_models::cards_info::CardInfoResponse> {
+    let card_info = get_card_info_by_bin(state.store.as_ref(), &request.card_bin).await?;
+
+    Ok(ApplicationResponse::Json(
+        api_models::cards_info::CardInfoResponse::foreign_from(card_info),
+    ))
+}
+
+fn get_card_info_from_import_row(
+    row: serde_json::Value,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<CardInfo> {
+    let card_info = serde_json::from_value::<api_models::cards_info::CardInfoCreateRequest>(row)
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Malformed card info row".to_string(),
+        })?;
+    verify_card_bin(&card_info.card_iin)?;
+
+    Ok(CardInfo {
+        card_iin: card_info.card_iin,
+        card_issuer: card_info.card_issuer,
+        card_network: card_info.card_network,
+        card_type: card_info.card_type,
+        card_subtype: card_info.card_subtype,
+        card_issuing_country: card_info.card_issuing_country,
+        bank_code_id: card_info.bank_code_id,
+        bank_code: card_info.bank_code,
+        country_code: card_info.country_code,
+        date_created: now,
+        last_updated: Some(now),
+        last_updated_provider: card_info.last_updated_provider,
+    })
+}
+
+/// Inserts or updates the card information of all the rows in the request, in batches of
+/// `CARD_INFO_IMPORT_BATCH_SIZE` that are each stored within a transaction. When the request
+/// contains multiple rows for a bin, only the last one is stored. The cached card information of
+/// the stored bins is invalidated on every instance through the redact channel.
+pub async fn bulk_import_card_info(
+    db: &dyn StorageInterface,
+    request: api_models::cards_info::CardInfoImportRequest,
+) -> RouterResult<api_models::cards_info::CardInfoImportResponse> {
+    let now = common_utils::date_time::now();
+    let mut response = api_models::cards_info::CardInfoImportResponse::default();
+
+    let mut card_info_by_bin = BTreeMap::new();
+    for row in request.card_info {
+        match get_card_info_from_import_row(row, now) {
+            Ok(card_info) => {
+                if card_info_by_bin
+                    .insert(card_info.card_iin.clone(), card_info)
+                    .is_some()
+                {
+                    response.duplicates += 1;
+                }
+            }
+            Err(error) => {
+                logger::warn!(?error, "Skipping malformed card info row");
+                response.failed += 1;
+            }
+        }
+    }
+
+    let card_info_list = card_info_by_bin.into_values().collect::<Vec<_>>();
+    for batch in card_info_list.chunks(CARD_INFO_IMPORT_BATCH_SIZE) {
+        match db.bulk_upsert_card_info(batch.to_vec()).await {
+            Ok((inserted, updated)) => {
+                response.inserted += inserted;
+                response.updated += updated;
+                let cache_keys = batch
+                    .iter()
+                    .map(|card_info| CacheKind::CardInfo(card_info.card_iin.as_str().into()));
+                publish_into_redact_channel(db, cache_keys)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to invalidate cached card info of batch")
+                    })
+                    .ok();
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to import card info batch");
+                response.failed += batch.len();
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+#[instrument(skip_all)]
+pub async fn import_card_info(
+    state: routes::AppState,
+    request: api_models::cards_info::CardInfoImportRequest,
+) -> RouterResponse<api_models::cards_info::CardInfoImportResponse> {
+    let response = bulk_import_card_info(state.store.as_ref(), request).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::db::MockDb;
+
+    async fn get_mock_db(card_iin: &str) -> MockDb {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        db.cards_info.lock().await.push(CardInfo {
+            card_iin: card_iin.to_string(),
+            card_issuer: Some("HDFC BANK".to_string()),
+            card_network: Some(common_enums::CardNetwork::Visa),
+            card_type: Some("CREDIT".to_string()),
+            card_subtype: Some("CLASSIC".to_string()),
+            card_issuing_country: Some("INDIA".to_string()),
+            bank_code_id: None,
+            bank_code: None,
+            country_code: Some("IN".to_string()),
+            date_created: common_utils::date_time::now(),
+            last_updated: None,
+            last_updated_provider: None,
+        });
+        db
+    }
+
+    #[tokio::test]
+    async fn test_get_card_info_for_known_bin() {
+        let db = get_mock_db("457173").await;
+
+        let card_info = get_card_info_by_bin(&db, "457173").await.unwrap();
+        assert_eq!(card_info.card_issuer.as_deref(), Some("HDFC BANK"));
+        assert_eq!(
+            card_info.card_network,
+            Some(common_enums::CardNetwork::Visa)
+        );
+        assert_eq!(card_info.card_issuing_country.as_deref(), Some("INDIA"));
+
+        // An 8 digit bin falls back to the card information of its 6 digit prefix
+        let card_info = get_card_info_by_bin(&db, "45717360").await.unwrap();
+        assert_eq!(card_info.card_iin, "457173");
+
+        // Card information of a known bin is served from the cache afterwards
+        db.cards_info.lock().await.clear();
+        let card_info = get_card_info_by_bin(&db, "457173").await.unwrap();
+        assert_eq!(card_info.card_iin, "457173");
+    }
+
+    #[tokio::test]
+    async fn test_get_card_info_for_unknown_bin() {
+        let db = get_mock_db("457173").await;
+
+        let err = get_card_info_by_bin(&db, "400000").await.unwrap_err();
+        assert!(matches!(
+            err.current_context(),
+            errors::ApiErrorResponse::GenericNotFoundError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_card_info_rejects_invalid_bin() {
+        let db = get_mock_db("457173").await;
+
+        for card_bin in ["4571", "45717", "457173601", "45717a"] {
+            let err = get_card_info_by_bin(&db, card_bin).await.unwrap_err();
+            assert!(matches!(
+                err.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_import_card_info() {
+        let db = get_mock_db("512345").await;
+        let request = api_models::cards_info::CardInfoImportRequest {
+            card_info: vec![
+                serde_json::json!({
+                    "card_iin": "512345",
+                    "card_issuer": "AXIS BANK",
+                    "card_network": "Mastercard",
+                }),
+                serde_json::json!({ "card_iin": "42424242", "card_issuer": "OLD BANK" }),
+                serde_json::json!({
+                    "card_iin": "42424242",
+                    "card_issuer": "STRIPE TEST BANK",
+                    "card_network": "Visa",
+                    "card_type": "CREDIT",
+                }),
+                serde_json::json!({ "card_iin": "4242", "card_issuer": "SHORT BIN BANK" }),
+                serde_json::json!({ "card_issuer": "MISSING BIN BANK" }),
+            ],
+        };
+
+        let response = bulk_import_card_info(&db, request).await.unwrap();
+        assert_eq!(response.inserted, 1);
+        assert_eq!(response.updated, 1);
+        assert_eq!(response.failed, 2);
+        assert_eq!(response.duplicates, 1);
+
+        // The last row of a duplicated bin is the one that is stored
+        let card_info = get_card_info_by_bin(&db, "42424242").await.unwrap();
+        assert_eq!(card_info.card_issuer.as_deref(), Some("STRIPE TEST BANK"));
+        assert_eq!(
+            card_info.card_network,
+            Some(common_enums::CardNetwork::Visa)
+        );
+
+        // Fields that are not set in the row keep their stored value
+        let card_info = get_card_info_by_bin(&db, "512345").await.unwrap();
+        assert_eq!(card_info.card_issuer.as_deref(), Some("AXIS BANK"));
+        assert_eq!(card_info.card_type.as_deref(), Some("CREDIT"));
+        assert_eq!(card_info.card_issuing_country.as_deref(), Some("INDIA"));
+        assert_eq!(db.cards_info.lock().await.len(), 2);
+    }
+}
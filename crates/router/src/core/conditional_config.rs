@@ -11,7 +11,10 @@ use super::routing::helpers::{
     get_payment_config_routing_id, update_merchant_active_algorithm_ref,
 };
 use crate::{
-    core::errors::{self, RouterResponse},
+    core::{
+        errors::{self, RouterResponse},
+        payments::conditional_configs::parse_time_window,
+    },
     routes::AppState,
     services::api as service_api,
     types::domain,
@@ -25,7 +28,7 @@ pub async fn upsert_conditional_config(
     request: DecisionManager,
 ) -> RouterResponse<DecisionManagerRecord> {
     let db = state.store.as_ref();
-    let (name, prog) = match request {
+    let (name, prog, time_windows) = match request {
         DecisionManager::DecisionManagerv0(ccr) => {
             let name = ccr.name;
 
@@ -36,7 +39,7 @@ pub async fn upsert_conditional_config(
                     field_name: "algorithm",
                 })
                 .attach_printable("Algorithm for config not given")?;
-            (name, prog)
+            (name, prog, ccr.time_windows)
         }
         DecisionManager::DecisionManagerv1(dmr) => {
             let name = dmr.name;
@@ -48,7 +51,7 @@ pub async fn upsert_conditional_config(
                     field_name: "program",
                 })
                 .attach_printable("Program for config not given")?;
-            (name, prog)
+            (name, prog, dmr.time_windows)
         }
     };
     let timestamp = common_utils::date_time::now_unix_timestamp();
@@ -70,6 +73,19 @@ pub async fn upsert_conditional_config(
         })
         .attach_printable("The Request has an Invalid Comparison")?;
 
+    let time_windows = time_windows.unwrap_or_default();
+    if let Some(name) = time_windows
+        .iter()
+        .find_map(|(name, window)| parse_time_window(window).is_none().then_some(name))
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Time window {name} must have times as HH:MM and a UTC offset as +HH:MM"
+            ),
+        }
+        .into());
+    }
+
     match read_config_key {
         Ok(config) => {
             let previous_record: DecisionManagerRecord = config
@@ -81,6 +97,7 @@ pub async fn upsert_conditional_config(
             let new_algo = DecisionManagerRecord {
                 name: previous_record.name,
                 program: prog,
+                time_windows,
                 modified_at: timestamp,
                 created_at: previous_record.created_at,
             };
@@ -116,6 +133,7 @@ pub async fn upsert_conditional_config(
                     })
                     .attach_printable("name of the config not found")?,
                 program: prog,
+                time_windows,
                 modified_at: timestamp,
                 created_at: timestamp,
             };
@@ -196,6 +214,7 @@ pub async fn retrieve_conditional_config(
     let response = DecisionManagerRecord {
         name: record.name,
         program: record.program,
+        time_windows: record.time_windows,
         created_at: record.created_at,
         modified_at: record.modified_at,
     };
@@ -0,0 +1,258 @@
+use api_models::connector_replay::{
+    ConnectorReplayRecord, ConnectorReplayResponse, ConnectorReplayRetrieveRequest,
+};
+use common_utils::ext_traits::{Encode, StringExt};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::ConnectorReplayRecorderConfig,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    events::connector_api_logs::ConnectorEvent,
+    routes::AppState,
+    services,
+};
+
+fn get_connector_replay_key(merchant_id: &str, attempt_id: &str) -> String {
+    format!("connector_replay_{merchant_id}_{attempt_id}")
+}
+
+/// Returns the records of an attempt, oldest first, or `None` if none have been recorded
+async fn get_connector_replay_records(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+) -> RouterResult<Option<Vec<ConnectorReplayRecord>>> {
+    let records = redis_conn
+        .get_list_elements(key, 0, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector replay records")?;
+
+    if records.is_empty() {
+        return Ok(None);
+    }
+
+    records
+        .into_iter()
+        .map(|record| {
+            record
+                .parse_struct::<ConnectorReplayRecord>("ConnectorReplayRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse connector replay record")
+        })
+        .collect::<RouterResult<Vec<_>>>()
+        .map(Some)
+}
+
+/// Appends the record to the records of an attempt, discarding the oldest records once more than
+/// `max_records_per_attempt` are present. Each step is a single redis command, so that records
+/// persisted concurrently for an attempt are never lost to one another.
+async fn persist_connector_replay_record(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    config: &ConnectorReplayRecorderConfig,
+    key: &str,
+    record: &ConnectorReplayRecord,
+) -> RouterResult<()> {
+    let record = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize connector replay record")?;
+    let max_records = i64::try_from(config.max_records_per_attempt)
+        .unwrap_or(i64::MAX)
+        .max(1);
+
+    redis_conn
+        .append_elements_to_list(key, record)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to persist connector replay record")?;
+    redis_conn
+        .trim_list(key, -max_records, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to discard the oldest connector replay records")?;
+    redis_conn
+        .set_expiry(key, config.ttl_in_secs)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set expiry for connector replay records")
+}
+
+/// Records the masked connector request and response of the attempt if the replay recorder is
+/// enabled. Flows not tied to a payment attempt are not recorded, and failures are only logged,
+/// since recording must never affect the payment.
+#[instrument(skip_all)]
+pub async fn record_connector_exchange(
+    state: &AppState,
+    merchant_id: &str,
+    attempt_id: &str,
+    connector_event: &ConnectorEvent,
+) {
+    let config = &state.conf.connector_replay_recorder;
+    if !config.enabled || !core_utils::is_payment_attempt_id(attempt_id) {
+        return;
+    }
+
+    let key = get_connector_replay_key(merchant_id, attempt_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            persist_connector_replay_record(
+                &redis_conn,
+                config,
+                &key,
+                &connector_event.get_replay_record(),
+            )
+            .await
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection"),
+    };
+    if let Err(error) = result {
+        logger::error!(connector_replay_recorder_error=?error);
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_connector_replay_records(
+    state: AppState,
+    req: ConnectorReplayRetrieveRequest,
+) -> RouterResponse<ConnectorReplayResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_connector_replay_key(&req.merchant_id, &req.attempt_id);
+    let records = get_connector_replay_records(&redis_conn, &key)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No connector requests have been recorded for the attempt".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorReplayResponse {
+            merchant_id: req.merchant_id,
+            attempt_id: req.attempt_id,
+            records,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::request::Method;
+    use masking::Secret;
+    use serde::Serialize;
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    #[derive(Serialize)]
+    struct TestCard {
+        number: Secret<String>,
+        holder_name: Secret<String>,
+        brand: String,
+    }
+
+    fn get_connector_event(flow: &str) -> ConnectorEvent {
+        let card = TestCard {
+            number: Secret::new("4111111111111111".to_string()),
+            holder_name: Secret::new("John Doe".to_string()),
+            brand: "visa".to_string(),
+        };
+        let mut connector_event = ConnectorEvent::new(
+            "stripe".to_string(),
+            flow,
+            masking::masked_serialize(&card).unwrap(),
+            "https://api.stripe.com/v1/payment_intents".to_string(),
+            Method::Post,
+            "pay_123".to_string(),
+            "merchant_123".to_string(),
+            None,
+            120,
+            None,
+            None,
+            200,
+        );
+        connector_event.set_response_body(&card);
+        connector_event
+    }
+
+    fn get_recorder_config(max_records_per_attempt: usize) -> ConnectorReplayRecorderConfig {
+        ConnectorReplayRecorderConfig {
+            enabled: true,
+            ttl_in_secs: 60,
+            max_records_per_attempt,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recorded_pair_is_redacted_and_retrievable() {
+        #[allow(clippy::expect_used)]
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let redis_conn = db.get_redis_conn().unwrap();
+        let key = get_connector_replay_key("merchant_123", "pay_redacted_1");
+
+        persist_connector_replay_record(
+            &redis_conn,
+            &get_recorder_config(20),
+            &key,
+            &get_connector_event("router::types::api::Authorize").get_replay_record(),
+        )
+        .await
+        .unwrap();
+
+        let records = get_connector_replay_records(&redis_conn, &key)
+            .await
+            .unwrap()
+            .unwrap();
+        let record = records.first().unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(record.connector_name, "stripe");
+        assert_eq!(record.flow, "Authorize");
+        assert_eq!(record.status_code, 200);
+        let masked_response = record.masked_response.as_deref().unwrap();
+        for body in [record.masked_request.as_str(), masked_response] {
+            assert!(!body.contains("4111111111111111"));
+            assert!(!body.contains("John Doe"));
+            assert!(body.contains("visa"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oldest_records_are_discarded() {
+        #[allow(clippy::expect_used)]
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let redis_conn = db.get_redis_conn().unwrap();
+        let key = get_connector_replay_key("merchant_123", "pay_discarded_1");
+
+        for flow in ["Authorize", "PSync", "Capture"] {
+            persist_connector_replay_record(
+                &redis_conn,
+                &get_recorder_config(2),
+                &key,
+                &get_connector_event(flow).get_replay_record(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let records = get_connector_replay_records(&redis_conn, &key)
+            .await
+            .unwrap()
+            .unwrap();
+        let flows: Vec<_> = records.iter().map(|record| record.flow.as_str()).collect();
+        assert_eq!(flows, vec!["PSync", "Capture"]);
+    }
+}
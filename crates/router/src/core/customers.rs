@@ -1,6 +1,6 @@
 use common_utils::{
     crypto::{Encryptable, GcmAes256},
-    errors::ReportSwitchExt,
+    errors::{ErrorSwitch, ReportSwitchExt},
 };
 use error_stack::{report, ResultExt};
 use masking::ExposeInterface;
@@ -10,6 +10,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::cards,
+        payments::helpers as payment_helpers,
     },
     pii::PeekInterface,
     routes::{metrics, AppState},
@@ -35,6 +36,11 @@ pub async fn create_customer(
     mut customer_data: customers::CustomerRequest,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
     let db = state.store.as_ref();
+    customer_data.validate().map_err(|error| {
+        report!(ErrorSwitch::<errors::CustomersErrorResponse>::switch(
+            &error
+        ))
+    })?;
     let customer_id = &customer_data.customer_id;
     let merchant_id = &merchant_account.merchant_id;
     merchant_id.clone_into(&mut customer_data.merchant_id);
@@ -185,6 +191,30 @@ pub async fn list_customers(
     Ok(services::ApplicationResponse::Json(customers))
 }
 
+fn get_redacted_address_update(
+    redacted_encrypted_value: &Encryptable<masking::Secret<String>>,
+    redacted_encrypted_email: Encryptable<
+        masking::Secret<String, common_utils::pii::EmailStrategy>,
+    >,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> storage::AddressUpdate {
+    storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: None,
+        line1: Some(redacted_encrypted_value.clone()),
+        line2: Some(redacted_encrypted_value.clone()),
+        line3: Some(redacted_encrypted_value.clone()),
+        state: Some(redacted_encrypted_value.clone()),
+        zip: Some(redacted_encrypted_value.clone()),
+        first_name: Some(redacted_encrypted_value.clone()),
+        last_name: Some(redacted_encrypted_value.clone()),
+        phone_number: Some(redacted_encrypted_value.clone()),
+        country_code: Some(REDACTED.to_string()),
+        updated_by: storage_scheme.to_string(),
+        email: Some(redacted_encrypted_email),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn delete_customer(
     state: AppState,
@@ -210,7 +240,11 @@ pub async fn delete_customer(
         .switch()?;
 
     for mandate in customer_mandates.into_iter() {
-        if mandate.mandate_status == enums::MandateStatus::Active {
+        // A paused mandate can be resumed, so it blocks the deletion just like an active one
+        if matches!(
+            mandate.mandate_status,
+            enums::MandateStatus::Active | enums::MandateStatus::Paused
+        ) {
             Err(errors::CustomersErrorResponse::MandateActive)?
         }
     }
@@ -268,21 +302,12 @@ pub async fn delete_customer(
         .await
         .switch()?;
 
-    let update_address = storage::AddressUpdate::Update {
-        city: Some(REDACTED.to_string()),
-        country: None,
-        line1: Some(redacted_encrypted_value.clone()),
-        line2: Some(redacted_encrypted_value.clone()),
-        line3: Some(redacted_encrypted_value.clone()),
-        state: Some(redacted_encrypted_value.clone()),
-        zip: Some(redacted_encrypted_value.clone()),
-        first_name: Some(redacted_encrypted_value.clone()),
-        last_name: Some(redacted_encrypted_value.clone()),
-        phone_number: Some(redacted_encrypted_value.clone()),
-        country_code: Some(REDACTED.to_string()),
-        updated_by: merchant_account.storage_scheme.to_string(),
-        email: Some(redacted_encrypted_email),
-    };
+    // All the addresses of the customer are redacted, including the addresses of their payments
+    let update_address = get_redacted_address_update(
+        &redacted_encrypted_value,
+        redacted_encrypted_email,
+        merchant_account.storage_scheme,
+    );
 
     match db
         .update_address_by_merchant_id_customer_id(
@@ -348,6 +373,11 @@ pub async fn update_customer(
     key_store: domain::MerchantKeyStore,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
     let db = state.store.as_ref();
+    update_customer.validate().map_err(|error| {
+        report!(ErrorSwitch::<errors::CustomersErrorResponse>::switch(
+            &error
+        ))
+    })?;
     //Add this in update call if customer can be updated anywhere else
     let customer = db
         .find_customer_by_customer_id_merchant_id(
@@ -456,3 +486,313 @@ pub async fn update_customer(
         customers::CustomerResponse::from((response, update_customer.address)),
     ))
 }
+
+fn get_customer_address_response(
+    customer_id: String,
+    address: domain::Address,
+) -> customers::CustomerAddressResponse {
+    let api_models::payments::Address {
+        address: address_details,
+        phone,
+        email,
+    } = api_models::payments::Address::from(&address);
+
+    customers::CustomerAddressResponse {
+        address_id: address.address_id,
+        customer_id,
+        address: address_details,
+        phone,
+        email,
+        created_at: address.created_at,
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn add_customer_address(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerAddressRequest,
+) -> errors::CustomerResponse<customers::CustomerAddressResponse> {
+    let db = state.store.as_ref();
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .switch()?;
+
+    let address = payment_helpers::get_domain_address(
+        &req.into(),
+        &merchant_account.merchant_id,
+        key_store.key.get_inner().peek(),
+        merchant_account.storage_scheme,
+    )
+    .await
+    .switch()
+    .attach_printable("Failed while encrypting address")?;
+
+    let address = db
+        .insert_address_for_customers(
+            domain::CustomerAddress {
+                address,
+                customer_id: customer.customer_id.clone(),
+            },
+            &key_store,
+        )
+        .await
+        .switch()
+        .attach_printable("Failed while inserting new address")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(customer.customer_id, address),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_customer_addresses(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> errors::CustomerResponse<Vec<customers::CustomerAddressResponse>> {
+    let db = state.store.as_ref();
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .switch()?;
+
+    let addresses = db
+        .find_addresses_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            &req.customer_id,
+            &key_store,
+        )
+        .await
+        .switch()?
+        .into_iter()
+        .map(|address| get_customer_address_response(req.customer_id.clone(), address))
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(addresses))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_customer_address(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerAddressId,
+) -> errors::CustomerResponse<customers::CustomerAddressDeleteResponse> {
+    let db = state.store.as_ref();
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .switch()?;
+
+    // The address of the customer object is managed through the customer itself
+    if customer.address_id.as_ref() == Some(&req.address_id) {
+        return Err(report!(
+            errors::CustomersErrorResponse::PreconditionFailed {
+                message:
+                    "The address of the customer cannot be deleted, update the customer instead"
+                        .to_string(),
+            }
+        ));
+    }
+
+    let deleted = db
+        .delete_address_by_merchant_id_customer_id_address_id(
+            &merchant_account.merchant_id,
+            &req.customer_id,
+            &req.address_id,
+        )
+        .await
+        .to_not_found_response(errors::CustomersErrorResponse::AddressNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerAddressDeleteResponse {
+            customer_id: req.customer_id,
+            address_id: req.address_id,
+            deleted,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payments::{Address, AddressDetails};
+    use masking::Secret;
+
+    use super::*;
+    use crate::db::{address::AddressInterface, MasterKeyInterface, MockDb};
+
+    const MERCHANT_ID: &str = "merchant_123";
+    const CUSTOMER_ID: &str = "cus_123";
+
+    async fn get_key_store(db: &MockDb) -> domain::MerchantKeyStore {
+        domain::MerchantKeyStore {
+            merchant_id: MERCHANT_ID.to_string(),
+            key: types::encrypt(
+                services::generate_aes256_key().unwrap().to_vec().into(),
+                db.get_master_key(),
+            )
+            .await
+            .unwrap(),
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    async fn add_address(
+        db: &MockDb,
+        key_store: &domain::MerchantKeyStore,
+        city: &str,
+    ) -> domain::Address {
+        let address = Address {
+            address: Some(AddressDetails {
+                city: Some(city.to_string()),
+                line1: Some(Secret::new("1 Main Street".to_string())),
+                ..Default::default()
+            }),
+            phone: None,
+            email: None,
+        };
+        let address = payment_helpers::get_domain_address(
+            &address,
+            MERCHANT_ID,
+            key_store.key.get_inner().peek(),
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap();
+
+        db.insert_address_for_customers(
+            domain::CustomerAddress {
+                address,
+                customer_id: CUSTOMER_ID.to_string(),
+            },
+            key_store,
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn list_addresses(
+        db: &MockDb,
+        key_store: &domain::MerchantKeyStore,
+    ) -> Vec<customers::CustomerAddressResponse> {
+        db.find_addresses_by_merchant_id_customer_id(MERCHANT_ID, CUSTOMER_ID, key_store)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|address| get_customer_address_response(CUSTOMER_ID.to_string(), address))
+            .collect()
+    }
+
+    fn get_city(address: &customers::CustomerAddressResponse) -> Option<&str> {
+        address.address.as_ref()?.city.as_deref()
+    }
+
+    #[tokio::test]
+    async fn test_all_addresses_of_customer_are_listed_and_redacted() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let key_store = get_key_store(&db).await;
+        let home_address = add_address(&db, &key_store, "Bangalore").await;
+        let office_address = add_address(&db, &key_store, "Mumbai").await;
+
+        let addresses = list_addresses(&db, &key_store).await;
+        let address_ids: Vec<_> = addresses
+            .iter()
+            .map(|address| address.address_id.as_str())
+            .collect();
+        assert_eq!(
+            address_ids,
+            vec![
+                home_address.address_id.as_str(),
+                office_address.address_id.as_str()
+            ]
+        );
+        let cities: Vec<_> = addresses.iter().filter_map(get_city).collect();
+        assert_eq!(cities, vec!["Bangalore", "Mumbai"]);
+
+        let key = key_store.key.get_inner().peek();
+        let redacted_encrypted_value: Encryptable<Secret<_>> =
+            Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
+                .await
+                .unwrap();
+        let redacted_encrypted_email =
+            Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
+                .await
+                .unwrap();
+        let redacted_addresses = db
+            .update_address_by_merchant_id_customer_id(
+                CUSTOMER_ID,
+                MERCHANT_ID,
+                get_redacted_address_update(
+                    &redacted_encrypted_value,
+                    redacted_encrypted_email,
+                    enums::MerchantStorageScheme::PostgresOnly,
+                ),
+                &key_store,
+            )
+            .await
+            .unwrap();
+        assert_eq!(redacted_addresses.len(), 2);
+
+        let addresses = list_addresses(&db, &key_store).await;
+        assert_eq!(addresses.len(), 2);
+        for address in addresses {
+            assert_eq!(get_city(&address), Some(REDACTED));
+            let line1 = address.address.and_then(|details| details.line1).unwrap();
+            assert_eq!(line1.peek(), REDACTED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deleted_address_is_not_listed() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let key_store = get_key_store(&db).await;
+        let home_address = add_address(&db, &key_store, "Bangalore").await;
+        add_address(&db, &key_store, "Mumbai").await;
+
+        assert!(db
+            .delete_address_by_merchant_id_customer_id_address_id(
+                MERCHANT_ID,
+                CUSTOMER_ID,
+                &home_address.address_id,
+            )
+            .await
+            .unwrap());
+
+        let addresses = list_addresses(&db, &key_store).await;
+        let cities: Vec<_> = addresses.iter().filter_map(get_city).collect();
+        assert_eq!(cities, vec!["Mumbai"]);
+
+        // Addresses of other customers cannot be deleted
+        assert!(db
+            .delete_address_by_merchant_id_customer_id_address_id(
+                MERCHANT_ID,
+                "cus_456",
+                &addresses.first().unwrap().address_id,
+            )
+            .await
+            .is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, str::FromStr};
+
 use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
@@ -5,7 +7,7 @@ use router_env::{instrument, tracing};
 pub mod transformers;
 
 use super::{
-    errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
+    errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
     metrics,
 };
 use crate::{
@@ -65,7 +67,6 @@ pub async fn accept_dispute(
     key_store: domain::MerchantKeyStore,
     req: disputes::DisputeId,
 ) -> RouterResponse<dispute_models::DisputeResponse> {
-    let db = &state.store;
     let dispute = state
         .store
         .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
@@ -73,7 +74,6 @@ pub async fn accept_dispute(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
         })?;
-    let dispute_id = dispute.dispute_id.clone();
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
             && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
@@ -87,6 +87,23 @@ pub async fn accept_dispute(
         })
         },
     )?;
+    let updated_dispute =
+        accept_dispute_with_connector(&state, &merchant_account, &key_store, dispute).await?;
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+/// Accepts the dispute with the connector and updates the dispute with the status returned by the
+/// connector. The dispute is expected to be validated to be open by the caller.
+#[instrument(skip_all)]
+pub async fn accept_dispute_with_connector(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: diesel_models::dispute::Dispute,
+) -> RouterResult<diesel_models::dispute::Dispute> {
+    let db = &state.store;
+    let dispute_id = dispute.dispute_id.clone();
     let payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &dispute.payment_id,
@@ -116,16 +133,16 @@ pub async fn accept_dispute(
         AcceptDisputeResponse,
     > = connector_data.connector.get_connector_integration();
     let router_data = core_utils::construct_accept_dispute_router_data(
-        &state,
+        state,
         &payment_intent,
         &payment_attempt,
-        &merchant_account,
-        &key_store,
+        merchant_account,
+        key_store,
         &dispute,
     )
     .await?;
     let response = services::execute_connector_processing_step(
-        &state,
+        state,
         connector_integration,
         &router_data,
         payments::CallConnectorAction::Trigger,
@@ -148,15 +165,30 @@ pub async fn accept_dispute(
         dispute_status: accept_dispute_response.dispute_status,
         connector_status: accept_dispute_response.connector_status.clone(),
     };
-    let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+    db.update_dispute(dispute.clone(), update_dispute)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable_lazy(|| {
-            format!("Unable to update dispute with dispute_id: {dispute_id}")
-        })?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
-    Ok(services::ApplicationResponse::Json(dispute_response))
+        .attach_printable_lazy(|| format!("Unable to update dispute with dispute_id: {dispute_id}"))
+}
+
+/// Whether the dispute should be accepted without the involvement of the merchant, which is the
+/// case for open disputes of an amount at or below the auto accept threshold of the business
+/// profile for the dispute currency. The thresholds are in the lowest denomination of each
+/// currency. Disputes are left for the merchant to handle if no threshold is configured for their
+/// currency.
+pub fn should_auto_accept_dispute(
+    dispute: &diesel_models::dispute::Dispute,
+    dispute_auto_accept_threshold: Option<&HashMap<storage_enums::Currency, u32>>,
+) -> bool {
+    let threshold = storage_enums::Currency::from_str(&dispute.currency.to_uppercase())
+        .ok()
+        .and_then(|currency| dispute_auto_accept_threshold?.get(&currency));
+
+    threshold.is_some_and(|threshold| {
+        dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened
+            && dispute.dispute_amount <= i64::from(*threshold)
+    })
 }
 
 #[instrument(skip(state))]
@@ -166,7 +198,6 @@ pub async fn submit_evidence(
     key_store: domain::MerchantKeyStore,
     req: dispute_models::SubmitEvidenceRequest,
 ) -> RouterResponse<dispute_models::DisputeResponse> {
-    let db = &state.store;
     let dispute = state
         .store
         .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
@@ -174,7 +205,6 @@ pub async fn submit_evidence(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id.clone(),
         })?;
-    let dispute_id = dispute.dispute_id.clone();
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
             && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
@@ -192,6 +222,105 @@ pub async fn submit_evidence(
             })
         },
     )?;
+    submit_evidence_to_connector(state, merchant_account, key_store, req, dispute).await
+}
+
+/// Checks that the evidence bundle contains the evidence required in the stage of the dispute.
+/// A receipt is required in every stage, along with the communication with the customer once the
+/// dispute is raised and a rebuttal of the pre-arbitration in the pre-arbitration stage.
+pub fn validate_evidence_bundle(
+    bundle: &dispute_models::SubmitEvidenceBundleRequest,
+    dispute_stage: storage_enums::DisputeStage,
+) -> RouterResult<()> {
+    let receipt = ("receipt", bundle.receipt.is_some());
+    let customer_communication = (
+        "customer.communication",
+        bundle
+            .customer
+            .as_ref()
+            .is_some_and(|customer| customer.communication.is_some()),
+    );
+    let rebuttal = (
+        "additional.uncategorized_text",
+        bundle
+            .additional
+            .as_ref()
+            .is_some_and(|additional| additional.uncategorized_text.is_some()),
+    );
+    // A shipment can only be traced along with the carrier which delivered it
+    let shipping_carrier = (
+        "shipping.carrier",
+        bundle.shipping.as_ref().map_or(true, |shipping| {
+            shipping.carrier.is_some()
+                || (shipping.tracking_number.is_none() && shipping.documentation.is_none())
+        }),
+    );
+
+    let required_evidence = match dispute_stage {
+        storage_enums::DisputeStage::PreDispute => vec![receipt, shipping_carrier],
+        storage_enums::DisputeStage::Dispute => {
+            vec![receipt, customer_communication, shipping_carrier]
+        }
+        storage_enums::DisputeStage::PreArbitration => {
+            vec![receipt, customer_communication, rebuttal, shipping_carrier]
+        }
+    };
+
+    required_evidence
+        .into_iter()
+        .find(|(_, is_present)| !is_present)
+        .map_or(Ok(()), |(field_name, _)| {
+            Err(errors::ApiErrorResponse::MissingRequiredField { field_name }.into())
+        })
+}
+
+/// Submits a bundle of evidence for an open dispute in any stage, after checking that the bundle
+/// contains the evidence required in the stage. The dispute is updated with the status returned by
+/// the connector, which is `dispute_challenged` once the evidence is submitted.
+#[instrument(skip(state))]
+pub async fn submit_evidence_bundle(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: dispute_models::SubmitEvidenceBundleRequest,
+) -> RouterResponse<dispute_models::DisputeResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id.clone(),
+        })?;
+    common_utils::fp_utils::when(
+        dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened,
+        || {
+            metrics::EVIDENCE_SUBMISSION_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC.add(
+                &metrics::CONTEXT,
+                1,
+                &[],
+            );
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                    "Evidence cannot be submitted because the dispute has {} status",
+                    dispute.dispute_status
+                ),
+            })
+        },
+    )?;
+    validate_evidence_bundle(&req, dispute.dispute_stage)?;
+
+    submit_evidence_to_connector(state, merchant_account, key_store, req.into(), dispute).await
+}
+
+async fn submit_evidence_to_connector(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: dispute_models::SubmitEvidenceRequest,
+    dispute: diesel_models::dispute::Dispute,
+) -> RouterResponse<dispute_models::DisputeResponse> {
+    let db = &state.store;
+    let dispute_id = dispute.dispute_id.clone();
     let submit_evidence_request_data = transformers::get_evidence_request_data(
         &state,
         &merchant_account,
@@ -463,3 +592,206 @@ pub async fn delete_evidence(
         })?;
     Ok(services::ApplicationResponse::StatusOk)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_complete_bundle() -> dispute_models::SubmitEvidenceBundleRequest {
+        dispute_models::SubmitEvidenceBundleRequest {
+            dispute_id: "dp_123".to_string(),
+            customer: Some(dispute_models::CustomerEvidence {
+                name: Some("John Doe".to_string()),
+                email_address: Some("john@example.com".to_string()),
+                communication: Some("file_customer_communication".to_string()),
+                ..Default::default()
+            }),
+            receipt: Some("file_receipt".to_string()),
+            shipping: Some(dispute_models::ShippingEvidence {
+                carrier: Some("UPS".to_string()),
+                tracking_number: Some("1Z999AA10123456784".to_string()),
+                documentation: Some("file_shipping_documentation".to_string()),
+                ..Default::default()
+            }),
+            additional: Some(dispute_models::AdditionalEvidence {
+                uncategorized_text: Some("The pre-arbitration is not valid".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn get_missing_field(result: RouterResult<()>) -> Option<&'static str> {
+        match result.unwrap_err().current_context() {
+            errors::ApiErrorResponse::MissingRequiredField { field_name } => Some(*field_name),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_complete_evidence_bundle_is_accepted() {
+        let bundle = get_complete_bundle();
+        for dispute_stage in [
+            storage_enums::DisputeStage::PreDispute,
+            storage_enums::DisputeStage::Dispute,
+            storage_enums::DisputeStage::PreArbitration,
+        ] {
+            assert!(validate_evidence_bundle(&bundle, dispute_stage).is_ok());
+        }
+
+        let evidence = dispute_models::SubmitEvidenceRequest::from(bundle);
+        assert_eq!(evidence.dispute_id, "dp_123");
+        assert_eq!(
+            evidence.customer_communication.as_deref(),
+            Some("file_customer_communication")
+        );
+        assert_eq!(evidence.receipt.as_deref(), Some("file_receipt"));
+        assert_eq!(
+            evidence.shipping_documentation.as_deref(),
+            Some("file_shipping_documentation")
+        );
+        assert_eq!(evidence.shipping_carrier.as_deref(), Some("UPS"));
+    }
+
+    #[test]
+    fn test_evidence_bundle_without_required_evidence_is_rejected() {
+        let bundle = dispute_models::SubmitEvidenceBundleRequest {
+            customer: None,
+            ..get_complete_bundle()
+        };
+        // The communication with the customer is only required once the dispute is raised
+        assert!(validate_evidence_bundle(&bundle, storage_enums::DisputeStage::PreDispute).is_ok());
+        assert_eq!(
+            get_missing_field(validate_evidence_bundle(
+                &bundle,
+                storage_enums::DisputeStage::Dispute
+            )),
+            Some("customer.communication")
+        );
+
+        let bundle = dispute_models::SubmitEvidenceBundleRequest {
+            additional: None,
+            ..get_complete_bundle()
+        };
+        assert_eq!(
+            get_missing_field(validate_evidence_bundle(
+                &bundle,
+                storage_enums::DisputeStage::PreArbitration
+            )),
+            Some("additional.uncategorized_text")
+        );
+
+        let bundle = dispute_models::SubmitEvidenceBundleRequest {
+            shipping: Some(dispute_models::ShippingEvidence {
+                tracking_number: Some("1Z999AA10123456784".to_string()),
+                ..Default::default()
+            }),
+            ..get_complete_bundle()
+        };
+        assert_eq!(
+            get_missing_field(validate_evidence_bundle(
+                &bundle,
+                storage_enums::DisputeStage::Dispute
+            )),
+            Some("shipping.carrier")
+        );
+    }
+
+    fn get_dispute(
+        dispute_amount: i64,
+        dispute_status: storage_enums::DisputeStatus,
+    ) -> diesel_models::dispute::Dispute {
+        let now = common_utils::date_time::now();
+        diesel_models::dispute::Dispute {
+            id: 1,
+            dispute_id: "dp_123".to_string(),
+            amount: dispute_amount.to_string(),
+            currency: "USD".to_string(),
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status,
+            payment_id: "pay_123".to_string(),
+            attempt_id: "pay_123_1".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "du_123".to_string(),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by: None,
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at: now,
+            modified_at: now,
+            connector: "stripe".to_string(),
+            evidence: masking::Secret::new(serde_json::Value::Null),
+            profile_id: Some("pro_123".to_string()),
+            merchant_connector_id: None,
+            dispute_amount,
+            auto_accepted: None,
+        }
+    }
+
+    fn get_threshold() -> HashMap<storage_enums::Currency, u32> {
+        HashMap::from([
+            (storage_enums::Currency::USD, 2000),
+            (storage_enums::Currency::JPY, 300),
+        ])
+    }
+
+    #[test]
+    fn test_dispute_below_threshold_is_auto_accepted() {
+        let dispute = get_dispute(1500, storage_enums::DisputeStatus::DisputeOpened);
+        assert!(should_auto_accept_dispute(&dispute, Some(&get_threshold())));
+        // The threshold itself is included
+        let dispute = get_dispute(2000, storage_enums::DisputeStatus::DisputeOpened);
+        assert!(should_auto_accept_dispute(&dispute, Some(&get_threshold())));
+    }
+
+    #[test]
+    fn test_dispute_above_threshold_is_left_for_manual_handling() {
+        let dispute = get_dispute(2500, storage_enums::DisputeStatus::DisputeOpened);
+        assert!(!should_auto_accept_dispute(
+            &dispute,
+            Some(&get_threshold())
+        ));
+        // Disputes are never auto accepted without a threshold
+        assert!(!should_auto_accept_dispute(&dispute, None));
+
+        // Disputes which are no longer open cannot be accepted
+        let dispute = get_dispute(1500, storage_enums::DisputeStatus::DisputeWon);
+        assert!(!should_auto_accept_dispute(
+            &dispute,
+            Some(&get_threshold())
+        ));
+    }
+
+    #[test]
+    fn test_dispute_threshold_is_per_currency() {
+        // 1500 JPY is above the JPY threshold, though below the USD one
+        let dispute = diesel_models::dispute::Dispute {
+            currency: "JPY".to_string(),
+            ..get_dispute(1500, storage_enums::DisputeStatus::DisputeOpened)
+        };
+        assert!(!should_auto_accept_dispute(
+            &dispute,
+            Some(&get_threshold())
+        ));
+
+        // Connectors may send the currency in lowercase
+        let dispute = diesel_models::dispute::Dispute {
+            currency: "jpy".to_string(),
+            ..get_dispute(300, storage_enums::DisputeStatus::DisputeOpened)
+        };
+        assert!(should_auto_accept_dispute(&dispute, Some(&get_threshold())));
+
+        // Currencies without a threshold are never auto accepted
+        let dispute = diesel_models::dispute::Dispute {
+            currency: "EUR".to_string(),
+            ..get_dispute(100, storage_enums::DisputeStatus::DisputeOpened)
+        };
+        assert!(!should_auto_accept_dispute(
+            &dispute,
+            Some(&get_threshold())
+        ));
+    }
+}
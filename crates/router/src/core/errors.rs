@@ -254,6 +254,8 @@ pub enum VaultError {
     SavePaymentMethodFailed,
     #[error("Failed to generate fingerprint")]
     GenerateFingerprintFailed,
+    #[error("Card vault request payload of {size} bytes exceeds the maximum of {max_size} bytes")]
+    PayloadTooLarge { size: usize, max_size: usize },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -330,6 +332,8 @@ pub enum ApplePayDecryptionError {
     KeyDeserializationFailed,
     #[error("Failed to Derive a shared secret key")]
     DerivingSharedSecretKeyFailed,
+    #[error("Private key does not belong to the certificate")]
+    CertificateKeyMismatch,
 }
 
 impl ConnectorError {
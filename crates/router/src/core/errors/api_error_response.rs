@@ -192,6 +192,8 @@ pub enum ApiErrorResponse {
     RefundNotPossible { connector: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Mandate Validation Failed" )]
     MandateValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Mandate usage limit exceeded")]
+    MandateLimitExceeded { reason: String },
     #[error(error_type= ErrorType::ValidationError, code = "HE_03", message = "The payment has not succeeded yet. Please pass a successful payment to initiate refund")]
     PaymentNotSucceeded,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The specified merchant connector account is disabled")]
@@ -271,6 +273,22 @@ pub enum ApiErrorResponse {
     InvalidCookie,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_27", message = "Extended card info does not exist")]
     ExtendedCardInfoNotFound,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "Statement descriptor contains characters not supported by {connector}: {invalid_characters}")]
+    InvalidStatementDescriptor {
+        connector: String,
+        invalid_characters: String,
+    },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_29", message = "`customer_acceptance` is required to save the card for future use")]
+    CustomerAcceptanceRequired,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_30", message = "File size exceeds the maximum allowed size of {max_file_size_bytes} bytes")]
+    FileSizeExceeded { max_file_size_bytes: usize },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_31", message = "Files of type {file_type} are not supported")]
+    UnsupportedFileType { file_type: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_32", message = "The bank account linked through {connector} requires re-authentication")]
+    RequiresReauthentication {
+        connector: String,
+        link_token: String,
+    },
 }
 
 impl PTError for ApiErrorResponse {
@@ -16,6 +16,18 @@ pub enum CustomersErrorResponse {
 
     #[error("Customer with the given customer id already exists")]
     CustomerAlreadyExists,
+
+    #[error("Address does not exist in our records")]
+    AddressNotFound,
+
+    #[error("{message}")]
+    PreconditionFailed { message: String },
+
+    #[error("{field_name} contains invalid data. Expected format is {expected_format}")]
+    InvalidDataFormat {
+        field_name: String,
+        expected_format: String,
+    },
 }
 
 impl actix_web::ResponseError for CustomersErrorResponse {
@@ -197,6 +197,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::MandateValidationFailed { reason } => {
                 AER::BadRequest(ApiError::new("HE", 3, "Mandate Validation Failed", Some(Extra { reason: Some(reason.to_owned()), ..Default::default() })))
             }
+            Self::MandateLimitExceeded { reason } => {
+                AER::BadRequest(ApiError::new("HE", 3, "Mandate usage limit exceeded", Some(Extra { reason: Some(reason.to_owned()), ..Default::default() })))
+            }
             Self::PaymentNotSucceeded => AER::BadRequest(ApiError::new("HE", 3, "The payment has not succeeded yet. Please pass a successful payment to initiate refund", None)),
             Self::PaymentBlockedError {
                 message,
@@ -304,6 +307,46 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExtendedCardInfoNotFound => {
                 AER::NotFound(ApiError::new("IR", 27, "Extended card info does not exist", None))
             }
+            Self::InvalidStatementDescriptor {
+                connector,
+                invalid_characters,
+            } => AER::BadRequest(ApiError::new(
+                "IR",
+                28,
+                format!("Statement descriptor contains characters not supported by {connector}: {invalid_characters}"),
+                None,
+            )),
+            Self::CustomerAcceptanceRequired => AER::BadRequest(ApiError::new(
+                "IR",
+                29,
+                "`customer_acceptance` is required to save the card for future use",
+                None,
+            )),
+            Self::FileSizeExceeded { max_file_size_bytes } => AER::BadRequest(ApiError::new(
+                "IR",
+                30,
+                format!("File size exceeds the maximum allowed size of {max_file_size_bytes} bytes"),
+                None,
+            )),
+            Self::UnsupportedFileType { file_type } => AER::BadRequest(ApiError::new(
+                "IR",
+                31,
+                format!("Files of type {file_type} are not supported"),
+                None,
+            )),
+            Self::RequiresReauthentication {
+                connector,
+                link_token,
+            } => AER::BadRequest(ApiError::new(
+                "IR",
+                32,
+                format!("The bank account linked through {connector} requires re-authentication"),
+                Some(Extra {
+                    connector: Some(connector.clone()),
+                    data: Some(serde_json::json!({ "link_token": link_token })),
+                    ..Default::default()
+                }),
+            )),
         }
     }
 }
@@ -357,6 +400,33 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::AddressNotFound => AER::NotFound(ApiError::new(
+                "HE",
+                4,
+                "Address does not exist in our records",
+                None,
+            )),
+            Self::PreconditionFailed { message } => {
+                AER::BadRequest(ApiError::new("IR", 16, message.to_string(), None))
+            }
+            Self::InvalidDataFormat {
+                field_name,
+                expected_format,
+            } => AER::Unprocessable(ApiError::new(
+                "IR",
+                5,
+                format!("{field_name} contains invalid data. Expected format is {expected_format}"),
+                None,
+            )),
+        }
+    }
+}
+
+impl ErrorSwitch<CustomersErrorResponse> for api_models::customers::CustomerRequestValidationError {
+    fn switch(&self) -> CustomersErrorResponse {
+        CustomersErrorResponse::InvalidDataFormat {
+            field_name: self.field_name().to_string(),
+            expected_format: self.expected_format(),
         }
     }
 }
@@ -70,6 +70,10 @@ pub enum UserErrors {
     TotpNotSetup,
     #[error("InvalidTOTP")]
     InvalidTotp,
+    #[error("RoleInUse")]
+    RoleInUse,
+    #[error("TOTPRequired")]
+    TotpRequired,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -179,6 +183,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::InvalidTotp => {
                 AER::BadRequest(ApiError::new(sub_code, 37, self.get_error_message(), None))
             }
+            Self::RoleInUse => {
+                AER::BadRequest(ApiError::new(sub_code, 38, self.get_error_message(), None))
+            }
+            Self::TotpRequired => {
+                AER::BadRequest(ApiError::new(sub_code, 39, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -217,6 +227,8 @@ impl UserErrors {
             Self::RoleNameAlreadyExists => "Role name already exists",
             Self::TotpNotSetup => "TOTP not setup",
             Self::InvalidTotp => "Invalid TOTP",
+            Self::RoleInUse => "Role is assigned to one or more users",
+            Self::TotpRequired => "TOTP required",
         }
     }
 }
@@ -1,5 +1,7 @@
 pub mod helpers;
 
+use std::time::Duration;
+
 use api_models::files;
 use error_stack::ResultExt;
 
@@ -106,12 +108,81 @@ pub async fn files_retrieve_core(
         .await
         .change_context(errors::ApiErrorResponse::FileNotFound)
         .attach_printable("Unable to retrieve file_metadata")?;
+    let file_data = retrieve_file_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        file_metadata_object,
+        req.file_id,
+    )
+    .await?;
+    Ok(ApplicationResponse::FileData(file_data))
+}
+
+pub async fn files_download_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::FileDownloadRequest,
+) -> RouterResponse<files::FileDownloadResponse> {
+    let expires_in = helpers::get_file_download_url_expiry(req.expires_in)?;
+    let file_metadata_object = state
+        .store
+        .as_ref()
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &req.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Unable to retrieve file_metadata")?;
+    // Presigned URLs can only be issued for files stored by the router, files stored with
+    // the connector are always streamed
+    let presigned_url = match (
+        file_metadata_object.file_upload_provider,
+        file_metadata_object.provider_file_id.as_ref(),
+        file_metadata_object.available,
+    ) {
+        (Some(diesel_models::enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            state
+                .file_storage_client
+                .get_presigned_url(provider_file_id, Duration::from_secs(u64::from(expires_in)))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to generate presigned url for the file")?
+        }
+        _ => None,
+    };
+    match presigned_url {
+        Some(url) => Ok(ApplicationResponse::Json(files::FileDownloadResponse {
+            file_id: req.file_id,
+            url,
+            expires_in,
+        })),
+        None => {
+            let file_data = retrieve_file_data(
+                &state,
+                &merchant_account,
+                &key_store,
+                file_metadata_object,
+                req.file_id,
+            )
+            .await?;
+            Ok(ApplicationResponse::FileData(file_data))
+        }
+    }
+}
+
+async fn retrieve_file_data(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    file_metadata_object: diesel_models::file::FileMetadata,
+    file_id: String,
+) -> errors::RouterResult<(Vec<u8>, mime::Mime)> {
     let (received_data, _provider_file_id) =
         helpers::retrieve_file_and_provider_file_id_from_file_id(
-            &state,
-            Some(req.file_id),
-            &merchant_account,
-            &key_store,
+            state,
+            Some(file_id),
+            merchant_account,
+            key_store,
             api::FileDataRequired::Required,
         )
         .await?;
@@ -120,10 +191,10 @@ pub async fn files_retrieve_core(
         .parse::<mime::Mime>()
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to parse file content type")?;
-    Ok(ApplicationResponse::FileData((
+    Ok((
         received_data
             .ok_or(errors::ApiErrorResponse::FileNotAvailable)
             .attach_printable("File data not found")?,
         content_type,
-    )))
+    ))
 }
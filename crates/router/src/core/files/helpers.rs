@@ -4,6 +4,8 @@ use error_stack::ResultExt;
 use futures::TryStreamExt;
 
 use crate::{
+    configs::settings::FileUploadConfig,
+    consts,
     core::{
         errors::{self, StorageErrorExt},
         payments, utils,
@@ -30,11 +32,65 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     }
 }
 
+/// Detects the content type of the file from its magic bytes. The content type and file name
+/// declared in the request are not trusted, as they can be set to anything by the client.
+pub fn get_file_content_type(file: &[u8]) -> CustomResult<mime::Mime, errors::ApiErrorResponse> {
+    let kind = infer::get(file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+    kind.mime_type()
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::MissingFileContentType)
+        .attach_printable("File content type error")
+}
+
+/// Validates the size and the content type of the uploaded file against the configured limits,
+/// irrespective of the purpose of the file
+pub fn validate_file_content(
+    config: &FileUploadConfig,
+    create_file_request: &api::CreateFileRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if create_file_request.file.len() > config.max_file_size_bytes {
+        return Err(errors::ApiErrorResponse::FileSizeExceeded {
+            max_file_size_bytes: config.max_file_size_bytes,
+        }
+        .into());
+    }
+
+    let file_type = create_file_request.file_type.essence_str();
+    if !config.allowed_content_types.contains(file_type) {
+        return Err(errors::ApiErrorResponse::UnsupportedFileType {
+            file_type: file_type.to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Returns the validity of a presigned file download URL, defaulting to
+/// `DEFAULT_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS` when the merchant has not requested one
+pub fn get_file_download_url_expiry(
+    expires_in: Option<u32>,
+) -> CustomResult<u32, errors::ApiErrorResponse> {
+    let expires_in = expires_in.unwrap_or(consts::DEFAULT_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS);
+    if !(1..=consts::MAX_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS).contains(&expires_in) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "expires_in should be between 1 and {} seconds",
+                consts::MAX_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS
+            ),
+        }
+        .into());
+    }
+    Ok(expires_in)
+}
+
 pub async fn validate_file_upload(
     state: &AppState,
     merchant_account: domain::MerchantAccount,
     create_file_request: api::CreateFileRequest,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
+    validate_file_content(&state.conf.file_upload, &create_file_request)?;
+
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
@@ -341,3 +397,124 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use super::*;
+
+    const PDF_CONTENT: &[u8] = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n1 0 obj\n<< >>\nendobj\n";
+    const PNG_CONTENT: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x01";
+    const GIF_CONTENT: &[u8] = b"GIF89a\x01\x00\x01\x00\x80\x00\x00";
+    const EXE_CONTENT: &[u8] = b"MZ\x90\x00\x03\x00\x00\x00\x04\x00\x00\x00\xff\xff";
+
+    fn get_config() -> FileUploadConfig {
+        FileUploadConfig {
+            max_file_size_bytes: 1024,
+            allowed_content_types: HashSet::from([
+                "application/pdf".to_string(),
+                "image/png".to_string(),
+            ]),
+        }
+    }
+
+    fn get_create_file_request(file: Vec<u8>, file_name: &str) -> api::CreateFileRequest {
+        api::CreateFileRequest {
+            file_type: get_file_content_type(&file).unwrap(),
+            file_size: i32::try_from(file.len()).unwrap(),
+            file,
+            file_name: Some(file_name.to_string()),
+            purpose: api::FilePurpose::DisputeEvidence,
+            dispute_id: Some("dp_123".to_string()),
+        }
+    }
+
+    fn get_validation_error(
+        create_file_request: &api::CreateFileRequest,
+    ) -> errors::ApiErrorResponse {
+        validate_file_content(&get_config(), create_file_request)
+            .unwrap_err()
+            .current_context()
+            .clone()
+    }
+
+    #[test]
+    fn test_allowed_file_is_accepted() {
+        let create_file_request = get_create_file_request(PDF_CONTENT.to_vec(), "receipt.pdf");
+        assert_eq!(
+            create_file_request.file_type.essence_str(),
+            "application/pdf"
+        );
+        assert!(validate_file_content(&get_config(), &create_file_request).is_ok());
+    }
+
+    #[test]
+    fn test_oversized_file_is_rejected() {
+        let file = [PDF_CONTENT, &[b' '; 1024]].concat();
+        let create_file_request = get_create_file_request(file, "receipt.pdf");
+        assert!(matches!(
+            get_validation_error(&create_file_request),
+            errors::ApiErrorResponse::FileSizeExceeded {
+                max_file_size_bytes: 1024
+            }
+        ));
+    }
+
+    #[test]
+    fn test_disallowed_file_type_is_rejected() {
+        let create_file_request = get_create_file_request(GIF_CONTENT.to_vec(), "receipt.gif");
+        assert!(matches!(
+            get_validation_error(&create_file_request),
+            errors::ApiErrorResponse::UnsupportedFileType { file_type } if file_type == "image/gif"
+        ));
+    }
+
+    #[test]
+    fn test_file_type_is_detected_from_content_and_not_extension() {
+        // An executable named as a PDF is detected and rejected
+        let create_file_request = get_create_file_request(EXE_CONTENT.to_vec(), "receipt.pdf");
+        assert!(matches!(
+            get_validation_error(&create_file_request),
+            errors::ApiErrorResponse::UnsupportedFileType { file_type }
+                if file_type == "application/vnd.microsoft.portable-executable"
+        ));
+
+        // An allowed file type is accepted with the detected type, whatever its name
+        let create_file_request = get_create_file_request(PNG_CONTENT.to_vec(), "receipt.pdf");
+        assert_eq!(create_file_request.file_type.essence_str(), "image/png");
+        assert!(validate_file_content(&get_config(), &create_file_request).is_ok());
+
+        // Files whose type cannot be detected from their content are rejected
+        assert!(matches!(
+            get_file_content_type(b"just some text")
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::MissingFileContentType
+        ));
+    }
+
+    #[test]
+    fn test_file_download_url_expiry() {
+        assert_eq!(
+            get_file_download_url_expiry(None).unwrap(),
+            consts::DEFAULT_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS
+        );
+        assert_eq!(get_file_download_url_expiry(Some(300)).unwrap(), 300);
+        assert_eq!(
+            get_file_download_url_expiry(Some(consts::MAX_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS))
+                .unwrap(),
+            consts::MAX_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS
+        );
+
+        for expires_in in [0, consts::MAX_FILE_DOWNLOAD_URL_EXPIRY_IN_SECS + 1] {
+            assert!(matches!(
+                get_file_download_url_expiry(Some(expires_in))
+                    .unwrap_err()
+                    .current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+}
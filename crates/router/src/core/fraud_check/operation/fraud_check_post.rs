@@ -206,6 +206,10 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPost {
                 payment_id: frm_data.payment_intent.payment_id.clone(),
                 cancellation_reason: frm_data.fraud_check.frm_error.clone(),
                 merchant_connector_details: None,
+                force_reject: false,
+                rejection_reason: None,
+                reject_decision: None,
+                notify_customer: Some(false),
             };
             let cancel_res = Box::pin(payments::payments_core::<
                 Void,
@@ -260,6 +264,7 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPost {
                 statement_descriptor_suffix: None,
                 statement_descriptor_prefix: None,
                 merchant_connector_details: None,
+                capture_reference: None,
             };
             let capture_response = Box::pin(payments::payments_core::<
                 Capture,
@@ -1,14 +1,17 @@
+use std::str::FromStr;
+
 use api_models::gsm as gsm_api_types;
 use diesel_models::gsm as storage;
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
+    consts,
     core::{
         errors,
-        errors::{RouterResponse, StorageErrorExt},
+        errors::{CustomResult, RouterResponse, StorageErrorExt},
     },
-    db::gsm::GsmInterface,
+    db::{gsm::GsmInterface, StorageInterface},
     services,
     types::transformers::ForeignInto,
     AppState,
@@ -49,6 +52,114 @@ pub async fn retrieve_gsm_rule(
         .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
 }
 
+/// Finds the GSM rule for the connector error code and message. Both the rule configured for the
+/// exact message and the one configured with the wildcard message are considered, and the rule
+/// with the highest priority is picked. On a tie, the rule for the exact message is preferred.
+pub async fn find_gsm_rule_with_wildcard_message(
+    db: &dyn StorageInterface,
+    connector: String,
+    flow: String,
+    sub_flow: String,
+    code: String,
+    message: String,
+) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+    let gsm_rules = db
+        .find_gsm_rules_by_messages(
+            connector,
+            flow,
+            sub_flow,
+            code,
+            vec![message.clone(), consts::GSM_WILDCARD_MESSAGE.to_string()],
+        )
+        .await?;
+
+    select_gsm_rule(gsm_rules, &message).ok_or_else(|| {
+        errors::StorageError::ValueNotFound("cannot find gsm rule".to_string()).into()
+    })
+}
+
+fn select_gsm_rule(
+    gsm_rules: Vec<storage::GatewayStatusMap>,
+    message: &str,
+) -> Option<storage::GatewayStatusMap> {
+    gsm_rules
+        .into_iter()
+        .max_by_key(|gsm_rule| (gsm_rule.priority, gsm_rule.message == message))
+}
+
+/// Derives the reason and the suggested retry action for the GSM rule matched for a connector
+/// response. A missing rule or a rule with an unrecognized decision is not retried.
+pub fn get_gsm_retry_metadata(
+    option_gsm: Option<&storage::GatewayStatusMap>,
+) -> gsm_api_types::GsmRetryMetadata {
+    let retry_reason = match option_gsm {
+        Some(gsm) => match gsm_api_types::GsmDecision::from_str(gsm.decision.as_str()) {
+            Ok(gsm_api_types::GsmDecision::Retry) => gsm_api_types::GsmRetryReason::RetryableError,
+            Ok(gsm_api_types::GsmDecision::Requeue) => {
+                gsm_api_types::GsmRetryReason::RequeueRequested
+            }
+            Ok(gsm_api_types::GsmDecision::DoDefault) => {
+                gsm_api_types::GsmRetryReason::NonRetryableError
+            }
+            Err(error) => {
+                logger::warn!(
+                    gsm_decision_parse_error=?error,
+                    decision = %gsm.decision,
+                    "gsm decision parsing failed"
+                );
+                gsm_api_types::GsmRetryReason::InvalidGsmDecision
+            }
+        },
+        None => gsm_api_types::GsmRetryReason::GsmRuleNotFound,
+    };
+
+    let suggested_action = match retry_reason {
+        gsm_api_types::GsmRetryReason::RetryableError => {
+            gsm_api_types::GsmSuggestedAction::RetryAlternateConnector
+        }
+        gsm_api_types::GsmRetryReason::RequeueRequested => {
+            gsm_api_types::GsmSuggestedAction::RetrySameConnector
+        }
+        gsm_api_types::GsmRetryReason::NonRetryableError
+        | gsm_api_types::GsmRetryReason::GsmRuleNotFound
+        | gsm_api_types::GsmRetryReason::InvalidGsmDecision => {
+            gsm_api_types::GsmSuggestedAction::DoNotRetry
+        }
+    };
+
+    gsm_api_types::GsmRetryMetadata {
+        retry_reason,
+        suggested_action,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn lookup_gsm_rule(
+    state: AppState,
+    gsm_request: gsm_api_types::GsmLookupRequest,
+) -> RouterResponse<gsm_api_types::GsmResponse> {
+    let db = state.store.as_ref();
+    let gsm_api_types::GsmLookupRequest {
+        connector,
+        flow,
+        code,
+        message,
+    } = gsm_request;
+    find_gsm_rule_with_wildcard_message(
+        db,
+        connector.to_string(),
+        flow,
+        consts::DEFAULT_GSM_SUB_FLOW.to_string(),
+        code,
+        message,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "No GSM rule matches the given connector error code and message".to_string(),
+    })
+    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+}
+
 #[instrument(skip_all)]
 pub async fn update_gsm_rule(
     state: AppState,
@@ -67,6 +178,7 @@ pub async fn update_gsm_rule(
         step_up_possible,
         unified_code,
         unified_message,
+        priority,
     } = gsm_request;
     GsmInterface::update_gsm_rule(
         db,
@@ -82,6 +194,7 @@ pub async fn update_gsm_rule(
             step_up_possible,
             unified_code,
             unified_message,
+            priority,
         },
     )
     .await
@@ -138,3 +251,217 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::db::MockDb;
+
+    async fn get_mockdb_with_gsm_rules() -> MockDb {
+        get_mockdb_with_prioritized_gsm_rules(0, 0).await
+    }
+
+    async fn get_mockdb_with_prioritized_gsm_rules(
+        exact_message_priority: i32,
+        wildcard_message_priority: i32,
+    ) -> MockDb {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        for (message, decision, priority) in [
+            ("Insufficient funds", "do_default", exact_message_priority),
+            (
+                consts::GSM_WILDCARD_MESSAGE,
+                "retry",
+                wildcard_message_priority,
+            ),
+        ] {
+            mockdb
+                .add_gsm_rule(storage::GatewayStatusMappingNew {
+                    connector: "stripe".to_string(),
+                    flow: "Authorize".to_string(),
+                    sub_flow: consts::DEFAULT_GSM_SUB_FLOW.to_string(),
+                    code: "card_declined".to_string(),
+                    message: message.to_string(),
+                    status: "failure".to_string(),
+                    router_error: None,
+                    decision: decision.to_string(),
+                    step_up_possible: false,
+                    unified_code: None,
+                    unified_message: None,
+                    priority,
+                })
+                .await
+                .unwrap();
+        }
+
+        mockdb
+    }
+
+    async fn lookup(
+        db: &MockDb,
+        code: &str,
+        message: &str,
+    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+        find_gsm_rule_with_wildcard_message(
+            db,
+            "stripe".to_string(),
+            "Authorize".to_string(),
+            consts::DEFAULT_GSM_SUB_FLOW.to_string(),
+            code.to_string(),
+            message.to_string(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_gsm_lookup_exact_match() {
+        let mockdb = get_mockdb_with_gsm_rules().await;
+
+        let gsm = lookup(&mockdb, "card_declined", "Insufficient funds")
+            .await
+            .unwrap();
+
+        assert_eq!(gsm.message, "Insufficient funds");
+        assert_eq!(gsm.decision, "do_default");
+    }
+
+    #[tokio::test]
+    async fn test_gsm_lookup_wildcard_message_match() {
+        let mockdb = get_mockdb_with_gsm_rules().await;
+
+        let gsm = lookup(&mockdb, "card_declined", "Do not honor")
+            .await
+            .unwrap();
+
+        assert_eq!(gsm.message, consts::GSM_WILDCARD_MESSAGE);
+        assert_eq!(gsm.decision, "retry");
+    }
+
+    #[tokio::test]
+    async fn test_gsm_lookup_prefers_higher_priority_wildcard_message() {
+        let mockdb = get_mockdb_with_prioritized_gsm_rules(0, 10).await;
+
+        let gsm = lookup(&mockdb, "card_declined", "Insufficient funds")
+            .await
+            .unwrap();
+
+        assert_eq!(gsm.message, consts::GSM_WILDCARD_MESSAGE);
+        assert_eq!(gsm.decision, "retry");
+    }
+
+    #[tokio::test]
+    async fn test_gsm_lookup_prefers_higher_priority_exact_message() {
+        let mockdb = get_mockdb_with_prioritized_gsm_rules(10, 5).await;
+
+        let gsm = lookup(&mockdb, "card_declined", "Insufficient funds")
+            .await
+            .unwrap();
+
+        assert_eq!(gsm.message, "Insufficient funds");
+        assert_eq!(gsm.decision, "do_default");
+    }
+
+    #[tokio::test]
+    async fn test_gsm_lookup_priority_tie_prefers_exact_message() {
+        let mockdb = get_mockdb_with_prioritized_gsm_rules(5, 5).await;
+
+        let gsm = lookup(&mockdb, "card_declined", "Insufficient funds")
+            .await
+            .unwrap();
+
+        assert_eq!(gsm.message, "Insufficient funds");
+    }
+
+    #[tokio::test]
+    async fn test_gsm_retry_metadata_for_error_codes() {
+        let mockdb = get_mockdb_with_gsm_rules().await;
+        for (code, decision) in [
+            ("processing_error", "requeue"),
+            ("incorrect_number", "do_default"),
+            ("lost_card", "block"),
+        ] {
+            mockdb
+                .add_gsm_rule(storage::GatewayStatusMappingNew {
+                    connector: "stripe".to_string(),
+                    flow: "Authorize".to_string(),
+                    sub_flow: consts::DEFAULT_GSM_SUB_FLOW.to_string(),
+                    code: code.to_string(),
+                    message: consts::GSM_WILDCARD_MESSAGE.to_string(),
+                    status: "failure".to_string(),
+                    router_error: None,
+                    decision: decision.to_string(),
+                    step_up_possible: false,
+                    unified_code: None,
+                    unified_message: None,
+                    priority: 0,
+                })
+                .await
+                .unwrap();
+        }
+
+        for (code, message, retry_reason, suggested_action) in [
+            (
+                "card_declined",
+                "Do not honor",
+                gsm_api_types::GsmRetryReason::RetryableError,
+                gsm_api_types::GsmSuggestedAction::RetryAlternateConnector,
+            ),
+            (
+                "card_declined",
+                "Insufficient funds",
+                gsm_api_types::GsmRetryReason::NonRetryableError,
+                gsm_api_types::GsmSuggestedAction::DoNotRetry,
+            ),
+            (
+                "processing_error",
+                "An error occurred while processing your card",
+                gsm_api_types::GsmRetryReason::RequeueRequested,
+                gsm_api_types::GsmSuggestedAction::RetrySameConnector,
+            ),
+            (
+                "incorrect_number",
+                "Your card number is incorrect",
+                gsm_api_types::GsmRetryReason::NonRetryableError,
+                gsm_api_types::GsmSuggestedAction::DoNotRetry,
+            ),
+            (
+                "lost_card",
+                "Your card has been declined",
+                gsm_api_types::GsmRetryReason::InvalidGsmDecision,
+                gsm_api_types::GsmSuggestedAction::DoNotRetry,
+            ),
+            (
+                "expired_card",
+                "Your card has expired",
+                gsm_api_types::GsmRetryReason::GsmRuleNotFound,
+                gsm_api_types::GsmSuggestedAction::DoNotRetry,
+            ),
+        ] {
+            let gsm = lookup(&mockdb, code, message).await.ok();
+
+            assert_eq!(
+                get_gsm_retry_metadata(gsm.as_ref()),
+                gsm_api_types::GsmRetryMetadata {
+                    retry_reason,
+                    suggested_action,
+                },
+                "unexpected retry metadata for error code {code}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gsm_lookup_miss() {
+        let mockdb = get_mockdb_with_gsm_rules().await;
+
+        let error = lookup(&mockdb, "expired_card", "Insufficient funds")
+            .await
+            .unwrap_err();
+
+        assert!(error.current_context().is_db_not_found());
+    }
+}
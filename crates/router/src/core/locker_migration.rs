@@ -1,20 +1,186 @@
-use api_models::{enums as api_enums, locker_migration::MigrateCardResponse};
-use common_utils::errors::CustomResult;
-use diesel_models::{enums as storage_enums, PaymentMethod};
+use api_models::{
+    enums as api_enums,
+    locker_migration::{LockerMigrationStatus, LockerMigrationStatusResponse, MigrateCardResponse},
+};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::{configs, enums as storage_enums, PaymentMethod};
 use error_stack::{FutureExt, ResultExt};
 use futures::TryFutureExt;
 
-use super::{errors::StorageErrorExt, payment_methods::cards};
+use super::{
+    api_locking,
+    errors::{RouterResult, StorageErrorExt},
+    payment_methods::cards,
+};
 use crate::{
+    db::StorageInterface,
     errors,
-    routes::AppState,
+    routes::{lock_utils, AppState},
     services::{self, logger},
     types::{api, domain},
 };
 
+/// Progress of the card migration of a merchant, persisted so that an interrupted migration can
+/// be resumed from the last customer whose cards were processed
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LockerMigrationProgress {
+    pub status: LockerMigrationStatus,
+    pub total: usize,
+    pub customers_processed: usize,
+    pub migrated: usize,
+    pub failed: usize,
+    pub cursor: Option<String>,
+}
+
+impl LockerMigrationProgress {
+    fn new(total: usize) -> Self {
+        Self {
+            status: LockerMigrationStatus::InProgress,
+            total,
+            customers_processed: 0,
+            migrated: 0,
+            failed: 0,
+            cursor: None,
+        }
+    }
+
+    fn record_customer(&mut self, customer_id: &str, outcome: CardMigrationOutcome) {
+        self.customers_processed += 1;
+        self.migrated += outcome.migrated;
+        self.failed += outcome.failed;
+        self.cursor = Some(customer_id.to_string());
+    }
+
+    fn complete(&mut self) {
+        self.status = LockerMigrationStatus::Completed;
+    }
+
+    fn into_status_response(self, merchant_id: String) -> LockerMigrationStatusResponse {
+        LockerMigrationStatusResponse {
+            merchant_id,
+            status: self.status,
+            total: self.total,
+            customers_processed: self.customers_processed,
+            migrated: self.migrated,
+            failed: self.failed,
+            cursor: self.cursor,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CardMigrationOutcome {
+    pub migrated: usize,
+    pub failed: usize,
+}
+
+fn get_locker_migration_progress_key(merchant_id: &str) -> String {
+    format!("locker_migration_progress_{merchant_id}")
+}
+
+async fn find_locker_migration_progress(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<LockerMigrationProgress>> {
+    match db
+        .find_config_by_key_from_db(&get_locker_migration_progress_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("LockerMigrationProgress")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch locker migration progress"),
+    }
+}
+
+async fn store_locker_migration_progress(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    progress: &LockerMigrationProgress,
+    is_existing_record: bool,
+) -> RouterResult<()> {
+    let key = get_locker_migration_progress_key(merchant_id);
+    let config = progress
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize locker migration progress")?;
+
+    if is_existing_record {
+        // The progress is only read from the database, so the config cache need not be redacted
+        db.update_config_in_database(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .map(|_| ())
+    } else {
+        db.insert_config(configs::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store locker migration progress")
+}
+
+/// Customers whose cards are yet to be migrated, given the cursor of an interrupted migration.
+/// Returns `None` if the cursor no longer refers to any of the customers.
+fn get_pending_customers<'a, T>(
+    customers: &'a [T],
+    cursor: Option<&str>,
+    get_customer_id: impl Fn(&T) -> &str,
+) -> Option<&'a [T]> {
+    let Some(cursor) = cursor else {
+        return Some(customers);
+    };
+
+    customers
+        .iter()
+        .position(|customer| get_customer_id(customer) == cursor)
+        .map(|position| customers.get(position + 1..).unwrap_or_default())
+}
+
 pub async fn rust_locker_migration(
     state: AppState,
     merchant_id: &str,
+) -> CustomResult<services::ApplicationResponse<MigrateCardResponse>, errors::ApiErrorResponse> {
+    // A second migration for the merchant is rejected while one is running
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: merchant_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::RustLockerMigration,
+            override_lock_retries: Some(1),
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.to_owned())
+        .await?;
+
+    let result = rust_locker_migration_with_lock_held(&state, merchant_id, &lock_action).await;
+
+    lock_action
+        .free_lock_action(&state, merchant_id.to_owned())
+        .await?;
+
+    result
+}
+
+/// Migrates the cards of the customers of the merchant, renewing the lock after every customer as
+/// the migration of a merchant with many customers outlasts the lock expiry
+async fn rust_locker_migration_with_lock_held(
+    state: &AppState,
+    merchant_id: &str,
+    lock_action: &api_locking::LockAction,
 ) -> CustomResult<services::ApplicationResponse<MigrateCardResponse>, errors::ApiErrorResponse> {
     let db = state.store.as_ref();
 
@@ -38,11 +204,38 @@ pub async fn rust_locker_migration(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-    let mut customers_moved = 0;
-    let mut cards_moved = 0;
+    let existing_progress = find_locker_migration_progress(db, merchant_id).await?;
+    let is_existing_record = existing_progress.is_some();
+    let mut progress = match existing_progress {
+        Some(mut progress) if progress.status == LockerMigrationStatus::InProgress => {
+            logger::info!(cursor = ?progress.cursor, "Resuming locker migration");
+            progress.total = domain_customers.len();
+            progress
+        }
+        _ => LockerMigrationProgress::new(domain_customers.len()),
+    };
+    store_locker_migration_progress(db, merchant_id, &progress, is_existing_record).await?;
 
-    for customer in domain_customers {
-        let result = db
+    let pending_customers =
+        match get_pending_customers(&domain_customers, progress.cursor.as_deref(), |customer| {
+            customer.customer_id.as_str()
+        }) {
+            Some(pending_customers) => pending_customers,
+            None => {
+                // Cards which were already migrated are detected as duplicates by the locker, so the
+                // counts are started afresh rather than adding to those of the interrupted run
+                logger::warn!(
+                    cursor = ?progress.cursor,
+                    "Locker migration cursor not found, migrating all customers again"
+                );
+                progress = LockerMigrationProgress::new(domain_customers.len());
+                store_locker_migration_progress(db, merchant_id, &progress, true).await?;
+                &domain_customers
+            }
+        };
+
+    for customer in pending_customers {
+        let outcome = db
             .find_payment_method_by_customer_id_merchant_id_list(
                 &customer.customer_id,
                 merchant_id,
@@ -51,7 +244,7 @@ pub async fn rust_locker_migration(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .and_then(|pm| {
                 call_to_locker(
-                    &state,
+                    state,
                     pm,
                     &customer.customer_id,
                     merchant_id,
@@ -60,28 +253,54 @@ pub async fn rust_locker_migration(
             })
             .await?;
 
-        customers_moved += 1;
-        cards_moved += result;
+        progress.record_customer(&customer.customer_id, outcome);
+        store_locker_migration_progress(db, merchant_id, &progress, true).await?;
+
+        lock_action
+            .clone()
+            .renew_lock_action(state, merchant_id.to_owned())
+            .await?;
     }
 
+    progress.complete();
+    store_locker_migration_progress(db, merchant_id, &progress, true).await?;
+
     Ok(services::api::ApplicationResponse::Json(
         MigrateCardResponse {
             status_code: "200".to_string(),
             status_message: "Card migration completed".to_string(),
-            customers_moved,
-            cards_moved,
+            customers_moved: progress.customers_processed,
+            cards_moved: progress.migrated,
         },
     ))
 }
 
+pub async fn rust_locker_migration_status(
+    state: AppState,
+    merchant_id: &str,
+) -> CustomResult<
+    services::ApplicationResponse<LockerMigrationStatusResponse>,
+    errors::ApiErrorResponse,
+> {
+    let progress = find_locker_migration_progress(state.store.as_ref(), merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("No card migration has been started for merchant {merchant_id}"),
+        })?;
+
+    Ok(services::api::ApplicationResponse::Json(
+        progress.into_status_response(merchant_id.to_string()),
+    ))
+}
+
 pub async fn call_to_locker(
     state: &AppState,
     payment_methods: Vec<PaymentMethod>,
     customer_id: &String,
     merchant_id: &str,
     merchant_account: &domain::MerchantAccount,
-) -> CustomResult<usize, errors::ApiErrorResponse> {
-    let mut cards_moved = 0;
+) -> CustomResult<CardMigrationOutcome, errors::ApiErrorResponse> {
+    let mut outcome = CardMigrationOutcome::default();
 
     for pm in payment_methods
         .into_iter()
@@ -99,6 +318,7 @@ pub async fn call_to_locker(
             Ok(card) => card,
             Err(err) => {
                 logger::error!("Failed to fetch card from Basilisk HS locker : {:?}", err);
+                outcome.failed += 1;
                 continue;
             }
         };
@@ -152,11 +372,12 @@ pub async fn call_to_locker(
             Ok(output) => output,
             Err(err) => {
                 logger::error!("Failed to add card to Rust locker : {:?}", err);
+                outcome.failed += 1;
                 continue;
             }
         };
 
-        cards_moved += 1;
+        outcome.migrated += 1;
 
         logger::info!(
                 "Card migrated for merchant_id: {merchant_id}, customer_id: {customer_id}, payment_method_id: {} ",
@@ -164,5 +385,121 @@ pub async fn call_to_locker(
             );
     }
 
-    Ok(cards_moved)
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn customer_ids() -> Vec<String> {
+        vec![
+            "cus_1".to_string(),
+            "cus_2".to_string(),
+            "cus_3".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_locker_migration_status_reporting() {
+        let mut progress = LockerMigrationProgress::new(3);
+        progress.record_customer(
+            "cus_1",
+            CardMigrationOutcome {
+                migrated: 2,
+                failed: 0,
+            },
+        );
+        progress.record_customer(
+            "cus_2",
+            CardMigrationOutcome {
+                migrated: 1,
+                failed: 1,
+            },
+        );
+
+        let status = progress
+            .clone()
+            .into_status_response("merchant_1".to_string());
+        assert_eq!(status.status, LockerMigrationStatus::InProgress);
+        assert_eq!(status.total, 3);
+        assert_eq!(status.customers_processed, 2);
+        assert_eq!(status.migrated, 3);
+        assert_eq!(status.failed, 1);
+        assert_eq!(status.cursor.as_deref(), Some("cus_2"));
+
+        progress.complete();
+        assert_eq!(
+            progress
+                .into_status_response("merchant_1".to_string())
+                .status,
+            LockerMigrationStatus::Completed
+        );
+    }
+
+    #[test]
+    fn test_locker_migration_resumes_after_cursor() {
+        let customers = customer_ids();
+        let get_id = |customer: &String| customer.as_str();
+
+        assert_eq!(
+            get_pending_customers(&customers, None, get_id),
+            Some(customers.as_slice())
+        );
+        assert_eq!(
+            get_pending_customers(&customers, Some("cus_1"), get_id),
+            Some(["cus_2".to_string(), "cus_3".to_string()].as_slice())
+        );
+        assert_eq!(
+            get_pending_customers(&customers, Some("cus_3"), get_id),
+            Some([].as_slice())
+        );
+        assert_eq!(
+            get_pending_customers(&customers, Some("cus_deleted"), get_id),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_locker_migration_progress_is_persisted() {
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+
+        assert!(find_locker_migration_progress(&db, "merchant_1")
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut progress = LockerMigrationProgress::new(3);
+        store_locker_migration_progress(&db, "merchant_1", &progress, false)
+            .await
+            .unwrap();
+        progress.record_customer(
+            "cus_1",
+            CardMigrationOutcome {
+                migrated: 1,
+                failed: 0,
+            },
+        );
+        store_locker_migration_progress(&db, "merchant_1", &progress, true)
+            .await
+            .unwrap();
+
+        let stored_progress = find_locker_migration_progress(&db, "merchant_1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored_progress, progress);
+        assert_eq!(
+            get_pending_customers(
+                &customer_ids(),
+                stored_progress.cursor.as_deref(),
+                |customer| customer.as_str()
+            ),
+            Some(["cus_2".to_string(), "cus_3".to_string()].as_slice())
+        );
+    }
 }
@@ -9,12 +9,14 @@ use router_env::{instrument, logger, tracing};
 
 use super::payments::helpers as payment_helper;
 use crate::{
+    consts,
     core::{
+        api_locking,
         errors::{self, RouterResponse, StorageErrorExt},
         payments::CallConnectorAction,
     },
     db::StorageInterface,
-    routes::{metrics, AppState},
+    routes::{lock_utils, metrics, AppState},
     services,
     types::{
         self,
@@ -77,7 +79,8 @@ pub async fn revoke_mandate(
     match mandate.mandate_status {
         common_enums::MandateStatus::Active
         | common_enums::MandateStatus::Inactive
-        | common_enums::MandateStatus::Pending => {
+        | common_enums::MandateStatus::Pending
+        | common_enums::MandateStatus::Paused => {
             let profile_id =
                 helpers::get_profile_id_for_mandate(&state, &merchant_account, mandate.clone())
                     .await?;
@@ -166,6 +169,296 @@ pub async fn revoke_mandate(
     }
 }
 
+#[instrument(skip(state))]
+pub async fn pause_mandate(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: mandates::MandateId,
+) -> RouterResponse<mandates::MandateResponse> {
+    update_mandate_status(
+        state,
+        merchant_account,
+        key_store,
+        req,
+        helpers::get_paused_mandate_status,
+    )
+    .await
+}
+
+#[instrument(skip(state))]
+pub async fn resume_mandate(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: mandates::MandateId,
+) -> RouterResponse<mandates::MandateResponse> {
+    update_mandate_status(
+        state,
+        merchant_account,
+        key_store,
+        req,
+        helpers::get_resumed_mandate_status,
+    )
+    .await
+}
+
+async fn update_mandate_status(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: mandates::MandateId,
+    get_updated_status: fn(
+        storage_enums::MandateStatus,
+    ) -> errors::CustomResult<
+        storage_enums::MandateStatus,
+        errors::ApiErrorResponse,
+    >,
+) -> RouterResponse<mandates::MandateResponse> {
+    let db = state.store.as_ref();
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    let mandate_status = get_updated_status(mandate.mandate_status)?;
+
+    let mandate = db
+        .update_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            storage::MandateUpdate::StatusUpdate { mandate_status },
+            mandate,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        mandates::MandateResponse::from_db_mandate(
+            &state,
+            key_store,
+            mandate,
+            merchant_account.storage_scheme,
+        )
+        .await?,
+    ))
+}
+
+/// The debit reserved against the usage limits of a mandate for a payment attempt
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct MandateDebitReservation {
+    mandate_id: String,
+    amount: i64,
+}
+
+fn get_mandate_debit_reservation_key(merchant_id: &str, attempt_id: &str) -> String {
+    format!("mandate_debit_reservation_{merchant_id}_{attempt_id}")
+}
+
+/// Reserves a debit of `amount` against the usage limits of the mandate for the payment attempt,
+/// before the payment is made with the connector. The mandate is locked while its usage is updated,
+/// so that concurrent payments cannot both debit the last of the remaining usage. A debit is reserved
+/// at most once for a payment attempt.
+#[instrument(skip(state))]
+pub async fn reserve_mandate_debit(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    attempt_id: &str,
+    amount: i64,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let reservation_key = get_mandate_debit_reservation_key(merchant_id, attempt_id);
+    let reservation = MandateDebitReservation {
+        mandate_id: mandate_id.to_owned(),
+        amount,
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize mandate debit reservation")?;
+
+    let set_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &reservation_key,
+            reservation,
+            Some(consts::MANDATE_DEBIT_RESERVATION_TTL),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store mandate debit reservation")?;
+    if set_reply == redis_interface::SetnxReply::KeyNotSet {
+        logger::debug!("Mandate debit already reserved for the payment attempt");
+        return Ok(());
+    }
+
+    let result =
+        update_mandate_debit_usage(state, merchant_id, mandate_id, storage_scheme, |mandate| {
+            helpers::get_reserved_mandate_debit_usage(mandate, amount)
+        })
+        .await;
+
+    // Nothing is reserved if the mandate has no usage limits or the limits have been reached
+    if !matches!(result, Ok(true)) {
+        redis_conn
+            .delete_key(&reservation_key)
+            .await
+            .map_err(|error| logger::error!(mandate_debit_reservation_delete_error=?error))
+            .ok();
+    }
+
+    result.map(|_| ())
+}
+
+/// Releases the debit reserved for the payment attempt against the usage limits of the mandate, once
+/// the payment attempt has failed or has been voided. Nothing is released if no debit was reserved
+/// for the payment attempt, or if it has already been released.
+#[instrument(skip(state))]
+pub async fn release_mandate_debit(
+    state: &AppState,
+    merchant_id: &str,
+    attempt_id: &str,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let reservation_key = get_mandate_debit_reservation_key(merchant_id, attempt_id);
+
+    let reservation = match redis_conn
+        .get_and_deserialize_key::<MandateDebitReservation>(
+            &reservation_key,
+            "MandateDebitReservation",
+        )
+        .await
+    {
+        Ok(reservation) => reservation,
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) =>
+        {
+            return Ok(());
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch mandate debit reservation"));
+        }
+    };
+
+    // The reservation is deleted before the debit is released, so that concurrent updates of the
+    // payment attempt release it only once
+    let delete_reply = redis_conn
+        .delete_key(&reservation_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete mandate debit reservation")?;
+    if matches!(delete_reply, redis_interface::DelReply::KeyNotDeleted) {
+        return Ok(());
+    }
+
+    update_mandate_debit_usage(
+        state,
+        merchant_id,
+        &reservation.mandate_id,
+        storage_scheme,
+        |mandate| {
+            Ok(helpers::get_released_mandate_debit_usage(
+                mandate,
+                reservation.amount,
+            ))
+        },
+    )
+    .await
+    .map(|_| ())
+}
+
+/// Returns whether the usage of the mandate was updated, which is only the case for mandates with
+/// usage limits
+async fn update_mandate_debit_usage(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    storage_scheme: MerchantStorageScheme,
+    get_debit_usage: impl FnOnce(&Mandate) -> errors::RouterResult<(i32, i64)>,
+) -> errors::RouterResult<bool> {
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: mandate_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Mandates,
+            override_lock_retries: None,
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(state, merchant_id.to_owned())
+        .await?;
+
+    let result = update_mandate_debit_usage_with_lock_held(
+        state,
+        merchant_id,
+        mandate_id,
+        storage_scheme,
+        get_debit_usage,
+    )
+    .await;
+
+    lock_action
+        .free_lock_action(state, merchant_id.to_owned())
+        .await?;
+
+    result
+}
+
+async fn update_mandate_debit_usage_with_lock_held(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    storage_scheme: MerchantStorageScheme,
+    get_debit_usage: impl FnOnce(&Mandate) -> errors::RouterResult<(i32, i64)>,
+) -> errors::RouterResult<bool> {
+    let db = state.store.as_ref();
+    // The mandate is read again once the lock is acquired, so that the usage of debits made in the
+    // meantime is accounted for
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    if !helpers::has_mandate_usage_limits(&mandate) {
+        return Ok(false);
+    }
+
+    let (debit_count, debited_amount) = get_debit_usage(&mandate)?;
+    db.update_mandate_by_merchant_id_mandate_id(
+        merchant_id,
+        mandate_id,
+        storage::MandateUpdate::DebitUsageUpdate {
+            debit_count,
+            debited_amount,
+        },
+        mandate,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+
+    Ok(true)
+}
+
 #[instrument(skip(db))]
 pub async fn update_connector_mandate_id(
     db: &dyn StorageInterface,
@@ -221,12 +514,62 @@ pub async fn update_connector_mandate_id(
     Ok(services::ApplicationResponse::StatusOk)
 }
 
+/// Backfills the connector mandate id of a mandate that was stored without one, for connectors that
+/// return the mandate reference only in a later webhook rather than in the authorize response.
+/// Mandates that already have a connector mandate id are left untouched.
+#[instrument(skip(db))]
+pub async fn backfill_connector_mandate_id(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    mandate_id: &str,
+    mandate_reference: types::MandateReference,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<Option<Mandate>> {
+    let Some(connector_mandate_id) = mandate_reference.connector_mandate_id.clone() else {
+        return Ok(None);
+    };
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    if mandate.connector_mandate_id.is_some() {
+        return Ok(None);
+    }
+
+    let connector_mandate_ids = mandate_reference
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .map(masking::Secret::new)?;
+    let update_mandate_details = storage::MandateUpdate::ConnectorMandateIdUpdate {
+        connector_mandate_id: Some(connector_mandate_id),
+        connector_mandate_ids: Some(connector_mandate_ids),
+        payment_method_id: mandate.payment_method_id.clone(),
+        original_payment_id: mandate.original_payment_id.clone(),
+    };
+
+    let updated_mandate = db
+        .update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            mandate_id,
+            update_mandate_details,
+            mandate,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+    logger::info!(mandate_id, "Backfilled connector mandate id from webhook");
+
+    Ok(Some(updated_mandate))
+}
+
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: customers::CustomerId,
+    constraints: mandates::CustomerMandateListConstraints,
 ) -> RouterResponse<Vec<mandates::MandateResponse>> {
     let mandates = state
         .store
@@ -243,6 +586,7 @@ pub async fn get_customer_mandates(
     if mandates.is_empty() {
         Err(report!(errors::ApiErrorResponse::MandateNotFound).attach_printable("No Mandate found"))
     } else {
+        let mandates = helpers::filter_customer_mandates(mandates, &constraints);
         let mut response_vec = Vec::with_capacity(mandates.len());
         for mandate in mandates {
             response_vec.push(
@@ -520,3 +864,110 @@ pub trait MandateBehaviour {
     ) -> Option<&hyperswitch_domain_models::mandates::MandateData>;
     fn get_customer_acceptance(&self) -> Option<payments::CustomerAcceptance>;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::db::{mandate::MandateInterface, MockDb};
+
+    const MERCHANT_ID: &str = "merchant_mandate_backfill";
+    const MANDATE_ID: &str = "man_backfill";
+
+    async fn get_db_with_mandate(connector_mandate_id: Option<String>) -> MockDb {
+        #[allow(clippy::expect_used)]
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        db.insert_mandate(
+            storage::MandateNew {
+                mandate_id: MANDATE_ID.to_string(),
+                merchant_id: MERCHANT_ID.to_string(),
+                payment_method_id: "pm_backfill".to_string(),
+                original_payment_id: Some("pay_backfill".to_string()),
+                connector_mandate_id,
+                ..Default::default()
+            },
+            MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap();
+        db
+    }
+
+    fn get_webhook_mandate_reference(connector_mandate_id: &str) -> types::MandateReference {
+        types::MandateReference {
+            connector_mandate_id: Some(connector_mandate_id.to_string()),
+            payment_method_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_later_webhook_backfills_empty_mandate_reference() {
+        let db = get_db_with_mandate(None).await;
+
+        let updated_mandate = backfill_connector_mandate_id(
+            &db,
+            MERCHANT_ID,
+            MANDATE_ID,
+            get_webhook_mandate_reference("8415736344864224"),
+            MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap();
+        assert!(updated_mandate.is_some());
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                MERCHANT_ID,
+                MANDATE_ID,
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            mandate.connector_mandate_id.as_deref(),
+            Some("8415736344864224")
+        );
+        assert_eq!(mandate.payment_method_id, "pm_backfill");
+        assert_eq!(mandate.original_payment_id.as_deref(), Some("pay_backfill"));
+        assert!(mandate.connector_mandate_ids.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_does_not_overwrite_existing_mandate_reference() {
+        let db = get_db_with_mandate(Some("8415736344864224".to_string())).await;
+
+        for mandate_reference in [
+            get_webhook_mandate_reference("9915736344864224"),
+            types::MandateReference {
+                connector_mandate_id: None,
+                payment_method_id: None,
+            },
+        ] {
+            let updated_mandate = backfill_connector_mandate_id(
+                &db,
+                MERCHANT_ID,
+                MANDATE_ID,
+                mandate_reference,
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+            assert!(updated_mandate.is_none());
+        }
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                MERCHANT_ID,
+                MANDATE_ID,
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            mandate.connector_mandate_id.as_deref(),
+            Some("8415736344864224")
+        );
+    }
+}
@@ -85,3 +85,310 @@ pub struct MandateGenericData {
     pub mandate_connector: Option<payments::MandateConnectorDetails>,
     pub payment_method_info: Option<diesel_models::PaymentMethod>,
 }
+
+/// Only an active mandate can be paused, a paused mandate rejects payments until it is resumed
+pub fn get_paused_mandate_status(
+    mandate_status: enums::MandateStatus,
+) -> CustomResult<enums::MandateStatus, errors::ApiErrorResponse> {
+    match mandate_status {
+        enums::MandateStatus::Active => Ok(enums::MandateStatus::Paused),
+        enums::MandateStatus::Paused => Err(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "Mandate is already paused".to_string(),
+        }
+        .into()),
+        enums::MandateStatus::Inactive
+        | enums::MandateStatus::Pending
+        | enums::MandateStatus::Revoked => Err(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: format!("Mandate with status {mandate_status} cannot be paused"),
+        }
+        .into()),
+    }
+}
+
+pub fn get_resumed_mandate_status(
+    mandate_status: enums::MandateStatus,
+) -> CustomResult<enums::MandateStatus, errors::ApiErrorResponse> {
+    match mandate_status {
+        enums::MandateStatus::Paused => Ok(enums::MandateStatus::Active),
+        enums::MandateStatus::Active
+        | enums::MandateStatus::Inactive
+        | enums::MandateStatus::Pending
+        | enums::MandateStatus::Revoked => Err(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: format!("Mandate with status {mandate_status} is not paused"),
+        }
+        .into()),
+    }
+}
+
+pub fn validate_mandate_status_for_payment(
+    mandate_status: enums::MandateStatus,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    match mandate_status {
+        enums::MandateStatus::Active => Ok(()),
+        enums::MandateStatus::Paused => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "mandate is paused, resume the mandate to make payments with it".into(),
+        }
+        .into()),
+        enums::MandateStatus::Inactive
+        | enums::MandateStatus::Pending
+        | enums::MandateStatus::Revoked => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "mandate is not active".into(),
+        }
+        .into()),
+    }
+}
+
+/// Usage limits are enforced only on mandates created with a maximum total amount or debit count
+pub fn has_mandate_usage_limits(mandate: &Mandate) -> bool {
+    mandate.max_total_amount.is_some() || mandate.max_debit_count.is_some()
+}
+
+pub fn validate_mandate_usage_limits(
+    mandate: &Mandate,
+    amount: i64,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if mandate
+        .max_debit_count
+        .is_some_and(|max_debit_count| mandate.debit_count >= max_debit_count)
+    {
+        return Err(errors::ApiErrorResponse::MandateLimitExceeded {
+            reason: "maximum number of debits for the mandate has been reached".to_string(),
+        }
+        .into());
+    }
+
+    if let Some(max_total_amount) = mandate.max_total_amount {
+        let remaining_amount = max_total_amount.saturating_sub(mandate.debited_amount);
+        if amount > remaining_amount {
+            return Err(errors::ApiErrorResponse::MandateLimitExceeded {
+                reason: format!(
+                    "amount exceeds the remaining amount of {remaining_amount} that can be debited with the mandate"
+                ),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the debit count and debited amount of the mandate after reserving a debit of `amount`
+pub fn get_reserved_mandate_debit_usage(
+    mandate: &Mandate,
+    amount: i64,
+) -> CustomResult<(i32, i64), errors::ApiErrorResponse> {
+    validate_mandate_usage_limits(mandate, amount)?;
+    Ok((
+        mandate.debit_count.saturating_add(1),
+        mandate.debited_amount.saturating_add(amount),
+    ))
+}
+
+/// Returns the debit count and debited amount of the mandate after releasing a debit of `amount`
+/// that was reserved for a failed payment
+pub fn get_released_mandate_debit_usage(mandate: &Mandate, amount: i64) -> (i32, i64) {
+    (
+        mandate.debit_count.saturating_sub(1).max(0),
+        mandate.debited_amount.saturating_sub(amount).max(0),
+    )
+}
+
+/// Filters the mandates of a customer by status and paginates them, latest mandates first
+pub fn filter_customer_mandates(
+    mut mandates: Vec<Mandate>,
+    constraints: &api::mandates::CustomerMandateListConstraints,
+) -> Vec<Mandate> {
+    mandates.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let offset = constraints
+        .offset
+        .and_then(|offset| usize::try_from(offset).ok())
+        .unwrap_or(0);
+    let limit = constraints
+        .limit
+        .and_then(|limit| usize::try_from(limit).ok())
+        .unwrap_or(usize::MAX);
+
+    mandates
+        .into_iter()
+        .filter(|mandate| {
+            constraints
+                .status
+                .map_or(true, |status| mandate.mandate_status == status)
+        })
+        .skip(offset)
+        .take(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_pause_active_mandate() {
+        assert_eq!(
+            get_paused_mandate_status(enums::MandateStatus::Active).unwrap(),
+            enums::MandateStatus::Paused
+        );
+        assert!(get_paused_mandate_status(enums::MandateStatus::Paused).is_err());
+        assert!(get_paused_mandate_status(enums::MandateStatus::Revoked).is_err());
+    }
+
+    #[test]
+    fn test_resume_paused_mandate() {
+        assert_eq!(
+            get_resumed_mandate_status(enums::MandateStatus::Paused).unwrap(),
+            enums::MandateStatus::Active
+        );
+        assert!(get_resumed_mandate_status(enums::MandateStatus::Active).is_err());
+    }
+
+    #[test]
+    fn test_payment_with_paused_mandate_is_rejected() {
+        assert!(validate_mandate_status_for_payment(enums::MandateStatus::Active).is_ok());
+        assert!(matches!(
+            validate_mandate_status_for_payment(enums::MandateStatus::Paused)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { message }
+                if message.contains("paused")
+        ));
+    }
+
+    fn get_mandate(
+        max_total_amount: Option<i64>,
+        max_debit_count: Option<i32>,
+        debit_count: i32,
+        debited_amount: i64,
+    ) -> Mandate {
+        Mandate {
+            debit_count,
+            debited_amount,
+            ..Mandate::from(&diesel_models::MandateNew {
+                max_total_amount,
+                max_debit_count,
+                ..Default::default()
+            })
+        }
+    }
+
+    #[test]
+    fn test_mandate_amount_cap() {
+        let mandate = get_mandate(Some(1000), None, 2, 600);
+        assert!(has_mandate_usage_limits(&mandate));
+        assert_eq!(
+            get_reserved_mandate_debit_usage(&mandate, 400).unwrap(),
+            (3, 1000)
+        );
+        assert!(matches!(
+            get_reserved_mandate_debit_usage(&mandate, 401)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::MandateLimitExceeded { .. }
+        ));
+
+        // A debit released for a failed payment can be made again
+        let mandate = get_mandate(Some(1000), None, 3, 1000);
+        assert!(validate_mandate_usage_limits(&mandate, 400).is_err());
+        let (debit_count, debited_amount) = get_released_mandate_debit_usage(&mandate, 400);
+        let mandate = get_mandate(Some(1000), None, debit_count, debited_amount);
+        assert!(validate_mandate_usage_limits(&mandate, 400).is_ok());
+    }
+
+    #[test]
+    fn test_mandate_count_cap() {
+        let mandate = get_mandate(None, Some(2), 1, 5000);
+        let (debit_count, debited_amount) =
+            get_reserved_mandate_debit_usage(&mandate, 5000).unwrap();
+        assert_eq!((debit_count, debited_amount), (2, 10000));
+
+        // The last debit has been reserved, so the next payment is rejected
+        let mandate = get_mandate(None, Some(2), debit_count, debited_amount);
+        assert!(matches!(
+            get_reserved_mandate_debit_usage(&mandate, 1)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::MandateLimitExceeded { .. }
+        ));
+    }
+
+    fn get_customer_mandates() -> Vec<Mandate> {
+        let now = common_utils::date_time::now();
+        [
+            ("mandate_1", enums::MandateStatus::Active, 3),
+            ("mandate_2", enums::MandateStatus::Revoked, 2),
+            ("mandate_3", enums::MandateStatus::Active, 1),
+            ("mandate_4", enums::MandateStatus::Paused, 0),
+        ]
+        .into_iter()
+        .map(|(mandate_id, mandate_status, days_ago)| {
+            Mandate::from(&diesel_models::MandateNew {
+                mandate_id: mandate_id.to_string(),
+                mandate_status,
+                created_at: Some(now - time::Duration::days(days_ago)),
+                ..Default::default()
+            })
+        })
+        .collect()
+    }
+
+    fn get_mandate_ids(mandates: &[Mandate]) -> Vec<&str> {
+        mandates
+            .iter()
+            .map(|mandate| mandate.mandate_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_customer_mandates_all_statuses() {
+        let mandates = filter_customer_mandates(
+            get_customer_mandates(),
+            &api::mandates::CustomerMandateListConstraints::default(),
+        );
+        assert_eq!(
+            get_mandate_ids(&mandates),
+            vec!["mandate_4", "mandate_3", "mandate_2", "mandate_1"]
+        );
+
+        let mandates = filter_customer_mandates(
+            get_customer_mandates(),
+            &api::mandates::CustomerMandateListConstraints {
+                status: None,
+                limit: Some(2),
+                offset: Some(1),
+            },
+        );
+        assert_eq!(get_mandate_ids(&mandates), vec!["mandate_3", "mandate_2"]);
+    }
+
+    #[test]
+    fn test_filter_customer_mandates_active_only() {
+        let mandates = filter_customer_mandates(
+            get_customer_mandates(),
+            &api::mandates::CustomerMandateListConstraints {
+                status: Some(enums::MandateStatus::Active),
+                ..Default::default()
+            },
+        );
+        assert_eq!(get_mandate_ids(&mandates), vec!["mandate_3", "mandate_1"]);
+
+        let mandates = filter_customer_mandates(
+            get_customer_mandates(),
+            &api::mandates::CustomerMandateListConstraints {
+                status: Some(enums::MandateStatus::Active),
+                limit: None,
+                offset: Some(2),
+            },
+        );
+        assert!(mandates.is_empty());
+    }
+
+    #[test]
+    fn test_mandate_without_usage_limits() {
+        let mandate = get_mandate(None, None, 100, 1_000_000);
+        assert!(!has_mandate_usage_limits(&mandate));
+        assert!(validate_mandate_usage_limits(&mandate, i64::MAX).is_ok());
+    }
+}
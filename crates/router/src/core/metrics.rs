@@ -32,10 +32,13 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_AUTO_ACCEPTED_METRIC, GLOBAL_METER); // No. of disputes accepted automatically as they were within the auto accept threshold of the business profile
+counter_metric!(DISPUTE_AUTO_ACCEPT_FAILURE_METRIC, GLOBAL_METER); // No. of disputes within the auto accept threshold which could not be accepted with the connector
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_REPLAY_REJECTED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
@@ -0,0 +1,232 @@
+use std::collections::{HashSet, VecDeque};
+
+use api_models::organization as organization_api;
+use diesel_models::organization as storage;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::transformers::ForeignFrom,
+};
+
+#[instrument(skip_all)]
+pub async fn update_parent_organization(
+    state: AppState,
+    req: organization_api::OrganizationParentUpdateRequest,
+) -> RouterResponse<organization_api::OrganizationResponse> {
+    let organization = set_parent_organization(
+        state.store.as_ref(),
+        &req.org_id,
+        req.parent_organization_id.as_deref(),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(ForeignFrom::foreign_from(
+        organization,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_organization_descendants(
+    state: AppState,
+    req: organization_api::OrganizationId,
+) -> RouterResponse<Vec<organization_api::OrganizationResponse>> {
+    let db = state.store.as_ref();
+    find_organization(db, &req.org_id).await?;
+
+    let descendants = get_organization_descendants(db, &req.org_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        descendants
+            .into_iter()
+            .map(ForeignFrom::foreign_from)
+            .collect(),
+    ))
+}
+
+async fn find_organization(
+    db: &dyn StorageInterface,
+    org_id: &str,
+) -> RouterResult<storage::Organization> {
+    db.find_organization_by_org_id(org_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("organization with id {org_id} does not exist"),
+        })
+}
+
+async fn set_parent_organization(
+    db: &dyn StorageInterface,
+    org_id: &str,
+    parent_organization_id: Option<&str>,
+) -> RouterResult<storage::Organization> {
+    find_organization(db, org_id).await?;
+    if let Some(parent_organization_id) = parent_organization_id {
+        find_organization(db, parent_organization_id).await?;
+    }
+
+    db.update_parent_organization_by_org_id(org_id, parent_organization_id.map(str::to_string))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("organization with id {org_id} does not exist"),
+        })?
+        // The organization must not be the new parent itself or one of its ancestors, as the
+        // parent would then end up being its own descendant
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "organization {} cannot be the parent of organization {org_id} as it would \
+                     create a cycle",
+                    parent_organization_id.unwrap_or_default()
+                ),
+            })
+        })
+}
+
+/// Returns all the organizations below the given organization in the tree, closest ones first.
+async fn get_organization_descendants(
+    db: &dyn StorageInterface,
+    org_id: &str,
+) -> RouterResult<Vec<storage::Organization>> {
+    let mut visited = HashSet::from([org_id.to_string()]);
+    let mut pending = VecDeque::from([org_id.to_string()]);
+    let mut descendants = Vec::new();
+
+    while let Some(parent_organization_id) = pending.pop_front() {
+        let children = db
+            .list_organizations_by_parent_organization_id(&parent_organization_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list sub-organizations")?;
+
+        for child in children {
+            if visited.insert(child.org_id.clone()) {
+                pending.push_back(child.org_id.clone());
+                descendants.push(child);
+            }
+        }
+    }
+
+    Ok(descendants)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::organization::OrganizationInterface;
+
+    async fn insert_organization(db: &MockDb, org_id: &str) {
+        db.insert_organization(storage::OrganizationNew {
+            org_id: org_id.to_string(),
+            org_name: None,
+            parent_organization_id: None,
+        })
+        .await
+        .unwrap();
+    }
+
+    async fn get_mock_db(org_ids: &[&str]) -> MockDb {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        for org_id in org_ids {
+            insert_organization(&db, org_id).await;
+        }
+        db
+    }
+
+    #[tokio::test]
+    async fn test_assigning_parent_organization() {
+        let db = get_mock_db(&["org_parent", "org_child"]).await;
+
+        let organization = set_parent_organization(&db, "org_child", Some("org_parent"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            organization.parent_organization_id.as_deref(),
+            Some("org_parent")
+        );
+        assert_eq!(
+            db.find_organization_by_org_id("org_child")
+                .await
+                .unwrap()
+                .parent_organization_id
+                .as_deref(),
+            Some("org_parent")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_listing_organization_descendants() {
+        let db = get_mock_db(&["org_root", "org_a", "org_b", "org_a_1", "org_other"]).await;
+        set_parent_organization(&db, "org_a", Some("org_root"))
+            .await
+            .unwrap();
+        set_parent_organization(&db, "org_b", Some("org_root"))
+            .await
+            .unwrap();
+        set_parent_organization(&db, "org_a_1", Some("org_a"))
+            .await
+            .unwrap();
+
+        let descendants = get_organization_descendants(&db, "org_root")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|organization| organization.org_id)
+            .collect::<Vec<_>>();
+        assert_eq!(descendants, vec!["org_a", "org_b", "org_a_1"]);
+
+        let descendants = get_organization_descendants(&db, "org_a_1").await.unwrap();
+        assert!(descendants.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_setting_parent_organization_rejects_cycles() {
+        let db = get_mock_db(&["org_root", "org_a", "org_a_1"]).await;
+        set_parent_organization(&db, "org_a", Some("org_root"))
+            .await
+            .unwrap();
+        set_parent_organization(&db, "org_a_1", Some("org_a"))
+            .await
+            .unwrap();
+
+        assert!(set_parent_organization(&db, "org_root", Some("org_a_1"))
+            .await
+            .is_err());
+        assert!(set_parent_organization(&db, "org_a", Some("org_a"))
+            .await
+            .is_err());
+        assert_eq!(
+            db.find_organization_by_org_id("org_root")
+                .await
+                .unwrap()
+                .parent_organization_id,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detaching_organization_from_parent() {
+        let db = get_mock_db(&["org_root", "org_a"]).await;
+        set_parent_organization(&db, "org_a", Some("org_root"))
+            .await
+            .unwrap();
+
+        let organization = set_parent_organization(&db, "org_a", None).await.unwrap();
+
+        assert_eq!(organization.parent_organization_id, None);
+        assert!(get_organization_descendants(&db, "org_root")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
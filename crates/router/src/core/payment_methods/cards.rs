@@ -49,7 +49,9 @@ use crate::{
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
+            CallConnectorAction,
         },
+        routing::helpers as routing_helpers,
         utils as core_utils,
     },
     db, logger,
@@ -61,6 +63,7 @@ use crate::{
     },
     services,
     types::{
+        self,
         api::{self, routing as routing_types, PaymentMethodCreateExt},
         domain::{
             self,
@@ -140,6 +143,7 @@ pub async fn create_payment_method(
                 last_modified: current_time,
                 last_used_at: current_time,
                 payment_method_billing_address,
+                display_order: None,
             },
             storage_scheme,
         )
@@ -1877,6 +1881,22 @@ pub async fn list_payment_methods(
     // filter out connectors based on the business country
     let filtered_mcas = helpers::filter_mca_based_on_business_profile(all_mcas, profile_id);
 
+    // filter out connectors that the payment will not be routed to
+    let filtered_mcas = match get_effective_connectors(
+        &state,
+        &merchant_account,
+        business_profile.as_ref(),
+        payment_attempt.as_ref(),
+    )
+    .await
+    {
+        Some(effective_connectors) => filtered_mcas
+            .into_iter()
+            .filter(|mca| effective_connectors.contains(&mca.connector_name))
+            .collect(),
+        None => filtered_mcas,
+    };
+
     logger::debug!(mca_before_filtering=?filtered_mcas);
 
     let mut response: Vec<ResponsePaymentMethodIntermediate> = vec![];
@@ -2605,6 +2625,8 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_total_amount: i.max_total_amount,
+                            max_debit_count: i.max_debit_count,
                         })
                     }
                     hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -2614,6 +2636,8 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_total_amount: i.max_total_amount,
+                            max_debit_count: i.max_debit_count,
                         }))
                     }
                     hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None) => {
@@ -2754,6 +2778,114 @@ pub async fn call_surcharge_decision_management_for_saved_card(
     Ok(())
 }
 
+/// Returns the connectors that the payment can be routed to, if the payment is restricted to a
+/// set of connectors. The straight through algorithm of the payment attempt takes precedence over
+/// the active routing algorithm of the profile (or merchant), and the connectors of the fallback
+/// config are included as routing falls back to them. Payment methods of other connectors cannot
+/// be used for the payment. Customer payment method lists, without a payment, are not restricted.
+async fn get_effective_connectors(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: Option<&storage::business_profile::BusinessProfile>,
+    payment_attempt: Option<&storage::PaymentAttempt>,
+) -> Option<HashSet<String>> {
+    let payment_attempt = payment_attempt?;
+
+    let straight_through_algorithm = payment_attempt
+        .straight_through_algorithm
+        .clone()
+        .and_then(|algorithm| {
+            algorithm
+                .parse_value::<storage::PaymentRoutingInfo>("PaymentRoutingInfo")
+                .map_err(|error| logger::error!(?error, "Failed to parse PaymentRoutingInfo"))
+                .ok()
+        })
+        .and_then(|routing_info| routing_info.algorithm);
+
+    let routed_connectors = match straight_through_algorithm {
+        Some(algorithm) => get_connectors_from_straight_through_algorithm(&algorithm),
+        None => {
+            let routing_algorithm = if cfg!(feature = "business_profile_routing") {
+                business_profile?.routing_algorithm.clone()
+            } else {
+                merchant_account.routing_algorithm.clone()
+            };
+            let algorithm_ref = routing_algorithm?
+                .parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+                .map_err(|error| logger::error!(?error, "Failed to parse RoutingAlgorithmRef"))
+                .ok()?;
+
+            routing::get_connectors_from_algorithm_ref_v1(
+                state,
+                &merchant_account.merchant_id,
+                algorithm_ref,
+                #[cfg(feature = "business_profile_routing")]
+                business_profile.map(|profile| profile.profile_id.clone()),
+                &api_enums::TransactionType::Payment,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to resolve the active routing algorithm")
+            })
+            .ok()
+            .flatten()?
+            .into_iter()
+            .map(|choice| choice.connector.to_string())
+            .collect()
+        }
+    };
+
+    #[cfg(feature = "profile_specific_fallback_routing")]
+    let fallback_config_id = &business_profile?.profile_id;
+    #[cfg(not(feature = "profile_specific_fallback_routing"))]
+    let fallback_config_id = &merchant_account.merchant_id;
+
+    let fallback_config = routing_helpers::get_merchant_default_config(
+        &*state.store,
+        fallback_config_id,
+        &api_enums::TransactionType::Payment,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch the fallback routing config"))
+    .ok()?;
+
+    Some(include_fallback_connectors(
+        routed_connectors,
+        &fallback_config,
+    ))
+}
+
+fn include_fallback_connectors(
+    mut connectors: HashSet<String>,
+    fallback_config: &[routing_types::RoutableConnectorChoice],
+) -> HashSet<String> {
+    connectors.extend(
+        fallback_config
+            .iter()
+            .map(|choice| choice.connector.to_string()),
+    );
+    connectors
+}
+
+fn get_connectors_from_straight_through_algorithm(
+    algorithm: &routing_types::StraightThroughAlgorithm,
+) -> HashSet<String> {
+    match algorithm {
+        routing_types::StraightThroughAlgorithm::Single(choice) => {
+            HashSet::from([choice.connector.to_string()])
+        }
+        routing_types::StraightThroughAlgorithm::Priority(choices) => choices
+            .iter()
+            .map(|choice| choice.connector.to_string())
+            .collect(),
+        routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => splits
+            .iter()
+            .filter(|split| split.split > 0)
+            .map(|split| split.connector.connector.to_string())
+            .collect(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn filter_payment_methods(
     payment_methods: Vec<serde_json::Value>,
@@ -2840,7 +2972,7 @@ pub async fn filter_payment_methods(
                         payment_attempt,
                         &mut payment_method_object.card_networks,
                         &address.and_then(|inner| inner.country),
-                        payment_attempt.and_then(|value| value.currency),
+                        get_payment_currency(payment_attempt, payment_intent),
                     );
 
                     let filter6 = filter_pm_based_on_allowed_types(
@@ -2973,6 +3105,16 @@ fn filter_pm_based_on_supported_payments_for_mandate(
         .unwrap_or(false)
 }
 
+/// The currency of the attempt, or of the intent when the attempt does not carry it yet
+fn get_payment_currency(
+    payment_attempt: Option<&storage::PaymentAttempt>,
+    payment_intent: Option<&storage::PaymentIntent>,
+) -> Option<api_enums::Currency> {
+    payment_attempt
+        .and_then(|payment_attempt| payment_attempt.currency)
+        .or_else(|| payment_intent.and_then(|payment_intent| payment_intent.currency))
+}
+
 fn filter_pm_based_on_config<'a>(
     config: &'a settings::ConnectorFilters,
     connector: &'a str,
@@ -3404,7 +3546,7 @@ pub async fn list_customer_payment_method(
 
     let requires_cvv = is_requires_cvv.config != "false";
 
-    let resp = db
+    let mut resp = db
         .find_payment_method_by_customer_id_merchant_id_status(
             customer_id,
             &merchant_account.merchant_id,
@@ -3414,6 +3556,7 @@ pub async fn list_customer_payment_method(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    sort_by_display_order(&mut resp, |pm| pm.display_order);
     //let mca = query::find_mca_by_merchant_id(conn, &merchant_account.merchant_id)?;
     let mut customer_pms = Vec::new();
     for pm in resp.into_iter() {
@@ -3546,6 +3689,7 @@ pub async fn list_customer_payment_method(
             default_payment_method_set: customer.default_payment_method_id.is_some()
                 && customer.default_payment_method_id == Some(pm.payment_method_id),
             billing: payment_method_billing,
+            display_order: pm.display_order,
         };
         customer_pms.push(pma.to_owned());
 
@@ -3944,6 +4088,527 @@ pub async fn set_default_payment_method(
     Ok(services::ApplicationResponse::Json(resp))
 }
 
+/// Orders the payment methods by the display order set by the merchant. Payment methods
+/// without a display order are placed after them, retaining their existing order.
+fn sort_by_display_order<T>(payment_methods: &mut [T], display_order: impl Fn(&T) -> Option<i32>) {
+    payment_methods.sort_by_key(|pm| {
+        let display_order = display_order(pm);
+        (display_order.is_none(), display_order)
+    });
+}
+
+/// Places the requested payment methods first, in the requested order, followed by the rest of the
+/// customer's payment methods in their current display order, so that every payment method gets
+/// a distinct position. Returns the first requested id which is not one of the customer's payment
+/// methods, if any.
+fn get_reordered_payment_methods<T>(
+    mut payment_methods: Vec<T>,
+    payment_method_ids: &[String],
+    payment_method_id: impl Fn(&T) -> &str,
+    display_order: impl Fn(&T) -> Option<i32>,
+) -> Result<Vec<T>, String> {
+    let mut reordered_payment_methods = Vec::with_capacity(payment_methods.len());
+    for requested_payment_method_id in payment_method_ids {
+        let position = payment_methods
+            .iter()
+            .position(|pm| payment_method_id(pm) == requested_payment_method_id)
+            .ok_or_else(|| requested_payment_method_id.clone())?;
+        reordered_payment_methods.push(payment_methods.remove(position));
+    }
+
+    sort_by_display_order(&mut payment_methods, display_order);
+    reordered_payment_methods.extend(payment_methods);
+    Ok(reordered_payment_methods)
+}
+
+fn validate_payment_methods_reorder_request(
+    req: &api::CustomerPaymentMethodsReorderRequest,
+) -> errors::RouterResult<()> {
+    utils::when(req.payment_method_ids.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment_method_ids must not be empty".to_string(),
+        })
+    })?;
+
+    let unique_payment_method_ids = req.payment_method_ids.iter().collect::<HashSet<_>>();
+    utils::when(
+        unique_payment_method_ids.len() != req.payment_method_ids.len(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment_method_ids must not contain duplicates".to_string(),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn reorder_customer_payment_methods(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::CustomerPaymentMethodsReorderRequest,
+) -> errors::RouterResponse<api::CustomerPaymentMethodsReorderResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    validate_payment_methods_reorder_request(&req)?;
+
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let customer_payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_status(
+            &req.customer_id,
+            &merchant_account.merchant_id,
+            common_enums::PaymentMethodStatus::Active,
+            None,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let payment_methods = get_reordered_payment_methods(
+        customer_payment_methods,
+        &req.payment_method_ids,
+        |pm| &pm.payment_method_id,
+        |pm| pm.display_order,
+    )
+    .map_err(
+        |payment_method_id| errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("The payment_method_id {payment_method_id} is not valid"),
+        },
+    )?
+    .into_iter()
+    .enumerate()
+    .map(|(display_order, payment_method)| {
+        i32::try_from(display_order)
+            .map(|display_order| (payment_method, display_order))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert display order of payment method")
+    })
+    .collect::<errors::RouterResult<Vec<_>>>()?;
+
+    let updated_payment_methods = db
+        .update_payment_methods_display_order(payment_methods)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the display order of payment methods")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::CustomerPaymentMethodsReorderResponse {
+            customer_id: req.customer_id,
+            payment_method_ids: updated_payment_methods
+                .into_iter()
+                .map(|payment_method| payment_method.payment_method_id)
+                .collect(),
+        },
+    ))
+}
+
+/// Determines why a saved payment method cannot be used for payments, if at all
+fn get_payment_method_unusable_reason(
+    status: enums::PaymentMethodStatus,
+    card_expiry: Option<(&Secret<String>, &Secret<String>)>,
+    connector_mandate_details: Option<&storage::PaymentsMandateReference>,
+    available_connector_ids: &HashSet<String>,
+) -> Option<api::PaymentMethodUnusableReason> {
+    if status != enums::PaymentMethodStatus::Active {
+        return Some(api::PaymentMethodUnusableReason::TokenInactive);
+    }
+
+    if card_expiry.is_some_and(|(expiry_month, expiry_year)| {
+        helpers::validate_card_expiry(expiry_month, expiry_year).is_err()
+    }) {
+        return Some(api::PaymentMethodUnusableReason::CardExpired);
+    }
+
+    // Payment methods saved with connectors are usable only if one of those connectors is
+    // still enabled for the merchant
+    if connector_mandate_details.is_some_and(|mandate_reference| {
+        !mandate_reference.is_empty()
+            && !mandate_reference.keys().any(|merchant_connector_id| {
+                available_connector_ids.contains(merchant_connector_id)
+            })
+    }) {
+        return Some(api::PaymentMethodUnusableReason::ConnectorUnavailable);
+    }
+
+    None
+}
+
+#[instrument(skip_all)]
+pub async fn probe_payment_methods(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+) -> errors::RouterResponse<api::CustomerPaymentMethodsProbeResponse> {
+    let db = &*state.store;
+    let key = key_store.key.get_inner().peek();
+
+    db.find_customer_by_customer_id_merchant_id(
+        &customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mut saved_payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &customer_id,
+            &merchant_account.merchant_id,
+            None,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    sort_by_display_order(&mut saved_payment_methods, |pm| pm.display_order);
+
+    let available_connector_ids = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .into_iter()
+        .map(|mca| mca.merchant_connector_id)
+        .collect::<HashSet<_>>();
+
+    let mut payment_methods = Vec::with_capacity(saved_payment_methods.len());
+    for pm in saved_payment_methods {
+        let card_details = if pm.payment_method == Some(enums::PaymentMethod::Card) {
+            decrypt::<serde_json::Value, masking::WithType>(pm.payment_method_data.clone(), key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to decrypt card details")?
+                .map(|value| value.into_inner().expose())
+                .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+                .and_then(|pmd| match pmd {
+                    PaymentMethodsData::Card(card) => Some(card),
+                    _ => None,
+                })
+        } else {
+            None
+        };
+
+        let connector_mandate_details = pm
+            .connector_mandate_details
+            .clone()
+            .map(|details| {
+                details
+                    .parse_value::<storage::PaymentsMandateReference>("connector_mandate_details")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize connector mandate details")?;
+
+        let unusable_reason = get_payment_method_unusable_reason(
+            pm.status,
+            card_details
+                .as_ref()
+                .and_then(|card| card.expiry_month.as_ref().zip(card.expiry_year.as_ref())),
+            connector_mandate_details.as_ref(),
+            &available_connector_ids,
+        );
+
+        payment_methods.push(api::PaymentMethodUsability {
+            payment_method_id: pm.payment_method_id,
+            payment_method: pm.payment_method,
+            payment_method_type: pm.payment_method_type,
+            is_usable: unusable_reason.is_none(),
+            unusable_reason,
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api::CustomerPaymentMethodsProbeResponse {
+            customer_id,
+            payment_methods,
+        },
+    ))
+}
+
+/// Validates the external token with the connector it was issued by
+fn validate_external_token_ref<C: services::ConnectorValidation + ?Sized>(
+    connector: &C,
+    external_token_ref: &str,
+) -> errors::RouterResult<()> {
+    connector
+        .validate_connector_payment_method_token(external_token_ref)
+        .map_err(|error| {
+            let api_error = match error.current_context() {
+                errors::ConnectorError::NotSupported { message, connector } => {
+                    errors::ApiErrorResponse::FlowNotSupported {
+                        flow: message.to_owned(),
+                        connector: connector.to_string(),
+                    }
+                }
+                _ => errors::ApiErrorResponse::InvalidRequestData {
+                    message: "external_token_ref is not a valid token for the connector"
+                        .to_string(),
+                },
+            };
+            error.change_context(api_error)
+        })
+}
+
+const IRRELEVANT_PAYMENT_ID_IN_PAYMENT_METHOD_IMPORT_FLOW: &str =
+    "irrelevant_payment_id_in_payment_method_import_flow";
+
+const IRRELEVANT_ATTEMPT_ID_IN_PAYMENT_METHOD_IMPORT_FLOW: &str =
+    "irrelevant_attempt_id_in_payment_method_import_flow";
+
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_PAYMENT_METHOD_IMPORT_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_payment_method_import_flow";
+
+fn construct_payment_method_retrieve_router_data(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &str,
+    external_token_ref: &str,
+) -> errors::RouterResult<types::PaymentMethodRetrieveRouterData> {
+    let auth_type: types::ConnectorAuthType =
+        helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account.clone())
+            .get_connector_account_details()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: Some(customer_id.to_string()),
+        connector_customer: None,
+        connector: merchant_connector_account.connector_name.clone(),
+        payment_id: IRRELEVANT_PAYMENT_ID_IN_PAYMENT_METHOD_IMPORT_FLOW.to_string(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_PAYMENT_METHOD_IMPORT_FLOW.to_string(),
+        status: storage_enums::AttemptStatus::default(),
+        payment_method: storage_enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: types::PaymentAddress::default(),
+        auth_type: storage_enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        payment_method_status: None,
+        request: types::PaymentMethodRetrieveRequestData {
+            connector_payment_method_id: external_token_ref.to_string(),
+        },
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        connector_request_reference_id:
+            IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_PAYMENT_METHOD_IMPORT_FLOW.to_string(),
+        test_mode: merchant_connector_account.test_mode,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+    })
+}
+
+/// Retrieves the external token from the connector to make sure it exists before importing it
+async fn verify_external_token_ref_with_connector(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    connector_data: &api::ConnectorData,
+    customer_id: &str,
+    external_token_ref: &str,
+) -> errors::RouterResult<()> {
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PaymentMethodRetrieve,
+        types::PaymentMethodRetrieveRequestData,
+        types::PaymentMethodRetrieveResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = construct_payment_method_retrieve_router_data(
+        merchant_connector_account,
+        merchant_account,
+        customer_id,
+        external_token_ref,
+    )?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to retrieve the payment method from the connector")?;
+
+    match response.response {
+        Ok(_) => Ok(()),
+        Err(err) => Err(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: merchant_connector_account.connector_name.clone(),
+            status_code: err.status_code,
+            reason: err.reason,
+        }
+        .into()),
+    }
+}
+
+fn get_imported_payment_method_mandate_reference(
+    merchant_connector_id: &str,
+    external_token_ref: &str,
+    payment_method_type: Option<enums::PaymentMethodType>,
+) -> storage::PaymentsMandateReference {
+    storage::PaymentsMandateReference(HashMap::from([(
+        merchant_connector_id.to_string(),
+        storage::PaymentsMandateReferenceRecord {
+            connector_mandate_id: external_token_ref.to_string(),
+            payment_method_type,
+            original_payment_authorized_amount: None,
+            original_payment_authorized_currency: None,
+        },
+    )]))
+}
+
+#[instrument(skip_all)]
+pub async fn import_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentMethodImportRequest,
+) -> errors::RouterResponse<api::PaymentMethodResponse> {
+    let db = &*state.store;
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_account.merchant_id,
+            &req.merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: req.merchant_connector_id.clone(),
+        })?;
+
+    utils::when(merchant_connector_account.disabled.unwrap_or(false), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The merchant_connector_id {} is disabled",
+                req.merchant_connector_id
+            ),
+        })
+    })?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.merchant_connector_id.clone()),
+    )?;
+    validate_external_token_ref(&**connector_data.connector, &req.external_token_ref)?;
+    verify_external_token_ref_with_connector(
+        &state,
+        &merchant_account,
+        &merchant_connector_account,
+        &connector_data,
+        &req.customer_id,
+        &req.external_token_ref,
+    )
+    .await?;
+
+    // The payment method only references the token stored with the connector, no card details
+    // are stored in the locker
+    let connector_mandate_details = get_imported_payment_method_mandate_reference(
+        &merchant_connector_account.merchant_connector_id,
+        &req.external_token_ref,
+        req.payment_method_type,
+    )
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize connector mandate details")?;
+
+    let pm_create = api::PaymentMethodCreate {
+        payment_method: Some(req.payment_method),
+        payment_method_type: req.payment_method_type,
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: None,
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: req.metadata.clone(),
+        customer_id: Some(req.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+    };
+    let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+
+    let pm = create_payment_method(
+        db,
+        &pm_create,
+        &req.customer_id,
+        &payment_method_id,
+        None,
+        &merchant_account.merchant_id,
+        req.metadata.map(|metadata| metadata.expose()),
+        None,
+        None,
+        &key_store,
+        Some(connector_mandate_details),
+        None,
+        None,
+        merchant_account.storage_scheme,
+        None,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentMethodResponse {
+            merchant_id: pm.merchant_id,
+            customer_id: Some(pm.customer_id),
+            payment_method_id: pm.payment_method_id,
+            payment_method: pm.payment_method,
+            payment_method_type: pm.payment_method_type,
+            #[cfg(feature = "payouts")]
+            bank_transfer: None,
+            card: None,
+            metadata: pm.metadata,
+            created: Some(pm.created_at),
+            recurring_enabled: true,
+            installment_payment_enabled: false,
+            payment_experience: None,
+            last_used_at: Some(pm.last_used_at),
+            client_secret: pm.client_secret,
+        },
+    ))
+}
+
 pub async fn update_last_used_at(
     pm_id: &str,
     state: &routes::AppState,
@@ -4338,3 +5003,327 @@ pub async fn list_countries_currencies_for_connector_payment_method_util(
             .collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_payment_methods_reorder_request_validation() {
+        let req = api::CustomerPaymentMethodsReorderRequest {
+            customer_id: "cus_1".to_string(),
+            payment_method_ids: vec!["pm_2".to_string(), "pm_1".to_string()],
+        };
+        assert!(validate_payment_methods_reorder_request(&req).is_ok());
+
+        let duplicate_req = api::CustomerPaymentMethodsReorderRequest {
+            customer_id: "cus_1".to_string(),
+            payment_method_ids: vec!["pm_2".to_string(), "pm_2".to_string()],
+        };
+        assert!(validate_payment_methods_reorder_request(&duplicate_req).is_err());
+
+        let empty_req = api::CustomerPaymentMethodsReorderRequest {
+            customer_id: "cus_1".to_string(),
+            payment_method_ids: vec![],
+        };
+        assert!(validate_payment_methods_reorder_request(&empty_req).is_err());
+    }
+
+    #[test]
+    fn test_list_reflects_display_order() {
+        // listed by last used, with pm_3 and pm_1 reordered by the merchant
+        let mut payment_methods = vec![
+            ("pm_1", Some(1)),
+            ("pm_2", None),
+            ("pm_3", Some(0)),
+            ("pm_4", None),
+        ];
+        sort_by_display_order(&mut payment_methods, |pm| pm.1);
+        let payment_method_ids = payment_methods.iter().map(|pm| pm.0).collect::<Vec<_>>();
+        assert_eq!(payment_method_ids, vec!["pm_3", "pm_1", "pm_2", "pm_4"]);
+    }
+
+    #[test]
+    fn test_reorder_renumbers_payment_methods_not_in_request() {
+        let payment_methods = vec![
+            ("pm_1", Some(1)),
+            ("pm_2", None),
+            ("pm_3", Some(0)),
+            ("pm_4", Some(2)),
+        ];
+        let reordered = get_reordered_payment_methods(
+            payment_methods.clone(),
+            &["pm_2".to_string(), "pm_4".to_string()],
+            |pm| pm.0,
+            |pm| pm.1,
+        )
+        .unwrap();
+        let payment_method_ids = reordered.iter().map(|pm| pm.0).collect::<Vec<_>>();
+        assert_eq!(payment_method_ids, vec!["pm_2", "pm_4", "pm_3", "pm_1"]);
+
+        // Payment methods of other customers cannot be reordered
+        assert_eq!(
+            get_reordered_payment_methods(
+                payment_methods,
+                &["pm_2".to_string(), "pm_5".to_string()],
+                |pm| pm.0,
+                |pm| pm.1,
+            )
+            .unwrap_err(),
+            "pm_5"
+        );
+    }
+
+    fn get_connector_mandate_details(
+        merchant_connector_id: &str,
+    ) -> storage::PaymentsMandateReference {
+        storage::PaymentsMandateReference(HashMap::from([(
+            merchant_connector_id.to_string(),
+            storage::PaymentsMandateReferenceRecord {
+                connector_mandate_id: "mandate_1".to_string(),
+                payment_method_type: None,
+                original_payment_authorized_amount: None,
+                original_payment_authorized_currency: None,
+            },
+        )]))
+    }
+
+    #[test]
+    fn test_import_payment_method_with_valid_token() {
+        let external_token_ref = "pm_1OqQeL2eZvKYlo2C3dZg4Y4t";
+        assert!(validate_external_token_ref(&crate::connector::Stripe, external_token_ref).is_ok());
+
+        let mandate_reference = get_imported_payment_method_mandate_reference(
+            "mca_1",
+            external_token_ref,
+            Some(enums::PaymentMethodType::Credit),
+        );
+        let record = mandate_reference.get("mca_1").unwrap();
+        assert_eq!(record.connector_mandate_id, external_token_ref);
+        assert_eq!(
+            record.payment_method_type,
+            Some(enums::PaymentMethodType::Credit)
+        );
+    }
+
+    #[test]
+    fn test_import_payment_method_with_invalid_token() {
+        for external_token_ref in ["tok_123", "pm_", "pm_123/456", ""] {
+            let error = validate_external_token_ref(&crate::connector::Stripe, external_token_ref)
+                .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+
+        let error = validate_external_token_ref(&crate::connector::Adyen, "pm_123").unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FlowNotSupported { .. }
+        ));
+    }
+
+    #[test]
+    fn test_probe_usable_payment_method() {
+        let available_connector_ids = HashSet::from(["mca_1".to_string()]);
+        let expiry_month = Secret::new("12".to_string());
+        let expiry_year = Secret::new("2099".to_string());
+        let connector_mandate_details = get_connector_mandate_details("mca_1");
+
+        let unusable_reason = get_payment_method_unusable_reason(
+            enums::PaymentMethodStatus::Active,
+            Some((&expiry_month, &expiry_year)),
+            Some(&connector_mandate_details),
+            &available_connector_ids,
+        );
+        assert_eq!(unusable_reason, None);
+    }
+
+    #[test]
+    fn test_probe_expired_card() {
+        let expiry_month = Secret::new("01".to_string());
+        let expiry_year = Secret::new("2020".to_string());
+
+        let unusable_reason = get_payment_method_unusable_reason(
+            enums::PaymentMethodStatus::Active,
+            Some((&expiry_month, &expiry_year)),
+            None,
+            &HashSet::new(),
+        );
+        assert_eq!(
+            unusable_reason,
+            Some(api::PaymentMethodUnusableReason::CardExpired)
+        );
+    }
+
+    #[test]
+    fn test_probe_revoked_token() {
+        let expiry_month = Secret::new("12".to_string());
+        let expiry_year = Secret::new("2099".to_string());
+
+        let unusable_reason = get_payment_method_unusable_reason(
+            enums::PaymentMethodStatus::Inactive,
+            Some((&expiry_month, &expiry_year)),
+            None,
+            &HashSet::new(),
+        );
+        assert_eq!(
+            unusable_reason,
+            Some(api::PaymentMethodUnusableReason::TokenInactive)
+        );
+    }
+
+    #[test]
+    fn test_probe_unavailable_connector() {
+        let available_connector_ids = HashSet::from(["mca_2".to_string()]);
+        let connector_mandate_details = get_connector_mandate_details("mca_1");
+
+        let unusable_reason = get_payment_method_unusable_reason(
+            enums::PaymentMethodStatus::Active,
+            None,
+            Some(&connector_mandate_details),
+            &available_connector_ids,
+        );
+        assert_eq!(
+            unusable_reason,
+            Some(api::PaymentMethodUnusableReason::ConnectorUnavailable)
+        );
+    }
+
+    #[test]
+    fn test_pm_unsupported_for_currency_is_excluded() {
+        let config: settings::ConnectorFilters = serde_json::from_value(serde_json::json!({
+            "stripe": { "affirm": { "country": "US", "currency": "USD" } }
+        }))
+        .unwrap();
+        let is_listed = |currency| {
+            filter_pm_based_on_config(
+                &config,
+                "stripe",
+                &api_enums::PaymentMethodType::Affirm,
+                None,
+                &mut None,
+                &Some(api_enums::CountryAlpha2::US),
+                Some(currency),
+            )
+        };
+
+        assert!(is_listed(api_enums::Currency::USD));
+        assert!(!is_listed(api_enums::Currency::EUR));
+    }
+
+    #[test]
+    fn test_pm_list_is_restricted_to_straight_through_connectors() {
+        let algorithm: routing_types::StraightThroughAlgorithm =
+            serde_json::from_value(serde_json::json!({
+                "type": "volume_split",
+                "data": [
+                    { "connector": { "connector": "stripe" }, "split": 100 },
+                    { "connector": { "connector": "adyen" }, "split": 0 }
+                ]
+            }))
+            .unwrap();
+
+        assert_eq!(
+            get_connectors_from_straight_through_algorithm(&algorithm),
+            HashSet::from(["stripe".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_pm_list_includes_fallback_connectors() {
+        let fallback_config: Vec<routing_types::RoutableConnectorChoice> =
+            serde_json::from_value(serde_json::json!([
+                { "connector": "adyen" },
+                { "connector": "stripe" }
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            include_fallback_connectors(HashSet::from(["stripe".to_string()]), &fallback_config),
+            HashSet::from(["stripe".to_string(), "adyen".to_string()])
+        );
+    }
+
+    fn get_payment_intent(currency: Option<api_enums::Currency>) -> storage::PaymentIntent {
+        storage::PaymentIntent {
+            id: 1,
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            status: enums::IntentStatus::RequiresPaymentMethod,
+            amount: 1000,
+            currency,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                "pay_1_1".to_string(),
+            ),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+        }
+    }
+
+    #[test]
+    fn test_pm_list_falls_back_to_the_intent_currency() {
+        let config: settings::ConnectorFilters = serde_json::from_value(serde_json::json!({
+            "stripe": { "affirm": { "country": "US", "currency": "USD" } }
+        }))
+        .unwrap();
+        let is_listed = |payment_intent: &storage::PaymentIntent| {
+            filter_pm_based_on_config(
+                &config,
+                "stripe",
+                &api_enums::PaymentMethodType::Affirm,
+                None,
+                &mut None,
+                &Some(api_enums::CountryAlpha2::US),
+                // The attempt has not been created yet, the currency is read from the intent
+                get_payment_currency(None, Some(payment_intent)),
+            )
+        };
+
+        assert!(is_listed(&get_payment_intent(Some(
+            api_enums::Currency::USD
+        ))));
+        assert!(!is_listed(&get_payment_intent(Some(
+            api_enums::Currency::EUR
+        ))));
+        assert_eq!(
+            get_payment_currency(None, Some(&get_payment_intent(None))),
+            None
+        );
+    }
+}
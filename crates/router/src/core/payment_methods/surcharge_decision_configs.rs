@@ -1,15 +1,19 @@
-use std::sync::Arc;
+use std::{cmp::Reverse, collections::HashSet, str::FromStr, sync::Arc};
 
 use api_models::{
     payment_methods::SurchargeDetailsResponse,
     payments, routing,
     surcharge_decision_configs::{self, SurchargeDecisionConfigs, SurchargeDecisionManagerRecord},
 };
-use common_utils::{ext_traits::StringExt, static_cache::StaticCache, types as common_utils_types};
+use common_utils::{
+    consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH, ext_traits::StringExt,
+    static_cache::StaticCache, types as common_utils_types,
+};
 use error_stack::{self, ResultExt};
 use euclid::{
     backend,
     backend::{inputs as dsl_inputs, EuclidBackend},
+    frontend::{ast, dir::DirKeyKind},
 };
 use router_env::{instrument, tracing};
 
@@ -41,7 +45,21 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     type Error = error_stack::Report<ConfigError>;
 
     fn try_from(value: SurchargeDecisionManagerRecord) -> Result<Self, Self::Error> {
-        let cached_alogorith = backend::VirInterpreterBackend::with_program(value.algorithm)
+        let mut algorithm = value.algorithm;
+        // Rules are evaluated in the order in which they are listed, the first matching rule
+        // wins. Merchants can opt in to having the rules on both the card network and the card
+        // type take precedence over the rules on either of them, followed by the rest of the rules
+        if value
+            .merchant_surcharge_configs
+            .prioritize_card_specific_rules
+            .unwrap_or(false)
+        {
+            // The sort is stable, so rules of the same specificity keep their relative order
+            algorithm.rules.sort_by_key(|rule| {
+                Reverse(get_card_specificity(&rule.statements, &HashSet::new()))
+            });
+        }
+        let cached_alogorith = backend::VirInterpreterBackend::with_program(algorithm)
             .change_context(ConfigError::DslBackendInitError)
             .attach_printable("Error initializing DSL interpreter backend")?;
         let merchant_surcharge_configs = value.merchant_surcharge_configs;
@@ -52,6 +70,30 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     }
 }
 
+/// Returns the number of card keys, out of the card network and the card type, that the most
+/// specific branch of the statements matches on
+fn get_card_specificity(
+    statements: &[ast::IfStatement],
+    parent_card_keys: &HashSet<DirKeyKind>,
+) -> usize {
+    statements
+        .iter()
+        .map(|statement| {
+            let mut card_keys = parent_card_keys.clone();
+            card_keys.extend(statement.condition.iter().filter_map(|comparison| {
+                DirKeyKind::from_str(&comparison.lhs)
+                    .ok()
+                    .filter(|key| matches!(key, DirKeyKind::CardNetwork | DirKeyKind::CardType))
+            }));
+            match &statement.nested {
+                Some(nested_statements) => get_card_specificity(nested_statements, &card_keys),
+                None => card_keys.len(),
+            }
+        })
+        .max()
+        .unwrap_or(parent_card_keys.len())
+}
+
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
     Generate(Arc<VirInterpreterBackendCacheWrapper>),
@@ -346,17 +388,49 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     Ok(surcharge_metadata)
 }
 
+fn apply_surcharge_percentage(
+    percentage: &common_utils_types::Percentage<{ SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>,
+    amount: i64,
+) -> ConditionalConfigResult<i64> {
+    percentage
+        .apply_and_ceil_result(amount)
+        .change_context(ConfigError::DslExecutionError)
+        .attach_printable("Failed to Calculate surcharge amount by applying percentage")
+}
+
+/// Computes the surcharge on the amount in minor units. The percentage component is ceiled to the
+/// minor unit of the currency before the fixed component is added.
+fn get_surcharge_amount(
+    surcharge: &surcharge_decision_configs::SurchargeOutput,
+    amount: i64,
+) -> ConditionalConfigResult<i64> {
+    match surcharge {
+        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => Ok(*amount),
+        surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
+            apply_surcharge_percentage(percentage, amount)
+        }
+        surcharge_decision_configs::SurchargeOutput::RatePlusFixed {
+            percentage,
+            amount: fixed_amount,
+        } => apply_surcharge_percentage(percentage, amount)?
+            .checked_add(*fixed_amount)
+            .ok_or(ConfigError::DslExecutionError)
+            .attach_printable("Surcharge amount overflowed"),
+    }
+}
+
 fn get_surcharge_details_from_surcharge_output(
     surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     payment_attempt: &oss_storage::PaymentAttempt,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
-    let surcharge_amount = match surcharge_details.surcharge.clone() {
-        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => amount,
-        surcharge_decision_configs::SurchargeOutput::Rate(percentage) => percentage
-            .apply_and_ceil_result(payment_attempt.amount)
-            .change_context(ConfigError::DslExecutionError)
-            .attach_printable("Failed to Calculate surcharge amount by applying percentage")?,
-    };
+    get_surcharge_details_for_amount(surcharge_details, payment_attempt.amount)
+}
+
+fn get_surcharge_details_for_amount(
+    surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
+    original_amount: i64,
+) -> ConditionalConfigResult<types::SurchargeDetails> {
+    let surcharge_amount = get_surcharge_amount(&surcharge_details.surcharge, original_amount)?;
     let tax_on_surcharge_amount = surcharge_details
         .tax_on_surcharge
         .clone()
@@ -369,7 +443,7 @@ fn get_surcharge_details_from_surcharge_output(
         .transpose()?
         .unwrap_or(0);
     Ok(types::SurchargeDetails {
-        original_amount: payment_attempt.amount,
+        original_amount,
         surcharge: match surcharge_details.surcharge {
             surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
                 common_utils_types::Surcharge::Fixed(amount)
@@ -377,11 +451,14 @@ fn get_surcharge_details_from_surcharge_output(
             surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
                 common_utils_types::Surcharge::Rate(percentage)
             }
+            surcharge_decision_configs::SurchargeOutput::RatePlusFixed { percentage, amount } => {
+                common_utils_types::Surcharge::RatePlusFixed { percentage, amount }
+            }
         },
         tax_on_surcharge: surcharge_details.tax_on_surcharge,
         surcharge_amount,
         tax_on_surcharge_amount,
-        final_amount: payment_attempt.amount + surcharge_amount + tax_on_surcharge_amount,
+        final_amount: original_amount + surcharge_amount + tax_on_surcharge_amount,
     })
 }
 
@@ -443,3 +520,239 @@ pub fn execute_dsl_and_get_conditional_config(
         .change_context(ConfigError::DslExecutionError)?;
     Ok(routing_output)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::Currency;
+
+    use super::*;
+
+    fn get_rate_plus_fixed_surcharge(
+        percentage: &str,
+        amount: i64,
+    ) -> surcharge_decision_configs::SurchargeDetailsOutput {
+        surcharge_decision_configs::SurchargeDetailsOutput {
+            surcharge: surcharge_decision_configs::SurchargeOutput::RatePlusFixed {
+                percentage: common_utils_types::Percentage::from_string(percentage.to_string())
+                    .unwrap(),
+                amount,
+            },
+            tax_on_surcharge: None,
+        }
+    }
+
+    #[test]
+    fn test_rate_plus_fixed_surcharge_is_rounded_to_minor_units() {
+        for (
+            currency,
+            original_amount,
+            percentage,
+            fixed_amount,
+            surcharge_amount,
+            display_total,
+        ) in [
+            // 2.5% of $10.00 + 30 cents
+            (Currency::USD, 1000, "2.5", 30, 55, 10.55),
+            // 2.5% of $10.01 is 25.025 cents, which is ceiled to 26 cents
+            (Currency::USD, 1001, "2.5", 30, 56, 10.57),
+            // 2.9% of 1999 yen is 57.971 yen, which is ceiled to 58 yen
+            (Currency::JPY, 1999, "2.9", 30, 88, 2087.0),
+            // 1.1% of 1.000 KWD + 0.100 KWD
+            (Currency::KWD, 1000, "1.1", 100, 111, 1.111),
+        ] {
+            let surcharge_details = get_surcharge_details_for_amount(
+                get_rate_plus_fixed_surcharge(percentage, fixed_amount),
+                original_amount,
+            )
+            .unwrap();
+
+            assert_eq!(surcharge_details.surcharge_amount, surcharge_amount);
+            assert_eq!(
+                surcharge_details.final_amount,
+                original_amount + surcharge_amount
+            );
+            assert_eq!(
+                currency
+                    .to_currency_base_unit_asf64(surcharge_details.final_amount)
+                    .unwrap(),
+                display_total
+            );
+        }
+    }
+
+    fn get_card_surcharge_rule(
+        name: &str,
+        conditions: Vec<(&str, &str)>,
+        amount: i64,
+    ) -> serde_json::Value {
+        let condition: Vec<_> = conditions
+            .into_iter()
+            .map(|(lhs, value)| {
+                serde_json::json!({
+                    "lhs": lhs,
+                    "comparison": "equal",
+                    "value": { "type": "enum_variant", "value": value },
+                    "metadata": {}
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "name": name,
+            "connectorSelection": {
+                "surcharge_details": {
+                    "surcharge": { "type": "fixed", "value": { "amount": amount } },
+                    "tax_on_surcharge": null
+                }
+            },
+            "statements": [{ "condition": condition, "nested": null }]
+        })
+    }
+
+    fn get_card_surcharge_interpreter(
+        prioritize_card_specific_rules: bool,
+    ) -> VirInterpreterBackendCacheWrapper {
+        // The network level rule is listed before the more specific rule
+        let record: SurchargeDecisionManagerRecord = serde_json::from_value(serde_json::json!({
+            "name": "card_surcharge",
+            "merchant_surcharge_configs": {
+                "prioritize_card_specific_rules": prioritize_card_specific_rules
+            },
+            "algorithm": {
+                "defaultSelection": {
+                    "surcharge_details": {
+                        "surcharge": { "type": "fixed", "value": { "amount": 10 } },
+                        "tax_on_surcharge": null
+                    }
+                },
+                "rules": [
+                    get_card_surcharge_rule("visa", vec![("card_network", "Visa")], 20),
+                    get_card_surcharge_rule(
+                        "visa_credit",
+                        vec![("card_network", "Visa"), ("card_type", "credit")],
+                        30
+                    ),
+                ],
+                "metadata": {}
+            },
+            "created_at": 0,
+            "modified_at": 0
+        }))
+        .unwrap();
+
+        VirInterpreterBackendCacheWrapper::try_from(record).unwrap()
+    }
+
+    fn get_card_surcharge_amount(
+        interpreter: &VirInterpreterBackendCacheWrapper,
+        card_network: common_enums::CardNetwork,
+        payment_method_type: Option<common_enums::PaymentMethodType>,
+    ) -> Option<i64> {
+        let backend_input = dsl_inputs::BackendInput {
+            metadata: None,
+            payment: dsl_inputs::PaymentInput {
+                amount: 1000,
+                currency: Currency::USD,
+                authentication_type: None,
+                card_bin: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: Some(common_enums::PaymentMethod::Card),
+                payment_method_type,
+                card_network: Some(card_network),
+            },
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+
+        execute_dsl_and_get_conditional_config(backend_input, &interpreter.cached_alogorith)
+            .unwrap()
+            .surcharge_details
+            .map(|surcharge_details| get_surcharge_amount(&surcharge_details.surcharge, 1000))
+            .transpose()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_card_surcharge_rules_are_evaluated_in_order_by_default() {
+        let interpreter = get_card_surcharge_interpreter(false);
+
+        for (card_network, payment_method_type, surcharge_amount) in [
+            (
+                common_enums::CardNetwork::Visa,
+                Some(common_enums::PaymentMethodType::Credit),
+                20,
+            ),
+            (
+                common_enums::CardNetwork::Visa,
+                Some(common_enums::PaymentMethodType::Debit),
+                20,
+            ),
+            (
+                common_enums::CardNetwork::Mastercard,
+                Some(common_enums::PaymentMethodType::Credit),
+                10,
+            ),
+        ] {
+            assert_eq!(
+                get_card_surcharge_amount(&interpreter, card_network, payment_method_type),
+                Some(surcharge_amount)
+            );
+        }
+    }
+
+    #[test]
+    fn test_most_specific_card_surcharge_rule_is_selected() {
+        let interpreter = get_card_surcharge_interpreter(true);
+
+        for (card_network, payment_method_type, surcharge_amount) in [
+            (
+                common_enums::CardNetwork::Visa,
+                Some(common_enums::PaymentMethodType::Credit),
+                30,
+            ),
+            (
+                common_enums::CardNetwork::Visa,
+                Some(common_enums::PaymentMethodType::Debit),
+                20,
+            ),
+            // An unknown funding type falls back to the network level rule
+            (common_enums::CardNetwork::Visa, None, 20),
+            (
+                common_enums::CardNetwork::Mastercard,
+                Some(common_enums::PaymentMethodType::Credit),
+                10,
+            ),
+        ] {
+            assert_eq!(
+                get_card_surcharge_amount(&interpreter, card_network, payment_method_type),
+                Some(surcharge_amount)
+            );
+        }
+    }
+
+    #[test]
+    fn test_tax_is_applied_on_combined_surcharge() {
+        let mut surcharge = get_rate_plus_fixed_surcharge("2.5", 30);
+        surcharge.tax_on_surcharge =
+            Some(common_utils_types::Percentage::from_string("10".to_string()).unwrap());
+
+        let surcharge_details = get_surcharge_details_for_amount(surcharge, 1001).unwrap();
+
+        assert_eq!(surcharge_details.surcharge_amount, 56);
+        assert_eq!(surcharge_details.tax_on_surcharge_amount, 6);
+        assert_eq!(surcharge_details.final_amount, 1063);
+        assert!(matches!(
+            surcharge_details.surcharge,
+            common_utils_types::Surcharge::RatePlusFixed { amount: 30, .. }
+        ));
+    }
+}
@@ -6,7 +6,7 @@ use common_utils::{
     pii::Email,
     request::RequestContent,
 };
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use josekit::jwe;
 use serde::{Deserialize, Serialize};
 
@@ -17,7 +17,7 @@ use crate::{
     pii::{prelude::*, Secret},
     services::{api as services, encryption},
     types::{api, storage},
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Serialize)]
@@ -234,6 +234,7 @@ pub async fn get_decrypted_response_payload(
 
 pub async fn mk_basilisk_req(
     jwekey: &settings::Jwekey,
+    locker: &settings::Locker,
     jws: &str,
     locker_choice: api_enums::LockerChoice,
 ) -> CustomResult<encryption::JweBody, errors::VaultError> {
@@ -252,6 +253,7 @@ pub async fn mk_basilisk_req(
     let payload = jws_body
         .encode_to_vec()
         .change_context(errors::VaultError::SaveCardFailed)?;
+    validate_locker_payload_size(&payload, locker)?;
 
     let public_key = match locker_choice {
         api_enums::LockerChoice::HyperswitchCardVault => {
@@ -280,6 +282,20 @@ pub async fn mk_basilisk_req(
     Ok(jwe_body)
 }
 
+/// Rejects payloads larger than the configured maximum before they are encrypted, so that
+/// arbitrarily large generic payloads are never sent to the locker
+fn validate_locker_payload_size(
+    payload: &[u8],
+    locker: &settings::Locker,
+) -> CustomResult<(), errors::VaultError> {
+    utils::when(payload.len() > locker.max_payload_size_in_bytes, || {
+        Err(report!(errors::VaultError::PayloadTooLarge {
+            size: payload.len(),
+            max_size: locker.max_payload_size_in_bytes,
+        }))
+    })
+}
+
 pub async fn mk_add_locker_request_hs<'a>(
     jwekey: &settings::Jwekey,
     locker: &settings::Locker,
@@ -296,7 +312,7 @@ pub async fn mk_add_locker_request_hs<'a>(
         .await
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    let jwe_payload = mk_basilisk_req(jwekey, &jws, locker_choice).await?;
+    let jwe_payload = mk_basilisk_req(jwekey, locker, &jws, locker_choice).await?;
     let mut url = match locker_choice {
         api_enums::LockerChoice::HyperswitchCardVault => locker.host.to_owned(),
     };
@@ -437,7 +453,7 @@ pub async fn mk_get_card_request_hs(
 
     let target_locker = locker_choice.unwrap_or(api_enums::LockerChoice::HyperswitchCardVault);
 
-    let jwe_payload = mk_basilisk_req(jwekey, &jws, target_locker).await?;
+    let jwe_payload = mk_basilisk_req(jwekey, locker, &jws, target_locker).await?;
     let mut url = match target_locker {
         api_enums::LockerChoice::HyperswitchCardVault => locker.host.to_owned(),
     };
@@ -506,8 +522,13 @@ pub async fn mk_delete_card_request_hs(
         .await
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    let jwe_payload =
-        mk_basilisk_req(jwekey, &jws, api_enums::LockerChoice::HyperswitchCardVault).await?;
+    let jwe_payload = mk_basilisk_req(
+        jwekey,
+        locker,
+        &jws,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await?;
 
     let mut url = locker.host.to_owned();
     url.push_str("/cards/delete");
@@ -579,6 +600,11 @@ pub fn mk_crud_locker_request(
     path: &str,
     req: api::TokenizePayloadEncrypted,
 ) -> CustomResult<services::Request, errors::VaultError> {
+    let payload = req
+        .encode_to_vec()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+    validate_locker_payload_size(&payload, locker)?;
+
     let mut url = locker.basilisk_host.to_owned();
     url.push_str(path);
     let mut request = services::Request::new(services::Method::Post, &url);
@@ -631,3 +657,76 @@ pub fn mk_card_value2(
         .change_context(errors::VaultError::FetchCardFailed)?;
     Ok(value2_req)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_generic_store_payload(enc_data_size: usize) -> Vec<u8> {
+        StoreLockerReq::LockerGeneric(StoreGenericReq {
+            merchant_id: "merchant_123",
+            merchant_customer_id: "cus_123".to_string(),
+            enc_data: "a".repeat(enc_data_size),
+        })
+        .encode_to_vec()
+        .unwrap()
+    }
+
+    fn get_locker_config(max_payload_size_in_bytes: usize) -> settings::Locker {
+        settings::Locker {
+            max_payload_size_in_bytes,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_payload_under_limit_is_accepted() {
+        let payload = get_generic_store_payload(512);
+        assert!(validate_locker_payload_size(&payload, &get_locker_config(1024)).is_ok());
+    }
+
+    #[test]
+    fn test_payload_over_limit_is_rejected() {
+        let payload = get_generic_store_payload(2048);
+        let error = validate_locker_payload_size(&payload, &get_locker_config(1024)).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::VaultError::PayloadTooLarge { size, max_size: 1024 } if *size == payload.len()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_basilisk_request_over_limit_is_rejected() {
+        let jws = format!("header.{}.signature", "a".repeat(2048));
+
+        let error = mk_basilisk_req(
+            &settings::Jwekey::default(),
+            &get_locker_config(1024),
+            &jws,
+            api_enums::LockerChoice::HyperswitchCardVault,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::VaultError::PayloadTooLarge { max_size: 1024, .. }
+        ));
+    }
+
+    #[test]
+    fn test_basilisk_tokenize_request_over_limit_is_rejected() {
+        let request = api::TokenizePayloadEncrypted {
+            payload: "a".repeat(2048),
+            key_id: "1".to_string(),
+            version: None,
+        };
+
+        let error =
+            mk_crud_locker_request(&get_locker_config(1024), "/tokenize", request).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::VaultError::PayloadTooLarge { max_size: 1024, .. }
+        ));
+    }
+}
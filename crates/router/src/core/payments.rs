@@ -1,5 +1,10 @@
 pub mod access_token;
+pub mod auto_void;
+pub mod bulk_reject;
+pub mod capture_delay;
 pub mod conditional_configs;
+pub mod connector_health;
+pub mod connector_retry_after;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
@@ -69,7 +74,7 @@ use crate::{
     core::{
         authentication as authentication_core,
         errors::{self, CustomResult, RouterResponse, RouterResult},
-        utils,
+        mandate, utils,
     },
     db::StorageInterface,
     logger,
@@ -178,7 +183,7 @@ where
         &business_profile,
         &key_store,
         &mut payment_data,
-        eligible_connectors,
+        eligible_connectors.clone(),
         mandate_type,
     )
     .await?;
@@ -400,6 +405,7 @@ where
                                 #[cfg(not(feature = "frm"))]
                                 None,
                                 &business_profile,
+                                eligible_connectors.as_ref(),
                             )
                             .await?;
                         };
@@ -771,7 +777,7 @@ where
             .flat_map(|c| c.foreign_try_into())
             .collect()
     });
-    let (payment_data, _req, customer, connector_http_status_code, external_latency) =
+    let (mut payment_data, _req, customer, connector_http_status_code, external_latency) =
         payments_operation_core::<_, _, _, _>(
             &state,
             req_state,
@@ -786,6 +792,11 @@ where
         )
         .await?;
 
+    payment_data.customer_decline_message = helpers::get_customer_decline_message_for_attempt(
+        &state.conf.customer_decline_messages,
+        &payment_data.payment_attempt,
+    );
+
     Res::generate_response(
         payment_data,
         customer,
@@ -1278,26 +1289,6 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to get the response in json"),
         }?;
-        // When intent status is RequiresCustomerAction, Set poll_id in redis to allow the fetch status of poll through retrieve_poll_status api from client
-        if payments_response.status == common_enums::IntentStatus::RequiresCustomerAction {
-            let req_poll_id = utils::get_external_authentication_request_poll_id(&payment_id);
-            let poll_id = utils::get_poll_id(merchant_id.clone(), req_poll_id.clone());
-            let redis_conn = state
-                .store
-                .get_redis_conn()
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to get redis connection")?;
-            redis_conn
-                .set_key_with_expiry(
-                    &poll_id,
-                    api_models::poll::PollStatus::Pending.to_string(),
-                    crate::consts::POLL_ID_TTL,
-                )
-                .await
-                .change_context(errors::StorageError::KVError)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to add poll_id in redis")?;
-        };
         let default_poll_config = router_types::PollConfig::default();
         let default_config_str = default_poll_config
             .encode_to_string_of_json()
@@ -1317,6 +1308,37 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
             .parse_struct("PollConfig")
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error while parsing PollConfig")?;
+        // When intent status is RequiresCustomerAction, Set poll_id in redis to allow the fetch status of poll through retrieve_poll_status api from client
+        if payments_response.status == common_enums::IntentStatus::RequiresCustomerAction {
+            let req_poll_id = utils::get_external_authentication_request_poll_id(&payment_id);
+            let poll_id = utils::get_poll_id(merchant_id.clone(), req_poll_id.clone());
+            let poll_ttl = poll_config.get_poll_ttl_in_secs();
+            let redis_conn = state
+                .store
+                .get_redis_conn()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get redis connection")?;
+            redis_conn
+                .set_key_with_expiry(
+                    &poll_id,
+                    api_models::poll::PollStatus::Pending.to_string(),
+                    poll_ttl,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add poll_id in redis")?;
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &router_types::PollTracker::get_poll_tracker_key(&poll_id),
+                    router_types::PollTracker::new(payment_id.clone(), &poll_config),
+                    poll_ttl,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add poll tracker in redis")?;
+        };
         let profile_id = payments_response
             .profile_id
             .as_ref()
@@ -1475,6 +1497,16 @@ where
         TokenizationAction::DecryptApplePayToken
             | TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt
     ) {
+        let payment_processing_certificate = get_apple_pay_payment_processing_certificate(
+            state,
+            &connector.connector_name.to_string(),
+            &router_data.apple_pay_flow,
+            &merchant_connector_account,
+        )?
+        .get_required_value("payment_processing_certificate")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Apple Pay payment processing certificate not found")?;
+
         let apple_pay_data = match payment_data.payment_method_data.clone() {
             Some(payment_data) => {
                 let domain_data = domain::PaymentMethodData::from(payment_data);
@@ -1484,8 +1516,7 @@ where
                     )) => Some(
                         ApplePayData::token_json(domain::WalletData::ApplePay(wallet_data))
                             .change_context(errors::ApiErrorResponse::InternalServerError)?
-                            .decrypt(state)
-                            .await
+                            .decrypt(&payment_processing_certificate)
                             .change_context(errors::ApiErrorResponse::InternalServerError)?,
                     ),
                     _ => None,
@@ -1557,10 +1588,30 @@ where
             .ok();
     }
 
+    // Reserve the debit against the usage limits of the mandate just before calling the connector.
+    // This is done for every attempt of the payment, so that retries are checked against the limits
+    if should_continue_further && payment_data.recurring_mandate_payment_data.is_some() {
+        if let Some(mandate_id) = payment_data
+            .mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.mandate_id.as_ref())
+        {
+            mandate::reserve_mandate_debit(
+                state,
+                &merchant_account.merchant_id,
+                mandate_id,
+                &payment_data.payment_attempt.attempt_id,
+                payment_data.payment_attempt.amount,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        }
+    }
+
     // Update the payment trackers just before calling the connector
     // Since the request is already built in the previous step,
     // there should be no error in request construction from hyperswitch end
-    (_, *payment_data) = operation
+    (_, *payment_data) = match operation
         .to_update_tracker()?
         .update_trackers(
             state,
@@ -1573,7 +1624,23 @@ where
             frm_suggestion,
             header_payload,
         )
-        .await?;
+        .await
+    {
+        Ok(update_trackers_result) => update_trackers_result,
+        Err(error) => {
+            // The connector is not called, so the debit reserved for the payment is released
+            mandate::release_mandate_debit(
+                state,
+                &merchant_account.merchant_id,
+                &payment_data.payment_attempt.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map_err(|error| logger::error!(mandate_debit_release_error=?error))
+            .ok();
+            return Err(error);
+        }
+    };
 
     let router_data_res = if should_continue_further {
         // The status of payment_attempt and intent will be updated in the previous step
@@ -2137,6 +2204,52 @@ fn check_apple_pay_metadata(
     })
 }
 
+/// The certificate with which the Apple Pay payment tokens are decrypted before they are passed to
+/// the connector. Tokens are decrypted with the certificate of Hyperswitch in the simplified flow,
+/// and in the manual flow only if the merchant has configured their payment processing certificate
+/// and the connector accepts decrypted tokens.
+fn get_apple_pay_payment_processing_certificate(
+    state: &AppState,
+    connector_name: &str,
+    apple_pay_flow: &Option<enums::ApplePayFlow>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> RouterResult<Option<helpers::ApplePayPaymentProcessingCertificate>> {
+    match apple_pay_flow {
+        Some(enums::ApplePayFlow::Simplified) => Ok(Some(
+            helpers::ApplePayPaymentProcessingCertificate::from_config(state),
+        )),
+        Some(enums::ApplePayFlow::Manual) => {
+            let is_predecrypt_supported_by_connector = connector_name
+                .parse::<enums::Connector>()
+                .map(|connector| {
+                    state
+                        .conf
+                        .apple_pay_predecrypt_supported_connectors
+                        .connector_list
+                        .contains(&connector)
+                })
+                .unwrap_or(false);
+
+            if !is_predecrypt_supported_by_connector {
+                return Ok(None);
+            }
+
+            merchant_connector_account
+                .get_connector_wallets_details()
+                .map(|connector_wallets_details| {
+                    helpers::ApplePayPaymentProcessingCertificate::from_connector_wallets_details(
+                        &connector_wallets_details,
+                    )
+                })
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the connector wallets details")
+                .map(Option::flatten)
+        }
+        None => Ok(None),
+    }
+}
+
 fn is_payment_method_type_allowed_for_connector(
     current_pm_type: &Option<storage::enums::PaymentMethodType>,
     pm_type_filter: Option<PaymentMethodTypeTokenFilter>,
@@ -2157,11 +2270,8 @@ async fn decide_payment_method_tokenize_action(
     payment_method: &storage::enums::PaymentMethod,
     pm_parent_token: Option<&String>,
     is_connector_tokenization_enabled: bool,
-    apple_pay_flow: Option<enums::ApplePayFlow>,
+    is_apple_pay_predecrypt_supported: bool,
 ) -> RouterResult<TokenizationAction> {
-    let is_apple_pay_predecrypt_supported =
-        matches!(apple_pay_flow, Some(enums::ApplePayFlow::Simplified));
-
     match pm_parent_token {
         None => {
             if is_connector_tokenization_enabled && is_apple_pay_predecrypt_supported {
@@ -2278,13 +2388,21 @@ where
                 payment_data.payment_attempt.merchant_id.clone(),
             );
 
+            let is_apple_pay_predecrypt_supported = get_apple_pay_payment_processing_certificate(
+                state,
+                &connector,
+                &apple_pay_flow,
+                merchant_connector_account,
+            )?
+            .is_some();
+
             let payment_method_action = decide_payment_method_tokenize_action(
                 state,
                 &connector,
                 payment_method,
                 payment_data.token.as_ref(),
                 is_connector_tokenization_enabled,
-                apple_pay_flow,
+                is_apple_pay_predecrypt_supported,
             )
             .await?;
 
@@ -2532,6 +2650,16 @@ where
     pub frm_metadata: Option<serde_json::Value>,
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
+    pub capture_reference: Option<String>,
+    /// Whether the customer is to be notified about the outcome of the operation
+    pub should_notify_customer: bool,
+    pub routing_explanation: Option<api_models::routing::RoutingExplanation>,
+    /// Customer-friendly message for the decline of the payment attempt, if it failed
+    pub customer_decline_message: Option<String>,
+    /// Reason given by the merchant for rejecting the payment
+    pub rejection_reason: Option<String>,
+    /// Decision of the merchant recorded on the payment intent of a rejected payment
+    pub reject_decision: Option<api_models::payments::RejectDecision>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -3003,10 +3131,11 @@ pub fn update_straight_through_routing<F>(
 where
     F: Send + Clone,
 {
-    let _: api_models::routing::RoutingAlgorithm = request_straight_through
+    let algorithm: api_models::routing::RoutingAlgorithm = request_straight_through
         .clone()
         .parse_value("RoutingAlgorithm")
         .attach_printable("Invalid straight through routing rules format")?;
+    algorithm.validate_volume_splits()?;
 
     payment_data.payment_attempt.straight_through_algorithm = Some(request_straight_through);
 
@@ -3083,7 +3212,9 @@ where
         })
     } else if let api::ConnectorChoice::StraightThrough(algorithm) = connector_choice {
         update_straight_through_routing(payment_data, algorithm)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing",
+            })
             .attach_printable("Failed to update straight through routing algorithm")?;
 
         None
@@ -3265,11 +3396,15 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             &routing_algorithm,
             payment_data.creds_identifier.clone(),
+            Some(&payment_data.payment_intent.payment_id),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
+        let mut routing_explanation =
+            routing::get_straight_through_routing_explanation(&routing_algorithm);
 
         if check_eligibility {
+            let routed_connectors = connectors.clone();
             #[cfg(feature = "business_profile_routing")]
             let profile_id = payment_data.payment_intent.profile_id.clone();
 
@@ -3289,8 +3424,21 @@ where
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed eligibility analysis and fallback")?;
+
+            routing::add_eligibility_analysis_to_routing_explanation(
+                &mut routing_explanation,
+                &routed_connectors,
+                &connectors,
+            );
         }
 
+        let connectors = connector_health::exclude_unhealthy_connectors(&state, connectors).await;
+
+        payment_data.routing_explanation =
+            routing::is_routing_explanation_enabled(&state, &merchant_account.merchant_id)
+                .await
+                .then_some(routing_explanation);
+
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -3323,11 +3471,15 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             routing_algorithm,
             payment_data.creds_identifier.clone(),
+            Some(&payment_data.payment_intent.payment_id),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
+        let mut routing_explanation =
+            routing::get_straight_through_routing_explanation(routing_algorithm);
 
         if check_eligibility {
+            let routed_connectors = connectors.clone();
             #[cfg(feature = "business_profile_routing")]
             let profile_id = payment_data.payment_intent.profile_id.clone();
 
@@ -3347,8 +3499,21 @@ where
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed eligibility analysis and fallback")?;
+
+            routing::add_eligibility_analysis_to_routing_explanation(
+                &mut routing_explanation,
+                &routed_connectors,
+                &connectors,
+            );
         }
 
+        let connectors = connector_health::exclude_unhealthy_connectors(&state, connectors).await;
+
+        payment_data.routing_explanation =
+            routing::is_routing_explanation_enabled(&state, &merchant_account.merchant_id)
+                .await
+                .then_some(routing_explanation);
+
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -3755,7 +3920,7 @@ where
         .attach_printable("Could not decode merchant routing algorithm ref")?
         .unwrap_or_default();
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, mut routing_explanation) = routing::perform_static_routing_v1(
         state,
         &merchant_account.merchant_id,
         algorithm_ref,
@@ -3763,6 +3928,7 @@ where
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let routed_connectors = connectors.clone();
 
     let connectors = routing::perform_eligibility_analysis_with_fallback(
         &state.clone(),
@@ -3778,6 +3944,14 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors = connector_health::exclude_unhealthy_connectors(state, connectors).await;
+
+    routing::add_eligibility_analysis_to_routing_explanation(
+        &mut routing_explanation,
+        &routed_connectors,
+        &connectors,
+    );
+
     #[cfg(feature = "payouts")]
     let first_connector_choice = connectors
         .first()
@@ -3804,6 +3978,11 @@ where
 
     match transaction_data {
         TransactionData::Payment(payment_data) => {
+            payment_data.routing_explanation =
+                routing::is_routing_explanation_enabled(state, &merchant_account.merchant_id)
+                    .await
+                    .then_some(routing_explanation);
+
             decide_multiplex_connector_for_normal_or_recurring_payment(
                 state,
                 payment_data,
@@ -0,0 +1,239 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::AuthorizationExpiryConfig,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::{metrics, AppState},
+    types::storage::{self, enums},
+};
+
+const AUTO_VOID_TASK_TAG: &str = "PAYMENT";
+
+const AUTO_VOID_TASK_NAME: &str = "AUTO_VOID_AUTHORIZATION";
+
+const AUTO_VOID_TASK_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::PaymentAutoVoidWorkflow;
+
+pub const AUTO_VOID_CANCELLATION_REASON: &str = "authorization_expiry";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentAutoVoidTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub authorized_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AutoVoidDecision {
+    /// The authorization is about to expire and should be voided
+    Void,
+    /// The authorization is not close to expiry yet, it should be checked again at the given time
+    RetryAt(PrimitiveDateTime),
+    /// The payment is no longer pending capture, or auto void is not configured for the connector
+    Skip,
+}
+
+/// The time at which an authorization with the connector should be voided, if the
+/// authorization expiry window of the connector is configured
+pub fn get_auto_void_time(
+    config: &AuthorizationExpiryConfig,
+    connector: &str,
+    authorized_at: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    config.connectors.get(connector).map(|expiry_window| {
+        let void_after_seconds = expiry_window
+            .saturating_sub(config.auto_void_buffer_in_seconds)
+            .max(0);
+        authorized_at.saturating_add(time::Duration::seconds(void_after_seconds))
+    })
+}
+
+pub fn get_auto_void_decision(
+    config: &AuthorizationExpiryConfig,
+    tracking_data: &PaymentAutoVoidTrackingData,
+    attempt_status: enums::AttemptStatus,
+    current_time: PrimitiveDateTime,
+) -> AutoVoidDecision {
+    if attempt_status != enums::AttemptStatus::Authorized {
+        return AutoVoidDecision::Skip;
+    }
+
+    match get_auto_void_time(
+        config,
+        &tracking_data.connector,
+        tracking_data.authorized_at,
+    ) {
+        Some(auto_void_time) if current_time >= auto_void_time => AutoVoidDecision::Void,
+        Some(auto_void_time) => AutoVoidDecision::RetryAt(auto_void_time),
+        None => AutoVoidDecision::Skip,
+    }
+}
+
+/// Whether the merchant has opted in to automatically void authorizations before they expire
+#[instrument(skip_all)]
+pub async fn is_auto_void_enabled(db: &dyn StorageInterface, merchant_id: &str) -> bool {
+    let key = format!("auto_void_authorization_enabled_{merchant_id}");
+    db.find_config_by_key(key.as_str())
+        .await
+        .map_err(|err| {
+            if !err.current_context().is_db_not_found() {
+                logger::error!(auto_void_config_error=?err);
+            }
+        })
+        .is_ok_and(|config| config.config == "true")
+}
+
+fn get_auto_void_task(
+    payment_attempt: &storage::PaymentAttempt,
+    connector: &str,
+    authorized_at: PrimitiveDateTime,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let tracking_data = PaymentAutoVoidTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        connector: connector.to_string(),
+        authorized_at,
+    };
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        AUTO_VOID_TASK_RUNNER,
+        AUTO_VOID_TASK_NAME,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        AUTO_VOID_TASK_NAME,
+        AUTO_VOID_TASK_RUNNER,
+        [AUTO_VOID_TASK_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct auto void process tracker task")
+}
+
+/// Schedules the authorization of the payment attempt to be voided before it expires at the
+/// connector, if the merchant has opted in to auto void
+#[instrument(skip_all)]
+pub async fn add_auto_void_task_if_enabled(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(connector) = payment_attempt.connector.as_deref() else {
+        return Ok(());
+    };
+
+    let authorized_at = common_utils::date_time::now();
+    let Some(schedule_time) =
+        get_auto_void_time(&state.conf.authorization_expiry, connector, authorized_at)
+    else {
+        return Ok(());
+    };
+
+    if !is_auto_void_enabled(&*state.store, &payment_attempt.merchant_id).await {
+        return Ok(());
+    }
+
+    let process_tracker_entry =
+        get_auto_void_task(payment_attempt, connector, authorized_at, schedule_time)?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting auto void task to process_tracker: payment_id: {}",
+                payment_attempt.payment_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "PaymentAutoVoid")],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_config() -> AuthorizationExpiryConfig {
+        AuthorizationExpiryConfig {
+            // 1 day
+            auto_void_buffer_in_seconds: 86400,
+            // 7 days
+            connectors: HashMap::from([("stripe".to_string(), 604800)]),
+        }
+    }
+
+    fn get_tracking_data(connector: &str) -> PaymentAutoVoidTrackingData {
+        PaymentAutoVoidTrackingData {
+            merchant_id: "merchant1".to_string(),
+            payment_id: "pay_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            connector: connector.to_string(),
+            authorized_at: datetime!(2024-01-01 00:00),
+        }
+    }
+
+    #[test]
+    fn test_authorization_near_expiry_is_voided() {
+        let decision = get_auto_void_decision(
+            &get_config(),
+            &get_tracking_data("stripe"),
+            enums::AttemptStatus::Authorized,
+            datetime!(2024-01-07 00:00),
+        );
+        assert_eq!(decision, AutoVoidDecision::Void);
+    }
+
+    #[test]
+    fn test_authorization_within_window_is_untouched() {
+        let decision = get_auto_void_decision(
+            &get_config(),
+            &get_tracking_data("stripe"),
+            enums::AttemptStatus::Authorized,
+            datetime!(2024-01-03 00:00),
+        );
+        assert_eq!(
+            decision,
+            AutoVoidDecision::RetryAt(datetime!(2024-01-07 00:00))
+        );
+    }
+
+    #[test]
+    fn test_captured_or_unconfigured_authorization_is_skipped() {
+        let decision = get_auto_void_decision(
+            &get_config(),
+            &get_tracking_data("stripe"),
+            enums::AttemptStatus::Charged,
+            datetime!(2024-01-07 00:00),
+        );
+        assert_eq!(decision, AutoVoidDecision::Skip);
+
+        let decision = get_auto_void_decision(
+            &get_config(),
+            &get_tracking_data("adyen"),
+            enums::AttemptStatus::Authorized,
+            datetime!(2024-01-07 00:00),
+        );
+        assert_eq!(decision, AutoVoidDecision::Skip);
+    }
+}
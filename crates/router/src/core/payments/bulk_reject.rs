@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use api_models::payments::{
+    BulkRejectPaymentResult, BulkRejectPaymentStatus, PaymentsBulkRejectRequest,
+    PaymentsBulkRejectResponse, PaymentsCancelRequest,
+};
+use router_env::{instrument, logger, tracing, Flow};
+
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        errors::{self, RouterResponse, RouterResult},
+        payments,
+    },
+    routes::{app::ReqState, lock_utils, AppState},
+    services,
+    types::{api, domain},
+    utils,
+};
+
+/// Rejects each payment of the batch through the `PaymentReject` operation, continuing past payments
+/// which could not be rejected. Payments repeated within the request are only rejected once, the
+/// repetitions are reported as failed.
+#[instrument(skip_all)]
+pub async fn bulk_reject_payments(
+    state: AppState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsBulkRejectRequest,
+) -> RouterResponse<PaymentsBulkRejectResponse> {
+    validate_bulk_reject_request(&req)?;
+
+    let rejection_reason = req.rejection_reason;
+    let response = process_bulk_reject_request(req.payment_ids, |payment_id| {
+        reject_payment(
+            &state,
+            req_state.clone(),
+            &merchant_account,
+            &key_store,
+            payment_id,
+            rejection_reason.clone(),
+        )
+    })
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+fn validate_bulk_reject_request(req: &PaymentsBulkRejectRequest) -> RouterResult<()> {
+    utils::when(
+        !(1..=consts::MAX_PAYMENTS_PER_BULK_REJECT).contains(&req.payment_ids.len()),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payment_ids should contain between 1 and {} payments",
+                    consts::MAX_PAYMENTS_PER_BULK_REJECT
+                ),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+/// Rejects the payment while holding the same lock as the reject endpoint of the payment
+async fn reject_payment(
+    state: &AppState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: String,
+    rejection_reason: Option<String>,
+) -> RouterResult<()> {
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.clone(),
+            api_identifier: lock_utils::ApiIdentifier::from(Flow::PaymentsReject),
+            override_lock_retries: None,
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(state, merchant_account.merchant_id.clone())
+        .await?;
+
+    let result = payments::payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        key_store.clone(),
+        payments::PaymentReject,
+        PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason: Some("Rejected by merchant".to_string()),
+            rejection_reason,
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    )
+    .await
+    .map(|_| ());
+
+    if let Err(error) = lock_action
+        .free_lock_action(state, merchant_account.merchant_id.clone())
+        .await
+    {
+        logger::error!(bulk_reject_lock_error=?error);
+    }
+
+    result
+}
+
+/// Rejects each payment with `reject_payment`, aggregating the outcome of each payment instead of
+/// aborting on the first failure
+async fn process_bulk_reject_request<F, Fut>(
+    payment_ids: Vec<String>,
+    mut reject_payment: F,
+) -> PaymentsBulkRejectResponse
+where
+    F: FnMut(String) -> Fut,
+    Fut: futures::Future<Output = RouterResult<()>>,
+{
+    let mut seen_payment_ids = HashSet::new();
+    let mut payments = Vec::with_capacity(payment_ids.len());
+
+    for payment_id in payment_ids {
+        let result = if !seen_payment_ids.insert(payment_id.clone()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment_id is repeated within the request".to_string(),
+            }
+            .into())
+        } else {
+            reject_payment(payment_id.clone()).await
+        };
+
+        payments.push(match result {
+            Ok(()) => BulkRejectPaymentResult {
+                payment_id,
+                status: BulkRejectPaymentStatus::Rejected,
+                error_code: None,
+                error_message: None,
+            },
+            Err(error) => {
+                logger::error!(bulk_reject_payment_error=?error);
+                // The reject operation fails with a precondition failure for payments whose
+                // status does not allow them to be rejected
+                let status = match error.current_context() {
+                    errors::ApiErrorResponse::PreconditionFailed { .. } => {
+                        BulkRejectPaymentStatus::NotRejectable
+                    }
+                    _ => BulkRejectPaymentStatus::Failed,
+                };
+                BulkRejectPaymentResult {
+                    payment_id,
+                    status,
+                    error_code: Some(error.current_context().error_code()),
+                    error_message: Some(error.current_context().error_message()),
+                }
+            }
+        });
+    }
+
+    PaymentsBulkRejectResponse { payments }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use error_stack::report;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mixed_batch_rejects_rejectable_payments() {
+        let response = process_bulk_reject_request(
+            vec![
+                "pay_flagged".to_string(),
+                "pay_succeeded".to_string(),
+                "pay_missing".to_string(),
+                "pay_flagged".to_string(),
+            ],
+            |payment_id| async move {
+                match payment_id.as_str() {
+                    "pay_flagged" => Ok(()),
+                    "pay_succeeded" => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "You cannot reject this payment because it has status succeeded"
+                            .to_string(),
+                    })),
+                    _ => Err(report!(errors::ApiErrorResponse::PaymentNotFound)),
+                }
+            },
+        )
+        .await;
+
+        let outcomes: Vec<_> = response
+            .payments
+            .iter()
+            .map(|payment| (payment.payment_id.as_str(), payment.status))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("pay_flagged", BulkRejectPaymentStatus::Rejected),
+                ("pay_succeeded", BulkRejectPaymentStatus::NotRejectable),
+                ("pay_missing", BulkRejectPaymentStatus::Failed),
+                ("pay_flagged", BulkRejectPaymentStatus::Failed),
+            ]
+        );
+
+        // The failure of a payment does not prevent the remaining payments from being processed
+        let not_rejectable = response.payments.get(1).unwrap();
+        assert_eq!(
+            not_rejectable.error_message.as_deref(),
+            Some("You cannot reject this payment because it has status succeeded")
+        );
+        assert!(response.payments.first().unwrap().error_code.is_none());
+    }
+
+    #[test]
+    fn test_bulk_reject_request_size_is_bounded() {
+        let get_request = |count: usize| PaymentsBulkRejectRequest {
+            payment_ids: (0..count).map(|index| format!("pay_{index}")).collect(),
+            rejection_reason: None,
+        };
+
+        assert!(validate_bulk_reject_request(&get_request(0)).is_err());
+        assert!(validate_bulk_reject_request(&get_request(1)).is_ok());
+        assert!(
+            validate_bulk_reject_request(&get_request(consts::MAX_PAYMENTS_PER_BULK_REJECT))
+                .is_ok()
+        );
+        assert!(validate_bulk_reject_request(&get_request(
+            consts::MAX_PAYMENTS_PER_BULK_REJECT + 1
+        ))
+        .is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::ConnectorCaptureDelayConfig,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self as payment_flows, helpers, operations},
+    },
+    routes::{app::ReqState, metrics, AppState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const DEFERRED_CAPTURE_TASK_TAG: &str = "PAYMENT";
+
+const DEFERRED_CAPTURE_TASK_NAME: &str = "DEFERRED_CAPTURE";
+
+const DEFERRED_CAPTURE_TASK_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::PaymentDeferredCaptureWorkflow;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentDeferredCaptureTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub capture_request: api::PaymentsCaptureRequest,
+}
+
+/// The earliest time at which an authorization with the connector can be captured, if a minimum
+/// delay between authorization and capture is configured for the connector
+pub fn get_earliest_capture_time(
+    config: &ConnectorCaptureDelayConfig,
+    connector: &str,
+    authorized_at: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    config
+        .connectors
+        .get(connector)
+        .map(|delay| authorized_at.saturating_add(time::Duration::seconds(*delay)))
+}
+
+/// The time until which the capture of an authorization with the connector should be deferred, if
+/// it is issued within the minimum delay of the connector. The capture should be issued right away
+/// otherwise.
+pub fn get_deferred_capture_time(
+    config: &ConnectorCaptureDelayConfig,
+    connector: &str,
+    attempt_status: enums::AttemptStatus,
+    authorized_at: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    if attempt_status != enums::AttemptStatus::Authorized {
+        return None;
+    }
+
+    get_earliest_capture_time(config, connector, authorized_at)
+        .filter(|capture_time| current_time < *capture_time)
+}
+
+fn get_deferred_capture_task(
+    payment_attempt: &storage::PaymentAttempt,
+    capture_request: api::PaymentsCaptureRequest,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let tracking_data = PaymentDeferredCaptureTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        // Connector credentials passed with the request are not persisted
+        capture_request: api::PaymentsCaptureRequest {
+            merchant_connector_details: None,
+            ..capture_request
+        },
+    };
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        DEFERRED_CAPTURE_TASK_RUNNER,
+        DEFERRED_CAPTURE_TASK_NAME,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DEFERRED_CAPTURE_TASK_NAME,
+        DEFERRED_CAPTURE_TASK_RUNNER,
+        [DEFERRED_CAPTURE_TASK_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct deferred capture process tracker task")
+}
+
+/// Schedules the capture of the payment to be issued once the minimum delay of the connector has
+/// passed, if it is requested too soon after authorization. Returns whether the capture was
+/// deferred.
+#[instrument(skip_all)]
+async fn defer_capture_if_required(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    capture_request: &api::PaymentsCaptureRequest,
+) -> RouterResult<bool> {
+    if state.conf.connector_capture_delay.connectors.is_empty() {
+        return Ok(false);
+    }
+
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &capture_request.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &payment_intent.active_attempt.get_id(),
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let Some(connector) = payment_attempt.connector.as_deref() else {
+        return Ok(false);
+    };
+    // Attempts authorized before the authorization time was recorded fall back to the last
+    // modification, which is never earlier than the authorization
+    let Some(schedule_time) = get_deferred_capture_time(
+        &state.conf.connector_capture_delay,
+        connector,
+        payment_attempt.status,
+        payment_attempt
+            .authorized_at
+            .unwrap_or(payment_attempt.modified_at),
+        common_utils::date_time::now(),
+    ) else {
+        return Ok(false);
+    };
+
+    // The capture is validated right away, so that an invalid capture is rejected instead of
+    // failing in the deferred task
+    helpers::validate_capture_request(
+        payment_intent.status,
+        &payment_attempt,
+        capture_request.amount_to_capture,
+        &state.conf.over_capture,
+    )?;
+
+    let process_tracker_entry =
+        get_deferred_capture_task(&payment_attempt, capture_request.clone(), schedule_time)?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Capture of the payment is already scheduled".to_string(),
+        })
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting deferred capture task to process_tracker: payment_id: {}",
+                payment_attempt.payment_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "PaymentDeferredCapture",
+        )],
+    );
+    logger::info!(
+        payment_id = %payment_attempt.payment_id,
+        %schedule_time,
+        "Deferred capture issued within the minimum delay of the connector"
+    );
+
+    Ok(true)
+}
+
+/// Captures the payment, unless the capture is issued within the minimum delay between
+/// authorization and capture of the connector. Such captures are scheduled to be issued once the
+/// delay has passed, and the payment is returned as it is in the meantime.
+#[instrument(skip_all)]
+pub async fn payments_capture_core(
+    state: AppState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    capture_request: api::PaymentsCaptureRequest,
+    header_payload: api::HeaderPayload,
+) -> RouterResponse<api::PaymentsResponse> {
+    if defer_capture_if_required(&state, &merchant_account, &capture_request).await? {
+        let retrieve_request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(capture_request.payment_id),
+            merchant_id: capture_request.merchant_id,
+            force_sync: false,
+            ..Default::default()
+        };
+
+        return payment_flows::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+            state,
+            req_state,
+            merchant_account,
+            key_store,
+            operations::PaymentStatus,
+            retrieve_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Avoid,
+            None,
+            header_payload,
+        )
+        .await;
+    }
+
+    payment_flows::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        operations::PaymentCapture,
+        capture_request,
+        services::AuthFlow::Merchant,
+        payment_flows::CallConnectorAction::Trigger,
+        None,
+        header_payload,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_config() -> ConnectorCaptureDelayConfig {
+        ConnectorCaptureDelayConfig {
+            connectors: HashMap::from([("worldpay".to_string(), 60)]),
+        }
+    }
+
+    #[test]
+    fn test_capture_within_minimum_delay_is_deferred() {
+        let capture_time = get_deferred_capture_time(
+            &get_config(),
+            "worldpay",
+            enums::AttemptStatus::Authorized,
+            datetime!(2024-01-01 00:00),
+            datetime!(2024-01-01 00:00:30),
+        );
+        assert_eq!(capture_time, Some(datetime!(2024-01-01 00:01)));
+    }
+
+    #[test]
+    fn test_capture_after_minimum_delay_is_immediate() {
+        let capture_time = get_deferred_capture_time(
+            &get_config(),
+            "worldpay",
+            enums::AttemptStatus::Authorized,
+            datetime!(2024-01-01 00:00),
+            datetime!(2024-01-01 00:01),
+        );
+        assert_eq!(capture_time, None);
+
+        // Connectors without a minimum delay are always captured right away
+        let capture_time = get_deferred_capture_time(
+            &get_config(),
+            "stripe",
+            enums::AttemptStatus::Authorized,
+            datetime!(2024-01-01 00:00),
+            datetime!(2024-01-01 00:00:30),
+        );
+        assert_eq!(capture_time, None);
+    }
+}
@@ -1,13 +1,16 @@
 mod transformers;
 
+use std::collections::{HashMap, HashSet};
+
 use api_models::{
-    conditional_configs::{ConditionalConfigs, DecisionManagerRecord},
+    conditional_configs::{ConditionalConfigs, DecisionManagerRecord, TimeWindow},
     routing,
 };
 use common_utils::{ext_traits::StringExt, static_cache::StaticCache};
 use error_stack::ResultExt;
 use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::routing::make_dsl_input;
 use crate::{
@@ -15,10 +18,120 @@ use crate::{
     routes,
 };
 
-static CONF_CACHE: StaticCache<backend::VirInterpreterBackend<ConditionalConfigs>> =
-    StaticCache::new();
+static CONF_CACHE: StaticCache<ConditionalConfigCacheWrapper> = StaticCache::new();
 pub type ConditionalConfigResult<O> = errors::CustomResult<O, ConfigError>;
 
+/// Format of the start and end times of a time window, e.g. `22:30`
+const TIME_WINDOW_TIME_FORMAT: &str = "[hour]:[minute]";
+/// Format of the UTC offset of a time window, e.g. `+05:30`
+const TIME_WINDOW_UTC_OFFSET_FORMAT: &str = "[offset_hour sign:mandatory]:[offset_minute]";
+
+struct ConditionalConfigCacheWrapper {
+    interpreter: backend::VirInterpreterBackend<ConditionalConfigs>,
+    time_windows: HashMap<String, ParsedTimeWindow>,
+}
+
+impl TryFrom<DecisionManagerRecord> for ConditionalConfigCacheWrapper {
+    type Error = error_stack::Report<ConfigError>;
+
+    fn try_from(value: DecisionManagerRecord) -> Result<Self, Self::Error> {
+        let time_windows = value
+            .time_windows
+            .iter()
+            .map(|(name, window)| {
+                parse_time_window(window)
+                    .map(|window| (name.clone(), window))
+                    .ok_or(ConfigError::DslParsingError)
+                    .attach_printable_lazy(|| format!("Invalid time window {name}"))
+            })
+            .collect::<Result<_, _>>()?;
+        let interpreter = backend::VirInterpreterBackend::with_program(value.program)
+            .change_context(ConfigError::DslBackendInitError)
+            .attach_printable("Error initializing DSL interpreter backend")?;
+        Ok(Self {
+            interpreter,
+            time_windows,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedTimeWindow {
+    start_time: time::Time,
+    end_time: time::Time,
+    days: Option<HashSet<time::Weekday>>,
+    utc_offset: time::UtcOffset,
+}
+
+impl ParsedTimeWindow {
+    fn is_window_day(&self, day: time::Weekday) -> bool {
+        match &self.days {
+            Some(days) => days.contains(&day),
+            None => true,
+        }
+    }
+
+    /// Checks whether the timestamp, in UTC, falls within the window. A window crossing midnight
+    /// belongs to the day on which it starts.
+    pub fn contains(&self, timestamp: PrimitiveDateTime) -> bool {
+        let local_timestamp = timestamp.assume_utc().to_offset(self.utc_offset);
+        let (time, day) = (local_timestamp.time(), local_timestamp.weekday());
+
+        if self.start_time < self.end_time {
+            self.start_time <= time && time < self.end_time && self.is_window_day(day)
+        } else if time >= self.start_time {
+            self.is_window_day(day)
+        } else {
+            time < self.end_time && self.is_window_day(day.previous())
+        }
+    }
+}
+
+/// Parses the times and the UTC offset of the time window, returning `None` if any of them is
+/// invalid
+pub fn parse_time_window(window: &TimeWindow) -> Option<ParsedTimeWindow> {
+    let time_format = time::format_description::parse(TIME_WINDOW_TIME_FORMAT).ok()?;
+    let utc_offset = match window.utc_offset.as_deref() {
+        Some(utc_offset) => time::UtcOffset::parse(
+            utc_offset,
+            &time::format_description::parse(TIME_WINDOW_UTC_OFFSET_FORMAT).ok()?,
+        )
+        .ok()?,
+        None => time::UtcOffset::UTC,
+    };
+
+    Some(ParsedTimeWindow {
+        start_time: time::Time::parse(&window.start_time, &time_format).ok()?,
+        end_time: time::Time::parse(&window.end_time, &time_format).ok()?,
+        days: window
+            .days
+            .as_ref()
+            .map(|days| days.iter().copied().map(time::Weekday::from).collect()),
+        utc_offset,
+    })
+}
+
+/// Adds the time windows of the config to the metadata of the input under their namespaced keys,
+/// with `true` as the value of the windows within which the request is made and `false` as the
+/// value of the rest
+fn add_time_window_metadata(
+    backend_input: &mut dsl_inputs::BackendInput,
+    time_windows: &HashMap<String, ParsedTimeWindow>,
+    timestamp: PrimitiveDateTime,
+) {
+    if time_windows.is_empty() {
+        return;
+    }
+
+    let metadata = backend_input.metadata.get_or_insert_with(HashMap::new);
+    for (name, window) in time_windows {
+        metadata.insert(
+            TimeWindow::metadata_key(name),
+            window.contains(timestamp).to_string(),
+        );
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn perform_decision_management<F: Clone>(
     state: &routes::AppState,
@@ -43,10 +156,14 @@ pub async fn perform_decision_management<F: Clone>(
         .retrieve(&key)
         .change_context(ConfigError::CacheMiss)
         .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
-    let backend_input =
+    let mut backend_input =
         make_dsl_input(payment_data).change_context(ConfigError::InputConstructionError)?;
-    let interpreter = cached_algo.as_ref();
-    execute_dsl_and_get_conditional_config(backend_input, interpreter).await
+    add_time_window_metadata(
+        &mut backend_input,
+        &cached_algo.time_windows,
+        common_utils::date_time::now(),
+    );
+    execute_dsl_and_get_conditional_config(backend_input, &cached_algo.interpreter).await
 }
 
 #[instrument(skip_all)]
@@ -89,12 +206,9 @@ pub async fn refresh_routing_cache(
         .parse_struct("Program")
         .change_context(ConfigError::DslParsingError)
         .attach_printable("Error parsing routing algorithm from configs")?;
-    let interpreter: backend::VirInterpreterBackend<ConditionalConfigs> =
-        backend::VirInterpreterBackend::with_program(rec.program)
-            .change_context(ConfigError::DslBackendInitError)
-            .attach_printable("Error initializing DSL interpreter backend")?;
+    let value_to_cache = ConditionalConfigCacheWrapper::try_from(rec)?;
     CONF_CACHE
-        .save(key, interpreter, timestamp)
+        .save(key, value_to_cache, timestamp)
         .change_context(ConfigError::DslCachePoisoned)
         .attach_printable("Error saving DSL to cache")?;
     Ok(())
@@ -110,3 +224,156 @@ pub async fn execute_dsl_and_get_conditional_config(
         .change_context(ConfigError::DslExecutionError)?;
     Ok(routing_output)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::conditional_configs::{AuthenticationType, DayOfWeek};
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_time_window(
+        start_time: &str,
+        end_time: &str,
+        days: Option<Vec<DayOfWeek>>,
+        utc_offset: Option<&str>,
+    ) -> ParsedTimeWindow {
+        parse_time_window(&TimeWindow {
+            start_time: start_time.to_string(),
+            end_time: end_time.to_string(),
+            days,
+            utc_offset: utc_offset.map(ToString::to_string),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_request_inside_and_outside_time_window() {
+        // 09:00 to 17:00 in IST, which is 03:30 to 11:30 in UTC
+        let window = get_time_window("09:00", "17:00", None, Some("+05:30"));
+
+        assert!(window.contains(datetime!(2024-01-01 03:30)));
+        assert!(window.contains(datetime!(2024-01-01 11:29)));
+        assert!(!window.contains(datetime!(2024-01-01 03:29)));
+        assert!(!window.contains(datetime!(2024-01-01 11:30)));
+        assert!(!window.contains(datetime!(2024-01-01 17:00)));
+    }
+
+    #[test]
+    fn test_time_window_crossing_midnight() {
+        // Friday night 22:00 to 06:00 in UTC, 2024-01-05 is a Friday
+        let window = get_time_window("22:00", "06:00", Some(vec![DayOfWeek::Friday]), None);
+
+        assert!(window.contains(datetime!(2024-01-05 22:00)));
+        assert!(window.contains(datetime!(2024-01-06 05:59)));
+        assert!(!window.contains(datetime!(2024-01-05 21:59)));
+        assert!(!window.contains(datetime!(2024-01-06 06:00)));
+        // The early hours of Friday belong to the window starting on Thursday
+        assert!(!window.contains(datetime!(2024-01-05 01:00)));
+        assert!(!window.contains(datetime!(2024-01-06 22:00)));
+    }
+
+    #[test]
+    fn test_invalid_time_window_is_rejected() {
+        for (start_time, end_time, utc_offset) in [
+            ("9 AM", "17:00", None),
+            ("09:00", "24:00", None),
+            ("09:00", "17:00", Some("IST")),
+        ] {
+            assert!(parse_time_window(&TimeWindow {
+                start_time: start_time.to_string(),
+                end_time: end_time.to_string(),
+                days: None,
+                utc_offset: utc_offset.map(ToString::to_string),
+            })
+            .is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rule_matches_on_time_window() {
+        let record: DecisionManagerRecord = serde_json::from_value(serde_json::json!({
+            "name": "off_peak_3ds",
+            "program": {
+                "defaultSelection": { "override_3ds": null },
+                "rules": [{
+                    "name": "off_peak",
+                    "connectorSelection": { "override_3ds": "three_ds" },
+                    "statements": [{
+                        "condition": [{
+                            "lhs": "metadata",
+                            "comparison": "equal",
+                            "value": {
+                                "type": "metadata_variant",
+                                "value": { "key": "time_window.off_peak", "value": "true" }
+                            },
+                            "metadata": {}
+                        }],
+                        "nested": null
+                    }]
+                }],
+                "metadata": {}
+            },
+            "time_windows": {
+                "off_peak": { "start_time": "22:00", "end_time": "06:00" }
+            },
+            "created_at": 0,
+            "modified_at": 0
+        }))
+        .unwrap();
+        let cached_algo = ConditionalConfigCacheWrapper::try_from(record).unwrap();
+
+        for (timestamp, override_3ds) in [
+            (
+                datetime!(2024-01-01 23:00),
+                Some(AuthenticationType::ThreeDs),
+            ),
+            (datetime!(2024-01-01 12:00), None),
+        ] {
+            let mut backend_input = dsl_inputs::BackendInput {
+                // A merchant key with the same name as the window is left untouched
+                metadata: Some(HashMap::from([(
+                    "off_peak".to_string(),
+                    "true".to_string(),
+                )])),
+                payment: dsl_inputs::PaymentInput {
+                    amount: 1000,
+                    currency: common_enums::Currency::USD,
+                    authentication_type: None,
+                    card_bin: None,
+                    capture_method: None,
+                    business_country: None,
+                    billing_country: None,
+                    business_label: None,
+                    setup_future_usage: None,
+                },
+                payment_method: dsl_inputs::PaymentMethodInput {
+                    payment_method: Some(common_enums::PaymentMethod::Card),
+                    payment_method_type: None,
+                    card_network: None,
+                },
+                mandate: dsl_inputs::MandateData {
+                    mandate_acceptance_type: None,
+                    mandate_type: None,
+                    payment_type: None,
+                },
+            };
+            add_time_window_metadata(&mut backend_input, &cached_algo.time_windows, timestamp);
+            assert_eq!(
+                backend_input
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.get("off_peak"))
+                    .map(String::as_str),
+                Some("true")
+            );
+
+            let conditional_configs =
+                execute_dsl_and_get_conditional_config(backend_input, &cached_algo.interpreter)
+                    .await
+                    .unwrap();
+            assert_eq!(conditional_configs.override_3ds, override_3ds);
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::HashSet;
+
+use api_models::routing as routing_types;
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::ConnectorHealthProbeConfig,
+    core::errors::{self, RouterResult},
+    routes::{metrics, AppState},
+    services,
+    types::{self, api, storage},
+};
+
+const CONNECTOR_HEALTH_PROBE_TASK_TAG: &str = "CONNECTOR";
+
+const CONNECTOR_HEALTH_PROBE_TASK_NAME: &str = "CONNECTOR_HEALTH_PROBE";
+
+const CONNECTOR_HEALTH_PROBE_TASK_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::ConnectorHealthProbeWorkflow;
+
+/// Connectors are never probed more often than this, irrespective of the configured interval
+const MIN_PROBE_INTERVAL_IN_SECS: i64 = 10;
+
+/// Health statuses are retained for this many probe intervals, so that a connector which is no
+/// longer being probed is not excluded from routing indefinitely
+const HEALTH_STATUS_TTL_IN_INTERVALS: i64 = 3;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorHealthProbeTrackingData {
+    pub connector: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorHealthStatus {
+    pub is_healthy: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub probed_at: PrimitiveDateTime,
+}
+
+fn get_probe_interval(config: &ConnectorHealthProbeConfig) -> time::Duration {
+    time::Duration::seconds(config.interval_in_secs.max(MIN_PROBE_INTERVAL_IN_SECS))
+}
+
+/// The time at which the connector should be probed next, given the time of its last probe
+pub fn get_next_probe_time(
+    config: &ConnectorHealthProbeConfig,
+    last_probed_at: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    last_probed_at.saturating_add(get_probe_interval(config))
+}
+
+/// Whether a probe interval has elapsed since the connector was last probed
+pub fn is_probe_due(
+    config: &ConnectorHealthProbeConfig,
+    last_status: Option<&ConnectorHealthStatus>,
+    current_time: PrimitiveDateTime,
+) -> bool {
+    last_status.map_or(true, |status| {
+        current_time >= get_next_probe_time(config, status.probed_at)
+    })
+}
+
+/// Derives the health of a connector from the outcome of a probe request. The probe only checks
+/// that the connector is reachable, so any response other than a server error is healthy.
+pub fn get_connector_health_status(
+    probe_result: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    probed_at: PrimitiveDateTime,
+) -> ConnectorHealthStatus {
+    let is_healthy = match probe_result {
+        Ok(Ok(_)) => true,
+        Ok(Err(response)) => response.status_code < 500,
+        Err(_) => false,
+    };
+
+    ConnectorHealthStatus {
+        is_healthy,
+        probed_at,
+    }
+}
+
+fn get_connector_health_key(connector: &str) -> String {
+    format!("connector_health_{connector}")
+}
+
+/// Fetches the last recorded health status of the connector, if any
+#[instrument(skip_all)]
+pub async fn get_last_connector_health_status(
+    state: &AppState,
+    connector: &str,
+) -> Option<ConnectorHealthStatus> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(connector_health_redis_error=?error))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<ConnectorHealthStatus>(
+            &get_connector_health_key(connector),
+            "ConnectorHealthStatus",
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context() != &redis_interface::errors::RedisError::NotFound {
+                logger::error!(connector_health_redis_error=?error);
+            }
+        })
+        .ok()
+}
+
+/// Sends a lightweight request to the base URL of the connector and records its health status
+#[instrument(skip_all)]
+pub async fn probe_connector_health(
+    state: &AppState,
+    connector: &str,
+) -> RouterResult<ConnectorHealthStatus> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector,
+        api::GetToken::Connector,
+        None,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid connector name received for health probe")?;
+
+    let base_url = connector_data
+        .connector
+        .base_url(&state.conf.connectors)
+        .to_string();
+    let request = services::Request::new(services::Method::Get, &base_url);
+    let probe_result = services::call_connector_api(state, request, "connector_health_probe").await;
+    let health_status = get_connector_health_status(&probe_result, common_utils::date_time::now());
+
+    logger::info!(
+        connector,
+        is_healthy = health_status.is_healthy,
+        "Recorded connector health probe"
+    );
+
+    let ttl = get_probe_interval(&state.conf.connector_health_probe)
+        .whole_seconds()
+        .saturating_mul(HEALTH_STATUS_TTL_IN_INTERVALS);
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_connector_health_key(connector),
+            &health_status,
+            ttl,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record connector health status")?;
+
+    Ok(health_status)
+}
+
+/// Removes the unhealthy connectors from the list of routable connectors. If every connector is
+/// unhealthy the list is returned unchanged, so that payments are still attempted.
+pub fn retain_healthy_connectors(
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    unhealthy_connectors: &HashSet<api_models::enums::RoutableConnectors>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if connectors
+        .iter()
+        .all(|choice| unhealthy_connectors.contains(&choice.connector))
+    {
+        return connectors;
+    }
+
+    connectors
+        .into_iter()
+        .filter(|choice| !unhealthy_connectors.contains(&choice.connector))
+        .collect()
+}
+
+/// Excludes connectors whose last health probe found them to be unhealthy from routing, if
+/// connector health probing is enabled. Probes are scheduled for connectors which have no health
+/// status recorded, such as those whose accounts were created before probing was enabled.
+#[instrument(skip_all)]
+pub async fn exclude_unhealthy_connectors(
+    state: &AppState,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if !state.conf.connector_health_probe.enabled {
+        return connectors;
+    }
+
+    let mut unhealthy_connectors = HashSet::new();
+    for connector in connectors
+        .iter()
+        .map(|choice| choice.connector)
+        .collect::<HashSet<_>>()
+    {
+        match get_last_connector_health_status(state, &connector.to_string()).await {
+            Some(status) if !status.is_healthy => {
+                unhealthy_connectors.insert(connector);
+            }
+            Some(_) => {}
+            None => {
+                add_connector_health_probe_task_if_enabled(state, &connector.to_string())
+                    .await
+                    .map_err(|error| logger::error!(connector_health_probe_task_error=?error))
+                    .ok();
+            }
+        }
+    }
+
+    retain_healthy_connectors(connectors, &unhealthy_connectors)
+}
+
+/// Schedules periodic health probes of the connector, if probing is enabled and the connector is
+/// not already being probed
+#[instrument(skip_all)]
+pub async fn add_connector_health_probe_task_if_enabled(
+    state: &AppState,
+    connector: &str,
+) -> RouterResult<()> {
+    if !state.conf.connector_health_probe.enabled {
+        return Ok(());
+    }
+
+    let process_tracker_id = format!(
+        "{}_{CONNECTOR_HEALTH_PROBE_TASK_NAME}_{connector}",
+        CONNECTOR_HEALTH_PROBE_TASK_RUNNER
+    );
+    let existing_task = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector health probe task")?;
+    if existing_task.is_some() {
+        return Ok(());
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CONNECTOR_HEALTH_PROBE_TASK_NAME,
+        CONNECTOR_HEALTH_PROBE_TASK_RUNNER,
+        [CONNECTOR_HEALTH_PROBE_TASK_TAG],
+        ConnectorHealthProbeTrackingData {
+            connector: connector.to_string(),
+        },
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct connector health probe process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting connector health probe task to process_tracker: connector: {connector}"
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "ConnectorHealthProbe",
+        )],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_config(interval_in_secs: i64) -> ConnectorHealthProbeConfig {
+        ConnectorHealthProbeConfig {
+            enabled: true,
+            interval_in_secs,
+        }
+    }
+
+    fn get_response(status_code: u16) -> types::Response {
+        types::Response {
+            headers: None,
+            response: bytes::Bytes::new(),
+            status_code,
+        }
+    }
+
+    fn get_connector_choice(
+        connector: api_models::enums::RoutableConnectors,
+    ) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            #[cfg(feature = "backwards_compatibility")]
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            #[cfg(feature = "connector_choice_mca_id")]
+            merchant_connector_id: None,
+            #[cfg(not(feature = "connector_choice_mca_id"))]
+            sub_label: None,
+        }
+    }
+
+    fn get_connector_names(
+        connectors: &[routing_types::RoutableConnectorChoice],
+    ) -> Vec<api_models::enums::RoutableConnectors> {
+        connectors.iter().map(|choice| choice.connector).collect()
+    }
+
+    #[test]
+    fn test_probe_updates_connector_health_status() {
+        let probed_at = datetime!(2024-01-01 00:00);
+
+        let status = get_connector_health_status(&Ok(Ok(get_response(200))), probed_at);
+        assert_eq!(
+            status,
+            ConnectorHealthStatus {
+                is_healthy: true,
+                probed_at,
+            }
+        );
+
+        // A client error still means that the connector is reachable
+        let status = get_connector_health_status(&Ok(Err(get_response(404))), probed_at);
+        assert!(status.is_healthy);
+
+        let status = get_connector_health_status(&Ok(Err(get_response(503))), probed_at);
+        assert!(!status.is_healthy);
+
+        let status = get_connector_health_status(
+            &Err(errors::ApiClientError::RequestTimeoutReceived.into()),
+            probed_at,
+        );
+        assert!(!status.is_healthy);
+    }
+
+    #[test]
+    fn test_probe_schedule_respects_interval() {
+        let config = get_config(60);
+        let last_status = ConnectorHealthStatus {
+            is_healthy: true,
+            probed_at: datetime!(2024-01-01 00:00),
+        };
+
+        assert_eq!(
+            get_next_probe_time(&config, last_status.probed_at),
+            datetime!(2024-01-01 00:01)
+        );
+        assert!(is_probe_due(&config, None, datetime!(2024-01-01 00:00)));
+        assert!(!is_probe_due(
+            &config,
+            Some(&last_status),
+            datetime!(2024-01-01 00:00:30)
+        ));
+        assert!(is_probe_due(
+            &config,
+            Some(&last_status),
+            datetime!(2024-01-01 00:01)
+        ));
+    }
+
+    #[test]
+    fn test_probe_interval_has_a_lower_bound() {
+        assert_eq!(
+            get_next_probe_time(&get_config(0), datetime!(2024-01-01 00:00)),
+            datetime!(2024-01-01 00:00:10)
+        );
+    }
+
+    #[test]
+    fn test_unhealthy_connectors_are_excluded() {
+        let connectors = vec![
+            get_connector_choice(api_models::enums::RoutableConnectors::Stripe),
+            get_connector_choice(api_models::enums::RoutableConnectors::Adyen),
+        ];
+
+        let healthy_connectors = retain_healthy_connectors(
+            connectors.clone(),
+            &HashSet::from([api_models::enums::RoutableConnectors::Stripe]),
+        );
+        assert_eq!(
+            get_connector_names(&healthy_connectors),
+            vec![api_models::enums::RoutableConnectors::Adyen]
+        );
+
+        // Routing falls back to all connectors when none of them are healthy
+        let healthy_connectors = retain_healthy_connectors(
+            connectors.clone(),
+            &HashSet::from([
+                api_models::enums::RoutableConnectors::Stripe,
+                api_models::enums::RoutableConnectors::Adyen,
+            ]),
+        );
+        assert_eq!(
+            get_connector_names(&healthy_connectors),
+            get_connector_names(&connectors)
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::ConnectorRetryAfterConfig,
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types,
+};
+
+/// Format of the HTTP-date form of the `Retry-After` header (RFC 9110), e.g. `Wed, 21 Oct 2015 07:28:00 GMT`
+const HTTP_DATE_FORMAT: &str =
+    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT";
+
+fn get_connector_retry_after_key(merchant_id: &str, attempt_id: &str) -> String {
+    format!("connector_retry_after_{merchant_id}_{attempt_id}")
+}
+
+/// Parses the `Retry-After` header of a rate limited (429) connector response into the time after
+/// which the request may be retried. The header may either be a number of seconds or an HTTP-date.
+pub fn get_retry_after_time(
+    response: &types::Response,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    if response.status_code != 429 {
+        return None;
+    }
+
+    let retry_after = response
+        .headers
+        .as_ref()?
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    match retry_after.parse::<u32>() {
+        Ok(delay_in_secs) => {
+            Some(current_time.saturating_add(time::Duration::seconds(delay_in_secs.into())))
+        }
+        Err(_) => time::format_description::parse(HTTP_DATE_FORMAT)
+            .ok()
+            .and_then(|format| PrimitiveDateTime::parse(retry_after, &format).ok()),
+    }
+}
+
+/// Caps the time requested by the connector at the configured maximum delay, so that a
+/// misbehaving connector cannot postpone the next sync indefinitely
+pub fn cap_retry_after_time(
+    config: &ConnectorRetryAfterConfig,
+    retry_after: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    retry_after.min(current_time.saturating_add(time::Duration::seconds(config.max_delay_in_secs)))
+}
+
+/// Stores the `Retry-After` of a rate limited connector response, so that the next sync of the
+/// attempt can be scheduled accordingly. Failures are only logged.
+#[instrument(skip_all)]
+pub async fn record_connector_retry_after(
+    state: &AppState,
+    merchant_id: &str,
+    attempt_id: &str,
+    response: &types::Response,
+) {
+    let current_time = common_utils::date_time::now();
+    let Some(retry_after) = get_retry_after_time(response, current_time) else {
+        return;
+    };
+
+    let ttl = (retry_after - current_time)
+        .whole_seconds()
+        .clamp(1, state.conf.connector_retry_after.max_delay_in_secs.max(1));
+    let result = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?
+            .serialize_and_set_key_with_expiry(
+                &get_connector_retry_after_key(merchant_id, attempt_id),
+                &retry_after.assume_utc().unix_timestamp(),
+                ttl,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record connector retry after")
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(connector_retry_after_error=?error);
+    }
+}
+
+/// Fetches the `Retry-After` recorded for the attempt, if the connector rate limited it recently.
+/// The next sync of the attempt should be scheduled at this time instead of the default schedule.
+#[instrument(skip_all)]
+pub async fn get_connector_retry_after(
+    state: &AppState,
+    merchant_id: &str,
+    attempt_id: &str,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    match redis_conn
+        .get_and_deserialize_key::<i64>(
+            &get_connector_retry_after_key(merchant_id, attempt_id),
+            "i64",
+        )
+        .await
+    {
+        Ok(timestamp) => {
+            let retry_after = time::OffsetDateTime::from_unix_timestamp(timestamp)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid connector retry after timestamp")?;
+            Ok(Some(cap_retry_after_time(
+                &state.conf.connector_retry_after,
+                PrimitiveDateTime::new(retry_after.date(), retry_after.time()),
+                common_utils::date_time::now(),
+            )))
+        }
+        Err(error) if error.current_context() == &redis_interface::errors::RedisError::NotFound => {
+            Ok(None)
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch connector retry after")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_config() -> ConnectorRetryAfterConfig {
+        ConnectorRetryAfterConfig {
+            max_delay_in_secs: 3600,
+        }
+    }
+
+    fn get_response(status_code: u16, retry_after: Option<&'static str>) -> types::Response {
+        let headers = retry_after.map(|retry_after| {
+            let mut headers = http::HeaderMap::new();
+            headers.insert(
+                http::header::RETRY_AFTER,
+                http::HeaderValue::from_static(retry_after),
+            );
+            headers
+        });
+
+        types::Response {
+            headers,
+            response: bytes::Bytes::new(),
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_rate_limited_sync_is_scheduled_after_retry_after() {
+        let current_time = datetime!(2024-01-01 00:00);
+
+        let retry_after = get_retry_after_time(&get_response(429, Some("120")), current_time);
+        assert_eq!(retry_after, Some(datetime!(2024-01-01 00:02)));
+
+        let retry_after = get_retry_after_time(
+            &get_response(429, Some("Mon, 01 Jan 2024 00:10:00 GMT")),
+            current_time,
+        );
+        assert_eq!(retry_after, Some(datetime!(2024-01-01 00:10)));
+
+        // The delay requested by the connector is capped
+        let retry_after =
+            get_retry_after_time(&get_response(429, Some("86400")), current_time).unwrap();
+        assert_eq!(
+            cap_retry_after_time(&get_config(), retry_after, current_time),
+            datetime!(2024-01-01 01:00)
+        );
+    }
+
+    #[test]
+    fn test_sync_without_retry_after_uses_default_backoff() {
+        let current_time = datetime!(2024-01-01 00:00);
+
+        // Without a usable `Retry-After`, nothing is recorded and the default schedule is used
+        for response in [
+            get_response(429, None),
+            get_response(429, Some("soon")),
+            get_response(503, Some("120")),
+        ] {
+            assert_eq!(get_retry_after_time(&response, current_time), None);
+        }
+    }
+}
@@ -2563,6 +2563,94 @@ default_imp_for_revoking_mandates!(
     connector::Zsl
 );
 
+macro_rules! default_imp_for_payment_method_retrieve {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl api::ConnectorPaymentMethodRetrieve for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PaymentMethodRetrieve,
+            types::PaymentMethodRetrieveRequestData,
+            types::PaymentMethodRetrieveResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::ConnectorPaymentMethodRetrieve for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::PaymentMethodRetrieve,
+        types::PaymentMethodRetrieveRequestData,
+        types::PaymentMethodRetrieveResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+default_imp_for_payment_method_retrieve!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cryptopay,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Globepay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Helcim,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Shift4,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stax,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Tsys,
+    connector::Volt,
+    connector::Wise,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl
+);
+
 macro_rules! default_imp_for_connector_authentication {
     ($($path:ident::$connector:ident),*) => {
         $( impl api::ExternalAuthentication for $path::$connector {}
@@ -4,6 +4,7 @@ use error_stack;
 // use router_env::tracing::Instrument;
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
+    configs::settings,
     core::{
         errors::{self, ConnectorErrorExt, RouterResult},
         mandate,
@@ -73,7 +74,7 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         > = connector.connector.get_connector_integration();
 
         if self.should_proceed_with_authorize() {
-            self.decide_authentication_type();
+            self.decide_authentication_type(&state.conf.minimum_three_ds_amount);
             logger::debug!(auth_type=?self.auth_type);
             let resp = services::execute_connector_processing_step(
                 state,
@@ -194,7 +195,7 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                 logger::debug!(completed_pre_tasks=?true);
 
                 if self.should_proceed_with_authorize() {
-                    self.decide_authentication_type();
+                    self.decide_authentication_type(&state.conf.minimum_three_ds_amount);
                     logger::debug!(auth_type=?self.auth_type);
 
                     Ok((
@@ -213,14 +214,43 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
 }
 
 impl types::PaymentsAuthorizeRouterData {
-    fn decide_authentication_type(&mut self) {
+    fn decide_authentication_type(
+        &mut self,
+        minimum_three_ds_amount: &settings::MinimumThreeDsAmountConfig,
+    ) {
         if self.auth_type == diesel_models::enums::AuthenticationType::ThreeDs
-            && !self.request.enrolled_for_3ds
+            && (!self.request.enrolled_for_3ds
+                || self.is_below_minimum_three_ds_amount(minimum_three_ds_amount))
         {
             self.auth_type = diesel_models::enums::AuthenticationType::NoThreeDs
         }
     }
 
+    /// 3DS is skipped for payments below the minimum 3DS amount configured for the connector in
+    /// the billing country of the payment. Mandate setup payments always go through 3DS.
+    fn is_below_minimum_three_ds_amount(
+        &self,
+        minimum_three_ds_amount: &settings::MinimumThreeDsAmountConfig,
+    ) -> bool {
+        let is_mandate_setup = self.request.setup_mandate_details.is_some()
+            || self.request.setup_future_usage
+                == Some(diesel_models::enums::FutureUsage::OffSession);
+
+        let billing_country = self
+            .address
+            .get_payment_method_billing()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country);
+
+        !is_mandate_setup
+            && minimum_three_ds_amount.is_below_threshold(
+                &self.connector,
+                billing_country,
+                self.request.currency,
+                self.request.amount,
+            )
+    }
+
     /// to decide if we need to proceed with authorize or not, Eg: If any of the pretask returns `redirection_response` then we should not proceed with authorize call
     fn should_proceed_with_authorize(&self) -> bool {
         match &self.response {
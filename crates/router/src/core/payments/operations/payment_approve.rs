@@ -176,6 +176,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
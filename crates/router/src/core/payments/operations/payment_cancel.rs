@@ -5,12 +5,13 @@ use async_trait::async_trait;
 use common_utils::ext_traits::AsyncExt;
 use error_stack::ResultExt;
 use router_derive;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        mandate,
         payments::{helpers, operations, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
@@ -189,6 +190,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -261,6 +268,21 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest> for
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment is voided without calling the connector, so any debit reserved against the
+        // usage limits of a mandate for the payment is released
+        if attempt_status_update == enums::AttemptStatus::Voided {
+            mandate::release_mandate_debit(
+                db,
+                &payment_data.payment_attempt.merchant_id,
+                &payment_data.payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| logger::error!(mandate_debit_release_error=?error))
+            .ok();
+        }
+
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentCancelled {
@@ -70,19 +70,13 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             .amount_to_capture
             .update_value(request.amount_to_capture);
 
-        let capture_method = payment_attempt
-            .capture_method
-            .get_required_value("capture_method")?;
-
-        helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
-
-        helpers::validate_amount_to_capture(
-            payment_attempt.amount_capturable,
+        let capture_method = helpers::validate_capture_request(
+            payment_intent.status,
+            &payment_attempt,
             request.amount_to_capture,
+            &state.conf.over_capture,
         )?;
 
-        helpers::validate_capture_method(capture_method)?;
-
         let multiple_capture_data = if capture_method == enums::CaptureMethod::ManualMultiple {
             let amount_to_capture = request
                 .amount_to_capture
@@ -232,6 +226,12 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            capture_reference: request.capture_reference.clone(),
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
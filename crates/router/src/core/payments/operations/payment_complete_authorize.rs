@@ -306,6 +306,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             frm_metadata: None,
             recurring_details,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
         let customer_details = Some(CustomerDetails {
@@ -636,8 +636,27 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
+        helpers::validate_card_save_consent(
+            state,
+            merchant_id,
+            payment_data.payment_method_data.as_ref(),
+            payment_data.payment_intent.setup_future_usage,
+            payment_data.customer_acceptance.is_some()
+                || payment_data
+                    .setup_mandate
+                    .as_ref()
+                    .is_some_and(|mandate_data| mandate_data.customer_acceptance.is_some()),
+        )
+        .await?;
+
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
             customer_details: Some(customer_details),
@@ -760,6 +779,15 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         payment_data: &mut PaymentData<F>,
         _merchant_account: &domain::MerchantAccount,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
+        // The connector is known only once the payment has been routed, so the statement
+        // descriptor is validated here, before the payment trackers are updated
+        if let Some(connector) = payment_data.payment_attempt.connector.as_deref() {
+            core_utils::validate_statement_descriptor_for_connector(
+                &state.conf,
+                connector,
+                &payment_data.payment_intent,
+            )?;
+        }
         populate_surcharge_details(state, payment_data).await
     }
 
@@ -1249,6 +1277,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
         operations::ValidateResult<'a>,
     )> {
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_customer_address_ids_in_request(request)?;
 
         let request_merchant_id = request.merchant_id.as_deref();
         helpers::validate_merchant_id(&merchant_account.merchant_id, request_merchant_id)
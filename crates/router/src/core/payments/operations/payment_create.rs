@@ -101,6 +101,26 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     id: profile_id.to_string(),
                 })?
         };
+        if request.confirm == Some(true) {
+            helpers::validate_card_save_consent(
+                state,
+                merchant_id,
+                request
+                    .payment_method_data
+                    .as_ref()
+                    .and_then(|payment_method_data| {
+                        payment_method_data.payment_method_data.as_ref()
+                    }),
+                request.setup_future_usage,
+                request.customer_acceptance.is_some()
+                    || request
+                        .mandate_data
+                        .as_ref()
+                        .is_some_and(|mandate_data| mandate_data.customer_acceptance.is_some()),
+            )
+            .await?;
+        }
+
         let customer_acceptance = request.customer_acceptance.clone().map(From::from);
 
         let recurring_details = request.recurring_details.clone();
@@ -136,9 +156,30 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         let customer_details = helpers::get_customer_details_from_request(request);
 
-        let shipping_address = helpers::create_or_find_address_for_payment_by_request(
+        let shipping = helpers::get_address_for_payment_create(
             db,
             request.shipping.as_ref(),
+            request.shipping_address_id.as_deref(),
+            "shipping",
+            merchant_id,
+            customer_details.customer_id.as_ref(),
+            merchant_key_store,
+        )
+        .await?;
+        let billing = helpers::get_address_for_payment_create(
+            db,
+            request.billing.as_ref(),
+            request.billing_address_id.as_deref(),
+            "billing",
+            merchant_id,
+            customer_details.customer_id.as_ref(),
+            merchant_key_store,
+        )
+        .await?;
+
+        let shipping_address = helpers::create_or_find_address_for_payment_by_request(
+            db,
+            shipping.as_ref(),
             None,
             merchant_id,
             customer_details.customer_id.as_ref(),
@@ -150,7 +191,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         let billing_address = helpers::create_or_find_address_for_payment_by_request(
             db,
-            request.billing.as_ref(),
+            billing.as_ref(),
             None,
             merchant_id,
             customer_details.customer_id.as_ref(),
@@ -449,6 +490,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             frm_metadata: request.frm_metadata.clone(),
             recurring_details,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -928,6 +975,7 @@ impl PaymentCreate {
                 fingerprint_id: None,
                 authentication_connector: None,
                 authentication_id: None,
+                connector_latency: None,
             },
             additional_pm_data,
         ))
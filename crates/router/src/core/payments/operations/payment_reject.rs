@@ -1,18 +1,24 @@
 use std::marker::PhantomData;
 
-use api_models::{enums::FrmSuggestion, payments::PaymentsCancelRequest};
+use api_models::{
+    enums::FrmSuggestion,
+    payments::{PaymentsCancelRequest, RejectDecision},
+};
 use async_trait::async_trait;
 use error_stack::ResultExt;
 use router_derive;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        mandate,
         payments::{helpers, operations, PaymentAddress, PaymentData},
     },
-    routes::{app::ReqState, AppState},
+    db::StorageInterface,
+    routes::{app::ReqState, metrics, AppState},
     services,
     types::{
         api::{self, PaymentIdTypeExt},
@@ -26,6 +32,130 @@ use crate::{
 #[operation(operations = "all", flow = "cancel")]
 pub struct PaymentReject;
 
+const PAYMENT_REJECT_NOTIFICATION_TAG: &str = "PAYMENT";
+
+const PAYMENT_REJECT_NOTIFICATION_NAME: &str = "PAYMENT_REJECT_NOTIFICATION";
+
+const PAYMENT_REJECT_NOTIFICATION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::PaymentRejectNotificationWorkflow;
+
+/// The tracking data of the process tracker task which notifies the customer about a rejected
+/// payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRejectNotificationTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+}
+
+/// Intent statuses from which a payment cannot be rejected. A forced reject is allowed for
+/// payments stuck in `Processing`, but never for payments which are already terminal.
+fn get_reject_not_allowed_statuses(force_reject: bool) -> Vec<enums::IntentStatus> {
+    let mut not_allowed_statuses = vec![
+        enums::IntentStatus::Cancelled,
+        enums::IntentStatus::Failed,
+        enums::IntentStatus::Succeeded,
+    ];
+    if !force_reject {
+        not_allowed_statuses.push(enums::IntentStatus::Processing);
+    }
+    not_allowed_statuses
+}
+
+/// The merchant decision recorded on the payment intent of a rejected payment
+fn get_reject_merchant_decision(reject_decision: RejectDecision) -> String {
+    enums::MerchantDecision::from(reject_decision).to_string()
+}
+
+/// The error code and message recorded on a rejected payment attempt. An explicit rejection reason
+/// takes precedence over the details of the fraud check, if any.
+fn get_reject_error_details(
+    rejection_reason: Option<String>,
+    frm_message: Option<&storage::FraudCheck>,
+) -> (Option<Option<String>>, Option<Option<String>>) {
+    match (rejection_reason, frm_message) {
+        (Some(rejection_reason), fraud_check) => (
+            fraud_check.map(|fraud_check| Some(fraud_check.frm_status.to_string())),
+            Some(Some(rejection_reason)),
+        ),
+        (None, Some(fraud_check)) => (
+            Some(Some(fraud_check.frm_status.to_string())),
+            Some(
+                fraud_check
+                    .frm_reason
+                    .as_ref()
+                    .map(|reason| reason.to_string()),
+            ),
+        ),
+        (None, None) => (None, None),
+    }
+}
+
+/// Whether the customer is to be notified about the rejection. An explicit choice of the merchant
+/// always wins, otherwise the customer is notified unless the fraud check flagged the payment as
+/// fraudulent.
+fn should_notify_customer_on_reject(
+    notify_customer: Option<bool>,
+    frm_message: Option<&storage::FraudCheck>,
+) -> bool {
+    let is_fraud = frm_message
+        .is_some_and(|fraud_check| fraud_check.frm_status == enums::FraudCheckStatus::Fraud);
+    notify_customer.unwrap_or(!is_fraud)
+}
+
+fn get_payment_reject_notification_task(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let tracking_data = PaymentRejectNotificationTrackingData {
+        merchant_id: payment_intent.merchant_id.clone(),
+        payment_id: payment_intent.payment_id.clone(),
+    };
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        PAYMENT_REJECT_NOTIFICATION_RUNNER,
+        PAYMENT_REJECT_NOTIFICATION_NAME,
+        &payment_intent.payment_id,
+        &payment_intent.merchant_id,
+    );
+
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYMENT_REJECT_NOTIFICATION_NAME,
+        PAYMENT_REJECT_NOTIFICATION_RUNNER,
+        [PAYMENT_REJECT_NOTIFICATION_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment reject notification process tracker task")
+}
+
+/// Enqueues a process tracker task to notify the customer about the rejected payment
+async fn add_payment_reject_notification_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let process_tracker_entry = get_payment_reject_notification_task(payment_intent)?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting payment reject notification to process_tracker: payment_id: {}",
+                payment_intent.payment_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "PaymentRejectNotification",
+        )],
+    );
+
+    Ok(())
+}
+
 #[async_trait]
 impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for PaymentReject {
     #[instrument(skip_all)]
@@ -33,7 +163,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
         &'a self,
         state: &'a AppState,
         payment_id: &api::PaymentIdType,
-        _request: &PaymentsCancelRequest,
+        request: &PaymentsCancelRequest,
         merchant_account: &domain::MerchantAccount,
         key_store: &domain::MerchantKeyStore,
         _auth_flow: services::AuthFlow,
@@ -53,12 +183,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
 
         helpers::validate_payment_status_against_not_allowed_statuses(
             &payment_intent.status,
-            &[
-                enums::IntentStatus::Cancelled,
-                enums::IntentStatus::Failed,
-                enums::IntentStatus::Succeeded,
-                enums::IntentStatus::Processing,
-            ],
+            &get_reject_not_allowed_statuses(request.force_reject),
             "reject",
         )?;
 
@@ -129,6 +254,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
                 id: profile_id.to_string(),
             })?;
 
+        let frm_message = frm_response.ok();
+        let should_notify_customer =
+            should_notify_customer_on_reject(request.notify_customer, frm_message.as_ref());
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -165,7 +294,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
             multiple_capture_data: None,
             redirect_response: None,
             surcharge_details: None,
-            frm_message: frm_response.ok(),
+            frm_message,
             payment_link_data: None,
             incremental_authorization_details: None,
             authorizations: vec![],
@@ -173,6 +302,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
             frm_metadata: None,
             recurring_details: None,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: request.rejection_reason.clone(),
+            reject_decision: request.reject_decision,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -207,19 +342,15 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, PaymentsCancelRequest> for Payme
     {
         let intent_status_update = storage::PaymentIntentUpdate::RejectUpdate {
             status: enums::IntentStatus::Failed,
-            merchant_decision: Some(enums::MerchantDecision::Rejected.to_string()),
+            merchant_decision: Some(get_reject_merchant_decision(
+                payment_data.reject_decision.unwrap_or_default(),
+            )),
             updated_by: storage_scheme.to_string(),
         };
-        let (error_code, error_message) =
-            payment_data
-                .frm_message
-                .clone()
-                .map_or((None, None), |fraud_check| {
-                    (
-                        Some(Some(fraud_check.frm_status.to_string())),
-                        Some(fraud_check.frm_reason.map(|reason| reason.to_string())),
-                    )
-                });
+        let (error_code, error_message) = get_reject_error_details(
+            payment_data.rejection_reason.clone(),
+            payment_data.frm_message.as_ref(),
+        );
         let attempt_status_update = storage::PaymentAttemptUpdate::RejectUpdate {
             status: enums::AttemptStatus::Failure,
             error_code,
@@ -247,6 +378,34 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, PaymentsCancelRequest> for Payme
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        // Release any debit reserved against the usage limits of a mandate for the payment
+        mandate::release_mandate_debit(
+            state,
+            &payment_data.payment_attempt.merchant_id,
+            &payment_data.payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::error!(mandate_debit_release_error=?error))
+        .ok();
+
+        if payment_data.should_notify_customer {
+            if cfg!(feature = "email") {
+                // The payment has already been rejected at this point, so a failure to enqueue the
+                // notification must not fail the request
+                add_payment_reject_notification_task(&*state.store, &payment_data.payment_intent)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to add payment reject notification task")
+                    })
+                    .ok();
+            } else {
+                logger::warn!(
+                    "Customer not notified about the rejected payment since the email feature is disabled"
+                );
+            }
+        }
+
         Ok((Box::new(self), payment_data))
     }
 }
@@ -272,3 +431,167 @@ impl<F: Send + Clone> ValidateRequest<F, PaymentsCancelRequest> for PaymentRejec
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_fraud_check() -> storage::FraudCheck {
+        storage::FraudCheck {
+            frm_id: "frm_id".to_string(),
+            payment_id: "pay_id".to_string(),
+            merchant_id: "merchant_id".to_string(),
+            attempt_id: "attempt_id".to_string(),
+            created_at: common_utils::date_time::now(),
+            frm_name: "signifyd".to_string(),
+            frm_transaction_id: None,
+            frm_transaction_type: enums::FraudCheckType::PreFrm,
+            frm_status: enums::FraudCheckStatus::Fraud,
+            frm_score: None,
+            frm_reason: Some(serde_json::json!("suspected_fraud")),
+            frm_error: None,
+            payment_details: None,
+            metadata: None,
+            modified_at: common_utils::date_time::now(),
+            last_step: enums::FraudCheckLastStep::CheckoutOrSale,
+            payment_capture_method: None,
+        }
+    }
+
+    #[test]
+    fn test_reject_notifies_customer_when_requested() {
+        let mut fraud_check = get_fraud_check();
+        fraud_check.frm_status = enums::FraudCheckStatus::ManualReview;
+
+        assert!(should_notify_customer_on_reject(Some(true), None));
+        assert!(should_notify_customer_on_reject(
+            Some(true),
+            Some(&fraud_check)
+        ));
+    }
+
+    #[test]
+    fn test_reject_does_not_notify_customer_when_suppressed() {
+        let fraud_check = get_fraud_check();
+
+        assert!(!should_notify_customer_on_reject(Some(false), None));
+        assert!(!should_notify_customer_on_reject(
+            Some(false),
+            Some(&fraud_check)
+        ));
+    }
+
+    #[test]
+    fn test_reject_notification_defaults() {
+        let mut fraud_check = get_fraud_check();
+
+        // Fraud rejections are not notified unless the merchant asks for it
+        assert!(should_notify_customer_on_reject(None, None));
+        assert!(!should_notify_customer_on_reject(None, Some(&fraud_check)));
+
+        // An explicit choice of the merchant wins over the default for fraud rejections
+        assert!(should_notify_customer_on_reject(
+            Some(true),
+            Some(&fraud_check)
+        ));
+
+        fraud_check.frm_status = enums::FraudCheckStatus::ManualReview;
+        assert!(should_notify_customer_on_reject(None, Some(&fraud_check)));
+    }
+
+    #[test]
+    fn test_reject_merchant_decision() {
+        assert_eq!(
+            get_reject_merchant_decision(RejectDecision::default()),
+            "rejected"
+        );
+        assert_eq!(
+            get_reject_merchant_decision(RejectDecision::ManualReviewDeclined),
+            "manual_review_declined"
+        );
+    }
+
+    #[test]
+    fn test_reject_error_details_from_rejection_reason() {
+        let (error_code, error_message) =
+            get_reject_error_details(Some("Customer not verified".to_string()), None);
+
+        assert_eq!(error_code, None);
+        assert_eq!(
+            error_message,
+            Some(Some("Customer not verified".to_string()))
+        );
+
+        let fraud_check = get_fraud_check();
+        let (error_code, error_message) = get_reject_error_details(
+            Some("Customer not verified".to_string()),
+            Some(&fraud_check),
+        );
+
+        assert_eq!(error_code, Some(Some("fraud".to_string())));
+        assert_eq!(
+            error_message,
+            Some(Some("Customer not verified".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_reject_error_details_fall_back_to_fraud_check() {
+        let fraud_check = get_fraud_check();
+        let (error_code, error_message) = get_reject_error_details(None, Some(&fraud_check));
+
+        assert_eq!(error_code, Some(Some("fraud".to_string())));
+        assert_eq!(error_message, Some(Some("\"suspected_fraud\"".to_string())));
+
+        assert_eq!(get_reject_error_details(None, None), (None, None));
+    }
+
+    #[test]
+    fn test_reject_from_processing_is_blocked_without_force() {
+        let not_allowed_statuses = get_reject_not_allowed_statuses(false);
+
+        assert!(
+            helpers::validate_payment_status_against_not_allowed_statuses(
+                &enums::IntentStatus::Processing,
+                &not_allowed_statuses,
+                "reject",
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_force_reject_from_processing_is_allowed() {
+        let not_allowed_statuses = get_reject_not_allowed_statuses(true);
+
+        assert!(
+            helpers::validate_payment_status_against_not_allowed_statuses(
+                &enums::IntentStatus::Processing,
+                &not_allowed_statuses,
+                "reject",
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_force_reject_from_terminal_statuses_is_blocked() {
+        let not_allowed_statuses = get_reject_not_allowed_statuses(true);
+
+        for status in [
+            enums::IntentStatus::Cancelled,
+            enums::IntentStatus::Failed,
+            enums::IntentStatus::Succeeded,
+        ] {
+            assert!(
+                helpers::validate_payment_status_against_not_allowed_statuses(
+                    &status,
+                    &not_allowed_statuses,
+                    "reject",
+                )
+                .is_err(),
+                "force reject should not be allowed from {status}"
+            );
+        }
+    }
+}
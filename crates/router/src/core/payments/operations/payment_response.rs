@@ -18,11 +18,12 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate, payment_methods,
         payments::{
+            auto_void,
             helpers::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            routing, tokenization,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -699,6 +700,27 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     }
 }
 
+/// Whether the attempt succeeded or failed with the connector, absent while the outcome is not known
+fn get_connector_payment_outcome(status: enums::AttemptStatus) -> Option<bool> {
+    match status {
+        enums::AttemptStatus::Charged
+        | enums::AttemptStatus::Authorized
+        | enums::AttemptStatus::PartialCharged => Some(true),
+        enums::AttemptStatus::Failure | enums::AttemptStatus::AuthorizationFailed => Some(false),
+        _ => None,
+    }
+}
+
+/// Whether the payment attempt has ended without the customer being debited
+fn is_failed_attempt_status(status: enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        enums::AttemptStatus::Failure
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::Voided
+    )
+}
+
 #[instrument(skip_all)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     state: &AppState,
@@ -725,6 +747,10 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .as_mut()
             .map(|info| info.status = status)
     });
+    let connector_latency = payments_helpers::get_connector_latency(
+        &payment_data.payment_attempt,
+        router_data.external_latency,
+    );
     let (capture_update, mut payment_attempt_update) = match router_data.response.clone() {
         Err(err) => {
             let (capture_update, attempt_update) = match payment_data.multiple_capture_data {
@@ -799,6 +825,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             unified_message: option_gsm.map(|gsm| gsm.unified_message),
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
+                            connector_latency,
                         }),
                     )
                 }
@@ -955,6 +982,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 authentication_data,
                                 encoded_data,
                                 payment_method_data: additional_payment_method_data,
+                                connector_latency,
                             }),
                         ),
                     };
@@ -986,6 +1014,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             error_reason: Some(reason.map(|cd| cd.message)),
                             connector_response_reference_id,
                             updated_by: storage_scheme.to_string(),
+                            connector_latency,
                         }),
                     )
                 }
@@ -1039,6 +1068,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     // Stage 1
 
     let payment_attempt = payment_data.payment_attempt.clone();
+    let previous_attempt_status = payment_attempt.status;
 
     let m_db = state.clone().store;
     let m_payment_attempt_update = payment_attempt_update.clone();
@@ -1078,6 +1108,38 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_attempt = payment_attempt;
 
+    // Release the debit reserved against the usage limits of the mandate once the payment fails or
+    // is voided. This also covers the sync and void flows, which do not carry the mandate details.
+    if !is_failed_attempt_status(previous_attempt_status)
+        && is_failed_attempt_status(payment_data.payment_attempt.status)
+    {
+        mandate::release_mandate_debit(
+            state,
+            &payment_data.payment_attempt.merchant_id,
+            &payment_data.payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::error!(mandate_debit_release_error=?error))
+        .ok();
+    }
+
+    // The outcome of the attempt feeds the success rates used by adaptive volume splits, recorded
+    // only once when the attempt reaches it
+    if let (None, Some(is_success), Some(connector)) = (
+        get_connector_payment_outcome(previous_attempt_status),
+        get_connector_payment_outcome(payment_data.payment_attempt.status),
+        payment_data.payment_attempt.connector.as_deref(),
+    ) {
+        routing::record_connector_payment_outcome(
+            state,
+            &payment_data.payment_attempt.merchant_id,
+            connector,
+            is_success,
+        )
+        .await;
+    }
+
     payment_data.authentication = match payment_data.authentication {
         Some(authentication) => {
             let authentication_update = storage::AuthenticationUpdate::PostAuthorizationUpdate {
@@ -1225,6 +1287,18 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .as_mut()
             .map(|info| info.status = status)
     });
+
+    if previous_attempt_status != enums::AttemptStatus::Authorized
+        && payment_data.payment_attempt.status == enums::AttemptStatus::Authorized
+    {
+        auto_void::add_auto_void_task_if_enabled(state, &payment_data.payment_attempt)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to schedule auto void of authorization")
+            })
+            .ok();
+    }
+
     Ok(payment_data)
 }
 
@@ -474,6 +474,12 @@ async fn get_tracker_for_sync<
         frm_metadata: None,
         recurring_details: None,
         poll_config: None,
+        capture_reference: None,
+        should_notify_customer: false,
+        routing_explanation: None,
+        customer_decline_message: None,
+        rejection_reason: None,
+        reject_decision: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -75,11 +75,7 @@ impl<F: Send + Clone>
             })?
         }
 
-        if request.amount < payment_intent.amount {
-            Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "Amount should be greater than original authorized amount".to_owned(),
-            })?
-        }
+        helpers::validate_incremental_authorization_amount(payment_intent.amount, request.amount)?;
 
         let attempt_id = payment_intent.active_attempt.get_id().clone();
         let payment_attempt = db
@@ -154,6 +150,12 @@ impl<F: Send + Clone>
             frm_metadata: None,
             recurring_details: None,
             poll_config: None,
+            capture_reference: None,
+            should_notify_customer: false,
+            routing_explanation: None,
+            customer_decline_message: None,
+            rejection_reason: None,
+            reject_decision: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1,6 +1,7 @@
-use std::{str::FromStr, vec::IntoIter};
+use std::{collections::HashSet, str::FromStr, vec::IntoIter};
 
-use common_utils::ext_traits::Encode;
+use api_models::routing::{ConnectorErrorFallback, RoutableConnectorChoice};
+use common_utils::ext_traits::{Encode, ValueExt};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use router_env::{
@@ -11,11 +12,13 @@ use router_env::{
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        gsm, mandate,
         payments::{
-            self,
+            self, connector_health,
             flows::{ConstructFlowSpecificData, Feature},
-            operations,
+            operations, routing,
         },
+        routing::TransactionData,
     },
     db::StorageInterface,
     routes::{
@@ -34,7 +37,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData>(
     state: &app::AppState,
     req_state: ReqState,
     payment_data: &mut payments::PaymentData<F>,
-    mut connectors: IntoIter<api::ConnectorData>,
+    connectors: IntoIter<api::ConnectorData>,
     original_connector_data: api::ConnectorData,
     mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     merchant_account: &domain::MerchantAccount,
@@ -45,6 +48,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData>(
     schedule_time: Option<time::PrimitiveDateTime>,
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
     business_profile: &storage::business_profile::BusinessProfile,
+    eligible_connectors: Option<&Vec<api_models::enums::RoutableConnectors>>,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     F: Clone + Send + Sync,
@@ -56,6 +60,11 @@ where
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
     let mut retries = None;
+    let mut connectors: Vec<_> = connectors.collect();
+    let mut tried_connectors = vec![(
+        original_connector_data.connector_name,
+        original_connector_data.merchant_connector_id.clone(),
+    )];
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
@@ -102,6 +111,30 @@ where
     }
     // Step up is not applicable so proceed with auto retries flow
     else {
+        let connector_error_fallback =
+            get_connector_error_fallback(if cfg!(feature = "business_profile_routing") {
+                business_profile.routing_algorithm.clone()
+            } else {
+                merchant_account.routing_algorithm.clone()
+            })?;
+        let fallback_only_connectors = match connector_error_fallback.as_ref() {
+            Some(connector_error_fallback) => {
+                get_fallback_only_connectors(
+                    state,
+                    merchant_account,
+                    key_store,
+                    business_profile,
+                    payment_data,
+                    connector_error_fallback,
+                    &connectors,
+                    &original_connector_data,
+                    eligible_connectors,
+                )
+                .await?
+            }
+            None => Vec::new(),
+        };
+
         loop {
             // Use initial_gsm for first time alone
             let gsm = match initial_gsm.as_ref() {
@@ -109,8 +142,15 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
-            match get_gsm_decision(gsm) {
-                api_models::gsm::GsmDecision::Retry => {
+            let retry_metadata = get_gsm_retry_metadata(gsm.as_ref());
+            logger::info!(
+                retry_reason = %retry_metadata.retry_reason,
+                suggested_action = %retry_metadata.suggested_action,
+                "auto retry decision"
+            );
+
+            match retry_metadata.suggested_action {
+                api_models::gsm::GsmSuggestedAction::RetryAlternateConnector => {
                     retries = get_retries(state, retries, &merchant_account.merchant_id).await;
 
                     if retries.is_none() || retries == Some(0) {
@@ -119,13 +159,16 @@ where
                         break;
                     }
 
-                    if connectors.len() == 0 {
+                    let Some(connector) = get_next_retry_connector(
+                        &mut connectors,
+                        &fallback_only_connectors,
+                        &mut tried_connectors,
+                        connector_error_fallback.as_ref(),
+                    ) else {
                         logger::info!("connectors exhausted for auto_retry payment");
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
                         break;
-                    }
-
-                    let connector = super::get_connector_data(&mut connectors)?;
+                    };
 
                     router_data = do_retry(
                         &state.clone(),
@@ -148,14 +191,14 @@ where
 
                     retries = retries.map(|i| i - 1);
                 }
-                api_models::gsm::GsmDecision::Requeue => {
+                api_models::gsm::GsmSuggestedAction::RetrySameConnector => {
                     Err(report!(errors::ApiErrorResponse::NotImplemented {
                         message: errors::api_error_response::NotImplementedMessage::Reason(
                             "Requeue not implemented".to_string(),
                         ),
                     }))?
                 }
-                api_models::gsm::GsmDecision::DoDefault => break,
+                api_models::gsm::GsmSuggestedAction::DoNotRetry => break,
             }
             initial_gsm = None;
         }
@@ -187,6 +230,207 @@ pub async fn is_step_up_enabled_for_merchant_connector(
         .unwrap_or(false)
 }
 
+/// Reads the connector error fallback from the routing algorithm reference of the business
+/// profile, or of the merchant account when routing is not configured per business profile
+pub fn get_connector_error_fallback(
+    routing_algorithm: Option<serde_json::Value>,
+) -> RouterResult<Option<ConnectorErrorFallback>> {
+    Ok(routing_algorithm
+        .map(|routing_algorithm| {
+            routing_algorithm
+                .parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm ref")?
+        .and_then(|algorithm_ref| algorithm_ref.connector_error_fallback))
+}
+
+/// Returns the connectors configured as fallbacks which are neither the connector chosen by
+/// routing nor one of the routed connectors
+fn get_fallback_only_connector_names(
+    connector_error_fallback: &ConnectorErrorFallback,
+    routed_connectors: &[api::ConnectorData],
+    original_connector_data: &api::ConnectorData,
+) -> HashSet<String> {
+    connector_error_fallback
+        .0
+        .values()
+        .flatten()
+        .map(ToString::to_string)
+        .filter(|fallback_connector| {
+            std::iter::once(original_connector_data)
+                .chain(routed_connectors)
+                .all(|connector| connector.connector_name.to_string() != *fallback_connector)
+        })
+        .collect()
+}
+
+/// Builds the connectors which are configured as fallbacks but were not chosen by routing, from
+/// the enabled connector accounts of the business profile, so that they can still be retried with.
+/// They go through the same eligibility analysis and health check as the routed connectors.
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn get_fallback_only_connectors<F: Clone>(
+    state: &app::AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_data: &mut payments::PaymentData<F>,
+    connector_error_fallback: &ConnectorErrorFallback,
+    routed_connectors: &[api::ConnectorData],
+    original_connector_data: &api::ConnectorData,
+    eligible_connectors: Option<&Vec<api_models::enums::RoutableConnectors>>,
+) -> RouterResult<Vec<api::ConnectorData>> {
+    let fallback_connector_names = get_fallback_only_connector_names(
+        connector_error_fallback,
+        routed_connectors,
+        original_connector_data,
+    );
+
+    if fallback_connector_names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let fallback_connector_choices = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts for connector fallback")?
+        .into_iter()
+        .filter(|merchant_connector_account| {
+            merchant_connector_account.profile_id.as_ref() == Some(&business_profile.profile_id)
+                && fallback_connector_names.contains(&merchant_connector_account.connector_name)
+        })
+        .map(|merchant_connector_account| {
+            Ok(RoutableConnectorChoice {
+                #[cfg(feature = "backwards_compatibility")]
+                choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+                connector: merchant_connector_account
+                    .connector_name
+                    .parse::<api_models::enums::RoutableConnectors>()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Invalid connector name in fallback connector account")?,
+                #[cfg(feature = "connector_choice_mca_id")]
+                merchant_connector_id: Some(merchant_connector_account.merchant_connector_id),
+                #[cfg(not(feature = "connector_choice_mca_id"))]
+                sub_label: merchant_connector_account.business_sub_label,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    #[cfg(feature = "business_profile_routing")]
+    let profile_id = payment_data.payment_intent.profile_id.clone();
+    let eligible_fallback_connectors = routing::perform_eligibility_analysis(
+        state,
+        key_store,
+        merchant_account.modified_at.assume_utc().unix_timestamp(),
+        fallback_connector_choices,
+        &TransactionData::Payment(payment_data),
+        eligible_connectors,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed eligibility analysis of fallback connectors")?;
+
+    connector_health::exclude_unhealthy_connectors(state, eligible_fallback_connectors)
+        .await
+        .into_iter()
+        .map(|connector_choice| {
+            api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &connector_choice.connector.to_string(),
+                api::GetToken::Connector,
+                #[cfg(feature = "connector_choice_mca_id")]
+                connector_choice.merchant_connector_id,
+                #[cfg(not(feature = "connector_choice_mca_id"))]
+                None,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector name received")
+}
+
+/// Picks the connector to retry the payment with after the last of `tried_connectors` returned an
+/// error, and records it as tried. Connectors in the fallback list configured for the failed
+/// connector are preferred, in order, over the remaining routed connectors. A fallback connector
+/// which was not chosen by routing is picked from `fallback_only_connectors`, and only ever when
+/// it is configured for the failed connector. Connectors which have already been tried are never
+/// picked again, so that fallback lists referencing each other cannot make the payment loop.
+pub fn get_next_retry_connector(
+    connectors: &mut Vec<api::ConnectorData>,
+    fallback_only_connectors: &[api::ConnectorData],
+    tried_connectors: &mut Vec<(types::Connector, Option<String>)>,
+    connector_error_fallback: Option<&ConnectorErrorFallback>,
+) -> Option<api::ConnectorData> {
+    let next_connector = pick_next_retry_connector(
+        connectors,
+        fallback_only_connectors,
+        tried_connectors,
+        connector_error_fallback,
+    )?;
+    tried_connectors.push((
+        next_connector.connector_name,
+        next_connector.merchant_connector_id.clone(),
+    ));
+    Some(next_connector)
+}
+
+fn pick_next_retry_connector(
+    connectors: &mut Vec<api::ConnectorData>,
+    fallback_only_connectors: &[api::ConnectorData],
+    tried_connectors: &[(types::Connector, Option<String>)],
+    connector_error_fallback: Option<&ConnectorErrorFallback>,
+) -> Option<api::ConnectorData> {
+    connectors.retain(|connector| {
+        !tried_connectors.contains(&(
+            connector.connector_name,
+            connector.merchant_connector_id.clone(),
+        ))
+    });
+
+    let fallback_connectors = tried_connectors
+        .last()
+        .and_then(|(failed_connector, _)| {
+            api_models::enums::RoutableConnectors::from_str(&failed_connector.to_string()).ok()
+        })
+        .zip(connector_error_fallback)
+        .map(|(failed_connector, connector_error_fallback)| {
+            connector_error_fallback.get_fallback_connectors(&failed_connector)
+        })
+        .unwrap_or_default();
+
+    for fallback_connector in fallback_connectors {
+        let is_fallback_connector = |connector: &api::ConnectorData| {
+            connector.connector_name.to_string() == fallback_connector.to_string()
+        };
+
+        if let Some(position) = connectors.iter().position(is_fallback_connector) {
+            return Some(connectors.remove(position));
+        }
+
+        if let Some(connector) = fallback_only_connectors.iter().find(|connector| {
+            is_fallback_connector(connector)
+                && !tried_connectors.contains(&(
+                    connector.connector_name,
+                    connector.merchant_connector_id.clone(),
+                ))
+        }) {
+            return Some(connector.clone());
+        }
+    }
+
+    (!connectors.is_empty()).then(|| connectors.remove(0))
+}
+
 #[instrument(skip_all)]
 pub async fn get_retries(
     state: &app::AppState,
@@ -234,25 +478,17 @@ pub async fn get_gsm<F, FData>(
 }
 
 #[instrument(skip_all)]
-pub fn get_gsm_decision(
-    option_gsm: Option<storage::gsm::GatewayStatusMap>,
-) -> api_models::gsm::GsmDecision {
-    let option_gsm_decision = option_gsm
-            .and_then(|gsm| {
-                api_models::gsm::GsmDecision::from_str(gsm.decision.as_str())
-                    .map_err(|err| {
-                        let api_error = report!(err).change_context(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable("gsm decision parsing failed");
-                        logger::warn!(get_gsm_decision_parse_error=?api_error, "error fetching gsm decision");
-                        api_error
-                    })
-                    .ok()
-            });
-
-    if option_gsm_decision.is_some() {
+pub fn get_gsm_retry_metadata(
+    option_gsm: Option<&storage::gsm::GatewayStatusMap>,
+) -> api_models::gsm::GsmRetryMetadata {
+    let retry_metadata = gsm::get_gsm_retry_metadata(option_gsm);
+
+    if option_gsm.is_some()
+        && retry_metadata.retry_reason != api_models::gsm::GsmRetryReason::InvalidGsmDecision
+    {
         metrics::AUTO_RETRY_GSM_MATCH_COUNT.add(&metrics::CONTEXT, 1, &[]);
     }
-    option_gsm_decision.unwrap_or_default()
+    retry_metadata
 }
 
 #[inline]
@@ -355,6 +591,11 @@ where
                 .and_then(|connector_response| connector_response.additional_payment_method_data),
         )?;
 
+    let connector_latency = payments::helpers::get_connector_latency(
+        &payment_data.payment_attempt,
+        router_data.external_latency,
+    );
+
     match router_data.response {
         Ok(types::PaymentsResponseData::TransactionResponse {
             resource_id,
@@ -407,6 +648,7 @@ where
                     unified_code: None,
                     unified_message: None,
                     payment_method_data: additional_payment_method_data,
+                    connector_latency,
                 },
                 storage_scheme,
             )
@@ -434,6 +676,7 @@ where
                     unified_message: option_gsm.map(|gsm| gsm.unified_message),
                     connector_transaction_id: error_response.connector_transaction_id.clone(),
                     payment_method_data: additional_payment_method_data,
+                    connector_latency,
                 },
                 storage_scheme,
             )
@@ -442,6 +685,18 @@ where
         }
     }
 
+    // The debit reserved against the usage limits of a mandate for the failed attempt is released.
+    // The limits are checked again when the debit is reserved for the new attempt.
+    mandate::release_mandate_debit(
+        state,
+        &payment_data.payment_attempt.merchant_id,
+        &payment_data.payment_attempt.attempt_id,
+        storage_scheme,
+    )
+    .await
+    .map_err(|error| logger::error!(mandate_debit_release_error=?error))
+    .ok();
+
     let payment_attempt = db
         .insert_payment_attempt(new_payment_attempt, storage_scheme)
         .await
@@ -576,3 +831,164 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use api_models::enums::RoutableConnectors;
+
+    use super::*;
+    use crate::configs::settings::Connectors;
+
+    fn get_connectors(connector_names: &[&str]) -> Vec<api::ConnectorData> {
+        connector_names
+            .iter()
+            .map(|connector_name| {
+                api::ConnectorData::get_connector_by_name(
+                    &Connectors::default(),
+                    connector_name,
+                    api::GetToken::Connector,
+                    None,
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_failed_connector_falls_back_to_configured_connector() {
+        let connector_error_fallback = ConnectorErrorFallback(HashMap::from([(
+            RoutableConnectors::Stripe,
+            vec![RoutableConnectors::Checkout],
+        )]));
+        // Routing chose stripe, followed by adyen and checkout
+        let mut connectors = get_connectors(&["adyen", "checkout"]);
+        let mut tried_connectors = vec![(types::Connector::Stripe, None)];
+
+        // Stripe errors, so the payment is retried with checkout which succeeds
+        let next_connector = get_next_retry_connector(
+            &mut connectors,
+            &[],
+            &mut tried_connectors,
+            Some(&connector_error_fallback),
+        )
+        .unwrap();
+
+        assert_eq!(next_connector.connector_name, types::Connector::Checkout);
+        assert_eq!(connectors.len(), 1);
+        assert_eq!(
+            tried_connectors.last(),
+            Some(&(types::Connector::Checkout, None))
+        );
+    }
+
+    #[test]
+    fn test_already_tried_connectors_are_not_retried() {
+        let connector_error_fallback = ConnectorErrorFallback(HashMap::from([
+            (RoutableConnectors::Stripe, vec![RoutableConnectors::Adyen]),
+            (RoutableConnectors::Adyen, vec![RoutableConnectors::Stripe]),
+        ]));
+        let mut connectors = get_connectors(&["stripe", "adyen"]);
+        let mut tried_connectors = vec![(types::Connector::Stripe, None)];
+
+        let next_connector = get_next_retry_connector(
+            &mut connectors,
+            &[],
+            &mut tried_connectors,
+            Some(&connector_error_fallback),
+        )
+        .unwrap();
+        assert_eq!(next_connector.connector_name, types::Connector::Adyen);
+
+        // Adyen falls back to stripe, which has already been tried
+        assert!(get_next_retry_connector(
+            &mut connectors,
+            &[],
+            &mut tried_connectors,
+            Some(&connector_error_fallback),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_retry_path_when_every_connector_errors() {
+        let connector_error_fallback = ConnectorErrorFallback(HashMap::from([(
+            RoutableConnectors::Stripe,
+            vec![RoutableConnectors::Checkout],
+        )]));
+        // Routing chose stripe, followed by adyen, checkout is only configured as a fallback
+        let mut connectors = get_connectors(&["adyen"]);
+        let fallback_only_connectors = get_connectors(&["checkout"]);
+        let mut tried_connectors = vec![(types::Connector::Stripe, None)];
+
+        // Every retried connector errors as well, until no connector is left to retry with
+        let mut retried_connectors = Vec::new();
+        while let Some(connector) = get_next_retry_connector(
+            &mut connectors,
+            &fallback_only_connectors,
+            &mut tried_connectors,
+            Some(&connector_error_fallback),
+        ) {
+            retried_connectors.push(connector.connector_name);
+        }
+
+        // Checkout is retried as the fallback of stripe, adyen as the next routed connector, and
+        // connectors only configured as a fallback are not retried otherwise
+        assert_eq!(
+            retried_connectors,
+            vec![types::Connector::Checkout, types::Connector::Adyen]
+        );
+        assert_eq!(tried_connectors.len(), 3);
+    }
+
+    #[test]
+    fn test_fallback_only_connectors_exclude_routed_connectors() {
+        let connector_error_fallback = ConnectorErrorFallback(HashMap::from([
+            (
+                RoutableConnectors::Stripe,
+                vec![RoutableConnectors::Adyen, RoutableConnectors::Checkout],
+            ),
+            (RoutableConnectors::Adyen, vec![RoutableConnectors::Stripe]),
+        ]));
+        let original_connector = get_connectors(&["stripe"]).remove(0);
+
+        let fallback_only_connector_names = get_fallback_only_connector_names(
+            &connector_error_fallback,
+            &get_connectors(&["adyen"]),
+            &original_connector,
+        );
+
+        assert_eq!(
+            fallback_only_connector_names,
+            HashSet::from(["checkout".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_connector_error_fallback_is_read_from_routing_algorithm_ref() {
+        let routing_algorithm = serde_json::json!({
+            "algorithm_id": "routing_1",
+            "timestamp": 0,
+            "config_algo_id": null,
+            "surcharge_config_algo_id": null,
+            "connector_error_fallback": { "stripe": ["checkout"] }
+        });
+
+        let connector_error_fallback = get_connector_error_fallback(Some(routing_algorithm))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            connector_error_fallback.get_fallback_connectors(&RoutableConnectors::Stripe),
+            &[RoutableConnectors::Checkout]
+        );
+
+        // Routing algorithm refs stored before the fallback was configured have none
+        let routing_algorithm = serde_json::json!({ "algorithm_id": null, "timestamp": 0 });
+        assert!(get_connector_error_fallback(Some(routing_algorithm))
+            .unwrap()
+            .is_none());
+        assert!(get_connector_error_fallback(None).unwrap().is_none());
+    }
+}
@@ -1,10 +1,6 @@
 mod transformers;
 
-use std::{
-    collections::hash_map,
-    hash::{Hash, Hasher},
-    sync::Arc,
-};
+use std::sync::Arc;
 
 use api_models::{
     admin as admin_api,
@@ -12,7 +8,7 @@ use api_models::{
     payments::Address,
     routing::ConnectorSelection,
 };
-use common_utils::static_cache::StaticCache;
+use common_utils::{errors::CustomResult, static_cache::StaticCache};
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
 use euclid::{
@@ -26,10 +22,8 @@ use kgraph_utils::{
     transformers::{IntoContext, IntoDirValue},
 };
 use masking::PeekInterface;
-use rand::{
-    distributions::{self, Distribution},
-    SeedableRng,
-};
+use rand::distributions::{self, Distribution};
+use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
 
 #[cfg(feature = "payouts")]
@@ -37,6 +31,7 @@ use crate::core::payouts;
 #[cfg(not(feature = "business_profile_routing"))]
 use crate::utils::StringExt;
 use crate::{
+    configs::settings::AdaptiveVolumeSplitConfig,
     core::{
         errors, errors as oss_errors, payments as payments_oss,
         routing::{self, helpers as routing_helpers},
@@ -71,7 +66,7 @@ pub struct SessionRoutingPmTypeInput<'a> {
     state: &'a AppState,
     key_store: &'a domain::MerchantKeyStore,
     merchant_last_modified: i64,
-    attempt_id: &'a str,
+    payment_id: &'a str,
     routing_algorithm: &'a MerchantAccountRoutingAlgorithm,
     backend_input: dsl_inputs::BackendInput,
     allowed_connectors: FxHashMap<String, api::GetToken>,
@@ -264,12 +259,50 @@ where
     })
 }
 
+/// Builds the DSL input from the sample payment context of a routing evaluation request
+pub fn make_dsl_input_for_routing_evaluation(
+    request: &routing_types::RoutingEvaluateRequest,
+) -> dsl_inputs::BackendInput {
+    dsl_inputs::BackendInput {
+        metadata: request
+            .metadata
+            .clone()
+            .map(|metadata| metadata.into_iter().collect()),
+        payment: dsl_inputs::PaymentInput {
+            amount: request.amount,
+            currency: request.currency,
+            authentication_type: request.authentication_type,
+            card_bin: request.card_bin.clone(),
+            capture_method: request.capture_method,
+            business_country: request
+                .business_country
+                .map(api_enums::Country::from_alpha2),
+            billing_country: request.billing_country.map(api_enums::Country::from_alpha2),
+            business_label: request.business_label.clone(),
+            setup_future_usage: request.setup_future_usage,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: request.payment_method,
+            payment_method_type: request.payment_method_type,
+            card_network: request.card_network.clone(),
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: Some(euclid_enums::PaymentType::NonMandate),
+        },
+    }
+}
+
 pub async fn perform_static_routing_v1<F: Clone>(
     state: &AppState,
     merchant_id: &str,
     algorithm_ref: routing_types::RoutingAlgorithmRef,
     transaction_data: &routing::TransactionData<'_, F>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    routing_types::RoutingExplanation,
+)> {
     #[cfg(any(
         feature = "profile_specific_fallback_routing",
         feature = "business_profile_routing"
@@ -284,6 +317,48 @@ pub async fn perform_static_routing_v1<F: Clone>(
         #[cfg(feature = "payouts")]
         routing::TransactionData::Payout(payout_data) => &payout_data.payout_attempt.profile_id,
     };
+
+    perform_routing_with_algorithm_ref_v1(
+        state,
+        merchant_id,
+        algorithm_ref,
+        #[cfg(any(
+            feature = "profile_specific_fallback_routing",
+            feature = "business_profile_routing"
+        ))]
+        profile_id,
+        &api_enums::TransactionType::from(transaction_data),
+        || match transaction_data {
+            routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data),
+            #[cfg(feature = "payouts")]
+            routing::TransactionData::Payout(payout_data) => {
+                make_dsl_input_for_payouts(payout_data)
+            }
+        },
+        Some(get_volume_split_seed(transaction_data)),
+    )
+    .await
+}
+
+/// Performs routing with the routing algorithm referenced by `algorithm_ref`, falling back to the
+/// default fallback list when no algorithm is active. The DSL input is only built when the active
+/// algorithm is an advanced one.
+pub async fn perform_routing_with_algorithm_ref_v1(
+    state: &AppState,
+    merchant_id: &str,
+    algorithm_ref: routing_types::RoutingAlgorithmRef,
+    #[cfg(any(
+        feature = "profile_specific_fallback_routing",
+        feature = "business_profile_routing"
+    ))]
+    profile_id: &str,
+    transaction_type: &api_enums::TransactionType,
+    get_backend_input: impl FnOnce() -> RoutingResult<dsl_inputs::BackendInput>,
+    rng_seed: Option<&str>,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    routing_types::RoutingExplanation,
+)> {
     let algorithm_id = if let Some(id) = algorithm_ref.algorithm_id {
         id
     } else {
@@ -293,12 +368,15 @@ pub async fn perform_static_routing_v1<F: Clone>(
             merchant_id,
             #[cfg(feature = "profile_specific_fallback_routing")]
             profile_id,
-            &api_enums::TransactionType::from(transaction_data),
+            transaction_type,
         )
         .await
         .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        return Ok(fallback_config);
+        return Ok((
+            fallback_config,
+            get_routing_explanation(routing_types::RoutingDecisionSource::DefaultFallback, None),
+        ));
     };
     let key = ensure_algorithm_cached_v1(
         state,
@@ -306,8 +384,8 @@ pub async fn perform_static_routing_v1<F: Clone>(
         algorithm_ref.timestamp,
         &algorithm_id,
         #[cfg(feature = "business_profile_routing")]
-        Some(profile_id).cloned(),
-        &api_enums::TransactionType::from(transaction_data),
+        Some(profile_id.to_string()),
+        transaction_type,
     )
     .await?;
     let cached_algorithm: Arc<CachedAlgorithm> = ROUTING_CACHE
@@ -316,27 +394,126 @@ pub async fn perform_static_routing_v1<F: Clone>(
         .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
 
     Ok(match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+        CachedAlgorithm::Single(conn) => (
+            vec![(**conn).clone()],
+            get_routing_explanation(
+                routing_types::RoutingDecisionSource::MerchantAlgorithm,
+                Some(routing_types::RoutingAlgorithmKind::Single),
+            ),
+        ),
 
-        CachedAlgorithm::Priority(plist) => plist.clone(),
+        CachedAlgorithm::Priority(plist) => (
+            plist.clone(),
+            get_routing_explanation(
+                routing_types::RoutingDecisionSource::MerchantAlgorithm,
+                Some(routing_types::RoutingAlgorithmKind::Priority),
+            ),
+        ),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => {
+            let splits = get_adaptive_volume_splits(state, merchant_id, splits.to_vec()).await;
+            (
+                perform_volume_split(splits.clone(), rng_seed)
+                    .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+                routing_types::RoutingExplanation {
+                    volume_split: Some(splits),
+                    ..get_routing_explanation(
+                        routing_types::RoutingDecisionSource::MerchantAlgorithm,
+                        Some(routing_types::RoutingAlgorithmKind::VolumeSplit),
+                    )
+                },
+            )
+        }
 
         CachedAlgorithm::Advanced(interpreter) => {
-            let backend_input = match transaction_data {
-                routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
-                #[cfg(feature = "payouts")]
-                routing::TransactionData::Payout(payout_data) => {
-                    make_dsl_input_for_payouts(payout_data)?
-                }
-            };
-
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            execute_dsl_and_get_connector_v1(get_backend_input()?, interpreter, rng_seed)?
         }
     })
 }
 
+/// The payment (or payout) id is used as the seed for volume split routing, so that all attempts
+/// of a transaction are routed to the same connector
+fn get_volume_split_seed<'a, F: Clone>(
+    transaction_data: &'a routing::TransactionData<'_, F>,
+) -> &'a str {
+    match transaction_data {
+        routing::TransactionData::Payment(payment_data) => &payment_data.payment_intent.payment_id,
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => &payout_data.payouts.payout_id,
+    }
+}
+
+fn get_routing_explanation(
+    routing_source: routing_types::RoutingDecisionSource,
+    algorithm_kind: Option<routing_types::RoutingAlgorithmKind>,
+) -> routing_types::RoutingExplanation {
+    routing_types::RoutingExplanation {
+        routing_source,
+        algorithm_kind,
+        matched_rule: None,
+        volume_split: None,
+        excluded_connectors: Vec::new(),
+        fallback_connectors: Vec::new(),
+    }
+}
+
+pub fn get_straight_through_routing_explanation(
+    algorithm: &routing_types::StraightThroughAlgorithm,
+) -> routing_types::RoutingExplanation {
+    let (algorithm_kind, volume_split) = match algorithm {
+        routing_types::StraightThroughAlgorithm::Single(_) => {
+            (routing_types::RoutingAlgorithmKind::Single, None)
+        }
+        routing_types::StraightThroughAlgorithm::Priority(_) => {
+            (routing_types::RoutingAlgorithmKind::Priority, None)
+        }
+        routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
+            routing_types::RoutingAlgorithmKind::VolumeSplit,
+            Some(splits.clone()),
+        ),
+    };
+
+    routing_types::RoutingExplanation {
+        volume_split,
+        ..get_routing_explanation(
+            routing_types::RoutingDecisionSource::StraightThrough,
+            Some(algorithm_kind),
+        )
+    }
+}
+
+/// Records the connectors removed by the eligibility analysis and the ones appended from the
+/// default fallback list, by comparing the routed connectors with the final selection.
+pub fn add_eligibility_analysis_to_routing_explanation(
+    explanation: &mut routing_types::RoutingExplanation,
+    routed_connectors: &[routing_types::RoutableConnectorChoice],
+    final_selection: &[routing_types::RoutableConnectorChoice],
+) {
+    explanation.excluded_connectors = routed_connectors
+        .iter()
+        .filter(|connector| !final_selection.contains(connector))
+        .cloned()
+        .collect();
+    explanation.fallback_connectors = final_selection
+        .iter()
+        .filter(|connector| !routed_connectors.contains(connector))
+        .cloned()
+        .collect();
+}
+
+pub async fn is_routing_explanation_enabled(state: &AppState, merchant_id: &str) -> bool {
+    let key = format!("routing_explanation_enabled_{merchant_id}");
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => config.config == "true",
+        Err(error) => {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(routing_explanation_config_error=?error);
+            }
+            false
+        }
+    }
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &AppState,
     merchant_id: &str,
@@ -399,9 +576,52 @@ async fn ensure_algorithm_cached_v1(
     Ok(key)
 }
 
+/// Returns the connectors that the routing algorithm referenced by `algorithm_ref` can route a
+/// payment to. `None` is returned when no algorithm is active, or when the active algorithm is an
+/// advanced one, whose output depends on the payment method of the payment.
+pub async fn get_connectors_from_algorithm_ref_v1(
+    state: &AppState,
+    merchant_id: &str,
+    algorithm_ref: routing_types::RoutingAlgorithmRef,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+    transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<Option<Vec<routing_types::RoutableConnectorChoice>>> {
+    let Some(algorithm_id) = algorithm_ref.algorithm_id else {
+        return Ok(None);
+    };
+    let key = ensure_algorithm_cached_v1(
+        state,
+        merchant_id,
+        algorithm_ref.timestamp,
+        &algorithm_id,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+        transaction_type,
+    )
+    .await?;
+    let cached_algorithm: Arc<CachedAlgorithm> = ROUTING_CACHE
+        .retrieve(&key)
+        .change_context(errors::RoutingError::CacheMiss)
+        .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
+
+    Ok(match cached_algorithm.as_ref() {
+        CachedAlgorithm::Single(conn) => Some(vec![(**conn).clone()]),
+        CachedAlgorithm::Priority(plist) => Some(plist.clone()),
+        CachedAlgorithm::VolumeSplit(splits) => Some(
+            splits
+                .iter()
+                .filter(|split| split.split > 0)
+                .map(|split| split.connector.clone())
+                .collect(),
+        ),
+        CachedAlgorithm::Advanced(_) => None,
+    })
+}
+
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<String>,
+    rng_seed: Option<&str>,
 ) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     Ok(match algorithm {
         routing_types::StraightThroughAlgorithm::Single(conn) => {
@@ -411,7 +631,7 @@ pub fn perform_straight_through_routing(
         routing_types::StraightThroughAlgorithm::Priority(conns) => (conns.clone(), true),
 
         routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec(), None)
+            perform_volume_split(splits.to_vec(), rng_seed)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)
                 .attach_printable(
                     "Volume Split connector selection error in straight through routing",
@@ -424,17 +644,36 @@ pub fn perform_straight_through_routing(
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    rng_seed: Option<&str>,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    routing_types::RoutingExplanation,
+)> {
+    let backend_output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
+    let routing_output: routing_types::RoutingAlgorithm =
+        backend_output.connector_selection.foreign_into();
+
+    let explanation = routing_types::RoutingExplanation {
+        matched_rule: backend_output.rule_name,
+        ..get_routing_explanation(
+            routing_types::RoutingDecisionSource::MerchantAlgorithm,
+            Some(routing_types::RoutingAlgorithmKind::Advanced),
+        )
+    };
 
     Ok(match routing_output {
-        routing_types::RoutingAlgorithm::Priority(plist) => plist,
-
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::RoutingAlgorithm::Priority(plist) => (plist, explanation),
+
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.clone(), rng_seed)
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+            routing_types::RoutingExplanation {
+                volume_split: Some(splits),
+                ..explanation
+            },
+        ),
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
@@ -504,22 +743,297 @@ pub async fn refresh_routing_cache_v1(
     Ok(())
 }
 
+fn get_connector_outcome_keys(merchant_id: &str, connector: &str) -> (String, String) {
+    (
+        format!("adaptive_volume_split_{merchant_id}_{connector}_attempts"),
+        format!("adaptive_volume_split_{merchant_id}_{connector}_successes"),
+    )
+}
+
+async fn increment_connector_outcome_count(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+    window_in_secs: i64,
+) -> CustomResult<(), redis_interface::errors::RedisError> {
+    // The window starts with the first outcome recorded after the previous window expired
+    if redis_conn.increment_key(key).await? == 1 {
+        redis_conn.set_expiry(key, window_in_secs).await?;
+    }
+    Ok(())
+}
+
+/// Records the final outcome of a payment attempt with the connector, from which the success rate
+/// used by adaptive volume splits is derived
+#[instrument(skip_all)]
+pub async fn record_connector_payment_outcome(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    is_success: bool,
+) {
+    let config = &state.conf.adaptive_volume_split;
+    if !config.enabled {
+        return;
+    }
+
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(adaptive_volume_split_redis_error=?error))
+    else {
+        return;
+    };
+    let (attempts_key, successes_key) = get_connector_outcome_keys(merchant_id, connector);
+
+    increment_connector_outcome_count(&redis_conn, &attempts_key, config.window_in_secs)
+        .await
+        .map_err(|error| logger::error!(adaptive_volume_split_redis_error=?error))
+        .ok();
+    if is_success {
+        increment_connector_outcome_count(&redis_conn, &successes_key, config.window_in_secs)
+            .await
+            .map_err(|error| logger::error!(adaptive_volume_split_redis_error=?error))
+            .ok();
+    }
+}
+
+/// Success rate (in percent) of the connector over the current window, absent when too few
+/// payments were made with the connector in the window for the rate to be meaningful
+async fn get_connector_success_rate(
+    state: &AppState,
+    merchant_id: &str,
+    connector: String,
+) -> Option<u8> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(adaptive_volume_split_redis_error=?error))
+        .ok()?;
+    let (attempts_key, successes_key) = get_connector_outcome_keys(merchant_id, &connector);
+
+    let attempts = redis_conn
+        .get_key::<Option<i64>>(&attempts_key)
+        .await
+        .map_err(|error| logger::error!(adaptive_volume_split_redis_error=?error))
+        .ok()
+        .flatten()
+        .filter(|attempts| {
+            *attempts > 0 && *attempts >= state.conf.adaptive_volume_split.min_attempts
+        })?;
+    let successes = redis_conn
+        .get_key::<Option<i64>>(&successes_key)
+        .await
+        .map_err(|error| logger::error!(adaptive_volume_split_redis_error=?error))
+        .ok()?
+        .unwrap_or(0);
+
+    u8::try_from(
+        successes
+            .saturating_mul(100)
+            .checked_div(attempts)?
+            .clamp(0, 100),
+    )
+    .ok()
+}
+
+/// Adjusts the configured volume splits by the recent success rates of the connectors, when
+/// adaptive volume splits are enabled
+async fn get_adaptive_volume_splits(
+    state: &AppState,
+    merchant_id: &str,
+    splits: Vec<routing_types::ConnectorVolumeSplit>,
+) -> Vec<routing_types::ConnectorVolumeSplit> {
+    let config = &state.conf.adaptive_volume_split;
+    if !config.enabled {
+        return splits;
+    }
+
+    let success_rates = futures::future::join_all(splits.iter().map(|split| {
+        get_connector_success_rate(state, merchant_id, split.connector.connector.to_string())
+    }))
+    .await;
+
+    adjust_volume_splits_by_success_rate(splits, &success_rates, config)
+}
+
+/// Nudges the configured volume splits towards connectors with higher recent success rates.
+///
+/// `success_rates` holds the recent success rate (in percent) of each connector in `splits`, in
+/// the same order. Connectors without a known success rate keep their configured split, before
+/// the splits are scaled back to add up to 100.
+pub fn adjust_volume_splits_by_success_rate(
+    splits: Vec<routing_types::ConnectorVolumeSplit>,
+    success_rates: &[Option<u8>],
+    config: &AdaptiveVolumeSplitConfig,
+) -> Vec<routing_types::ConnectorVolumeSplit> {
+    let configured_splits: Vec<u8> = splits.iter().map(|sp| sp.split).collect();
+    let adjusted_splits = get_adaptive_split_weights(&configured_splits, success_rates, config);
+
+    splits
+        .into_iter()
+        .zip(adjusted_splits)
+        .map(|(sp, split)| routing_types::ConnectorVolumeSplit { split, ..sp })
+        .collect()
+}
+
+fn get_adaptive_split_weights(
+    configured_splits: &[u8],
+    success_rates: &[Option<u8>],
+    config: &AdaptiveVolumeSplitConfig,
+) -> Vec<u8> {
+    let (weighted_success_rate_sum, known_split_sum) = configured_splits
+        .iter()
+        .zip(success_rates)
+        .filter_map(|(split, success_rate)| {
+            success_rate.map(|rate| (i64::from(*split), i64::from(rate)))
+        })
+        .fold((0, 0), |(rate_sum, split_sum), (split, rate)| {
+            (rate_sum + split * rate, split_sum + split)
+        });
+
+    let Some(average_success_rate) = weighted_success_rate_sum.checked_div(known_split_sum) else {
+        return configured_splits.to_vec();
+    };
+    let max_adjustment = i64::from(config.max_adjustment);
+    // The minimum split is lowered when it can't be retained by every connector
+    let active_connector_count = configured_splits.iter().filter(|split| **split > 0).count();
+    let min_split = i64::from(config.min_split).min(
+        i64::try_from(active_connector_count)
+            .ok()
+            .and_then(|count| 100_i64.checked_div(count))
+            .unwrap_or(0),
+    );
+
+    let adjusted_splits = configured_splits
+        .iter()
+        .zip(success_rates)
+        .map(|(split, success_rate)| {
+            let adjustment = success_rate.map_or(0, |rate| {
+                (i64::from(rate) - average_success_rate).clamp(-max_adjustment, max_adjustment)
+            });
+
+            // Connectors configured without any volume are left out of the split
+            match i64::from(*split) {
+                0 => 0,
+                split => (split + adjustment).max(min_split),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    normalize_split_weights(&adjusted_splits, min_split)
+}
+
+/// Scales the weights so that they add up to 100, with every non zero weight retaining at least
+/// `min_split`. Weights are rounded by their largest remainders, so that the total is exact.
+fn normalize_split_weights(weights: &[i64], min_split: i64) -> Vec<u8> {
+    let mut is_pinned = vec![false; weights.len()];
+
+    let scaled_weights = loop {
+        let (pinned_total, free_total) = weights.iter().zip(&is_pinned).fold(
+            (0, 0),
+            |(pinned_total, free_total), (weight, is_pinned)| {
+                if *is_pinned {
+                    (pinned_total + min_split, free_total)
+                } else {
+                    (pinned_total, free_total + weight)
+                }
+            },
+        );
+        let budget = (100 - pinned_total).max(0);
+
+        // Each weight is scaled down along with the remainder of the scaling
+        let scaled_weights = weights
+            .iter()
+            .zip(&is_pinned)
+            .map(|(weight, is_pinned)| {
+                if *is_pinned {
+                    (min_split, 0)
+                } else {
+                    let scaled = weight * budget;
+                    (
+                        scaled.checked_div(free_total).unwrap_or(0),
+                        scaled.checked_rem(free_total).unwrap_or(0),
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Weights scaled below the minimum are pinned to it, and the rest scaled again
+        let mut has_newly_pinned = false;
+        for ((weight, (scaled, _)), is_pinned) in weights
+            .iter()
+            .zip(&scaled_weights)
+            .zip(is_pinned.iter_mut())
+        {
+            if !*is_pinned && *weight > 0 && *scaled < min_split {
+                *is_pinned = true;
+                has_newly_pinned = true;
+            }
+        }
+        if !has_newly_pinned {
+            break scaled_weights;
+        }
+    };
+
+    let remaining = 100 - scaled_weights.iter().map(|(scaled, _)| scaled).sum::<i64>();
+    let mut remainders = weights
+        .iter()
+        .zip(&scaled_weights)
+        .enumerate()
+        .filter(|(_, (weight, _))| **weight > 0)
+        .map(|(idx, (_, (_, remainder)))| (idx, *remainder))
+        .collect::<Vec<_>>();
+    remainders.sort_by(|(_, left), (_, right)| right.cmp(left));
+    let rounded_up = remainders
+        .into_iter()
+        .take(usize::try_from(remaining).unwrap_or(0))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    scaled_weights
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (scaled, _))| {
+            let weight = if rounded_up.contains(&idx) {
+                scaled + 1
+            } else {
+                scaled
+            };
+            u8::try_from(weight.clamp(0, 100)).unwrap_or(u8::MAX)
+        })
+        .collect()
+}
+
+/// Deterministically maps the seed to one of the weighted buckets, using a hash that is stable
+/// across processes and releases. The same seed (e.g. a payment id) always lands in the same
+/// bucket for the same weights, so retries of a payment are routed to the same connector.
+fn get_volume_split_bucket_index(weights: &[u8], seed: &str) -> Option<usize> {
+    let total_weight: u64 = weights.iter().map(|weight| u64::from(*weight)).sum();
+    let [b0, b1, b2, b3, b4, b5, b6, b7, ..] = *blake3::hash(seed.as_bytes()).as_bytes();
+    let bucket = u64::from_be_bytes([b0, b1, b2, b3, b4, b5, b6, b7]).checked_rem(total_weight)?;
+
+    weights
+        .iter()
+        .scan(0u64, |cumulative_weight, weight| {
+            *cumulative_weight += u64::from(*weight);
+            Some(*cumulative_weight)
+        })
+        .position(|cumulative_weight| bucket < cumulative_weight)
+}
+
 pub fn perform_volume_split(
     mut splits: Vec<routing_types::ConnectorVolumeSplit>,
     rng_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let weights: Vec<u8> = splits.iter().map(|sp| sp.split).collect();
-    let weighted_index = distributions::WeightedIndex::new(weights)
+    let weighted_index = distributions::WeightedIndex::new(&weights)
         .change_context(errors::RoutingError::VolumeSplitFailed)
         .attach_printable("Error creating weighted distribution for volume split")?;
 
     let idx = if let Some(seed) = rng_seed {
-        let mut hasher = hash_map::DefaultHasher::new();
-        seed.hash(&mut hasher);
-        let hash = hasher.finish();
-
-        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hash);
-        weighted_index.sample(&mut rng)
+        get_volume_split_bucket_index(&weights, seed)
+            .ok_or(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Error bucketing the seed for volume split")?
     } else {
         let mut rng = rand::thread_rng();
         weighted_index.sample(&mut rng)
@@ -970,7 +1484,7 @@ pub async fn perform_session_flow_routing(
             state: session_input.state,
             key_store: session_input.key_store,
             merchant_last_modified,
-            attempt_id: &session_input.payment_attempt.attempt_id,
+            payment_id: &session_input.payment_intent.payment_id,
             routing_algorithm: &routing_algorithm,
             backend_input: backend_input.clone(),
             allowed_connectors,
@@ -1029,13 +1543,17 @@ async fn perform_session_routing_for_pm_type(
                     CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
                     CachedAlgorithm::Priority(plist) => plist.clone(),
                     CachedAlgorithm::VolumeSplit(splits) => {
-                        perform_volume_split(splits.to_vec(), Some(session_pm_input.attempt_id))
+                        perform_volume_split(splits.to_vec(), Some(session_pm_input.payment_id))
                             .change_context(errors::RoutingError::ConnectorSelectionFailed)?
                     }
-                    CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                        session_pm_input.backend_input.clone(),
-                        interpreter,
-                    )?,
+                    CachedAlgorithm::Advanced(interpreter) => {
+                        let (connectors, _) = execute_dsl_and_get_connector_v1(
+                            session_pm_input.backend_input.clone(),
+                            interpreter,
+                            Some(session_pm_input.payment_id),
+                        )?;
+                        connectors
+                    }
                 }
             } else {
                 routing_helpers::get_merchant_default_config(
@@ -1187,3 +1705,395 @@ pub fn make_dsl_input_for_surcharge(
     };
     Ok(backend_input)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use rand::SeedableRng;
+
+    use super::*;
+
+    const CONFIG: AdaptiveVolumeSplitConfig = AdaptiveVolumeSplitConfig {
+        enabled: true,
+        max_adjustment: 20,
+        min_split: 10,
+        window_in_secs: 3600,
+        min_attempts: 20,
+    };
+
+    fn simulate_volume_share(weights: &[u8], runs: u32) -> Vec<f64> {
+        let weighted_index = distributions::WeightedIndex::new(weights).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(42);
+        let mut counts = vec![0u32; weights.len()];
+        for _ in 0..runs {
+            if let Some(count) = counts.get_mut(weighted_index.sample(&mut rng)) {
+                *count += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .map(|count| f64::from(count) / f64::from(runs))
+            .collect()
+    }
+
+    #[test]
+    fn test_volume_shifts_towards_higher_success_rate_connector() {
+        let weights = get_adaptive_split_weights(&[50, 50], &[Some(95), Some(75)], &CONFIG);
+        assert_eq!(weights, vec![60, 40]);
+
+        let volume_share = simulate_volume_share(&weights, 10_000);
+        assert!(
+            matches!(volume_share.as_slice(), [better, worse] if *better > 0.55 && *better < 0.65 && *worse > 0.35 && *worse < 0.45)
+        );
+    }
+
+    #[test]
+    fn test_adjustment_is_bounded_and_connector_not_starved() {
+        let weights = get_adaptive_split_weights(&[80, 20], &[Some(99), Some(10)], &CONFIG);
+        // the adjustment is capped at `max_adjustment`, the worse connector keeps `min_split` and
+        // the splits are scaled back to add up to 100
+        assert_eq!(weights, vec![90, 10]);
+
+        let volume_share = simulate_volume_share(&weights, 10_000);
+        assert!(matches!(volume_share.as_slice(), [_, worse] if *worse > 0.05));
+    }
+
+    #[test]
+    fn test_unknown_success_rates_keep_configured_splits() {
+        assert_eq!(
+            get_adaptive_split_weights(&[30, 70], &[None, None], &CONFIG),
+            vec![30, 70]
+        );
+        assert_eq!(
+            get_adaptive_split_weights(&[30, 30, 40], &[Some(90), None, Some(90)], &CONFIG),
+            vec![30, 30, 40]
+        );
+    }
+
+    #[test]
+    fn test_adjusted_splits_add_up_to_hundred() {
+        let weights =
+            get_adaptive_split_weights(&[40, 30, 30], &[Some(95), Some(80), Some(50)], &CONFIG);
+        assert_eq!(weights, vec![57, 33, 10]);
+
+        // Connectors configured without any volume don't receive any
+        let weights =
+            get_adaptive_split_weights(&[0, 50, 50], &[Some(99), Some(90), Some(60)], &CONFIG);
+        assert_eq!(weights, vec![0, 65, 35]);
+    }
+
+    fn get_bin_override_interpreter() -> backend::VirInterpreterBackend<ConnectorSelection> {
+        let program: ast::Program<ConnectorSelection> = serde_json::from_value(serde_json::json!({
+            "defaultSelection": {
+                "type": "volume_split",
+                "data": [
+                    { "connector": { "connector": "stripe" }, "split": 70 },
+                    { "connector": { "connector": "adyen" }, "split": 30 }
+                ]
+            },
+            "rules": [
+                {
+                    "name": "bin_override",
+                    "connectorSelection": {
+                        "type": "priority",
+                        "data": [{ "connector": "checkout" }]
+                    },
+                    "statements": [
+                        {
+                            "condition": [
+                                {
+                                    "lhs": "card_bin",
+                                    "comparison": "equal",
+                                    "value": { "type": "str_value", "value": "424242" },
+                                    "metadata": {}
+                                }
+                            ],
+                            "nested": null
+                        }
+                    ]
+                }
+            ],
+            "metadata": {}
+        }))
+        .unwrap();
+
+        backend::VirInterpreterBackend::with_program(program).unwrap()
+    }
+
+    fn get_card_backend_input(card_bin: &str) -> dsl_inputs::BackendInput {
+        dsl_inputs::BackendInput {
+            metadata: None,
+            payment: dsl_inputs::PaymentInput {
+                amount: 1000,
+                currency: euclid_enums::Currency::USD,
+                authentication_type: Some(euclid_enums::AuthenticationType::NoThreeDs),
+                card_bin: Some(card_bin.to_string()),
+                capture_method: Some(euclid_enums::CaptureMethod::Automatic),
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: Some(euclid_enums::PaymentMethod::Card),
+                payment_method_type: Some(euclid_enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: Some(euclid_enums::PaymentType::NonMandate),
+            },
+        }
+    }
+
+    #[test]
+    fn test_routing_explanation_for_volume_split() {
+        let (connectors, explanation) = execute_dsl_and_get_connector_v1(
+            get_card_backend_input("411111"),
+            &get_bin_override_interpreter(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            explanation.algorithm_kind,
+            Some(routing_types::RoutingAlgorithmKind::Advanced)
+        );
+        assert_eq!(explanation.matched_rule, None);
+
+        let volume_split = explanation.volume_split.unwrap();
+        let splits: Vec<_> = volume_split
+            .iter()
+            .map(|split| (split.connector.connector, split.split))
+            .collect();
+        assert_eq!(
+            splits,
+            vec![
+                (api_enums::RoutableConnectors::Stripe, 70),
+                (api_enums::RoutableConnectors::Adyen, 30)
+            ]
+        );
+        assert!(connectors
+            .first()
+            .is_some_and(|chosen| volume_split.iter().any(|split| split.connector == *chosen)));
+    }
+
+    #[test]
+    fn test_routing_explanation_for_bin_override() {
+        let (connectors, mut explanation) = execute_dsl_and_get_connector_v1(
+            get_card_backend_input("424242"),
+            &get_bin_override_interpreter(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(explanation.matched_rule.as_deref(), Some("bin_override"));
+        assert_eq!(explanation.volume_split, None);
+
+        let chosen: Vec<_> = connectors.iter().map(|choice| choice.connector).collect();
+        assert_eq!(chosen, vec![api_enums::RoutableConnectors::Checkout]);
+
+        // The overriding connector is not eligible, so the fallback connector is used instead
+        let fallback_connectors: Vec<routing_types::RoutableConnectorChoice> =
+            serde_json::from_value(serde_json::json!([{ "connector": "stripe" }])).unwrap();
+        add_eligibility_analysis_to_routing_explanation(
+            &mut explanation,
+            &connectors,
+            &fallback_connectors,
+        );
+
+        assert_eq!(explanation.excluded_connectors, connectors);
+        assert_eq!(explanation.fallback_connectors, fallback_connectors);
+    }
+
+    fn get_volume_splits() -> Vec<routing_types::ConnectorVolumeSplit> {
+        serde_json::from_value(serde_json::json!([
+            { "connector": { "connector": "stripe" }, "split": 70 },
+            { "connector": { "connector": "adyen" }, "split": 30 }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_volume_split_distribution_follows_weights() {
+        let runs = 10_000u32;
+        let stripe_count = (0..runs)
+            .filter(|run| {
+                let connectors =
+                    perform_volume_split(get_volume_splits(), Some(&format!("pay_{run}"))).unwrap();
+                connectors.first().map(|choice| choice.connector)
+                    == Some(api_enums::RoutableConnectors::Stripe)
+            })
+            .count();
+
+        let stripe_share = f64::from(u32::try_from(stripe_count).unwrap()) / f64::from(runs);
+        assert!(stripe_share > 0.67 && stripe_share < 0.73);
+    }
+
+    #[test]
+    fn test_volume_split_is_stable_for_retries_of_a_payment() {
+        let connectors = perform_volume_split(get_volume_splits(), Some("pay_retry")).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(
+                perform_volume_split(get_volume_splits(), Some("pay_retry")).unwrap(),
+                connectors
+            );
+        }
+    }
+
+    #[test]
+    fn test_volume_split_weights_must_add_up_to_100() {
+        let algorithm = |splits: serde_json::Value| {
+            serde_json::from_value::<routing_types::StraightThroughAlgorithm>(
+                serde_json::json!({ "type": "volume_split", "data": splits }),
+            )
+            .unwrap()
+        };
+
+        assert!(
+            algorithm(serde_json::to_value(get_volume_splits()).unwrap())
+                .validate_volume_splits()
+                .is_ok()
+        );
+
+        // Algorithms that are already stored can still be read, but are rejected when validated
+        let invalid_algorithm = algorithm(serde_json::json!([
+            { "connector": { "connector": "stripe" }, "split": 70 },
+            { "connector": { "connector": "adyen" }, "split": 20 }
+        ]));
+        assert!(invalid_algorithm.validate_volume_splits().is_err());
+    }
+
+    #[test]
+    fn test_volume_split_weights_of_advanced_rules_must_add_up_to_100() {
+        let algorithm = |rule_splits: serde_json::Value| {
+            serde_json::from_value::<routing_types::RoutingAlgorithm>(serde_json::json!({
+                "type": "advanced",
+                "data": {
+                    "defaultSelection": {
+                        "type": "priority",
+                        "data": [{ "connector": "stripe" }]
+                    },
+                    "rules": [{
+                        "name": "usd_split",
+                        "connectorSelection": { "type": "volume_split", "data": rule_splits },
+                        "statements": [{
+                            "condition": [{
+                                "lhs": "currency",
+                                "comparison": "equal",
+                                "value": { "type": "enum_variant", "value": "USD" },
+                                "metadata": {}
+                            }],
+                            "nested": null
+                        }]
+                    }],
+                    "metadata": {}
+                }
+            }))
+            .unwrap()
+        };
+
+        assert!(
+            algorithm(serde_json::to_value(get_volume_splits()).unwrap())
+                .validate_volume_splits()
+                .is_ok()
+        );
+        assert!(algorithm(serde_json::json!([
+            { "connector": { "connector": "stripe" }, "split": 70 },
+            { "connector": { "connector": "adyen" }, "split": 20 }
+        ]))
+        .validate_volume_splits()
+        .is_err());
+    }
+
+    fn get_currency_amount_interpreter() -> backend::VirInterpreterBackend<ConnectorSelection> {
+        let program: ast::Program<ConnectorSelection> = serde_json::from_value(serde_json::json!({
+            "defaultSelection": {
+                "type": "priority",
+                "data": [{ "connector": "stripe" }]
+            },
+            "rules": [
+                {
+                    "name": "high_value_eur",
+                    "connectorSelection": {
+                        "type": "priority",
+                        "data": [{ "connector": "adyen" }]
+                    },
+                    "statements": [
+                        {
+                            "condition": [
+                                {
+                                    "lhs": "currency",
+                                    "comparison": "equal",
+                                    "value": { "type": "enum_variant", "value": "EUR" },
+                                    "metadata": {}
+                                },
+                                {
+                                    "lhs": "amount",
+                                    "comparison": "greater_than",
+                                    "value": { "type": "number", "value": 10000 },
+                                    "metadata": {}
+                                }
+                            ],
+                            "nested": null
+                        }
+                    ]
+                }
+            ],
+            "metadata": {}
+        }))
+        .unwrap();
+
+        backend::VirInterpreterBackend::with_program(program).unwrap()
+    }
+
+    fn evaluate_routing(
+        amount: i64,
+        currency: &str,
+    ) -> (
+        Vec<api_enums::RoutableConnectors>,
+        routing_types::RoutingExplanation,
+    ) {
+        let request: routing_types::RoutingEvaluateRequest =
+            serde_json::from_value(serde_json::json!({
+                "amount": amount,
+                "currency": currency,
+                "payment_method": "card",
+                "payment_method_type": "credit",
+                "billing_country": "DE"
+            }))
+            .unwrap();
+
+        let (connectors, explanation) = execute_dsl_and_get_connector_v1(
+            make_dsl_input_for_routing_evaluation(&request),
+            &get_currency_amount_interpreter(),
+            None,
+        )
+        .unwrap();
+
+        (
+            connectors.iter().map(|choice| choice.connector).collect(),
+            explanation,
+        )
+    }
+
+    #[test]
+    fn test_routing_evaluation_picks_connector_of_matched_rule() {
+        let (connectors, explanation) = evaluate_routing(25000, "EUR");
+
+        assert_eq!(connectors, vec![api_enums::RoutableConnectors::Adyen]);
+        assert_eq!(explanation.matched_rule.as_deref(), Some("high_value_eur"));
+    }
+
+    #[test]
+    fn test_routing_evaluation_falls_through_to_default_selection() {
+        for (amount, currency) in [(25000, "USD"), (5000, "EUR")] {
+            let (connectors, explanation) = evaluate_routing(amount, currency);
+
+            assert_eq!(connectors, vec![api_enums::RoutableConnectors::Stripe]);
+            assert_eq!(explanation.matched_rule, None);
+        }
+    }
+}
@@ -33,7 +33,7 @@ use crate::{
 #[instrument(skip_all)]
 pub async fn construct_payment_router_data<'a, F, T>(
     state: &'a AppState,
-    payment_data: PaymentData<F>,
+    mut payment_data: PaymentData<F>,
     connector_id: &str,
     merchant_account: &domain::MerchantAccount,
     _key_store: &domain::MerchantKeyStore,
@@ -87,6 +87,40 @@ where
         incremental_authorization_allowed: None,
     });
 
+    // An empty prefix is cached for merchants without the config, so that the lookup does not
+    // hit the database on every payment
+    let statement_descriptor_prefix = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &format!(
+                "statement_descriptor_prefix_{}",
+                merchant_account.merchant_id
+            ),
+            Some(String::new()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the statement descriptor prefix config")?
+        .config;
+
+    if !statement_descriptor_prefix.is_empty() {
+        let max_length = state
+            .conf
+            .statement_descriptor_max_length
+            .0
+            .get(connector_id)
+            .copied();
+        payment_data.payment_intent.statement_descriptor_name =
+            Some(core_utils::get_statement_descriptor_with_prefix(
+                &statement_descriptor_prefix,
+                payment_data
+                    .payment_intent
+                    .statement_descriptor_name
+                    .as_deref(),
+                max_length,
+            ));
+    }
+
     let additional_data = PaymentAdditionalData {
         router_base_url: state.conf.server.base_url.clone(),
         connector_name: connector_id.to_string(),
@@ -375,6 +409,12 @@ where
             field_name: "amount",
         })?;
     let mandate_id = payment_attempt.mandate_id.clone();
+    let refund_summary = get_payment_refund_summary(
+        &payment_data.refunds,
+        payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.amount),
+    );
     let refunds_response = if payment_data.refunds.is_empty() {
         None
     } else {
@@ -686,6 +726,8 @@ where
                                     start_date: i.start_date,
                                     end_date: i.end_date,
                                     metadata: i.metadata,
+                                    max_total_amount: i.max_total_amount,
+                                    max_debit_count: i.max_debit_count,
                                 }))
                             }
                             hyperswitch_domain_models::mandates::MandateDataType::SingleUse(i) => {
@@ -695,6 +737,8 @@ where
                                     start_date: i.start_date,
                                     end_date: i.end_date,
                                     metadata: i.metadata,
+                                    max_total_amount: i.max_total_amount,
+                                    max_debit_count: i.max_debit_count,
                                 })
                             }
                             hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None) => {
@@ -707,6 +751,7 @@ where
                 )
                 .set_description(payment_intent.description)
                 .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                .set_refund_summary(refund_summary)
                 .set_disputes(disputes_response)
                 .set_attempts(attempts_response)
                 .set_captures(captures_response)
@@ -763,6 +808,7 @@ where
                 .set_merchant_connector_id(payment_attempt.merchant_connector_id)
                 .set_unified_code(payment_attempt.unified_code)
                 .set_unified_message(payment_attempt.unified_message)
+                .set_customer_error_message(payment_data.customer_decline_message)
                 .set_incremental_authorization_allowed(
                     payment_intent.incremental_authorization_allowed,
                 )
@@ -779,6 +825,7 @@ where
                 .set_customer(customer_details_response.clone())
                 .set_browser_info(payment_attempt.browser_info)
                 .set_updated(Some(payment_intent.modified_at))
+                .set_routing_explanation(payment_data.routing_explanation)
                 .to_owned(),
             headers,
         ))
@@ -798,6 +845,40 @@ where
     Ok(output)
 }
 
+/// Summarizes the refunds of a payment, returns `None` when no refund was initiated against it
+pub fn get_payment_refund_summary(
+    refunds: &[storage::Refund],
+    amount_captured: i64,
+) -> Option<api_models::payments::PaymentRefundSummary> {
+    if refunds.is_empty() {
+        return None;
+    }
+
+    let total_refunded = crate::core::refunds::validator::get_total_refunded_amount(refunds);
+    let refund_count = refunds
+        .iter()
+        .filter(|refund| {
+            !matches!(
+                refund.refund_status,
+                enums::RefundStatus::Failure | enums::RefundStatus::TransactionFailure
+            )
+        })
+        .count();
+    let refund_state = if total_refunded <= 0 {
+        api_models::payments::PaymentRefundState::None
+    } else if total_refunded >= amount_captured {
+        api_models::payments::PaymentRefundState::Full
+    } else {
+        api_models::payments::PaymentRefundState::Partial
+    };
+
+    Some(api_models::payments::PaymentRefundSummary {
+        total_refunded,
+        refund_count,
+        refund_state,
+    })
+}
+
 pub fn third_party_sdk_session_next_action<Op>(
     payment_attempt: &storage::PaymentAttempt,
     operation: &Op,
@@ -1346,6 +1427,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             },
             browser_info,
             metadata: payment_data.payment_intent.metadata,
+            capture_reference: payment_data.capture_reference,
         })
     }
 }
@@ -1709,3 +1791,75 @@ impl ForeignFrom<payments::FraudCheck> for FrmMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_refund(refund_id: &str, refund_amount: i64) -> storage::Refund {
+        let current_time = common_utils::date_time::now();
+        storage::Refund {
+            id: 1,
+            internal_reference_id: format!("refid_{refund_id}"),
+            refund_id: refund_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            connector_transaction_id: "txn_1".to_string(),
+            connector: "adyen".to_string(),
+            connector_refund_id: None,
+            external_reference_id: Some(refund_id.to_string()),
+            refund_type: enums::RefundType::InstantRefund,
+            total_amount: 1000,
+            currency: enums::Currency::USD,
+            refund_amount,
+            refund_status: enums::RefundStatus::Success,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at: current_time,
+            updated_at: current_time,
+            description: None,
+            attempt_id: "pay_1_1".to_string(),
+            refund_reason: None,
+            refund_error_code: None,
+            profile_id: None,
+            updated_by: "postgres_only".to_string(),
+            merchant_connector_id: None,
+            merchant_refund_reference_id: None,
+        }
+    }
+
+    #[test]
+    fn test_refund_summary_with_multiple_partial_refunds() {
+        let refunds = vec![get_refund("ref_1", 100), get_refund("ref_2", 250)];
+
+        assert_eq!(
+            get_payment_refund_summary(&refunds, 1000),
+            Some(api_models::payments::PaymentRefundSummary {
+                total_refunded: 350,
+                refund_count: 2,
+                refund_state: api_models::payments::PaymentRefundState::Partial,
+            })
+        );
+    }
+
+    #[test]
+    fn test_refund_summary_excludes_failed_refunds() {
+        let failed_refund = storage::Refund {
+            refund_status: enums::RefundStatus::Failure,
+            ..get_refund("ref_2", 250)
+        };
+        let refunds = vec![get_refund("ref_1", 1000), failed_refund];
+
+        assert_eq!(
+            get_payment_refund_summary(&refunds, 1000),
+            Some(api_models::payments::PaymentRefundSummary {
+                total_refunded: 1000,
+                refund_count: 1,
+                refund_state: api_models::payments::PaymentRefundState::Full,
+            })
+        );
+        assert_eq!(get_payment_refund_summary(&[], 1000), None);
+    }
+}
@@ -1,6 +1,9 @@
 use std::{collections::HashMap, num::TryFromIntError};
 
-use api_models::{payment_methods::SurchargeDetailsResponse, payments::RequestSurchargeDetails};
+use api_models::{
+    payment_methods::SurchargeDetailsResponse,
+    payments::{AttemptSurchargeBreakdown, RequestSurchargeDetails},
+};
 use common_utils::{
     consts,
     errors::CustomResult,
@@ -213,6 +216,20 @@ impl From<(&RequestSurchargeDetails, &PaymentAttempt)> for SurchargeDetails {
     }
 }
 
+/// Returns the surcharge breakdown stored on the payment attempt, if surcharge was applied on it
+pub fn get_attempt_surcharge_breakdown(
+    payment_attempt: &PaymentAttempt,
+) -> Option<AttemptSurchargeBreakdown> {
+    payment_attempt
+        .surcharge_amount
+        .map(|surcharge_amount| AttemptSurchargeBreakdown {
+            base_amount: payment_attempt.amount,
+            surcharge_amount,
+            tax_on_surcharge_amount: payment_attempt.tax_amount.unwrap_or(0),
+            net_amount: payment_attempt.net_amount,
+        })
+}
+
 impl ForeignTryFrom<(&SurchargeDetails, &PaymentAttempt)> for SurchargeDetailsResponse {
     type Error = TryFromIntError;
     fn foreign_try_from(
@@ -407,6 +424,18 @@ impl ForeignTryFrom<&storage::Authentication> for AuthenticationData {
                 .get_required_value("cavv")
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("cavv must not be null when authentication_status is success")?;
+            if !is_eci_consistent_with_trans_status(
+                authentication.eci.as_deref(),
+                authentication.trans_status.as_ref(),
+            ) {
+                return Err(errors::ApiErrorResponse::PaymentAuthenticationFailed { data: None })
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "eci {:?} does not match the authentication result {:?}",
+                            authentication.eci, authentication.trans_status
+                        )
+                    });
+            }
             Ok(Self {
                 eci: authentication.eci.clone(),
                 cavv,
@@ -418,3 +447,143 @@ impl ForeignTryFrom<&storage::Authentication> for AuthenticationData {
         }
     }
 }
+
+/// ECI values indicating a fully authenticated 3DS transaction (Visa / Mastercard)
+const FULLY_AUTHENTICATED_ECI_VALUES: [&str; 2] = ["05", "02"];
+/// ECI values indicating an attempted 3DS authentication (Visa / Mastercard)
+const ATTEMPTED_AUTHENTICATION_ECI_VALUES: [&str; 2] = ["06", "01"];
+
+/// Checks that the ECI echoed back after 3DS agrees with the authentication result.
+/// A missing ECI or transaction status is not treated as a mismatch.
+fn is_eci_consistent_with_trans_status(
+    eci: Option<&str>,
+    trans_status: Option<&common_enums::TransactionStatus>,
+) -> bool {
+    match (eci, trans_status) {
+        (Some(eci), Some(trans_status)) => {
+            let eci = format!("{:0>2}", eci.trim());
+            match trans_status {
+                common_enums::TransactionStatus::Success => {
+                    FULLY_AUTHENTICATED_ECI_VALUES.contains(&eci.as_str())
+                }
+                common_enums::TransactionStatus::NotVerified => {
+                    ATTEMPTED_AUTHENTICATION_ECI_VALUES.contains(&eci.as_str())
+                }
+                common_enums::TransactionStatus::Failure
+                | common_enums::TransactionStatus::VerificationNotPerformed
+                | common_enums::TransactionStatus::Rejected
+                | common_enums::TransactionStatus::ChallengeRequired
+                | common_enums::TransactionStatus::ChallengeRequiredDecoupledAuthentication
+                | common_enums::TransactionStatus::InformationOnly => false,
+            }
+        }
+        (None, _) | (_, None) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use hyperswitch_domain_models::payments::payment_attempt::{
+        PaymentAttemptInterface, PaymentAttemptNew, PaymentAttemptUpdate,
+    };
+
+    use super::*;
+    use crate::db::MockDb;
+
+    #[tokio::test]
+    async fn test_stored_surcharge_breakdown_matches_computed_surcharge() {
+        #[allow(clippy::expect_used)]
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+
+        let payment_attempt = db
+            .insert_payment_attempt(
+                PaymentAttemptNew {
+                    payment_id: "pay_surcharge_breakdown".to_string(),
+                    merchant_id: "merchant_surcharge_breakdown".to_string(),
+                    attempt_id: "pay_surcharge_breakdown_1".to_string(),
+                    amount: 1000,
+                    ..Default::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(get_attempt_surcharge_breakdown(&payment_attempt), None);
+
+        let surcharge_details = SurchargeDetails::from((
+            &RequestSurchargeDetails {
+                surcharge_amount: 50,
+                tax_amount: Some(5),
+            },
+            &payment_attempt,
+        ));
+        let payment_attempt = db
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                PaymentAttemptUpdate::UpdateTrackers {
+                    payment_token: None,
+                    connector: None,
+                    straight_through_algorithm: None,
+                    amount_capturable: None,
+                    surcharge_amount: Some(surcharge_details.surcharge_amount),
+                    tax_amount: Some(surcharge_details.tax_on_surcharge_amount),
+                    updated_by: storage_scheme.to_string(),
+                    merchant_connector_id: None,
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            get_attempt_surcharge_breakdown(&payment_attempt),
+            Some(AttemptSurchargeBreakdown {
+                base_amount: surcharge_details.original_amount,
+                surcharge_amount: surcharge_details.surcharge_amount,
+                tax_on_surcharge_amount: surcharge_details.tax_on_surcharge_amount,
+                net_amount: surcharge_details.final_amount,
+            })
+        );
+        assert_eq!(surcharge_details.final_amount, 1055);
+    }
+
+    #[test]
+    fn test_consistent_3ds_data() {
+        assert!(is_eci_consistent_with_trans_status(
+            Some("05"),
+            Some(&common_enums::TransactionStatus::Success)
+        ));
+        assert!(is_eci_consistent_with_trans_status(
+            Some("02"),
+            Some(&common_enums::TransactionStatus::Success)
+        ));
+        assert!(is_eci_consistent_with_trans_status(
+            Some("6"),
+            Some(&common_enums::TransactionStatus::NotVerified)
+        ));
+        assert!(is_eci_consistent_with_trans_status(
+            None,
+            Some(&common_enums::TransactionStatus::Success)
+        ));
+    }
+
+    #[test]
+    fn test_eci_mismatching_authentication_result() {
+        assert!(!is_eci_consistent_with_trans_status(
+            Some("07"),
+            Some(&common_enums::TransactionStatus::Success)
+        ));
+        assert!(!is_eci_consistent_with_trans_status(
+            Some("05"),
+            Some(&common_enums::TransactionStatus::NotVerified)
+        ));
+        assert!(!is_eci_consistent_with_trans_status(
+            Some("05"),
+            Some(&common_enums::TransactionStatus::Failure)
+        ));
+    }
+}
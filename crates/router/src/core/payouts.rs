@@ -57,6 +57,8 @@ pub struct PayoutData {
     pub payout_method_data: Option<payouts::PayoutMethodData>,
     pub profile_id: String,
     pub should_terminate: bool,
+    pub eligibility_details: Option<payouts::PayoutEligibilityDetails>,
+    pub cancellation_reason: Option<String>,
 }
 
 // ********************************************** CORE FLOWS **********************************************
@@ -98,6 +100,11 @@ pub async fn get_connector_choice(
                 .parse_value("StraightThroughAlgorithm")
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Invalid straight through routing rules format")?;
+            request_straight_through
+                .validate_volume_splits()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "routing",
+                })?;
             payout_data.payout_attempt.routing_info = Some(straight_through);
             let mut routing_data = storage::RoutingData {
                 routed_through: connector,
@@ -338,6 +345,51 @@ pub async fn payouts_create_core(
     response_handler(&merchant_account, &payout_data).await
 }
 
+#[instrument(skip_all)]
+pub async fn payouts_batch_create_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBatchCreateRequest,
+) -> RouterResponse<payouts::PayoutBatchCreateResponse> {
+    let max_batch_size = state.conf.payouts.max_batch_size;
+    utils::when(
+        req.payouts.is_empty() || req.payouts.len() > max_batch_size,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("payouts must contain between 1 and {max_batch_size} payouts"),
+            })
+        },
+    )?;
+
+    let state = &state;
+    let merchant_account = &merchant_account;
+    let key_store = &key_store;
+    let results = helpers::process_payout_batch(
+        req.payouts,
+        state.conf.payouts.batch_concurrency,
+        |payout_request| async move {
+            match payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                payout_request,
+            )
+            .await?
+            {
+                services::ApplicationResponse::Json(response) => Ok(response),
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to get the payout response in json"),
+            }
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchCreateResponse { results },
+    ))
+}
+
 pub async fn payouts_update_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -492,6 +544,8 @@ pub async fn payouts_cancel_core(
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutActionRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    validator::validate_payout_cancellation_reason(req.cancellation_reason.as_deref())?;
+
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
@@ -500,21 +554,26 @@ pub async fn payouts_cancel_core(
     )
     .await?;
 
-    let payout_attempt = payout_data.payout_attempt.to_owned();
-    let connector_payout_id = payout_attempt.connector_payout_id.to_owned();
-    let status = payout_attempt.status;
+    let status = payout_data.payout_attempt.status;
 
     // Verify if cancellation can be triggered
     if helpers::is_payout_terminal_state(status) {
         return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
             message: format!(
                 "Payout {} cannot be cancelled for status {}",
-                payout_attempt.payout_id, status
+                payout_data.payout_attempt.payout_id, status
             ),
         }));
+    }
+
+    // The reason is forwarded to the connector, it is recorded once the payout is cancelled
+    payout_data.cancellation_reason = req.cancellation_reason;
+
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    let connector_payout_id = payout_attempt.connector_payout_id.to_owned();
 
     // Make local cancellation
-    } else if helpers::is_eligible_for_local_payout_cancellation(status) {
+    if helpers::is_eligible_for_local_payout_cancellation(status) {
         let status = storage_enums::PayoutStatus::Cancelled;
         let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
             connector_payout_id: connector_payout_id.to_owned(),
@@ -578,6 +637,23 @@ pub async fn payouts_cancel_core(
         .attach_printable("Payout cancellation failed for given Payout request")?;
     }
 
+    if let Some(payout_attempt_update) = helpers::get_cancellation_reason_update(
+        payout_data.payout_attempt.status,
+        payout_data.cancellation_reason.to_owned(),
+    ) {
+        payout_data.payout_attempt = state
+            .store
+            .update_payout_attempt(
+                &payout_data.payout_attempt,
+                payout_attempt_update,
+                &payout_data.payouts,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating cancellation reason in payout_attempt")?;
+    }
+
     response_handler(&merchant_account, &payout_data).await
 }
 
@@ -588,6 +664,12 @@ pub async fn payouts_fulfill_core(
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutActionRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    if req.cancellation_reason.is_some() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "cancellation_reason is only applicable when cancelling a payout".to_string(),
+        }));
+    }
+
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
@@ -1209,6 +1291,7 @@ pub async fn check_payout_eligibility(
                 error_message: None,
                 is_eligible: payout_response_data.payout_eligible,
             };
+            payout_data.eligibility_details = payout_response_data.eligibility_details;
             payout_data.payout_attempt = db
                 .update_payout_attempt(
                     payout_attempt,
@@ -1886,6 +1969,8 @@ pub async fn response_handler(
         status: payout_attempt.status.to_owned(),
         error_message: payout_attempt.error_message.to_owned(),
         error_code: payout_attempt.error_code,
+        eligibility_details: payout_data.eligibility_details.to_owned(),
+        cancellation_reason: payout_attempt.cancellation_reason,
         profile_id: payout_attempt.profile_id,
         created: Some(payouts.created_at),
         attempts: None,
@@ -2049,6 +2134,8 @@ pub async fn payout_create_db_entries(
             .or(stored_payout_method_data.cloned()),
         should_terminate: false,
         profile_id: profile_id.to_owned(),
+        eligibility_details: None,
+        cancellation_reason: None,
     })
 }
 
@@ -2124,6 +2211,8 @@ pub async fn make_payout_data(
         merchant_connector_account: None,
         should_terminate: false,
         profile_id,
+        eligibility_details: None,
+        cancellation_reason: None,
     })
 }
 
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use api_models::{enums, payment_methods::Card, payouts};
 use common_utils::{
     errors::CustomResult,
@@ -5,13 +7,16 @@ use common_utils::{
 };
 use diesel_models::encryption::Encryption;
 use error_stack::ResultExt;
+use futures::StreamExt;
 use masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::logger;
 
-use super::PayoutData;
+use super::{validator, PayoutData};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        gsm,
         payment_methods::{
             cards,
             transformers::{DataDuplicationCheck, StoreCardReq, StoreGenericReq, StoreLockerReq},
@@ -53,6 +58,10 @@ pub async fn make_payout_method_data<'a>(
 ) -> RouterResult<Option<api::PayoutMethodData>> {
     let db = &*state.store;
     let certain_payout_type = payout_type.get_required_value("payout_type")?.to_owned();
+    payout_method_data
+        .map(validator::validate_payout_method_data)
+        .transpose()?;
+
     let hyperswitch_token = if let Some(payout_token) = payout_token {
         if payout_token.starts_with("temporary_token_") {
             Some(payout_token.to_string())
@@ -665,10 +674,13 @@ pub async fn decide_payout_connector(
 
     // 2. Check routing algorithm passed in the request
     if let Some(routing_algorithm) = request_straight_through {
-        let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(&routing_algorithm, None)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed execution of straight through routing")?;
+        let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
+            &routing_algorithm,
+            None,
+            Some(&payout_data.payouts.payout_id),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -724,10 +736,13 @@ pub async fn decide_payout_connector(
 
     // 3. Check algorithm passed in routing data
     if let Some(ref routing_algorithm) = routing_data.algorithm {
-        let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(routing_algorithm, None)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed execution of straight through routing")?;
+        let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
+            routing_algorithm,
+            None,
+            Some(&payout_data.payouts.payout_id),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -843,10 +858,11 @@ pub async fn get_gsm_record(
     flow: String,
 ) -> Option<storage::gsm::GatewayStatusMap> {
     let get_gsm = || async {
-        state.store.find_gsm_rule(
+        gsm::find_gsm_rule_with_wildcard_message(
+                state.store.as_ref(),
                 connector_name.clone().unwrap_or_default(),
                 flow.clone(),
-                "sub_flow".to_string(),
+                consts::DEFAULT_GSM_SUB_FLOW.to_string(),
                 error_code.clone().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
                 error_message.clone().unwrap_or_default(),
             )
@@ -912,6 +928,80 @@ pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus
     )
 }
 
+/// The cancellation reason is only recorded once the payout is cancelled, a reason is not stored
+/// against a payout whose cancellation failed at the connector
+pub fn get_cancellation_reason_update(
+    status: api_enums::PayoutStatus,
+    cancellation_reason: Option<String>,
+) -> Option<storage::PayoutAttemptUpdate> {
+    cancellation_reason
+        .filter(|_| status == api_enums::PayoutStatus::Cancelled)
+        .map(
+            |cancellation_reason| storage::PayoutAttemptUpdate::CancellationReasonUpdate {
+                cancellation_reason: Some(cancellation_reason),
+            },
+        )
+}
+
+/// Processes the payouts of a batch with `create_payout`, at most `concurrency` payouts at a time,
+/// continuing past payouts that fail. A payout_id repeated within the batch is only processed
+/// once, the repetitions are reported as failed.
+pub async fn process_payout_batch<F, Fut>(
+    payouts: Vec<api::PayoutCreateRequest>,
+    concurrency: usize,
+    mut create_payout: F,
+) -> Vec<api::PayoutBatchItemResult>
+where
+    F: FnMut(api::PayoutCreateRequest) -> Fut,
+    Fut: futures::Future<Output = RouterResult<api::PayoutCreateResponse>>,
+{
+    let mut seen_payout_ids = HashSet::new();
+
+    futures::stream::iter(payouts.into_iter().enumerate())
+        .map(|(index, payout)| {
+            let payout_id = payout.payout_id.clone();
+            let is_repeated = payout_id
+                .as_ref()
+                .is_some_and(|payout_id| !seen_payout_ids.insert(payout_id.clone()));
+            let result = (!is_repeated).then(|| create_payout(payout));
+
+            async move {
+                let result = match result {
+                    Some(result) => result.await,
+                    None => Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "payout_id is repeated within the batch".to_string(),
+                    }
+                    .into()),
+                };
+
+                match result {
+                    Ok(payout) => api::PayoutBatchItemResult {
+                        index,
+                        payout_id,
+                        status: api::PayoutBatchItemStatus::Succeeded,
+                        payout: Some(payout),
+                        error_code: None,
+                        error_message: None,
+                    },
+                    Err(error) => {
+                        logger::error!(batch_payout_item_error=?error);
+                        api::PayoutBatchItemResult {
+                            index,
+                            payout_id,
+                            status: api::PayoutBatchItemStatus::Failed,
+                            payout: None,
+                            error_code: Some(error.current_context().error_code()),
+                            error_message: Some(error.current_context().error_message()),
+                        }
+                    }
+                }
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
 #[cfg(feature = "olap")]
 pub(super) async fn filter_by_constraints(
     db: &dyn StorageInterface,
@@ -924,3 +1014,154 @@ pub(super) async fn filter_by_constraints(
         .await?;
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{
+        str::FromStr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use error_stack::report;
+
+    use super::*;
+
+    fn get_card_payout(payout_id: &str) -> api::PayoutCreateRequest {
+        api::PayoutCreateRequest {
+            payout_id: Some(payout_id.to_string()),
+            payout_type: Some(api_enums::PayoutType::Card),
+            payout_method_data: Some(api::PayoutMethodData::Card(api::CardPayout {
+                card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+                expiry_month: Secret::new("03".to_string()),
+                expiry_year: Secret::new("2030".to_string()),
+                card_holder_name: Some(Secret::new("John Doe".to_string())),
+            })),
+            ..Default::default()
+        }
+    }
+
+    fn get_bank_payout(payout_id: &str) -> api::PayoutCreateRequest {
+        api::PayoutCreateRequest {
+            payout_id: Some(payout_id.to_string()),
+            payout_type: Some(api_enums::PayoutType::Bank),
+            payout_method_data: Some(api::PayoutMethodData::Bank(api::BankPayout::Ach(
+                api::AchBankTransfer {
+                    bank_account_number: Secret::new("000123456".to_string()),
+                    bank_routing_number: Secret::new("110000000".to_string()),
+                    ..Default::default()
+                },
+            ))),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_continues_past_invalid_payouts() {
+        let invalid_bank_payout = api::PayoutCreateRequest {
+            payout_method_data: None,
+            ..get_bank_payout("payout_bank_invalid")
+        };
+        let payouts = vec![
+            get_card_payout("payout_card_1"),
+            invalid_bank_payout,
+            get_bank_payout("payout_bank_1"),
+            get_card_payout("payout_card_2"),
+            get_card_payout("payout_card_1"),
+        ];
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let results = process_payout_batch(payouts, 2, |payout| {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                payout.payout_method_data.ok_or_else(|| {
+                    report!(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "payout_method_data"
+                    })
+                })?;
+                Ok(api::PayoutCreateResponse {
+                    payout_id: payout.payout_id.unwrap_or_default(),
+                    payout_type: payout.payout_type.unwrap_or_default(),
+                    status: api_enums::PayoutStatus::Success,
+                    ..Default::default()
+                })
+            }
+        })
+        .await;
+
+        let statuses: Vec<_> = results
+            .iter()
+            .map(|result| (result.index, result.payout_id.as_deref(), result.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (
+                    0,
+                    Some("payout_card_1"),
+                    api::PayoutBatchItemStatus::Succeeded
+                ),
+                (
+                    1,
+                    Some("payout_bank_invalid"),
+                    api::PayoutBatchItemStatus::Failed
+                ),
+                (
+                    2,
+                    Some("payout_bank_1"),
+                    api::PayoutBatchItemStatus::Succeeded
+                ),
+                (
+                    3,
+                    Some("payout_card_2"),
+                    api::PayoutBatchItemStatus::Succeeded
+                ),
+                (4, Some("payout_card_1"), api::PayoutBatchItemStatus::Failed),
+            ]
+        );
+
+        let bank_payout = results.get(2).unwrap().payout.as_ref().unwrap();
+        assert_eq!(bank_payout.payout_type, api_enums::PayoutType::Bank);
+        let invalid_payout = results.get(1).unwrap();
+        assert!(invalid_payout.payout.is_none());
+        assert_eq!(invalid_payout.error_code.as_deref(), Some("IR_04"));
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[test]
+    fn test_cancellation_reason_is_recorded_only_for_cancelled_payouts() {
+        let cancellation_reason = Some("requested_by_customer".to_string());
+
+        assert!(matches!(
+            get_cancellation_reason_update(
+                api_enums::PayoutStatus::Cancelled,
+                cancellation_reason.clone()
+            ),
+            Some(storage::PayoutAttemptUpdate::CancellationReasonUpdate {
+                cancellation_reason: Some(reason)
+            }) if reason == "requested_by_customer"
+        ));
+        // The connector rejected the cancellation
+        assert!(get_cancellation_reason_update(
+            api_enums::PayoutStatus::Failed,
+            cancellation_reason.clone()
+        )
+        .is_none());
+        assert!(get_cancellation_reason_update(
+            api_enums::PayoutStatus::Pending,
+            cancellation_reason
+        )
+        .is_none());
+        assert!(get_cancellation_reason_update(api_enums::PayoutStatus::Cancelled, None).is_none());
+    }
+}
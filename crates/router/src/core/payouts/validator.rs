@@ -2,6 +2,7 @@
 use common_utils::errors::CustomResult;
 use error_stack::{report, ResultExt};
 pub use hyperswitch_domain_models::errors::StorageError;
+use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
 use super::helpers;
@@ -155,3 +156,191 @@ pub(super) fn validate_payout_list_request_for_joins(
     })?;
     Ok(())
 }
+
+/// Validates the bank details of SEPA payouts, so that invalid details are rejected before the
+/// payout is sent to the connector
+pub fn validate_payout_method_data(
+    payout_method_data: &payouts::PayoutMethodData,
+) -> RouterResult<()> {
+    match payout_method_data {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(sepa_bank_transfer)) => {
+            validate_iban(sepa_bank_transfer.iban.peek())?;
+            sepa_bank_transfer
+                .bic
+                .as_ref()
+                .map(|bic| validate_bic(bic.peek()))
+                .transpose()?;
+            Ok(())
+        }
+        payouts::PayoutMethodData::Bank(_)
+        | payouts::PayoutMethodData::Card(_)
+        | payouts::PayoutMethodData::Wallet(_) => Ok(()),
+    }
+}
+
+/// Validates the structure of the IBAN and its mod-97 check digits (ISO 13616)
+fn validate_iban(iban: &str) -> RouterResult<()> {
+    let iban: String = iban
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let (country_code, check_digits, bban) = match (iban.get(..2), iban.get(2..4), iban.get(4..)) {
+        (Some(country_code), Some(check_digits), Some(bban)) if (15..=34).contains(&iban.len()) => {
+            (country_code, check_digits, bban)
+        }
+        _ => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_method_data.bank.iban must be between 15 and 34 characters long"
+                    .to_string(),
+            }
+            .into())
+        }
+    };
+
+    utils::when(
+        !country_code.chars().all(|c| c.is_ascii_uppercase())
+            || !check_digits.chars().all(|c| c.is_ascii_digit())
+            || !bban.chars().all(|c| c.is_ascii_alphanumeric()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_method_data.bank.iban must be a country code followed by check digits and an alphanumeric account number".to_string(),
+            }))
+        },
+    )?;
+
+    // The country code and check digits are moved to the end, and each letter is replaced with
+    // two digits (A = 10, ..., Z = 35), the resulting number of a valid IBAN leaves a remainder 1
+    let remainder = bban
+        .chars()
+        .chain(country_code.chars())
+        .chain(check_digits.chars())
+        .filter_map(|c| c.to_digit(36))
+        .fold(0, |remainder, value| {
+            let multiplier = if value < 10 { 10 } else { 100 };
+            (remainder * multiplier + value) % 97
+        });
+
+    utils::when(remainder != 1, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout_method_data.bank.iban has invalid check digits".to_string(),
+        }))
+    })
+}
+
+/// Validates the format of the BIC (ISO 9362), a 4 letter bank code, 2 letter country code, 2
+/// character location code and an optional 3 character branch code
+fn validate_bic(bic: &str) -> RouterResult<()> {
+    let is_valid = (bic.len() == 8 || bic.len() == 11)
+        && bic.chars().enumerate().all(|(position, c)| {
+            if position < 6 {
+                c.is_ascii_uppercase()
+            } else {
+                c.is_ascii_uppercase() || c.is_ascii_digit()
+            }
+        });
+
+    utils::when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout_method_data.bank.bic must be an 8 or 11 character BIC".to_string(),
+        }))
+    })
+}
+
+pub(super) fn validate_payout_cancellation_reason(
+    cancellation_reason: Option<&str>,
+) -> RouterResult<()> {
+    use crate::consts::PAYOUT_CANCELLATION_REASON_MAX_LENGTH;
+
+    utils::when(
+        cancellation_reason
+            .is_some_and(|reason| reason.chars().count() > PAYOUT_CANCELLATION_REASON_MAX_LENGTH),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "cancellation_reason must be at most {} characters long",
+                    PAYOUT_CANCELLATION_REASON_MAX_LENGTH
+                ),
+            }))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_sepa_payout_method_data(iban: &str, bic: Option<&str>) -> payouts::PayoutMethodData {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(payouts::SepaBankTransfer {
+            bank_name: None,
+            bank_country_code: None,
+            bank_city: None,
+            iban: Secret::new(iban.to_string()),
+            bic: bic.map(|bic| Secret::new(bic.to_string())),
+        }))
+    }
+
+    fn get_error_message(payout_method_data: &payouts::PayoutMethodData) -> String {
+        match validate_payout_method_data(payout_method_data)
+            .unwrap_err()
+            .current_context()
+        {
+            errors::ApiErrorResponse::InvalidRequestData { message } => message.clone(),
+            error => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_valid_iban_is_accepted() {
+        assert!(validate_payout_method_data(&get_sepa_payout_method_data(
+            "DE89370400440532013000",
+            Some("COBADEFFXXX")
+        ))
+        .is_ok());
+        // IBANs are commonly written in groups of four characters
+        assert!(validate_payout_method_data(&get_sepa_payout_method_data(
+            "NL91 ABNA 0417 1643 00",
+            Some("ABNANL2A")
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_iban_with_bad_checksum_is_rejected() {
+        let error_message = get_error_message(&get_sepa_payout_method_data(
+            "DE89370400440532013001",
+            Some("COBADEFFXXX"),
+        ));
+        assert!(error_message.contains("payout_method_data.bank.iban"));
+        assert!(error_message.contains("check digits"));
+    }
+
+    #[test]
+    fn test_malformed_bic_is_rejected() {
+        for bic in ["COBADEF", "COBA1EFFXXX", "COBADEFFXX"] {
+            let error_message = get_error_message(&get_sepa_payout_method_data(
+                "DE89370400440532013000",
+                Some(bic),
+            ));
+            assert!(error_message.contains("payout_method_data.bank.bic"));
+        }
+    }
+
+    #[test]
+    fn test_cancellation_reason_length_is_validated() {
+        assert!(validate_payout_cancellation_reason(None).is_ok());
+        assert!(validate_payout_cancellation_reason(Some("requested_by_customer")).is_ok());
+        assert!(validate_payout_cancellation_reason(Some(&"a".repeat(255))).is_ok());
+
+        let error = validate_payout_cancellation_reason(Some(&"a".repeat(256))).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.contains("cancellation_reason")
+        ));
+    }
+}
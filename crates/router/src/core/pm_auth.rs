@@ -25,7 +25,7 @@ use pm_auth::{
     types::{
         self as pm_auth_types,
         api::{
-            auth_service::{BankAccountCredentials, ExchangeToken, LinkToken},
+            auth_service::{BankAccountCredentials, ConnectionStatus, ExchangeToken, LinkToken},
             BoxedConnectorIntegration, PaymentAuthConnectorData,
         },
     },
@@ -51,7 +51,7 @@ use crate::{
         storage,
         transformers::ForeignTryFrom,
     },
-    utils::ext_traits::OptionExt,
+    utils::{self, ext_traits::OptionExt},
 };
 
 pub async fn create_link_token(
@@ -91,12 +91,6 @@ pub async fn create_link_token(
     let connector_name = selected_config.connector_name.as_str();
 
     let connector = PaymentAuthConnectorData::get_connector_by_name(connector_name)?;
-    let connector_integration: BoxedConnectorIntegration<
-        '_,
-        LinkToken,
-        pm_auth_types::LinkTokenRequest,
-        pm_auth_types::LinkTokenResponse,
-    > = connector.connector.get_connector_integration();
 
     let payment_intent = oss_helpers::verify_payment_intent_time_and_client_secret(
         &*state.store,
@@ -138,20 +132,56 @@ pub async fn create_link_token(
 
     let auth_type = helpers::get_connector_auth_type(merchant_connector_account)?;
 
+    let link_token_request = pm_auth_types::LinkTokenRequest {
+        client_name: "HyperSwitch".to_string(),
+        country_codes: Some(vec![billing_country.ok_or(
+            ApiErrorResponse::MissingRequiredField {
+                field_name: "billing_country",
+            },
+        )?]),
+        language: payload.language,
+        user_info: payment_intent.and_then(|pi| pi.customer_id),
+        access_token: None,
+    };
+
+    let link_token_resp = get_link_token_from_connector(
+        &connector,
+        &merchant_account.merchant_id,
+        connector_name,
+        link_token_request,
+        auth_type,
+        &state,
+    )
+    .await?;
+
+    let response = api_models::pm_auth::LinkTokenCreateResponse {
+        link_token: link_token_resp.link_token,
+        connector: connector.connector_name.to_string(),
+    };
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn get_link_token_from_connector(
+    connector: &PaymentAuthConnectorData,
+    merchant_id: &str,
+    connector_name: &str,
+    link_token_request: pm_auth_types::LinkTokenRequest,
+    auth_type: pm_auth_types::ConnectorAuthType,
+    state: &AppState,
+) -> RouterResult<pm_auth_types::LinkTokenResponse> {
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        LinkToken,
+        pm_auth_types::LinkTokenRequest,
+        pm_auth_types::LinkTokenResponse,
+    > = connector.connector.get_connector_integration();
+
     let router_data = pm_auth_types::LinkTokenRouterData {
         flow: std::marker::PhantomData,
-        merchant_id: Some(merchant_account.merchant_id),
+        merchant_id: Some(merchant_id.to_string()),
         connector: Some(connector_name.to_string()),
-        request: pm_auth_types::LinkTokenRequest {
-            client_name: "HyperSwitch".to_string(),
-            country_codes: Some(vec![billing_country.ok_or(
-                ApiErrorResponse::MissingRequiredField {
-                    field_name: "billing_country",
-                },
-            )?]),
-            language: payload.language,
-            user_info: payment_intent.and_then(|pi| pi.customer_id),
-        },
+        request: link_token_request,
         response: Ok(pm_auth_types::LinkTokenResponse {
             link_token: "".to_string(),
         }),
@@ -160,7 +190,7 @@ pub async fn create_link_token(
     };
 
     let connector_resp = pm_auth_services::execute_connector_processing_step(
-        state.as_ref(),
+        state,
         connector_integration,
         &router_data,
         &connector.connector_name,
@@ -180,12 +210,7 @@ pub async fn create_link_token(
                 reason: err.reason,
             })?;
 
-    let response = api_models::pm_auth::LinkTokenCreateResponse {
-        link_token: link_token_resp.link_token,
-        connector: connector.connector_name.to_string(),
-    };
-
-    Ok(ApplicationResponse::Json(response))
+    Ok(link_token_resp)
 }
 
 impl ForeignTryFrom<&types::ConnectorAuthType> for PlaidAuthType {
@@ -266,6 +291,144 @@ pub async fn exchange_token_core(
     Ok(ApplicationResponse::StatusOk)
 }
 
+pub async fn retrieve_connection_status(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payload: api_models::pm_auth::ConnectionStatusRetrieveRequest,
+) -> RouterResponse<api_models::pm_auth::ConnectionStatusRetrieveResponse> {
+    let db = &*state.store;
+    let key = key_store.key.get_inner().peek();
+
+    let payment_method = db
+        .find_payment_method(&payload.payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id,
+        || Err(ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    let bank_details_pm_data = decrypt::<serde_json::Value, masking::WithType>(
+        payment_method.payment_method_data.clone(),
+        key,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to decrypt bank account details")?
+    .map(|x| x.into_inner().expose())
+    .map(|v| {
+        serde_json::from_value::<payment_methods::PaymentMethodsData>(v)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize payment method data")
+    })
+    .transpose()?
+    .and_then(|pmd| match pmd {
+        payment_methods::PaymentMethodsData::BankDetails(bank_creds) => Some(bank_creds),
+        _ => None,
+    })
+    .ok_or(ApiErrorResponse::InvalidRequestData {
+        message: "payment method is not linked through a payment method auth connector".to_string(),
+    })?;
+
+    // The connector through which the account was linked most recently is listed first
+    let connector_details = bank_details_pm_data
+        .connector_details
+        .first()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment method auth connector details not found")?;
+
+    let connector = PaymentAuthConnectorData::get_connector_by_name(&connector_details.connector)?;
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            merchant_account.merchant_id.as_str(),
+            &connector_details.mca_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: connector_details.mca_id.clone(),
+        })?;
+
+    let auth_type = helpers::get_connector_auth_type(merchant_connector_account)?;
+
+    let BankAccountAccessCreds::AccessToken(access_token) = &connector_details.access_token;
+
+    let connection_status = get_connection_status_from_connector(
+        connector,
+        &merchant_account,
+        &connector_details.connector,
+        access_token,
+        auth_type,
+        &state,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        api_models::pm_auth::ConnectionStatusRetrieveResponse {
+            payment_method_id: payment_method.payment_method_id,
+            connector: connector_details.connector.clone(),
+            status: connection_status.status,
+            last_refreshed_at: connection_status.last_refreshed_at,
+        },
+    ))
+}
+
+async fn get_connection_status_from_connector(
+    connector: PaymentAuthConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    access_token: &Secret<String>,
+    auth_type: pm_auth_types::ConnectorAuthType,
+    state: &AppState,
+) -> RouterResult<pm_auth_types::ConnectionStatusResponse> {
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        ConnectionStatus,
+        pm_auth_types::ConnectionStatusRequest,
+        pm_auth_types::ConnectionStatusResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::ConnectionStatusRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        connector: Some(connector_name.to_string()),
+        request: pm_auth_types::ConnectionStatusRequest {
+            access_token: access_token.clone(),
+        },
+        response: Ok(pm_auth_types::ConnectionStatusResponse {
+            status: api_models::pm_auth::PaymentMethodAuthConnectionStatus::Active,
+            last_refreshed_at: None,
+        }),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling connection status connector api")?;
+
+    let connection_status_resp =
+        resp.response
+            .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.connector_name.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    Ok(connection_status_resp)
+}
+
 async fn store_bank_details_in_payment_methods(
     key_store: domain::MerchantKeyStore,
     payload: api_models::pm_auth::ExchangeTokenCreateRequest,
@@ -467,6 +630,7 @@ async fn store_bank_details_in_payment_methods(
                 network_transaction_id: None,
                 client_secret: None,
                 payment_method_billing_address: None,
+                display_order: None,
             };
 
             new_entries.push(pm_new);
@@ -525,6 +689,39 @@ pub async fn get_bank_account_creds(
     state: &AppState,
     bank_account_id: Option<Secret<String>>,
 ) -> RouterResult<pm_auth_types::BankAccountCredentialsResponse> {
+    let bank_details_resp = execute_bank_account_creds_flow(
+        &connector,
+        merchant_account,
+        connector_name,
+        access_token,
+        auth_type,
+        state,
+        bank_account_id,
+    )
+    .await?;
+
+    let bank_account_details_resp =
+        bank_details_resp.map_err(|err| ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })?;
+
+    Ok(bank_account_details_resp)
+}
+
+async fn execute_bank_account_creds_flow(
+    connector: &PaymentAuthConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    access_token: &Secret<String>,
+    auth_type: pm_auth_types::ConnectorAuthType,
+    state: &AppState,
+    bank_account_id: Option<Secret<String>>,
+) -> RouterResult<Result<pm_auth_types::BankAccountCredentialsResponse, pm_auth_types::ErrorResponse>>
+{
     let connector_integration_bank_details: BoxedConnectorIntegration<
         '_,
         BankAccountCredentials,
@@ -558,18 +755,37 @@ pub async fn get_bank_account_creds(
     .change_context(ApiErrorResponse::InternalServerError)
     .attach_printable("Failed while calling bank account details connector api")?;
 
-    let bank_account_details_resp =
-        bank_details_resp
-            .response
-            .map_err(|err| ApiErrorResponse::ExternalConnectorError {
-                code: err.code,
-                message: err.message,
-                connector: connector.connector_name.to_string(),
-                status_code: err.status_code,
-                reason: err.reason,
-            })?;
+    Ok(bank_details_resp.response)
+}
 
-    Ok(bank_account_details_resp)
+#[derive(Debug)]
+enum BankAccountCredsResult {
+    Valid(pm_auth_types::BankAccountCredentialsResponse),
+    ReauthenticationRequired,
+}
+
+fn get_bank_account_creds_result(
+    connector: &PaymentAuthConnectorData,
+    response: Result<pm_auth_types::BankAccountCredentialsResponse, pm_auth_types::ErrorResponse>,
+) -> RouterResult<BankAccountCredsResult> {
+    match response {
+        Ok(creds) => Ok(BankAccountCredsResult::Valid(creds)),
+        Err(err) if connector.connector.is_reauthentication_required(&err) => {
+            logger::info!(
+                error_code = %err.code,
+                "bank account access token has expired, re-authentication is required"
+            );
+            Ok(BankAccountCredsResult::ReauthenticationRequired)
+        }
+        Err(err) => Err(ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        }
+        .into()),
+    }
 }
 
 async fn get_access_token_from_exchange_api(
@@ -697,17 +913,68 @@ pub async fn retrieve_payment_method_from_auth_service(
     let BankAccountAccessCreds::AccessToken(access_token) =
         &auth_token.connector_details.access_token;
 
-    let bank_account_creds = get_bank_account_creds(
-        connector,
+    let address = oss_helpers::get_address_by_id(
+        &*state.store,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    let bank_details_resp = execute_bank_account_creds_flow(
+        &connector,
         &merchant_account,
         &auth_token.connector_details.connector,
         access_token,
-        auth_type,
+        auth_type.clone(),
         state,
         Some(auth_token.connector_details.account_id.clone()),
     )
     .await?;
 
+    let bank_account_creds = match get_bank_account_creds_result(&connector, bank_details_resp)? {
+        BankAccountCredsResult::Valid(creds) => creds,
+        BankAccountCredsResult::ReauthenticationRequired => {
+            let billing_country = address.as_ref().and_then(|addr| addr.country).ok_or(
+                ApiErrorResponse::MissingRequiredField {
+                    field_name: "billing_country",
+                },
+            )?;
+
+            let customer_id = payment_intent.customer_id.clone().ok_or(
+                ApiErrorResponse::MissingRequiredField {
+                    field_name: "customer_id",
+                },
+            )?;
+
+            let link_token_request = pm_auth_types::LinkTokenRequest {
+                client_name: "HyperSwitch".to_string(),
+                country_codes: Some(vec![billing_country.to_string()]),
+                language: None,
+                user_info: Some(customer_id),
+                access_token: Some(access_token.clone()),
+            };
+
+            let link_token_resp = get_link_token_from_connector(
+                &connector,
+                &merchant_account.merchant_id,
+                &auth_token.connector_details.connector,
+                link_token_request,
+                auth_type,
+                state,
+            )
+            .await?;
+
+            return Err(ApiErrorResponse::RequiresReauthentication {
+                connector: auth_token.connector_details.connector.clone(),
+                link_token: link_token_resp.link_token,
+            }
+            .into());
+        }
+    };
+
     let bank_account = bank_account_creds
         .credentials
         .iter()
@@ -725,16 +992,6 @@ pub async fn retrieve_payment_method_from_auth_service(
             .ok();
     }
 
-    let address = oss_helpers::get_address_by_id(
-        &*state.store,
-        payment_intent.billing_address_id.clone(),
-        key_store,
-        &payment_intent.payment_id,
-        &merchant_account.merchant_id,
-        merchant_account.storage_scheme,
-    )
-    .await?;
-
     let name = address
         .as_ref()
         .and_then(|addr| addr.first_name.clone().map(|name| name.into_inner()))
@@ -809,3 +1066,67 @@ pub async fn retrieve_payment_method_from_auth_service(
 
     Ok(Some((payment_method_data, enums::PaymentMethod::BankDebit)))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn get_error_response(code: &str) -> pm_auth_types::ErrorResponse {
+        pm_auth_types::ErrorResponse {
+            code: code.to_string(),
+            message: "the login details of this item have changed".to_string(),
+            reason: None,
+            status_code: 400,
+        }
+    }
+
+    #[test]
+    fn test_expired_access_token_requires_reauthentication() {
+        let connector =
+            PaymentAuthConnectorData::get_connector_by_name("plaid").expect("plaid connector");
+
+        let result = get_bank_account_creds_result(
+            &connector,
+            Err(get_error_response("ITEM_LOGIN_REQUIRED")),
+        )
+        .expect("reauthentication result");
+
+        assert!(matches!(
+            result,
+            BankAccountCredsResult::ReauthenticationRequired
+        ));
+    }
+
+    #[test]
+    fn test_valid_access_token_returns_credentials() {
+        let connector =
+            PaymentAuthConnectorData::get_connector_by_name("plaid").expect("plaid connector");
+
+        let result = get_bank_account_creds_result(
+            &connector,
+            Ok(pm_auth_types::BankAccountCredentialsResponse {
+                credentials: Vec::new(),
+            }),
+        )
+        .expect("valid result");
+
+        assert!(matches!(result, BankAccountCredsResult::Valid(_)));
+    }
+
+    #[test]
+    fn test_other_connector_errors_are_not_reauthentication() {
+        let connector =
+            PaymentAuthConnectorData::get_connector_by_name("plaid").expect("plaid connector");
+
+        let error =
+            get_bank_account_creds_result(&connector, Err(get_error_response("INVALID_FIELD")))
+                .expect_err("connector error");
+
+        assert!(matches!(
+            error.current_context(),
+            ApiErrorResponse::ExternalConnectorError { .. }
+        ));
+    }
+}
@@ -1,10 +1,20 @@
-use api_models::poll::PollResponse;
+use api_models::poll::{PollProgressResponse, PollResponse, PollStatus};
 use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use super::errors;
-use crate::{core::errors::RouterResponse, services::ApplicationResponse, types::domain, AppState};
+use crate::{
+    consts,
+    core::errors::{RouterResponse, RouterResult, StorageErrorExt},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+        PollTracker,
+    },
+    AppState,
+};
 
 #[instrument(skip_all)]
 pub async fn retrieve_poll_status(
@@ -37,9 +47,460 @@ pub async fn retrieve_poll_status(
         .parse_enum("PollStatus")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error while parsing PollStatus")?;
+    let status = match status {
+        PollStatus::Pending => {
+            record_pending_poll(&state, &redis_conn, &poll_id, &merchant_account).await?
+        }
+        status => status,
+    };
     let poll_response = PollResponse {
         poll_id: request_poll_id,
         status,
     };
     Ok(ApplicationResponse::Json(poll_response))
 }
+
+#[instrument(skip_all)]
+pub async fn retrieve_poll_progress(
+    state: AppState,
+    req: crate::types::api::PollProgressRequest,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<PollProgressResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let poll_id = super::utils::get_poll_id(
+        merchant_account.merchant_id,
+        super::utils::get_external_authentication_request_poll_id(&req.payment_id),
+    );
+    let status = redis_conn
+        .get_key::<Option<String>>(poll_id.as_str())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Error while fetching the value for {poll_id} from redis")
+        })?
+        .map(|status| {
+            status
+                .parse_enum("PollStatus")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error while parsing PollStatus")
+        })
+        .transpose()?;
+    let poll_progress = match status {
+        Some(_) => get_recorded_poll_progress(&redis_conn, &poll_id).await?,
+        None => None,
+    };
+    Ok(ApplicationResponse::Json(get_poll_progress(
+        req.payment_id,
+        status,
+        poll_progress.as_ref(),
+    )))
+}
+
+/// Tracker of a poll along with the polls recorded for it so far
+struct RecordedPollProgress {
+    poll_tracker: PollTracker,
+    poll_count: u16,
+    last_polled_at: Option<time::PrimitiveDateTime>,
+}
+
+async fn get_recorded_poll_progress(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    poll_id: &str,
+) -> RouterResult<Option<RecordedPollProgress>> {
+    let poll_tracker = match redis_conn
+        .get_and_deserialize_key::<PollTracker>(
+            &PollTracker::get_poll_tracker_key(poll_id),
+            "PollTracker",
+        )
+        .await
+    {
+        Ok(poll_tracker) => poll_tracker,
+        Err(error) => {
+            logger::warn!(?error, "Poll tracker not found for {poll_id}");
+            return Ok(None);
+        }
+    };
+    let poll_count = redis_conn
+        .get_key::<Option<i64>>(&PollTracker::get_poll_count_key(poll_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the poll count from redis")?
+        .map_or(0, |poll_count| {
+            u16::try_from(poll_count).unwrap_or(u16::MAX)
+        });
+    let last_polled_at = redis_conn
+        .get_key::<Option<i64>>(&PollTracker::get_last_polled_at_key(poll_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the last poll time from redis")?
+        .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        .map(|polled_at| time::PrimitiveDateTime::new(polled_at.date(), polled_at.time()));
+
+    Ok(Some(RecordedPollProgress {
+        poll_tracker,
+        poll_count,
+        last_polled_at,
+    }))
+}
+
+fn get_poll_progress(
+    payment_id: String,
+    status: Option<PollStatus>,
+    poll_progress: Option<&RecordedPollProgress>,
+) -> PollProgressResponse {
+    let Some(status) = status else {
+        return PollProgressResponse {
+            payment_id,
+            status: PollStatus::NotFound,
+            attempts_made: 0,
+            max_attempts: 0,
+            next_poll_at: None,
+        };
+    };
+    let next_poll_at = match status {
+        PollStatus::Pending => poll_progress.and_then(|poll_progress| {
+            poll_progress
+                .poll_tracker
+                .get_next_poll_at(poll_progress.poll_count, poll_progress.last_polled_at)
+        }),
+        PollStatus::Completed | PollStatus::NotFound | PollStatus::Exhausted => None,
+    };
+    let max_attempts =
+        poll_progress.map_or(0, |poll_progress| poll_progress.poll_tracker.max_poll_count);
+    // Polls made after the allowed ones, such as the one that exhausted the poll, are still counted
+    // in redis but are not reported as attempts
+    PollProgressResponse {
+        payment_id,
+        status,
+        attempts_made: poll_progress
+            .map_or(0, |poll_progress| poll_progress.poll_count)
+            .min(max_attempts),
+        max_attempts,
+        next_poll_at,
+    }
+}
+
+/// Counts a poll that is still pending and marks the poll as exhausted once more than
+/// `max_poll_count` polls have been made for it
+async fn record_pending_poll(
+    state: &AppState,
+    redis_conn: &redis_interface::RedisConnectionPool,
+    poll_id: &str,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<PollStatus> {
+    let poll_tracker = match redis_conn
+        .get_and_deserialize_key::<PollTracker>(
+            &PollTracker::get_poll_tracker_key(poll_id),
+            "PollTracker",
+        )
+        .await
+    {
+        Ok(poll_tracker) => poll_tracker,
+        // Polls created without a tracker are not capped
+        Err(error) => {
+            logger::warn!(?error, "Poll tracker not found for {poll_id}");
+            return Ok(PollStatus::Pending);
+        }
+    };
+    let poll_count_key = PollTracker::get_poll_count_key(poll_id);
+    let poll_count = redis_conn
+        .increment_key(&poll_count_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment the poll count in redis")?;
+    if poll_count == 1 {
+        redis_conn
+            .set_expiry(&poll_count_key, poll_tracker.get_poll_ttl_in_secs())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry for the poll count in redis")?;
+    }
+
+    if !poll_tracker.is_exhausted(poll_count) {
+        redis_conn
+            .set_key_with_expiry(
+                &PollTracker::get_last_polled_at_key(poll_id),
+                common_utils::date_time::now().assume_utc().unix_timestamp(),
+                poll_tracker.get_poll_ttl_in_secs(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the poll time in redis")?;
+
+        return Ok(PollStatus::Pending);
+    }
+
+    logger::info!(
+        poll_count,
+        max_poll_count = poll_tracker.max_poll_count,
+        poll_interval_secs = poll_tracker.poll_interval_secs,
+        "Poll exhausted for {poll_id}"
+    );
+
+    redis_conn
+        .set_key_without_modifying_ttl(poll_id, PollStatus::Exhausted.to_string())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark poll as exhausted in redis")?;
+
+    fail_payment_on_poll_exhaustion(state, merchant_account, &poll_tracker.payment_id).await?;
+
+    Ok(PollStatus::Exhausted)
+}
+
+async fn fail_payment_on_poll_exhaustion(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // The payment might have reached a final state after the last poll, e.g. through a webhook
+    if payment_intent.status != storage_enums::IntentStatus::RequiresCustomerAction {
+        return Ok(());
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &payment_intent.active_attempt.get_id(),
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        get_poll_exhausted_attempt_update(storage_scheme),
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: storage_enums::IntentStatus::Failed,
+            incremental_authorization_allowed: Some(false),
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(())
+}
+
+fn get_poll_exhausted_attempt_update(
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> storage::PaymentAttemptUpdate {
+    storage::PaymentAttemptUpdate::ErrorUpdate {
+        connector: None,
+        status: storage_enums::AttemptStatus::AuthenticationFailed,
+        error_code: Some(Some(consts::POLL_EXHAUSTED_ERROR_REASON.to_string())),
+        error_message: Some(Some(consts::POLL_EXHAUSTED_ERROR_MESSAGE.to_string())),
+        error_reason: Some(Some(consts::POLL_EXHAUSTED_ERROR_REASON.to_string())),
+        amount_capturable: Some(0),
+        updated_by: storage_scheme.to_string(),
+        unified_code: None,
+        unified_message: None,
+        connector_transaction_id: None,
+        payment_method_data: None,
+        connector_latency: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::types::PollConfig;
+
+    #[test]
+    fn test_poll_tracker_is_exhausted_once_poll_count_exceeds_cap() {
+        let poll_config = PollConfig {
+            max_poll_count: 3,
+            poll_interval_secs: 2,
+            ..PollConfig::default()
+        };
+        let poll_tracker = PollTracker::new("pay_123".to_string(), &poll_config);
+
+        assert!(!poll_tracker.is_exhausted(1));
+        assert!(!poll_tracker.is_exhausted(3));
+        assert!(poll_tracker.is_exhausted(4));
+    }
+
+    #[test]
+    fn test_poll_progress_for_in_progress_poll() {
+        let poll_config = PollConfig {
+            max_poll_count: 5,
+            poll_interval_secs: 3,
+            ..PollConfig::default()
+        };
+        let poll_tracker = PollTracker::new("pay_123".to_string(), &poll_config);
+        let polled_at = poll_tracker.created_at + time::Duration::seconds(3);
+        let recorded_poll_progress = RecordedPollProgress {
+            poll_tracker,
+            poll_count: 2,
+            last_polled_at: Some(polled_at),
+        };
+
+        let poll_progress = get_poll_progress(
+            "pay_123".to_string(),
+            Some(PollStatus::Pending),
+            Some(&recorded_poll_progress),
+        );
+
+        assert!(matches!(poll_progress.status, PollStatus::Pending));
+        assert_eq!(poll_progress.attempts_made, 2);
+        assert_eq!(poll_progress.max_attempts, 5);
+        assert_eq!(
+            poll_progress.next_poll_at,
+            Some(polled_at + time::Duration::seconds(3))
+        );
+    }
+
+    #[test]
+    fn test_next_poll_at_ends_with_poll_cap() {
+        let poll_config = PollConfig {
+            max_poll_count: 3,
+            poll_interval_secs: 2,
+            ..PollConfig::default()
+        };
+        let poll_tracker = PollTracker::new("pay_123".to_string(), &poll_config);
+        let polled_at = poll_tracker.created_at + time::Duration::seconds(3);
+
+        // A next poll is advertised only while it is still within the cap
+        assert_eq!(
+            poll_tracker.get_next_poll_at(0, None),
+            Some(poll_tracker.created_at + time::Duration::seconds(2))
+        );
+        assert_eq!(
+            poll_tracker.get_next_poll_at(2, Some(polled_at)),
+            Some(polled_at + time::Duration::seconds(2))
+        );
+        assert_eq!(poll_tracker.get_next_poll_at(3, Some(polled_at)), None);
+    }
+
+    #[test]
+    fn test_poll_progress_once_poll_cap_is_reached() {
+        let poll_config = PollConfig {
+            max_poll_count: 3,
+            poll_interval_secs: 2,
+            ..PollConfig::default()
+        };
+        let recorded_poll_progress = RecordedPollProgress {
+            poll_tracker: PollTracker::new("pay_123".to_string(), &poll_config),
+            poll_count: 3,
+            last_polled_at: None,
+        };
+
+        let poll_progress = get_poll_progress(
+            "pay_123".to_string(),
+            Some(PollStatus::Pending),
+            Some(&recorded_poll_progress),
+        );
+
+        assert_eq!(poll_progress.attempts_made, poll_progress.max_attempts);
+        assert_eq!(poll_progress.next_poll_at, None);
+    }
+
+    #[test]
+    fn test_attempts_made_never_exceed_max_attempts() {
+        let poll_config = PollConfig {
+            max_poll_count: 3,
+            poll_interval_secs: 2,
+            ..PollConfig::default()
+        };
+        // The fourth poll exhausted the poll, and clients kept polling after it
+        let recorded_poll_progress = RecordedPollProgress {
+            poll_tracker: PollTracker::new("pay_123".to_string(), &poll_config),
+            poll_count: 6,
+            last_polled_at: None,
+        };
+
+        let poll_progress = get_poll_progress(
+            "pay_123".to_string(),
+            Some(PollStatus::Exhausted),
+            Some(&recorded_poll_progress),
+        );
+
+        assert!(matches!(poll_progress.status, PollStatus::Exhausted));
+        assert_eq!(poll_progress.attempts_made, 3);
+        assert_eq!(poll_progress.max_attempts, 3);
+        assert_eq!(poll_progress.next_poll_at, None);
+    }
+
+    #[test]
+    fn test_poll_progress_without_active_poll() {
+        let poll_progress = get_poll_progress("pay_123".to_string(), None, None);
+
+        assert!(matches!(poll_progress.status, PollStatus::NotFound));
+        assert_eq!(poll_progress.attempts_made, 0);
+        assert_eq!(poll_progress.max_attempts, 0);
+        assert_eq!(poll_progress.next_poll_at, None);
+    }
+
+    #[test]
+    fn test_poll_progress_has_no_next_poll_once_completed() {
+        let recorded_poll_progress = RecordedPollProgress {
+            poll_tracker: PollTracker::new("pay_123".to_string(), &PollConfig::default()),
+            poll_count: 1,
+            last_polled_at: None,
+        };
+
+        let poll_progress = get_poll_progress(
+            "pay_123".to_string(),
+            Some(PollStatus::Completed),
+            Some(&recorded_poll_progress),
+        );
+
+        assert!(matches!(poll_progress.status, PollStatus::Completed));
+        assert_eq!(poll_progress.next_poll_at, None);
+    }
+
+    #[test]
+    fn test_poll_config_defaults_when_cap_is_not_configured() {
+        let poll_config: PollConfig = r#"{"delay_in_secs":2,"frequency":5}"#
+            .to_string()
+            .parse_struct("PollConfig")
+            .expect("poll config without a cap");
+
+        assert_eq!(poll_config.max_poll_count, consts::DEFAULT_MAX_POLL_COUNT);
+        assert_eq!(
+            poll_config.poll_interval_secs,
+            consts::DEFAULT_POLL_INTERVAL_IN_SECS
+        );
+    }
+
+    #[test]
+    fn test_poll_exhausted_attempt_update_sets_terminal_status() {
+        let attempt_update =
+            get_poll_exhausted_attempt_update(storage_enums::MerchantStorageScheme::PostgresOnly);
+
+        assert!(matches!(
+            attempt_update,
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                status: storage_enums::AttemptStatus::AuthenticationFailed,
+                error_reason: Some(Some(ref reason)),
+                ..
+            } if reason == consts::POLL_EXHAUSTED_ERROR_REASON
+        ));
+    }
+}
@@ -0,0 +1,654 @@
+use std::collections::{btree_map::Entry, BTreeMap, HashMap};
+
+use api_models::{payments::TimeRange, recon as recon_api};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    routes::AppState,
+    services::api as service_api,
+    types::{api::enums, domain, storage},
+    utils::{self, OptionExt},
+};
+
+/// Every payment in the time range of the report is loaded to be compared with the settlement
+/// records, so the time range is limited to bound the number of payments loaded
+const RECON_REPORT_MAX_TIME_RANGE_DAYS: i64 = 31;
+
+#[instrument(skip_all)]
+pub async fn generate_recon_difference_report(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: recon_api::ReconDifferenceReportRequest,
+) -> RouterResponse<recon_api::ReconDifferenceReportResponse> {
+    let end_time = validate_recon_report_time_range(&req.time_range)?;
+
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: Some(req.time_range.start_time),
+        ending_at: Some(end_time),
+        amount_filter: None,
+        connector: Some(vec![req.connector]),
+        currency: None,
+        status: Some(vec![
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::PartiallyCaptured,
+        ]),
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: None,
+    }));
+
+    let recorded_payments = state
+        .store
+        .get_filtered_payment_intents_attempt(
+            &merchant_account.merchant_id,
+            &constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payments for the reconciliation report")?;
+
+    let recorded_payments = recorded_payments
+        .iter()
+        .map(|(payment_intent, payment_attempt)| RecordedPayment {
+            payment_id: payment_intent.payment_id.clone(),
+            connector_transaction_id: payment_attempt.connector_transaction_id.clone(),
+            amount: payment_intent
+                .amount_captured
+                .unwrap_or(payment_attempt.amount),
+            currency: payment_attempt.currency.or(payment_intent.currency),
+        })
+        .collect::<Vec<_>>();
+
+    let mismatches = get_recon_mismatches(
+        &recorded_payments,
+        req.settlement_records,
+        req.connector.to_string(),
+    );
+    let mismatches = apply_recon_entry_statuses(
+        &*state.store,
+        &merchant_account.merchant_id,
+        mismatches,
+        req.include_resolved,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        recon_api::ReconDifferenceReportResponse {
+            count: mismatches.len(),
+            mismatches,
+        },
+    ))
+}
+
+/// The time range of the report must have an end, and span at most
+/// `RECON_REPORT_MAX_TIME_RANGE_DAYS` days
+fn validate_recon_report_time_range(
+    time_range: &TimeRange,
+) -> RouterResult<time::PrimitiveDateTime> {
+    let end_time = time_range.end_time.get_required_value("end_time")?;
+
+    utils::when(end_time < time_range.start_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_time must not be before start_time".to_string(),
+        }))
+    })?;
+    utils::when(
+        end_time - time_range.start_time > time::Duration::days(RECON_REPORT_MAX_TIME_RANGE_DAYS),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The time range of the report must not exceed {} days",
+                    RECON_REPORT_MAX_TIME_RANGE_DAYS
+                ),
+            }))
+        },
+    )?;
+
+    Ok(end_time)
+}
+
+#[derive(Debug)]
+struct RecordedPayment {
+    payment_id: String,
+    connector_transaction_id: Option<String>,
+    amount: i64,
+    currency: Option<enums::Currency>,
+}
+
+#[instrument(skip_all)]
+pub async fn mark_recon_entry_as_resolved(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    req: recon_api::ReconEntryResolveRequest,
+) -> RouterResponse<recon_api::ReconEntryResponse> {
+    let recon_entry =
+        resolve_recon_entry_in_db(&*state.store, &merchant_account.merchant_id, user_id, req)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while marking the recon entry as resolved")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        recon_api::ReconEntryResponse {
+            entry_id: recon_entry.entry_id,
+            connector: recon_entry.connector,
+            status: recon_entry.status,
+            recorded_amount: recon_entry.recorded_amount,
+            settled_amount: recon_entry.settled_amount,
+            note: recon_entry.note,
+            resolved_by: recon_entry.resolved_by,
+            modified_at: recon_entry.modified_at,
+        },
+    ))
+}
+
+/// Recon entries are only stored once they are resolved, so an entry is created if it is being
+/// resolved for the first time. The amounts of the mismatch are stored along with the entry, so
+/// that the entry is listed again if the mismatch changes after it was resolved.
+async fn resolve_recon_entry_in_db(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    user_id: String,
+    req: recon_api::ReconEntryResolveRequest,
+) -> errors::CustomResult<storage::ReconEntry, errors::StorageError> {
+    let now = common_utils::date_time::now();
+    db.upsert_recon_entry(storage::ReconEntryNew {
+        merchant_id: merchant_id.to_string(),
+        connector: req.connector,
+        entry_id: req.entry_id,
+        status: enums::ReconEntryStatus::Resolved,
+        note: req.note,
+        resolved_by: Some(user_id),
+        recorded_amount: req.recorded_amount,
+        settled_amount: req.settled_amount,
+        created_at: now,
+        modified_at: now,
+    })
+    .await
+}
+
+/// Marks the mismatches whose recon entries were resolved with the same amounts, leaving them out
+/// of the report unless `include_resolved` is set
+async fn apply_recon_entry_statuses(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    mismatches: Vec<recon_api::ReconMismatch>,
+    include_resolved: bool,
+) -> RouterResult<Vec<recon_api::ReconMismatch>> {
+    if mismatches.is_empty() {
+        return Ok(mismatches);
+    }
+
+    let entry_ids = mismatches
+        .iter()
+        .map(|mismatch| mismatch.entry_id.clone())
+        .collect();
+    let resolved_entries = db
+        .find_recon_entries_by_merchant_id_entry_ids(merchant_id, entry_ids)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch recon entries")?
+        .into_iter()
+        .filter(|recon_entry| recon_entry.status == enums::ReconEntryStatus::Resolved)
+        .map(|recon_entry| {
+            (
+                (recon_entry.connector.clone(), recon_entry.entry_id.clone()),
+                recon_entry,
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    Ok(mismatches
+        .into_iter()
+        .map(|mismatch| {
+            let is_resolved = resolved_entries
+                .get(&(mismatch.connector.clone(), mismatch.entry_id.clone()))
+                .is_some_and(|recon_entry| {
+                    recon_entry.recorded_amount == mismatch.recorded_amount
+                        && recon_entry.settled_amount == mismatch.settled_amount
+                });
+
+            if is_resolved {
+                recon_api::ReconMismatch {
+                    status: enums::ReconEntryStatus::Resolved,
+                    ..mismatch
+                }
+            } else {
+                mismatch
+            }
+        })
+        .filter(|mismatch| include_resolved || mismatch.status != enums::ReconEntryStatus::Resolved)
+        .collect())
+}
+
+/// Compares the settlements reported by the connector with the recorded payments. Settlements for
+/// the same transaction in the same currency are added up before comparing.
+fn get_recon_mismatches(
+    recorded_payments: &[RecordedPayment],
+    settlement_records: Vec<recon_api::SettlementRecord>,
+    connector: String,
+) -> Vec<recon_api::ReconMismatch> {
+    let mut mismatches = Vec::new();
+    let mut settlements: BTreeMap<String, recon_api::SettlementRecord> = BTreeMap::new();
+
+    for record in settlement_records {
+        match settlements.entry(record.connector_transaction_id.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(record);
+            }
+            Entry::Occupied(mut entry) if entry.get().currency == record.currency => {
+                let settlement = entry.get_mut();
+                settlement.settled_amount = settlement
+                    .settled_amount
+                    .saturating_add(record.settled_amount);
+            }
+            // Settlements in a different currency can't be added up, so they are reported as is
+            Entry::Occupied(_) => {
+                mismatches.push(get_extra_settlement_mismatch(record, connector.clone()));
+            }
+        }
+    }
+
+    for recorded_payment in recorded_payments {
+        let recorded_amount = recorded_payment.amount;
+        let recorded_currency = recorded_payment.currency;
+        let settlement = recorded_payment
+            .connector_transaction_id
+            .as_ref()
+            .and_then(|connector_transaction_id| settlements.remove(connector_transaction_id));
+
+        let (delta, reason) = match &settlement {
+            None => (
+                Some(recorded_amount.saturating_neg()),
+                recon_api::ReconMismatchReason::Missing,
+            ),
+            Some(settlement) if Some(settlement.currency) != recorded_currency => {
+                (None, recon_api::ReconMismatchReason::Fx)
+            }
+            Some(settlement) if settlement.settled_amount != recorded_amount => (
+                Some(settlement.settled_amount.saturating_sub(recorded_amount)),
+                recon_api::ReconMismatchReason::Fee,
+            ),
+            Some(_) => continue,
+        };
+
+        mismatches.push(recon_api::ReconMismatch {
+            entry_id: recorded_payment
+                .connector_transaction_id
+                .clone()
+                .unwrap_or_else(|| recorded_payment.payment_id.clone()),
+            status: enums::ReconEntryStatus::Unresolved,
+            connector: connector.clone(),
+            payment_id: Some(recorded_payment.payment_id.clone()),
+            connector_transaction_id: recorded_payment.connector_transaction_id.clone(),
+            recorded_amount: Some(recorded_amount),
+            recorded_currency,
+            settled_amount: settlement
+                .as_ref()
+                .map(|settlement| settlement.settled_amount),
+            settled_currency: settlement.as_ref().map(|settlement| settlement.currency),
+            delta,
+            reason,
+        });
+    }
+
+    mismatches.extend(
+        settlements
+            .into_values()
+            .map(|record| get_extra_settlement_mismatch(record, connector.clone())),
+    );
+
+    mismatches
+}
+
+fn get_extra_settlement_mismatch(
+    record: recon_api::SettlementRecord,
+    connector: String,
+) -> recon_api::ReconMismatch {
+    recon_api::ReconMismatch {
+        entry_id: record.connector_transaction_id.clone(),
+        status: enums::ReconEntryStatus::Unresolved,
+        connector,
+        payment_id: None,
+        connector_transaction_id: Some(record.connector_transaction_id),
+        recorded_amount: None,
+        recorded_currency: None,
+        settled_amount: Some(record.settled_amount),
+        settled_currency: Some(record.currency),
+        delta: Some(record.settled_amount),
+        reason: recon_api::ReconMismatchReason::Extra,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_recorded_payment(
+        payment_id: &str,
+        connector_transaction_id: Option<&str>,
+        amount: i64,
+    ) -> RecordedPayment {
+        RecordedPayment {
+            payment_id: payment_id.to_string(),
+            connector_transaction_id: connector_transaction_id.map(ToString::to_string),
+            amount,
+            currency: Some(enums::Currency::USD),
+        }
+    }
+
+    fn get_settlement_record(
+        connector_transaction_id: &str,
+        settled_amount: i64,
+        currency: enums::Currency,
+    ) -> recon_api::SettlementRecord {
+        recon_api::SettlementRecord {
+            connector_transaction_id: connector_transaction_id.to_string(),
+            settled_amount,
+            currency,
+        }
+    }
+
+    #[test]
+    fn test_recon_report_time_range_is_bounded() {
+        let start_time = common_utils::date_time::now();
+        let get_time_range = |end_time| TimeRange {
+            start_time,
+            end_time,
+        };
+
+        assert_eq!(
+            validate_recon_report_time_range(&get_time_range(Some(
+                start_time + time::Duration::days(31)
+            )))
+            .unwrap(),
+            start_time + time::Duration::days(31)
+        );
+        assert!(matches!(
+            validate_recon_report_time_range(&get_time_range(None))
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "end_time"
+            }
+        ));
+        for end_time in [
+            start_time + time::Duration::days(32),
+            start_time - time::Duration::days(1),
+        ] {
+            assert!(matches!(
+                validate_recon_report_time_range(&get_time_range(Some(end_time)))
+                    .unwrap_err()
+                    .current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_recon_mismatches_over_settlement_fixture() {
+        let recorded_payments = vec![
+            get_recorded_payment("pay_matched", Some("txn_matched"), 1000),
+            get_recorded_payment("pay_fee", Some("txn_fee"), 2000),
+            get_recorded_payment("pay_fx", Some("txn_fx"), 3000),
+            get_recorded_payment("pay_missing", Some("txn_missing"), 4000),
+        ];
+        let settlement_records = vec![
+            get_settlement_record("txn_matched", 1000, enums::Currency::USD),
+            get_settlement_record("txn_fee", 1940, enums::Currency::USD),
+            get_settlement_record("txn_fx", 2750, enums::Currency::EUR),
+            get_settlement_record("txn_extra", 500, enums::Currency::USD),
+        ];
+
+        let mismatches =
+            get_recon_mismatches(&recorded_payments, settlement_records, "stripe".to_string());
+
+        assert_eq!(
+            mismatches,
+            vec![
+                recon_api::ReconMismatch {
+                    entry_id: "txn_fee".to_string(),
+                    status: enums::ReconEntryStatus::Unresolved,
+                    connector: "stripe".to_string(),
+                    payment_id: Some("pay_fee".to_string()),
+                    connector_transaction_id: Some("txn_fee".to_string()),
+                    recorded_amount: Some(2000),
+                    recorded_currency: Some(enums::Currency::USD),
+                    settled_amount: Some(1940),
+                    settled_currency: Some(enums::Currency::USD),
+                    delta: Some(-60),
+                    reason: recon_api::ReconMismatchReason::Fee,
+                },
+                recon_api::ReconMismatch {
+                    entry_id: "txn_fx".to_string(),
+                    status: enums::ReconEntryStatus::Unresolved,
+                    connector: "stripe".to_string(),
+                    payment_id: Some("pay_fx".to_string()),
+                    connector_transaction_id: Some("txn_fx".to_string()),
+                    recorded_amount: Some(3000),
+                    recorded_currency: Some(enums::Currency::USD),
+                    settled_amount: Some(2750),
+                    settled_currency: Some(enums::Currency::EUR),
+                    delta: None,
+                    reason: recon_api::ReconMismatchReason::Fx,
+                },
+                recon_api::ReconMismatch {
+                    entry_id: "txn_missing".to_string(),
+                    status: enums::ReconEntryStatus::Unresolved,
+                    connector: "stripe".to_string(),
+                    payment_id: Some("pay_missing".to_string()),
+                    connector_transaction_id: Some("txn_missing".to_string()),
+                    recorded_amount: Some(4000),
+                    recorded_currency: Some(enums::Currency::USD),
+                    settled_amount: None,
+                    settled_currency: None,
+                    delta: Some(-4000),
+                    reason: recon_api::ReconMismatchReason::Missing,
+                },
+                recon_api::ReconMismatch {
+                    entry_id: "txn_extra".to_string(),
+                    status: enums::ReconEntryStatus::Unresolved,
+                    connector: "stripe".to_string(),
+                    payment_id: None,
+                    connector_transaction_id: Some("txn_extra".to_string()),
+                    recorded_amount: None,
+                    recorded_currency: None,
+                    settled_amount: Some(500),
+                    settled_currency: Some(enums::Currency::USD),
+                    delta: Some(500),
+                    reason: recon_api::ReconMismatchReason::Extra,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recon_mismatches_add_up_partial_settlements() {
+        let recorded_payments = vec![get_recorded_payment("pay_split", Some("txn_split"), 1000)];
+        let settlement_records = vec![
+            get_settlement_record("txn_split", 600, enums::Currency::USD),
+            get_settlement_record("txn_split", 400, enums::Currency::USD),
+        ];
+
+        let mismatches =
+            get_recon_mismatches(&recorded_payments, settlement_records, "stripe".to_string());
+
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_recon_mismatches_for_payment_without_connector_transaction_id() {
+        let recorded_payments = vec![get_recorded_payment("pay_no_txn", None, 1500)];
+
+        let mismatches = get_recon_mismatches(&recorded_payments, Vec::new(), "stripe".to_string());
+
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches.iter().all(|mismatch| mismatch.reason
+            == recon_api::ReconMismatchReason::Missing
+            && mismatch.delta == Some(-1500)));
+    }
+
+    fn get_fixture_mismatches() -> Vec<recon_api::ReconMismatch> {
+        let recorded_payments = vec![
+            get_recorded_payment("pay_fee", Some("txn_fee"), 2000),
+            get_recorded_payment("pay_missing", Some("txn_missing"), 4000),
+        ];
+        let settlement_records = vec![get_settlement_record("txn_fee", 1940, enums::Currency::USD)];
+
+        get_recon_mismatches(&recorded_payments, settlement_records, "stripe".to_string())
+    }
+
+    fn get_resolve_request(
+        connector: &str,
+        settled_amount: i64,
+        note: Option<&str>,
+    ) -> recon_api::ReconEntryResolveRequest {
+        recon_api::ReconEntryResolveRequest {
+            entry_id: "txn_fee".to_string(),
+            connector: connector.to_string(),
+            recorded_amount: Some(2000),
+            settled_amount: Some(settled_amount),
+            note: note.map(ToString::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolved_recon_entries_are_excluded_by_default() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+
+        let recon_entry = resolve_recon_entry_in_db(
+            &db,
+            "merchant_1",
+            "user_1".to_string(),
+            get_resolve_request(
+                "stripe",
+                1940,
+                Some("Processing fee charged by the connector"),
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(recon_entry.status, enums::ReconEntryStatus::Resolved);
+        assert_eq!(recon_entry.resolved_by.as_deref(), Some("user_1"));
+
+        let mismatches =
+            apply_recon_entry_statuses(&db, "merchant_1", get_fixture_mismatches(), false)
+                .await
+                .unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches
+            .iter()
+            .all(|mismatch| mismatch.entry_id == "txn_missing"
+                && mismatch.status == enums::ReconEntryStatus::Unresolved));
+    }
+
+    #[tokio::test]
+    async fn test_resolved_recon_entries_are_listed_when_requested() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+
+        resolve_recon_entry_in_db(
+            &db,
+            "merchant_1",
+            "user_1".to_string(),
+            get_resolve_request("stripe", 1940, None),
+        )
+        .await
+        .unwrap();
+
+        let mismatches =
+            apply_recon_entry_statuses(&db, "merchant_1", get_fixture_mismatches(), true)
+                .await
+                .unwrap();
+        let statuses = mismatches
+            .iter()
+            .map(|mismatch| (mismatch.entry_id.as_str(), mismatch.status))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statuses,
+            vec![
+                ("txn_fee", enums::ReconEntryStatus::Resolved),
+                ("txn_missing", enums::ReconEntryStatus::Unresolved),
+            ]
+        );
+
+        // Entries resolved for another merchant don't affect the report
+        let mismatches =
+            apply_recon_entry_statuses(&db, "merchant_2", get_fixture_mismatches(), false)
+                .await
+                .unwrap();
+        assert_eq!(mismatches.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolved_recon_entries_are_listed_again_when_the_mismatch_changes() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+
+        // Resolved when a smaller amount was settled
+        resolve_recon_entry_in_db(
+            &db,
+            "merchant_1",
+            "user_1".to_string(),
+            get_resolve_request("stripe", 1900, None),
+        )
+        .await
+        .unwrap();
+        // An entry with the same id at another connector is a different entry
+        resolve_recon_entry_in_db(
+            &db,
+            "merchant_1",
+            "user_1".to_string(),
+            get_resolve_request("adyen", 1940, None),
+        )
+        .await
+        .unwrap();
+
+        let mismatches =
+            apply_recon_entry_statuses(&db, "merchant_1", get_fixture_mismatches(), false)
+                .await
+                .unwrap();
+        assert_eq!(mismatches.len(), 2);
+
+        // Resolving the entry again updates the stored amounts
+        let recon_entry = resolve_recon_entry_in_db(
+            &db,
+            "merchant_1",
+            "user_2".to_string(),
+            get_resolve_request("stripe", 1940, Some("Fee charged on the settled amount")),
+        )
+        .await
+        .unwrap();
+        assert_eq!(recon_entry.settled_amount, Some(1940));
+        assert_eq!(recon_entry.resolved_by.as_deref(), Some("user_2"));
+
+        let mismatches =
+            apply_recon_entry_statuses(&db, "merchant_1", get_fixture_mismatches(), false)
+                .await
+                .unwrap();
+        assert_eq!(mismatches.len(), 1);
+    }
+}
@@ -570,6 +570,245 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND APPROVAL **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_approval_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            &merchant_account.merchant_id,
+            &req.refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    let refund_status =
+        validator::validate_refund_approval_decision(refund.refund_status, req.decision)
+            .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot {} this refund because it has status {}",
+                    req.decision, refund.refund_status
+                ),
+            })?;
+
+    let refund_update = match req.decision {
+        refunds::RefundApprovalDecision::Approve => storage::RefundUpdate::StatusUpdate {
+            connector_refund_id: None,
+            sent_to_gateway: false,
+            refund_status,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        refunds::RefundApprovalDecision::Reject => storage::RefundUpdate::ErrorUpdate {
+            refund_status: Some(refund_status),
+            refund_error_message: Some("Refund was rejected during approval".to_string()),
+            refund_error_code: None,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+    };
+
+    let refund = db
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update refund with refund_id: {}", req.refund_id)
+        })?;
+
+    let refund = match req.decision {
+        refunds::RefundApprovalDecision::Approve => {
+            let payment_attempt = db
+                .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+                    &refund.connector_transaction_id,
+                    &refund.payment_id,
+                    &refund.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &payment_attempt.payment_id,
+                    &refund.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let refund_type = match refund.refund_type {
+                enums::RefundType::InstantRefund => api_models::refunds::RefundType::Instant,
+                enums::RefundType::RegularRefund | enums::RefundType::RetryRefund => {
+                    api_models::refunds::RefundType::Scheduled
+                }
+            };
+
+            schedule_refund_execution(
+                &state,
+                refund,
+                refund_type,
+                &merchant_account,
+                &key_store,
+                &payment_attempt,
+                &payment_intent,
+                None,
+            )
+            .await?
+        }
+        refunds::RefundApprovalDecision::Reject => refund,
+    };
+
+    Ok(services::ApplicationResponse::Json(refund.foreign_into()))
+}
+
+/// The refund amount above which a refund requires approval before it is sent to the connector
+#[instrument(skip_all)]
+pub async fn get_refund_approval_threshold(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+) -> Option<i64> {
+    let key = format!("refund_approval_threshold_{merchant_id}");
+    db.find_config_by_key(key.as_str())
+        .await
+        .map_err(|err| {
+            if !err.current_context().is_db_not_found() {
+                logger::error!(refund_approval_threshold_error=?err);
+            }
+        })
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse::<i64>()
+                .map_err(|err| {
+                    logger::error!(refund_approval_threshold_parsing_error=?err);
+                })
+                .ok()
+        })
+}
+
+// ********************************************** REFUND ELIGIBILITY **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_eligibility_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: refunds::RefundEligibilityRequest,
+) -> RouterResponse<refunds::RefundEligibilityResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let (max_refundable_amount, reasons) = match payment_intent.status {
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured => {
+            let payment_attempt = db
+                .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+                    &req.payment_id,
+                    merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+
+            let connector_transaction_id = payment_attempt
+                .connector_transaction_id
+                .as_ref()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Missing field \"connector_transaction_id\" in payment_attempt",
+                )?;
+
+            let all_refunds = db
+                .find_refund_by_merchant_id_connector_transaction_id(
+                    merchant_id,
+                    connector_transaction_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+            let total_amount_captured = payment_intent
+                .amount_captured
+                .unwrap_or(payment_attempt.amount);
+
+            let (max_refundable_amount, mut reasons) = validator::get_refund_eligibility(
+                payment_intent.status,
+                total_amount_captured,
+                &all_refunds,
+                req.amount,
+                state.conf.refund.max_attempts,
+            );
+
+            if validator::validate_payment_order_age(
+                &payment_intent.created_at,
+                state.conf.refund.max_age,
+            )
+            .is_err()
+            {
+                reasons.push(refunds::RefundIneligibilityReason::PaymentOrderExpired);
+            }
+
+            let routed_through = payment_attempt
+                .connector
+                .as_ref()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("No connector populated in payment attempt")?;
+
+            let connector = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                routed_through,
+                api::GetToken::Connector,
+                payment_attempt.merchant_connector_id.clone(),
+            )?;
+
+            match validator::validate_for_valid_refunds(&payment_attempt, connector.connector_name)
+            {
+                Ok(()) => {}
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::ApiErrorResponse::RefundNotPossible { .. }
+                    ) =>
+                {
+                    reasons.push(refunds::RefundIneligibilityReason::RefundNotSupportedByConnector)
+                }
+                Err(error) => return Err(error),
+            }
+
+            (max_refundable_amount, reasons)
+        }
+        _ => validator::get_refund_eligibility(
+            payment_intent.status,
+            0,
+            &[],
+            req.amount,
+            state.conf.refund.max_attempts,
+        ),
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        refunds::RefundEligibilityResponse {
+            eligible: reasons.is_empty(),
+            max_refundable_amount,
+            reasons,
+        },
+    ))
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
@@ -593,6 +832,21 @@ pub async fn validate_and_create_refund(
 
     let refund_id = core_utils::get_or_generate_id("refund_id", &req.refund_id, "ref")?;
 
+    let merchant_refund_reference_id = req
+        .merchant_refund_reference_id
+        .clone()
+        .map(|merchant_refund_reference_id| {
+            core_utils::validate_id(merchant_refund_reference_id, "merchant_refund_reference_id")
+        })
+        .transpose()?;
+    let get_duplicate_merchant_refund_reference_id_error =
+        || errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!(
+                "A refund with the merchant_refund_reference_id '{}' already exists",
+                merchant_refund_reference_id.as_deref().unwrap_or_default()
+            ),
+        };
+
     let predicate = req
         .merchant_id
         .as_ref()
@@ -651,8 +905,37 @@ pub async fn validate_and_create_refund(
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
 
+    if let Some(merchant_refund_reference_id) = merchant_refund_reference_id.as_deref() {
+        let existing_refund = match db
+            .find_refund_by_merchant_id_merchant_refund_reference_id(
+                &merchant_account.merchant_id,
+                merchant_refund_reference_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(refund) => Some(refund),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed while finding refund by merchant refund reference id",
+                    )
+            }
+        };
+
+        validator::validate_merchant_refund_reference_id(existing_refund.as_ref(), &refund_id)
+            .change_context(get_duplicate_merchant_refund_reference_id_error())?;
+    }
+
+    let approval_threshold =
+        get_refund_approval_threshold(db, merchant_account.merchant_id.as_str()).await;
+    let refund_status = validator::get_initial_refund_status(refund_amount, approval_threshold);
+
     let refund_create_req = storage::RefundNew::default()
         .set_refund_id(refund_id.to_string())
+        .set_merchant_refund_reference_id(merchant_refund_reference_id.clone())
         .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
         .set_external_reference_id(Some(refund_id.clone()))
         .set_payment_id(req.payment_id)
@@ -665,7 +948,7 @@ pub async fn validate_and_create_refund(
         .set_currency(currency)
         .set_created_at(Some(common_utils::date_time::now()))
         .set_modified_at(Some(common_utils::date_time::now()))
-        .set_refund_status(enums::RefundStatus::Pending)
+        .set_refund_status(refund_status)
         .set_metadata(req.metadata)
         .set_description(req.reason.clone())
         .set_attempt_id(payment_attempt.attempt_id.clone())
@@ -691,15 +974,43 @@ pub async fn validate_and_create_refund(
             )
             .await?
         }
+        // Another refund claimed the merchant refund reference id after it was validated above
+        Err(err)
+            if matches!(
+                err.current_context(),
+                errors::StorageError::DuplicateValue {
+                    entity: "merchant_refund_reference_id",
+                    ..
+                }
+            ) =>
+        {
+            return Err(err).change_context(get_duplicate_merchant_refund_reference_id_error());
+        }
         Err(err) => {
             if err.current_context().is_db_unique_violation() {
-                db.find_refund_by_merchant_id_refund_id(
-                    merchant_account.merchant_id.as_str(),
-                    refund_id.as_str(),
-                    merchant_account.storage_scheme,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?
+                match db
+                    .find_refund_by_merchant_id_refund_id(
+                        merchant_account.merchant_id.as_str(),
+                        refund_id.as_str(),
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                {
+                    Ok(refund) => refund,
+                    // The unique violation is on the merchant refund reference id, as no refund
+                    // with the refund id exists
+                    Err(error)
+                        if error.current_context().is_db_not_found()
+                            && merchant_refund_reference_id.is_some() =>
+                    {
+                        return Err(error)
+                            .change_context(get_duplicate_merchant_refund_reference_id_error());
+                    }
+                    Err(error) => {
+                        return Err(error)
+                            .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)
+                    }
+                }
             } else {
                 return Err(err)
                     .change_context(errors::ApiErrorResponse::RefundNotFound)
@@ -829,6 +1140,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
         Self {
             payment_id: refund.payment_id,
             refund_id: refund.refund_id,
+            merchant_refund_reference_id: refund.merchant_refund_reference_id,
             amount: refund.refund_amount,
             currency: refund.currency.to_string(),
             reason: refund.refund_reason,
@@ -986,6 +1298,7 @@ pub async fn sync_refund_with_gateway_workflow(
                 response.connector,
                 response.merchant_id,
                 refund_tracker.to_owned(),
+                None,
             )
             .await?;
         }
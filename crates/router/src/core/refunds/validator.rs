@@ -1,3 +1,4 @@
+use api_models::refunds::RefundIneligibilityReason;
 use error_stack::report;
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
@@ -25,6 +26,10 @@ pub enum RefundValidationError {
     MaxRefundCountReached,
     #[error("There is already another refund request for this payment attempt")]
     DuplicateRefund,
+    #[error("The refund is not pending approval")]
+    RefundNotPendingApproval,
+    #[error("The merchant refund reference id is already used by another refund")]
+    DuplicateMerchantRefundReferenceId,
 }
 
 #[instrument(skip_all)]
@@ -44,7 +49,21 @@ pub fn validate_refund_amount(
     all_refunds: &[storage::Refund],
     refund_amount: i64,
 ) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
+    let total_refunded_amount = get_total_refunded_amount(all_refunds);
+
+    utils::when(
+        refund_amount > (amount_captured - total_refunded_amount),
+        || {
+            Err(report!(
+                RefundValidationError::RefundAmountExceedsPaymentAmount
+            ))
+        },
+    )
+}
+
+/// Failed refunds do not count towards the amount already refunded for a payment
+pub fn get_total_refunded_amount(all_refunds: &[storage::Refund]) -> i64 {
+    all_refunds
         .iter()
         .filter_map(|refund| {
             if refund.refund_status != enums::RefundStatus::Failure
@@ -55,16 +74,44 @@ pub fn validate_refund_amount(
                 None
             }
         })
-        .sum();
+        .sum()
+}
 
-    utils::when(
-        refund_amount > (amount_captured - total_refunded_amount),
-        || {
-            Err(report!(
-                RefundValidationError::RefundAmountExceedsPaymentAmount
-            ))
-        },
-    )
+/// Runs the status, amount and refund count checks of the refund create flow without failing on
+/// the first violation, returning the amount that can still be refunded along with every reason
+/// for which the refund would be rejected
+pub fn get_refund_eligibility(
+    intent_status: enums::IntentStatus,
+    amount_captured: i64,
+    all_refunds: &[storage::Refund],
+    refund_amount: Option<i64>,
+    refund_max_attempts: usize,
+) -> (i64, Vec<RefundIneligibilityReason>) {
+    if !matches!(
+        intent_status,
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+    ) {
+        return (0, vec![RefundIneligibilityReason::PaymentNotSucceeded]);
+    }
+
+    let max_refundable_amount = (amount_captured - get_total_refunded_amount(all_refunds)).max(0);
+    let mut reasons = Vec::new();
+
+    if max_refundable_amount == 0 {
+        reasons.push(RefundIneligibilityReason::FullyRefunded);
+    } else if let Some(refund_amount) = refund_amount {
+        if refund_amount <= 0 {
+            reasons.push(RefundIneligibilityReason::InvalidRefundAmount);
+        } else if refund_amount > max_refundable_amount {
+            reasons.push(RefundIneligibilityReason::AmountExceedsRefundableAmount);
+        }
+    }
+
+    if validate_maximum_refund_against_payment_attempt(all_refunds, refund_max_attempts).is_err() {
+        reasons.push(RefundIneligibilityReason::MaximumRefundCountReached);
+    }
+
+    (max_refundable_amount, reasons)
 }
 
 #[instrument(skip_all)]
@@ -90,6 +137,51 @@ pub fn validate_maximum_refund_against_payment_attempt(
     })
 }
 
+/// Refunds for an amount above the merchant's approval threshold are held in
+/// `PendingApproval` until they are approved, instead of being sent to the connector
+pub fn get_initial_refund_status(
+    refund_amount: i64,
+    approval_threshold: Option<i64>,
+) -> enums::RefundStatus {
+    match approval_threshold {
+        Some(threshold) if refund_amount > threshold => enums::RefundStatus::PendingApproval,
+        _ => enums::RefundStatus::Pending,
+    }
+}
+
+#[instrument(skip_all)]
+pub fn validate_refund_approval_decision(
+    refund_status: enums::RefundStatus,
+    decision: api_models::refunds::RefundApprovalDecision,
+) -> CustomResult<enums::RefundStatus, RefundValidationError> {
+    utils::when(
+        refund_status != enums::RefundStatus::PendingApproval,
+        || Err(report!(RefundValidationError::RefundNotPendingApproval)),
+    )?;
+
+    Ok(match decision {
+        api_models::refunds::RefundApprovalDecision::Approve => enums::RefundStatus::Pending,
+        api_models::refunds::RefundApprovalDecision::Reject => enums::RefundStatus::Failure,
+    })
+}
+
+/// A retried refund request carries the same `refund_id` along with its reference and is
+/// treated as an idempotent request, any other refund reusing the reference is rejected
+#[instrument(skip_all)]
+pub fn validate_merchant_refund_reference_id(
+    existing_refund: Option<&storage::Refund>,
+    refund_id: &str,
+) -> CustomResult<(), RefundValidationError> {
+    utils::when(
+        existing_refund.is_some_and(|refund| refund.refund_id != refund_id),
+        || {
+            Err(report!(
+                RefundValidationError::DuplicateMerchantRefundReferenceId
+            ))
+        },
+    )
+}
+
 pub fn validate_refund_list(limit: Option<i64>) -> CustomResult<i64, errors::ApiErrorResponse> {
     match limit {
         Some(limit_val) => {
@@ -144,3 +236,176 @@ pub fn validate_for_valid_refunds(
         _ => Ok(()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::refunds::RefundApprovalDecision;
+
+    use super::*;
+
+    #[test]
+    fn test_refund_below_approval_threshold_proceeds() {
+        assert_eq!(
+            get_initial_refund_status(5000, Some(10000)),
+            enums::RefundStatus::Pending
+        );
+        assert_eq!(
+            get_initial_refund_status(50000, None),
+            enums::RefundStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_refund_above_approval_threshold_is_pending_then_approved() {
+        let refund_status = get_initial_refund_status(15000, Some(10000));
+        assert_eq!(refund_status, enums::RefundStatus::PendingApproval);
+
+        let refund_status =
+            validate_refund_approval_decision(refund_status, RefundApprovalDecision::Approve)
+                .unwrap();
+        assert_eq!(refund_status, enums::RefundStatus::Pending);
+    }
+
+    #[test]
+    fn test_refund_approval_decision() {
+        assert_eq!(
+            validate_refund_approval_decision(
+                enums::RefundStatus::PendingApproval,
+                RefundApprovalDecision::Reject
+            )
+            .unwrap(),
+            enums::RefundStatus::Failure
+        );
+        assert!(validate_refund_approval_decision(
+            enums::RefundStatus::Pending,
+            RefundApprovalDecision::Approve
+        )
+        .is_err());
+    }
+
+    fn get_refund_with_amount(
+        refund_amount: i64,
+        refund_status: enums::RefundStatus,
+    ) -> storage::Refund {
+        storage::Refund {
+            refund_amount,
+            refund_status,
+            merchant_refund_reference_id: None,
+            ..get_refund("ref_1", "ledger_ref_1")
+        }
+    }
+
+    fn get_refund(refund_id: &str, merchant_refund_reference_id: &str) -> storage::Refund {
+        let current_time = common_utils::date_time::now();
+        storage::Refund {
+            id: 1,
+            internal_reference_id: "refid_1".to_string(),
+            refund_id: refund_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            connector_transaction_id: "txn_1".to_string(),
+            connector: "stripe".to_string(),
+            connector_refund_id: None,
+            external_reference_id: Some(refund_id.to_string()),
+            refund_type: enums::RefundType::InstantRefund,
+            total_amount: 1000,
+            currency: enums::Currency::USD,
+            refund_amount: 100,
+            refund_status: enums::RefundStatus::Pending,
+            sent_to_gateway: false,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at: current_time,
+            updated_at: current_time,
+            description: None,
+            attempt_id: "pay_1_1".to_string(),
+            refund_reason: None,
+            refund_error_code: None,
+            profile_id: None,
+            updated_by: "postgres_only".to_string(),
+            merchant_connector_id: None,
+            merchant_refund_reference_id: Some(merchant_refund_reference_id.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_merchant_refund_reference_id_reused_by_another_refund() {
+        let existing_refund = get_refund("ref_1", "ledger_ref_1");
+        assert!(matches!(
+            validate_merchant_refund_reference_id(Some(&existing_refund), "ref_2")
+                .unwrap_err()
+                .current_context(),
+            RefundValidationError::DuplicateMerchantRefundReferenceId
+        ));
+    }
+
+    #[test]
+    fn test_merchant_refund_reference_id_unused_or_retried() {
+        assert!(validate_merchant_refund_reference_id(None, "ref_1").is_ok());
+
+        let existing_refund = get_refund("ref_1", "ledger_ref_1");
+        assert!(validate_merchant_refund_reference_id(Some(&existing_refund), "ref_1").is_ok());
+    }
+
+    #[test]
+    fn test_refund_eligibility_for_fully_refunded_payment() {
+        let all_refunds = vec![
+            get_refund_with_amount(600, enums::RefundStatus::Success),
+            get_refund_with_amount(400, enums::RefundStatus::Pending),
+        ];
+
+        let (max_refundable_amount, reasons) = get_refund_eligibility(
+            enums::IntentStatus::Succeeded,
+            1000,
+            &all_refunds,
+            Some(100),
+            10,
+        );
+        assert_eq!(max_refundable_amount, 0);
+        assert_eq!(reasons, vec![RefundIneligibilityReason::FullyRefunded]);
+    }
+
+    #[test]
+    fn test_refund_eligibility_for_partially_refunded_payment() {
+        let all_refunds = vec![
+            get_refund_with_amount(600, enums::RefundStatus::Success),
+            get_refund_with_amount(300, enums::RefundStatus::Failure),
+        ];
+
+        let (max_refundable_amount, reasons) = get_refund_eligibility(
+            enums::IntentStatus::Succeeded,
+            1000,
+            &all_refunds,
+            Some(400),
+            10,
+        );
+        assert_eq!(max_refundable_amount, 400);
+        assert!(reasons.is_empty());
+
+        let (max_refundable_amount, reasons) = get_refund_eligibility(
+            enums::IntentStatus::Succeeded,
+            1000,
+            &all_refunds,
+            Some(500),
+            10,
+        );
+        assert_eq!(max_refundable_amount, 400);
+        assert_eq!(
+            reasons,
+            vec![RefundIneligibilityReason::AmountExceedsRefundableAmount]
+        );
+    }
+
+    #[test]
+    fn test_refund_eligibility_for_unsupported_payment_status() {
+        let (max_refundable_amount, reasons) =
+            get_refund_eligibility(enums::IntentStatus::RequiresCapture, 1000, &[], None, 10);
+        assert_eq!(max_refundable_amount, 0);
+        assert_eq!(
+            reasons,
+            vec![RefundIneligibilityReason::PaymentNotSucceeded]
+        );
+    }
+}
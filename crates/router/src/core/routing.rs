@@ -124,6 +124,12 @@ pub async fn create_routing_config(
         })
         .attach_printable("Algorithm of config not given")?;
 
+    algorithm.validate_volume_splits().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Splits must add up to 100 for Volume split Algorithm".to_string(),
+        },
+    )?;
+
     let algorithm_id = common_utils::generate_id(
         consts::ROUTING_CONFIG_ID_LENGTH,
         &format!("routing_{}", &merchant_account.merchant_id),
@@ -495,6 +501,7 @@ pub async fn unlink_routing_config(
                                 timestamp,
                                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
                                 surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                                connector_error_fallback: routing_algo_ref.connector_error_fallback,
                             };
 
                         let record = db
@@ -560,6 +567,7 @@ pub async fn unlink_routing_config(
                 timestamp,
                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
                 surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                connector_error_fallback: routing_algo_ref.connector_error_fallback,
             };
 
         let active_algorithm_id = merchant_dictionary
@@ -927,3 +935,70 @@ pub async fn update_default_routing_config_for_profile(
         },
     ))
 }
+
+/// Evaluates the active routing configuration against a sample payment context and returns the
+/// connectors it would select, without creating a payment
+pub async fn evaluate_routing_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: routing_types::RoutingEvaluateRequest,
+) -> RouterResponse<routing_types::RoutingEvaluateResponse> {
+    #[cfg(any(
+        feature = "profile_specific_fallback_routing",
+        feature = "business_profile_routing"
+    ))]
+    let profile_id = request
+        .profile_id
+        .clone()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+
+    #[cfg(feature = "business_profile_routing")]
+    let routing_algorithm = core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?
+    .get_required_value("BusinessProfile")
+    .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+        id: profile_id.clone(),
+    })?
+    .routing_algorithm;
+    #[cfg(not(feature = "business_profile_routing"))]
+    let routing_algorithm = merchant_account.routing_algorithm.clone();
+
+    let algorithm_ref: routing_types::RoutingAlgorithmRef = routing_algorithm
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref")?
+        .unwrap_or_default();
+
+    let backend_input = payments::routing::make_dsl_input_for_routing_evaluation(&request);
+    let (connectors, explanation) = payments::routing::perform_routing_with_algorithm_ref_v1(
+        &state,
+        &merchant_account.merchant_id,
+        algorithm_ref,
+        #[cfg(any(
+            feature = "profile_specific_fallback_routing",
+            feature = "business_profile_routing"
+        ))]
+        &profile_id,
+        &enums::TransactionType::Payment,
+        || Ok(backend_input),
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to evaluate the routing config")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingEvaluateResponse {
+            connectors,
+            explanation,
+        },
+    ))
+}
@@ -266,6 +266,7 @@ pub async fn update_business_profile_active_algorithm_ref(
         extended_card_info_config: None,
         use_billing_as_payment_method_billing: None,
         collect_shipping_details_from_wallet_connector: None,
+        dispute_auto_accept_threshold: None,
     };
 
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
@@ -179,6 +179,7 @@ pub async fn signin(
         .into();
 
     user_from_db.compare_password(request.password)?;
+    utils::user::ensure_totp_not_set(&user_from_db)?;
 
     let signin_strategy =
         if let Some(preferred_merchant_id) = user_from_db.get_preferred_merchant_id() {
@@ -332,7 +333,7 @@ pub async fn change_password(
     state: AppState,
     request: user_api::ChangePasswordRequest,
     user_from_token: auth::UserFromToken,
-) -> UserResponse<()> {
+) -> UserResponse<user_api::ChangePasswordResponse> {
     let user: domain::UserFromStorage = state
         .store
         .find_user_by_id(&user_from_token.user_id)
@@ -362,9 +363,18 @@ pub async fn change_password(
         .await
         .change_context(UserErrors::InternalServerError)?;
 
-    let _ = auth::blacklist::insert_user_in_blacklist(&state, user.get_user_id())
-        .await
-        .map_err(|e| logger::error!(?e));
+    // Revokes every token issued so far, including the one used for this request
+    auth::blacklist::insert_user_in_blacklist(&state, user.get_user_id()).await?;
+
+    // Keep the current session alive by issuing it a fresh token
+    let token = auth::AuthToken::new_token(
+        user_from_token.user_id.clone(),
+        user_from_token.merchant_id.clone(),
+        user_from_token.role_id.clone(),
+        &state.conf,
+        user_from_token.org_id.clone(),
+    )
+    .await?;
 
     #[cfg(not(feature = "email"))]
     {
@@ -380,7 +390,11 @@ pub async fn change_password(
             .ok();
     }
 
-    Ok(ApplicationResponse::StatusOk)
+    let token = masking::Secret::new(token);
+    let response = user_api::ChangePasswordResponse {
+        token: token.clone(),
+    };
+    auth::cookies::set_cookie_response(response, token)
 }
 
 #[cfg(feature = "email")]
@@ -921,6 +935,7 @@ pub async fn accept_invite_from_email(
         .await
         .change_context(UserErrors::InternalServerError)?
         .into();
+    utils::user::ensure_totp_not_set(&user)?;
 
     let merchant_id = email_token
         .get_merchant_id()
@@ -1361,7 +1376,7 @@ pub async fn verify_email(
 
     auth::blacklist::check_email_token_in_blacklist(&state, &token).await?;
 
-    let user = state
+    let user_from_email: domain::UserFromStorage = state
         .store
         .find_user_by_email(
             &email_token
@@ -1369,15 +1384,22 @@ pub async fn verify_email(
                 .change_context(UserErrors::InternalServerError)?,
         )
         .await
-        .change_context(UserErrors::InternalServerError)?;
+        .change_context(UserErrors::InternalServerError)?
+        .into();
 
-    let user = state
+    // Checked before the user is marked as verified, so that the request of a TOTP user leaves
+    // the user untouched
+    utils::user::ensure_totp_not_set(&user_from_email)?;
+
+    let user_from_db: domain::UserFromStorage = state
         .store
-        .update_user_by_user_id(user.user_id.as_str(), storage_user::UserUpdate::VerifyUser)
+        .update_user_by_user_id(
+            user_from_email.get_user_id(),
+            storage_user::UserUpdate::VerifyUser,
+        )
         .await
-        .change_context(UserErrors::InternalServerError)?;
-
-    let user_from_db: domain::UserFromStorage = user.into();
+        .change_context(UserErrors::InternalServerError)?
+        .into();
 
     let signin_strategy =
         if let Some(preferred_merchant_id) = user_from_db.get_preferred_merchant_id() {
@@ -1683,6 +1705,10 @@ pub async fn verify_totp(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
+    if req.totp.is_none() && user_from_db.get_totp_status() == TotpStatus::Set {
+        return Err(UserErrors::TotpRequired.into());
+    }
+
     if let Some(user_totp) = req.totp {
         if user_from_db.get_totp_status() == TotpStatus::NotSet {
             return Err(UserErrors::TotpNotSetup.into());
@@ -1696,13 +1722,7 @@ pub async fn verify_totp(
         let totp =
             utils::user::generate_default_totp(user_from_db.get_email(), Some(user_totp_secret))?;
 
-        if totp
-            .generate_current()
-            .change_context(UserErrors::InternalServerError)?
-            != user_totp.expose()
-        {
-            return Err(UserErrors::InvalidTotp.into());
-        }
+        utils::user::validate_totp(&totp, &user_totp.expose())?;
 
         if user_from_db.get_totp_status() == TotpStatus::InProgress {
             state
@@ -389,3 +389,54 @@ pub async fn delete_user_role(
     auth::blacklist::insert_user_in_blacklist(&state, &deleted_user_role.user_id).await?;
     Ok(ApplicationResponse::StatusOk)
 }
+
+pub async fn force_signout(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::ForceSignoutRequest,
+    _req_state: ReqState,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .store
+        .find_user_by_email(&domain::UserEmail::from_pii_email(request.email)?.into_inner())
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleOperation)
+        .attach_printable("User not found in our records")?
+        .into();
+
+    let user_role = state
+        .store
+        .find_user_role_by_user_id_merchant_id(
+            user_from_db.get_user_id(),
+            &user_from_token.merchant_id,
+        )
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleOperation)
+        .attach_printable("User is not associated with the merchant")?;
+
+    // Users with roles above the merchant level, such as organization admins, cannot be signed out
+    // by the users of the merchant, in the same way as their roles cannot be updated
+    let role_info = roles::RoleInfo::from_role_id(
+        &state,
+        &user_role.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+    if !role_info.is_updatable() {
+        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+            "User with role {} cannot be signed out",
+            role_info.get_role_id()
+        ));
+    }
+
+    // Only the sessions of the user in this merchant are revoked
+    auth::blacklist::insert_user_merchant_in_blacklist(
+        &state,
+        user_from_db.get_user_id(),
+        &user_from_token.merchant_id,
+    )
+    .await?;
+    Ok(ApplicationResponse::StatusOk)
+}
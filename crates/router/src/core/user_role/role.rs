@@ -322,3 +322,133 @@ pub async fn update_role(
         },
     ))
 }
+
+pub async fn delete_role(
+    state: AppState,
+    user_from_token: UserFromToken,
+    role_id: &str,
+) -> UserResponse<()> {
+    let role = state
+        .store
+        .find_role_by_role_id_in_merchant_scope(
+            role_id,
+            &user_from_token.merchant_id,
+            &user_from_token.org_id,
+        )
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleOperation)
+        .attach_printable("Only custom roles of the merchant can be deleted")?;
+
+    if matches!(role.scope, RoleScope::Organization)
+        && user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
+    {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Non org admin user deleting org level role");
+    }
+
+    // The role was found above, so it is only left undeleted when it is assigned to a user
+    state
+        .store
+        .delete_unassigned_role_by_role_id(role_id)
+        .await
+        .to_not_found_response(UserErrors::RoleInUse)
+        .attach_printable_lazy(|| format!("Role {role_id} is assigned to users"))?;
+
+    blacklist::insert_role_in_blacklist(&state, role_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_enums::PermissionGroup;
+    use diesel_models::{enums::UserStatus, user_role::UserRoleNew};
+
+    use super::*;
+    use crate::{db::StorageInterface, services::authorization::permissions::Permission};
+
+    fn custom_role(role_id: &str) -> RoleNew {
+        let now = common_utils::date_time::now();
+        RoleNew {
+            role_id: role_id.to_string(),
+            role_name: "payments_viewer".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            org_id: "org_1".to_string(),
+            groups: vec![PermissionGroup::OperationsView],
+            scope: RoleScope::Merchant,
+            created_by: "user_admin".to_string(),
+            last_modified_by: "user_admin".to_string(),
+            created_at: now,
+            last_modified_at: now,
+        }
+    }
+
+    fn user_role_with(role_id: &str) -> UserRoleNew {
+        let now = common_utils::date_time::now();
+        UserRoleNew {
+            user_id: "user_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            role_id: role_id.to_string(),
+            org_id: "org_1".to_string(),
+            status: UserStatus::Active,
+            created_by: "user_admin".to_string(),
+            last_modified_by: "user_admin".to_string(),
+            created_at: now,
+            last_modified: now,
+        }
+    }
+
+    async fn mock_db() -> storage_impl::MockDb {
+        storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn custom_role_grants_permissions_of_its_groups() {
+        let db = mock_db().await;
+        let store: &dyn StorageInterface = &db;
+
+        store.insert_role(custom_role("role_custom")).await.unwrap();
+
+        let role_info = roles::RoleInfo::from(
+            store
+                .find_role_by_role_id_in_merchant_scope("role_custom", "merchant_1", "org_1")
+                .await
+                .unwrap(),
+        );
+
+        assert!(role_info.check_permission_exists(&Permission::PaymentRead));
+        assert!(!role_info.check_permission_exists(&Permission::PaymentWrite));
+        assert!(role_info.is_deletable());
+    }
+
+    #[tokio::test]
+    async fn assigned_custom_role_cannot_be_deleted() {
+        let db = mock_db().await;
+        let store: &dyn StorageInterface = &db;
+
+        store.insert_role(custom_role("role_custom")).await.unwrap();
+        store
+            .insert_user_role(user_role_with("role_custom"))
+            .await
+            .unwrap();
+
+        let result = store.delete_unassigned_role_by_role_id("role_custom").await;
+        assert!(result.unwrap_err().current_context().is_db_not_found());
+        assert!(store.find_role_by_role_id("role_custom").await.is_ok());
+
+        store
+            .delete_user_role_by_user_id_merchant_id("user_1", "merchant_1")
+            .await
+            .unwrap();
+
+        store
+            .delete_unassigned_role_by_role_id("role_custom")
+            .await
+            .unwrap();
+        assert!(store.find_role_by_role_id("role_custom").await.is_err());
+    }
+}
@@ -40,6 +40,14 @@ pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_PAYOUTS_FLOW: &str =
     "irrelevant_connector_request_reference_id_in_payouts_flow";
 const IRRELEVANT_PAYMENT_ID_IN_DISPUTE_FLOW: &str = "irrelevant_payment_id_in_dispute_flow";
 const IRRELEVANT_ATTEMPT_ID_IN_DISPUTE_FLOW: &str = "irrelevant_attempt_id_in_dispute_flow";
+/// Prefix of the placeholder ids set in the router data of flows not tied to a payment attempt
+const IRRELEVANT_ID_PREFIX: &str = "irrelevant_";
+
+/// Whether the attempt id of a router data is that of a payment attempt, rather than empty or a
+/// placeholder set by flows that are not tied to a payment attempt
+pub fn is_payment_attempt_id(attempt_id: &str) -> bool {
+    !attempt_id.is_empty() && !attempt_id.starts_with(IRRELEVANT_ID_PREFIX)
+}
 
 #[cfg(feature = "payouts")]
 #[instrument(skip_all)]
@@ -183,6 +191,7 @@ pub async fn construct_payout_router_data<'a, F>(
                     phone: c.phone.map(Encryptable::into_inner),
                     phone_country_code: c.phone_country_code,
                 }),
+            cancellation_reason: payout_data.cancellation_reason.to_owned(),
         },
         response: Ok(types::PayoutsResponseData::default()),
         access_token: None,
@@ -327,6 +336,7 @@ pub async fn construct_refund_router_data<'a, F>(
             reason: refund.refund_reason.clone(),
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
+            merchant_refund_reference_id: refund.merchant_refund_reference_id.clone(),
         },
 
         response: Ok(types::RefundsResponseData {
@@ -438,6 +448,56 @@ mod tests {
         let generated_id = generate_id(consts::ID_LENGTH, "ref");
         assert_eq!(generated_id.len(), consts::ID_LENGTH + 4)
     }
+
+    #[test]
+    fn test_statement_descriptor_with_prefix_within_limit() {
+        let statement_descriptor =
+            get_statement_descriptor_with_prefix("ACME* ", Some("ORDER 1234"), Some(22));
+        assert_eq!(statement_descriptor, "ACME* ORDER 1234");
+    }
+
+    #[test]
+    fn test_statement_descriptor_with_prefix_truncated() {
+        let statement_descriptor = get_statement_descriptor_with_prefix(
+            "ACME* ",
+            Some("SUMMER SALE ORDER 123456"),
+            Some(22),
+        );
+        assert_eq!(statement_descriptor, "ACME* SUMMER SALE ORDE");
+        assert_eq!(statement_descriptor.chars().count(), 22);
+    }
+
+    #[test]
+    fn test_statement_descriptor_with_prefix_longer_than_limit() {
+        let statement_descriptor = get_statement_descriptor_with_prefix(
+            "ACME INTERNATIONAL HOLDINGS* ",
+            Some("ORDER 1234"),
+            Some(22),
+        );
+        assert_eq!(statement_descriptor, "ACME INTERNATIONAL HOL");
+    }
+
+    #[test]
+    fn test_statement_descriptor_with_allowed_characters() {
+        assert!(
+            validate_statement_descriptor_characters("stripe", "ACME ORDER #1234", Some(" #"))
+                .is_ok()
+        );
+        assert!(validate_statement_descriptor_characters("stripe", "ACME <ORDER>", None).is_ok());
+    }
+
+    #[test]
+    fn test_statement_descriptor_with_disallowed_characters() {
+        let result =
+            validate_statement_descriptor_characters("stripe", "ACME* <ORDER> 'ä'", Some(" "));
+        assert!(matches!(
+            result.map_err(|error| error.current_context().clone()),
+            Err(errors::ApiErrorResponse::InvalidStatementDescriptor {
+                connector,
+                invalid_characters,
+            }) if connector == "stripe" && invalid_characters == "*<>'ä"
+        ));
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration
@@ -973,6 +1033,94 @@ pub fn is_merchant_enabled_for_payment_id_as_connector_request_id(
     config_map.contains(merchant_id)
 }
 
+/// Prepend the merchant's statement descriptor prefix to the statement descriptor of the payment.
+/// If the combined descriptor exceeds the connector's maximum length, the dynamic part of the
+/// descriptor is truncated first, the prefix is truncated only if it alone exceeds the limit.
+pub fn get_statement_descriptor_with_prefix(
+    prefix: &str,
+    statement_descriptor: Option<&str>,
+    max_length: Option<usize>,
+) -> String {
+    let statement_descriptor = statement_descriptor.unwrap_or_default();
+    let prefix: String = prefix
+        .chars()
+        .take(max_length.unwrap_or(usize::MAX))
+        .collect();
+    let allowed_descriptor_length = max_length
+        .map(|max_length| max_length.saturating_sub(prefix.chars().count()))
+        .unwrap_or(statement_descriptor.chars().count());
+
+    let truncated_descriptor: String = statement_descriptor
+        .chars()
+        .take(allowed_descriptor_length)
+        .collect();
+
+    format!("{prefix}{truncated_descriptor}")
+}
+
+/// Validate that the statement descriptor contains only characters supported by the connector.
+/// ASCII letters and digits are supported by all connectors, connectors without configured
+/// characters are not validated.
+pub fn validate_statement_descriptor_characters(
+    connector: &str,
+    statement_descriptor: &str,
+    allowed_characters: Option<&str>,
+) -> RouterResult<()> {
+    let Some(allowed_characters) = allowed_characters else {
+        return Ok(());
+    };
+
+    let invalid_characters =
+        statement_descriptor
+            .chars()
+            .fold(String::new(), |mut invalid_characters, character| {
+                if !character.is_ascii_alphanumeric()
+                    && !allowed_characters.contains(character)
+                    && !invalid_characters.contains(character)
+                {
+                    invalid_characters.push(character);
+                }
+                invalid_characters
+            });
+
+    common_utils::fp_utils::when(!invalid_characters.is_empty(), || {
+        Err(report!(
+            errors::ApiErrorResponse::InvalidStatementDescriptor {
+                connector: connector.to_string(),
+                invalid_characters,
+            }
+        ))
+    })
+}
+
+/// Validate the statement descriptor name and suffix of the payment against the characters
+/// supported by the connector
+pub fn validate_statement_descriptor_for_connector(
+    conf: &Settings,
+    connector: &str,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let allowed_characters = conf
+        .statement_descriptor_allowed_characters
+        .0
+        .get(connector)
+        .map(String::as_str);
+    for statement_descriptor in [
+        &payment_intent.statement_descriptor_name,
+        &payment_intent.statement_descriptor_suffix,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        validate_statement_descriptor_characters(
+            connector,
+            statement_descriptor,
+            allowed_characters,
+        )?;
+    }
+    Ok(())
+}
+
 pub fn get_connector_request_reference_id(
     conf: &Settings,
     merchant_id: &str,
@@ -4,7 +4,13 @@ use common_utils::{errors::CustomResult, request::RequestContent};
 use error_stack::ResultExt;
 use masking::ExposeInterface;
 
-use crate::{core::errors::api_error_response, headers, logger, routes::AppState, services};
+use crate::{
+    core::errors::{api_error_response, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services,
+    types::domain,
+};
 
 const APPLEPAY_INTERNAL_MERCHANT_NAME: &str = "Applepay_merchant";
 
@@ -16,6 +22,56 @@ pub async fn verify_merchant_creds_for_applepay(
     services::ApplicationResponse<ApplepayMerchantResponse>,
     api_error_response::ApiErrorResponse,
 > {
+    register_applepay_domains(
+        &state,
+        merchant_id,
+        body.merchant_connector_account_id,
+        body.domain_names,
+    )
+    .await?;
+
+    Ok(services::api::ApplicationResponse::Json(
+        ApplepayMerchantResponse {
+            status_message: "Applepay verification Completed".to_string(),
+        },
+    ))
+}
+
+pub async fn reverify_applepay_domains(
+    state: AppState,
+    merchant_id: String,
+    body: verifications::ApplepayReverificationRequest,
+) -> CustomResult<
+    services::ApplicationResponse<ApplepayMerchantResponse>,
+    api_error_response::ApiErrorResponse,
+> {
+    let verified_domains =
+        get_applepay_verified_domains(&state, &merchant_id, &body.merchant_connector_account_id)
+            .await?;
+    let domain_names = utils::get_domains_for_reverification(verified_domains)?;
+
+    register_applepay_domains(
+        &state,
+        merchant_id,
+        body.merchant_connector_account_id,
+        domain_names,
+    )
+    .await?;
+
+    Ok(services::api::ApplicationResponse::Json(
+        ApplepayMerchantResponse {
+            status_message: "Applepay re-verification Completed".to_string(),
+        },
+    ))
+}
+
+/// Registers the domains with Apple Pay and schedules their re-verification before it expires
+pub async fn register_applepay_domains(
+    state: &AppState,
+    merchant_id: String,
+    merchant_connector_account_id: String,
+    domain_names: Vec<String>,
+) -> RouterResult<()> {
     let applepay_merchant_configs = state.conf.applepay_merchant_configs.get_inner();
 
     let applepay_internal_merchant_identifier = applepay_merchant_configs
@@ -27,7 +83,7 @@ pub async fn verify_merchant_creds_for_applepay(
     let applepay_endpoint = &applepay_merchant_configs.applepay_endpoint;
 
     let request_body = verifications::ApplepayMerchantVerificationConfigs {
-        domain_names: body.domain_names.clone(),
+        domain_names: domain_names.clone(),
         encrypt_to: applepay_internal_merchant_identifier.clone(),
         partner_internal_merchant_identifier: applepay_internal_merchant_identifier,
         partner_merchant_name: APPLEPAY_INTERNAL_MERCHANT_NAME.to_string(),
@@ -47,7 +103,7 @@ pub async fn verify_merchant_creds_for_applepay(
         .build();
 
     let response = services::call_connector_api(
-        &state,
+        state,
         apple_pay_merch_verification_req,
         "verify_merchant_creds_for_applepay",
     )
@@ -60,19 +116,30 @@ pub async fn verify_merchant_creds_for_applepay(
     // Error is already logged
     match applepay_response {
         Ok(_) => {
+            let verification_details =
+                utils::ApplepayVerificationDetails::new(common_utils::date_time::now());
             utils::check_existence_and_add_domain_to_db(
-                &state,
-                merchant_id,
-                body.merchant_connector_account_id.clone(),
-                body.domain_names.clone(),
+                state,
+                merchant_id.clone(),
+                merchant_connector_account_id.clone(),
+                domain_names,
+                &verification_details,
             )
             .await
             .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
-            Ok(services::api::ApplicationResponse::Json(
-                ApplepayMerchantResponse {
-                    status_message: "Applepay verification Completed".to_string(),
-                },
-            ))
+
+            // The domains stay verified even if the re-verification could not be scheduled
+            utils::add_applepay_reverification_task(
+                state,
+                &merchant_id,
+                &merchant_connector_account_id,
+                &verification_details,
+            )
+            .await
+            .map_err(|error| logger::error!(applepay_reverification_task_error=?error))
+            .ok();
+
+            Ok(())
         }
         Err(error) => {
             logger::error!(?error);
@@ -92,24 +159,72 @@ pub async fn get_verified_apple_domains_with_mid_mca_id(
     services::ApplicationResponse<verifications::ApplepayVerifiedDomainsResponse>,
     api_error_response::ApiErrorResponse,
 > {
-    let db = state.store.as_ref();
-    let key_store = db
-        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
-        .await
-        .change_context(api_error_response::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_connector_account =
+        find_merchant_connector_account(&state, &merchant_id, &merchant_connector_id).await?;
+    let verification_details = utils::ApplepayVerificationDetails::from_merchant_connector_account(
+        &merchant_connector_account,
+    );
 
-    let verified_domains = db
-        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
-            merchant_id.as_str(),
-            merchant_connector_id.as_str(),
-            &key_store,
+    if utils::is_legacy_applepay_verification(
+        merchant_connector_account
+            .applepay_verified_domains
+            .as_deref(),
+        verification_details.as_ref(),
+    ) {
+        // The domains are still returned if their re-verification could not be scheduled
+        utils::add_legacy_applepay_reverification_task(
+            &state,
+            &merchant_id,
+            &merchant_connector_id,
         )
         .await
-        .change_context(api_error_response::ApiErrorResponse::ResourceIdNotFound)?
+        .map_err(|error| logger::error!(applepay_reverification_task_error=?error))
+        .ok();
+    }
+
+    let verified_domains = merchant_connector_account
         .applepay_verified_domains
         .unwrap_or_default();
 
     Ok(services::api::ApplicationResponse::Json(
-        verifications::ApplepayVerifiedDomainsResponse { verified_domains },
+        verifications::ApplepayVerifiedDomainsResponse {
+            verified_domains,
+            verified_at: verification_details
+                .as_ref()
+                .map(|details| details.verified_at),
+            expires_at: verification_details.map(|details| details.expires_at),
+        },
     ))
 }
+
+pub async fn get_applepay_verified_domains(
+    state: &AppState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+) -> RouterResult<Option<Vec<String>>> {
+    Ok(
+        find_merchant_connector_account(state, merchant_id, merchant_connector_id)
+            .await?
+            .applepay_verified_domains,
+    )
+}
+
+pub async fn find_merchant_connector_account(
+    state: &AppState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .change_context(api_error_response::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    db.find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+        merchant_id,
+        merchant_connector_id,
+        &key_store,
+    )
+    .await
+    .change_context(api_error_response::ApiErrorResponse::ResourceIdNotFound)
+}
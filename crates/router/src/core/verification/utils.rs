@@ -1,19 +1,95 @@
 use common_utils::errors::CustomResult;
-use error_stack::{Report, ResultExt};
+use error_stack::{report, Report, ResultExt};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
-    core::errors::{self, utils::StorageErrorExt},
+    consts,
+    core::errors::{self, utils::StorageErrorExt, RouterResult},
     logger,
-    routes::AppState,
-    types,
-    types::storage,
+    routes::{metrics, AppState},
+    types::{self, domain, storage},
 };
 
+const APPLEPAY_REVERIFICATION_TASK_TAG: &str = "APPLEPAY_VERIFICATION";
+
+const APPLEPAY_REVERIFICATION_TASK_NAME: &str = "APPLEPAY_DOMAIN_REVERIFICATION";
+
+const APPLEPAY_REVERIFICATION_TASK_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::ApplepayDomainReverificationWorkflow;
+
+/// Suffix of the task that re-verifies domains which were verified before the verification time
+/// was recorded, there is at most one such task per merchant connector account
+const APPLEPAY_LEGACY_REVERIFICATION_TASK_SUFFIX: &str = "legacy";
+
+/// Details of the last successful Apple Pay verification of a merchant connector account's domains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplepayVerificationDetails {
+    pub verified_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl ApplepayVerificationDetails {
+    pub fn new(verified_at: PrimitiveDateTime) -> Self {
+        Self {
+            verified_at,
+            expires_at: verified_at.saturating_add(time::Duration::days(
+                consts::APPLEPAY_VERIFICATION_VALIDITY_IN_DAYS,
+            )),
+        }
+    }
+
+    /// Details recorded on the merchant connector account, which are missing for domains that were
+    /// verified before the verification time was recorded
+    pub fn from_merchant_connector_account(
+        merchant_connector_account: &domain::MerchantConnectorAccount,
+    ) -> Option<Self> {
+        merchant_connector_account
+            .applepay_verified_at
+            .zip(merchant_connector_account.applepay_verification_expires_at)
+            .map(|(verified_at, expires_at)| Self {
+                verified_at,
+                expires_at,
+            })
+    }
+
+    /// Time from which the domains are due to be verified again
+    pub fn get_reverification_time(&self) -> PrimitiveDateTime {
+        self.expires_at.saturating_sub(time::Duration::days(
+            consts::APPLEPAY_REVERIFICATION_WINDOW_IN_DAYS,
+        ))
+    }
+
+    pub fn is_reverification_due(&self, current_time: PrimitiveDateTime) -> bool {
+        current_time >= self.get_reverification_time()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplepayReverificationTrackingData {
+    pub merchant_id: String,
+    pub merchant_connector_account_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ApplepayReverificationDecision {
+    /// The verification is close to expiring, or its time was never recorded, and the domains
+    /// should be verified again
+    Reverify,
+    /// The verification is not close to expiring yet, it should be checked again at the given time
+    RetryAt(PrimitiveDateTime),
+    /// The domains were verified again after the task was scheduled, which has a task of its own
+    Skip,
+}
+
 pub async fn check_existence_and_add_domain_to_db(
     state: &AppState,
     merchant_id: String,
     merchant_connector_id: String,
     domain_from_req: Vec<String>,
+    verification_details: &ApplepayVerificationDetails,
 ) -> CustomResult<Vec<String>, errors::ApiErrorResponse> {
     let key_store = state
         .store
@@ -61,6 +137,9 @@ pub async fn check_existence_and_add_domain_to_db(
         pm_auth_config: None,
         connector_label: None,
         status: None,
+        connector_wallets_details: None,
+        applepay_verified_at: Some(verification_details.verified_at),
+        applepay_verification_expires_at: Some(verification_details.expires_at),
     };
     state
         .store
@@ -89,3 +168,309 @@ pub fn log_applepay_verification_response_if_error(
             .map_err(|error| logger::error!(applepay_domain_verification_error= ?error))
     });
 }
+
+pub fn get_domains_for_reverification(
+    verified_domains: Option<Vec<String>>,
+) -> RouterResult<Vec<String>> {
+    verified_domains
+        .filter(|domains| !domains.is_empty())
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "no domains have been verified for the merchant connector account".to_string(),
+        }))
+}
+
+pub fn get_applepay_reverification_decision(
+    verification_details: Option<&ApplepayVerificationDetails>,
+    tracking_data: &ApplepayReverificationTrackingData,
+    current_time: PrimitiveDateTime,
+) -> ApplepayReverificationDecision {
+    match verification_details {
+        Some(details) if details.expires_at != tracking_data.expires_at => {
+            ApplepayReverificationDecision::Skip
+        }
+        Some(details) if details.is_reverification_due(current_time) => {
+            ApplepayReverificationDecision::Reverify
+        }
+        Some(details) => ApplepayReverificationDecision::RetryAt(details.get_reverification_time()),
+        None => ApplepayReverificationDecision::Reverify,
+    }
+}
+
+/// Whether the domains were verified before the verification time was recorded, in which case no
+/// re-verification has been scheduled for them
+pub fn is_legacy_applepay_verification(
+    verified_domains: Option<&[String]>,
+    verification_details: Option<&ApplepayVerificationDetails>,
+) -> bool {
+    verification_details.is_none() && verified_domains.is_some_and(|domains| !domains.is_empty())
+}
+
+/// Time at which a failed re-verification is retried, backing off exponentially with every retry.
+/// Returns `None` when the retry would not happen before the verification expires.
+pub fn get_applepay_reverification_retry_time(
+    tracking_data: &ApplepayReverificationTrackingData,
+    retry_count: i32,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let delay_in_secs = u32::try_from(retry_count)
+        .ok()
+        .and_then(|retry_count| 2_i64.checked_pow(retry_count))
+        .and_then(|multiplier| {
+            consts::APPLEPAY_REVERIFICATION_RETRY_BASE_DELAY_IN_SECS.checked_mul(multiplier)
+        })
+        .map_or(
+            consts::APPLEPAY_REVERIFICATION_RETRY_MAX_DELAY_IN_SECS,
+            |delay| delay.min(consts::APPLEPAY_REVERIFICATION_RETRY_MAX_DELAY_IN_SECS),
+        );
+    let retry_time = current_time.saturating_add(time::Duration::seconds(delay_in_secs));
+
+    (retry_time < tracking_data.expires_at).then_some(retry_time)
+}
+
+fn get_applepay_reverification_task(
+    merchant_id: &str,
+    merchant_connector_id: &str,
+    task_id_suffix: &str,
+    expires_at: PrimitiveDateTime,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        APPLEPAY_REVERIFICATION_TASK_RUNNER,
+        APPLEPAY_REVERIFICATION_TASK_NAME,
+        &format!("{merchant_connector_id}_{task_id_suffix}"),
+        merchant_id,
+    );
+    let tracking_data = ApplepayReverificationTrackingData {
+        merchant_id: merchant_id.to_string(),
+        merchant_connector_account_id: merchant_connector_id.to_string(),
+        expires_at,
+    };
+
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        APPLEPAY_REVERIFICATION_TASK_NAME,
+        APPLEPAY_REVERIFICATION_TASK_RUNNER,
+        [APPLEPAY_REVERIFICATION_TASK_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct apple pay reverification process tracker task")
+}
+
+fn record_applepay_reverification_task_added() {
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "ApplepayDomainReverification",
+        )],
+    );
+}
+
+/// Schedules the re-verification of the domains before their verification expires
+#[instrument(skip_all)]
+pub async fn add_applepay_reverification_task(
+    state: &AppState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+    verification_details: &ApplepayVerificationDetails,
+) -> RouterResult<()> {
+    let process_tracker_entry = get_applepay_reverification_task(
+        merchant_id,
+        merchant_connector_id,
+        &verification_details
+            .expires_at
+            .assume_utc()
+            .unix_timestamp()
+            .to_string(),
+        verification_details.expires_at,
+        verification_details.get_reverification_time(),
+    )?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while inserting apple pay reverification task to process_tracker",
+        )?;
+    record_applepay_reverification_task_added();
+
+    Ok(())
+}
+
+/// Schedules an immediate re-verification of domains that were verified before the verification
+/// time was recorded. As their expiry is unknown, failed attempts are retried for the length of
+/// the re-verification window. The task is only scheduled once per merchant connector account.
+#[instrument(skip_all)]
+pub async fn add_legacy_applepay_reverification_task(
+    state: &AppState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+) -> RouterResult<()> {
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = get_applepay_reverification_task(
+        merchant_id,
+        merchant_connector_id,
+        APPLEPAY_LEGACY_REVERIFICATION_TASK_SUFFIX,
+        current_time.saturating_add(time::Duration::days(
+            consts::APPLEPAY_REVERIFICATION_WINDOW_IN_DAYS,
+        )),
+        current_time,
+    )?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            record_applepay_reverification_task_added();
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed while inserting apple pay legacy reverification task to process_tracker",
+            ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_tracking_data(expires_at: PrimitiveDateTime) -> ApplepayReverificationTrackingData {
+        ApplepayReverificationTrackingData {
+            merchant_id: "merchant_1".to_string(),
+            merchant_connector_account_id: "mca_1".to_string(),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_verification_expiry_detection() {
+        let verified_at = time::macros::datetime!(2024-01-01 00:00);
+        let details = ApplepayVerificationDetails::new(verified_at);
+
+        assert_eq!(
+            details.expires_at,
+            time::macros::datetime!(2024-12-31 00:00)
+        );
+        assert_eq!(
+            details.get_reverification_time(),
+            time::macros::datetime!(2024-12-01 00:00)
+        );
+        assert!(!details.is_reverification_due(time::macros::datetime!(2024-11-30 23:59)));
+        assert!(details.is_reverification_due(time::macros::datetime!(2024-12-01 00:00)));
+        assert!(details.is_reverification_due(time::macros::datetime!(2025-01-15 00:00)));
+    }
+
+    #[test]
+    fn test_legacy_verification_detection() {
+        let domains = vec!["example.com".to_string()];
+        let details = ApplepayVerificationDetails::new(time::macros::datetime!(2024-01-01 00:00));
+
+        assert!(is_legacy_applepay_verification(
+            Some(domains.as_slice()),
+            None
+        ));
+        assert!(!is_legacy_applepay_verification(
+            Some(domains.as_slice()),
+            Some(&details)
+        ));
+        assert!(!is_legacy_applepay_verification(Some(&[][..]), None));
+        assert!(!is_legacy_applepay_verification(None, None));
+    }
+
+    #[test]
+    fn test_applepay_reverification_decision() {
+        let details = ApplepayVerificationDetails::new(time::macros::datetime!(2024-01-01 00:00));
+        let tracking_data = get_tracking_data(details.expires_at);
+
+        assert_eq!(
+            get_applepay_reverification_decision(
+                Some(&details),
+                &tracking_data,
+                time::macros::datetime!(2024-12-05 00:00)
+            ),
+            ApplepayReverificationDecision::Reverify
+        );
+        assert_eq!(
+            get_applepay_reverification_decision(
+                Some(&details),
+                &tracking_data,
+                time::macros::datetime!(2024-06-01 00:00)
+            ),
+            ApplepayReverificationDecision::RetryAt(details.get_reverification_time())
+        );
+    }
+
+    #[test]
+    fn test_applepay_reverification_skipped_when_verified_again() {
+        let previous = ApplepayVerificationDetails::new(time::macros::datetime!(2024-01-01 00:00));
+        let latest = ApplepayVerificationDetails::new(time::macros::datetime!(2024-12-02 00:00));
+        let tracking_data = get_tracking_data(previous.expires_at);
+        let current_time = time::macros::datetime!(2024-12-05 00:00);
+
+        assert_eq!(
+            get_applepay_reverification_decision(Some(&latest), &tracking_data, current_time),
+            ApplepayReverificationDecision::Skip
+        );
+    }
+
+    #[test]
+    fn test_applepay_reverification_of_legacy_verification() {
+        let tracking_data = get_tracking_data(time::macros::datetime!(2025-01-04 00:00));
+
+        // Domains verified before the verification time was recorded are verified again right away
+        assert_eq!(
+            get_applepay_reverification_decision(
+                None,
+                &tracking_data,
+                time::macros::datetime!(2024-12-05 00:00)
+            ),
+            ApplepayReverificationDecision::Reverify
+        );
+    }
+
+    #[test]
+    fn test_applepay_reverification_retry_backs_off_until_expiry() {
+        let details = ApplepayVerificationDetails::new(time::macros::datetime!(2024-01-01 00:00));
+        let tracking_data = get_tracking_data(details.expires_at);
+        let current_time = time::macros::datetime!(2024-12-05 00:00);
+
+        assert_eq!(
+            get_applepay_reverification_retry_time(&tracking_data, 0, current_time),
+            Some(time::macros::datetime!(2024-12-05 00:15))
+        );
+        assert_eq!(
+            get_applepay_reverification_retry_time(&tracking_data, 2, current_time),
+            Some(time::macros::datetime!(2024-12-05 01:00))
+        );
+        assert_eq!(
+            get_applepay_reverification_retry_time(&tracking_data, 40, current_time),
+            Some(time::macros::datetime!(2024-12-06 00:00))
+        );
+        assert_eq!(
+            get_applepay_reverification_retry_time(
+                &tracking_data,
+                40,
+                time::macros::datetime!(2024-12-30 00:00)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reverification_requires_verified_domains() {
+        assert!(get_domains_for_reverification(None).is_err());
+        assert!(get_domains_for_reverification(Some(vec![])).is_err());
+        assert_eq!(
+            get_domains_for_reverification(Some(vec!["example.com".to_string()])).unwrap(),
+            vec!["example.com".to_string()]
+        );
+    }
+}
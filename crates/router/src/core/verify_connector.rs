@@ -1,9 +1,21 @@
-use api_models::{enums::Connector, verify_connector::VerifyConnectorRequest};
+use std::str::FromStr;
+
+use api_models::{
+    enums::Connector,
+    verify_connector::{
+        MerchantConnectorHealthRequest, MerchantConnectorHealthResponse, VerificationResult,
+        VerificationScope, VerifyConnectorRequest, VerifyConnectorResponse,
+    },
+};
+use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
     connector,
-    core::errors,
+    core::errors::{self, StorageErrorExt},
     services,
     types::{
         api,
@@ -13,6 +25,13 @@ use crate::{
     AppState,
 };
 
+/// Health of merchant connector accounts is cached for this duration, so that the connector is
+/// not called on every health check
+const MERCHANT_CONNECTOR_HEALTH_TTL_IN_SECS: i64 = 60;
+
+/// Signing secrets of Stripe webhook endpoints always start with this prefix
+const STRIPE_WEBHOOK_SECRET_PREFIX: &str = "whsec_";
+
 pub async fn verify_connector_credentials(
     state: AppState,
     req: VerifyConnectorRequest,
@@ -61,3 +80,454 @@ pub async fn verify_connector_credentials(
         .into()),
     }
 }
+
+/// Verifies the connector credentials and, depending on the verification scope of the request, the
+/// webhook configuration. Requests without a verification scope only verify the credentials.
+pub async fn verify_connector(
+    state: AppState,
+    req: VerifyConnectorRequest,
+) -> errors::RouterResponse<VerifyConnectorResponse> {
+    let Some(verification_scope) = req.verification_scope else {
+        return verify_connector_credentials(state, req)
+            .await
+            .map(|_| services::ApplicationResponse::StatusOk);
+    };
+
+    let webhook_result = verification_scope
+        .includes_webhook()
+        .then(|| verify_webhook_configuration(&req))
+        .transpose()?;
+    let auth_result = if verification_scope.includes_auth() {
+        Some(verify_connector_credentials(state, req).await)
+    } else {
+        None
+    };
+
+    get_verify_connector_response(verification_scope, auth_result, webhook_result)
+        .map(services::ApplicationResponse::Json)
+}
+
+/// Checks that the webhook secrets have the format the connector uses to verify the source of its
+/// webhooks, and that the return URL used after 3DS authentication is configured
+pub fn verify_webhook_configuration(
+    req: &VerifyConnectorRequest,
+) -> errors::RouterResult<VerificationResult> {
+    let mut errors = Vec::new();
+
+    let merchant_secret = req
+        .connector_webhook_details
+        .as_ref()
+        .map(|webhook_details| webhook_details.merchant_secret.peek().as_str())
+        .filter(|merchant_secret| !merchant_secret.is_empty());
+    let additional_secret = req
+        .connector_webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.additional_secret.as_ref())
+        .map(|additional_secret| additional_secret.peek().as_str())
+        .filter(|additional_secret| !additional_secret.is_empty());
+
+    let merchant_secret_error = match (req.connector_name, merchant_secret) {
+        (Connector::Stripe | Connector::Adyen | Connector::Braintree | Connector::Paypal, None) => {
+            Some(
+                "connector_webhook_details.merchant_secret is required to verify incoming webhooks",
+            )
+        }
+        (Connector::Stripe, Some(merchant_secret))
+            if !merchant_secret.starts_with(STRIPE_WEBHOOK_SECRET_PREFIX) =>
+        {
+            Some("connector_webhook_details.merchant_secret must be a Stripe whsec_ secret")
+        }
+        (Connector::Adyen, Some(merchant_secret)) if hex::decode(merchant_secret).is_err() => {
+            Some("connector_webhook_details.merchant_secret must be the hex encoded Adyen HMAC key")
+        }
+        (Connector::Paypal, Some(merchant_secret))
+            if !merchant_secret
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric()) =>
+        {
+            Some("connector_webhook_details.merchant_secret must be the id of the PayPal webhook")
+        }
+        (Connector::Stripe | Connector::Adyen | Connector::Braintree | Connector::Paypal, _) => {
+            None
+        }
+        _ => {
+            return Err(errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Verify webhook configuration".to_string(),
+                connector: req.connector_name.to_string(),
+            }
+            .into())
+        }
+    };
+    errors.extend(merchant_secret_error.map(str::to_string));
+
+    if req.connector_name == Connector::Braintree && additional_secret.is_none() {
+        errors.push(
+            "connector_webhook_details.additional_secret must be the Braintree public key"
+                .to_string(),
+        );
+    }
+
+    match req.return_url.as_deref().map(url::Url::parse) {
+        None => errors.push(
+            "return_url is required to redirect the customer after 3DS authentication".to_string(),
+        ),
+        Some(Ok(return_url)) if matches!(return_url.scheme(), "http" | "https") => {}
+        Some(_) => errors.push("return_url must be a valid http or https URL".to_string()),
+    }
+
+    Ok(VerificationResult::from_errors(errors))
+}
+
+/// Builds the per scope verification results. Credentials rejected by the connector fail the auth
+/// scope, while any other failure (such as the connector not supporting verification) is returned
+/// as is.
+pub fn get_verify_connector_response(
+    verification_scope: VerificationScope,
+    auth_result: Option<errors::RouterResponse<()>>,
+    webhook_result: Option<VerificationResult>,
+) -> errors::RouterResult<VerifyConnectorResponse> {
+    let auth = auth_result
+        .map(get_verification_error)
+        .transpose()?
+        .map(|error| VerificationResult::from_errors(error.into_iter().collect()));
+
+    Ok(VerifyConnectorResponse {
+        verification_scope,
+        auth,
+        webhook: webhook_result,
+    })
+}
+
+/// Returns the reason for which the connector rejected the credentials, if it did
+fn get_verification_error(
+    verification_result: errors::RouterResponse<()>,
+) -> errors::RouterResult<Option<String>> {
+    match verification_result {
+        Ok(_) => Ok(None),
+        Err(error) => match error.current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { message } => Ok(Some(message.clone())),
+            _ => Err(error),
+        },
+    }
+}
+
+fn get_merchant_connector_health_key(merchant_id: &str, merchant_connector_id: &str) -> String {
+    format!("merchant_connector_health_{merchant_id}_{merchant_connector_id}")
+}
+
+/// Derives the health of a merchant connector account from the outcome of verifying its
+/// credentials. Credentials rejected by the connector make the account unhealthy, while any other
+/// failure (such as the connector not supporting verification) is returned as is.
+pub fn get_merchant_connector_health(
+    merchant_connector_id: String,
+    connector_name: Connector,
+    verification_result: errors::RouterResponse<()>,
+    checked_at: PrimitiveDateTime,
+) -> errors::RouterResult<MerchantConnectorHealthResponse> {
+    let error = get_verification_error(verification_result)?;
+
+    Ok(MerchantConnectorHealthResponse {
+        merchant_connector_id,
+        connector_name,
+        healthy: error.is_none(),
+        checked_at,
+        error,
+    })
+}
+
+async fn get_cached_merchant_connector_health(
+    state: &AppState,
+    key: &str,
+) -> Option<MerchantConnectorHealthResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(merchant_connector_health_redis_error=?error))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<MerchantConnectorHealthResponse>(
+            key,
+            "MerchantConnectorHealthResponse",
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context() != &redis_interface::errors::RedisError::NotFound {
+                logger::error!(merchant_connector_health_redis_error=?error);
+            }
+        })
+        .ok()
+}
+
+/// Verifies that the credentials of a merchant connector account are still accepted by the
+/// connector, through the same lightweight call used to verify credentials while onboarding
+#[instrument(skip(state))]
+pub async fn check_merchant_connector_health(
+    state: AppState,
+    req: MerchantConnectorHealthRequest,
+) -> errors::RouterResponse<MerchantConnectorHealthResponse> {
+    let key = get_merchant_connector_health_key(&req.merchant_id, &req.merchant_connector_id);
+    if let Some(health) = get_cached_merchant_connector_health(&state, &key).await {
+        return Ok(services::ApplicationResponse::Json(health));
+    }
+
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &req.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &req.merchant_id,
+            &req.merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: req.merchant_connector_id.clone(),
+        })?;
+
+    let connector_name = Connector::from_str(&mca.connector_name)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        })
+        .attach_printable_lazy(|| {
+            format!("unable to parse connector name {:?}", mca.connector_name)
+        })?;
+    let connector_account_details = mca
+        .connector_account_details
+        .into_inner()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse connector account details")?;
+
+    let verification_result = verify_connector_credentials(
+        state.clone(),
+        VerifyConnectorRequest {
+            connector_name,
+            connector_account_details,
+            verification_scope: None,
+            connector_webhook_details: None,
+            return_url: None,
+        },
+    )
+    .await;
+    let health = get_merchant_connector_health(
+        req.merchant_connector_id,
+        connector_name,
+        verification_result,
+        common_utils::date_time::now(),
+    )?;
+
+    if let Err(error) = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .serialize_and_set_key_with_expiry(&key, &health, MERCHANT_CONNECTOR_HEALTH_TTL_IN_SECS)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    }
+    .await
+    {
+        logger::error!(merchant_connector_health_redis_error=?error);
+    }
+
+    Ok(services::ApplicationResponse::Json(health))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_merchant_connector_with_valid_credentials_is_healthy() {
+        let checked_at = datetime!(2024-01-01 00:00);
+        let health = get_merchant_connector_health(
+            "mca_123".to_string(),
+            Connector::Stripe,
+            Ok(services::ApplicationResponse::StatusOk),
+            checked_at,
+        )
+        .unwrap();
+
+        assert_eq!(
+            health,
+            MerchantConnectorHealthResponse {
+                merchant_connector_id: "mca_123".to_string(),
+                connector_name: Connector::Stripe,
+                healthy: true,
+                checked_at,
+                error: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_merchant_connector_with_bad_credentials_is_unhealthy() {
+        let health = get_merchant_connector_health(
+            "mca_123".to_string(),
+            Connector::Stripe,
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid API Key provided".to_string(),
+            }
+            .into()),
+            datetime!(2024-01-01 00:00),
+        )
+        .unwrap();
+
+        assert!(!health.healthy);
+        assert_eq!(health.error.as_deref(), Some("Invalid API Key provided"));
+
+        // Connectors which cannot be verified are reported as an error rather than as unhealthy
+        let error = get_merchant_connector_health(
+            "mca_123".to_string(),
+            Connector::Adyen,
+            Err(errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Verify credentials".to_string(),
+                connector: "adyen".to_string(),
+            }
+            .into()),
+            datetime!(2024-01-01 00:00),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FlowNotSupported { .. }
+        ));
+    }
+
+    fn get_verify_connector_request(
+        connector_name: Connector,
+        verification_scope: VerificationScope,
+        connector_webhook_details: Option<api_models::admin::MerchantConnectorWebhookDetails>,
+    ) -> VerifyConnectorRequest {
+        VerifyConnectorRequest {
+            connector_name,
+            connector_account_details: api_models::admin::ConnectorAuthType::HeaderKey {
+                api_key: masking::Secret::new("sk_test_123".to_string()),
+            },
+            verification_scope: Some(verification_scope),
+            connector_webhook_details,
+            return_url: Some("https://example.com/return".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_auth_scope_passes_without_webhook_configuration() {
+        let response = get_verify_connector_response(
+            VerificationScope::Auth,
+            Some(Ok(services::ApplicationResponse::StatusOk)),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            VerifyConnectorResponse {
+                verification_scope: VerificationScope::Auth,
+                auth: Some(VerificationResult {
+                    verified: true,
+                    errors: Vec::new(),
+                }),
+                webhook: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_full_scope_fails_with_incomplete_webhook_configuration() {
+        let req = get_verify_connector_request(Connector::Stripe, VerificationScope::Full, None);
+        let response = get_verify_connector_response(
+            VerificationScope::Full,
+            Some(Ok(services::ApplicationResponse::StatusOk)),
+            Some(verify_webhook_configuration(&req).unwrap()),
+        )
+        .unwrap();
+
+        assert!(response.auth.unwrap().verified);
+        let webhook = response.webhook.unwrap();
+        assert!(!webhook.verified);
+        assert_eq!(
+            webhook.errors,
+            vec![
+                "connector_webhook_details.merchant_secret is required to verify incoming webhooks"
+                    .to_string()
+            ]
+        );
+
+        // The webhook configuration is verified once the secret is configured
+        let req = get_verify_connector_request(
+            Connector::Stripe,
+            VerificationScope::Full,
+            Some(api_models::admin::MerchantConnectorWebhookDetails {
+                merchant_secret: masking::Secret::new("whsec_123".to_string()),
+                additional_secret: None,
+            }),
+        );
+        assert!(verify_webhook_configuration(&req).unwrap().verified);
+    }
+
+    #[test]
+    fn test_webhook_secrets_are_verified_against_the_connector_format() {
+        let get_webhook_errors = |connector_name, merchant_secret: &str, additional_secret| {
+            let req = get_verify_connector_request(
+                connector_name,
+                VerificationScope::Webhook,
+                Some(api_models::admin::MerchantConnectorWebhookDetails {
+                    merchant_secret: masking::Secret::new(merchant_secret.to_string()),
+                    additional_secret,
+                }),
+            );
+            verify_webhook_configuration(&req).unwrap().errors
+        };
+
+        // A Stripe API key is not the signing secret of a webhook endpoint
+        assert_eq!(
+            get_webhook_errors(Connector::Stripe, "sk_test_123", None),
+            vec![
+                "connector_webhook_details.merchant_secret must be a Stripe whsec_ secret"
+                    .to_string()
+            ]
+        );
+        assert_eq!(
+            get_webhook_errors(Connector::Adyen, "not-a-hmac-key", None),
+            vec![
+                "connector_webhook_details.merchant_secret must be the hex encoded Adyen HMAC key"
+                    .to_string()
+            ]
+        );
+        assert!(get_webhook_errors(Connector::Adyen, "44782def547aaa06", None).is_empty());
+        assert_eq!(
+            get_webhook_errors(Connector::Braintree, "private_key", None),
+            vec![
+                "connector_webhook_details.additional_secret must be the Braintree public key"
+                    .to_string()
+            ]
+        );
+        assert!(get_webhook_errors(
+            Connector::Braintree,
+            "private_key",
+            Some(masking::Secret::new("public_key".to_string()))
+        )
+        .is_empty());
+        assert!(get_webhook_errors(Connector::Paypal, "1JE4291016473214C", None).is_empty());
+
+        // Connectors whose webhook secrets are not known cannot be verified
+        let req =
+            get_verify_connector_request(Connector::Checkout, VerificationScope::Webhook, None);
+        assert!(matches!(
+            verify_webhook_configuration(&req)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::FlowNotSupported { .. }
+        ));
+    }
+}
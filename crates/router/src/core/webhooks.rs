@@ -3,7 +3,7 @@ pub mod utils;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
-use std::{str::FromStr, time::Instant};
+use std::{collections::HashMap, str::FromStr, time::Instant};
 
 use actix_web::FromRequest;
 use api_models::{
@@ -28,9 +28,9 @@ use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
-        payments, refunds,
+        mandate, payments, refunds,
     },
     db::StorageInterface,
     events::{
@@ -58,6 +58,7 @@ use crate::{
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
 const MERCHANT_ID: &str = "merchant_id";
 
+#[allow(clippy::too_many_arguments)]
 pub async fn payments_incoming_webhook_flow(
     state: AppState,
     req_state: ReqState,
@@ -66,6 +67,7 @@ pub async fn payments_incoming_webhook_flow(
     key_store: domain::MerchantKeyStore,
     webhook_details: api::IncomingWebhookDetails,
     source_verified: bool,
+    mandate_reference: Option<crate::types::MandateReference>,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     let consume_or_trigger_flow = if source_verified {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
@@ -171,6 +173,27 @@ pub async fn payments_incoming_webhook_flow(
 
             let status = payments_response.status;
 
+            // The mandate reference may only be sent by the connector in a later webhook, rather
+            // than in the authorize response
+            if let Some((mandate_id, mandate_reference)) = payments_response
+                .mandate_id
+                .as_deref()
+                .zip(mandate_reference)
+                .filter(|_| source_verified)
+            {
+                if let Err(error) = mandate::backfill_connector_mandate_id(
+                    &*state.store,
+                    &merchant_account.merchant_id,
+                    mandate_id,
+                    mandate_reference,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                {
+                    logger::error!(mandate_backfill_error=?error);
+                }
+            }
+
             let event_type: Option<enums::EventType> = payments_response.status.foreign_into();
 
             // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -719,6 +742,24 @@ pub async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        let dispute_auto_accept_threshold = business_profile
+            .dispute_auto_accept_threshold
+            .clone()
+            .map(|dispute_auto_accept_threshold| {
+                dispute_auto_accept_threshold
+                    .parse_value::<HashMap<enums::Currency, u32>>("DisputeAutoAcceptThreshold")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the dispute auto accept threshold")?;
+        let dispute_object = if disputes::should_auto_accept_dispute(
+            &dispute_object,
+            dispute_auto_accept_threshold.as_ref(),
+        ) {
+            auto_accept_dispute(&state, &merchant_account, &key_store, dispute_object).await
+        } else {
+            dispute_object
+        };
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -749,6 +790,61 @@ pub async fn disputes_incoming_webhook_flow(
     }
 }
 
+/// Accepts the dispute with the connector on behalf of the merchant. If the connector fails to
+/// accept it, the dispute is left open for the merchant to handle. The outcome is recorded on the
+/// dispute.
+#[instrument(skip_all)]
+async fn auto_accept_dispute(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: diesel_models::dispute::Dispute,
+) -> diesel_models::dispute::Dispute {
+    let (dispute, auto_accepted) = match disputes::accept_dispute_with_connector(
+        state,
+        merchant_account,
+        key_store,
+        dispute.clone(),
+    )
+    .await
+    {
+        Ok(accepted_dispute) => {
+            metrics::DISPUTE_AUTO_ACCEPTED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+            logger::info!(
+                dispute_id = %accepted_dispute.dispute_id,
+                dispute_amount = accepted_dispute.dispute_amount,
+                dispute_status = %accepted_dispute.dispute_status,
+                "Dispute auto accepted as its amount is within the auto accept threshold"
+            );
+            (accepted_dispute, true)
+        }
+        Err(error) => {
+            metrics::DISPUTE_AUTO_ACCEPT_FAILURE_METRIC.add(&metrics::CONTEXT, 1, &[]);
+            logger::error!(?error, dispute_id = %dispute.dispute_id, "Failed to auto accept dispute");
+            (dispute, false)
+        }
+    };
+
+    match state
+        .store
+        .update_dispute(
+            dispute.clone(),
+            storage::DisputeUpdate::AutoAcceptUpdate { auto_accepted },
+        )
+        .await
+    {
+        Ok(updated_dispute) => updated_dispute,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                dispute_id = %dispute.dispute_id,
+                "Failed to record the auto accept decision of the dispute"
+            );
+            dispute
+        }
+    }
+}
+
 async fn bank_transfer_webhook_flow(
     state: AppState,
     req_state: ReqState,
@@ -884,6 +980,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         &merchant_account,
         outgoing_webhook,
         business_profile.payment_response_hash_key.as_deref(),
+        get_outgoing_webhook_signature_algorithm(&business_profile),
     )
     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
     .attach_printable("Failed to construct outgoing webhook request content")?;
@@ -1320,7 +1417,7 @@ async fn trigger_webhook_to_merchant(
                     // Schedule a retry attempt for webhook delivery
                     outgoing_webhook_retry::retry_webhook_delivery_task(
                         &*state.store,
-                        &business_profile.merchant_id,
+                        &business_profile,
                         process_tracker,
                     )
                     .await
@@ -1357,7 +1454,7 @@ async fn trigger_webhook_to_merchant(
                         // Schedule a retry attempt for webhook delivery
                         outgoing_webhook_retry::retry_webhook_delivery_task(
                             &*state.store,
-                            &business_profile.merchant_id,
+                            &business_profile,
                             process_tracker,
                         )
                         .await
@@ -1561,8 +1658,159 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
 
     request_details.body = &decoded_body;
 
+    let event_bodies = connector
+        .get_webhook_event_bodies(&request_details)
+        .switch()
+        .attach_printable("Could not split incoming webhook body into individual events")?;
+
+    let (webhook_effect, serialized_request) = if event_bodies.len() > 1 {
+        Box::pin(batched_incoming_webhook_core(
+            state,
+            req_state,
+            merchant_account,
+            key_store,
+            merchant_connector_account,
+            connector,
+            &connector_name,
+            &request_details,
+            event_bodies,
+        ))
+        .await?
+    } else {
+        Box::pin(incoming_webhook_event_core(
+            state,
+            req_state,
+            merchant_account,
+            key_store,
+            merchant_connector_account,
+            connector,
+            &connector_name,
+            &request_details,
+        ))
+        .await?
+    };
+
+    let response = connector
+        .get_webhook_api_response(&request_details)
+        .switch()
+        .attach_printable("Could not get incoming webhook api response from connector")?;
+
+    Ok((response, webhook_effect, serialized_request))
+}
+
+/// Processes every event of a batched incoming webhook independently of the others. Events that
+/// were processed in an earlier delivery of the batch are skipped, and the batch is failed if any
+/// of its events fail so that the connector redelivers it.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn batched_incoming_webhook_core(
+    state: AppState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    merchant_connector_account: Option<domain::MerchantConnectorAccount>,
+    connector: &'static (dyn api::Connector + Sync),
+    connector_name: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+    event_bodies: Vec<Vec<u8>>,
+) -> errors::RouterResult<(WebhookResponseTracker, serde_json::Value)> {
+    logger::info!(
+        batch_size = event_bodies.len(),
+        "Received batched incoming webhook"
+    );
+
+    let event_outcomes = utils::process_webhook_event_batch(event_bodies, |event_body| {
+        let state = state.clone();
+        let req_state = req_state.clone();
+        let merchant_account = merchant_account.clone();
+        let key_store = key_store.clone();
+        let merchant_connector_account = merchant_connector_account.clone();
+        async move {
+            let dedup_key = utils::get_webhook_event_dedup_key(
+                &merchant_account.merchant_id,
+                connector_name,
+                &event_body,
+            )?;
+            if utils::is_webhook_event_processed(&*state.store, &dedup_key).await {
+                return Ok(None);
+            }
+
+            let event_request_details = api::IncomingWebhookRequestDetails {
+                method: request_details.method.clone(),
+                uri: request_details.uri.clone(),
+                headers: request_details.headers,
+                query_params: request_details.query_params.clone(),
+                body: &event_body,
+            };
+            let event_result = Box::pin(incoming_webhook_event_core(
+                state.clone(),
+                req_state,
+                merchant_account,
+                key_store,
+                merchant_connector_account,
+                connector,
+                connector_name,
+                &event_request_details,
+            ))
+            .await?;
+
+            utils::mark_webhook_event_processed(&*state.store, &dedup_key).await;
+            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(Some(event_result))
+        }
+    })
+    .await;
+
+    let mut webhook_effects = Vec::with_capacity(event_outcomes.len());
+    let mut serialized_events = Vec::with_capacity(event_outcomes.len());
+    let mut failed_events_count = 0;
+    for event_outcome in event_outcomes {
+        match event_outcome {
+            utils::WebhookBatchEventOutcome::Processed((webhook_effect, serialized_event)) => {
+                webhook_effects.push(webhook_effect);
+                serialized_events.push(serialized_event);
+            }
+            utils::WebhookBatchEventOutcome::Duplicate => {
+                logger::info!("Skipping an already processed event of batched incoming webhook");
+            }
+            utils::WebhookBatchEventOutcome::Failed(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to process an event of batched incoming webhook"
+                );
+                failed_events_count += 1;
+            }
+        }
+    }
+
+    if failed_events_count > 0 {
+        return Err(report!(errors::ApiErrorResponse::WebhookProcessingFailure))
+            .attach_printable_lazy(|| {
+                format!("{failed_events_count} events of batched incoming webhook failed")
+            });
+    }
+
+    Ok((
+        WebhookResponseTracker::Batch {
+            effects: webhook_effects,
+        },
+        serde_json::Value::Array(serialized_events),
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn incoming_webhook_event_core(
+    state: AppState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    merchant_connector_account: Option<domain::MerchantConnectorAccount>,
+    connector: &'static (dyn api::Connector + Sync),
+    connector_name: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<(WebhookResponseTracker, serde_json::Value)> {
     let event_type = match connector
-        .get_webhook_event_type(&request_details)
+        .get_webhook_event_type(request_details)
         .allow_webhook_event_type_not_found(
             state
                 .clone()
@@ -1592,16 +1840,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
                 ],
             );
 
-            let response = connector
-                .get_webhook_api_response(&request_details)
-                .switch()
-                .attach_printable("Failed while early return in case of event type parsing")?;
-
-            return Ok((
-                response,
-                WebhookResponseTracker::NoEffect,
-                serde_json::Value::Null,
-            ));
+            return Ok((WebhookResponseTracker::NoEffect, serde_json::Value::Null));
         }
     };
     logger::info!(event_type=?event_type);
@@ -1612,7 +1851,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
     );
     let is_webhook_event_enabled = !utils::is_webhook_event_disabled(
         &*state.clone().store,
-        connector_name.as_str(),
+        connector_name,
         &merchant_account.merchant_id,
         &event_type,
     )
@@ -1629,10 +1868,10 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
         && !matches!(flow_type, api::WebhookFlow::ReturnResponse)
     {
         let object_ref_id = connector
-            .get_webhook_object_reference_id(&request_details)
+            .get_webhook_object_reference_id(request_details)
             .switch()
             .attach_printable("Could not find object reference id in incoming webhook body")?;
-        let connector_enum = api_models::enums::Connector::from_str(&connector_name)
+        let connector_enum = api_models::enums::Connector::from_str(connector_name)
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "connector",
             })
@@ -1648,7 +1887,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
                     &*state.clone().store,
                     object_ref_id.clone(),
                     &merchant_account,
-                    &connector_name,
+                    connector_name,
                     &key_store,
                 )
                 .await?
@@ -1664,8 +1903,8 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
                     &state,
                     &merchant_account,
                     merchant_connector_account.clone(),
-                    &connector_name,
-                    &request_details,
+                    connector_name,
+                    request_details,
                 )
                 .await
                 .or_else(|error| match error.current_context() {
@@ -1680,10 +1919,10 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
         } else {
             connector
                 .verify_webhook_source(
-                    &request_details,
+                    request_details,
                     &merchant_account,
                     merchant_connector_account.clone(),
-                    connector_name.as_str(),
+                    connector_name,
                 )
                 .await
                 .or_else(|error| match error.current_context() {
@@ -1714,8 +1953,45 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
 
         logger::info!(source_verified=?source_verified);
 
+        if let Some(replay_protection_window_in_secs) =
+            state.conf.webhooks.replay_protection_window_in_secs
+        {
+            let webhook_timestamp = connector
+                .get_webhook_timestamp(request_details)
+                .switch()
+                .attach_printable("Could not find timestamp in incoming webhook body")?;
+
+            if let Some(webhook_timestamp) = webhook_timestamp {
+                if utils::is_webhook_timestamp_stale(
+                    webhook_timestamp,
+                    common_utils::date_time::now(),
+                    replay_protection_window_in_secs,
+                ) {
+                    logger::warn!(
+                        ?webhook_timestamp,
+                        "Incoming webhook is older than the replay protection window, skipping processing"
+                    );
+
+                    metrics::WEBHOOK_INCOMING_REPLAY_REJECTED_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[
+                            metrics::KeyValue::new(
+                                MERCHANT_ID,
+                                merchant_account.merchant_id.clone(),
+                            ),
+                            metrics::KeyValue::new("connector", connector_name.to_string()),
+                        ],
+                    );
+
+                    // Acknowledge the webhook so that the connector does not keep retrying it
+                    return Ok((WebhookResponseTracker::NoEffect, serde_json::Value::Null));
+                }
+            }
+        }
+
         event_object = connector
-            .get_webhook_resource_object(&request_details)
+            .get_webhook_resource_object(request_details)
             .switch()
             .attach_printable("Could not find resource object in incoming webhook body")?;
 
@@ -1746,17 +2022,26 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
             })?;
 
         match flow_type {
-            api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            api::WebhookFlow::Payment => {
+                let mandate_reference = connector
+                    .get_webhook_mandate_reference(request_details)
+                    .map_err(|error| logger::error!(webhook_mandate_reference_error=?error))
+                    .ok()
+                    .flatten();
+
+                Box::pin(payments_incoming_webhook_flow(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                    mandate_reference,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payments failed")?
+            }
 
             api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
                 state.clone(),
@@ -1764,7 +2049,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
                 business_profile,
                 key_store,
                 webhook_details,
-                connector_name.as_str(),
+                connector_name,
                 source_verified,
                 event_type,
             ))
@@ -1779,7 +2064,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
                 webhook_details,
                 source_verified,
                 connector,
-                &request_details,
+                request_details,
                 event_type,
             ))
             .await
@@ -1819,7 +2104,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
                     key_store,
                     source_verified,
                     event_type,
-                    &request_details,
+                    request_details,
                     connector,
                     object_ref_id,
                     business_profile,
@@ -1844,16 +2129,12 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
         WebhookResponseTracker::NoEffect
     };
 
-    let response = connector
-        .get_webhook_api_response(&request_details)
-        .switch()
-        .attach_printable("Could not get incoming webhook api response from connector")?;
-
-    let serialized_request = event_object
+    let serialized_event = event_object
         .masked_serialize()
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not convert webhook effect to string")?;
-    Ok((response, webhook_effect, serialized_request))
+
+    Ok((webhook_effect, serialized_event))
 }
 
 #[inline]
@@ -1977,16 +2258,13 @@ pub async fn add_outgoing_webhook_retry_task_to_process_tracker(
     business_profile: &diesel_models::business_profile::BusinessProfile,
     event: &domain::Event,
 ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-    let schedule_time = outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(
-        db,
-        &business_profile.merchant_id,
-        0,
-    )
-    .await
-    .ok_or(errors::StorageError::ValueNotFound(
-        "Process tracker schedule time".into(), // Can raise a better error here
-    ))
-    .attach_printable("Failed to obtain initial process tracker schedule time")?;
+    let schedule_time =
+        outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(db, business_profile, 0)
+            .await
+            .ok_or(errors::StorageError::ValueNotFound(
+                "Process tracker schedule time".into(), // Can raise a better error here
+            ))
+            .attach_printable("Failed to obtain initial process tracker schedule time")?;
 
     let tracking_data = types::OutgoingWebhookTrackingData {
         merchant_id: business_profile.merchant_id.clone(),
@@ -2058,15 +2336,40 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Get the algorithm used to sign outgoing webhooks for the business profile, falling back to
+/// the default algorithm if none is configured.
+pub(crate) fn get_outgoing_webhook_signature_algorithm(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> enums::OutgoingWebhookSignatureAlgorithm {
+    business_profile
+        .webhook_details
+        .clone()
+        .and_then(|webhook_details| {
+            webhook_details
+                .parse_value::<api::WebhookDetails>("WebhookDetails")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse webhook details of business profile, using default outgoing webhook signature algorithm"
+                    )
+                })
+                .ok()
+        })
+        .and_then(|webhook_details| webhook_details.outgoing_webhook_signature_algorithm)
+        .unwrap_or_default()
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     payment_response_hash_key: Option<&str>,
+    signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         payment_response_hash_key: Option<&str>,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![(
             reqwest::header::CONTENT_TYPE.to_string(),
@@ -2076,10 +2379,10 @@ pub(crate) fn get_outgoing_webhook_request(
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
 
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(payment_response_hash_key, signature_algorithm)?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
-            WebhookType::add_webhook_header(&mut headers, signature)
+            WebhookType::add_webhook_header(&mut headers, signature, signature_algorithm)
         }
 
         Ok(OutgoingWebhookRequestContent {
@@ -2093,14 +2396,167 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(
-            outgoing_webhook, payment_response_hash_key
-        ),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                payment_response_hash_key,
+                signature_algorithm,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             payment_response_hash_key,
+            signature_algorithm,
+        ),
+    }
+}
+
+/// Signs the payload of a previously stored outgoing webhook request with the current
+/// `payment_response_hash_key`, so that replayed webhooks can be verified by the merchant.
+pub(crate) fn get_resigned_outgoing_webhook_request(
+    merchant_account: &domain::MerchantAccount,
+    request_content: OutgoingWebhookRequestContent,
+    payment_response_hash_key: Option<&str>,
+    signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    match merchant_account.get_compatible_connector() {
+        #[cfg(feature = "stripe")]
+        Some(api_models::enums::Connector::Stripe) => {
+            get_resigned_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                request_content,
+                payment_response_hash_key,
+                signature_algorithm,
+            )
+        }
+        _ => get_resigned_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
+            request_content,
+            payment_response_hash_key,
+            signature_algorithm,
         ),
     }
 }
+
+fn get_resigned_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
+    request_content: OutgoingWebhookRequestContent,
+    payment_response_hash_key: Option<&str>,
+    signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    let mut headers = vec![(
+        reqwest::header::CONTENT_TYPE.to_string(),
+        mime::APPLICATION_JSON.essence_str().into(),
+    )];
+
+    if let Some(signature) = WebhookType::get_signature_for_payload(
+        request_content.body.peek(),
+        payment_response_hash_key,
+        signature_algorithm,
+    )? {
+        WebhookType::add_webhook_header(&mut headers, signature, signature_algorithm)
+    }
+
+    Ok(OutgoingWebhookRequestContent {
+        body: request_content.body,
+        headers: headers
+            .into_iter()
+            .map(|(name, value)| (name, Secret::new(value.into_inner())))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    #[test]
+    fn test_resigned_outgoing_webhook_request_uses_current_key() {
+        let body = r#"{"merchant_id":"merchant1","event_id":"evt_replay"}"#;
+        let stored_request_content = OutgoingWebhookRequestContent {
+            body: Secret::new(body.to_string()),
+            headers: vec![(
+                crate::headers::X_WEBHOOK_SIGNATURE.to_string(),
+                Secret::new("signature_with_rotated_key".to_string()),
+            )],
+        };
+
+        let request_content =
+            get_resigned_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
+                stored_request_content,
+                Some("current_key"),
+                enums::OutgoingWebhookSignatureAlgorithm::HmacSha512,
+            )
+            .unwrap();
+
+        let expected_signature = hex::encode(
+            common_utils::crypto::HmacSha512
+                .sign_message(b"current_key", body.as_bytes())
+                .unwrap(),
+        );
+        let signature = request_content
+            .headers
+            .iter()
+            .find(|(name, _)| name.as_str() == crate::headers::X_WEBHOOK_SIGNATURE)
+            .map(|(_, value)| value.peek().clone());
+
+        assert_eq!(request_content.body.peek(), body);
+        assert_eq!(signature, Some(expected_signature));
+    }
+
+    fn get_outgoing_webhook_signature_header(
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+    ) -> Vec<(String, String)> {
+        // Test case 2 from RFC 4231
+        let stored_request_content = OutgoingWebhookRequestContent {
+            body: Secret::new("what do ya want for nothing?".to_string()),
+            headers: vec![],
+        };
+
+        get_resigned_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
+            stored_request_content,
+            Some("Jefe"),
+            signature_algorithm,
+        )
+        .unwrap()
+        .headers
+        .into_iter()
+        .filter(|(name, _)| name.starts_with("X-Webhook-Signature"))
+        .map(|(name, value)| (name, value.expose()))
+        .collect()
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_hmac_sha256() {
+        assert_eq!(
+            get_outgoing_webhook_signature_header(
+                enums::OutgoingWebhookSignatureAlgorithm::HmacSha256
+            ),
+            vec![(
+                crate::headers::X_WEBHOOK_SIGNATURE_256.to_string(),
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_hmac_sha512() {
+        assert_eq!(
+            get_outgoing_webhook_signature_header(
+                enums::OutgoingWebhookSignatureAlgorithm::HmacSha512
+            ),
+            vec![(
+                crate::headers::X_WEBHOOK_SIGNATURE.to_string(),
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_algorithm_defaults_to_hmac_sha512() {
+        assert_eq!(
+            enums::OutgoingWebhookSignatureAlgorithm::default(),
+            enums::OutgoingWebhookSignatureAlgorithm::HmacSha512
+        );
+    }
+}
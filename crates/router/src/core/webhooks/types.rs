@@ -17,33 +17,39 @@ pub trait OutgoingWebhookType:
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+    /// Computes the signature for an already encoded outgoing webhook payload
+    fn get_signature_for_payload(
+        payload: &str,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+    ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError>;
+
+    fn add_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+    );
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let webhook_signature_payload = self
             .encode_to_string_of_json()
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let signature = payment_response_hash_key
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
+        let signature = Self::get_signature_for_payload(
+            &webhook_signature_payload,
+            payment_response_hash_key,
+            signature_algorithm,
+        )?;
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
@@ -51,8 +57,46 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
         })
     }
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
-        header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
+    fn get_signature_for_payload(
+        payload: &str,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+    ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+        payment_response_hash_key
+            .map(|key| match signature_algorithm {
+                enums::OutgoingWebhookSignatureAlgorithm::HmacSha256 => {
+                    common_utils::crypto::HmacSha256::sign_message(
+                        &common_utils::crypto::HmacSha256,
+                        key.as_ref(),
+                        payload.as_bytes(),
+                    )
+                }
+                enums::OutgoingWebhookSignatureAlgorithm::HmacSha512 => {
+                    common_utils::crypto::HmacSha512::sign_message(
+                        &common_utils::crypto::HmacSha512,
+                        key.as_ref(),
+                        payload.as_bytes(),
+                    )
+                }
+            })
+            .transpose()
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+            .attach_printable("Failed to sign the message")
+            .map(|signature| signature.map(hex::encode))
+    }
+
+    fn add_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+        signature_algorithm: enums::OutgoingWebhookSignatureAlgorithm,
+    ) {
+        let header_name = match signature_algorithm {
+            enums::OutgoingWebhookSignatureAlgorithm::HmacSha256 => {
+                headers::X_WEBHOOK_SIGNATURE_256
+            }
+            enums::OutgoingWebhookSignatureAlgorithm::HmacSha512 => headers::X_WEBHOOK_SIGNATURE,
+        };
+        header.push((header_name.to_string(), signature.into()))
     }
 }
 
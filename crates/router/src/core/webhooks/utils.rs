@@ -1,6 +1,10 @@
-use std::marker::PhantomData;
+use std::{collections::HashSet, future::Future, marker::PhantomData};
 
-use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+};
 use error_stack::ResultExt;
 
 use crate::{
@@ -19,6 +23,19 @@ const IRRELEVANT_ATTEMPT_ID_IN_SOURCE_VERIFICATION_FLOW: &str =
     "irrelevant_attempt_id_in_source_verification_flow";
 const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_SOURCE_VERIFICATION_FLOW: &str =
     "irrelevant_connector_request_reference_id_in_source_verification_flow";
+/// Time for which a processed event of a batched incoming webhook is remembered, so that it is
+/// not processed again when the connector redelivers the batch
+const BATCHED_WEBHOOK_EVENT_DEDUP_TTL_IN_SECS: i64 = 24 * 60 * 60;
+
+/// Outcome of processing a single event of a batched incoming webhook
+#[derive(Debug)]
+pub(crate) enum WebhookBatchEventOutcome<T> {
+    Processed(T),
+    /// The event was already processed, either earlier in the same batch or in an earlier
+    /// delivery of the batch
+    Duplicate,
+    Failed(error_stack::Report<errors::ApiErrorResponse>),
+}
 
 /// Check whether the merchant has configured to disable the webhook `event` for the `connector`
 /// First check for the key "whconf_{merchant_id}_{connector_id}" in redis,
@@ -58,6 +75,99 @@ pub async fn is_webhook_event_disabled(
     }
 }
 
+/// Check whether an incoming webhook is older than the configured replay-protection window.
+/// Webhooks with a timestamp in the future are not considered stale.
+pub(crate) fn is_webhook_timestamp_stale(
+    webhook_timestamp: time::PrimitiveDateTime,
+    current_time: time::PrimitiveDateTime,
+    replay_protection_window_in_secs: u32,
+) -> bool {
+    current_time - webhook_timestamp
+        > time::Duration::seconds(i64::from(replay_protection_window_in_secs))
+}
+
+/// Processes the events of a batched incoming webhook one after the other, such that a failure in
+/// one event does not stop the rest of the batch from being processed. Events repeated within the
+/// batch are processed only once, `process_event` returns `None` for events it finds were already
+/// processed.
+pub(crate) async fn process_webhook_event_batch<T, F, Fut>(
+    event_bodies: Vec<Vec<u8>>,
+    mut process_event: F,
+) -> Vec<WebhookBatchEventOutcome<T>>
+where
+    F: FnMut(Vec<u8>) -> Fut,
+    Fut: Future<Output = errors::RouterResult<Option<T>>>,
+{
+    let mut seen_event_bodies = HashSet::new();
+    let mut event_outcomes = Vec::with_capacity(event_bodies.len());
+
+    for event_body in event_bodies {
+        if !seen_event_bodies.insert(event_body.clone()) {
+            event_outcomes.push(WebhookBatchEventOutcome::Duplicate);
+            continue;
+        }
+
+        event_outcomes.push(match process_event(event_body).await {
+            Ok(Some(processed_event)) => WebhookBatchEventOutcome::Processed(processed_event),
+            Ok(None) => WebhookBatchEventOutcome::Duplicate,
+            Err(error) => WebhookBatchEventOutcome::Failed(error),
+        });
+    }
+
+    event_outcomes
+}
+
+pub(crate) fn get_webhook_event_dedup_key(
+    merchant_id: &str,
+    connector_name: &str,
+    event_body: &[u8],
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    let event_digest = crypto::Sha256
+        .generate_digest(event_body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate digest of the incoming webhook event")?;
+
+    Ok(format!(
+        "whbatch_event_{merchant_id}_{connector_name}_{}",
+        hex::encode(event_digest)
+    ))
+}
+
+/// Check whether the event of a batched incoming webhook was processed in an earlier delivery.
+/// Redis failures are logged and the event is treated as not processed.
+pub(crate) async fn is_webhook_event_processed(db: &dyn StorageInterface, dedup_key: &str) -> bool {
+    let redis_conn = match db.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return false;
+        }
+    };
+
+    redis_conn
+        .exists::<()>(dedup_key)
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(?error, "Failed to check if webhook event was processed");
+            false
+        })
+}
+
+pub(crate) async fn mark_webhook_event_processed(db: &dyn StorageInterface, dedup_key: &str) {
+    let result = match db.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(dedup_key, true, BATCHED_WEBHOOK_EVENT_DEDUP_TTL_IN_SECS)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to mark webhook event as processed");
+    }
+}
+
 pub async fn construct_webhook_router_data<'a>(
     connector_name: &str,
     merchant_connector_account: domain::MerchantConnectorAccount,
@@ -144,3 +254,124 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_fresh_webhook_is_not_stale() {
+        let current_time = common_utils::date_time::now();
+        let webhook_timestamp = current_time - time::Duration::seconds(60);
+
+        assert!(!is_webhook_timestamp_stale(
+            webhook_timestamp,
+            current_time,
+            300
+        ));
+    }
+
+    #[test]
+    fn test_replayed_webhook_is_stale() {
+        let current_time = common_utils::date_time::now();
+        let webhook_timestamp = current_time - time::Duration::hours(2);
+
+        assert!(is_webhook_timestamp_stale(
+            webhook_timestamp,
+            current_time,
+            300
+        ));
+    }
+
+    fn get_batch_event_bodies() -> Vec<Vec<u8>> {
+        vec![
+            br#"{"event":"payment_succeeded","id":"evt_1"}"#.to_vec(),
+            br#"{"event":"refund_succeeded","id":"evt_2"}"#.to_vec(),
+            br#"{"event":"payment_failed","id":"evt_3"}"#.to_vec(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_each_event_of_batch_is_processed_independently() {
+        let failing_event = br#"{"event":"refund_succeeded","id":"evt_2"}"#.to_vec();
+        let mut processed_events = Vec::new();
+
+        let event_outcomes = process_webhook_event_batch(get_batch_event_bodies(), |event_body| {
+            processed_events.push(event_body.clone());
+            let is_failing_event = event_body == failing_event;
+            async move {
+                if is_failing_event {
+                    Err(error_stack::report!(
+                        errors::ApiErrorResponse::WebhookProcessingFailure
+                    ))
+                } else {
+                    Ok(Some(event_body))
+                }
+            }
+        })
+        .await;
+
+        // The failure of the second event neither stops nor affects the third event
+        assert_eq!(processed_events, get_batch_event_bodies());
+        assert_eq!(event_outcomes.len(), 3);
+        assert!(matches!(
+            event_outcomes.first(),
+            Some(WebhookBatchEventOutcome::Processed(event_body))
+                if Some(event_body) == get_batch_event_bodies().first()
+        ));
+        assert!(matches!(
+            event_outcomes.get(1),
+            Some(WebhookBatchEventOutcome::Failed(_))
+        ));
+        assert!(matches!(
+            event_outcomes.get(2),
+            Some(WebhookBatchEventOutcome::Processed(event_body))
+                if Some(event_body) == get_batch_event_bodies().get(2)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_events_of_batch_are_skipped() {
+        let already_processed_event = br#"{"event":"payment_failed","id":"evt_3"}"#.to_vec();
+        let mut event_bodies = get_batch_event_bodies();
+        event_bodies.extend(get_batch_event_bodies().first().cloned());
+        let mut processed_events_count = 0;
+
+        let event_outcomes = process_webhook_event_batch(event_bodies, |event_body| {
+            processed_events_count += 1;
+            let is_already_processed = event_body == already_processed_event;
+            async move { Ok((!is_already_processed).then_some(event_body)) }
+        })
+        .await;
+
+        // The event repeated within the batch is not handed over for processing again
+        assert_eq!(processed_events_count, 3);
+        assert_eq!(event_outcomes.len(), 4);
+        assert!(matches!(
+            event_outcomes.get(2),
+            Some(WebhookBatchEventOutcome::Duplicate)
+        ));
+        assert!(matches!(
+            event_outcomes.get(3),
+            Some(WebhookBatchEventOutcome::Duplicate)
+        ));
+    }
+
+    #[test]
+    fn test_webhook_event_dedup_key_is_unique_per_event() {
+        let event_bodies = get_batch_event_bodies();
+        let dedup_keys = event_bodies
+            .iter()
+            .map(|event_body| {
+                get_webhook_event_dedup_key("merchant_1", "adyen", event_body).unwrap()
+            })
+            .collect::<HashSet<_>>();
+
+        assert_eq!(dedup_keys.len(), 3);
+        assert_eq!(
+            get_webhook_event_dedup_key("merchant_1", "adyen", br#"{"id":"evt_1"}"#).unwrap(),
+            get_webhook_event_dedup_key("merchant_1", "adyen", br#"{"id":"evt_1"}"#).unwrap()
+        );
+    }
+}
@@ -1,12 +1,19 @@
+use common_utils::ext_traits::Encode;
 use error_stack::ResultExt;
-use masking::PeekInterface;
+use masking::{PeekInterface, Secret};
 use router_env::{instrument, tracing};
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
     routes::AppState,
     services::ApplicationResponse,
-    types::{api, domain, storage, transformers::ForeignTryFrom},
+    types::{
+        api,
+        domain::{self, types as domain_types},
+        storage,
+        transformers::ForeignTryFrom,
+    },
     utils::{OptionExt, StringExt},
 };
 
@@ -56,6 +63,7 @@ pub async fn list_initial_delivery_attempts(
             created_before,
             limit,
             offset,
+            delivery_status,
         } => {
             let limit = match limit {
                 Some(limit) if  limit <= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Ok(Some(limit)),
@@ -79,6 +87,7 @@ pub async fn list_initial_delivery_attempts(
                     created_before,
                     limit,
                     offset,
+                    delivery_status,
                     &key_store,
                 )
                 .await,
@@ -89,6 +98,7 @@ pub async fn list_initial_delivery_attempts(
                     created_before,
                     limit,
                     offset,
+                    delivery_status,
                     &key_store,
                 )
                 .await,
@@ -166,26 +176,9 @@ pub async fn retry_delivery_attempt(
     let (account, key_store) =
         determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
 
-    let event_to_retry = store
-        .find_event_by_merchant_id_event_id(&key_store.merchant_id, &event_id, &key_store)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+    let event_to_retry = find_event_to_redeliver(store, &key_store, &event_id).await?;
 
-    let business_profile = match account {
-        MerchantAccountOrBusinessProfile::MerchantAccount(_) => {
-            let business_profile_id = event_to_retry
-                .business_profile_id
-                .get_required_value("business_profile_id")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to read business profile ID from event to retry")?;
-            store
-                .find_business_profile_by_profile_id(&business_profile_id)
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to find business profile")
-        }
-        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => Ok(business_profile),
-    }?;
+    let business_profile = get_business_profile_for_event(store, account, &event_to_retry).await?;
 
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
@@ -253,6 +246,154 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+#[instrument(skip(state))]
+pub async fn replay_webhook_event(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    event_id: String,
+) -> RouterResponse<api::webhook_events::EventRetrieveResponse> {
+    let store = state.store.as_ref();
+
+    let (account, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let event_to_replay = find_event_to_redeliver(store, &key_store, &event_id).await?;
+
+    let business_profile = get_business_profile_for_event(store, account, &event_to_replay).await?;
+
+    super::get_webhook_url_from_business_profile(&business_profile).change_context(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Webhook endpoint is not configured for the business profile of this event"
+                .to_string(),
+        },
+    )?;
+
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(&business_profile.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // We only allow replaying events with `request` populated.
+    let stored_request_content = event_to_replay
+        .request
+        .as_ref()
+        .get_required_value("request")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook event request information")?;
+
+    let request_content = super::get_resigned_outgoing_webhook_request(
+        &merchant_account,
+        stored_request_content,
+        business_profile.payment_response_hash_key.as_deref(),
+        super::get_outgoing_webhook_signature_algorithm(&business_profile),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to sign outgoing webhook request content")?;
+
+    let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
+    let new_event_id = super::utils::generate_event_id();
+    let idempotent_event_id = super::utils::get_idempotent_event_id(
+        &event_to_replay.primary_object_id,
+        event_to_replay.event_type,
+        delivery_attempt,
+    );
+
+    let now = common_utils::date_time::now();
+    let new_event = domain::Event {
+        event_id: new_event_id.clone(),
+        event_type: event_to_replay.event_type,
+        event_class: event_to_replay.event_class,
+        is_webhook_notified: false,
+        primary_object_id: event_to_replay.primary_object_id,
+        primary_object_type: event_to_replay.primary_object_type,
+        created_at: now,
+        merchant_id: Some(business_profile.merchant_id.clone()),
+        business_profile_id: Some(business_profile.profile_id.clone()),
+        primary_object_created_at: event_to_replay.primary_object_created_at,
+        idempotent_event_id: Some(idempotent_event_id),
+        initial_attempt_id: event_to_replay.initial_attempt_id,
+        request: Some(
+            domain_types::encrypt(
+                request_content
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+                    .attach_printable("Failed to encode outgoing webhook request content")
+                    .map(Secret::new)?,
+                key_store.key.get_inner().peek(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to encrypt outgoing webhook request content")?,
+        ),
+        response: None,
+        delivery_attempt: Some(delivery_attempt),
+    };
+
+    let event = store
+        .insert_event(new_event, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert event")?;
+
+    super::trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        &key_store,
+        event,
+        request_content,
+        delivery_attempt,
+        None,
+        None,
+    )
+    .await;
+
+    let updated_event = store
+        .find_event_by_merchant_id_event_id(&key_store.merchant_id, &new_event_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+async fn find_event_to_redeliver(
+    store: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    event_id: &str,
+) -> errors::RouterResult<domain::Event> {
+    store
+        .find_event_by_merchant_id_event_id(&key_store.merchant_id, event_id, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+}
+
+async fn get_business_profile_for_event(
+    store: &dyn StorageInterface,
+    account: MerchantAccountOrBusinessProfile,
+    event: &domain::Event,
+) -> errors::RouterResult<storage::BusinessProfile> {
+    match account {
+        MerchantAccountOrBusinessProfile::MerchantAccount(_) => {
+            let business_profile_id = event
+                .business_profile_id
+                .as_ref()
+                .get_required_value("business_profile_id")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to read business profile ID from event")?;
+            store
+                .find_business_profile_by_profile_id(business_profile_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find business profile")
+        }
+        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => Ok(business_profile),
+    }
+}
+
 async fn determine_identifier_and_get_key_store(
     state: AppState,
     merchant_id_or_profile_id: String,
@@ -317,3 +458,103 @@ async fn determine_identifier_and_get_key_store(
             .attach_printable("Failed to find merchant key store by merchant ID"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use diesel_models::enums;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        db::{
+            events::EventInterface, merchant_key_store::MerchantKeyStoreInterface,
+            MasterKeyInterface, MockDb,
+        },
+        services,
+    };
+
+    async fn get_mockdb_with_event(
+        merchant_id: &str,
+        event_id: &str,
+    ) -> (MockDb, domain::MerchantKeyStore) {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.into(),
+                    key: domain::types::encrypt(
+                        services::generate_aes256_key().unwrap().to_vec().into(),
+                        master_key,
+                    )
+                    .await
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(merchant_id, &master_key.to_vec().into())
+            .await
+            .unwrap();
+
+        mockdb
+            .insert_event(
+                domain::Event {
+                    event_id: event_id.into(),
+                    event_type: enums::EventType::PaymentSucceeded,
+                    event_class: enums::EventClass::Payments,
+                    is_webhook_notified: true,
+                    primary_object_id: "pay_replay".into(),
+                    primary_object_type: enums::EventObjectType::PaymentDetails,
+                    created_at: common_utils::date_time::now(),
+                    merchant_id: Some(merchant_id.to_owned()),
+                    business_profile_id: Some("profile1".to_owned()),
+                    primary_object_created_at: Some(common_utils::date_time::now()),
+                    idempotent_event_id: Some(event_id.into()),
+                    initial_attempt_id: Some(event_id.into()),
+                    request: None,
+                    response: None,
+                    delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                },
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+
+        (mockdb, merchant_key_store)
+    }
+
+    #[tokio::test]
+    async fn test_find_event_to_replay() {
+        let (mockdb, merchant_key_store) = get_mockdb_with_event("merchant1", "evt_replay").await;
+
+        let event = find_event_to_redeliver(&mockdb, &merchant_key_store, "evt_replay")
+            .await
+            .unwrap();
+
+        assert_eq!(event.event_id, "evt_replay");
+        assert_eq!(event.primary_object_id, "pay_replay");
+    }
+
+    #[tokio::test]
+    async fn test_find_missing_event_to_replay() {
+        let (mockdb, merchant_key_store) = get_mockdb_with_event("merchant1", "evt_replay").await;
+
+        let error = find_event_to_redeliver(&mockdb, &merchant_key_store, "evt_missing")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::EventNotFound
+        ));
+    }
+}
@@ -28,6 +28,7 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod recon_entry;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
@@ -102,6 +103,7 @@ pub trait StorageInterface:
     + PayoutAttemptInterface
     + PayoutsInterface
     + refund::RefundInterface
+    + recon_entry::ReconEntryInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
@@ -264,4 +266,23 @@ impl OrganizationInterface for KafkaStore {
             .update_organization_by_org_id(org_id, update)
             .await
     }
+
+    async fn list_organizations_by_parent_organization_id(
+        &self,
+        parent_organization_id: &str,
+    ) -> CustomResult<Vec<Organization>, StorageError> {
+        self.diesel_store
+            .list_organizations_by_parent_organization_id(parent_organization_id)
+            .await
+    }
+
+    async fn update_parent_organization_by_org_id(
+        &self,
+        org_id: &str,
+        parent_organization_id: Option<String>,
+    ) -> CustomResult<Option<Organization>, StorageError> {
+        self.diesel_store
+            .update_parent_organization_by_org_id(org_id, parent_organization_id)
+            .await
+    }
 }
@@ -71,6 +71,20 @@ where
         address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn find_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn delete_address_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -265,6 +279,50 @@ mod storage {
             })
             .await
         }
+
+        #[instrument(skip_all)]
+        async fn find_addresses_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|addresses| async {
+                    let mut output = Vec::with_capacity(addresses.len());
+                    for address in addresses.into_iter() {
+                        output.push(
+                            address
+                                .convert(key_store.key.get_inner())
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
+        #[instrument(skip_all)]
+        async fn delete_address_by_merchant_id_customer_id_address_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            address_id: &str,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Address::delete_by_merchant_id_customer_id_address_id(
+                &conn,
+                merchant_id,
+                customer_id,
+                address_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -598,6 +656,50 @@ mod storage {
             })
             .await
         }
+
+        #[instrument(skip_all)]
+        async fn find_addresses_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|addresses| async {
+                    let mut output = Vec::with_capacity(addresses.len());
+                    for address in addresses.into_iter() {
+                        output.push(
+                            address
+                                .convert(key_store.key.get_inner())
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
+        #[instrument(skip_all)]
+        async fn delete_address_by_merchant_id_customer_id_address_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            address_id: &str,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Address::delete_by_merchant_id_customer_id_address_id(
+                &conn,
+                merchant_id,
+                customer_id,
+                address_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -765,32 +867,93 @@ impl AddressInterface for MockDb {
         address_update: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
-        let updated_addr = self
+        let address_update = AddressUpdateInternal::from(address_update);
+        // All the addresses of the customer are updated, including the addresses of payments
+        let updated_addresses = self
             .addresses
             .lock()
             .await
             .iter_mut()
-            .find(|address| {
-                address.customer_id == Some(customer_id.to_string())
+            .filter(|address| {
+                address.customer_id.as_deref() == Some(customer_id)
                     && address.merchant_id == merchant_id
             })
             .map(|a| {
-                let address_updated =
-                    AddressUpdateInternal::from(address_update).create_address(a.clone());
+                let address_updated = address_update.clone().create_address(a.clone());
                 *a = address_updated.clone();
                 address_updated
-            });
-        match updated_addr {
-            Some(address) => {
-                let address: domain::Address = address
+            })
+            .collect::<Vec<_>>();
+        if updated_addresses.is_empty() {
+            return Err(
+                errors::StorageError::ValueNotFound("address not found".to_string()).into(),
+            );
+        }
+
+        let mut output = Vec::with_capacity(updated_addresses.len());
+        for address in updated_addresses {
+            output.push(
+                address
                     .convert(key_store.key.get_inner())
                     .await
-                    .change_context(errors::StorageError::DecryptionError)?;
-                Ok(vec![address])
-            }
-            None => {
-                Err(errors::StorageError::ValueNotFound("address not found".to_string()).into())
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(output)
+    }
+
+    async fn find_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.customer_id.as_deref() == Some(customer_id)
+                    && address.merchant_id == merchant_id
+                    && address.payment_id.is_none()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            output.push(
+                address
+                    .convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(output)
+    }
+
+    async fn delete_address_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut addresses = self.addresses.lock().await;
+        match addresses.iter().position(|address| {
+            address.address_id == address_id
+                && address.customer_id.as_deref() == Some(customer_id)
+                && address.merchant_id == merchant_id
+                && address.payment_id.is_none()
+        }) {
+            Some(index) => {
+                addresses.remove(index);
+                Ok(true)
             }
+            None => Err(errors::StorageError::ValueNotFound(
+                "cannot find address to delete".to_string(),
+            )
+            .into()),
         }
     }
 }
@@ -233,6 +233,7 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            permissions: api_key.permissions,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -405,6 +406,7 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                permissions: None,
             })
             .await
             .unwrap();
@@ -420,6 +422,7 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                permissions: None,
             })
             .await
             .unwrap();
@@ -497,6 +500,7 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            permissions: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
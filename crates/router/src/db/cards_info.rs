@@ -1,4 +1,4 @@
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use crate::{
@@ -6,7 +6,7 @@ use crate::{
     core::errors::{self, CustomResult},
     db::MockDb,
     services::Store,
-    types::storage::cards_info::CardInfo,
+    types::storage::cards_info::{CardInfo, CardInfoUpdateInternal},
 };
 
 #[async_trait::async_trait]
@@ -15,6 +15,14 @@ pub trait CardsInfoInterface {
         &self,
         _card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError>;
+
+    /// Inserts the card information of the bins that are not stored yet and updates the fields
+    /// that are set for the rest, in a single statement. Returns the number of inserted and
+    /// updated bins.
+    async fn bulk_upsert_card_info(
+        &self,
+        card_info_list: Vec<CardInfo>,
+    ) -> CustomResult<(usize, usize), errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -29,6 +37,24 @@ impl CardsInfoInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn bulk_upsert_card_info(
+        &self,
+        card_info_list: Vec<CardInfo>,
+    ) -> CustomResult<(usize, usize), errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        let (inserted, updated): (Vec<_>, Vec<_>) = CardInfo::bulk_upsert(&conn, card_info_list)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .into_iter()
+            .partition(|is_inserted| *is_inserted);
+
+        Ok((inserted.len(), updated.len()))
+    }
 }
 
 #[async_trait::async_trait]
@@ -46,4 +72,31 @@ impl CardsInfoInterface for MockDb {
             .find(|ci| ci.card_iin == card_iin)
             .cloned())
     }
+
+    #[instrument(skip_all)]
+    async fn bulk_upsert_card_info(
+        &self,
+        card_info_list: Vec<CardInfo>,
+    ) -> CustomResult<(usize, usize), errors::StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        let mut inserted = 0;
+        let mut updated = 0;
+        for card_info in card_info_list {
+            match cards_info
+                .iter_mut()
+                .find(|ci| ci.card_iin == card_info.card_iin)
+            {
+                Some(existing_card_info) => {
+                    *existing_card_info = CardInfoUpdateInternal::from(card_info)
+                        .apply_changeset(existing_card_info.clone());
+                    updated += 1;
+                }
+                None => {
+                    cards_info.push(card_info);
+                    inserted += 1;
+                }
+            }
+        }
+        Ok((inserted, updated))
+    }
 }
@@ -172,6 +172,7 @@ impl DisputeInterface for MockDb {
             evidence,
             merchant_connector_id: dispute.merchant_connector_id,
             dispute_amount: dispute.dispute_amount,
+            auto_accepted: None,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -353,6 +354,9 @@ impl DisputeInterface for MockDb {
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::AutoAcceptUpdate { auto_accepted } => {
+                dispute_to_update.auto_accepted = Some(auto_accepted);
+            }
         }
 
         dispute_to_update.modified_at = now;
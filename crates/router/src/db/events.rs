@@ -48,6 +48,7 @@ where
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -72,6 +73,7 @@ where
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -166,6 +168,7 @@ impl EventInterface for Store {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -176,6 +179,7 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            delivery_status,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -262,6 +266,7 @@ impl EventInterface for Store {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -272,6 +277,7 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            delivery_status,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -421,6 +427,7 @@ impl EventInterface for MockDb {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -436,6 +443,10 @@ impl EventInterface for MockDb {
                 check = check && (event.created_at <= created_before);
             }
 
+            if let Some(delivery_status) = delivery_status {
+                check = check && event_matches_delivery_status(event, delivery_status);
+            }
+
             check
         });
 
@@ -544,6 +555,7 @@ impl EventInterface for MockDb {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -559,6 +571,10 @@ impl EventInterface for MockDb {
                 check = check && (event.created_at <= created_before);
             }
 
+            if let Some(delivery_status) = delivery_status {
+                check = check && event_matches_delivery_status(event, delivery_status);
+            }
+
             check
         });
 
@@ -663,9 +679,28 @@ impl EventInterface for MockDb {
     }
 }
 
+/// An event is considered to have failed delivery if a response (or an error) was recorded for it
+/// without the merchant server acknowledging the webhook, and pending if no delivery has been
+/// attempted yet.
+fn event_matches_delivery_status(
+    event: &storage::events::Event,
+    delivery_status: storage::enums::WebhookDeliveryStatus,
+) -> bool {
+    match delivery_status {
+        storage::enums::WebhookDeliveryStatus::Delivered => event.is_webhook_notified,
+        storage::enums::WebhookDeliveryStatus::Failed => {
+            !event.is_webhook_notified && event.response.is_some()
+        }
+        storage::enums::WebhookDeliveryStatus::Pending => {
+            !event.is_webhook_notified && event.response.is_none()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use diesel_models::enums;
+    use masking::{PeekInterface, Secret};
     use time::macros::datetime;
 
     use crate::{
@@ -753,4 +788,145 @@ mod tests {
         assert_eq!(updated_event.primary_object_id, "primary_object_tet");
         assert_eq!(updated_event.event_id, event_id);
     }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_list_initial_events_by_delivery_status() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let merchant_id = "merchant1";
+        let business_profile_id = "profile1";
+
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.into(),
+                    key: domain::types::encrypt(
+                        services::generate_aes256_key().unwrap().to_vec().into(),
+                        master_key,
+                    )
+                    .await
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(merchant_id, &master_key.to_vec().into())
+            .await
+            .unwrap();
+
+        // (event_id, is_webhook_notified, has_response)
+        let events = [
+            ("delivered_event_1", true, true),
+            ("delivered_event_2", true, true),
+            ("failed_event_1", false, true),
+            ("failed_event_2", false, true),
+            ("pending_event_1", false, false),
+        ];
+        for (event_id, is_webhook_notified, has_response) in events {
+            let response = if has_response {
+                Some(
+                    domain::types::encrypt(
+                        Secret::new(r#"{"status_code":500}"#.to_string()),
+                        merchant_key_store.key.get_inner().peek(),
+                    )
+                    .await
+                    .unwrap(),
+                )
+            } else {
+                None
+            };
+
+            mockdb
+                .insert_event(
+                    domain::Event {
+                        event_id: event_id.into(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified,
+                        primary_object_id: "primary_object_test".into(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id: Some(business_profile_id.to_owned()),
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id.into()),
+                        initial_attempt_id: Some(event_id.into()),
+                        request: None,
+                        response,
+                        delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+
+        let list_event_ids = |delivery_status: Option<enums::WebhookDeliveryStatus>,
+                              limit: Option<i64>,
+                              offset: Option<i64>| {
+            let mockdb = &mockdb;
+            let merchant_key_store = &merchant_key_store;
+            async move {
+                mockdb
+                    .list_initial_events_by_merchant_id_constraints(
+                        merchant_id,
+                        None,
+                        None,
+                        limit,
+                        offset,
+                        delivery_status,
+                        merchant_key_store,
+                    )
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|event| event.event_id)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(
+            list_event_ids(Some(enums::WebhookDeliveryStatus::Delivered), None, None).await,
+            vec!["delivered_event_1", "delivered_event_2"]
+        );
+        assert_eq!(
+            list_event_ids(Some(enums::WebhookDeliveryStatus::Failed), None, None).await,
+            vec!["failed_event_1", "failed_event_2"]
+        );
+        assert_eq!(
+            list_event_ids(Some(enums::WebhookDeliveryStatus::Pending), None, None).await,
+            vec!["pending_event_1"]
+        );
+        assert_eq!(list_event_ids(None, None, None).await.len(), 5);
+
+        // Pagination is applied after filtering by delivery status
+        assert_eq!(
+            list_event_ids(Some(enums::WebhookDeliveryStatus::Failed), Some(1), Some(1)).await,
+            vec!["failed_event_2"]
+        );
+
+        let profile_events = mockdb
+            .list_initial_events_by_profile_id_constraints(
+                business_profile_id,
+                None,
+                None,
+                None,
+                None,
+                Some(enums::WebhookDeliveryStatus::Failed),
+                &merchant_key_store,
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|event| event.event_id)
+            .collect::<Vec<_>>();
+        assert_eq!(profile_events, vec!["failed_event_1", "failed_event_2"]);
+    }
 }
@@ -31,6 +31,14 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError>;
+    async fn find_gsm_rules_by_messages(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        messages: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -96,6 +104,23 @@ impl GsmInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn find_gsm_rules_by_messages(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        messages: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_all_by_messages(
+            &conn, connector, flow, sub_flow, code, messages,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_gsm_rule(
         &self,
@@ -132,31 +157,106 @@ impl GsmInterface for Store {
 impl GsmInterface for MockDb {
     async fn add_gsm_rule(
         &self,
-        _rule: storage::GatewayStatusMappingNew,
+        rule: storage::GatewayStatusMappingNew,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut gsm_rules = self.gsm_rules.lock().await;
+
+        if gsm_rules.iter().any(|gsm_rule| {
+            gsm_rule.connector == rule.connector
+                && gsm_rule.flow == rule.flow
+                && gsm_rule.sub_flow == rule.sub_flow
+                && gsm_rule.code == rule.code
+                && gsm_rule.message == rule.message
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "gsm rule",
+                key: None,
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let gsm_rule = storage::GatewayStatusMap {
+            connector: rule.connector,
+            flow: rule.flow,
+            sub_flow: rule.sub_flow,
+            code: rule.code,
+            message: rule.message,
+            status: rule.status,
+            router_error: rule.router_error,
+            decision: rule.decision,
+            created_at: now,
+            last_modified: now,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+            priority: rule.priority,
+        };
+        gsm_rules.push(gsm_rule.clone());
+
+        Ok(gsm_rule)
     }
 
     async fn find_gsm_decision(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<String, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.find_gsm_rule(connector, flow, sub_flow, code, message)
+            .await
+            .map(|gsm_rule| gsm_rule.decision)
     }
 
     async fn find_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.gsm_rules
+            .lock()
+            .await
+            .iter()
+            .find(|gsm_rule| {
+                gsm_rule.connector == connector
+                    && gsm_rule.flow == flow
+                    && gsm_rule.sub_flow == sub_flow
+                    && gsm_rule.code == code
+                    && gsm_rule.message == message
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound("cannot find gsm rule".to_string()).into())
+    }
+
+    async fn find_gsm_rules_by_messages(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        messages: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let mut gsm_rules: Vec<storage::GatewayStatusMap> = self
+            .gsm_rules
+            .lock()
+            .await
+            .iter()
+            .filter(|gsm_rule| {
+                gsm_rule.connector == connector
+                    && gsm_rule.flow == flow
+                    && gsm_rule.sub_flow == sub_flow
+                    && gsm_rule.code == code
+                    && messages.contains(&gsm_rule.message)
+            })
+            .cloned()
+            .collect();
+        gsm_rules.sort_by(|a, b| b.priority.cmp(&a.priority));
+
+        Ok(gsm_rules)
     }
 
     async fn update_gsm_rule(
@@ -184,6 +184,32 @@ impl AddressInterface for KafkaStore {
             .update_address_by_merchant_id_customer_id(customer_id, merchant_id, address, key_store)
             .await
     }
+
+    async fn find_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        self.diesel_store
+            .find_addresses_by_merchant_id_customer_id(merchant_id, customer_id, key_store)
+            .await
+    }
+
+    async fn delete_address_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_address_by_merchant_id_customer_id_address_id(
+                merchant_id,
+                customer_id,
+                address_id,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -253,6 +279,15 @@ impl CardsInfoInterface for KafkaStore {
     ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
         self.diesel_store.get_card_info(card_iin).await
     }
+
+    async fn bulk_upsert_card_info(
+        &self,
+        card_info_list: Vec<storage::CardInfo>,
+    ) -> CustomResult<(usize, usize), errors::StorageError> {
+        self.diesel_store
+            .bulk_upsert_card_info(card_info_list)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -554,6 +589,7 @@ impl EventInterface for KafkaStore {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -563,6 +599,7 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                delivery_status,
                 merchant_key_store,
             )
             .await
@@ -605,6 +642,7 @@ impl EventInterface for KafkaStore {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -614,6 +652,7 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                delivery_status,
                 merchant_key_store,
             )
             .await
@@ -1519,6 +1558,15 @@ impl PaymentMethodInterface for KafkaStore {
             .delete_payment_method_by_merchant_id_payment_method_id(merchant_id, payment_method_id)
             .await
     }
+
+    async fn update_payment_methods_display_order(
+        &self,
+        payment_methods: Vec<(storage::PaymentMethod, i32)>,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .update_payment_methods_display_order(payment_methods)
+            .await
+    }
 }
 
 #[cfg(not(feature = "payouts"))]
@@ -1875,6 +1923,21 @@ impl RefundInterface for KafkaStore {
             .await
     }
 
+    async fn find_refund_by_merchant_id_merchant_refund_reference_id(
+        &self,
+        merchant_id: &str,
+        merchant_refund_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::Refund, errors::StorageError> {
+        self.diesel_store
+            .find_refund_by_merchant_id_merchant_refund_reference_id(
+                merchant_id,
+                merchant_refund_reference_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn find_refund_by_merchant_id_connector_refund_id_connector(
         &self,
         merchant_id: &str,
@@ -2224,6 +2287,19 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn find_gsm_rules_by_messages(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        messages: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_rules_by_messages(connector, flow, sub_flow, code, messages)
+            .await
+    }
+
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -2742,6 +2818,15 @@ impl RoleInterface for KafkaStore {
         self.diesel_store.delete_role_by_role_id(role_id).await
     }
 
+    async fn delete_unassigned_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        self.diesel_store
+            .delete_unassigned_role_by_role_id(role_id)
+            .await
+    }
+
     async fn list_all_roles(
         &self,
         merchant_id: &str,
@@ -540,6 +540,13 @@ impl MandateInterface for MockDb {
                         mandate.payment_method_id = payment_method_id;
                         mandate.original_payment_id = original_payment_id
                     }
+                    diesel_models::MandateUpdate::DebitUsageUpdate {
+                        debit_count,
+                        debited_amount,
+                    } => {
+                        mandate.debit_count = debit_count;
+                        mandate.debited_amount = debited_amount;
+                    }
                 }
                 Ok(mandate.clone())
             }
@@ -634,6 +641,10 @@ impl MandateInterface for MockDb {
             metadata: mandate_new.metadata,
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
+            max_total_amount: mandate_new.max_total_amount,
+            max_debit_count: mandate_new.max_debit_count,
+            debit_count: mandate_new.debit_count.unwrap_or(0),
+            debited_amount: mandate_new.debited_amount.unwrap_or(0),
         };
         mandates.push(mandate.clone());
         Ok(mandate)
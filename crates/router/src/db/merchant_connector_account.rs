@@ -648,6 +648,9 @@ impl MerchantConnectorAccountInterface for MockDb {
             applepay_verified_domains: t.applepay_verified_domains,
             pm_auth_config: t.pm_auth_config,
             status: t.status,
+            connector_wallets_details: t.connector_wallets_details.map(Into::into),
+            applepay_verified_at: t.applepay_verified_at,
+            applepay_verification_expires_at: t.applepay_verification_expires_at,
         };
         accounts.push(account.clone());
         account
@@ -846,6 +849,9 @@ mod merchant_connector_account_cache_tests {
             applepay_verified_domains: None,
             pm_auth_config: None,
             status: common_enums::ConnectorStatus::Inactive,
+            connector_wallets_details: None,
+            applepay_verified_at: None,
+            applepay_verification_expires_at: None,
         };
 
         db.insert_merchant_connector_account(mca.clone(), &merchant_key)
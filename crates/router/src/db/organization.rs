@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+
+use async_bb8_diesel::AsyncConnection;
 use common_utils::errors::CustomResult;
 use diesel_models::organization as storage;
 use error_stack::report;
@@ -22,6 +25,46 @@ pub trait OrganizationInterface {
         user_id: &str,
         update: storage::OrganizationUpdate,
     ) -> CustomResult<storage::Organization, errors::StorageError>;
+
+    async fn list_organizations_by_parent_organization_id(
+        &self,
+        parent_organization_id: &str,
+    ) -> CustomResult<Vec<storage::Organization>, errors::StorageError>;
+
+    /// Sets the parent of an organization, checking in the same transaction that the new parent
+    /// is neither the organization itself nor one of its descendants. Returns `None` without
+    /// updating the organization if the new parent would create a cycle.
+    async fn update_parent_organization_by_org_id(
+        &self,
+        org_id: &str,
+        parent_organization_id: Option<String>,
+    ) -> CustomResult<Option<storage::Organization>, errors::StorageError>;
+}
+
+/// Whether the organization is reached by walking up the tree from `parent_organization_id`
+async fn is_ancestor_of<F, Fut, E>(
+    org_id: &str,
+    parent_organization_id: &str,
+    find_parent_organization_id: F,
+) -> Result<bool, E>
+where
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Result<Option<String>, E>>,
+{
+    let mut visited = HashSet::new();
+    let mut current = Some(parent_organization_id.to_string());
+
+    while let Some(organization_id) = current {
+        if organization_id == org_id {
+            return Ok(true);
+        }
+        if !visited.insert(organization_id.clone()) {
+            break;
+        }
+        current = find_parent_organization_id(organization_id).await?;
+    }
+
+    Ok(false)
 }
 
 #[async_trait::async_trait]
@@ -61,6 +104,63 @@ impl OrganizationInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_organizations_by_parent_organization_id(
+        &self,
+        parent_organization_id: &str,
+    ) -> CustomResult<Vec<storage::Organization>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Organization::find_by_parent_organization_id(&conn, parent_organization_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_parent_organization_by_org_id(
+        &self,
+        org_id: &str,
+        parent_organization_id: Option<String>,
+    ) -> CustomResult<Option<storage::Organization>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        conn.transaction_async(|conn| async move {
+            storage::Organization::lock_hierarchy(&conn)
+                .await
+                .map_err(|error| *error.current_context())?;
+
+            if let Some(parent_organization_id) = parent_organization_id.as_deref() {
+                let conn = &conn;
+                let creates_cycle = is_ancestor_of(
+                    org_id,
+                    parent_organization_id,
+                    move |organization_id| async move {
+                        storage::Organization::find_by_org_id(conn, organization_id)
+                            .await
+                            .map(|organization| organization.parent_organization_id)
+                            .map_err(|error| *error.current_context())
+                    },
+                )
+                .await?;
+                if creates_cycle {
+                    return Ok(None);
+                }
+            }
+
+            storage::Organization::update_by_org_id(
+                &conn,
+                org_id.to_string(),
+                storage::OrganizationUpdate::ParentUpdate {
+                    parent_organization_id,
+                },
+            )
+            .await
+            .map(Some)
+            .map_err(|error| *error.current_context())
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
 }
 
 #[async_trait::async_trait]
@@ -83,6 +183,7 @@ impl OrganizationInterface for super::MockDb {
         let org = storage::Organization {
             org_id: organization.org_id.clone(),
             org_name: organization.org_name,
+            parent_organization_id: organization.parent_organization_id,
         };
         organizations.push(org.clone());
         Ok(org)
@@ -116,11 +217,76 @@ impl OrganizationInterface for super::MockDb {
         organizations
             .iter_mut()
             .find(|org| org.org_id == org_id)
-            .map(|org| match &update {
-                storage::OrganizationUpdate::Update { org_name } => storage::Organization {
-                    org_name: org_name.clone(),
-                    ..org.to_owned()
+            .map(|org| {
+                *org = match &update {
+                    storage::OrganizationUpdate::Update { org_name } => storage::Organization {
+                        org_name: org_name.clone(),
+                        ..org.to_owned()
+                    },
+                    storage::OrganizationUpdate::ParentUpdate {
+                        parent_organization_id,
+                    } => storage::Organization {
+                        parent_organization_id: parent_organization_id.clone(),
+                        ..org.to_owned()
+                    },
+                };
+                org.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No organization available for org_id = {org_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_organizations_by_parent_organization_id(
+        &self,
+        parent_organization_id: &str,
+    ) -> CustomResult<Vec<storage::Organization>, errors::StorageError> {
+        let organizations = self.organizations.lock().await;
+
+        Ok(organizations
+            .iter()
+            .filter(|org| org.parent_organization_id.as_deref() == Some(parent_organization_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_parent_organization_by_org_id(
+        &self,
+        org_id: &str,
+        parent_organization_id: Option<String>,
+    ) -> CustomResult<Option<storage::Organization>, errors::StorageError> {
+        let mut organizations = self.organizations.lock().await;
+
+        if let Some(parent_organization_id) = parent_organization_id.as_deref() {
+            let organization_list = organizations.as_slice();
+            let creates_cycle = is_ancestor_of(
+                org_id,
+                parent_organization_id,
+                move |organization_id| async move {
+                    organization_list
+                        .iter()
+                        .find(|org| org.org_id == organization_id)
+                        .map(|org| org.parent_organization_id.clone())
+                        .ok_or(report!(errors::StorageError::ValueNotFound(format!(
+                            "No organization available for org_id = {organization_id}"
+                        ))))
                 },
+            )
+            .await?;
+            if creates_cycle {
+                return Ok(None);
+            }
+        }
+
+        organizations
+            .iter_mut()
+            .find(|org| org.org_id == org_id)
+            .map(|org| {
+                org.parent_organization_id = parent_organization_id;
+                Some(org.to_owned())
             })
             .ok_or(
                 errors::StorageError::ValueNotFound(format!(
@@ -62,10 +62,17 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
         payment_method_id: &str,
     ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
+
+    /// Updates the display order of all the payment methods in a single transaction
+    async fn update_payment_methods_display_order(
+        &self,
+        payment_methods: Vec<(storage_types::PaymentMethod, i32)>,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
 mod storage {
+    use async_bb8_diesel::AsyncConnection;
     use common_utils::fallback_reverse_lookup_not_found;
     use diesel_models::{kv, PaymentMethodUpdateInternal};
     use error_stack::{report, ResultExt};
@@ -422,11 +429,39 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn update_payment_methods_display_order(
+            &self,
+            payment_methods: Vec<(storage_types::PaymentMethod, i32)>,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            conn.transaction_async(|conn| async move {
+                let mut updated_payment_methods = Vec::with_capacity(payment_methods.len());
+                for (payment_method, display_order) in payment_methods {
+                    let payment_method_update =
+                        storage_types::PaymentMethodUpdate::DisplayOrderUpdate {
+                            display_order: Some(display_order),
+                        };
+                    updated_payment_methods.push(
+                        payment_method
+                            .update_with_payment_method_id(&conn, payment_method_update.into())
+                            .await
+                            .map_err(|error| *error.current_context())?,
+                    );
+                }
+
+                Ok::<_, diesel_models::errors::DatabaseError>(updated_payment_methods)
+            })
+            .await
+            .map_err(|error| report!(errors::StorageError::from(report!(error))))
+        }
     }
 }
 
 #[cfg(not(feature = "kv_store"))]
 mod storage {
+    use async_bb8_diesel::AsyncConnection;
     use error_stack::report;
     use router_env::{instrument, tracing};
 
@@ -561,6 +596,33 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn update_payment_methods_display_order(
+            &self,
+            payment_methods: Vec<(storage_types::PaymentMethod, i32)>,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            conn.transaction_async(|conn| async move {
+                let mut updated_payment_methods = Vec::with_capacity(payment_methods.len());
+                for (payment_method, display_order) in payment_methods {
+                    let payment_method_update =
+                        storage_types::PaymentMethodUpdate::DisplayOrderUpdate {
+                            display_order: Some(display_order),
+                        };
+                    updated_payment_methods.push(
+                        payment_method
+                            .update_with_payment_method_id(&conn, payment_method_update.into())
+                            .await
+                            .map_err(|error| *error.current_context())?,
+                    );
+                }
+
+                Ok::<_, diesel_models::errors::DatabaseError>(updated_payment_methods)
+            })
+            .await
+            .map_err(|error| report!(errors::StorageError::from(report!(error))))
+        }
     }
 }
 
@@ -663,6 +725,7 @@ impl PaymentMethodInterface for MockDb {
             client_secret: payment_method_new.client_secret,
             network_transaction_id: payment_method_new.network_transaction_id,
             payment_method_billing_address: payment_method_new.payment_method_billing_address,
+            display_order: payment_method_new.display_order,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -740,6 +803,40 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn update_payment_methods_display_order(
+        &self,
+        payment_methods: Vec<(storage_types::PaymentMethod, i32)>,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let mut stored_payment_methods = self.payment_methods.lock().await;
+        let positions = payment_methods
+            .iter()
+            .map(|(payment_method, _)| {
+                stored_payment_methods
+                    .iter()
+                    .position(|pm| pm.id == payment_method.id)
+                    .ok_or(errors::StorageError::ValueNotFound(
+                        "cannot find payment method to update".to_string(),
+                    ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut updated_payment_methods = Vec::with_capacity(positions.len());
+        for (position, (_, display_order)) in positions.into_iter().zip(payment_methods) {
+            if let Some(pm) = stored_payment_methods.get_mut(position) {
+                let payment_method_updated = PaymentMethodUpdateInternal::from(
+                    storage_types::PaymentMethodUpdate::DisplayOrderUpdate {
+                        display_order: Some(display_order),
+                    },
+                )
+                .create_payment_method(pm.clone());
+                *pm = payment_method_updated.clone();
+                updated_payment_methods.push(payment_method_updated);
+            }
+        }
+
+        Ok(updated_payment_methods)
+    }
+
     async fn update_payment_method(
         &self,
         payment_method: storage_types::PaymentMethod,
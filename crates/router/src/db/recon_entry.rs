@@ -0,0 +1,123 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReconEntryInterface {
+    async fn upsert_recon_entry(
+        &self,
+        recon_entry: storage::ReconEntryNew,
+    ) -> CustomResult<storage::ReconEntry, errors::StorageError>;
+
+    async fn find_recon_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::ReconEntry>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReconEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn upsert_recon_entry(
+        &self,
+        recon_entry: storage::ReconEntryNew,
+    ) -> CustomResult<storage::ReconEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        recon_entry
+            .upsert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_recon_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::ReconEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconEntry::filter_by_merchant_id_entry_ids(&conn, merchant_id, entry_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconEntryInterface for MockDb {
+    async fn upsert_recon_entry(
+        &self,
+        recon_entry: storage::ReconEntryNew,
+    ) -> CustomResult<storage::ReconEntry, errors::StorageError> {
+        let mut recon_entries = self.recon_entries.lock().await;
+        if let Some(entry) = recon_entries.iter_mut().find(|entry| {
+            entry.merchant_id == recon_entry.merchant_id
+                && entry.connector == recon_entry.connector
+                && entry.entry_id == recon_entry.entry_id
+        }) {
+            *entry = storage::ReconEntryUpdateInternal::from(&recon_entry)
+                .apply_changeset(entry.clone());
+            return Ok(entry.clone());
+        }
+
+        let recon_entry = storage::ReconEntry {
+            id: i32::try_from(recon_entries.len())
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: recon_entry.merchant_id,
+            connector: recon_entry.connector,
+            entry_id: recon_entry.entry_id,
+            status: recon_entry.status,
+            note: recon_entry.note,
+            resolved_by: recon_entry.resolved_by,
+            recorded_amount: recon_entry.recorded_amount,
+            settled_amount: recon_entry.settled_amount,
+            created_at: recon_entry.created_at,
+            modified_at: recon_entry.modified_at,
+        };
+        recon_entries.push(recon_entry.clone());
+        Ok(recon_entry)
+    }
+
+    async fn find_recon_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::ReconEntry>, errors::StorageError> {
+        let recon_entries = self.recon_entries.lock().await;
+        Ok(recon_entries
+            .iter()
+            .filter(|entry| entry.merchant_id == merchant_id && entry_ids.contains(&entry.entry_id))
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconEntryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn upsert_recon_entry(
+        &self,
+        recon_entry: storage::ReconEntryNew,
+    ) -> CustomResult<storage::ReconEntry, errors::StorageError> {
+        self.diesel_store.upsert_recon_entry(recon_entry).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_recon_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::ReconEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_recon_entries_by_merchant_id_entry_ids(merchant_id, entry_ids)
+            .await
+    }
+}
@@ -36,6 +36,13 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage_types::Refund, errors::StorageError>;
 
+    async fn find_refund_by_merchant_id_merchant_refund_reference_id(
+        &self,
+        merchant_id: &str,
+        merchant_refund_reference_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Refund, errors::StorageError>;
+
     async fn find_refund_by_merchant_id_connector_refund_id_connector(
         &self,
         merchant_id: &str,
@@ -178,6 +185,23 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_refund_by_merchant_id_merchant_refund_reference_id(
+            &self,
+            merchant_id: &str,
+            merchant_refund_reference_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_merchant_refund_reference_id(
+                &conn,
+                merchant_id,
+                merchant_refund_reference_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_refund_by_merchant_id_connector_refund_id_connector(
             &self,
@@ -386,6 +410,7 @@ mod storage {
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
+                        merchant_refund_reference_id: new.merchant_refund_reference_id.clone(),
                     };
 
                     let field = format!(
@@ -437,6 +462,59 @@ mod storage {
                             updated_by: storage_scheme.to_string(),
                         })
                     };
+                    // The lookup of the merchant refund reference id is set only if it does not
+                    // exist, so it is claimed first to keep the reference unique across refunds
+                    if let Some(merchant_refund_reference_id) =
+                        created_refund.merchant_refund_reference_id.as_ref()
+                    {
+                        let lookup_id = format!(
+                            "ref_merchant_ref_{}_{}",
+                            created_refund.merchant_id, merchant_refund_reference_id
+                        );
+                        match self
+                            .insert_reverse_lookup(
+                                storage_types::ReverseLookupNew {
+                                    sk_id: field.clone(),
+                                    lookup_id: lookup_id.clone(),
+                                    pk_id: key_str.clone(),
+                                    source: "refund".to_string(),
+                                    updated_by: storage_scheme.to_string(),
+                                },
+                                storage_scheme,
+                            )
+                            .await
+                        {
+                            Ok(_) => (),
+                            Err(error)
+                                if matches!(
+                                    error.current_context(),
+                                    errors::StorageError::DuplicateValue { .. }
+                                ) =>
+                            {
+                                // A retry of the same refund finds the reference claimed by
+                                // itself, in which case the stored refund is returned
+                                let lookup = self
+                                    .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                                    .await?;
+                                if lookup.pk_id == key_str && lookup.sk_id == field {
+                                    return self
+                                        .find_refund_by_merchant_id_refund_id(
+                                            &created_refund.merchant_id,
+                                            &created_refund.refund_id,
+                                            storage_scheme,
+                                        )
+                                        .await;
+                                }
+                                return Err(error.change_context(
+                                    errors::StorageError::DuplicateValue {
+                                        entity: "merchant_refund_reference_id",
+                                        key: Some(merchant_refund_reference_id.clone()),
+                                    },
+                                ));
+                            }
+                            Err(error) => return Err(error),
+                        }
+                    };
                     let rev_look = reverse_lookups
                         .into_iter()
                         .map(|rev| self.insert_reverse_lookup(rev, storage_scheme));
@@ -618,6 +696,54 @@ mod storage {
             }
         }
 
+        #[instrument(skip_all)]
+        async fn find_refund_by_merchant_id_merchant_refund_reference_id(
+            &self,
+            merchant_id: &str,
+            merchant_refund_reference_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Refund::find_by_merchant_id_merchant_refund_reference_id(
+                    &conn,
+                    merchant_id,
+                    merchant_refund_reference_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id =
+                        format!("ref_merchant_ref_{merchant_id}_{merchant_refund_reference_id}");
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = PartitionKey::CombinationKey {
+                        combination: &lookup.pk_id,
+                    };
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Refund>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
         #[instrument(skip_all)]
         async fn find_refund_by_merchant_id_connector_refund_id_connector(
             &self,
@@ -794,6 +920,24 @@ impl RefundInterface for MockDb {
         let mut refunds = self.refunds.lock().await;
         let current_time = common_utils::date_time::now();
 
+        if let Some(merchant_refund_reference_id) = new.merchant_refund_reference_id.as_ref() {
+            if let Some(existing_refund) = refunds.iter().find(|refund| {
+                refund.merchant_id == new.merchant_id
+                    && refund.merchant_refund_reference_id.as_ref()
+                        == Some(merchant_refund_reference_id)
+            }) {
+                // A retry of the same refund is not a reuse of the reference
+                if existing_refund.refund_id == new.refund_id {
+                    return Ok(existing_refund.clone());
+                }
+                return Err(errors::StorageError::DuplicateValue {
+                    entity: "merchant_refund_reference_id",
+                    key: Some(merchant_refund_reference_id.clone()),
+                }
+                .into());
+            }
+        }
+
         let refund = storage_types::Refund {
             id: i32::try_from(refunds.len()).change_context(errors::StorageError::MockDbError)?,
             internal_reference_id: new.internal_reference_id,
@@ -822,6 +966,7 @@ impl RefundInterface for MockDb {
             profile_id: new.profile_id,
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
+            merchant_refund_reference_id: new.merchant_refund_reference_id,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -883,6 +1028,27 @@ impl RefundInterface for MockDb {
             })
     }
 
+    async fn find_refund_by_merchant_id_merchant_refund_reference_id(
+        &self,
+        merchant_id: &str,
+        merchant_refund_reference_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        refunds
+            .iter()
+            .find(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund.merchant_refund_reference_id.as_deref()
+                        == Some(merchant_refund_reference_id)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
     async fn find_refund_by_merchant_id_connector_refund_id_connector(
         &self,
         merchant_id: &str,
@@ -1173,3 +1339,94 @@ impl RefundInterface for MockDb {
         Ok(filtered_refunds_count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_refund_interface {
+        use redis_interface::RedisSettings;
+
+        use crate::{
+            db::{refund::RefundInterface, MockDb},
+            types::storage::{self as storage_types, enums},
+        };
+
+        fn create_refund_new(
+            refund_id: &str,
+            merchant_refund_reference_id: &str,
+        ) -> storage_types::RefundNew {
+            storage_types::RefundNew {
+                refund_id: refund_id.into(),
+                payment_id: "payment_1".into(),
+                merchant_id: "merchant_1".into(),
+                internal_reference_id: format!("refid_{refund_id}"),
+                attempt_id: "payment_1_1".into(),
+                connector_transaction_id: "txn_1".into(),
+                connector: "stripe".into(),
+                total_amount: 1000,
+                refund_amount: 100,
+                merchant_refund_reference_id: Some(merchant_refund_reference_id.into()),
+                ..Default::default()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_insert_refund_retry_with_same_merchant_refund_reference_id() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create a mock DB");
+
+            let created_refund = mockdb
+                .insert_refund(
+                    create_refund_new("refund_1", "reference_1"),
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+
+            let retried_refund = mockdb
+                .insert_refund(
+                    create_refund_new("refund_1", "reference_1"),
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(created_refund, retried_refund);
+            assert_eq!(mockdb.refunds.lock().await.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn test_insert_refund_reusing_merchant_refund_reference_id() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create a mock DB");
+
+            mockdb
+                .insert_refund(
+                    create_refund_new("refund_1", "reference_1"),
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+
+            let error = mockdb
+                .insert_refund(
+                    create_refund_new("refund_2", "reference_1"),
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                crate::core::errors::StorageError::DuplicateValue {
+                    entity: "merchant_refund_reference_id",
+                    ..
+                }
+            ));
+        }
+    }
+}
@@ -40,6 +40,13 @@ pub trait RoleInterface {
         role_id: &str,
     ) -> CustomResult<storage::Role, errors::StorageError>;
 
+    /// Deletes the role only when it is not assigned to any user, fails with a not found error
+    /// otherwise
+    async fn delete_unassigned_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError>;
+
     async fn list_all_roles(
         &self,
         merchant_id: &str,
@@ -107,6 +114,17 @@ impl RoleInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn delete_unassigned_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Role::delete_unassigned_by_role_id(&conn, role_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn list_all_roles(
         &self,
@@ -243,6 +261,27 @@ impl RoleInterface for MockDb {
         Ok(roles.remove(role_index))
     }
 
+    async fn delete_unassigned_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let mut roles = self.roles.lock().await;
+        let user_roles = self.user_roles.lock().await;
+        let role_index = roles
+            .iter()
+            .position(|role| {
+                role.role_id == role_id
+                    && !user_roles
+                        .iter()
+                        .any(|user_role| user_role.role_id == role_id)
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No unassigned role available for role_id = {role_id}"
+            )))?;
+
+        Ok(roles.remove(role_index))
+    }
+
     async fn list_all_roles(
         &self,
         merchant_id: &str,
@@ -20,7 +20,8 @@ use crate::{
         PaymentLinkFormData,
     },
     types::api::{
-        AttachEvidenceRequest, Config, ConfigUpdate, CreateFileRequest, DisputeId, FileId, PollId,
+        AttachEvidenceRequest, Config, ConfigUpdate, CreateFileRequest, DisputeId,
+        FileDownloadRequest, FileId, PollId,
     },
 };
 
@@ -114,6 +115,7 @@ impl_misc_api_event_type!(
     Config,
     CreateFileRequest,
     FileId,
+    FileDownloadRequest,
     AttachEvidenceRequest,
     PaymentLinkFormData,
     ConfigUpdate
@@ -1,3 +1,4 @@
+use api_models::connector_replay::ConnectorReplayRecord;
 use common_utils::request::Method;
 use router_env::tracing_actix_web::RequestId;
 use serde::Serialize;
@@ -88,6 +89,23 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// Builds the record persisted by the connector replay recorder, the request and response
+    /// bodies are recorded masked as they are logged
+    pub fn get_replay_record(&self) -> ConnectorReplayRecord {
+        ConnectorReplayRecord {
+            connector_name: self.connector_name.clone(),
+            flow: self.flow.clone(),
+            url: self.url.clone(),
+            method: self.method.clone(),
+            masked_request: self.request.clone(),
+            masked_response: self.masked_response.clone(),
+            error: self.error.clone(),
+            status_code: self.status_code,
+            request_id: self.request_id.clone(),
+            created_at: i64::try_from(self.created_at).unwrap_or_default(),
+        }
+    }
 }
 
 impl KafkaMessage for ConnectorEvent {
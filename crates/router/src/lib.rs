@@ -47,11 +47,13 @@ static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 /// Header Constants
 pub mod headers {
     pub const ACCEPT: &str = "Accept";
+    pub const ACCEPT_LANGUAGE: &str = "Accept-Language";
     pub const API_KEY: &str = "API-KEY";
     pub const APIKEY: &str = "apikey";
     pub const X_CC_API_KEY: &str = "X-CC-Api-Key";
     pub const API_TOKEN: &str = "Api-Token";
     pub const AUTHORIZATION: &str = "Authorization";
+    pub const CONTENT_ENCODING: &str = "Content-Encoding";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
@@ -69,6 +71,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_256: &str = "X-Webhook-Signature-256";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -133,6 +136,7 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
+            .service(routes::Organization::server(state.clone()))
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
@@ -140,10 +144,12 @@ pub fn mk_app(
             .service(routes::Routing::server(state.clone()))
             .service(routes::Blocklist::server(state.clone()))
             .service(routes::Gsm::server(state.clone()))
+            .service(routes::ConnectorReplay::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()))
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
+            .service(routes::LockerMigrate::server(state.clone()))
             .service(routes::WebhookEvents::server(state.clone()));
     }
 
@@ -8,6 +8,8 @@ pub mod cards_info;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+#[cfg(feature = "olap")]
+pub mod connector_replay;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
@@ -60,10 +62,11 @@ pub use self::app::Recon;
 pub use self::app::{
     ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, ConnectorOnboarding, Customers,
     Disputes, EphemeralKey, Files, Gsm, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Refunds, User, Webhooks,
+    MerchantConnectorAccount, Organization, PaymentLink, PaymentMethods, Payments, Poll, Refunds,
+    User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{Blocklist, ConnectorReplay, LockerMigrate, Routing, Verify, WebhookEvents};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
@@ -1,9 +1,10 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::organization as organization_api;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{admin::*, api_locking, organization},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -190,6 +191,85 @@ pub async fn delete_merchant_account(
     )
     .await
 }
+
+/// Organization - Update Parent
+///
+/// Make the organization a sub-organization of another organization. An organization cannot be
+/// made a sub-organization of one of its own sub-organizations.
+#[utoipa::path(
+    post,
+    path = "/organization/{org_id}/parent",
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    request_body = OrganizationParentUpdateRequest,
+    responses(
+        (status = 200, description = "Organization Updated", body = OrganizationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Update the parent of an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationParentUpdate))]
+pub async fn organization_parent_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<String>,
+    json_payload: web::Json<organization_api::OrganizationParentUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationParentUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.org_id = org_id.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| organization::update_parent_organization(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Organization - List Descendants
+///
+/// List all the sub-organizations under the organization, including the nested ones.
+#[utoipa::path(
+    get,
+    path = "/organization/{org_id}/descendants",
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Sub-organizations retrieved", body = Vec<OrganizationResponse>),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "List the descendants of an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationDescendantsList))]
+pub async fn organization_descendants_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationDescendantsList;
+    let payload = organization_api::OrganizationId {
+        org_id: org_id.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| organization::list_organization_descendants(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 /// Merchant Connector - Create
 ///
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
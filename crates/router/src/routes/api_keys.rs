@@ -197,6 +197,59 @@ pub async fn api_key_revoke(
     )
     .await
 }
+/// API Key - Rotate
+///
+/// Issue a new API Key in place of the specified API Key. The rotated API Key continues to be
+/// accepted until the end of the grace period, after which it can no longer be used for
+/// authenticating with our APIs.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = RotateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_types::RotateApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let (merchant_id, key_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.key_id = key_id;
+    payload.merchant_id.clone_from(&merchant_id);
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| api_keys::rotate_api_key(state, payload),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 /// API Key - List
 ///
 /// List all API Keys associated with your merchant account.
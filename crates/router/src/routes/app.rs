@@ -21,16 +21,20 @@ use tokio::sync::oneshot;
 use super::blocklist;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
+#[cfg(feature = "olap")]
+use super::locker_migration::{rust_locker_migration, rust_locker_migration_status};
 #[cfg(feature = "payouts")]
 use super::payouts::*;
 #[cfg(feature = "olap")]
 use super::routing as cloud_routing;
 #[cfg(feature = "olap")]
-use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
+use super::verification::{
+    apple_pay_merchant_registration, apple_pay_reverification, retrieve_apple_pay_verified_domains,
+};
 #[cfg(feature = "olap")]
 use super::{
-    admin::*, api_keys::*, connector_onboarding::*, disputes::*, files::*, gsm::*, payment_link::*,
-    user::*, user_role::*, webhook_events::*,
+    admin::*, api_keys::*, connector_onboarding::*, connector_replay::*, disputes::*, files::*,
+    gsm::*, payment_link::*, user::*, user_role::*, webhook_events::*,
 };
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -40,7 +44,10 @@ use super::{currency, payment_methods::*};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, webhooks::*};
 #[cfg(feature = "oltp")]
-use super::{pm_auth, poll::retrieve_poll_status};
+use super::{
+    pm_auth,
+    poll::{retrieve_poll_progress, retrieve_poll_status},
+};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 use crate::configs::secrets_transformers;
@@ -49,7 +56,7 @@ use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "recon", feature = "olap"))]
 use crate::routes::recon as recon_routes;
 #[cfg(feature = "olap")]
-use crate::routes::verify_connector::payment_connector_verify;
+use crate::routes::verify_connector::{merchant_connector_health, payment_connector_verify};
 pub use crate::{
     configs::settings,
     core::routing,
@@ -347,6 +354,9 @@ impl Payments {
                     web::resource("/sync")
                         .route(web::post().to(payments_retrieve_with_gateway_creds)),
                 )
+                .service(
+                    web::resource("/reject/bulk").route(web::post().to(payments_bulk_reject)),
+                )
                 .service(
                     web::resource("/{payment_id}")
                         .route(web::get().to(payments_retrieve))
@@ -531,6 +541,10 @@ impl Routing {
                         &TransactionType::Payment,
                     )
                 })),
+            )
+            .service(
+                web::resource("/evaluate")
+                    .route(web::post().to(cloud_routing::routing_evaluate_config)),
             );
 
         #[cfg(feature = "payouts")]
@@ -689,10 +703,31 @@ impl Customers {
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/reorder")
+                        .route(web::post().to(customer_payment_methods_reorder_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/probe")
+                        .route(web::get().to(customer_payment_methods_probe_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/import")
+                        .route(web::post().to(customer_payment_methods_import_api)),
+                )
                 .service(
                     web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
                         .route(web::post().to(default_payment_method_set_api)),
                 )
+                .service(
+                    web::resource("/{customer_id}/addresses")
+                        .route(web::post().to(customers_add_address))
+                        .route(web::get().to(customers_list_addresses)),
+                )
+                .service(
+                    web::resource("/{customer_id}/addresses/{address_id}")
+                        .route(web::delete().to(customers_delete_address)),
+                )
                 .service(
                     web::resource("/{customer_id}")
                         .route(web::get().to(customers_retrieve))
@@ -724,11 +759,13 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(web::resource("/eligibility").route(web::post().to(refunds_eligibility)))
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
                         .route(web::post().to(refunds_update)),
-                );
+                )
+                .service(web::resource("/{id}/approval").route(web::post().to(refunds_approval)));
         }
         route
     }
@@ -741,7 +778,9 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/batch/create").route(web::post().to(payouts_batch_create)));
 
         #[cfg(feature = "olap")]
         {
@@ -807,6 +846,10 @@ impl PaymentMethods {
                 .service(
                     web::resource("/auth/exchange").route(web::post().to(pm_auth::exchange_token)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/auth/status")
+                        .route(web::get().to(pm_auth::connection_status_retrieve)),
+                )
         }
         route
     }
@@ -829,6 +872,14 @@ impl Recon {
                 web::resource("/request").route(web::post().to(recon_routes::request_for_recon)),
             )
             .service(web::resource("/verify_token").route(web::get().to(verify_recon_token)))
+            .service(
+                web::resource("/difference_report")
+                    .route(web::post().to(recon_routes::get_recon_difference_report)),
+            )
+            .service(
+                web::resource("/entries/{entry_id}/resolve")
+                    .route(web::post().to(recon_routes::resolve_recon_entry)),
+            )
     }
 }
 
@@ -846,6 +897,11 @@ impl Blocklist {
                     .route(web::post().to(blocklist::add_entry_to_blocklist))
                     .route(web::delete().to(blocklist::remove_entry_from_blocklist)),
             )
+            .service(
+                web::resource("/bulk")
+                    .route(web::post().to(blocklist::bulk_add_entries_to_blocklist))
+                    .route(web::delete().to(blocklist::bulk_remove_entries_from_blocklist)),
+            )
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
@@ -875,6 +931,23 @@ impl MerchantAccount {
     }
 }
 
+pub struct Organization;
+
+#[cfg(feature = "olap")]
+impl Organization {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/organization")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{id}/parent").route(web::post().to(organization_parent_update)),
+            )
+            .service(
+                web::resource("/{id}/descendants")
+                    .route(web::get().to(organization_descendants_list)),
+            )
+    }
+}
+
 pub struct MerchantConnectorAccount;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -901,6 +974,10 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(payment_connector_retrieve))
                         .route(web::post().to(payment_connector_update))
                         .route(web::delete().to(payment_connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/health")
+                        .route(web::get().to(merchant_connector_health)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -940,8 +1017,10 @@ impl Mandates {
         }
         #[cfg(feature = "oltp")]
         {
-            route =
-                route.service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
+            route = route
+                .service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)))
+                .service(web::resource("/pause/{id}").route(web::post().to(pause_mandate)))
+                .service(web::resource("/resume/{id}").route(web::post().to(resume_mandate)));
         }
         route
     }
@@ -1005,6 +1084,10 @@ impl Poll {
         web::scope("/poll")
             .app_data(web::Data::new(config))
             .service(web::resource("/status/{poll_id}").route(web::get().to(retrieve_poll_status)))
+            .service(
+                web::resource("/progress/{payment_id}")
+                    .route(web::get().to(retrieve_poll_progress)),
+            )
     }
 }
 
@@ -1023,6 +1106,7 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
     }
 }
 
@@ -1045,6 +1129,10 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence_bundle/{dispute_id}")
+                    .route(web::post().to(submit_dispute_evidence_bundle)),
+            )
             .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
     }
 }
@@ -1055,7 +1143,9 @@ impl Cards {
     pub fn server(state: AppState) -> Scope {
         web::scope("/cards")
             .app_data(web::Data::new(state))
+            .service(web::resource("/import").route(web::post().to(card_info_import)))
             .service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
+            .service(web::resource("/bin/{bin}").route(web::get().to(card_bin_lookup)))
     }
 }
 
@@ -1072,6 +1162,7 @@ impl Files {
                     .route(web::delete().to(files_delete))
                     .route(web::get().to(files_retrieve)),
             )
+            .service(web::resource("/{file_id}/download").route(web::get().to(files_download)))
     }
 }
 
@@ -1147,11 +1238,43 @@ impl Gsm {
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(create_gsm_rule)))
             .service(web::resource("/get").route(web::post().to(get_gsm_rule)))
+            .service(web::resource("/lookup").route(web::post().to(lookup_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(delete_gsm_rule)))
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct ConnectorReplay;
+
+#[cfg(feature = "olap")]
+impl ConnectorReplay {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_replay")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{merchant_id}/{attempt_id}")
+                    .route(web::get().to(retrieve_connector_replay)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct LockerMigrate;
+
+#[cfg(feature = "olap")]
+impl LockerMigrate {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/locker_migration")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/{merchant_id}").route(web::post().to(rust_locker_migration)))
+            .service(
+                web::resource("/{merchant_id}/status")
+                    .route(web::get().to(rust_locker_migration_status)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Verify;
 
@@ -1164,6 +1287,10 @@ impl Verify {
                 web::resource("/apple_pay/{merchant_id}")
                     .route(web::post().to(apple_pay_merchant_registration)),
             )
+            .service(
+                web::resource("/apple_pay/{merchant_id}/reverify")
+                    .route(web::post().to(apple_pay_reverification)),
+            )
             .service(
                 web::resource("/applepay_verified_domains")
                     .route(web::get().to(retrieve_apple_pay_verified_domains)),
@@ -1257,7 +1384,8 @@ impl User {
                     web::resource("/transfer_ownership")
                         .route(web::post().to(transfer_org_ownership)),
                 )
-                .service(web::resource("/delete").route(web::delete().to(delete_user_role))),
+                .service(web::resource("/delete").route(web::delete().to(delete_user_role)))
+                .service(web::resource("/force_signout").route(web::post().to(force_signout))),
         );
 
         // Role information
@@ -1272,7 +1400,8 @@ impl User {
                 .service(
                     web::resource("/{role_id}")
                         .route(web::get().to(get_role))
-                        .route(web::put().to(update_role)),
+                        .route(web::put().to(update_role))
+                        .route(web::delete().to(delete_role)),
                 ),
         );
 
@@ -1319,7 +1448,8 @@ impl WebhookEvents {
                     .service(
                         web::resource("retry")
                             .route(web::post().to(retry_webhook_delivery_attempt)),
-                    ),
+                    )
+                    .service(web::resource("replay").route(web::post().to(replay_webhook_event))),
             )
     }
 }
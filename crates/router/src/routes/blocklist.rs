@@ -11,7 +11,7 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -80,6 +80,78 @@ pub async fn remove_entry_from_blocklist(
     .await
 }
 
+#[utoipa::path(
+    post,
+    path = "/blocklist/bulk",
+    request_body = BulkBlocklistRequest,
+    responses(
+        (status = 200, description = "Outcome of blocking each entry", body = BulkBlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Block Fingerprints in bulk",
+    security(("api_key" = []))
+)]
+pub async fn bulk_add_entries_to_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BulkAddToBlocklistRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkAddToBlocklist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::bulk_add_entries_to_blocklist(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/blocklist/bulk",
+    request_body = BulkBlocklistRequest,
+    responses(
+        (status = 200, description = "Outcome of unblocking each entry", body = BulkBlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Unblock Fingerprints in bulk",
+    security(("api_key" = []))
+)]
+pub async fn bulk_remove_entries_from_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BulkDeleteFromBlocklistRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkDeleteFromBlocklist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::bulk_remove_entries_from_blocklist(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[utoipa::path(
     get,
     path = "/blocklist",
@@ -52,3 +52,53 @@ pub async fn card_iin_info(
     )
     .await
 }
+
+/// Cards Info - Lookup
+///
+/// Resolve a card bin to the issuer, network, country and card type of the card
+#[instrument(skip_all, fields(flow = ?Flow::CardsInfo))]
+pub async fn card_bin_lookup(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payload = api_models::cards_info::CardBinLookupRequest {
+        card_bin: path.into_inner(),
+    };
+
+    api::server_wrap(
+        Flow::CardsInfo,
+        state,
+        &req,
+        payload,
+        |state, _auth, req, _| cards_info::get_card_info(state, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Cards Info - Import
+///
+/// Bulk insert or update the card information of card bins
+#[instrument(skip_all, fields(flow = ?Flow::CardsInfoImport))]
+pub async fn card_info_import(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::cards_info::CardInfoImportRequest>,
+) -> impl Responder {
+    Box::pin(api::server_wrap(
+        Flow::CardsInfoImport,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| cards_info::import_card_info(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,34 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connector_replay as connector_replay_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_replay},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorReplayRetrieve))]
+pub async fn retrieve_connector_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::ConnectorReplayRetrieve;
+    let (merchant_id, attempt_id) = path.into_inner();
+    let payload = connector_replay_types::ConnectorReplayRetrieveRequest {
+        merchant_id,
+        attempt_id,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| connector_replay::retrieve_connector_replay_records(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
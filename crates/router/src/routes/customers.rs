@@ -5,7 +5,7 @@ use super::app::AppState;
 use crate::{
     core::{api_locking, customers::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
-    types::api::customers,
+    types::api::{customers, mandates},
 };
 
 #[instrument(skip_all, fields(flow = ?Flow::CustomersCreate))]
@@ -138,16 +138,109 @@ pub async fn customers_delete(
     ))
     .await
 }
+#[instrument(skip_all, fields(flow = ?Flow::CustomersAddAddress))]
+pub async fn customers_add_address(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<customers::CustomerAddressRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersAddAddress;
+    let payload = customers::CustomerAddressRequest {
+        customer_id: path.into_inner(),
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            add_customer_address(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomersListAddresses))]
+pub async fn customers_list_addresses(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CustomersListAddresses;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            list_customer_addresses(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomersDeleteAddress))]
+pub async fn customers_delete_address(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::CustomersDeleteAddress;
+    let (customer_id, address_id) = path.into_inner();
+    let payload = customers::CustomerAddressId {
+        customer_id,
+        address_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            delete_customer_address(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 pub async fn get_customer_mandates(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<String>,
+    query_payload: web::Query<mandates::CustomerMandateListConstraints>,
 ) -> impl Responder {
     let flow = Flow::CustomersGetMandates;
     let customer_id = customers::CustomerId {
         customer_id: path.into_inner(),
     };
+    let constraints = query_payload.into_inner();
 
     Box::pin(api::server_wrap(
         flow,
@@ -160,6 +253,7 @@ pub async fn get_customer_mandates(
                 auth.merchant_account,
                 auth.key_store,
                 req,
+                constraints.clone(),
             )
         },
         auth::auth_type(
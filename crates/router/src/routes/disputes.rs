@@ -179,6 +179,54 @@ pub async fn submit_dispute_evidence(
     ))
     .await
 }
+/// Disputes - Submit Dispute Evidence Bundle
+///
+/// To submit the evidence for a dispute, grouped by what it proves
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence_bundle/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    request_body=SubmitEvidenceBundleRequest,
+    responses(
+        (status = 200, description = "The dispute evidence submitted successfully", body = DisputeResponse),
+        (status = 400, description = "Evidence required in the stage of the dispute is missing"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Submit Dispute Evidence Bundle",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceBundleSubmit))]
+pub async fn submit_dispute_evidence_bundle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dispute_models::SubmitEvidenceBundleRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputesEvidenceBundleSubmit;
+    let payload = dispute_models::SubmitEvidenceBundleRequest {
+        dispute_id: path.into_inner(),
+        ..json_payload.into_inner()
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            disputes::submit_evidence_bundle(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Disputes - Attach Evidence to Dispute
 ///
 /// To attach an evidence file to dispute
@@ -1,5 +1,6 @@
 use actix_multipart::Multipart;
 use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::files as file_models;
 use router_env::{instrument, tracing, Flow};
 
 use crate::core::api_locking;
@@ -34,7 +35,9 @@ pub async fn files_create(
     payload: Multipart,
 ) -> HttpResponse {
     let flow = Flow::CreateFile;
-    let create_file_request_result = transformers::get_create_file_request(payload).await;
+    let create_file_request_result =
+        transformers::get_create_file_request(payload, state.conf.file_upload.max_file_size_bytes)
+            .await;
     let create_file_request = match create_file_request_result {
         Ok(valid_request) => valid_request,
         Err(err) => return api::log_and_return_error_response(err),
@@ -140,3 +143,53 @@ pub async fn files_retrieve(
     ))
     .await
 }
+
+/// Files - Download
+///
+/// To get a short-lived URL to download a file directly from the file storage.
+/// Falls back to returning the file body if the file storage does not support presigned URLs.
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}/download",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file"),
+        ("expires_in" = Option<u32>, Query, description = "Duration in seconds for which the download URL is valid")
+    ),
+    responses(
+        (status = 200, description = "File download URL or file body", body = FileDownloadResponse),
+        (status = 400, description = "Bad Request"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Download a File",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DownloadFile))]
+pub async fn files_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<file_models::FileDownloadParams>,
+) -> HttpResponse {
+    let flow = Flow::DownloadFile;
+    let file_download_request = files::FileDownloadRequest {
+        file_id: path.into_inner(),
+        expires_in: query.into_inner().expires_in,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        file_download_request,
+        |state, auth, req, _| {
+            files_download_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
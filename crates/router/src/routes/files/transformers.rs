@@ -10,8 +10,11 @@ use crate::{
     utils::OptionExt,
 };
 
+/// Reads the file upload request from the multipart payload. The file is rejected as soon as it
+/// exceeds the maximum file size, without buffering the rest of it.
 pub async fn get_create_file_request(
     mut payload: Multipart,
+    max_file_size_bytes: usize,
 ) -> CustomResult<CreateFileRequest, errors::ApiErrorResponse> {
     let mut option_purpose: Option<files::FilePurpose> = None;
     let mut dispute_id: Option<String> = None;
@@ -32,10 +35,20 @@ pub async fn get_create_file_request(
 
                 //Collect the file content and throw error if something fails
                 let mut file_data = Vec::new();
+                let mut file_data_size = 0;
                 let mut stream = field.into_stream();
                 while let Some(chunk) = stream.next().await {
                     match chunk {
-                        Ok(bytes) => file_data.push(bytes),
+                        Ok(bytes) => {
+                            file_data_size += bytes.len();
+                            if file_data_size > max_file_size_bytes {
+                                return Err(errors::ApiErrorResponse::FileSizeExceeded {
+                                    max_file_size_bytes,
+                                }
+                                .into());
+                            }
+                            file_data.push(bytes)
+                        }
                         Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
                             .attach_printable(format!("{}{}", "File parsing error: ", err))?,
                     }
@@ -64,13 +77,8 @@ pub async fn get_create_file_request(
         Err(errors::ApiErrorResponse::MissingFile)
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    // Get file mime type from the file content using 'infer'
+    let file_type = helpers::get_file_content_type(&file)?;
     Ok(CreateFileRequest {
         file,
         file_name,
@@ -83,6 +83,44 @@ pub async fn get_gsm_rule(
     .await
 }
 
+/// Gsm - Lookup
+///
+/// To find the Gsm Rule that a connector error code and message map to
+#[utoipa::path(
+    post,
+    path = "/gsm/lookup",
+    request_body(
+        content = GsmLookupRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm retrieved", body = GsmResponse),
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 404, description = "No Gsm Rule matches the error code and message")
+    ),
+    tag = "Gsm",
+    operation_id = "Lookup Gsm Rule",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleLookup))]
+pub async fn lookup_gsm_rule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmLookupRequest>,
+) -> impl Responder {
+    let gsm_lookup_req = json_payload.into_inner();
+    let flow = Flow::GsmRuleLookup;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        gsm_lookup_req,
+        |state, _, gsm_lookup_req, _| gsm::lookup_gsm_rule(state, gsm_lookup_req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Gsm - Update
 ///
 /// To update a Gsm Rule
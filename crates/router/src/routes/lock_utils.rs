@@ -29,12 +29,14 @@ pub enum ApiIdentifier {
     Forex,
     RustLockerMigration,
     Gsm,
+    ConnectorReplay,
     Role,
     User,
     UserRole,
     ConnectorOnboarding,
     Recon,
     Poll,
+    Organization,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -56,6 +58,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingEvaluateConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
@@ -64,11 +67,14 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
+            Flow::BulkAddToBlocklist => Self::Blocklist,
+            Flow::BulkDeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
 
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
+            | Flow::MerchantConnectorsHealthCheck
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
             | Flow::MerchantConnectorsList => Self::MerchantConnector,
@@ -84,12 +90,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersAddAddress
+            | Flow::CustomersListAddresses
+            | Flow::CustomersDeleteAddress
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
-            Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
+            Flow::MandatesRetrieve
+            | Flow::MandatesRevoke
+            | Flow::MandatesPause
+            | Flow::MandatesResume
+            | Flow::MandatesList => Self::Mandates,
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsList
@@ -100,9 +113,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
+            | Flow::CustomerPaymentMethodsReorder
+            | Flow::CustomerPaymentMethodsProbe
+            | Flow::CustomerPaymentMethodsImport
             | Flow::PaymentMethodSave => Self::PaymentMethods,
 
-            Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
+            Flow::PmAuthLinkTokenCreate
+            | Flow::PmAuthExchangeToken
+            | Flow::PmAuthConnectionStatusRetrieve => Self::PaymentMethodAuth,
 
             Flow::PaymentsCreate
             | Flow::PaymentsRetrieve
@@ -113,6 +131,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
+            | Flow::PaymentsBulkReject
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
@@ -124,6 +143,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetExtendedCardInfo => Self::Payments,
 
             Flow::PayoutsCreate
+            | Flow::PayoutsBatchCreate
             | Flow::PayoutsRetrieve
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
@@ -136,6 +156,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsRetrieve
             | Flow::RefundsRetrieveForceSync
             | Flow::RefundsUpdate
+            | Flow::RefundsApproval
+            | Flow::RefundsEligibility
             | Flow::RefundsList
             | Flow::RefundsFilters => Self::Refunds,
 
@@ -143,24 +165,29 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventReplay => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
-            | Flow::ApiKeyList => Self::ApiKeys,
+            | Flow::ApiKeyList
+            | Flow::ApiKeyRotate => Self::ApiKeys,
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
             | Flow::DisputesEvidenceSubmit
+            | Flow::DisputesEvidenceBundleSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
-            Flow::CardsInfo => Self::CardsInfo,
+            Flow::CardsInfo | Flow::CardsInfoImport => Self::CardsInfo,
 
-            Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
+            Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile | Flow::DownloadFile => {
+                Self::Files
+            }
 
             Flow::CacheInvalidate => Self::Cache,
 
@@ -179,12 +206,17 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::Verification => Self::Verification,
 
-            Flow::RustLockerMigration => Self::RustLockerMigration,
+            Flow::RustLockerMigration | Flow::RustLockerMigrationStatus => {
+                Self::RustLockerMigration
+            }
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
+            | Flow::GsmRuleLookup
             | Flow::GsmRuleUpdate
             | Flow::GsmRuleDelete => Self::Gsm,
 
+            Flow::ConnectorReplayRetrieve => Self::ConnectorReplay,
+
             Flow::UserConnectAccount
             | Flow::UserSignUp
             | Flow::UserSignIn
@@ -223,9 +255,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AcceptInvitation
             | Flow::MerchantSelect
             | Flow::DeleteUserRole
+            | Flow::ForceSignout
             | Flow::TransferOrgOwnership
             | Flow::CreateRole
             | Flow::UpdateRole
+            | Flow::DeleteRole
             | Flow::UserFromEmail => Self::UserRole,
 
             Flow::GetActionUrl | Flow::SyncOnboardingStatus | Flow::ResetTrackingId => {
@@ -235,9 +269,15 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::ReconDifferenceReport
+            | Flow::ReconEntryResolve => Self::Recon,
 
-            Flow::RetrievePollStatus => Self::Poll,
+            Flow::RetrievePollStatus | Flow::RetrievePollProgress => Self::Poll,
+
+            Flow::OrganizationParentUpdate | Flow::OrganizationDescendantsList => {
+                Self::Organization
+            }
         }
     }
 }
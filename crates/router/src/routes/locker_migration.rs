@@ -21,6 +21,26 @@ pub async fn rust_locker_migration(
         &merchant_id,
         |state, _, _, _| locker_migration::rust_locker_migration(state, &merchant_id),
         &auth::AdminApiAuth,
+        // The migration holds its own lock, which it renews for as long as it runs
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn rust_locker_migration_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RustLockerMigrationStatus;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &merchant_id,
+        |state, _, _, _| locker_migration::rust_locker_migration_status(state, &merchant_id),
+        &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
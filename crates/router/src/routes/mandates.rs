@@ -90,6 +90,90 @@ pub async fn revoke_mandate(
     ))
     .await
 }
+/// Mandates - Pause Mandate
+///
+/// Pauses an active mandate, payments with a paused mandate are rejected until it is resumed
+#[utoipa::path(
+    post,
+    path = "/mandates/pause/{mandate_id}",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    responses(
+        (status = 200, description = "The mandate was paused successfully", body = MandateResponse),
+        (status = 400, description = "Mandate cannot be paused"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Pause a Mandate",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesPause))]
+// #[post("/pause/{id}")]
+pub async fn pause_mandate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MandatesPause;
+    let mandate_id = mandates::MandateId {
+        mandate_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        mandate_id,
+        |state, auth, req, _| {
+            mandate::pause_mandate(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Mandates - Resume Mandate
+///
+/// Resumes a paused mandate, making it active again
+#[utoipa::path(
+    post,
+    path = "/mandates/resume/{mandate_id}",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    responses(
+        (status = 200, description = "The mandate was resumed successfully", body = MandateResponse),
+        (status = 400, description = "Mandate cannot be resumed"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Resume a Mandate",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesResume))]
+// #[post("/resume/{id}")]
+pub async fn resume_mandate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MandatesResume;
+    let mandate_id = mandates::MandateId {
+        mandate_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        mandate_id,
+        |state, auth, req, _| {
+            mandate::resume_mandate(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Mandates - List Mandates
 #[utoipa::path(
     get,
@@ -123,5 +123,7 @@ counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process trac
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
 
+counter_metric!(APPLEPAY_REVERIFICATION_FAILURE_COUNT, GLOBAL_METER); // Apple Pay domain re-verifications that could not be completed before the verification expired
+
 pub mod request;
 pub mod utils;
@@ -371,6 +371,96 @@ pub async fn default_payment_method_set_api(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsReorder))]
+pub async fn customer_payment_methods_reorder_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::CustomerPaymentMethodsReorderRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsReorder;
+    let payload = payment_methods::CustomerPaymentMethodsReorderRequest {
+        customer_id: path.into_inner(),
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            cards::reorder_customer_payment_methods(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsProbe))]
+pub async fn customer_payment_methods_probe_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsProbe;
+    let payload = api_models::customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            cards::probe_payment_methods(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req.customer_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsImport))]
+pub async fn customer_payment_methods_import_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::PaymentMethodImportRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsImport;
+    let payload = payment_methods::PaymentMethodImportRequest {
+        customer_id: path.into_inner(),
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            cards::import_payment_method(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
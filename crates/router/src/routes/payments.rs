@@ -136,9 +136,9 @@ pub async fn payments_create(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 req.headers(),
             ),
@@ -256,11 +256,14 @@ pub async fn payments_retrieve(
     tracing::Span::current().record("payment_id", &path.to_string());
     tracing::Span::current().record("flow", &flow.to_string());
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_scoped_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -314,10 +317,11 @@ pub async fn payments_retrieve_with_gateway_creds(
     req: actix_web::HttpRequest,
     json_payload: web::Json<payment_types::PaymentRetrieveBodyWithCredentials>,
 ) -> impl Responder {
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_scoped_auth_type_and_flow(req.headers(), Permission::PaymentRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
     let payload = payment_types::PaymentsRetrieveRequest {
         resource_id: payment_types::PaymentIdType::PaymentIntentId(
             json_payload.payment_id.to_string(),
@@ -400,10 +404,11 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_scoped_auth_type_and_flow(req.headers(), Permission::PaymentWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -477,11 +482,14 @@ pub async fn payments_confirm(
         }
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_scoped_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -550,20 +558,16 @@ pub async fn payments_capture(
         &req,
         payload,
         |state, auth, payload, req_state| {
-            payments::payments_core::<api_types::Capture, payment_types::PaymentsResponse, _, _, _>(
+            payments::capture_delay::payments_capture_core(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.key_store,
-                payments::PaymentCapture,
                 payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -845,7 +849,7 @@ pub async fn payments_cancel(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -891,7 +895,7 @@ pub async fn payments_list(
         payload,
         |state, auth, req, _| payments::list_payments(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(Permission::PaymentRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -1007,9 +1011,9 @@ pub async fn payments_approve(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 http_req.headers(),
             ),
@@ -1053,6 +1057,10 @@ pub async fn payments_reject(
                 payment_types::PaymentsCancelRequest {
                     payment_id: req.payment_id,
                     cancellation_reason: Some("Rejected by merchant".to_string()),
+                    force_reject: req.force.unwrap_or(false),
+                    rejection_reason: req.rejection_reason,
+                    reject_decision: req.decision,
+                    notify_customer: req.notify_customer,
                     ..Default::default()
                 },
                 api::AuthFlow::Merchant,
@@ -1062,9 +1070,9 @@ pub async fn payments_reject(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 http_req.headers(),
             ),
@@ -1074,6 +1082,44 @@ pub async fn payments_reject(
     .await
 }
 
+#[cfg(feature = "oltp")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsBulkReject))]
+// #[post("/reject/bulk")]
+pub async fn payments_bulk_reject(
+    state: web::Data<app::AppState>,
+    http_req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsBulkRejectRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsBulkReject;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &http_req,
+        json_payload.into_inner(),
+        |state, auth, req, req_state| {
+            payments::bulk_reject::bulk_reject_payments(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        match env::which() {
+            env::Env::Production => &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
+            _ => auth::auth_type(
+                &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
+                &auth::JWTAuth(Permission::PaymentWrite),
+                http_req.headers(),
+            ),
+        },
+        // Each payment is locked individually while it is being rejected
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn authorize_verify_select<Op>(
     operation: Op,
@@ -1206,7 +1252,7 @@ pub async fn payments_incremental_authorization(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -46,6 +46,39 @@ pub async fn payouts_create(
     ))
     .await
 }
+/// Payouts - Batch Create
+#[utoipa::path(
+    post,
+    path = "/payouts/batch/create",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payouts of the batch processed", body = PayoutBatchCreateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a batch of Payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            payouts_batch_create_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Payouts - Retrieve
 #[utoipa::path(
     get,
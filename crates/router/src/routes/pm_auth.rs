@@ -71,3 +71,36 @@ pub async fn exchange_token(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::PmAuthConnectionStatusRetrieve))]
+pub async fn connection_status_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payload = api_types::pm_auth::ConnectionStatusRetrieveRequest {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PmAuthConnectionStatusRetrieve;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, _| {
+            crate::core::pm_auth::retrieve_connection_status(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        crate::services::authentication::auth_type(
+            &crate::services::authentication::ApiKeyAuth,
+            &crate::services::authentication::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -5,7 +5,7 @@ use super::app::AppState;
 use crate::{
     core::{api_locking, poll},
     services::{api, authentication as auth},
-    types::api::PollId,
+    types::api::{PollId, PollProgressRequest},
 };
 
 /// Poll - Retrieve Poll Status
@@ -44,3 +44,39 @@ pub async fn retrieve_poll_status(
     )
     .await
 }
+
+/// Poll - Retrieve Poll Progress
+#[utoipa::path(
+    get,
+    path = "/poll/progress/{payment_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "The poll progress was retrieved successfully", body = PollProgressResponse)
+    ),
+    tag = "Poll",
+    operation_id = "Retrieve Poll Progress",
+    security(("publishable_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RetrievePollProgress))]
+pub async fn retrieve_poll_progress(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RetrievePollProgress;
+    let payload = PollProgressRequest {
+        payment_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| poll::retrieve_poll_progress(state, req, auth.merchant_account),
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
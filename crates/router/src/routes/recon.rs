@@ -5,6 +5,8 @@ use masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::Flow;
 
 use super::AppState;
+#[cfg(feature = "olap")]
+use crate::{core::recon as recon_core, services::authorization::permissions::Permission};
 use crate::{
     core::{
         api_locking,
@@ -69,6 +71,51 @@ pub async fn get_recon_token(state: web::Data<AppState>, req: HttpRequest) -> Ht
     .await
 }
 
+#[cfg(feature = "olap")]
+pub async fn get_recon_difference_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_api::ReconDifferenceReportRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconDifferenceReport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            recon_core::generate_recon_difference_report(state, auth.merchant_account, req)
+        },
+        &auth::JWTAuth(Permission::PaymentRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+pub async fn resolve_recon_entry(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<recon_api::ReconEntryResolveRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconEntryResolve;
+    let mut payload = json_payload.into_inner();
+    payload.entry_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            recon_core::mark_recon_entry_as_resolved(state, auth.merchant_account, user_id, req)
+        },
+        &auth::JWTAuth(Permission::PaymentWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn send_recon_request(
     state: AppState,
     user: UserFromToken,
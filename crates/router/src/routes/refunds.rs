@@ -38,7 +38,7 @@ pub async fn refunds_create(
         json_payload.into_inner(),
         |state, auth, req, _| refund_create_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(Permission::RefundWrite),
             &auth::JWTAuth(Permission::RefundWrite),
             req.headers(),
         ),
@@ -98,7 +98,7 @@ pub async fn refunds_retrieve(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -148,7 +148,7 @@ pub async fn refunds_retrieve_with_body(
                 refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(Permission::RefundRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -188,11 +188,95 @@ pub async fn refunds_update(
         &req,
         refund_update_req,
         |state, auth, req, _| refund_update_core(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(Permission::RefundWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
 }
+/// Refunds - Approval
+///
+/// To approve or reject a refund which is pending approval. An approved refund is sent to the connector
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/approval",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body=RefundApprovalRequest,
+    responses(
+        (status = 200, description = "Refund approval decision recorded", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval")
+    ),
+    tag = "Refunds",
+    operation_id = "Approve or Reject a Refund",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsApproval))]
+// #[post("/{id}/approval")]
+pub async fn refunds_approval(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsApproval;
+    let mut refund_approval_req = json_payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, auth, req, _| {
+            refund_approval_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ScopedApiKeyAuth(Permission::RefundWrite),
+            &auth::JWTAuth(Permission::RefundWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Refunds - Eligibility
+///
+/// To check whether a refund can be initiated for a payment and the amount that can still be refunded, without creating a refund
+#[utoipa::path(
+    post,
+    path = "/refunds/eligibility",
+    request_body=RefundEligibilityRequest,
+    responses(
+        (status = 200, description = "Refund eligibility of the payment", body = RefundEligibilityResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Check Refund Eligibility",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsEligibility))]
+// #[post("/eligibility")]
+pub async fn refunds_eligibility(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundEligibilityRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsEligibility;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| refund_eligibility_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ScopedApiKeyAuth(Permission::RefundRead),
+            &auth::JWTAuth(Permission::RefundRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Refunds - List
 ///
 /// To list the refunds associated with a payment_id or with the merchant, if payment_id is not provided
@@ -222,7 +306,7 @@ pub async fn refunds_list(
         payload.into_inner(),
         |state, auth, req, _| refund_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -260,7 +344,7 @@ pub async fn refunds_filter_list(
         payload.into_inner(),
         |state, auth, req, _| refund_filter_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -293,7 +377,7 @@ pub async fn get_refunds_filters(state: web::Data<AppState>, req: HttpRequest) -
         (),
         |state, auth, _, _| get_filters_for_refunds(state, auth.merchant_account),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -120,6 +120,35 @@ pub async fn routing_retrieve_config(
     .await
 }
 
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_evaluate_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingEvaluateRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingEvaluateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::evaluate_routing_config(state, auth.merchant_account, payload)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn list_routing_configs(
@@ -167,6 +167,26 @@ pub async fn update_role(
     .await
 }
 
+pub async fn delete_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DeleteRole;
+    let role_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| role_core::delete_role(state, user, &role_id),
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn update_user_role(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -268,3 +288,21 @@ pub async fn delete_user_role(
     ))
     .await
 }
+
+pub async fn force_signout(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<user_role_api::ForceSignoutRequest>,
+) -> HttpResponse {
+    let flow = Flow::ForceSignout;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload.into_inner(),
+        user_role_core::force_signout,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -39,6 +39,33 @@ pub async fn apple_pay_merchant_registration(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::Verification))]
+pub async fn apple_pay_reverification(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<verifications::ApplepayReverificationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::Verification;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, body, _| {
+            verification::reverify_applepay_domains(state, merchant_id.clone(), body)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::Verification))]
 pub async fn retrieve_apple_pay_verified_domains(
     state: web::Data<AppState>,
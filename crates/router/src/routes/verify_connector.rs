@@ -1,5 +1,5 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use api_models::verify_connector::VerifyConnectorRequest;
+use api_models::verify_connector::{MerchantConnectorHealthRequest, VerifyConnectorRequest};
 use router_env::{instrument, tracing, Flow};
 
 use super::AppState;
@@ -20,9 +20,41 @@ pub async fn payment_connector_verify(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _: (), req, _| verify_connector::verify_connector_credentials(state, req),
+        |state, _: (), req, _| verify_connector::verify_connector(state, req),
         &auth::JWTAuth(Permission::MerchantConnectorAccountWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsHealthCheck))]
+pub async fn merchant_connector_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsHealthCheck;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = MerchantConnectorHealthRequest {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(services::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| verify_connector::check_merchant_connector_health(state, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
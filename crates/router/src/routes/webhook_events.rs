@@ -7,7 +7,7 @@ use crate::{
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookEventReplayRequestInternal,
     },
 };
 
@@ -129,3 +129,42 @@ pub async fn retry_webhook_delivery_attempt(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplay))]
+pub async fn replay_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplay;
+    let (merchant_id_or_profile_id, event_id) = path.into_inner();
+
+    let request_internal = WebhookEventReplayRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        event_id,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::replay_webhook_event(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.event_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
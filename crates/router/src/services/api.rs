@@ -5,6 +5,7 @@ use std::{
     error::Error,
     fmt::Debug,
     future::Future,
+    io::Write,
     str,
     time::{Duration, Instant},
 };
@@ -23,6 +24,7 @@ use common_utils::{
     request::RequestContent,
 };
 use error_stack::{report, Report, ResultExt};
+use flate2::{write::GzEncoder, Compression};
 use masking::{Maskable, PeekInterface, Secret};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId, Tag};
 use serde::Serialize;
@@ -32,12 +34,15 @@ use tera::{Context, Tera};
 use self::request::{HeaderExt, RequestBuilderExt};
 use super::authentication::AuthenticateAndFetch;
 use crate::{
-    configs::{settings::Connectors, Settings},
+    configs::{
+        settings::{ConnectorAttemptIdHeaderConfig, ConnectorRequestCompressionConfig, Connectors},
+        Settings,
+    },
     consts,
     core::{
-        api_locking,
+        api_locking, connector_replay,
         errors::{self, CustomResult},
-        payments,
+        payments, utils as core_utils,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -125,6 +130,19 @@ pub trait ConnectorValidation: ConnectorCommon {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
+
+    /// Validates a payment method token issued by the connector, before it is imported as a
+    /// payment method
+    fn validate_connector_payment_method_token(
+        &self,
+        _token: &str,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        Err(errors::ConnectorError::NotSupported {
+            message: "payment method import".to_string(),
+            connector: self.id(),
+        }
+        .into())
+    }
 }
 
 #[async_trait::async_trait]
@@ -398,6 +416,17 @@ where
                         },
                         None => serde_json::Value::Null,
                     };
+                    let request = add_attempt_id_header_if_required(
+                        request,
+                        &req.connector,
+                        &req.attempt_id,
+                        &state.conf.connector_attempt_id_header,
+                    );
+                    let request = compress_connector_request_if_required(
+                        request,
+                        &req.connector,
+                        &state.conf.connector_request_compression,
+                    )?;
                     let request_url = request.url.clone();
                     let request_method = request.method;
                     let current_time = Instant::now();
@@ -452,7 +481,12 @@ where
                                         });
                                     match handle_response_result {
                                         Ok(mut data) => {
-                                            state.event_handler().log_event(&connector_event);
+                                            log_and_record_connector_event(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            )
+                                            .await;
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
@@ -468,7 +502,12 @@ where
                                             connector_event
                                                 .set_error(json!({"error": err.to_string()}));
 
-                                            state.event_handler().log_event(&connector_event);
+                                            log_and_record_connector_event(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            )
+                                            .await;
                                             Err(err)
                                         }
                                     }?
@@ -496,10 +535,22 @@ where
                                                     body,
                                                     Some(&mut connector_event),
                                                 )?;
-                                            state.event_handler().log_event(&connector_event);
+                                            log_and_record_connector_event(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            )
+                                            .await;
                                             error_res
                                         }
                                         _ => {
+                                            payments::connector_retry_after::record_connector_retry_after(
+                                                state,
+                                                &req.merchant_id,
+                                                &req.attempt_id,
+                                                &body,
+                                            )
+                                            .await;
                                             let error_res = connector_integration
                                                 .get_error_response(
                                                     body,
@@ -508,6 +559,13 @@ where
                                             if let Some(status) = error_res.attempt_status {
                                                 router_data.status = status;
                                             };
+                                            connector_replay::record_connector_exchange(
+                                                state,
+                                                &req.merchant_id,
+                                                &req.attempt_id,
+                                                &connector_event,
+                                            )
+                                            .await;
                                             error_res
                                         }
                                     };
@@ -521,7 +579,7 @@ where
                         }
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
-                            state.event_handler().log_event(&connector_event);
+                            log_and_record_connector_event(state, req, &connector_event).await;
                             if error.current_context().is_upstream_timeout() {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
@@ -553,6 +611,112 @@ where
     }
 }
 
+async fn log_and_record_connector_event<T, Req, Resp>(
+    state: &AppState,
+    req: &types::RouterData<T, Req, Resp>,
+    connector_event: &ConnectorEvent,
+) {
+    state.event_handler().log_event(connector_event);
+    connector_replay::record_connector_exchange(
+        state,
+        &req.merchant_id,
+        &req.attempt_id,
+        connector_event,
+    )
+    .await;
+}
+
+/// Sends the attempt id as the merchant reference, in the header configured for the connector.
+/// A header already set by the connector integration is not overridden, and flows that do not
+/// carry the id of a payment attempt send no header.
+fn add_attempt_id_header_if_required(
+    mut request: Request,
+    connector_name: &str,
+    attempt_id: &str,
+    attempt_id_header_config: &ConnectorAttemptIdHeaderConfig,
+) -> Request {
+    if !core_utils::is_payment_attempt_id(attempt_id) {
+        return request;
+    }
+    let Some(header_name) = attempt_id_header_config.connectors.get(connector_name) else {
+        return request;
+    };
+
+    let is_header_set = request
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(header_name));
+    if !is_header_set {
+        request.add_header(header_name, attempt_id.to_string().into());
+    }
+
+    request
+}
+
+/// Compresses the request body with gzip if the connector accepts gzip encoded requests and
+/// the body is at least `min_body_size_in_bytes` long.
+fn compress_connector_request_if_required(
+    mut request: Request,
+    connector_name: &str,
+    compression_config: &ConnectorRequestCompressionConfig,
+) -> CustomResult<Request, errors::ConnectorError> {
+    if !compression_config
+        .gzip_enabled_connectors
+        .contains(connector_name)
+    {
+        return Ok(request);
+    }
+
+    let (body, content_type) = match request.body.as_ref() {
+        Some(RequestContent::Json(payload)) => (
+            serde_json::to_vec(payload)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+            mime::APPLICATION_JSON.essence_str(),
+        ),
+        Some(RequestContent::FormUrlEncoded(payload)) => (
+            serde_urlencoded::to_string(payload)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?
+                .into_bytes(),
+            mime::APPLICATION_WWW_FORM_URLENCODED.essence_str(),
+        ),
+        Some(RequestContent::Xml(payload)) => (
+            quick_xml::se::to_string(payload)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?
+                .into_bytes(),
+            "application/xml",
+        ),
+        Some(RequestContent::FormData(_)) | Some(RequestContent::RawBytes(_)) | None => {
+            return Ok(request)
+        }
+    };
+
+    if body.len() < compression_config.min_body_size_in_bytes {
+        return Ok(request);
+    }
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&body)
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failed to gzip encode connector request body")?;
+    let compressed_body = encoder
+        .finish()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failed to gzip encode connector request body")?;
+
+    let is_content_type_set = request
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(headers::CONTENT_TYPE));
+    if !is_content_type_set {
+        request.add_header(headers::CONTENT_TYPE, content_type.to_string().into());
+    }
+    request.add_header(headers::CONTENT_ENCODING, "gzip".to_string().into());
+    request.set_body(RequestContent::RawBytes(compressed_body));
+
+    Ok(request)
+}
+
 #[instrument(skip_all)]
 pub async fn call_connector_api(
     state: &AppState,
@@ -2102,8 +2266,157 @@ pub fn get_payment_link_status(
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used, clippy::panic)]
+    use std::{
+        collections::{HashMap, HashSet},
+        io::Read,
+    };
+
+    use common_utils::request::{Method, Request, RequestContent};
+    use flate2::read::GzDecoder;
+    use serde_json::json;
+
+    use super::{add_attempt_id_header_if_required, compress_connector_request_if_required};
+    use crate::{
+        configs::settings::{ConnectorAttemptIdHeaderConfig, ConnectorRequestCompressionConfig},
+        headers,
+    };
+
+    fn get_compression_config() -> ConnectorRequestCompressionConfig {
+        ConnectorRequestCompressionConfig {
+            gzip_enabled_connectors: HashSet::from(["adyen".to_string()]),
+            min_body_size_in_bytes: 1024,
+        }
+    }
+
+    fn get_json_request(description: String) -> Request {
+        let mut request = Request::new(Method::Post, "https://connector.example.com/payments");
+        request.set_body(RequestContent::Json(Box::new(json!({
+            "amount": 6540,
+            "description": description,
+        }))));
+        request
+    }
+
+    fn get_header_value(request: &Request, header: &str) -> Option<String> {
+        request
+            .headers
+            .iter()
+            .find(|(name, _)| name.as_str() == header)
+            .map(|(_, value)| value.clone().into_inner())
+    }
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_large_connector_request_is_gzipped() {
+        let description = "a".repeat(2048);
+        let expected_body = serde_json::to_vec(&json!({
+            "amount": 6540,
+            "description": description.clone(),
+        }))
+        .unwrap();
+
+        let request = compress_connector_request_if_required(
+            get_json_request(description),
+            "adyen",
+            &get_compression_config(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_header_value(&request, headers::CONTENT_ENCODING),
+            Some("gzip".to_string())
+        );
+        assert_eq!(
+            get_header_value(&request, headers::CONTENT_TYPE),
+            Some("application/json".to_string())
+        );
+        let Some(RequestContent::RawBytes(compressed_body)) = request.body else {
+            panic!("Expected the request body to be gzip encoded raw bytes");
+        };
+        assert!(compressed_body.len() < expected_body.len());
+
+        let mut decompressed_body = Vec::new();
+        GzDecoder::new(compressed_body.as_slice())
+            .read_to_end(&mut decompressed_body)
+            .unwrap();
+        assert_eq!(decompressed_body, expected_body);
+    }
+
+    #[test]
+    fn test_small_connector_request_is_not_gzipped() {
+        let request = compress_connector_request_if_required(
+            get_json_request("small".to_string()),
+            "adyen",
+            &get_compression_config(),
+        )
+        .unwrap();
+
+        assert_eq!(get_header_value(&request, headers::CONTENT_ENCODING), None);
+        assert!(matches!(request.body, Some(RequestContent::Json(_))));
+    }
+
+    #[test]
+    fn test_connector_request_is_not_gzipped_when_not_enabled_for_connector() {
+        let request = compress_connector_request_if_required(
+            get_json_request("a".repeat(2048)),
+            "stripe",
+            &get_compression_config(),
+        )
+        .unwrap();
+
+        assert_eq!(get_header_value(&request, headers::CONTENT_ENCODING), None);
+        assert!(matches!(request.body, Some(RequestContent::Json(_))));
+    }
+
+    fn get_attempt_id_header_config() -> ConnectorAttemptIdHeaderConfig {
+        ConnectorAttemptIdHeaderConfig {
+            connectors: HashMap::from([("adyen".to_string(), "X-Merchant-Reference".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_attempt_id_is_sent_to_connector_which_accepts_it() {
+        let request = add_attempt_id_header_if_required(
+            get_json_request("small".to_string()),
+            "adyen",
+            "pay_123_1",
+            &get_attempt_id_header_config(),
+        );
+
+        assert_eq!(
+            get_header_value(&request, "X-Merchant-Reference"),
+            Some("pay_123_1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_placeholder_attempt_id_is_not_sent_to_connector() {
+        for attempt_id in ["", "irrelevant_attempt_id_in_dispute_flow"] {
+            let request = add_attempt_id_header_if_required(
+                get_json_request("small".to_string()),
+                "adyen",
+                attempt_id,
+                &get_attempt_id_header_config(),
+            );
+
+            assert!(request.headers.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_attempt_id_is_not_sent_to_other_connectors() {
+        let request = add_attempt_id_header_if_required(
+            get_json_request("small".to_string()),
+            "stripe",
+            "pay_123_1",
+            &get_attempt_id_header_config(),
+        );
+
+        assert!(request.headers.is_empty());
+    }
 }
@@ -238,6 +238,11 @@ where
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
+/// Authenticates API keys which are permitted to perform operations requiring the given
+/// permission. API keys without any permissions configured are always accepted.
+#[derive(Debug)]
+pub struct ScopedApiKeyAuth(pub Permission);
+
 pub struct NoAuth;
 
 #[async_trait]
@@ -264,67 +269,97 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("API key is empty");
-        }
+        authenticate_api_key(request_headers, state, None).await
+    }
+}
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ScopedApiKeyAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        authenticate_api_key(request_headers, state, Some(self.0)).await
+    }
+}
 
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
-        }
+async fn authenticate_api_key<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    required_permission: Option<Permission>,
+) -> RouterResult<(AuthenticationData, AuthenticationType)>
+where
+    A: AppStateInfo + Sync,
+{
+    let api_key = get_api_key(request_headers)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?
+        .trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized).attach_printable("API key is empty");
+    }
 
-        let key_store = state
-            .store()
-            .get_merchant_key_store_by_merchant_id(
-                &stored_api_key.merchant_id,
-                &state.store().get_master_key().to_vec().into(),
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        config.api_keys.get_inner().get_hash_key()?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
-        let merchant = state
-            .store()
-            .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if api_keys::is_api_key_expired(stored_api_key.expires_at, date_time::now()) {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
 
-        let auth = AuthenticationData {
-            merchant_account: merchant,
-            key_store,
-        };
-        Ok((
-            auth.clone(),
-            AuthenticationType::ApiKey {
-                merchant_id: auth.merchant_account.merchant_id.clone(),
-                key_id: stored_api_key.key_id,
-            },
-        ))
+    if !api_keys::is_api_key_permitted(stored_api_key.permissions.as_deref(), required_permission) {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: required_permission.map_or_else(
+                || "this operation".to_string(),
+                |permission| permission.to_string()
+            ),
+        }))
+        .attach_printable("API key is not permitted to perform this operation");
     }
+
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            &stored_api_key.merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    let auth = AuthenticationData {
+        merchant_account: merchant,
+        key_store,
+    };
+    Ok((
+        auth.clone(),
+        AuthenticationType::ApiKey {
+            merchant_id: auth.merchant_account.merchant_id.clone(),
+            key_id: stored_api_key.key_id,
+        },
+    ))
 }
 
 #[cfg(feature = "olap")]
@@ -1001,6 +1036,25 @@ pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
 }
 
+/// Same as [`get_auth_type_and_flow`], with API keys required to have the permission of the route
+pub fn get_scoped_auth_type_and_flow<A: AppStateInfo + Sync>(
+    headers: &HeaderMap,
+    required_permission: Permission,
+) -> RouterResult<(
+    Box<dyn AuthenticateAndFetch<AuthenticationData, A>>,
+    api::AuthFlow,
+)> {
+    let api_key = get_api_key(headers)?;
+
+    if api_key.starts_with("pk_") {
+        return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
+    }
+    Ok((
+        Box::new(ScopedApiKeyAuth(required_permission)),
+        api::AuthFlow::Merchant,
+    ))
+}
+
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
@@ -1034,6 +1088,28 @@ where
     Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
 }
 
+pub fn check_client_secret_and_get_scoped_auth<T>(
+    headers: &HeaderMap,
+    payload: &impl ClientSecretFetch,
+    required_permission: Permission,
+) -> RouterResult<(
+    Box<dyn AuthenticateAndFetch<AuthenticationData, T>>,
+    api::AuthFlow,
+)>
+where
+    T: AppStateInfo,
+    ApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+    ScopedApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+    PublishableKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+{
+    let (auth, auth_flow) = check_client_secret_and_get_auth(headers, payload)?;
+
+    match auth_flow {
+        api::AuthFlow::Merchant => Ok((Box::new(ScopedApiKeyAuth(required_permission)), auth_flow)),
+        api::AuthFlow::Client => Ok((auth, auth_flow)),
+    }
+}
+
 pub async fn is_ephemeral_auth<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
     db: &dyn StorageInterface,
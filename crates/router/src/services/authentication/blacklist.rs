@@ -11,7 +11,10 @@ use super::SinglePurposeToken;
 #[cfg(feature = "email")]
 use crate::consts::{EMAIL_TOKEN_BLACKLIST_PREFIX, EMAIL_TOKEN_TIME_IN_SECS};
 use crate::{
-    consts::{JWT_TOKEN_TIME_IN_SECS, ROLE_BLACKLIST_PREFIX, USER_BLACKLIST_PREFIX},
+    consts::{
+        JWT_TOKEN_TIME_IN_SECS, ROLE_BLACKLIST_PREFIX, USER_BLACKLIST_PREFIX,
+        USER_MERCHANT_BLACKLIST_PREFIX,
+    },
     core::errors::{ApiErrorResponse, RouterResult},
     routes::app::AppStateInfo,
 };
@@ -38,6 +41,28 @@ pub async fn insert_user_in_blacklist(state: &AppState, user_id: &str) -> UserRe
         .change_context(UserErrors::InternalServerError)
 }
 
+/// Revokes the tokens of the user for the merchant only, the sessions of the user in other
+/// merchants are not affected
+#[cfg(feature = "olap")]
+pub async fn insert_user_merchant_in_blacklist(
+    state: &AppState,
+    user_id: &str,
+    merchant_id: &str,
+) -> UserResult<()> {
+    let user_merchant_blacklist_key = get_user_merchant_blacklist_key(user_id, merchant_id);
+    let expiry =
+        expiry_to_i64(JWT_TOKEN_TIME_IN_SECS).change_context(UserErrors::InternalServerError)?;
+    let redis_conn = get_redis_connection(state).change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_key_with_expiry(
+            user_merchant_blacklist_key.as_str(),
+            date_time::now_unix_timestamp(),
+            expiry,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
 #[cfg(feature = "olap")]
 pub async fn insert_role_in_blacklist(state: &AppState, role_id: &str) -> UserResult<()> {
     let role_blacklist_key = format!("{}{}", ROLE_BLACKLIST_PREFIX, role_id);
@@ -73,13 +98,27 @@ pub async fn check_user_in_blacklist<A: AppStateInfo>(
     token_expiry: u64,
 ) -> RouterResult<bool> {
     let token = format!("{}{}", USER_BLACKLIST_PREFIX, user_id);
-    let token_issued_at = expiry_to_i64(token_expiry - JWT_TOKEN_TIME_IN_SECS)?;
     let redis_conn = get_redis_connection(state)?;
-    redis_conn
+    let blacklisted_at = redis_conn
         .get_key::<Option<i64>>(token.as_str())
         .await
-        .change_context(ApiErrorResponse::InternalServerError)
-        .map(|timestamp| timestamp.map_or(false, |timestamp| timestamp > token_issued_at))
+        .change_context(ApiErrorResponse::InternalServerError)?;
+    is_token_issued_before_blacklisting(blacklisted_at, token_expiry)
+}
+
+pub async fn check_user_merchant_in_blacklist<A: AppStateInfo>(
+    state: &A,
+    user_id: &str,
+    merchant_id: &str,
+    token_expiry: u64,
+) -> RouterResult<bool> {
+    let token = get_user_merchant_blacklist_key(user_id, merchant_id);
+    let redis_conn = get_redis_connection(state)?;
+    let blacklisted_at = redis_conn
+        .get_key::<Option<i64>>(token.as_str())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)?;
+    is_token_issued_before_blacklisting(blacklisted_at, token_expiry)
 }
 
 pub async fn check_role_in_blacklist<A: AppStateInfo>(
@@ -88,13 +127,12 @@ pub async fn check_role_in_blacklist<A: AppStateInfo>(
     token_expiry: u64,
 ) -> RouterResult<bool> {
     let token = format!("{}{}", ROLE_BLACKLIST_PREFIX, role_id);
-    let token_issued_at = expiry_to_i64(token_expiry - JWT_TOKEN_TIME_IN_SECS)?;
     let redis_conn = get_redis_connection(state)?;
-    redis_conn
+    let blacklisted_at = redis_conn
         .get_key::<Option<i64>>(token.as_str())
         .await
-        .change_context(ApiErrorResponse::InternalServerError)
-        .map(|timestamp| timestamp.map_or(false, |timestamp| timestamp > token_issued_at))
+        .change_context(ApiErrorResponse::InternalServerError)?;
+    is_token_issued_before_blacklisting(blacklisted_at, token_expiry)
 }
 
 #[cfg(feature = "email")]
@@ -132,10 +170,27 @@ fn get_redis_connection<A: AppStateInfo>(state: &A) -> RouterResult<Arc<RedisCon
         .attach_printable("Failed to get redis connection")
 }
 
+fn get_user_merchant_blacklist_key(user_id: &str, merchant_id: &str) -> String {
+    format!(
+        "{}{}_{}",
+        USER_MERCHANT_BLACKLIST_PREFIX, user_id, merchant_id
+    )
+}
+
 fn expiry_to_i64(expiry: u64) -> RouterResult<i64> {
     i64::try_from(expiry).change_context(ApiErrorResponse::InternalServerError)
 }
 
+/// Tokens issued in the same second as the blacklisting are still accepted, so that a token
+/// reissued right after revoking a user's sessions (e.g. on password change) stays valid.
+fn is_token_issued_before_blacklisting(
+    blacklisted_at: Option<i64>,
+    token_expiry: u64,
+) -> RouterResult<bool> {
+    let token_issued_at = expiry_to_i64(token_expiry - JWT_TOKEN_TIME_IN_SECS)?;
+    Ok(blacklisted_at.map_or(false, |blacklisted_at| blacklisted_at > token_issued_at))
+}
+
 #[async_trait::async_trait]
 pub trait BlackList {
     async fn check_in_blacklist<A>(&self, state: &A) -> RouterResult<bool>
@@ -151,6 +206,13 @@ impl BlackList for AuthToken {
     {
         Ok(
             check_user_in_blacklist(state, &self.user_id, self.exp).await?
+                || check_user_merchant_in_blacklist(
+                    state,
+                    &self.user_id,
+                    &self.merchant_id,
+                    self.exp,
+                )
+                .await?
                 || check_role_in_blacklist(state, &self.role_id, self.exp).await?,
         )
     }
@@ -166,3 +228,124 @@ impl BlackList for SinglePurposeToken {
         check_user_in_blacklist(state, &self.user_id, self.exp).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const SESSION_STARTED_AT: u64 = 1_716_800_000;
+    const PASSWORD_CHANGED_AT: i64 = 1_716_800_600;
+
+    fn token_expiry(issued_at: u64) -> u64 {
+        issued_at + JWT_TOKEN_TIME_IN_SECS
+    }
+
+    #[test]
+    fn test_token_is_valid_when_user_is_not_blacklisted() {
+        let expiry = token_expiry(SESSION_STARTED_AT);
+        assert!(!is_token_issued_before_blacklisting(None, expiry).unwrap());
+    }
+
+    #[test]
+    fn test_other_sessions_are_rejected_after_password_change() {
+        let first_session = token_expiry(SESSION_STARTED_AT);
+        let second_session = token_expiry(SESSION_STARTED_AT + 60);
+
+        assert!(
+            is_token_issued_before_blacklisting(Some(PASSWORD_CHANGED_AT), first_session).unwrap()
+        );
+        assert!(
+            is_token_issued_before_blacklisting(Some(PASSWORD_CHANGED_AT), second_session).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_token_reissued_on_password_change_is_accepted() {
+        let reissued_at = u64::try_from(PASSWORD_CHANGED_AT).unwrap();
+
+        assert!(!is_token_issued_before_blacklisting(
+            Some(PASSWORD_CHANGED_AT),
+            token_expiry(reissued_at)
+        )
+        .unwrap());
+        assert!(!is_token_issued_before_blacklisting(
+            Some(PASSWORD_CHANGED_AT),
+            token_expiry(reissued_at + 1)
+        )
+        .unwrap());
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_app_state() -> AppState {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        Box::pin(AppState::with_storage(
+            crate::configs::settings::Settings::default(),
+            crate::db::StorageImpl::Mock,
+            tx,
+            Box::new(crate::services::MockApiClient),
+        ))
+        .await
+    }
+
+    #[cfg(feature = "olap")]
+    fn get_auth_token(user_id: &str, merchant_id: &str, issued_at: u64) -> AuthToken {
+        AuthToken {
+            user_id: user_id.to_string(),
+            merchant_id: merchant_id.to_string(),
+            role_id: format!("role_{}", uuid::Uuid::new_v4()),
+            exp: token_expiry(issued_at),
+            org_id: "org_1".to_string(),
+        }
+    }
+
+    #[cfg(feature = "olap")]
+    fn now_unix_timestamp() -> u64 {
+        u64::try_from(date_time::now_unix_timestamp()).unwrap()
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_second_session_token_is_rejected_after_password_change() {
+        let state = get_app_state().await;
+        let user_id = format!("user_{}", uuid::Uuid::new_v4());
+        let other_user_id = format!("user_{}", uuid::Uuid::new_v4());
+
+        let first_session = get_auth_token(&user_id, "merchant_1", now_unix_timestamp() - 120);
+        let second_session = get_auth_token(&user_id, "merchant_1", now_unix_timestamp() - 60);
+        let other_user_session =
+            get_auth_token(&other_user_id, "merchant_1", now_unix_timestamp() - 60);
+        assert!(!second_session.check_in_blacklist(&state).await.unwrap());
+
+        // Changing the password revokes all the sessions of the user
+        insert_user_in_blacklist(&state, &user_id).await.unwrap();
+        let reissued_session = get_auth_token(&user_id, "merchant_1", now_unix_timestamp());
+
+        assert!(first_session.check_in_blacklist(&state).await.unwrap());
+        assert!(second_session.check_in_blacklist(&state).await.unwrap());
+        assert!(!reissued_session.check_in_blacklist(&state).await.unwrap());
+        assert!(!other_user_session.check_in_blacklist(&state).await.unwrap());
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_force_signout_is_scoped_to_the_merchant() {
+        let state = get_app_state().await;
+        let user_id = format!("user_{}", uuid::Uuid::new_v4());
+
+        let merchant_session = get_auth_token(&user_id, "merchant_1", now_unix_timestamp() - 60);
+        let other_merchant_session =
+            get_auth_token(&user_id, "merchant_2", now_unix_timestamp() - 60);
+
+        insert_user_merchant_in_blacklist(&state, &user_id, "merchant_1")
+            .await
+            .unwrap();
+
+        assert!(merchant_session.check_in_blacklist(&state).await.unwrap());
+        assert!(!other_merchant_session
+            .check_in_blacklist(&state)
+            .await
+            .unwrap());
+    }
+}
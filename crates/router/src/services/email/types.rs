@@ -56,6 +56,11 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    PaymentRejectedNotification {
+        merchant_name: String,
+        payment_id: String,
+        amount: String,
+    },
 }
 
 pub mod html {
@@ -143,6 +148,20 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::PaymentRejectedNotification {
+                merchant_name,
+                payment_id,
+                amount,
+            } => format!(
+                "Hello,
+
+Your payment of {amount} to {merchant_name} could not be completed and has been declined.
+Payment ID : {payment_id}
+
+No amount has been charged for this payment. Please reach out to {merchant_name} for any further details.
+
+(note: This is an auto generated email, please do not reply to this email)",
+            ),
         }
     }
 }
@@ -500,3 +519,28 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct PaymentRejectedNotification {
+    pub recipient_email: pii::Email,
+    pub subject: &'static str,
+    pub merchant_name: String,
+    pub payment_id: String,
+    pub amount: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentRejectedNotification {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::PaymentRejectedNotification {
+            merchant_name: self.merchant_name.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
@@ -51,6 +51,7 @@ pub struct KafkaPaymentAttempt<'a> {
     pub unified_code: Option<&'a String>,
     pub unified_message: Option<&'a String>,
     pub mandate_data: Option<&'a MandateDetails>,
+    pub connector_latency: Option<i64>,
 }
 
 impl<'a> KafkaPaymentAttempt<'a> {
@@ -95,6 +96,7 @@ impl<'a> KafkaPaymentAttempt<'a> {
             unified_code: attempt.unified_code.as_ref(),
             unified_message: attempt.unified_message.as_ref(),
             mandate_data: attempt.mandate_data.as_ref(),
+            connector_latency: attempt.connector_latency,
         }
     }
 }
@@ -125,6 +125,11 @@ pub type MandateRevokeType = dyn services::ConnectorIntegration<
     MandateRevokeRequestData,
     MandateRevokeResponseData,
 >;
+pub type PaymentMethodRetrieveType = dyn services::ConnectorIntegration<
+    api::PaymentMethodRetrieve,
+    PaymentMethodRetrieveRequestData,
+    PaymentMethodRetrieveResponseData,
+>;
 pub type PaymentsPreProcessingType = dyn services::ConnectorIntegration<
     api::PreProcessing,
     PaymentsPreProcessingData,
@@ -260,6 +265,12 @@ pub type DefendDisputeRouterData =
 pub type MandateRevokeRouterData =
     RouterData<api::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
 
+pub type PaymentMethodRetrieveRouterData = RouterData<
+    api::PaymentMethodRetrieve,
+    PaymentMethodRetrieveRequestData,
+    PaymentMethodRetrieveResponseData,
+>;
+
 #[cfg(feature = "payouts")]
 pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
 
@@ -398,6 +409,7 @@ pub struct PayoutsData {
     pub entity_type: storage_enums::PayoutEntityType,
     pub customer_details: Option<CustomerDetails>,
     pub vendor_details: Option<api_models::payouts::PayoutVendorAccountDetails>,
+    pub cancellation_reason: Option<String>,
 }
 
 #[cfg(feature = "payouts")]
@@ -422,6 +434,7 @@ pub struct PayoutsResponseData {
     pub status: Option<storage_enums::PayoutStatus>,
     pub connector_payout_id: String,
     pub payout_eligible: Option<bool>,
+    pub eligibility_details: Option<api_models::payouts::PayoutEligibilityDetails>,
     pub should_add_next_step_to_process_tracker: bool,
 }
 
@@ -479,6 +492,7 @@ pub struct PaymentsCaptureData {
     pub browser_info: Option<BrowserInformation>,
     pub metadata: Option<pii::SecretSerdeValue>,
     // This metadata is used to store the metadata shared during the payment intent request.
+    pub capture_reference: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1019,6 +1033,8 @@ pub struct RefundsData {
     /// Arbitrary metadata required for refund
     pub connector_metadata: Option<serde_json::Value>,
     pub browser_info: Option<BrowserInformation>,
+    /// Merchant supplied reference for the refund, for connectors that accept a refund reference
+    pub merchant_refund_reference_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -1167,12 +1183,37 @@ pub struct RetrieveFileResponse {
 pub struct PollConfig {
     pub delay_in_secs: i8,
     pub frequency: i8,
+    /// Number of poll intervals for which the poll is served before it is treated as exhausted
+    #[serde(default = "PollConfig::default_max_poll_count")]
+    pub max_poll_count: u16,
+    /// Minimum interval between two status polls, polls that come faster are rejected
+    #[serde(default = "PollConfig::default_poll_interval_secs")]
+    pub poll_interval_secs: u16,
 }
 
 impl PollConfig {
     pub fn get_poll_config_key(connector: String) -> String {
         format!("poll_config_external_three_ds_{connector}")
     }
+
+    fn default_max_poll_count() -> u16 {
+        consts::DEFAULT_MAX_POLL_COUNT
+    }
+
+    fn default_poll_interval_secs() -> u16 {
+        consts::DEFAULT_POLL_INTERVAL_IN_SECS
+    }
+
+    pub fn get_poll_ttl_in_secs(&self) -> i64 {
+        get_poll_ttl_in_secs(self.max_poll_count, self.poll_interval_secs)
+    }
+}
+
+fn get_poll_ttl_in_secs(max_poll_count: u16, poll_interval_secs: u16) -> i64 {
+    std::cmp::max(
+        consts::POLL_ID_TTL,
+        i64::from(max_poll_count) * i64::from(poll_interval_secs),
+    )
 }
 
 impl Default for PollConfig {
@@ -1180,8 +1221,66 @@ impl Default for PollConfig {
         Self {
             delay_in_secs: consts::DEFAULT_POLL_DELAY_IN_SECS,
             frequency: consts::DEFAULT_POLL_FREQUENCY,
+            max_poll_count: consts::DEFAULT_MAX_POLL_COUNT,
+            poll_interval_secs: consts::DEFAULT_POLL_INTERVAL_IN_SECS,
+        }
+    }
+}
+
+/// Details of a poll, stored next to the poll status to cap the number of polls served for it.
+/// The polls served so far are counted under a separate key, incremented atomically on every poll.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PollTracker {
+    pub payment_id: String,
+    pub max_poll_count: u16,
+    pub poll_interval_secs: u16,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+impl PollTracker {
+    pub fn new(payment_id: String, poll_config: &PollConfig) -> Self {
+        Self {
+            payment_id,
+            max_poll_count: poll_config.max_poll_count,
+            poll_interval_secs: poll_config.poll_interval_secs,
+            created_at: common_utils::date_time::now(),
         }
     }
+
+    pub fn get_poll_tracker_key(poll_id: &str) -> String {
+        format!("{poll_id}_tracker")
+    }
+
+    pub fn get_poll_count_key(poll_id: &str) -> String {
+        format!("{poll_id}_count")
+    }
+
+    /// Key holding the time of the latest poll
+    pub fn get_last_polled_at_key(poll_id: &str) -> String {
+        format!("{poll_id}_last_polled_at")
+    }
+
+    pub fn get_poll_ttl_in_secs(&self) -> i64 {
+        get_poll_ttl_in_secs(self.max_poll_count, self.poll_interval_secs)
+    }
+
+    /// The first `max_poll_count` polls are served, the poll is exhausted by the one after them
+    pub fn is_exhausted(&self, poll_count: i64) -> bool {
+        poll_count > i64::from(self.max_poll_count)
+    }
+
+    /// Time at which the client is expected to poll next, `None` once no more polls are served
+    pub fn get_next_poll_at(
+        &self,
+        poll_count: u16,
+        last_polled_at: Option<time::PrimitiveDateTime>,
+    ) -> Option<time::PrimitiveDateTime> {
+        (poll_count < self.max_poll_count).then(|| {
+            last_polled_at.unwrap_or(self.created_at)
+                + time::Duration::seconds(i64::from(self.poll_interval_secs))
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -1225,6 +1324,17 @@ pub struct MandateRevokeResponseData {
     pub mandate_status: MandateStatus,
 }
 
+#[derive(Debug, Clone)]
+pub struct PaymentMethodRetrieveRequestData {
+    /// The token referencing the payment method at the connector
+    pub connector_payment_method_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentMethodRetrieveResponseData {
+    pub connector_payment_method_id: String,
+}
+
 // Different patterns of authentication.
 #[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
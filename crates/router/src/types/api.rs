@@ -90,6 +90,18 @@ pub trait ConnectorMandateRevoke:
 {
 }
 
+#[derive(Clone, Debug)]
+pub struct PaymentMethodRetrieve;
+
+pub trait ConnectorPaymentMethodRetrieve:
+    ConnectorIntegration<
+    PaymentMethodRetrieve,
+    types::PaymentMethodRetrieveRequestData,
+    types::PaymentMethodRetrieveResponseData,
+>
+{
+}
+
 pub trait ConnectorTransactionId: ConnectorCommon + Sync {
     fn connector_transaction_id(
         &self,
@@ -181,6 +193,7 @@ pub trait Connector:
     + ConnectorVerifyWebhookSource
     + FraudCheck
     + ConnectorMandateRevoke
+    + ConnectorPaymentMethodRetrieve
     + ExternalAuthentication
 {
 }
@@ -204,6 +217,7 @@ impl<
             + ConnectorVerifyWebhookSource
             + FraudCheck
             + ConnectorMandateRevoke
+            + ConnectorPaymentMethodRetrieve
             + ExternalAuthentication,
     > Connector for T
 {
@@ -80,6 +80,12 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                 })
                 .transpose()?,
             use_billing_as_payment_method_billing: item.use_billing_as_payment_method_billing,
+            dispute_auto_accept_threshold: item
+                .dispute_auto_accept_threshold
+                .map(|dispute_auto_accept_threshold| {
+                    dispute_auto_accept_threshold.parse_value("DisputeAutoAcceptThreshold")
+                })
+                .transpose()?,
         })
     }
 }
@@ -125,6 +131,17 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             })
             .transpose()?;
 
+        let dispute_auto_accept_threshold = request
+            .dispute_auto_accept_threshold
+            .map(|dispute_auto_accept_threshold| {
+                dispute_auto_accept_threshold
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "dispute_auto_accept_threshold",
+                    })
+            })
+            .transpose()?;
+
         Ok(Self {
             profile_id,
             merchant_id: merchant_account.merchant_id,
@@ -184,6 +201,7 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(Some(true)),
             collect_shipping_details_from_wallet_connector: request
                 .collect_shipping_details_from_wallet_connector,
+            dispute_auto_accept_threshold,
         })
     }
 }
@@ -1,5 +1,8 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerAddressDeleteResponse, CustomerAddressId, CustomerAddressRequest,
+    CustomerAddressResponse, CustomerDeleteResponse, CustomerId, CustomerRequest,
+};
 use serde::Serialize;
 
 use super::payments;
@@ -13,6 +13,12 @@ pub struct FileId {
     pub file_id: String,
 }
 
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct FileDownloadRequest {
+    pub file_id: String,
+    pub expires_in: Option<u32>,
+}
+
 #[derive(Debug)]
 pub enum FileDataRequired {
     Required,
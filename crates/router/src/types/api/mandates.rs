@@ -1,5 +1,7 @@
 use api_models::mandates;
-pub use api_models::mandates::{MandateId, MandateResponse, MandateRevokedResponse};
+pub use api_models::mandates::{
+    CustomerMandateListConstraints, MandateId, MandateResponse, MandateRevokedResponse,
+};
 use common_utils::ext_traits::OptionExt;
 use error_stack::ResultExt;
 use masking::PeekInterface;
@@ -105,6 +107,10 @@ impl MandateResponseExt for MandateResponse {
             payment_method: pm.to_string(),
             payment_method_type,
             payment_method_id: mandate.payment_method_id,
+            max_total_amount: mandate.max_total_amount,
+            max_debit_count: mandate.max_debit_count,
+            debit_count: mandate.debit_count,
+            debited_amount: mandate.debited_amount,
         })
     }
 }
@@ -4,3 +4,8 @@ use serde;
 pub struct PollId {
     pub poll_id: String,
 }
+
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PollProgressRequest {
+    pub payment_id: String,
+}
@@ -1,6 +1,7 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundApprovalDecision, RefundApprovalRequest, RefundEligibilityRequest,
+    RefundEligibilityResponse, RefundIneligibilityReason, RefundRequest, RefundResponse,
+    RefundStatus, RefundType, RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 use super::ConnectorCommon;
@@ -16,6 +17,7 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
             | storage_enums::RefundStatus::TransactionFailure => Self::Failed,
             storage_enums::RefundStatus::ManualReview => Self::Review,
             storage_enums::RefundStatus::Pending => Self::Pending,
+            storage_enums::RefundStatus::PendingApproval => Self::PendingApproval,
             storage_enums::RefundStatus::Success => Self::Succeeded,
         }
     }
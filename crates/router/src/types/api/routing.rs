@@ -4,8 +4,8 @@ pub use api_models::{
     enums as api_enums,
     routing::{
         ConnectorVolumeSplit, RoutableConnectorChoice, RoutingAlgorithm, RoutingAlgorithmKind,
-        RoutingAlgorithmRef, RoutingConfigRequest, RoutingDictionary, RoutingDictionaryRecord,
-        StraightThroughAlgorithm,
+        RoutingAlgorithmRef, RoutingConfigRequest, RoutingDecisionSource, RoutingDictionary,
+        RoutingDictionaryRecord, RoutingExplanation, StraightThroughAlgorithm,
     },
 };
 
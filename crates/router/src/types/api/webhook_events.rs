@@ -2,5 +2,5 @@ pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
     EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
     OutgoingWebhookResponseContent, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    WebhookDeliveryRetryRequestInternal, WebhookEventReplayRequestInternal,
 };
@@ -241,6 +241,16 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
     }
 
+    /// Splits the webhook body into the bodies of the individual events it carries. Connectors
+    /// that deliver several events in one request return one body per event, each of which is
+    /// then verified and processed as a webhook of its own.
+    fn get_webhook_event_bodies(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<Vec<u8>>, errors::ConnectorError> {
+        Ok(vec![request.body.to_vec()])
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
@@ -256,6 +266,23 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError>;
 
+    fn get_webhook_timestamp(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// Returns the mandate reference carried by the webhook. Connectors that return the mandate
+    /// reference only in a later webhook rather than in the authorize response implement this, so
+    /// that the stored mandate can be backfilled.
+    fn get_webhook_mandate_reference(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<types::MandateReference>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
@@ -11,7 +11,10 @@ use diesel_models::{
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 
-use super::{behaviour, types::TypeEncryption};
+use super::{
+    behaviour,
+    types::{self, TypeEncryption},
+};
 #[derive(Clone, Debug)]
 pub struct MerchantConnectorAccount {
     pub id: Option<i32>,
@@ -36,6 +39,9 @@ pub struct MerchantConnectorAccount {
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: enums::ConnectorStatus,
+    pub connector_wallets_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    pub applepay_verified_at: Option<time::PrimitiveDateTime>,
+    pub applepay_verification_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug)]
@@ -56,6 +62,9 @@ pub enum MerchantConnectorAccountUpdate {
         pm_auth_config: Option<serde_json::Value>,
         connector_label: Option<String>,
         status: Option<enums::ConnectorStatus>,
+        connector_wallets_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        applepay_verified_at: Option<time::PrimitiveDateTime>,
+        applepay_verification_expires_at: Option<time::PrimitiveDateTime>,
     },
 }
 
@@ -92,6 +101,9 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 applepay_verified_domains: self.applepay_verified_domains,
                 pm_auth_config: self.pm_auth_config,
                 status: self.status,
+                connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
+                applepay_verified_at: self.applepay_verified_at,
+                applepay_verification_expires_at: self.applepay_verification_expires_at,
             },
         )
     }
@@ -132,6 +144,13 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             applepay_verified_domains: other.applepay_verified_domains,
             pm_auth_config: other.pm_auth_config,
             status: other.status,
+            connector_wallets_details: types::decrypt(other.connector_wallets_details, key.peek())
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting connector wallets details".to_string(),
+                })?,
+            applepay_verified_at: other.applepay_verified_at,
+            applepay_verification_expires_at: other.applepay_verification_expires_at,
         })
     }
 
@@ -160,6 +179,9 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             applepay_verified_domains: self.applepay_verified_domains,
             pm_auth_config: self.pm_auth_config,
             status: self.status,
+            connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
+            applepay_verified_at: self.applepay_verified_at,
+            applepay_verification_expires_at: self.applepay_verification_expires_at,
         })
     }
 }
@@ -183,6 +205,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config,
                 connector_label,
                 status,
+                connector_wallets_details,
+                applepay_verified_at,
+                applepay_verification_expires_at,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -201,6 +226,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config,
                 connector_label,
                 status,
+                connector_wallets_details: connector_wallets_details.map(Encryption::from),
+                applepay_verified_at,
+                applepay_verification_expires_at,
             },
         }
     }
@@ -297,6 +297,7 @@ impl From<UserMerchantCreateRequestWithToken> for NewUserOrganization {
         Self(diesel_org::OrganizationNew {
             org_id: value.2.org_id,
             org_name: Some(value.1.company_name),
+            parent_organization_id: None,
         })
     }
 }
@@ -30,6 +30,7 @@ pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod recon_entry;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
@@ -60,8 +61,8 @@ pub use self::{
     configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
     file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    process_tracker::*, recon_entry::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
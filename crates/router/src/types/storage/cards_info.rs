@@ -1 +1 @@
-pub use diesel_models::cards_info::CardInfo;
+pub use diesel_models::cards_info::{CardInfo, CardInfoUpdateInternal};
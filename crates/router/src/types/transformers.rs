@@ -17,7 +17,7 @@ use masking::{ExposeInterface, PeekInterface};
 
 use super::domain;
 use crate::{
-    core::errors,
+    core::{errors, gsm, payments::types as payments_types},
     services::authentication::get_header_value_by_key,
     types::{
         api::{self as api_types, routing as routing_types},
@@ -315,6 +315,8 @@ impl ForeignFrom<storage_enums::MandateAmountData> for payments::MandateAmountDa
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_total_amount: from.max_total_amount,
+            max_debit_count: from.max_debit_count,
         }
     }
 }
@@ -351,6 +353,8 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_total_amount: i.max_total_amount,
+                            max_debit_count: i.max_debit_count,
                         },
                     ))
                 }
@@ -362,6 +366,8 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_total_amount: i.max_total_amount,
+                            max_debit_count: i.max_debit_count,
                         },
                     )
                 }
@@ -382,6 +388,8 @@ impl ForeignFrom<payments::MandateAmountData> for storage_enums::MandateAmountDa
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_total_amount: from.max_total_amount,
+            max_debit_count: from.max_debit_count,
         }
     }
 }
@@ -542,6 +550,7 @@ impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventTyp
             storage_enums::RefundStatus::Failure => Some(storage_enums::EventType::RefundFailed),
             api_enums::RefundStatus::ManualReview
             | api_enums::RefundStatus::Pending
+            | api_enums::RefundStatus::PendingApproval
             | api_enums::RefundStatus::TransactionFailure => None,
         }
     }
@@ -566,7 +575,9 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
         match value {
             storage_enums::MandateStatus::Active => Some(storage_enums::EventType::MandateActive),
             storage_enums::MandateStatus::Revoked => Some(storage_enums::EventType::MandateRevoked),
-            storage_enums::MandateStatus::Inactive | storage_enums::MandateStatus::Pending => None,
+            storage_enums::MandateStatus::Inactive
+            | storage_enums::MandateStatus::Pending
+            | storage_enums::MandateStatus::Paused => None,
         }
     }
 }
@@ -689,6 +700,7 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key.permissions,
         }
     }
 }
@@ -703,6 +715,7 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key.permissions,
         }
     }
 }
@@ -902,12 +915,31 @@ impl TryFrom<domain::MerchantConnectorAccount> for api_models::admin::MerchantCo
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
             status: item.status,
+            // The certificates and keys are never returned, only the wallets that are configured
+            connector_wallets_details: item
+                .connector_wallets_details
+                .map(|connector_wallets_details| {
+                    connector_wallets_details
+                        .into_inner()
+                        .parse_value::<api_models::admin::ConnectorWalletDetails>(
+                            "ConnectorWalletDetails",
+                        )
+                        .and_then(|connector_wallets_details| {
+                            masking::masked_serialize(&connector_wallets_details)
+                                .change_context(errors::ParsingError::EncodeError("json"))
+                        })
+                        .map(masking::Secret::new)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Unable to mask connector_wallets_details")
+                })
+                .transpose()?,
         })
     }
 }
 
 impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
     fn foreign_from(payment_attempt: storage::PaymentAttempt) -> Self {
+        let surcharge_breakdown = payments_types::get_attempt_surcharge_breakdown(&payment_attempt);
         Self {
             attempt_id: payment_attempt.attempt_id,
             status: payment_attempt.status,
@@ -929,6 +961,8 @@ impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
             reference_id: payment_attempt.connector_response_reference_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            surcharge_breakdown,
+            connector_latency: payment_attempt.connector_latency,
         }
     }
 }
@@ -1129,6 +1163,19 @@ impl ForeignFrom<api_models::organization::OrganizationNew>
         Self {
             org_id: item.org_id,
             org_name: item.org_name,
+            parent_organization_id: None,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::organization::Organization>
+    for api_models::organization::OrganizationResponse
+{
+    fn foreign_from(item: diesel_models::organization::Organization) -> Self {
+        Self {
+            org_id: item.org_id,
+            org_name: item.org_name,
+            parent_organization_id: item.parent_organization_id,
         }
     }
 }
@@ -1147,12 +1194,17 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            priority: value.priority,
         }
     }
 }
 
 impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
     fn foreign_from(value: storage::GatewayStatusMap) -> Self {
+        let gsm_api_types::GsmRetryMetadata {
+            retry_reason,
+            suggested_action,
+        } = gsm::get_gsm_retry_metadata(Some(&value));
         Self {
             connector: value.connector.to_string(),
             flow: value.flow,
@@ -1165,6 +1217,9 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            priority: value.priority,
+            retry_reason,
+            suggested_action,
         }
     }
 }
@@ -1200,13 +1255,14 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
             && (item.created_after.is_some()
                 || item.created_before.is_some()
                 || item.limit.is_some()
-                || item.offset.is_some())
+                || item.offset.is_some()
+                || item.delivery_status.is_some())
         {
             return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message:
-                    "Either only `object_id` must be specified, or one or more of \
-                          `created_after`, `created_before`, `limit` and `offset` must be specified"
-                        .to_string()
+                message: "Either only `object_id` must be specified, or one or more of \
+                          `created_after`, `created_before`, `limit`, `offset` and \
+                          `delivery_status` must be specified"
+                    .to_string()
             }));
         }
 
@@ -1217,6 +1273,7 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
                 created_before: item.created_before,
                 limit: item.limit.map(i64::from),
                 offset: item.offset.map(i64::from),
+                delivery_status: item.delivery_status,
             }),
         }
     }
@@ -825,7 +825,7 @@ pub async fn trigger_payments_webhook<F, Op>(
     merchant_account: domain::MerchantAccount,
     business_profile: diesel_models::business_profile::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
-    payment_data: crate::core::payments::PaymentData<F>,
+    mut payment_data: crate::core::payments::PaymentData<F>,
     customer: Option<domain::Customer>,
     state: &crate::routes::AppState,
     operation: Op,
@@ -854,6 +854,12 @@ where
             | enums::IntentStatus::PartiallyCaptured
             | enums::IntentStatus::RequiresMerchantAction
     ) {
+        payment_data.customer_decline_message =
+            crate::core::payments::helpers::get_customer_decline_message_for_attempt(
+                &state.conf.customer_decline_messages,
+                &payment_data.payment_attempt,
+            );
+
         let payments_response = crate::core::payments::transformers::payments_to_payments_response(
             payment_data,
             captures,
@@ -1,9 +1,11 @@
-use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap, future::Future, ops::Deref, str::FromStr, sync::Arc, time::Duration,
+};
 
 use api_models::enums;
 use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
@@ -13,7 +15,7 @@ use tokio::{sync::RwLock, time::sleep};
 
 use crate::{
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexRateSource},
     services, AppState,
 };
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
@@ -45,6 +47,8 @@ pub enum ForexCacheError {
     ApiUnresponsive,
     #[error("Conversion error")]
     ConversionError,
+    #[error("Exchange rates are stale")]
+    StaleRates,
     #[error("Could not acquire the lock for cache entry")]
     CouldNotAcquireLock,
     #[error("Provided currency not acceptable")]
@@ -76,11 +80,23 @@ pub enum ForexCacheError {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct ForexResponse {
     pub rates: HashMap<String, FloatDecimal>,
+    /// Time (unix timestamp) at which the rates were published
+    pub timestamp: Option<i64>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct FallbackForexResponse {
     pub quotes: HashMap<String, FloatDecimal>,
+    /// Time (unix timestamp) at which the rates were published
+    pub timestamp: Option<i64>,
+}
+
+/// Exchange rates fetched from a rate source
+#[derive(Debug, Clone)]
+struct ForexRateSourceResponse {
+    rates: FxExchangeRatesCacheEntry,
+    /// Time (unix timestamp) at which the source published the rates, if provided by the source
+    published_at: Option<i64>,
 }
 
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
@@ -219,25 +235,28 @@ async fn successive_fetch_and_save_forex(
     state: &AppState,
     stale_redis_data: Option<FxExchangeRatesCacheEntry>,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    let forex_api = state.conf.forex_api.get_inner();
+    // Stale data is only used when no rate source is available, as long as it is not too old
+    let stale_redis_data =
+        stale_redis_data.filter(|rates| !rates.is_expired(forex_api.max_rate_staleness_in_secs));
+
     match acquire_redis_lock(state).await {
         Ok(lock_acquired) => {
             if !lock_acquired {
                 return stale_redis_data.ok_or(ForexCacheError::CouldNotAcquireLock.into());
             }
-            let api_rates = fetch_forex_rates(state).await;
+            let api_rates = fetch_forex_rates_with_failover(
+                &forex_api.rate_sources,
+                forex_api.max_rate_staleness_in_secs,
+                date_time::now_unix_timestamp(),
+                |rate_source| fetch_forex_rates_from_source(state, rate_source),
+            )
+            .await;
             match api_rates {
                 Ok(rates) => successive_save_data_to_redis_local(state, rates).await,
                 Err(err) => {
-                    // API not able to fetch data call secondary service
                     logger::error!(?err);
-                    let secondary_api_rates = fallback_fetch_forex_rates(state).await;
-                    match secondary_api_rates {
-                        Ok(rates) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-                        Err(err) => stale_redis_data.ok_or({
-                            logger::error!(?err);
-                            ForexCacheError::ApiUnresponsive.into()
-                        }),
-                    }
+                    stale_redis_data.ok_or(err)
                 }
             }
         }
@@ -315,9 +334,57 @@ async fn handler_local_expired(
     }
 }
 
+/// Fetches the exchange rates from the rate sources in order of preference, failing over to the
+/// next source when a source fails or responds with rates older than `max_rate_staleness_in_secs`
+async fn fetch_forex_rates_with_failover<F, Fut>(
+    rate_sources: &[ForexRateSource],
+    max_rate_staleness_in_secs: i64,
+    current_time: i64,
+    fetch_from_source: F,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError>
+where
+    F: Fn(ForexRateSource) -> Fut,
+    Fut: Future<Output = CustomResult<ForexRateSourceResponse, ForexCacheError>>,
+{
+    let mut last_error = None;
+
+    for rate_source in rate_sources {
+        match fetch_from_source(*rate_source).await {
+            Ok(response)
+                if response.published_at.map_or(false, |published_at| {
+                    published_at.saturating_add(max_rate_staleness_in_secs) < current_time
+                }) =>
+            {
+                logger::warn!(
+                    ?rate_source,
+                    "Rate source responded with stale exchange rates"
+                );
+                last_error = Some(report!(ForexCacheError::StaleRates));
+            }
+            Ok(response) => return Ok(response.rates),
+            Err(err) => {
+                logger::error!(?rate_source, ?err);
+                last_error = Some(err);
+            }
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| report!(ForexCacheError::ApiUnresponsive)))
+}
+
+async fn fetch_forex_rates_from_source(
+    state: &AppState,
+    rate_source: ForexRateSource,
+) -> CustomResult<ForexRateSourceResponse, ForexCacheError> {
+    match rate_source {
+        ForexRateSource::OpenExchangeRates => fetch_forex_rates(state).await,
+        ForexRateSource::ApiLayer => fallback_fetch_forex_rates(state).await,
+    }
+}
+
 async fn fetch_forex_rates(
     state: &AppState,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexCacheError>> {
+) -> CustomResult<ForexRateSourceResponse, ForexCacheError> {
     let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
 
     let forex_url: String = format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
@@ -364,15 +431,18 @@ async fn fetch_forex_rates(
         };
     }
 
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
+    Ok(ForexRateSourceResponse {
+        rates: FxExchangeRatesCacheEntry::new(ExchangeRates::new(
+            enums::Currency::USD,
+            conversions,
+        )),
+        published_at: forex_response.timestamp,
+    })
 }
 
-pub async fn fallback_fetch_forex_rates(
+async fn fallback_fetch_forex_rates(
     state: &AppState,
-) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+) -> CustomResult<ForexRateSourceResponse, ForexCacheError> {
     let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
 
     let fallback_forex_url: String =
@@ -426,15 +496,13 @@ pub async fn fallback_fetch_forex_rates(
         };
     }
 
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-        Err(e) => {
-            logger::error!(?e);
-            Ok(rates)
-        }
-    }
+    Ok(ForexRateSourceResponse {
+        rates: FxExchangeRatesCacheEntry::new(ExchangeRates::new(
+            enums::Currency::USD,
+            conversions,
+        )),
+        published_at: fallback_forex_response.timestamp,
+    })
 }
 
 async fn release_redis_lock(
@@ -540,3 +608,110 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::sync::Mutex;
+
+    use super::*;
+
+    const CURRENT_TIME: i64 = 1_700_000_000;
+    const MAX_RATE_STALENESS_IN_SECS: i64 = 86400;
+    const RATE_SOURCES: [ForexRateSource; 2] = [
+        ForexRateSource::OpenExchangeRates,
+        ForexRateSource::ApiLayer,
+    ];
+
+    fn get_source_response(
+        rate_source: ForexRateSource,
+        published_at: i64,
+    ) -> ForexRateSourceResponse {
+        let rate = match rate_source {
+            ForexRateSource::OpenExchangeRates => Decimal::new(92, 2),
+            ForexRateSource::ApiLayer => Decimal::new(93, 2),
+        };
+        let conversion = HashMap::from([(
+            enums::Currency::EUR,
+            CurrencyFactors::new(rate, Decimal::ONE / rate),
+        )]);
+
+        ForexRateSourceResponse {
+            rates: FxExchangeRatesCacheEntry::new(ExchangeRates::new(
+                enums::Currency::USD,
+                conversion,
+            )),
+            published_at: Some(published_at),
+        }
+    }
+
+    fn get_eur_rate(rates: &FxExchangeRatesCacheEntry) -> Decimal {
+        rates
+            .data
+            .conversion
+            .get(&enums::Currency::EUR)
+            .unwrap()
+            .to_factor
+    }
+
+    #[tokio::test]
+    async fn test_rates_are_fetched_from_primary_source() {
+        let fetched_sources = Mutex::new(Vec::new());
+        let rates = fetch_forex_rates_with_failover(
+            &RATE_SOURCES,
+            MAX_RATE_STALENESS_IN_SECS,
+            CURRENT_TIME,
+            |rate_source| {
+                fetched_sources.lock().unwrap().push(rate_source);
+                async move { Ok(get_source_response(rate_source, CURRENT_TIME)) }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(get_eur_rate(&rates), Decimal::new(92, 2));
+        assert_eq!(
+            *fetched_sources.lock().unwrap(),
+            vec![ForexRateSource::OpenExchangeRates]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rates_fail_over_to_secondary_source_on_primary_error() {
+        let rates = fetch_forex_rates_with_failover(
+            &RATE_SOURCES,
+            MAX_RATE_STALENESS_IN_SECS,
+            CURRENT_TIME,
+            |rate_source| async move {
+                match rate_source {
+                    ForexRateSource::OpenExchangeRates => {
+                        Err(report!(ForexCacheError::ApiUnresponsive))
+                    }
+                    ForexRateSource::ApiLayer => Ok(get_source_response(rate_source, CURRENT_TIME)),
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(get_eur_rate(&rates), Decimal::new(93, 2));
+    }
+
+    #[tokio::test]
+    async fn test_stale_rates_are_rejected_when_all_sources_are_stale() {
+        let stale_published_at = CURRENT_TIME - MAX_RATE_STALENESS_IN_SECS - 1;
+        let error = fetch_forex_rates_with_failover(
+            &RATE_SOURCES,
+            MAX_RATE_STALENESS_IN_SECS,
+            CURRENT_TIME,
+            |rate_source| async move { Ok(get_source_response(rate_source, stale_published_at)) },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ForexCacheError::StaleRates
+        ));
+    }
+}
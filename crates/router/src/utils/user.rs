@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use api_models::user as user_api;
 use common_utils::{errors::CustomResult, pii};
-use diesel_models::{enums::UserStatus, user_role::UserRole};
+use diesel_models::{
+    enums::{TotpStatus, UserStatus},
+    user_role::UserRole,
+};
 use error_stack::ResultExt;
 use masking::ExposeInterface;
 use totp_rs::{Algorithm, TOTP};
@@ -215,3 +218,80 @@ pub fn generate_default_totp(
     )
     .change_context(UserErrors::InternalServerError)
 }
+
+pub fn validate_totp(totp: &TOTP, code: &str) -> UserResult<()> {
+    let now = u64::try_from(common_utils::date_time::now_unix_timestamp())
+        .change_context(UserErrors::InternalServerError)?;
+    validate_totp_at(totp, code, now)
+}
+
+/// Codes from one step before or after `time` are also accepted, to allow for clock skew
+/// between the server and the authenticator app.
+pub fn validate_totp_at(totp: &TOTP, code: &str, time: u64) -> UserResult<()> {
+    if totp.check(code, time) {
+        Ok(())
+    } else {
+        Err(UserErrors::InvalidTotp.into())
+    }
+}
+
+/// Users who have set up TOTP have to go through the single purpose token flows, which verify the
+/// TOTP before issuing a login token. The legacy flows issue a login token right away.
+pub fn ensure_totp_not_set(user: &UserFromStorage) -> UserResult<()> {
+    if user.get_totp_status() == TotpStatus::Set {
+        return Err(UserErrors::TotpRequired.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use super::*;
+
+    const NOW: u64 = 1_716_800_000;
+
+    fn email() -> pii::Email {
+        pii::Email::from_str("user@example.com").unwrap()
+    }
+
+    #[test]
+    fn test_enrolled_totp_secret_verifies_codes() {
+        let enrolled = generate_default_totp(email(), None).unwrap();
+        let stored_secret = masking::Secret::new(enrolled.get_secret_base32());
+
+        let restored = generate_default_totp(email(), Some(stored_secret)).unwrap();
+        let code = enrolled.generate(NOW);
+
+        assert!(validate_totp_at(&restored, &code, NOW).is_ok());
+    }
+
+    #[test]
+    fn test_totp_allows_one_step_of_clock_skew() {
+        let totp = generate_default_totp(email(), None).unwrap();
+        let step = consts::user::TOTP_VALIDITY_DURATION_IN_SECONDS;
+
+        assert!(validate_totp_at(&totp, &totp.generate(NOW - step), NOW).is_ok());
+        assert!(validate_totp_at(&totp, &totp.generate(NOW + step), NOW).is_ok());
+        assert!(validate_totp_at(&totp, &totp.generate(NOW + 3 * step), NOW).is_err());
+    }
+
+    #[test]
+    fn test_wrong_totp_is_rejected() {
+        let totp = generate_default_totp(email(), None).unwrap();
+        let wrong_code = if totp.generate(NOW) == "000000" {
+            "111111"
+        } else {
+            "000000"
+        };
+
+        let result = validate_totp_at(&totp, wrong_code, NOW);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            UserErrors::InvalidTotp
+        ));
+    }
+}
@@ -1,8 +1,16 @@
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
+#[cfg(feature = "olap")]
+pub mod applepay_domain_reverification;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod blocklist_entry_expiry;
+pub mod connector_health_probe;
 pub mod outgoing_webhook_retry;
+pub mod payment_auto_void;
+pub mod payment_deferred_capture;
+#[cfg(feature = "email")]
+pub mod payment_reject_notification;
 pub mod payment_sync;
 pub mod refund_router;
 pub mod tokenized_data;
@@ -0,0 +1,150 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::verification::{
+        self,
+        utils::{
+            self as verification_utils, ApplepayReverificationDecision,
+            ApplepayReverificationTrackingData,
+        },
+    },
+    errors,
+    routes::{metrics, AppState},
+    types::storage,
+};
+
+pub struct ApplepayDomainReverificationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ApplepayDomainReverificationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ApplepayReverificationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ApplepayReverificationTrackingData")?;
+
+        let merchant_connector_account = verification::find_merchant_connector_account(
+            state,
+            &tracking_data.merchant_id,
+            &tracking_data.merchant_connector_account_id,
+        )
+        .await?;
+        let verification_details =
+            verification_utils::ApplepayVerificationDetails::from_merchant_connector_account(
+                &merchant_connector_account,
+            );
+
+        match verification_utils::get_applepay_reverification_decision(
+            verification_details.as_ref(),
+            &tracking_data,
+            common_utils::date_time::now(),
+        ) {
+            ApplepayReverificationDecision::Skip => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        "APPLEPAY_VERIFICATION_ALREADY_RENEWED".to_string(),
+                    )
+                    .await?;
+            }
+            ApplepayReverificationDecision::RetryAt(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            ApplepayReverificationDecision::Reverify => {
+                let domain_names = verification_utils::get_domains_for_reverification(
+                    merchant_connector_account.applepay_verified_domains,
+                )?;
+
+                // Schedules the next re-verification on success
+                verification::register_applepay_domains(
+                    state,
+                    tracking_data.merchant_id.clone(),
+                    tracking_data.merchant_connector_account_id.clone(),
+                    domain_names,
+                )
+                .await?;
+
+                logger::info!(
+                    merchant_id = %tracking_data.merchant_id,
+                    "Re-verified apple pay domains"
+                );
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        let tracking_data = process
+            .tracking_data
+            .clone()
+            .parse_value::<ApplepayReverificationTrackingData>(
+                "ApplepayReverificationTrackingData",
+            );
+        let retry_time = tracking_data.as_ref().ok().and_then(|tracking_data| {
+            verification_utils::get_applepay_reverification_retry_time(
+                tracking_data,
+                process.retry_count,
+                common_utils::date_time::now(),
+            )
+        });
+
+        match retry_time {
+            Some(schedule_time) => {
+                logger::warn!(
+                    pt.id = %process.id,
+                    ?error,
+                    %schedule_time,
+                    "Failed to re-verify apple pay domains, retrying"
+                );
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes(
+                        "flow",
+                        "ApplepayDomainReverification",
+                    )],
+                );
+                state
+                    .store
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await
+            }
+            None => {
+                logger::error!(
+                    pt.id = %process.id,
+                    merchant_id = ?tracking_data.as_ref().ok().map(|data| &data.merchant_id),
+                    ?error,
+                    "Failed to re-verify apple pay domains before the verification expired"
+                );
+                metrics::APPLEPAY_REVERIFICATION_FAILURE_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                state
+                    .store
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+                    .await
+            }
+        }
+        .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed)
+    }
+}
@@ -0,0 +1,91 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::blocklist::utils::{
+        self as blocklist_utils, BlocklistEntryExpiryDecision, BlocklistEntryExpiryTrackingData,
+    },
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct BlocklistEntryExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for BlocklistEntryExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: BlocklistEntryExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BlocklistEntryExpiryTrackingData")?;
+
+        let blocklist_entry = match db
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(
+                &tracking_data.merchant_id,
+                &tracking_data.fingerprint_id,
+            )
+            .await
+        {
+            Ok(blocklist_entry) => Some(blocklist_entry),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => return Err(error.into()),
+        };
+
+        match blocklist_utils::get_blocklist_entry_expiry_decision(
+            blocklist_entry.as_ref(),
+            &tracking_data,
+            common_utils::date_time::now(),
+        ) {
+            BlocklistEntryExpiryDecision::Skip => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        "BLOCKLIST_ENTRY_NOT_EXPIRING".to_string(),
+                    )
+                    .await?;
+            }
+            BlocklistEntryExpiryDecision::RetryAt(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            BlocklistEntryExpiryDecision::Purge => {
+                db.delete_blocklist_entry_by_merchant_id_fingerprint_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.fingerprint_id,
+                )
+                .await?;
+
+                logger::info!(
+                    merchant_id = %tracking_data.merchant_id,
+                    "Purged expired blocklist entry"
+                );
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
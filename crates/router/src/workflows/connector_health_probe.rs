@@ -0,0 +1,86 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments::connector_health::{self, ConnectorHealthProbeTrackingData},
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct ConnectorHealthProbeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ConnectorHealthProbeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.connector_health_probe;
+        let tracking_data: ConnectorHealthProbeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorHealthProbeTrackingData")?;
+
+        if !config.enabled {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    "CONNECTOR_HEALTH_PROBE_DISABLED".to_string(),
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let current_time = common_utils::date_time::now();
+        let last_status =
+            connector_health::get_last_connector_health_status(state, &tracking_data.connector)
+                .await;
+
+        // The connector may have been probed recently by another task, probe only once per interval
+        let last_probed_at =
+            if connector_health::is_probe_due(config, last_status.as_ref(), current_time) {
+                match connector_health::probe_connector_health(state, &tracking_data.connector)
+                    .await
+                {
+                    Ok(health_status) => health_status.probed_at,
+                    Err(error) => {
+                        logger::error!(
+                            connector = %tracking_data.connector,
+                            connector_health_probe_error=?error
+                        );
+                        current_time
+                    }
+                }
+            } else {
+                last_status
+                    .map(|status| status.probed_at)
+                    .unwrap_or(current_time)
+            };
+
+        db.as_scheduler()
+            .reset_process(
+                process,
+                connector_health::get_next_probe_time(config, last_probed_at),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
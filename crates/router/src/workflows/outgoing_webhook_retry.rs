@@ -1,4 +1,5 @@
 use api_models::{
+    admin::{WebhookDetails, WebhookRetrySchedule},
     enums::EventType,
     webhook_events::OutgoingWebhookRequestContent,
     webhooks::{OutgoingWebhook, OutgoingWebhookContent},
@@ -159,6 +160,9 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
                             &merchant_account,
                             outgoing_webhook,
                             business_profile.payment_response_hash_key.as_deref(),
+                            webhooks_core::get_outgoing_webhook_signature_algorithm(
+                                &business_profile,
+                            ),
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -243,12 +247,25 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
 ///   seconds between them by default.
 /// - `custom_merchant_mapping.merchant_id1`: Merchant-specific retry configuration for merchant
 ///   with merchant ID `merchant_id1`.
+///
+/// If a retry schedule is configured in the webhook details of the business profile, it takes
+/// precedence over the above configuration.
 #[instrument(skip_all)]
 pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     db: &dyn StorageInterface,
-    merchant_id: &str,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
+    if let Some(retry_schedule) = get_business_profile_webhook_retry_schedule(business_profile) {
+        logger::debug!(
+            ?retry_schedule,
+            "Using business profile webhook retry schedule"
+        );
+        let time_delta = get_webhook_retry_schedule_delay(&retry_schedule, retry_count);
+        return scheduler_utils::get_time_from_delta(time_delta);
+    }
+
+    let merchant_id = business_profile.merchant_id.as_str();
     let key = "pt_mapping_outgoing_webhooks";
 
     let result = db
@@ -287,15 +304,66 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
+fn get_business_profile_webhook_retry_schedule(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> Option<WebhookRetrySchedule> {
+    business_profile
+        .webhook_details
+        .clone()?
+        .parse_value::<WebhookDetails>("WebhookDetails")
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse webhook details of business profile, using default webhook retry schedule"
+            )
+        })
+        .ok()?
+        .retry_schedule
+}
+
+/// Get the delay (in seconds) before the retry attempt following `retry_count` retry attempts,
+/// or `None` if no further retries should be attempted.
+pub(crate) fn get_webhook_retry_schedule_delay(
+    retry_schedule: &WebhookRetrySchedule,
+    retry_count: i32,
+) -> Option<i32> {
+    let retry_count = u32::try_from(retry_count).ok()?;
+
+    let delay = match retry_schedule {
+        WebhookRetrySchedule::Fixed { delays_in_seconds } => delays_in_seconds
+            .get(usize::try_from(retry_count).ok()?)
+            .copied()?,
+        WebhookRetrySchedule::Exponential {
+            initial_delay_in_seconds,
+            multiplier,
+            max_delay_in_seconds,
+            max_retries,
+        } => {
+            if retry_count >= *max_retries {
+                return None;
+            }
+
+            u64::from(*initial_delay_in_seconds)
+                .saturating_mul(u64::from(*multiplier).saturating_pow(retry_count))
+                .min(u64::from(*max_delay_in_seconds))
+                .try_into()
+                .ok()?
+        }
+    };
+
+    i32::try_from(delay).ok()
+}
+
 /// Schedule the webhook delivery task for retry
 #[instrument(skip_all)]
 pub(crate) async fn retry_webhook_delivery_task(
     db: &dyn StorageInterface,
-    merchant_id: &str,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
     process: storage::ProcessTracker,
 ) -> errors::CustomResult<(), errors::StorageError> {
     let schedule_time =
-        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
+        get_webhook_delivery_retry_schedule_time(db, business_profile, process.retry_count + 1)
+            .await;
 
     match schedule_time {
         Some(schedule_time) => {
@@ -476,3 +544,66 @@ async fn get_outgoing_webhook_content_and_event_type(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use api_models::admin::WebhookRetrySchedule;
+
+    use super::get_webhook_retry_schedule_delay;
+
+    #[test]
+    fn test_fixed_webhook_retry_schedule_delay() {
+        let retry_schedule = WebhookRetrySchedule::Fixed {
+            delays_in_seconds: vec![60, 300, 900],
+        };
+
+        let delays = (0..4)
+            .map(|retry_count| get_webhook_retry_schedule_delay(&retry_schedule, retry_count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays, vec![Some(60), Some(300), Some(900), None]);
+        assert_eq!(get_webhook_retry_schedule_delay(&retry_schedule, -1), None);
+    }
+
+    #[test]
+    fn test_exponential_webhook_retry_schedule_delay_is_capped() {
+        let retry_schedule = WebhookRetrySchedule::Exponential {
+            initial_delay_in_seconds: 60,
+            multiplier: 3,
+            max_delay_in_seconds: 3600,
+            max_retries: 6,
+        };
+
+        let delays = (0..7)
+            .map(|retry_count| get_webhook_retry_schedule_delay(&retry_schedule, retry_count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(60),
+                Some(180),
+                Some(540),
+                Some(1620),
+                Some(3600),
+                Some(3600),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exponential_webhook_retry_schedule_delay_does_not_overflow() {
+        let retry_schedule = WebhookRetrySchedule::Exponential {
+            initial_delay_in_seconds: u32::MAX,
+            multiplier: u32::MAX,
+            max_delay_in_seconds: 86400,
+            max_retries: 100,
+        };
+
+        assert_eq!(
+            get_webhook_retry_schedule_delay(&retry_schedule, 50),
+            Some(86400)
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{
+            self as payment_flows,
+            auto_void::{self, AutoVoidDecision, PaymentAutoVoidTrackingData},
+            operations,
+        },
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{api, storage},
+    utils,
+};
+
+pub struct PaymentAutoVoidWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentAutoVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: PaymentAutoVoidTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentAutoVoidTrackingData")?;
+
+        if !auto_void::is_auto_void_enabled(db, &tracking_data.merchant_id).await {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "AUTO_VOID_DISABLED".to_string())
+                .await
+                .map_err(Into::into);
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &tracking_data.attempt_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        match auto_void::get_auto_void_decision(
+            &state.conf.authorization_expiry,
+            &tracking_data,
+            payment_attempt.status,
+            common_utils::date_time::now(),
+        ) {
+            AutoVoidDecision::Skip => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        "AUTO_VOID_NOT_APPLICABLE".to_string(),
+                    )
+                    .await?;
+            }
+            AutoVoidDecision::RetryAt(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            AutoVoidDecision::Void => {
+                let cancel_request = api::PaymentsCancelRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    cancellation_reason: Some(auto_void::AUTO_VOID_CANCELLATION_REASON.to_string()),
+                    ..Default::default()
+                };
+
+                // TODO: Add support for ReqState in PT flows
+                let (payment_data, _, customer, _, _) = Box::pin(
+                    payment_flows::payments_operation_core::<api::Void, _, _, _>(
+                        state,
+                        state.get_req_state(),
+                        merchant_account.clone(),
+                        key_store.clone(),
+                        operations::PaymentCancel,
+                        cancel_request,
+                        payment_flows::CallConnectorAction::Trigger,
+                        services::AuthFlow::Merchant,
+                        None,
+                        api::HeaderPayload::default(),
+                    ),
+                )
+                .await?;
+
+                logger::info!(
+                    payment_id = %tracking_data.payment_id,
+                    status = ?payment_data.payment_attempt.status,
+                    "Voided authorization approaching expiry"
+                );
+
+                let profile_id = payment_data
+                    .payment_intent
+                    .profile_id
+                    .as_ref()
+                    .get_required_value("profile_id")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not find profile_id in payment intent")?;
+
+                let business_profile = db
+                    .find_business_profile_by_profile_id(profile_id)
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                        id: profile_id.to_string(),
+                    })?;
+
+                // Trigger the outgoing webhook to notify the merchant about the voided payment
+                Box::pin(utils::trigger_payments_webhook(
+                    merchant_account,
+                    business_profile,
+                    &key_store,
+                    payment_data,
+                    customer,
+                    state,
+                    operations::PaymentCancel,
+                ))
+                .await
+                .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+                .ok();
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,146 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{
+            self as payment_flows, capture_delay::PaymentDeferredCaptureTrackingData, operations,
+        },
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{api, storage, storage::enums},
+    utils,
+};
+
+pub struct PaymentDeferredCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentDeferredCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: PaymentDeferredCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentDeferredCaptureTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &tracking_data.attempt_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment may have been voided or captured since the capture was deferred
+        if payment_attempt.status != enums::AttemptStatus::Authorized {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    "DEFERRED_CAPTURE_NOT_APPLICABLE".to_string(),
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            ..tracking_data.capture_request
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            operations::PaymentCapture,
+            capture_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await?;
+
+        logger::info!(
+            payment_id = %tracking_data.payment_id,
+            status = ?payment_data.payment_attempt.status,
+            "Issued deferred capture"
+        );
+
+        let profile_id = payment_data
+            .payment_intent
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not find profile_id in payment intent")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+
+        // Trigger the outgoing webhook to notify the merchant about the captured payment
+        Box::pin(utils::trigger_payments_webhook(
+            merchant_account,
+            business_profile,
+            &key_store,
+            payment_data,
+            customer,
+            state,
+            operations::PaymentCapture,
+        ))
+        .await
+        .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+        .ok();
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,130 @@
+use common_utils::ext_traits::ValueExt;
+use masking::PeekInterface;
+use router_env::logger;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::operations::payment_reject::PaymentRejectNotificationTrackingData,
+    },
+    errors,
+    logger::error,
+    routes::AppState,
+    services::email::types::PaymentRejectedNotification,
+    types::storage,
+};
+
+pub struct PaymentRejectNotificationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentRejectNotificationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PaymentRejectNotificationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRejectNotificationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                tracking_data.payment_id.as_str(),
+                tracking_data.merchant_id.as_str(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let customer = match payment_intent.customer_id.as_deref() {
+            Some(customer_id) => {
+                db.find_customer_optional_by_customer_id_merchant_id(
+                    customer_id,
+                    tracking_data.merchant_id.as_str(),
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await?
+            }
+            None => None,
+        };
+
+        let Some(recipient_email) = customer.and_then(|customer| customer.email) else {
+            logger::info!(
+                payment_id = %tracking_data.payment_id,
+                "Customer not notified about the rejected payment since no email is available"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    "CUSTOMER_EMAIL_NOT_FOUND".to_string(),
+                )
+                .await
+                .map_err(Into::into);
+        };
+
+        let merchant_name = merchant_account
+            .merchant_name
+            .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+            .unwrap_or_else(|| tracking_data.merchant_id.clone());
+
+        let amount = match payment_intent.currency {
+            Some(currency) => format!(
+                "{} {currency}",
+                currency
+                    .to_currency_base_unit(payment_intent.amount)
+                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?
+            ),
+            None => payment_intent.amount.to_string(),
+        };
+
+        let email_contents = PaymentRejectedNotification {
+            recipient_email: recipient_email.into(),
+            subject: "Your payment has been declined",
+            merchant_name,
+            payment_id: tracking_data.payment_id.clone(),
+            amount,
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .map_err(errors::ProcessTrackerError::EEmailError)?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
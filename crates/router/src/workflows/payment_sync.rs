@@ -99,11 +99,22 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                     .clone()
                     .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
 
+                let retry_after = payment_flows::connector_retry_after::get_connector_retry_after(
+                    state,
+                    &payment_data.payment_attempt.merchant_id,
+                    &payment_data.payment_attempt.attempt_id,
+                )
+                .await
+                .map_err(|error| logger::error!(connector_retry_after_error=?error))
+                .ok()
+                .flatten();
+
                 let is_last_retry = retry_sync_task(
                     db,
                     connector,
                     payment_data.payment_attempt.merchant_id.clone(),
                     process,
+                    retry_after,
                 )
                 .await?;
 
@@ -133,6 +144,7 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                             unified_message: None,
                             connector_transaction_id: None,
                             payment_method_data: None,
+                            connector_latency: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -252,19 +264,25 @@ pub async fn get_sync_process_schedule_time(
 
 /// Schedule the task for retry
 ///
+/// If the connector requested a delay through the `Retry-After` header of a rate limited
+/// response, the task is scheduled at the later of `retry_after` and the configured schedule
+///
 /// Returns bool which indicates whether this was the last retry or not
 pub async fn retry_sync_task(
     db: &dyn StorageInterface,
     connector: String,
     merchant_id: String,
     pt: storage::ProcessTracker,
+    retry_after: Option<time::PrimitiveDateTime>,
 ) -> Result<bool, sch_errors::ProcessTrackerError> {
     let schedule_time =
         get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count + 1).await?;
 
     match schedule_time {
         Some(s_time) => {
-            db.as_scheduler().retry_process(pt, s_time).await?;
+            db.as_scheduler()
+                .retry_process(pt, get_retry_time(s_time, retry_after))
+                .await?;
             Ok(false)
         }
         None => {
@@ -276,11 +294,40 @@ pub async fn retry_sync_task(
     }
 }
 
+/// A `Retry-After` only ever delays the retry, it never brings it before the configured backoff
+fn get_retry_time(
+    schedule_time: time::PrimitiveDateTime,
+    retry_after: Option<time::PrimitiveDateTime>,
+) -> time::PrimitiveDateTime {
+    retry_after.map_or(schedule_time, |retry_after| retry_after.max(schedule_time))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
     use super::*;
 
+    #[test]
+    fn test_retry_after_does_not_shorten_the_backoff() {
+        let schedule_time = common_utils::date_time::now() + time::Duration::minutes(5);
+
+        assert_eq!(get_retry_time(schedule_time, None), schedule_time);
+        assert_eq!(
+            get_retry_time(
+                schedule_time,
+                Some(schedule_time - time::Duration::minutes(4))
+            ),
+            schedule_time
+        );
+        assert_eq!(
+            get_retry_time(
+                schedule_time,
+                Some(schedule_time + time::Duration::minutes(10))
+            ),
+            schedule_time + time::Duration::minutes(10)
+        );
+    }
+
     #[test]
     fn test_get_default_schedule_time() {
         let schedule_time_delta =
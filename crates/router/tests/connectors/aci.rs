@@ -152,6 +152,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             reason: None,
             connector_refund_id: None,
             browser_info: None,
+            merchant_refund_reference_id: None,
         },
         response: Err(types::ErrorResponse::default()),
         address: PaymentAddress::default(),
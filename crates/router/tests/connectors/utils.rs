@@ -423,6 +423,7 @@ pub trait ConnectorActions: Connector {
                 reason: None,
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
+                merchant_refund_reference_id: None,
             }),
             payment_info,
         );
@@ -486,6 +487,7 @@ pub trait ConnectorActions: Connector {
                     phone_country_code: Some("+31".to_string()),
                 }),
                 vendor_details: None,
+                cancellation_reason: None,
             },
             payment_info,
         )
@@ -1017,6 +1019,7 @@ impl Default for PaymentRefundType {
             reason: Some("Customer returned product".to_string()),
             connector_refund_id: None,
             browser_info: None,
+            merchant_refund_reference_id: None,
         };
         Self(data)
     }
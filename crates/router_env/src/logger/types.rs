@@ -73,8 +73,14 @@ pub enum Flow {
     MerchantConnectorsCreate,
     /// Merchant Connectors retrieve flow.
     MerchantConnectorsRetrieve,
+    /// Merchant Connectors health check flow.
+    MerchantConnectorsHealthCheck,
     /// Merchant account list
     MerchantAccountList,
+    /// Organization parent update flow
+    OrganizationParentUpdate,
+    /// Organization descendants list flow
+    OrganizationDescendantsList,
     /// Merchant Connectors update flow.
     MerchantConnectorsUpdate,
     /// Merchant Connectors delete flow.
@@ -99,6 +105,12 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers add address flow.
+    CustomersAddAddress,
+    /// Customers list addresses flow.
+    CustomersListAddresses,
+    /// Customers delete address flow.
+    CustomersDeleteAddress,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -107,6 +119,10 @@ pub enum Flow {
     MandatesRetrieve,
     /// Mandates revoke flow.
     MandatesRevoke,
+    /// Mandates pause flow.
+    MandatesPause,
+    /// Mandates resume flow.
+    MandatesResume,
     /// Mandates list flow.
     MandatesList,
     /// Payment methods create flow.
@@ -129,6 +145,12 @@ pub enum Flow {
     PaymentMethodsDelete,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
+    /// Customer payment methods reorder flow.
+    CustomerPaymentMethodsReorder,
+    /// Customer payment methods usability probe flow.
+    CustomerPaymentMethodsProbe,
+    /// Customer payment methods import flow.
+    CustomerPaymentMethodsImport,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -147,6 +169,8 @@ pub enum Flow {
     PaymentsApprove,
     /// Payments reject flow.
     PaymentsReject,
+    /// Payments bulk reject flow.
+    PaymentsBulkReject,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
@@ -158,6 +182,8 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts create flow
     PayoutsCreate,
+    /// Payouts batch create flow
+    PayoutsBatchCreate,
     #[cfg(feature = "payouts")]
     /// Payouts retrieve flow.
     PayoutsRetrieve,
@@ -188,6 +214,10 @@ pub enum Flow {
     RefundsRetrieveForceSync,
     /// Refunds update flow.
     RefundsUpdate,
+    /// Refunds approval flow.
+    RefundsApproval,
+    /// Refunds eligibility flow.
+    RefundsEligibility,
     /// Refunds list flow.
     RefundsList,
     /// Refunds filters flow
@@ -202,6 +232,10 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Recon difference report flow
+    ReconDifferenceReport,
+    /// Recon entry resolve flow
+    ReconEntryResolve,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -222,10 +256,16 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing evaluate config
+    RoutingEvaluateConfig,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
     DeleteFromBlocklist,
+    /// Add records to blocklist in bulk
+    BulkAddToBlocklist,
+    /// Delete records from blocklist in bulk
+    BulkDeleteFromBlocklist,
     /// List entries from blocklist
     ListBlocklist,
     /// Toggle blocklist for merchant
@@ -244,20 +284,28 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
     DisputesList,
     /// Cards Info flow
     CardsInfo,
+    /// Cards Info import flow
+    CardsInfoImport,
     /// Create File flow
     CreateFile,
     /// Delete File flow
     DeleteFile,
     /// Retrieve File flow
     RetrieveFile,
+    /// Download File flow
+    DownloadFile,
     /// Dispute Evidence submission flow
     DisputesEvidenceSubmit,
+    /// Dispute Evidence bundle submission flow
+    DisputesEvidenceBundleSubmit,
     /// Create Config Key flow
     CreateConfigKey,
     /// Attach Dispute Evidence flow
@@ -290,14 +338,20 @@ pub enum Flow {
     Verification,
     /// Rust locker migration
     RustLockerMigration,
+    /// Locker migration status flow
+    RustLockerMigrationStatus,
     /// Gsm Rule Creation flow
     GsmRuleCreate,
     /// Gsm Rule Retrieve flow
     GsmRuleRetrieve,
+    /// Gsm Rule Lookup flow
+    GsmRuleLookup,
     /// Gsm Rule Update flow
     GsmRuleUpdate,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Connector replay records retrieve flow
+    ConnectorReplayRetrieve,
     /// User Sign Up
     UserSignUp,
     /// User Sign Up
@@ -358,6 +412,8 @@ pub enum Flow {
     PmAuthLinkTokenCreate,
     /// PaymentMethodAuth Exchange token create
     PmAuthExchangeToken,
+    /// PaymentMethodAuth Connection status retrieve
+    PmAuthConnectionStatusRetrieve,
     /// Get reset password link
     ForgotPassword,
     /// Reset password using link
@@ -372,6 +428,8 @@ pub enum Flow {
     AcceptInviteFromEmail,
     /// Delete user role
     DeleteUserRole,
+    /// Force signout of all sessions of a user
+    ForceSignout,
     /// Incremental Authorization flow
     PaymentsIncrementalAuthorization,
     /// Get action URL for connector onboarding
@@ -398,6 +456,8 @@ pub enum Flow {
     CreateRole,
     /// Update Role
     UpdateRole,
+    /// Delete Role
+    DeleteRole,
     /// User email flow start
     UserFromEmail,
     /// Begin TOTP
@@ -410,8 +470,12 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Replay a webhook event with the current signing key
+    WebhookEventReplay,
     /// Retrieve status of the Poll
     RetrievePollStatus,
+    /// Retrieve progress of the active poll of a payment
+    RetrievePollProgress,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
@@ -58,6 +58,8 @@ pub struct MockDb {
     pub authentications: Arc<Mutex<Vec<store::authentication::Authentication>>>,
     pub roles: Arc<Mutex<Vec<store::role::Role>>>,
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
+    pub gsm_rules: Arc<Mutex<Vec<store::gsm::GatewayStatusMap>>>,
+    pub recon_entries: Arc<Mutex<Vec<store::recon_entry::ReconEntry>>>,
 }
 
 impl MockDb {
@@ -102,6 +104,8 @@ impl MockDb {
             authentications: Default::default(),
             roles: Default::default(),
             user_key_store: Default::default(),
+            gsm_rules: Default::default(),
+            recon_entries: Default::default(),
         })
     }
 }
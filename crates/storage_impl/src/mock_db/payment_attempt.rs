@@ -157,6 +157,8 @@ impl PaymentAttemptInterface for MockDb {
             mandate_data: payment_attempt.mandate_data,
             payment_method_billing_address_id: payment_attempt.payment_method_billing_address_id,
             fingerprint_id: payment_attempt.fingerprint_id,
+            connector_latency: payment_attempt.connector_latency,
+            authorized_at: None,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
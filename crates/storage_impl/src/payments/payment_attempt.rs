@@ -410,6 +410,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .payment_method_billing_address_id
                         .clone(),
                     fingerprint_id: payment_attempt.fingerprint_id.clone(),
+                    connector_latency: payment_attempt.connector_latency,
+                    authorized_at: None,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1051,6 +1053,8 @@ impl DataModelExt for MandateAmountData {
             start_date: self.start_date,
             end_date: self.end_date,
             metadata: self.metadata,
+            max_total_amount: self.max_total_amount,
+            max_debit_count: self.max_debit_count,
         }
     }
 
@@ -1061,6 +1065,8 @@ impl DataModelExt for MandateAmountData {
             start_date: storage_model.start_date,
             end_date: storage_model.end_date,
             metadata: storage_model.metadata,
+            max_total_amount: storage_model.max_total_amount,
+            max_debit_count: storage_model.max_debit_count,
         }
     }
 }
@@ -1164,6 +1170,8 @@ impl DataModelExt for PaymentAttempt {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            connector_latency: self.connector_latency,
+            authorized_at: self.authorized_at,
         }
     }
 
@@ -1228,6 +1236,8 @@ impl DataModelExt for PaymentAttempt {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            connector_latency: storage_model.connector_latency,
+            authorized_at: storage_model.authorized_at,
         }
     }
 }
@@ -1290,6 +1300,7 @@ impl DataModelExt for PaymentAttemptNew {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            connector_latency: self.connector_latency,
         }
     }
 
@@ -1352,6 +1363,7 @@ impl DataModelExt for PaymentAttemptNew {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            connector_latency: storage_model.connector_latency,
         }
     }
 }
@@ -1528,6 +1540,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_latency,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1548,6 +1561,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_latency,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1559,6 +1573,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             } => DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
                 connector,
@@ -1569,6 +1584,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             },
             Self::StatusUpdate { status, updated_by } => {
                 DieselPaymentAttemptUpdate::StatusUpdate { status, updated_by }
@@ -1585,6 +1601,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_latency,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1597,6 +1614,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_latency,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -1849,6 +1867,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_latency,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -1869,6 +1888,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_latency,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1880,6 +1900,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             } => Self::UnresolvedResponseUpdate {
                 status,
                 connector,
@@ -1890,6 +1911,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             },
             DieselPaymentAttemptUpdate::StatusUpdate { status, updated_by } => {
                 Self::StatusUpdate { status, updated_by }
@@ -1906,6 +1928,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_latency,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -1918,6 +1941,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_latency,
             },
             DieselPaymentAttemptUpdate::CaptureUpdate {
                 amount_to_capture,
@@ -78,6 +78,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     profile_id: new_payout_attempt.profile_id.clone(),
                     merchant_connector_id: new_payout_attempt.merchant_connector_id.clone(),
                     routing_info: new_payout_attempt.routing_info.clone(),
+                    cancellation_reason: new_payout_attempt.cancellation_reason.clone(),
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -387,6 +388,7 @@ impl DataModelExt for PayoutAttempt {
             profile_id: self.profile_id,
             merchant_connector_id: self.merchant_connector_id,
             routing_info: self.routing_info,
+            cancellation_reason: self.cancellation_reason,
         }
     }
 
@@ -411,6 +413,7 @@ impl DataModelExt for PayoutAttempt {
             profile_id: storage_model.profile_id,
             merchant_connector_id: storage_model.merchant_connector_id,
             routing_info: storage_model.routing_info,
+            cancellation_reason: storage_model.cancellation_reason,
         }
     }
 }
@@ -438,6 +441,7 @@ impl DataModelExt for PayoutAttemptNew {
             profile_id: self.profile_id,
             merchant_connector_id: self.merchant_connector_id,
             routing_info: self.routing_info,
+            cancellation_reason: self.cancellation_reason,
         }
     }
 
@@ -462,6 +466,7 @@ impl DataModelExt for PayoutAttemptNew {
             profile_id: storage_model.profile_id,
             merchant_connector_id: storage_model.merchant_connector_id,
             routing_info: storage_model.routing_info,
+            cancellation_reason: storage_model.cancellation_reason,
         }
     }
 }
@@ -499,6 +504,11 @@ impl DataModelExt for PayoutAttemptUpdate {
                 connector,
                 routing_info,
             },
+            Self::CancellationReasonUpdate {
+                cancellation_reason,
+            } => DieselPayoutAttemptUpdate::CancellationReasonUpdate {
+                cancellation_reason,
+            },
         }
     }
 
@@ -2,7 +2,7 @@ use error_stack::ResultExt;
 use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
 use router_env::logger;
 
-use crate::redis::cache::{CacheKind, ACCOUNTS_CACHE, CONFIG_CACHE};
+use crate::redis::cache::{CacheKind, ACCOUNTS_CACHE, CARD_INFO_CACHE, CONFIG_CACHE};
 
 #[async_trait::async_trait]
 pub trait PubSubInterface {
@@ -67,9 +67,14 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
                     ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
                     key
                 }
+                CacheKind::CardInfo(key) => {
+                    CARD_INFO_CACHE.invalidate(key.as_ref()).await;
+                    key
+                }
                 CacheKind::All(key) => {
                     CONFIG_CACHE.invalidate(key.as_ref()).await;
                     ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
+                    CARD_INFO_CACHE.invalidate(key.as_ref()).await;
                     key
                 }
             };
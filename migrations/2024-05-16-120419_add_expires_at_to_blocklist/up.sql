@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS refund_merchant_id_merchant_refund_reference_id_index;
+
+ALTER TABLE refund DROP COLUMN IF EXISTS merchant_refund_reference_id;
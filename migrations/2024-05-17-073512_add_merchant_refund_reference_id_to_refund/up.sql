@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS merchant_refund_reference_id VARCHAR(64);
+
+CREATE UNIQUE INDEX IF NOT EXISTS refund_merchant_id_merchant_refund_reference_id_index ON refund (merchant_id, merchant_refund_reference_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS max_total_amount,
+DROP COLUMN IF EXISTS max_debit_count,
+DROP COLUMN IF EXISTS debit_count,
+DROP COLUMN IF EXISTS debited_amount;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS max_total_amount BIGINT,
+ADD COLUMN IF NOT EXISTS max_debit_count INTEGER,
+ADD COLUMN IF NOT EXISTS debit_count INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN IF NOT EXISTS debited_amount BIGINT NOT NULL DEFAULT 0;
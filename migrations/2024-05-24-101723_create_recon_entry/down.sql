@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS recon_entry_merchant_id_connector_entry_id_index;
+
+DROP TABLE IF EXISTS recon_entry;
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS recon_entry (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    entry_id VARCHAR(255) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    note TEXT,
+    resolved_by VARCHAR(64),
+    recorded_amount BIGINT,
+    settled_amount BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS recon_entry_merchant_id_connector_entry_id_index ON recon_entry (merchant_id, connector, entry_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS permissions TEXT[];
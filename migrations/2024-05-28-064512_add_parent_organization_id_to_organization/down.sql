@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS organization_parent_organization_id_index;
+
+ALTER TABLE organization DROP COLUMN IF EXISTS parent_organization_id;
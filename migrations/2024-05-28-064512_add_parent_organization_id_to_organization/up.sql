@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE organization ADD COLUMN IF NOT EXISTS parent_organization_id VARCHAR(32);
+
+CREATE INDEX IF NOT EXISTS organization_parent_organization_id_index ON organization (parent_organization_id);
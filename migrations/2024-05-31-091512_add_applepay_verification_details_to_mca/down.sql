@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account
+DROP COLUMN IF EXISTS applepay_verified_at,
+DROP COLUMN IF EXISTS applepay_verification_expires_at;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account
+ADD COLUMN IF NOT EXISTS applepay_verified_at TIMESTAMP DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS applepay_verification_expires_at TIMESTAMP DEFAULT NULL;